@@ -0,0 +1,128 @@
+//! Micro-benchmarks of the engines and their numerical kernels.
+//!
+//! Run with `cargo bench --features perfcounters`; pass a substring to only
+//! run matching benchmarks.
+extern crate quantlib;
+
+use quantlib::math::{linear_least_squares, Matrix};
+use quantlib::methods::montecarlo::{LsmBasisSystem, PolynomialType};
+use quantlib::termstructures::{Compounding, InterestRate};
+use quantlib::testutils::Rng;
+use quantlib::time::{Actual365Fixed, DayCounter, Frequency};
+use quantlib::utils::Parallelism;
+use quantlib::xva::{AmcExposureEngine, AmcPath, ExerciseRight, Settlement};
+use std::hint::black_box;
+use std::time::Instant;
+
+fn bench<R, F: FnMut() -> R>(filter: &Option<String>, name: &str, iterations: u32, mut f: F) {
+    if let Some(filter) = filter {
+        if !name.contains(filter.as_str()) {
+            return;
+        }
+    }
+    // warm up.
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let per_iter = start.elapsed().as_nanos() as f64 / f64::from(iterations);
+    println!("{:<32} {:>14.0} ns/iter", name, per_iter);
+}
+
+// Bermudan put on geometric Brownian motion paths.
+fn put_paths(paths: usize, dates: usize) -> Vec<AmcPath> {
+    let mut rng = Rng::new(1);
+    let (r, sigma, dt) = (0.05, 0.2, 1.0 / dates as f64);
+    (0..paths)
+        .map(|_| {
+            let mut s = 1.0;
+            let mut states = vec![];
+            for i in 0..dates {
+                if i > 0 {
+                    // sum of twelve uniforms is a cheap normal approximation.
+                    let z: f64 = (0..12).map(|_| rng.uniform()).sum::<f64>() - 6.0;
+                    s *= ((r - 0.5 * sigma * sigma) * dt + sigma * dt.sqrt() * z).exp();
+                }
+                states.push(vec![s]);
+            }
+            AmcPath {
+                numeraire: (0..dates).map(|i| (r * dt * i as f64).exp()).collect(),
+                exercise_values: states.iter().map(|x| (1.0 - x[0]).max(0.0)).collect(),
+                states,
+                cashflows: vec![0.0; dates],
+                underlying_values: vec![],
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|a| !a.starts_with('-'));
+
+    let paths = put_paths(10_000, 13);
+    let mut exercise = vec![true; 13];
+    exercise[0] = false;
+    let engine = AmcExposureEngine::new(
+        LsmBasisSystem::path_basis_system(3, PolynomialType::Laguerre),
+        exercise,
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    );
+    bench(&filter, "amc_bermudan_put_10k_paths", 10, || {
+        engine.calculate(&paths)
+    });
+    let results = engine.calculate(&paths);
+    if let Some(pps) = results.performance.paths_per_second() {
+        println!(
+            "{:<32} {:>14.0} paths/sec",
+            "amc_bermudan_put_10k_paths", pps
+        );
+    }
+
+    let mut rng = Rng::new(2);
+    let rows: Vec<Vec<f64>> = (0..1000)
+        .map(|_| (0..6).map(|_| rng.uniform()).collect())
+        .collect();
+    let a = Matrix::from_rows(&rows);
+    let b: Vec<f64> = (0..1000).map(|_| rng.uniform()).collect();
+    bench(&filter, "least_squares_1000x6", 100, || {
+        linear_least_squares(&a, &b)
+    });
+
+    let ir = InterestRate::new(
+        0.05,
+        Actual365Fixed {},
+        Compounding::Compounded,
+        Frequency::Semiannual,
+    );
+    bench(&filter, "rate_conversion", 100_000, || {
+        let c = ir.compound_factor_with_time(black_box(7.5));
+        InterestRate::implied_rate_with_time(
+            c,
+            Actual365Fixed {},
+            Compounding::Continuous,
+            Frequency::Annual,
+            7.5,
+        )
+        .rate
+    });
+
+    let dates: Vec<_> = (0..1000).map(|_| rng.any_date()).collect();
+    bench(&filter, "actual365_year_fractions_1000", 1000, || {
+        let dc = Actual365Fixed {};
+        dates
+            .windows(2)
+            .map(|w| dc.year_fraction(w[0], w[1], None, None))
+            .sum::<f64>()
+    });
+
+    let work: Vec<f64> = (0..64).map(f64::from).collect();
+    let heavy = |x: &f64| (0..20_000).fold(*x, |acc, i| (acc + f64::from(i)).sqrt());
+    bench(&filter, "parallel_map_sequential", 20, || {
+        Parallelism::Sequential.map(&work, heavy)
+    });
+    bench(&filter, "parallel_map_available", 20, || {
+        Parallelism::Available.map(&work, heavy)
+    });
+}
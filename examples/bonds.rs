@@ -1,5 +1,3 @@
-use quantlib::instruments::FixedRateBond;
-
 fn main() {
     // ZC
     // Fixed
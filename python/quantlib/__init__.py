@@ -0,0 +1,525 @@
+"""Curves, schedules, bonds, swaps and vanilla options of the quantlib crate.
+
+The package loads the crate built as a shared library with its ``python``
+feature::
+
+    cargo rustc --release --features python --lib --crate-type cdylib
+
+from ``target/release``, or from the path in ``QUANTLIB_LIBRARY``. Dates
+are ``datetime.date`` objects, and failures raise ``QuantLibError``.
+"""
+
+import ctypes
+import datetime
+import enum
+import os
+import sys
+from ctypes import POINTER, c_bool, c_char_p, c_double, c_int, c_int32, c_int64
+from ctypes import c_size_t, c_void_p
+
+__all__ = [
+    "BusinessDayConvention",
+    "Calendar",
+    "Compounding",
+    "DateGeneration",
+    "DayCounter",
+    "FixedRateBond",
+    "OptionType",
+    "QuantLibError",
+    "Schedule",
+    "SwapType",
+    "TimeUnit",
+    "VanillaOption",
+    "VanillaSwap",
+    "YieldCurve",
+    "set_evaluation_date",
+]
+
+
+class QuantLibError(Exception):
+    """A call into the library failed."""
+
+
+class Calendar(enum.IntEnum):
+    WEEKENDS_ONLY = 0
+    NULL = 1
+    BRAZIL = 2
+    SWEDEN = 3
+
+
+class DayCounter(enum.IntEnum):
+    ACTUAL_360 = 0
+    ACTUAL_365_FIXED = 1
+    THIRTY_360 = 2
+    ACTUAL_ACTUAL = 3
+
+
+class BusinessDayConvention(enum.IntEnum):
+    FOLLOWING = 0
+    MODIFIED_FOLLOWING = 1
+    PRECEDING = 2
+    MODIFIED_PRECEDING = 3
+    UNADJUSTED = 4
+
+
+class TimeUnit(enum.IntEnum):
+    DAYS = 0
+    WEEKS = 1
+    MONTHS = 2
+    YEARS = 3
+
+
+class DateGeneration(enum.IntEnum):
+    BACKWARD = 1
+    FORWARD = 2
+    ZERO = 3
+    THIRD_WEDNESDAY = 4
+    TWENTIETH = 5
+    TWENTIETH_IMM = 6
+
+
+class Compounding(enum.IntEnum):
+    SIMPLE = 0
+    COMPOUNDED = 1
+    CONTINUOUS = 2
+
+
+class OptionType(enum.IntEnum):
+    CALL = 1
+    PUT = -1
+
+
+class SwapType(enum.IntEnum):
+    PAYER = 1
+    RECEIVER = -1
+
+
+def _library_path():
+    path = os.environ.get("QUANTLIB_LIBRARY")
+    if path:
+        return path
+    if sys.platform == "win32":
+        name = "quantlib.dll"
+    elif sys.platform == "darwin":
+        name = "libquantlib.dylib"
+    else:
+        name = "libquantlib.so"
+    root = os.path.dirname(os.path.dirname(os.path.dirname(os.path.abspath(__file__))))
+    return os.path.join(root, "target", "release", name)
+
+
+_lib = ctypes.CDLL(_library_path())
+
+_DOUBLE = POINTER(c_double)
+_SIGNATURES = {
+    "ql_last_error": ([], c_char_p),
+    "ql_set_evaluation_date": ([c_int32], c_int),
+    "ql_curve_flat": ([c_int32, c_double, c_int32], c_void_p),
+    "ql_curve_bootstrap": (
+        [
+            c_int32,
+            c_int32,
+            POINTER(c_int32),
+            _DOUBLE,
+            c_size_t,
+            POINTER(c_int32),
+            _DOUBLE,
+            c_size_t,
+            c_int64,
+            c_int32,
+        ],
+        c_void_p,
+    ),
+    "ql_curve_reference_date": ([c_void_p, POINTER(c_int32)], c_int),
+    "ql_curve_discount": ([c_void_p, c_int32, c_bool, _DOUBLE], c_int),
+    "ql_curve_zero_rate": ([c_void_p, c_int32, c_bool, _DOUBLE], c_int),
+    "ql_curve_forward_rate": ([c_void_p, c_int32, c_int32, c_bool, _DOUBLE], c_int),
+    "ql_curve_free": ([c_void_p], None),
+    "ql_schedule_new": (
+        [c_int32, c_int32, c_int64, c_int32, c_int32, c_int32, c_int32, c_int32, c_bool],
+        c_void_p,
+    ),
+    "ql_schedule_size": ([c_void_p, POINTER(c_size_t)], c_int),
+    "ql_schedule_date": ([c_void_p, c_size_t, POINTER(c_int32)], c_int),
+    "ql_schedule_is_regular": ([c_void_p, c_size_t, POINTER(c_bool)], c_int),
+    "ql_schedule_free": ([c_void_p], None),
+    "ql_bond_new": ([c_void_p, c_double, c_int32, c_int32, c_double], c_void_p),
+    "ql_bond_clean_price": ([c_void_p, c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_bond_dirty_price": ([c_void_p, c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_bond_accrued_amount": ([c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_bond_clean_price_from_yield": ([c_void_p, c_double, c_int32, c_int32, _DOUBLE], c_int),
+    "ql_bond_yield": ([c_void_p, c_double, c_int32, c_int32, _DOUBLE], c_int),
+    "ql_bond_duration": ([c_void_p, c_double, c_int32, c_bool, c_int32, _DOUBLE], c_int),
+    "ql_bond_free": ([c_void_p], None),
+    "ql_swap_new": (
+        [
+            c_int32,
+            c_double,
+            c_void_p,
+            c_double,
+            c_int32,
+            c_void_p,
+            c_int64,
+            c_int64,
+            c_int32,
+            c_void_p,
+            c_double,
+        ],
+        c_void_p,
+    ),
+    "ql_swap_npv": ([c_void_p, c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_swap_fair_rate": ([c_void_p, c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_swap_fair_spread": ([c_void_p, c_void_p, c_int32, _DOUBLE], c_int),
+    "ql_swap_free": ([c_void_p], None),
+    "ql_option_new": ([c_int32, c_double, c_int32], c_void_p),
+    "ql_option_npv": ([c_void_p, c_double, c_void_p, c_void_p, c_double, _DOUBLE], c_int),
+    "ql_option_greeks": (
+        [c_void_p, c_double, c_void_p, c_void_p, c_double, POINTER(c_double * 7)],
+        c_int,
+    ),
+    "ql_option_binomial_npv": (
+        [c_void_p, c_bool, c_double, c_void_p, c_void_p, c_double, c_size_t, _DOUBLE],
+        c_int,
+    ),
+    "ql_option_free": ([c_void_p], None),
+}
+for _name, (_argtypes, _restype) in _SIGNATURES.items():
+    _function = getattr(_lib, _name)
+    _function.argtypes = _argtypes
+    _function.restype = _restype
+
+
+def _error():
+    message = _lib.ql_last_error()
+    return QuantLibError(message.decode() if message else "unknown error")
+
+
+def _new(function, *args):
+    pointer = function(*args)
+    if not pointer:
+        raise _error()
+    return pointer
+
+
+def _call(function, out_type, *args):
+    out = out_type()
+    if function(*args, ctypes.byref(out)) != 0:
+        raise _error()
+    return out
+
+
+def _float(function, *args):
+    return _call(function, c_double, *args).value
+
+
+def _ordinal(date):
+    return date.toordinal()
+
+
+def _date(ordinal):
+    return datetime.date.fromordinal(ordinal)
+
+
+def set_evaluation_date(date):
+    """Evaluate fixings as of `date` on every thread."""
+    if _lib.ql_set_evaluation_date(_ordinal(date)) != 0:
+        raise _error()
+
+
+class _Handle:
+    """An object owned by the library, released with the handle."""
+
+    _free = None
+
+    def __init__(self, pointer):
+        self._pointer = pointer
+
+    def __del__(self):
+        pointer, self._pointer = getattr(self, "_pointer", None), None
+        if pointer and self._free is not None:
+            getattr(_lib, self._free)(pointer)
+
+
+class YieldCurve(_Handle):
+    """An Actual/365 (Fixed) discount curve."""
+
+    _free = "ql_curve_free"
+
+    @classmethod
+    def flat(cls, reference_date, rate, calendar=Calendar.WEEKENDS_ONLY):
+        """A curve of flat continuously compounded `rate`."""
+        return cls(_new(_lib.ql_curve_flat, _ordinal(reference_date), rate, calendar))
+
+    @classmethod
+    def bootstrap(
+        cls,
+        reference_date,
+        deposits=(),
+        swaps=(),
+        calendar=Calendar.WEEKENDS_ONLY,
+        fixed_tenor_months=12,
+        fixed_day_counter=DayCounter.THIRTY_360,
+    ):
+        """A log-linear curve repricing `deposits` and `swaps`, each a
+        sequence of `(maturity, rate)` pairs.
+
+        Deposits are Actual/360 from the reference date, and swaps pay a
+        fixed leg every `fixed_tenor_months` from it."""
+        deposits, swaps = list(deposits), list(swaps)
+        deposit_maturities = (c_int32 * len(deposits))(*(_ordinal(d) for d, _ in deposits))
+        deposit_rates = (c_double * len(deposits))(*(r for _, r in deposits))
+        swap_maturities = (c_int32 * len(swaps))(*(_ordinal(d) for d, _ in swaps))
+        swap_rates = (c_double * len(swaps))(*(r for _, r in swaps))
+        return cls(
+            _new(
+                _lib.ql_curve_bootstrap,
+                _ordinal(reference_date),
+                calendar,
+                deposit_maturities,
+                deposit_rates,
+                len(deposits),
+                swap_maturities,
+                swap_rates,
+                len(swaps),
+                fixed_tenor_months,
+                fixed_day_counter,
+            )
+        )
+
+    @property
+    def reference_date(self):
+        ordinal = _call(_lib.ql_curve_reference_date, c_int32, self._pointer)
+        return _date(ordinal.value)
+
+    def discount(self, date, extrapolate=False):
+        return _float(_lib.ql_curve_discount, self._pointer, _ordinal(date), extrapolate)
+
+    def zero_rate(self, date, extrapolate=False):
+        """The continuously compounded zero rate to `date`."""
+        return _float(_lib.ql_curve_zero_rate, self._pointer, _ordinal(date), extrapolate)
+
+    def forward_rate(self, start, end, extrapolate=False):
+        """The continuously compounded forward rate from `start` to `end`."""
+        return _float(
+            _lib.ql_curve_forward_rate,
+            self._pointer,
+            _ordinal(start),
+            _ordinal(end),
+            extrapolate,
+        )
+
+
+class Schedule(_Handle):
+    """The dates of the periods from `effective_date` to
+    `termination_date` every `tenor_length` of `tenor_unit`."""
+
+    _free = "ql_schedule_free"
+
+    def __init__(
+        self,
+        effective_date,
+        termination_date,
+        tenor_length,
+        tenor_unit=TimeUnit.MONTHS,
+        calendar=Calendar.WEEKENDS_ONLY,
+        convention=BusinessDayConvention.MODIFIED_FOLLOWING,
+        termination_date_convention=BusinessDayConvention.MODIFIED_FOLLOWING,
+        rule=DateGeneration.BACKWARD,
+        end_of_month=False,
+    ):
+        super().__init__(
+            _new(
+                _lib.ql_schedule_new,
+                _ordinal(effective_date),
+                _ordinal(termination_date),
+                tenor_length,
+                tenor_unit,
+                calendar,
+                convention,
+                termination_date_convention,
+                rule,
+                end_of_month,
+            )
+        )
+
+    def __len__(self):
+        return _call(_lib.ql_schedule_size, c_size_t, self._pointer).value
+
+    def __getitem__(self, i):
+        if not 0 <= i < len(self):
+            raise IndexError("schedule date out of range")
+        return _date(_call(_lib.ql_schedule_date, c_int32, self._pointer, i).value)
+
+    @property
+    def dates(self):
+        return [self[i] for i in range(len(self))]
+
+    def is_regular(self, i):
+        """Whether the `i`-th period spans a full tenor."""
+        return _call(_lib.ql_schedule_is_regular, c_bool, self._pointer, i).value
+
+
+class FixedRateBond(_Handle):
+    """A bond paying `coupon` on `schedule`, `frequency` coupons a year,
+    and `face_amount` at maturity."""
+
+    _free = "ql_bond_free"
+
+    def __init__(
+        self,
+        schedule,
+        coupon,
+        day_counter=DayCounter.THIRTY_360,
+        frequency=2,
+        face_amount=100.0,
+    ):
+        super().__init__(
+            _new(
+                _lib.ql_bond_new,
+                schedule._pointer,
+                coupon,
+                day_counter,
+                frequency,
+                face_amount,
+            )
+        )
+        self.schedule = schedule
+
+    def clean_price(self, curve, settlement_date):
+        return _float(_lib.ql_bond_clean_price, self._pointer, curve._pointer, _ordinal(settlement_date))
+
+    def dirty_price(self, curve, settlement_date):
+        return _float(_lib.ql_bond_dirty_price, self._pointer, curve._pointer, _ordinal(settlement_date))
+
+    def accrued_amount(self, settlement_date):
+        return _float(_lib.ql_bond_accrued_amount, self._pointer, _ordinal(settlement_date))
+
+    def clean_price_from_yield(self, yield_rate, compounding, settlement_date):
+        return _float(
+            _lib.ql_bond_clean_price_from_yield,
+            self._pointer,
+            yield_rate,
+            compounding,
+            _ordinal(settlement_date),
+        )
+
+    def yield_to_maturity(self, clean_price, compounding, settlement_date):
+        return _float(
+            _lib.ql_bond_yield,
+            self._pointer,
+            clean_price,
+            compounding,
+            _ordinal(settlement_date),
+        )
+
+    def duration(self, yield_rate, compounding, settlement_date, modified=True):
+        """The modified duration, or the Macaulay one unless `modified`."""
+        return _float(
+            _lib.ql_bond_duration,
+            self._pointer,
+            yield_rate,
+            compounding,
+            modified,
+            _ordinal(settlement_date),
+        )
+
+
+class VanillaSwap(_Handle):
+    """A swap of `fixed_rate` on `fixed_schedule` against an Actual/360
+    Ibor index of `index_tenor_months` plus `spread`, projected off
+    `forwarding_curve`."""
+
+    _free = "ql_swap_free"
+
+    def __init__(
+        self,
+        swap_type,
+        notional,
+        fixed_schedule,
+        fixed_rate,
+        floating_schedule,
+        forwarding_curve,
+        fixed_day_counter=DayCounter.THIRTY_360,
+        index_tenor_months=6,
+        fixing_days=2,
+        calendar=Calendar.WEEKENDS_ONLY,
+        spread=0.0,
+    ):
+        super().__init__(
+            _new(
+                _lib.ql_swap_new,
+                swap_type,
+                notional,
+                fixed_schedule._pointer,
+                fixed_rate,
+                fixed_day_counter,
+                floating_schedule._pointer,
+                index_tenor_months,
+                fixing_days,
+                calendar,
+                forwarding_curve._pointer,
+                spread,
+            )
+        )
+        self.forwarding_curve = forwarding_curve
+
+    def npv(self, discount_curve, settlement_date):
+        return _float(_lib.ql_swap_npv, self._pointer, discount_curve._pointer, _ordinal(settlement_date))
+
+    def fair_rate(self, discount_curve, settlement_date):
+        return _float(
+            _lib.ql_swap_fair_rate, self._pointer, discount_curve._pointer, _ordinal(settlement_date)
+        )
+
+    def fair_spread(self, discount_curve, settlement_date):
+        return _float(
+            _lib.ql_swap_fair_spread, self._pointer, discount_curve._pointer, _ordinal(settlement_date)
+        )
+
+
+class VanillaOption(_Handle):
+    """A call or put on a spot price, exercised on `exercise_date` or, on
+    trees, at any time up to it if American."""
+
+    _free = "ql_option_free"
+    GREEKS = ("npv", "delta", "gamma", "vega", "theta", "rho", "dividend_rho")
+
+    def __init__(self, option_type, strike, exercise_date):
+        super().__init__(_new(_lib.ql_option_new, option_type, strike, _ordinal(exercise_date)))
+
+    def npv(self, spot, risk_free, dividend, volatility):
+        """The Black-Scholes-Merton value at a flat `volatility`."""
+        return _float(
+            _lib.ql_option_npv,
+            self._pointer,
+            spot,
+            risk_free._pointer,
+            dividend._pointer,
+            volatility,
+        )
+
+    def greeks(self, spot, risk_free, dividend, volatility):
+        """The value and sensitivities, keyed by the names in `GREEKS`."""
+        values = _call(
+            _lib.ql_option_greeks,
+            c_double * 7,
+            self._pointer,
+            spot,
+            risk_free._pointer,
+            dividend._pointer,
+            volatility,
+        )
+        return dict(zip(self.GREEKS, values))
+
+    def binomial_npv(self, spot, risk_free, dividend, volatility, steps=500, american=False):
+        """The value on a Cox-Ross-Rubinstein tree of `steps`."""
+        return _float(
+            _lib.ql_option_binomial_npv,
+            self._pointer,
+            american,
+            spot,
+            risk_free._pointer,
+            dividend._pointer,
+            volatility,
+            steps,
+        )
@@ -0,0 +1,116 @@
+import datetime
+import math
+import unittest
+
+import quantlib as ql
+
+TODAY = datetime.date(2020, 1, 15)
+
+
+class YieldCurveTest(unittest.TestCase):
+    def test_flat_curve(self):
+        curve = ql.YieldCurve.flat(TODAY, 0.03)
+        one_year = datetime.date(2021, 1, 14)
+        self.assertEqual(curve.reference_date, TODAY)
+        self.assertAlmostEqual(curve.discount(one_year), math.exp(-0.03), places=12)
+        self.assertAlmostEqual(curve.zero_rate(one_year), 0.03, places=12)
+        self.assertAlmostEqual(
+            curve.forward_rate(one_year, datetime.date(2022, 1, 14)), 0.03, places=12
+        )
+
+    def test_bootstrap_reprices_its_swaps(self):
+        curve = ql.YieldCurve.bootstrap(
+            TODAY,
+            deposits=[(datetime.date(2020, 7, 15), 0.02)],
+            swaps=[(datetime.date(2022, 1, 17), 0.025), (datetime.date(2025, 1, 15), 0.03)],
+        )
+        fixed = ql.Schedule(TODAY, datetime.date(2025, 1, 15), 12)
+        floating = ql.Schedule(TODAY, datetime.date(2025, 1, 15), 6)
+        swap = ql.VanillaSwap(ql.SwapType.PAYER, 1e6, fixed, 0.03, floating, curve)
+        self.assertGreater(curve.discount(datetime.date(2025, 1, 15)), 0.8)
+        self.assertAlmostEqual(swap.fair_rate(curve, TODAY), 0.03, delta=1e-5)
+
+    def test_errors_raise(self):
+        with self.assertRaisesRegex(ql.QuantLibError, "unknown calendar code 9"):
+            ql.YieldCurve.flat(TODAY, 0.03, calendar=9)
+        curve = ql.YieldCurve.flat(TODAY, 0.03)
+        with self.assertRaisesRegex(ql.QuantLibError, "not after the reference date"):
+            curve.zero_rate(TODAY)
+
+
+class ScheduleTest(unittest.TestCase):
+    def test_dates(self):
+        schedule = ql.Schedule(TODAY, datetime.date(2021, 3, 15), 6)
+        self.assertEqual(len(schedule), 4)
+        self.assertEqual(schedule[0], datetime.date(2020, 1, 15))
+        self.assertEqual(schedule.dates[-1], datetime.date(2021, 3, 15))
+        self.assertFalse(schedule.is_regular(0))
+        self.assertTrue(schedule.is_regular(2))
+        with self.assertRaises(IndexError):
+            schedule[4]
+
+
+class FixedRateBondTest(unittest.TestCase):
+    def test_yield_round_trip(self):
+        schedule = ql.Schedule(TODAY, datetime.date(2025, 1, 15), 6)
+        bond = ql.FixedRateBond(schedule, 0.04)
+        curve = ql.YieldCurve.flat(TODAY, 0.03)
+        settlement = datetime.date(2020, 3, 2)
+        clean = bond.clean_price(curve, settlement)
+        self.assertAlmostEqual(
+            bond.dirty_price(curve, settlement) - clean, bond.accrued_amount(settlement)
+        )
+        y = bond.yield_to_maturity(clean, ql.Compounding.COMPOUNDED, settlement)
+        self.assertAlmostEqual(
+            bond.clean_price_from_yield(y, ql.Compounding.COMPOUNDED, settlement), clean, places=6
+        )
+        modified = bond.duration(y, ql.Compounding.COMPOUNDED, settlement)
+        macaulay = bond.duration(y, ql.Compounding.COMPOUNDED, settlement, modified=False)
+        self.assertAlmostEqual(macaulay / modified, 1.0 + y / 2.0, places=8)
+
+
+class VanillaSwapTest(unittest.TestCase):
+    def test_fair_rate_swap_is_worth_zero(self):
+        curve = ql.YieldCurve.flat(TODAY, 0.03)
+        fixed = ql.Schedule(TODAY, datetime.date(2025, 1, 15), 12)
+        floating = ql.Schedule(TODAY, datetime.date(2025, 1, 15), 6)
+        swap = ql.VanillaSwap(ql.SwapType.PAYER, 1e6, fixed, 0.02, floating, curve)
+        self.assertGreater(swap.npv(curve, TODAY), 0.0)
+        fair = ql.VanillaSwap(
+            ql.SwapType.PAYER, 1e6, fixed, swap.fair_rate(curve, TODAY), floating, curve
+        )
+        self.assertAlmostEqual(fair.npv(curve, TODAY), 0.0, places=4)
+        self.assertAlmostEqual(fair.fair_spread(curve, TODAY), 0.0, places=8)
+
+
+class VanillaOptionTest(unittest.TestCase):
+    def setUp(self):
+        self.risk_free = ql.YieldCurve.flat(TODAY, 0.05)
+        self.dividend = ql.YieldCurve.flat(TODAY, 0.0)
+        self.expiry = datetime.date(2021, 1, 14)
+
+    def test_put_call_parity(self):
+        call = ql.VanillaOption(ql.OptionType.CALL, 100.0, self.expiry)
+        put = ql.VanillaOption(ql.OptionType.PUT, 100.0, self.expiry)
+        market = (100.0, self.risk_free, self.dividend, 0.2)
+        forward = 100.0 - 100.0 * self.risk_free.discount(self.expiry)
+        self.assertAlmostEqual(call.npv(*market) - put.npv(*market), forward, places=10)
+        greeks = call.greeks(*market)
+        self.assertEqual(tuple(greeks), ql.VanillaOption.GREEKS)
+        self.assertAlmostEqual(greeks["npv"], call.npv(*market))
+        self.assertGreater(greeks["delta"], 0.5)
+
+    def test_trees(self):
+        put = ql.VanillaOption(ql.OptionType.PUT, 100.0, self.expiry)
+        market = (100.0, self.risk_free, self.dividend, 0.2)
+        european = put.binomial_npv(*market)
+        self.assertAlmostEqual(european, put.npv(*market), places=2)
+        self.assertGreater(put.binomial_npv(*market, american=True), european)
+        with self.assertRaisesRegex(ql.QuantLibError, "negative volatility"):
+            put.npv(100.0, self.risk_free, self.dividend, -0.2)
+        with self.assertRaisesRegex(ql.QuantLibError, "no time steps"):
+            put.binomial_npv(*market, steps=0)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,210 @@
+//! Analytics of a leg of cash flows, off a discount curve or at a flat
+//! yield, shared by the instruments paying them. Legs mixing flow types
+//! can be passed as `&[Box<dyn CashFlow>]`.
+
+use super::traits::CashFlow;
+use crate::definitions::Rate;
+use crate::math::{Brent, Solver1D};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::{Compounding, InterestRate};
+use crate::time::{Date, DayCounter, Frequency};
+
+pub use super::cashflows::{bps, npv};
+
+const BASIS_POINT: f64 = 1.0e-4;
+
+/// Duration measure of a leg at a given yield.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Duration {
+    /// Present value weighted average time of the payments.
+    Macaulay,
+    /// Relative change of the present value for a change in yield.
+    Modified,
+}
+
+/// The flows of `leg` still to be paid at `settlement_date`.
+fn pending<CF: CashFlow>(
+    leg: &[CF],
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> impl Iterator<Item = &CF> {
+    leg.iter()
+        .filter(move |c| !CashFlow::has_occured(*c, settlement_date, include_settlement_date_flows))
+}
+
+/// Interest accrued at `settlement_date` on the coupons of `leg`.
+pub fn accrued_amount<CF: CashFlow>(leg: &[CF], settlement_date: Date) -> f64 {
+    leg.iter()
+        .filter_map(|c| c.try_as_coup())
+        .map(|c| c.accrued_amount(settlement_date))
+        .sum()
+}
+
+/// The coupon rate giving the coupons of `leg` paid after
+/// `settlement_date` the present value `target_npv`, by default their
+/// present value at their own rates.
+pub fn atm_rate<CF: CashFlow, Y: YieldTermStructure>(
+    leg: &[CF],
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    target_npv: Option<f64>,
+) -> Rate {
+    let bps = bps(
+        leg,
+        discount_curve,
+        include_settlement_date_flows,
+        settlement_date,
+    );
+    assert!(bps != 0.0, "leg has no coupons left");
+    let target = target_npv.unwrap_or_else(|| {
+        pending(leg, include_settlement_date_flows, settlement_date)
+            .filter(|c| c.try_as_coup().is_some())
+            .map(|c| c.amount() * discount_curve.discount(c.date(), true))
+            .sum()
+    });
+    target / bps * BASIS_POINT
+}
+
+/// Pending flows as (time from settlement on the yield day counter,
+/// amount) pairs.
+fn timed_flows<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    y: &InterestRate<DC>,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> Vec<(f64, f64)> {
+    pending(leg, include_settlement_date_flows, settlement_date)
+        .map(|c| {
+            let t = y
+                .day_counter
+                .year_fraction(settlement_date, c.date(), None, None);
+            (t, c.amount())
+        })
+        .collect()
+}
+
+/// Present value at `settlement_date` of the flows of `leg` paid after it,
+/// discounted at the flat yield `y`.
+pub fn npv_at_yield<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    y: &InterestRate<DC>,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    timed_flows(leg, y, include_settlement_date_flows, settlement_date)
+        .iter()
+        .map(|(t, a)| a * y.discount_factor_with_time(*t))
+        .sum()
+}
+
+/// Change in the present value at the flat yield `y` of the coupons of
+/// `leg` for a one basis point change in their rate.
+pub fn bps_at_yield<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    y: &InterestRate<DC>,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    pending(leg, include_settlement_date_flows, settlement_date)
+        .filter_map(|c| c.try_as_coup())
+        .map(|c| {
+            let t = y
+                .day_counter
+                .year_fraction(settlement_date, c.date(), None, None);
+            c.nominal() * c.accrual_period() * y.discount_factor_with_time(t)
+        })
+        .sum::<f64>()
+        * BASIS_POINT
+}
+
+/// The flat yield at which the flows of `leg` paid after
+/// `settlement_date` are worth `npv` there, its internal rate of return,
+/// by Brent's method from a bracket found around 5%.
+pub fn yield_rate<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    npv: f64,
+    day_counter: DC,
+    compounding: Compounding,
+    frequency: Frequency,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    accuracy: f64,
+) -> Rate {
+    assert!(
+        pending(leg, include_settlement_date_flows, settlement_date).count() > 0,
+        "no flows left"
+    );
+    Brent::new(accuracy).solve_from_guess(
+        |r| {
+            let y = InterestRate::new(r, day_counter, compounding, frequency);
+            npv_at_yield(leg, &y, include_settlement_date_flows, settlement_date) - npv
+        },
+        0.05,
+        0.01,
+    )
+}
+
+/// First and second derivatives in the rate of the discount factor at
+/// `y` over `t`, relative to the discount factor itself.
+fn discount_derivatives<DC: DayCounter>(y: &InterestRate<DC>, t: f64) -> (f64, f64) {
+    let (r, n) = (y.rate, y.freq);
+    let simple = (
+        -t / (1.0 + r * t),
+        2.0 * t * t / ((1.0 + r * t) * (1.0 + r * t)),
+    );
+    let compounded = {
+        let base = 1.0 + r / n;
+        (-t / base, t * (t + 1.0 / n) / (base * base))
+    };
+    match y.compounding {
+        Compounding::Simple => simple,
+        Compounding::Compounded => compounded,
+        Compounding::Continuous => (-t, t * t),
+        Compounding::SimpleThenCompounded if t < 1.0 / n => simple,
+        Compounding::SimpleThenCompounded => compounded,
+        Compounding::CompoundedThenSimple if t > 1.0 / n => simple,
+        Compounding::CompoundedThenSimple => compounded,
+    }
+}
+
+/// Duration in years of the flows of `leg` paid after `settlement_date`
+/// at the flat yield `y`.
+pub fn duration<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    y: &InterestRate<DC>,
+    duration: Duration,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    let (price, weighted) = timed_flows(leg, y, include_settlement_date_flows, settlement_date)
+        .iter()
+        .fold((0.0, 0.0), |(p, w), (t, a)| {
+            let pv = a * y.discount_factor_with_time(*t);
+            let weight = match duration {
+                Duration::Macaulay => *t,
+                Duration::Modified => -discount_derivatives(y, *t).0,
+            };
+            (p + pv, w + weight * pv)
+        });
+    assert!(price != 0.0, "no flows left");
+    weighted / price
+}
+
+/// Second derivative in the yield of the present value of the flows of
+/// `leg` paid after `settlement_date`, relative to the present value.
+pub fn convexity<CF: CashFlow, DC: DayCounter>(
+    leg: &[CF],
+    y: &InterestRate<DC>,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    let (price, second) = timed_flows(leg, y, include_settlement_date_flows, settlement_date)
+        .iter()
+        .fold((0.0, 0.0), |(p, s), (t, a)| {
+            let pv = a * y.discount_factor_with_time(*t);
+            (p + pv, s + discount_derivatives(y, *t).1 * pv)
+        });
+    assert!(price != 0.0, "no flows left");
+    second / price
+}
@@ -1,3 +1,244 @@
-pub struct AverageBMACoupon {
-    pub cool: usize,
+use super::traits::{CashFlow, Coupon, Event};
+use super::FloatingRateCoupon;
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::{check_fixings, BmaIndex, Index, InterestRateIndex};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Date, DayCounter, Schedule};
+use std::rc::Rc;
+
+/// Coupon paying the average of the weekly BMA resets in effect over its
+/// accrual period, weighted by the calendar days each is in effect, times
+/// a gearing plus a spread.
+pub struct AverageBmaCoupon<C: Cal, Y: YieldTermStructure, IDC: DayCounter, DC: DayCounter> {
+    pub base: Base<DC>,
+    pub index: Rc<BmaIndex<C, Y, IDC>>,
+    pub fixing_dates: Vec<Date>,
+    pub gearing: f64,
+    pub spread: f64,
+}
+
+impl<C, Y, IDC, DC> AverageBmaCoupon<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    pub fn new(
+        base: Base<DC>,
+        index: Rc<BmaIndex<C, Y, IDC>>,
+        gearing: f64,
+        spread: f64,
+    ) -> AverageBmaCoupon<C, Y, IDC, DC> {
+        let fixing_dates = index.fixing_dates(base.accrual_start_date, base.accrual_end_date);
+        AverageBmaCoupon {
+            base,
+            index,
+            fixing_dates,
+            gearing,
+            spread,
+        }
+    }
+
+    /// The average index rate before gearing and spread.
+    pub fn average_fixing(&self) -> Rate {
+        let (start, end) = (self.base.accrual_start_date, self.base.accrual_end_date);
+        let mut total = 0.0;
+        for (i, &fixing_date) in self.fixing_dates.iter().enumerate() {
+            let from = self.index.value_date(fixing_date).max(start);
+            let to = match self.fixing_dates.get(i + 1) {
+                Some(&next) => self.index.value_date(next).min(end),
+                None => end,
+            };
+            if to > from {
+                total += self.index.fixing(fixing_date) * to.sub(from) as f64;
+            }
+        }
+        total / end.sub(start) as f64
+    }
+}
+
+impl<C, Y, IDC, DC> Event for AverageBmaCoupon<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<C, Y, IDC, DC> CashFlow for AverageBmaCoupon<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    fn amount(&self) -> f64 {
+        self.base.nominal * self.rate() * self.accrual_period()
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+impl<C, Y, IDC, DC> Coupon for AverageBmaCoupon<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            Some(self.base.reference_period_start),
+            Some(self.base.reference_period_end),
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> f64 {
+        self.gearing * self.average_fixing() + self.spread
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        self.base.nominal
+            * self.rate()
+            * self
+                .base
+                .day_counter
+                .year_fraction(self.base.accrual_start_date, end, None, None)
+    }
+}
+
+impl<C, Y, IDC, DC> FloatingRateCoupon for AverageBmaCoupon<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    fn fixing_dates(&self) -> Vec<Date> {
+        self.fixing_dates.clone()
+    }
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>> {
+        check_fixings(self.index.as_ref(), &self.fixing_dates)
+    }
+}
+
+/// Builder of a leg of average BMA coupons paid at the end of each
+/// schedule period.
+pub struct AverageBmaLeg<C: Cal, Y: YieldTermStructure, IDC: DayCounter, DC: DayCounter> {
+    pub schedule: Schedule,
+    pub index: Rc<BmaIndex<C, Y, IDC>>,
+    pub day_counter: DC,
+    pub notional: f64,
+    pub gearing: f64,
+    pub spread: f64,
+}
+
+impl<C, Y, IDC, DC> AverageBmaLeg<C, Y, IDC, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    IDC: DayCounter,
+    DC: DayCounter,
+{
+    pub fn new(
+        schedule: Schedule,
+        index: Rc<BmaIndex<C, Y, IDC>>,
+        day_counter: DC,
+    ) -> AverageBmaLeg<C, Y, IDC, DC> {
+        AverageBmaLeg {
+            schedule,
+            index,
+            day_counter,
+            notional: 1.0,
+            gearing: 1.0,
+            spread: 0.0,
+        }
+    }
+    pub fn with_notional(mut self, notional: f64) -> AverageBmaLeg<C, Y, IDC, DC> {
+        self.notional = notional;
+        self
+    }
+    pub fn with_gearing(mut self, gearing: f64) -> AverageBmaLeg<C, Y, IDC, DC> {
+        self.gearing = gearing;
+        self
+    }
+    pub fn with_spread(mut self, spread: f64) -> AverageBmaLeg<C, Y, IDC, DC> {
+        self.spread = spread;
+        self
+    }
+
+    pub fn build(&self) -> Leg<AverageBmaCoupon<C, Y, IDC, DC>> {
+        self.schedule
+            .dates
+            .windows(2)
+            .map(|w| {
+                let base = Base {
+                    nominal: self.notional,
+                    day_counter: self.day_counter,
+                    payment_date: w[1],
+                    accrual_start_date: w[0],
+                    accrual_end_date: w[1],
+                    reference_period_start: w[0],
+                    reference_period_end: w[1],
+                };
+                AverageBmaCoupon::new(base, Rc::clone(&self.index), self.gearing, self.spread)
+            })
+            .collect()
+    }
 }
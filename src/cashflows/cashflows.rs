@@ -1,10 +1,10 @@
 use super::traits::CashFlow;
-use super::Leg;
 
+use crate::termstructures::traits::YieldTermStructure;
 use crate::time::date as df;
 use crate::time::Date;
 
-pub fn start_date<CF: CashFlow>(leg: &Leg<CF>) -> Date {
+pub fn start_date<CF: CashFlow>(leg: &[CF]) -> Date {
     assert!(!leg.is_empty());
     //
     let mut d = df::MAX_DATE;
@@ -22,14 +22,14 @@ pub fn start_date<CF: CashFlow>(leg: &Leg<CF>) -> Date {
     d
 }
 
-pub fn maturity_date<CF: CashFlow>(leg: &Leg<CF>) -> Date {
+pub fn maturity_date<CF: CashFlow>(leg: &[CF]) -> Date {
     assert!(!leg.is_empty());
     //
     let mut d = df::MIN_DATE;
     for c in leg {
         match c.try_as_coup() {
             Some(coup) => {
-                d = df::max(d, coup.accrual_start_date());
+                d = df::max(d, coup.accrual_end_date());
             }
             None => {
                 d = df::max(d, c.date());
@@ -39,3 +39,68 @@ pub fn maturity_date<CF: CashFlow>(leg: &Leg<CF>) -> Date {
     //
     d
 }
+
+/// Present value of the flows of `leg` paid after `settlement_date`,
+/// discounted to the reference date of `discount_curve`.
+pub fn npv<CF: CashFlow, Y: YieldTermStructure>(
+    leg: &[CF],
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    leg.iter()
+        .filter(|c| !CashFlow::has_occured(*c, settlement_date, include_settlement_date_flows))
+        .map(|c| c.amount() * discount_curve.discount(c.date(), true))
+        .sum()
+}
+
+/// Change in the present value of the coupons of `leg` paid after
+/// `settlement_date` for a one basis point change in their rate.
+pub fn bps<CF: CashFlow, Y: YieldTermStructure>(
+    leg: &[CF],
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> f64 {
+    const BASIS_POINT: f64 = 1.0e-4;
+    leg.iter()
+        .filter(|c| !CashFlow::has_occured(*c, settlement_date, include_settlement_date_flows))
+        .filter_map(|c| c.try_as_coup())
+        .map(|c| c.nominal() * c.accrual_period() * discount_curve.discount(c.date(), true))
+        .sum::<f64>()
+        * BASIS_POINT
+}
+
+/// Present value of the flows of `leg` paid after `settlement_date`,
+/// expressed as of `npv_date` rather than the curve reference date, e.g.
+/// the spot date swaps and bonds are quoted for.
+pub fn npv_at<CF: CashFlow, Y: YieldTermStructure>(
+    leg: &[CF],
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    npv_date: Date,
+) -> f64 {
+    npv(
+        leg,
+        discount_curve,
+        include_settlement_date_flows,
+        settlement_date,
+    ) / discount_curve.discount(npv_date, true)
+}
+
+/// Basis point sensitivity of `leg` expressed as of `npv_date`.
+pub fn bps_at<CF: CashFlow, Y: YieldTermStructure>(
+    leg: &[CF],
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    npv_date: Date,
+) -> f64 {
+    bps(
+        leg,
+        discount_curve,
+        include_settlement_date_flows,
+        settlement_date,
+    ) / discount_curve.discount(npv_date, true)
+}
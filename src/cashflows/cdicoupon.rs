@@ -0,0 +1,208 @@
+use super::traits::{CashFlow, Coupon, Event};
+use super::FloatingRateCoupon;
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::{check_fixings, InterestRateIndex};
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Business252, Date, DayCounter, Schedule};
+use std::rc::Rc;
+
+/// Coupon accruing a percentage of the CDI rate compounded over business
+/// days, e.g. 110% CDI, plus a spread compounded exponentially on top.
+///
+/// Each business day accrues `((1 + cdi)^(1/252) - 1) gearing + 1`; the
+/// coupon rate is the equivalent annual exponential business/252 rate.
+pub struct CdiCoupon<I: InterestRateIndex, C: Cal> {
+    pub base: Base<Business252<C>>,
+    pub index: Rc<I>,
+    pub gearing: f64,
+    pub spread: f64,
+}
+
+impl<I: InterestRateIndex, C: Cal> CdiCoupon<I, C> {
+    pub fn new(
+        base: Base<Business252<C>>,
+        index: Rc<I>,
+        gearing: f64,
+        spread: f64,
+    ) -> CdiCoupon<I, C> {
+        CdiCoupon {
+            base,
+            index,
+            gearing,
+            spread,
+        }
+    }
+
+    /// The compound factor accrued from the accrual start to `date`.
+    pub fn compound_factor_until(&self, date: Date) -> f64 {
+        let dc = self.base.day_counter;
+        let mut compound = 1.0;
+        let mut d = self.base.accrual_start_date;
+        while d < date {
+            let next = self.index.maturity_date(d);
+            let next = if next < date { next } else { date };
+            let cdi = self.index.fixing(self.index.fixing_date(d));
+            let t = dc.year_fraction(d, next, None, None);
+            compound *= ((1.0 + cdi).powf(t) - 1.0) * self.gearing + 1.0;
+            d = next;
+        }
+        let t = dc.year_fraction(self.base.accrual_start_date, date, None, None);
+        compound * (1.0 + self.spread).powf(t)
+    }
+
+    pub fn compound_factor(&self) -> f64 {
+        self.compound_factor_until(self.base.accrual_end_date)
+    }
+}
+
+impl<I: InterestRateIndex, C: Cal> Event for CdiCoupon<I, C> {
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<I: InterestRateIndex, C: Cal> CashFlow for CdiCoupon<I, C> {
+    fn amount(&self) -> f64 {
+        self.base.nominal * (self.compound_factor() - 1.0)
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+impl<I: InterestRateIndex, C: Cal> Coupon for CdiCoupon<I, C> {
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            None,
+            None,
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> Rate {
+        self.compound_factor().powf(1.0 / self.accrual_period()) - 1.0
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        self.base.nominal * (self.compound_factor_until(end) - 1.0)
+    }
+}
+
+impl<I: InterestRateIndex, C: Cal> FloatingRateCoupon for CdiCoupon<I, C> {
+    fn fixing_dates(&self) -> Vec<Date> {
+        let mut dates = vec![];
+        let mut d = self.base.accrual_start_date;
+        while d < self.base.accrual_end_date {
+            dates.push(self.index.fixing_date(d));
+            d = self.index.maturity_date(d);
+        }
+        dates
+    }
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>> {
+        check_fixings(self.index.as_ref(), &self.fixing_dates())
+    }
+}
+
+/// Builder of a leg of CDI coupons paid at the end of each schedule
+/// period.
+pub struct CdiLeg<I: InterestRateIndex, C: Cal> {
+    pub schedule: Schedule,
+    pub index: Rc<I>,
+    pub day_counter: Business252<C>,
+    pub notional: f64,
+    pub gearing: f64,
+    pub spread: f64,
+}
+
+impl<I: InterestRateIndex, C: Cal> CdiLeg<I, C> {
+    pub fn new(schedule: Schedule, index: Rc<I>, day_counter: Business252<C>) -> CdiLeg<I, C> {
+        CdiLeg {
+            schedule,
+            index,
+            day_counter,
+            notional: 1.0,
+            gearing: 1.0,
+            spread: 0.0,
+        }
+    }
+    pub fn with_notional(mut self, notional: f64) -> CdiLeg<I, C> {
+        self.notional = notional;
+        self
+    }
+    /// The percentage of CDI accrued, e.g. 1.1 for 110% CDI.
+    pub fn with_gearing(mut self, gearing: f64) -> CdiLeg<I, C> {
+        self.gearing = gearing;
+        self
+    }
+    pub fn with_spread(mut self, spread: f64) -> CdiLeg<I, C> {
+        self.spread = spread;
+        self
+    }
+
+    pub fn build(&self) -> Leg<CdiCoupon<I, C>> {
+        self.schedule
+            .dates
+            .windows(2)
+            .map(|w| {
+                let base = Base {
+                    nominal: self.notional,
+                    day_counter: self.day_counter,
+                    payment_date: w[1],
+                    accrual_start_date: w[0],
+                    accrual_end_date: w[1],
+                    reference_period_start: w[0],
+                    reference_period_end: w[1],
+                };
+                CdiCoupon::new(base, Rc::clone(&self.index), self.gearing, self.spread)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,235 @@
+use super::couponpricer::{CmsCouponPricer, CmsFixing, CouponPricerRegistry, PricedCoupon};
+use super::traits::{CashFlow, Coupon, Event};
+use super::FloatingRateCoupon;
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::{check_fixings, InterestRateIndex};
+use crate::time::{Date, DayCounter, Schedule};
+use std::rc::Rc;
+
+/// Coupon paying a swap rate fixing, e.g. the 10Y EUR swap rate, times a
+/// gearing plus a spread.
+///
+/// The rate is the forward swap rate as forecast by the index, with no
+/// convexity adjustment for payment at the end of the accrual period,
+/// unless `pricer` makes one.
+pub struct CmsCoupon<I: InterestRateIndex, DC: DayCounter> {
+    pub base: Base<DC>,
+    pub index: Rc<I>,
+    pub fixing_date: Date,
+    pub gearing: f64,
+    pub spread: f64,
+    pub pricer: Option<Rc<dyn CmsCouponPricer>>,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> CmsCoupon<I, DC> {
+    pub fn new(base: Base<DC>, index: Rc<I>, gearing: f64, spread: f64) -> CmsCoupon<I, DC> {
+        let fixing_date = index.fixing_date(base.accrual_start_date);
+        CmsCoupon {
+            base,
+            index,
+            fixing_date,
+            gearing,
+            spread,
+            pricer: None,
+        }
+    }
+
+    pub fn with_pricer(mut self, pricer: Rc<dyn CmsCouponPricer>) -> CmsCoupon<I, DC> {
+        self.pricer = Some(pricer);
+        self
+    }
+
+    /// The swap rate fixing before gearing and spread.
+    pub fn index_fixing(&self) -> Rate {
+        self.index.fixing(self.fixing_date)
+    }
+
+    /// The fixing paid, as adjusted by the pricer.
+    pub fn adjusted_fixing(&self) -> Rate {
+        let fixing = self.index_fixing();
+        match &self.pricer {
+            Some(p) => p.adjusted_fixing(&CmsFixing {
+                fixing_date: self.fixing_date,
+                forward: fixing,
+            }),
+            None => fixing,
+        }
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> PricedCoupon for CmsCoupon<I, DC> {
+    fn set_pricer(&mut self, registry: &CouponPricerRegistry) {
+        if let Some(p) = &registry.cms {
+            self.pricer = Some(Rc::clone(p));
+        }
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Event for CmsCoupon<I, DC> {
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> CashFlow for CmsCoupon<I, DC> {
+    fn amount(&self) -> f64 {
+        self.base.nominal * self.rate() * self.accrual_period()
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Coupon for CmsCoupon<I, DC> {
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            Some(self.base.reference_period_start),
+            Some(self.base.reference_period_end),
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> f64 {
+        self.gearing * self.adjusted_fixing() + self.spread
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        self.base.nominal
+            * self.rate()
+            * self.base.day_counter.year_fraction(
+                self.base.accrual_start_date,
+                end,
+                Some(self.base.reference_period_start),
+                Some(self.base.reference_period_end),
+            )
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> FloatingRateCoupon for CmsCoupon<I, DC> {
+    fn fixing_dates(&self) -> Vec<Date> {
+        vec![self.fixing_date]
+    }
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>> {
+        check_fixings(self.index.as_ref(), &self.fixing_dates())
+    }
+}
+
+/// Builder of a leg of CMS coupons paid at the end of each schedule period.
+pub struct CmsLeg<I: InterestRateIndex, DC: DayCounter> {
+    pub schedule: Schedule,
+    pub index: Rc<I>,
+    pub day_counter: DC,
+    pub notional: f64,
+    pub gearing: f64,
+    pub spread: f64,
+    pub pricer: Option<Rc<dyn CmsCouponPricer>>,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> CmsLeg<I, DC> {
+    pub fn new(schedule: Schedule, index: Rc<I>, day_counter: DC) -> CmsLeg<I, DC> {
+        CmsLeg {
+            schedule,
+            index,
+            day_counter,
+            notional: 1.0,
+            gearing: 1.0,
+            spread: 0.0,
+            pricer: None,
+        }
+    }
+    pub fn with_notional(mut self, notional: f64) -> CmsLeg<I, DC> {
+        self.notional = notional;
+        self
+    }
+    pub fn with_gearing(mut self, gearing: f64) -> CmsLeg<I, DC> {
+        self.gearing = gearing;
+        self
+    }
+    pub fn with_spread(mut self, spread: f64) -> CmsLeg<I, DC> {
+        self.spread = spread;
+        self
+    }
+    pub fn with_pricer(mut self, pricer: Rc<dyn CmsCouponPricer>) -> CmsLeg<I, DC> {
+        self.pricer = Some(pricer);
+        self
+    }
+    /// Takes the registry's pricer unless the leg has its own.
+    pub fn with_pricers(mut self, registry: &CouponPricerRegistry) -> CmsLeg<I, DC> {
+        if self.pricer.is_none() {
+            self.pricer = registry.cms.clone();
+        }
+        self
+    }
+
+    pub fn build(&self) -> Leg<CmsCoupon<I, DC>> {
+        self.schedule
+            .dates
+            .windows(2)
+            .map(|w| {
+                let base = Base {
+                    nominal: self.notional,
+                    day_counter: self.day_counter,
+                    payment_date: w[1],
+                    accrual_start_date: w[0],
+                    accrual_end_date: w[1],
+                    reference_period_start: w[0],
+                    reference_period_end: w[1],
+                };
+                let coupon =
+                    CmsCoupon::new(base, Rc::clone(&self.index), self.gearing, self.spread);
+                match &self.pricer {
+                    Some(p) => coupon.with_pricer(Rc::clone(p)),
+                    None => coupon,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,301 @@
+//! Pricers of Ibor and CMS coupons, modelling the rate paid beyond the
+//! forward fixing. A coupon set in arrears fixes at the end of its period
+//! and is paid then, earlier than the index deposit would, which is worth
+//! a convexity adjustment under the caplet volatilities. A quanto coupon
+//! pays the fixing in another currency than the index's, which shifts its
+//! expectation by the covariance of the rate with the exchange rate.
+
+use crate::definitions::{Rate, Time};
+use crate::instruments::OptionType;
+use crate::math::simpson;
+use crate::pricingengines::black_formula;
+use crate::quotes::{Quote, SimpleQuote};
+use crate::termstructures::traits::BlackVolTermStructure;
+use crate::time::{Actual365Fixed, Date, DayCounter};
+use std::rc::Rc;
+
+/// Standard deviations above the forward the replication integrates up
+/// to.
+const REPLICATION_STD_DEVS: f64 = 8.0;
+
+/// The Ibor fixing of a coupon, as given to its pricer.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IborFixing {
+    pub fixing_date: Date,
+    /// The index forward, or the fixing once known.
+    pub forward: Rate,
+    /// Accrual period of the index deposit fixed.
+    pub index_accrual: Time,
+    pub in_arrears: bool,
+}
+
+/// Model of the rate an Ibor coupon pays for its index fixing.
+pub trait IborCouponPricer {
+    fn adjusted_fixing(&self, fixing: &IborFixing) -> Rate;
+}
+
+/// How the in-arrears adjustment is taken.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InArrearsMethod {
+    /// The Black-76 adjustment `F^2 v tau / (1 + F tau)` for the caplet
+    /// variance `v` at the forward.
+    Black,
+    /// Static replication of the squared fixing by caplets across the
+    /// smile, integrated by Simpson's rule on `intervals` strikes.
+    Replication { intervals: usize },
+}
+
+/// Prices coupons with the Black caplet volatilities `caplet_volatility`,
+/// fixing times being measured Actual/365 from `reference_date`. Coupons
+/// set in advance pay their forward.
+pub struct BlackIborCouponPricer<V: BlackVolTermStructure> {
+    pub caplet_volatility: Rc<V>,
+    pub reference_date: Date,
+    pub method: InArrearsMethod,
+}
+
+impl<V: BlackVolTermStructure> BlackIborCouponPricer<V> {
+    pub fn new(caplet_volatility: Rc<V>, reference_date: Date) -> BlackIborCouponPricer<V> {
+        BlackIborCouponPricer {
+            caplet_volatility,
+            reference_date,
+            method: InArrearsMethod::Black,
+        }
+    }
+
+    pub fn with_method(mut self, method: InArrearsMethod) -> BlackIborCouponPricer<V> {
+        self.method = method;
+        self
+    }
+
+    /// Expectation of the squared fixing under the index payment measure,
+    /// twice the integral of the undiscounted caplets over the strikes.
+    fn replicated_second_moment(&self, forward: Rate, t: Time, intervals: usize) -> f64 {
+        assert!(forward > 0.0, "replication needs a positive forward");
+        let vol = &self.caplet_volatility;
+        let std_dev = vol.black_variance(t, forward).sqrt();
+        let upper = forward * (REPLICATION_STD_DEVS * std_dev).exp();
+        let caplet = |k: f64| {
+            let std_dev = vol.black_variance(t, k).sqrt();
+            black_formula(OptionType::Call, k, forward, std_dev, 1.0, 0.0)
+        };
+        2.0 * simpson(caplet, 0.0, upper, intervals)
+    }
+}
+
+impl<V: BlackVolTermStructure> IborCouponPricer for BlackIborCouponPricer<V> {
+    fn adjusted_fixing(&self, fixing: &IborFixing) -> Rate {
+        let t = Actual365Fixed.year_fraction(self.reference_date, fixing.fixing_date, None, None);
+        if !fixing.in_arrears || t <= 0.0 {
+            return fixing.forward;
+        }
+        let (f, tau) = (fixing.forward, fixing.index_accrual);
+        match self.method {
+            InArrearsMethod::Black => {
+                let variance = self.caplet_volatility.black_variance(t, f);
+                f + f * f * variance * tau / (1.0 + f * tau)
+            }
+            InArrearsMethod::Replication { intervals } => {
+                let second_moment = self.replicated_second_moment(f, t, intervals);
+                (f + tau * second_moment) / (1.0 + f * tau)
+            }
+        }
+    }
+}
+
+/// The swap rate fixing of a CMS coupon, as given to its pricer.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CmsFixing {
+    pub fixing_date: Date,
+    /// The forward swap rate, or the fixing once known.
+    pub forward: Rate,
+}
+
+/// Model of the rate a CMS coupon pays for its swap rate fixing.
+pub trait CmsCouponPricer {
+    fn adjusted_fixing(&self, fixing: &CmsFixing) -> Rate;
+}
+
+/// The lognormal forward `forward`, with volatility `rate_vol`, under the
+/// measure of the payment currency, the exchange rate in units of it per
+/// unit of the index currency having volatility `fx_vol` and correlation
+/// `correlation` with the rate over `t`.
+fn quanto_adjusted(forward: Rate, rate_vol: f64, fx_vol: f64, correlation: f64, t: Time) -> Rate {
+    forward * (-correlation * rate_vol * fx_vol * t).exp()
+}
+
+fn check_correlation(correlation: f64) {
+    assert!(
+        (-1.0..=1.0).contains(&correlation),
+        "correlation out of [-1, 1]"
+    );
+}
+
+/// Prices Ibor coupons paid in another currency than the index's: the
+/// forward is quanto adjusted with the caplet volatility at the money, the
+/// volatility `fx_volatility` of the exchange rate, in payment currency
+/// per unit of index currency, at `fx_strike` and its correlation with the
+/// rate, before `pricer` adds any in-arrears adjustment.
+pub struct QuantoIborCouponPricer<V, FV, Q = SimpleQuote>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub pricer: BlackIborCouponPricer<V>,
+    pub fx_volatility: Rc<FV>,
+    pub fx_strike: f64,
+    pub correlation: Q,
+}
+
+impl<V, FV, Q> QuantoIborCouponPricer<V, FV, Q>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub fn new(
+        pricer: BlackIborCouponPricer<V>,
+        fx_volatility: Rc<FV>,
+        fx_strike: f64,
+        correlation: Q,
+    ) -> QuantoIborCouponPricer<V, FV, Q> {
+        check_correlation(correlation.value());
+        QuantoIborCouponPricer {
+            pricer,
+            fx_volatility,
+            fx_strike,
+            correlation,
+        }
+    }
+}
+
+impl<V, FV, Q> IborCouponPricer for QuantoIborCouponPricer<V, FV, Q>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    fn adjusted_fixing(&self, fixing: &IborFixing) -> Rate {
+        let reference_date = self.pricer.reference_date;
+        let t = Actual365Fixed.year_fraction(reference_date, fixing.fixing_date, None, None);
+        if t <= 0.0 {
+            return fixing.forward;
+        }
+        let rate_vol = self.pricer.caplet_volatility.black_vol(t, fixing.forward);
+        let fx_vol = self.fx_volatility.black_vol(t, self.fx_strike);
+        let correlation = self.correlation.value();
+        check_correlation(correlation);
+        self.pricer.adjusted_fixing(&IborFixing {
+            forward: quanto_adjusted(fixing.forward, rate_vol, fx_vol, correlation, t),
+            ..*fixing
+        })
+    }
+}
+
+/// Prices CMS coupons paid in another currency than the index's, quanto
+/// adjusting the forward swap rate as `QuantoIborCouponPricer` does the
+/// Ibor forward, with the swaption volatility at the money. No convexity
+/// adjustment is taken.
+pub struct QuantoCmsCouponPricer<V, FV, Q = SimpleQuote>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub swaption_volatility: Rc<V>,
+    pub fx_volatility: Rc<FV>,
+    pub fx_strike: f64,
+    pub correlation: Q,
+    pub reference_date: Date,
+}
+
+impl<V, FV, Q> QuantoCmsCouponPricer<V, FV, Q>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub fn new(
+        swaption_volatility: Rc<V>,
+        fx_volatility: Rc<FV>,
+        fx_strike: f64,
+        correlation: Q,
+        reference_date: Date,
+    ) -> QuantoCmsCouponPricer<V, FV, Q> {
+        check_correlation(correlation.value());
+        QuantoCmsCouponPricer {
+            swaption_volatility,
+            fx_volatility,
+            fx_strike,
+            correlation,
+            reference_date,
+        }
+    }
+}
+
+impl<V, FV, Q> CmsCouponPricer for QuantoCmsCouponPricer<V, FV, Q>
+where
+    V: BlackVolTermStructure,
+    FV: BlackVolTermStructure,
+    Q: Quote,
+{
+    fn adjusted_fixing(&self, fixing: &CmsFixing) -> Rate {
+        let t = Actual365Fixed.year_fraction(self.reference_date, fixing.fixing_date, None, None);
+        if t <= 0.0 {
+            return fixing.forward;
+        }
+        let rate_vol = self.swaption_volatility.black_vol(t, fixing.forward);
+        let fx_vol = self.fx_volatility.black_vol(t, self.fx_strike);
+        let correlation = self.correlation.value();
+        check_correlation(correlation);
+        quanto_adjusted(fixing.forward, rate_vol, fx_vol, correlation, t)
+    }
+}
+
+/// Coupon pricers by coupon type, set on whole legs at once in place of
+/// assigning a pricer to each coupon.
+#[derive(Clone, Default)]
+pub struct CouponPricerRegistry {
+    pub ibor: Option<Rc<dyn IborCouponPricer>>,
+    pub cms: Option<Rc<dyn CmsCouponPricer>>,
+}
+
+impl CouponPricerRegistry {
+    pub fn new() -> CouponPricerRegistry {
+        CouponPricerRegistry::default()
+    }
+
+    pub fn with_ibor_pricer(mut self, pricer: Rc<dyn IborCouponPricer>) -> CouponPricerRegistry {
+        self.ibor = Some(pricer);
+        self
+    }
+
+    pub fn with_cms_pricer(mut self, pricer: Rc<dyn CmsCouponPricer>) -> CouponPricerRegistry {
+        self.cms = Some(pricer);
+        self
+    }
+
+    /// This registry with the pricers of `overrides` taking precedence,
+    /// e.g. those of one leg over the book's.
+    pub fn overridden_by(&self, overrides: &CouponPricerRegistry) -> CouponPricerRegistry {
+        CouponPricerRegistry {
+            ibor: overrides.ibor.clone().or_else(|| self.ibor.clone()),
+            cms: overrides.cms.clone().or_else(|| self.cms.clone()),
+        }
+    }
+}
+
+/// Coupon taking its pricer from a registry.
+pub trait PricedCoupon {
+    /// Sets the registry's pricer for this type of coupon, if it has one.
+    fn set_pricer(&mut self, registry: &CouponPricerRegistry);
+}
+
+/// Sets the pricers of `registry` on the coupons of `leg`, replacing
+/// those set before for the types it has a pricer for.
+pub fn set_coupon_pricers<C: PricedCoupon>(leg: &mut [C], registry: &CouponPricerRegistry) {
+    for coupon in leg {
+        coupon.set_pricer(registry);
+    }
+}
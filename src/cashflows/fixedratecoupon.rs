@@ -1,85 +1,202 @@
-use super::traits::Coupon;
-use super::{Base, CashFlow, Event};
-use crate::termstructures::InterestRate;
-use crate::time::{Date, DayCounter};
+use super::traits::{CashFlow, Coupon, Event};
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::termstructures::{Compounding, InterestRate};
+use crate::time::{Date, DayCounter, Frequency, Schedule};
 
+/// Coupon accruing a fixed interest rate over its accrual period.
 #[derive(Copy, Clone)]
 pub struct FixedRateCoupon<DC: DayCounter> {
     pub base: Base<DC>,
     pub interest_rate: InterestRate<DC>,
 }
 
-// impl<DC> Coupon for FixedRateCoupon<DC>
-// where
-//     DC: DayCounter,
-// {
-//     fn rate(&self) -> f64 {
-//         self.interest_rate.rate
-//     }
-//     fn accrued_amount(&self, date: Date) -> f64 {
-//         if date.d.le(&self.fields.accrual_start_date.d) || date.d.gt(&self.fields.payment_date.d) {
-//             0.0
-//         } else {
-//             let min_date = if date.d.le(&self.fields.accrual_end_date.d) {
-//                 date
-//             } else {
-//                 self.fields.accrual_end_date
-//             };
-//             self.fields.nominal
-//                 * (self.interest_rate.compound_factor_with_ref(
-//                     self.fields.accrual_start_date,
-//                     min_date,
-//                     Some(self.fields.reference_period_start),
-//                     Some(self.fields.reference_period_end),
-//                 ) - 1.0)
-//         }
-//     }
-//     fn accrual_period(&self) -> f64 {
-//         self.fields.day_counter.year_fraction(
-//             self.fields.accrual_start_date,
-//             self.fields.accrual_end_date,
-//             Some(self.fields.reference_period_start),
-//             Some(self.fields.reference_period_end),
-//         )
-//     }
-//     fn accrual_days(&self) -> usize {
-//         self.fields
-//             .day_counter
-//             .day_count(self.fields.accrual_start_date, self.fields.accrual_end_date)
-//             as usize
-//     }
-// }
+impl<DC: DayCounter> FixedRateCoupon<DC> {
+    pub fn new(base: Base<DC>, interest_rate: InterestRate<DC>) -> FixedRateCoupon<DC> {
+        FixedRateCoupon {
+            base,
+            interest_rate,
+        }
+    }
+}
+
+impl<DC: DayCounter> Event for FixedRateCoupon<DC> {
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<DC: DayCounter> CashFlow for FixedRateCoupon<DC> {
+    fn amount(&self) -> f64 {
+        self.base.nominal
+            * (self.interest_rate.compound_factor_with_ref(
+                self.base.accrual_start_date,
+                self.base.accrual_end_date,
+                Some(self.base.reference_period_start),
+                Some(self.base.reference_period_end),
+            ) - 1.0)
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
 
-// impl<DC> CashFlow for FixedRateCoupon<DC>
-// where
-//     DC: DayCounter,
-// {
-//     fn amount(&self) -> f64 {
-//         self.fields.nominal
-//             * (self.interest_rate.compound_factor_with_ref(
-//                 self.fields.accrual_start_date,
-//                 self.fields.accrual_end_date,
-//                 Some(self.fields.reference_period_start),
-//                 Some(self.fields.reference_period_end),
-//             ) - 1.0)
-//     }
-//     fn try_as_coup(&self) -> Option<&dyn Coupon> {
-//         Some(self)
-//     }
-// }
+impl<DC: DayCounter> Coupon for FixedRateCoupon<DC> {
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            Some(self.base.reference_period_start),
+            Some(self.base.reference_period_end),
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> f64 {
+        self.interest_rate.rate
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        self.base.nominal
+            * (self.interest_rate.compound_factor_with_ref(
+                self.base.accrual_start_date,
+                end,
+                Some(self.base.reference_period_start),
+                Some(self.base.reference_period_end),
+            ) - 1.0)
+    }
+}
+
+/// Builds a leg of fixed rate coupons over the periods of a schedule.
+///
+/// Notionals and rates are given per period; a list shorter than the
+/// schedule keeps its last value for the remaining periods, so a single
+/// value applies to the whole leg. Rates are simple unless another
+/// compounding is given.
+pub struct FixedRateLeg<DC: DayCounter> {
+    pub schedule: Schedule,
+    pub day_counter: DC,
+    pub notionals: Vec<f64>,
+    pub rates: Vec<Rate>,
+    pub compounding: Compounding,
+    pub frequency: Frequency,
+}
 
-// impl<DC> Event for FixedRateCoupon<DC>
-// where
-//     DC: DayCounter,
-// {
-//     fn date(&self) -> Date {
-//         self.fields.payment_date
-//     }
-//     fn has_occured(&self, date: Date, include_today: bool) -> bool {
-//         if include_today {
-//             self.fields.payment_date.d.le(&date.d)
-//         } else {
-//             self.fields.payment_date.d.le(&date.d) || self.fields.payment_date.d.eq(&date.d)
-//         }
-//     }
-// }
+impl<DC: DayCounter> FixedRateLeg<DC> {
+    pub fn new(schedule: Schedule, day_counter: DC) -> FixedRateLeg<DC> {
+        FixedRateLeg {
+            schedule,
+            day_counter,
+            notionals: vec![1.0],
+            rates: vec![],
+            compounding: Compounding::Simple,
+            frequency: Frequency::Annual,
+        }
+    }
+    pub fn with_notional(self, notional: f64) -> FixedRateLeg<DC> {
+        self.with_notionals(vec![notional])
+    }
+    /// Per period notionals, e.g. of an amortizing bond.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> FixedRateLeg<DC> {
+        assert!(!notionals.is_empty(), "no notionals given");
+        self.notionals = notionals;
+        self
+    }
+    pub fn with_rate(self, rate: Rate) -> FixedRateLeg<DC> {
+        self.with_rates(vec![rate])
+    }
+    /// Per period rates, e.g. of a step-up bond.
+    pub fn with_rates(mut self, rates: Vec<Rate>) -> FixedRateLeg<DC> {
+        assert!(!rates.is_empty(), "no rates given");
+        self.rates = rates;
+        self
+    }
+    pub fn with_compounding(
+        mut self,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> FixedRateLeg<DC> {
+        self.compounding = compounding;
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn build(&self) -> Leg<FixedRateCoupon<DC>> {
+        assert!(!self.rates.is_empty(), "no coupon rates given");
+        let dates = &self.schedule.dates;
+        let n = dates.len() - 1;
+        let tenor = self.schedule.tenor;
+        let nth = |values: &[f64], i: usize| values[i.min(values.len() - 1)];
+        (0..n)
+            .map(|i| {
+                let (start, end) = (dates[i], dates[i + 1]);
+                // stubs accrue against a notional full period.
+                let (ref_start, ref_end) = match (self.schedule.is_regular[i], i) {
+                    (false, 0) => (end.advance(-tenor.length, tenor.units), end),
+                    (false, _) => (start, start.advance(tenor.length, tenor.units)),
+                    _ => (start, end),
+                };
+                let base = Base {
+                    nominal: nth(&self.notionals, i),
+                    day_counter: self.day_counter,
+                    payment_date: end,
+                    accrual_start_date: start,
+                    accrual_end_date: end,
+                    reference_period_start: ref_start,
+                    reference_period_end: ref_end,
+                };
+                let rate = InterestRate::new(
+                    nth(&self.rates, i),
+                    self.day_counter,
+                    self.compounding,
+                    self.frequency,
+                );
+                FixedRateCoupon::new(base, rate)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,27 @@
+use super::traits::Coupon;
+use crate::errors::QuantLibError;
+use crate::time::Date;
+
+/// A coupon whose rate depends on index fixings.
+pub trait FloatingRateCoupon: Coupon {
+    /// The fixing dates the rate depends on.
+    fn fixing_dates(&self) -> Vec<Date>;
+    /// Resolves the fixings under the missing fixing policy of the current
+    /// `PricingContext`, reporting each one that cannot be. Pricing a
+    /// coupon with unresolved fixings panics with the same message.
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>>;
+}
+
+/// Checks the fixings of a whole floating leg before pricing it.
+pub fn check_leg_fixings<CF: FloatingRateCoupon>(leg: &[CF]) -> Result<(), Vec<QuantLibError>> {
+    let errors: Vec<QuantLibError> = leg
+        .iter()
+        .filter_map(|c| c.check_fixings().err())
+        .flatten()
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
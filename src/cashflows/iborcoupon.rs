@@ -0,0 +1,381 @@
+use super::couponpricer::{CouponPricerRegistry, IborCouponPricer, IborFixing, PricedCoupon};
+use super::traits::{CashFlow, Coupon, Event};
+use super::FloatingRateCoupon;
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::{check_fixings, InterestRateIndex};
+use crate::time::{Date, DayCounter, Schedule};
+use std::rc::Rc;
+
+/// Pair of index tenors bracketing a stub period, e.g. 1M and 3M Euribor
+/// for a two-month stub.
+pub struct StubInterpolation<I: InterestRateIndex> {
+    pub short_index: Rc<I>,
+    pub long_index: Rc<I>,
+}
+
+impl<I: InterestRateIndex> StubInterpolation<I> {
+    pub fn new(short_index: Rc<I>, long_index: Rc<I>) -> StubInterpolation<I> {
+        StubInterpolation {
+            short_index,
+            long_index,
+        }
+    }
+
+    /// The fixing for a period of `days` calendar days, interpolated
+    /// linearly in the number of days between the two index fixings (ISDA
+    /// 2006 Definitions, section 8.3). Periods outside the bracket get the
+    /// nearest index fixing.
+    pub fn fixing(&self, fixing_date: Date, days: i64) -> Rate {
+        let period_days = |index: &I| {
+            let start = index.value_date(fixing_date);
+            index.maturity_date(start).sub(start)
+        };
+        let (d1, d2) = (
+            period_days(&self.short_index),
+            period_days(&self.long_index),
+        );
+        assert!(d2 > d1, "long index tenor must exceed the short one");
+        let r1 = self.short_index.fixing(fixing_date);
+        let r2 = self.long_index.fixing(fixing_date);
+        let w = ((days - d1) as f64 / (d2 - d1) as f64).clamp(0.0, 1.0);
+        r1 + w * (r2 - r1)
+    }
+}
+
+impl<I: InterestRateIndex> Clone for StubInterpolation<I> {
+    fn clone(&self) -> StubInterpolation<I> {
+        StubInterpolation::new(Rc::clone(&self.short_index), Rc::clone(&self.long_index))
+    }
+}
+
+/// Coupon paying an Ibor fixing, set in advance unless `in_arrears`,
+/// times a gearing plus a spread.
+pub struct IborCoupon<I: InterestRateIndex, DC: DayCounter> {
+    pub base: Base<DC>,
+    pub index: Rc<I>,
+    pub fixing_date: Date,
+    pub gearing: f64,
+    pub spread: f64,
+    /// Replaces the index fixing with an interpolated one for stubs.
+    pub interpolation: Option<StubInterpolation<I>>,
+    /// Fixed at the end of the accrual period rather than its start.
+    pub in_arrears: bool,
+    /// Adjusts the fixing paid, e.g. for convexity when in arrears; the
+    /// fixing itself is paid without one.
+    pub pricer: Option<Rc<dyn IborCouponPricer>>,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> IborCoupon<I, DC> {
+    pub fn new(base: Base<DC>, index: Rc<I>, gearing: f64, spread: f64) -> IborCoupon<I, DC> {
+        let fixing_date = index.fixing_date(base.accrual_start_date);
+        IborCoupon {
+            base,
+            index,
+            fixing_date,
+            gearing,
+            spread,
+            interpolation: None,
+            in_arrears: false,
+            pricer: None,
+        }
+    }
+
+    /// Fixes the coupon at the end of its accrual period.
+    pub fn with_in_arrears(mut self) -> IborCoupon<I, DC> {
+        self.fixing_date = self.index.fixing_date(self.base.accrual_end_date);
+        self.in_arrears = true;
+        self
+    }
+
+    pub fn with_pricer(mut self, pricer: Rc<dyn IborCouponPricer>) -> IborCoupon<I, DC> {
+        self.pricer = Some(pricer);
+        self
+    }
+
+    pub fn with_interpolation(mut self, interpolation: StubInterpolation<I>) -> IborCoupon<I, DC> {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// The index fixing before gearing and spread.
+    pub fn index_fixing(&self) -> Rate {
+        match &self.interpolation {
+            Some(i) => i.fixing(
+                self.fixing_date,
+                self.base.accrual_end_date.sub(self.base.accrual_start_date),
+            ),
+            None => self.index.fixing(self.fixing_date),
+        }
+    }
+
+    /// The fixing paid, as adjusted by the pricer.
+    pub fn adjusted_fixing(&self) -> Rate {
+        let fixing = self.index_fixing();
+        let pricer = match &self.pricer {
+            Some(p) => p,
+            None => return fixing,
+        };
+        let value_date = self.index.value_date(self.fixing_date);
+        let index_accrual = self.base.day_counter.year_fraction(
+            value_date,
+            self.index.maturity_date(value_date),
+            None,
+            None,
+        );
+        pricer.adjusted_fixing(&IborFixing {
+            fixing_date: self.fixing_date,
+            forward: fixing,
+            index_accrual,
+            in_arrears: self.in_arrears,
+        })
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> PricedCoupon for IborCoupon<I, DC> {
+    fn set_pricer(&mut self, registry: &CouponPricerRegistry) {
+        if let Some(p) = &registry.ibor {
+            self.pricer = Some(Rc::clone(p));
+        }
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Event for IborCoupon<I, DC> {
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> CashFlow for IborCoupon<I, DC> {
+    fn amount(&self) -> f64 {
+        self.base.nominal * self.rate() * self.accrual_period()
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Coupon for IborCoupon<I, DC> {
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            Some(self.base.reference_period_start),
+            Some(self.base.reference_period_end),
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> f64 {
+        self.gearing * self.adjusted_fixing() + self.spread
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        self.base.nominal
+            * self.rate()
+            * self.base.day_counter.year_fraction(
+                self.base.accrual_start_date,
+                end,
+                Some(self.base.reference_period_start),
+                Some(self.base.reference_period_end),
+            )
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> FloatingRateCoupon for IborCoupon<I, DC> {
+    fn fixing_dates(&self) -> Vec<Date> {
+        vec![self.fixing_date]
+    }
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>> {
+        let dates = [self.fixing_date];
+        let checks = match &self.interpolation {
+            Some(i) => vec![
+                check_fixings(i.short_index.as_ref(), &dates),
+                check_fixings(i.long_index.as_ref(), &dates),
+            ],
+            None => vec![check_fixings(self.index.as_ref(), &dates)],
+        };
+        let errors: Vec<QuantLibError> = checks
+            .into_iter()
+            .filter_map(Result::err)
+            .flatten()
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Builder of a leg of Ibor coupons paid at the end of each schedule
+/// period.
+pub struct IborLeg<I: InterestRateIndex, DC: DayCounter> {
+    pub schedule: Schedule,
+    pub index: Rc<I>,
+    pub day_counter: DC,
+    pub notionals: Vec<f64>,
+    pub gearing: f64,
+    pub spread: f64,
+    pub first_stub: Option<StubInterpolation<I>>,
+    pub last_stub: Option<StubInterpolation<I>>,
+    pub in_arrears: bool,
+    pub pricer: Option<Rc<dyn IborCouponPricer>>,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> IborLeg<I, DC> {
+    pub fn new(schedule: Schedule, index: Rc<I>, day_counter: DC) -> IborLeg<I, DC> {
+        IborLeg {
+            schedule,
+            index,
+            day_counter,
+            notionals: vec![1.0],
+            gearing: 1.0,
+            spread: 0.0,
+            first_stub: None,
+            last_stub: None,
+            in_arrears: false,
+            pricer: None,
+        }
+    }
+    pub fn with_notional(self, notional: f64) -> IborLeg<I, DC> {
+        self.with_notionals(vec![notional])
+    }
+    /// Per period notionals, e.g. of an amortizing swap; the last one
+    /// carries on to the remaining periods.
+    pub fn with_notionals(mut self, notionals: Vec<f64>) -> IborLeg<I, DC> {
+        assert!(!notionals.is_empty(), "no notionals given");
+        self.notionals = notionals;
+        self
+    }
+    pub fn with_gearing(mut self, gearing: f64) -> IborLeg<I, DC> {
+        self.gearing = gearing;
+        self
+    }
+    pub fn with_spread(mut self, spread: f64) -> IborLeg<I, DC> {
+        self.spread = spread;
+        self
+    }
+    pub fn with_in_arrears(mut self, in_arrears: bool) -> IborLeg<I, DC> {
+        self.in_arrears = in_arrears;
+        self
+    }
+    pub fn with_pricer(mut self, pricer: Rc<dyn IborCouponPricer>) -> IborLeg<I, DC> {
+        self.pricer = Some(pricer);
+        self
+    }
+    /// Takes the registry's pricer unless the leg has its own.
+    pub fn with_pricers(mut self, registry: &CouponPricerRegistry) -> IborLeg<I, DC> {
+        if self.pricer.is_none() {
+            self.pricer = registry.ibor.clone();
+        }
+        self
+    }
+    /// Interpolates the fixing of an irregular first period.
+    pub fn with_first_stub_interpolation(
+        mut self,
+        short_index: Rc<I>,
+        long_index: Rc<I>,
+    ) -> IborLeg<I, DC> {
+        self.first_stub = Some(StubInterpolation::new(short_index, long_index));
+        self
+    }
+    /// Interpolates the fixing of an irregular last period.
+    pub fn with_last_stub_interpolation(
+        mut self,
+        short_index: Rc<I>,
+        long_index: Rc<I>,
+    ) -> IborLeg<I, DC> {
+        self.last_stub = Some(StubInterpolation::new(short_index, long_index));
+        self
+    }
+
+    pub fn build(&self) -> Leg<IborCoupon<I, DC>> {
+        let dates = &self.schedule.dates;
+        let n = dates.len() - 1;
+        let tenor = self.schedule.tenor;
+        (0..n)
+            .map(|i| {
+                let (start, end) = (dates[i], dates[i + 1]);
+                let regular = self.schedule.is_regular[i];
+                // stubs accrue against a notional full period.
+                let (ref_start, ref_end) = match (regular, i) {
+                    (false, 0) => (end.advance(-tenor.length, tenor.units), end),
+                    (false, _) => (start, start.advance(tenor.length, tenor.units)),
+                    _ => (start, end),
+                };
+                let base = Base {
+                    nominal: self.notionals[i.min(self.notionals.len() - 1)],
+                    day_counter: self.day_counter,
+                    payment_date: end,
+                    accrual_start_date: start,
+                    accrual_end_date: end,
+                    reference_period_start: ref_start,
+                    reference_period_end: ref_end,
+                };
+                let mut coupon =
+                    IborCoupon::new(base, Rc::clone(&self.index), self.gearing, self.spread);
+                if self.in_arrears {
+                    coupon = coupon.with_in_arrears();
+                }
+                if let Some(p) = &self.pricer {
+                    coupon = coupon.with_pricer(Rc::clone(p));
+                }
+                let stub = match (regular, i) {
+                    (false, 0) => self.first_stub.as_ref(),
+                    (false, i) if i == n - 1 => self.last_stub.as_ref(),
+                    _ => None,
+                };
+                match stub {
+                    Some(s) => coupon.with_interpolation(s.clone()),
+                    None => coupon,
+                }
+            })
+            .collect()
+    }
+}
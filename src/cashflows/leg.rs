@@ -1,3 +1,2 @@
-use super::CashFlow;
-
-pub type Leg<CF: CashFlow> = Vec<CF>;
+/// A sequence of cash flows.
+pub type Leg<CF> = Vec<CF>;
@@ -0,0 +1,153 @@
+use super::traits::{CashFlow, Event};
+use super::Leg;
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::indexes::ZeroInflationIndex;
+use crate::time::{Date, Schedule};
+use std::rc::Rc;
+
+/// Amount indexed to a limited price index, as UK pensions are: the
+/// nominal grown by the year-on-year ratios of `index` between successive
+/// `fixing_dates`, each collared to `[1 + floor, 1 + cap]`.
+///
+/// `amount` takes the forecast fixings, so prices the collars at their
+/// intrinsic value; `McLpiEngine` values their optionality.
+pub struct LpiCashFlow {
+    pub index: Rc<ZeroInflationIndex>,
+    pub nominal: f64,
+    pub payment_date: Date,
+    /// Fixing months from the base one, one more than the periods.
+    pub fixing_dates: Vec<Date>,
+    pub floor: Rate,
+    pub cap: Rate,
+}
+
+impl LpiCashFlow {
+    pub fn new(
+        index: Rc<ZeroInflationIndex>,
+        nominal: f64,
+        payment_date: Date,
+        fixing_dates: Vec<Date>,
+        floor: Rate,
+        cap: Rate,
+    ) -> LpiCashFlow {
+        assert!(fixing_dates.len() >= 2, "no indexation period");
+        assert!(
+            fixing_dates.windows(2).all(|w| w[0] < w[1]),
+            "fixing dates must be increasing"
+        );
+        assert!(floor > -1.0 && floor <= cap, "invalid collar");
+        LpiCashFlow {
+            index,
+            nominal,
+            payment_date,
+            fixing_dates,
+            floor,
+            cap,
+        }
+    }
+
+    /// The growth of the limited price index over the fixings given, one
+    /// per fixing date.
+    pub fn lpi_ratio(&self, fixings: &[f64]) -> f64 {
+        assert!(
+            fixings.len() == self.fixing_dates.len(),
+            "fixings and fixing dates size mismatch"
+        );
+        fixings
+            .windows(2)
+            .map(|w| (w[1] / w[0]).clamp(1.0 + self.floor, 1.0 + self.cap))
+            .product()
+    }
+
+    /// The index fixings, published or forecast.
+    pub fn forecast_fixings(&self) -> Result<Vec<f64>, QuantLibError> {
+        self.fixing_dates
+            .iter()
+            .map(|d| self.index.forecast_fixing(*d))
+            .collect()
+    }
+}
+
+impl Event for LpiCashFlow {
+    fn date(&self) -> Date {
+        self.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.payment_date < date
+    }
+}
+
+impl CashFlow for LpiCashFlow {
+    fn amount(&self) -> f64 {
+        let fixings = self.forecast_fixings().unwrap_or_else(|e| panic!("{}", e));
+        self.nominal * self.lpi_ratio(&fixings)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.payment_date < date
+        } else {
+            self.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+/// Builder of a leg of LPI indexed amounts paid at the end of each
+/// schedule period, indexed from the start of the schedule with the
+/// fixings `observation_lag` months before each schedule date, collared
+/// at 0% and 5% unless set otherwise.
+pub struct LpiLeg {
+    pub schedule: Schedule,
+    pub index: Rc<ZeroInflationIndex>,
+    pub observation_lag: i64,
+    pub notional: f64,
+    pub floor: Rate,
+    pub cap: Rate,
+}
+
+impl LpiLeg {
+    pub fn new(schedule: Schedule, index: Rc<ZeroInflationIndex>, observation_lag: i64) -> LpiLeg {
+        assert!(observation_lag >= 0, "negative observation lag");
+        LpiLeg {
+            schedule,
+            index,
+            observation_lag,
+            notional: 1.0,
+            floor: 0.0,
+            cap: 0.05,
+        }
+    }
+    pub fn with_notional(mut self, notional: f64) -> LpiLeg {
+        self.notional = notional;
+        self
+    }
+    pub fn with_collar(mut self, floor: Rate, cap: Rate) -> LpiLeg {
+        self.floor = floor;
+        self.cap = cap;
+        self
+    }
+
+    pub fn build(&self) -> Leg<LpiCashFlow> {
+        let fixings: Vec<Date> = self
+            .schedule
+            .dates
+            .iter()
+            .map(|d| ZeroInflationIndex::reference_month(*d).add_months(-self.observation_lag))
+            .collect();
+        (1..self.schedule.dates.len())
+            .map(|k| {
+                LpiCashFlow::new(
+                    Rc::clone(&self.index),
+                    self.notional,
+                    self.schedule.dates[k],
+                    fixings[..=k].to_vec(),
+                    self.floor,
+                    self.cap,
+                )
+            })
+            .collect()
+    }
+}
@@ -1,18 +1,40 @@
+pub mod analysis;
 pub mod averagebmacoupon;
 pub mod base;
 pub mod cappedflooredcoupon;
 pub mod cappedfloorediborcoupon;
 pub mod cashflows;
+pub mod cdicoupon;
 pub mod cmscoupon;
+pub mod couponpricer;
 pub mod dividend;
 pub mod fixedratecoupon;
 pub mod floatingratecoupon;
 pub mod iborcoupon;
 pub mod leg;
+pub mod lpicashflow;
+pub mod overnightindexedcoupon;
+pub mod settlement;
+pub mod simplecashflow;
 pub mod traits;
 
+pub use self::averagebmacoupon::{AverageBmaCoupon, AverageBmaLeg};
 pub use self::base::Base;
 pub use self::cashflows::*;
+pub use self::cdicoupon::{CdiCoupon, CdiLeg};
+pub use self::cmscoupon::{CmsCoupon, CmsLeg};
+pub use self::couponpricer::{
+    set_coupon_pricers, BlackIborCouponPricer, CmsCouponPricer, CmsFixing, CouponPricerRegistry,
+    IborCouponPricer, IborFixing, InArrearsMethod, PricedCoupon, QuantoCmsCouponPricer,
+    QuantoIborCouponPricer,
+};
 pub use self::dividend::Dividend;
+pub use self::fixedratecoupon::{FixedRateCoupon, FixedRateLeg};
+pub use self::floatingratecoupon::{check_leg_fixings, FloatingRateCoupon};
+pub use self::iborcoupon::{IborCoupon, IborLeg, StubInterpolation};
 pub use self::leg::Leg;
+pub use self::lpicashflow::{LpiCashFlow, LpiLeg};
+pub use self::overnightindexedcoupon::{OvernightIndexedCoupon, OvernightLeg};
+pub use self::settlement::{PayReceive, SettlementAmount, SettlementCalculator};
+pub use self::simplecashflow::SimpleCashFlow;
 pub use self::traits::{CashFlow, Coupon, Event};
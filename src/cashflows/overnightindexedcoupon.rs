@@ -0,0 +1,230 @@
+use super::traits::{CashFlow, Coupon, Event};
+use super::FloatingRateCoupon;
+use super::{Base, Leg};
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::{check_fixings, InterestRateIndex};
+use crate::time::{Date, DayCounter, Schedule};
+use std::rc::Rc;
+
+/// Coupon paying the daily compounded fixings of an overnight index over
+/// its accrual period, times a gearing plus a spread. The spread is not
+/// compounded.
+pub struct OvernightIndexedCoupon<I: InterestRateIndex, DC: DayCounter> {
+    pub base: Base<DC>,
+    pub index: Rc<I>,
+    pub gearing: f64,
+    pub spread: f64,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> OvernightIndexedCoupon<I, DC> {
+    pub fn new(
+        base: Base<DC>,
+        index: Rc<I>,
+        gearing: f64,
+        spread: f64,
+    ) -> OvernightIndexedCoupon<I, DC> {
+        OvernightIndexedCoupon {
+            base,
+            index,
+            gearing,
+            spread,
+        }
+    }
+
+    /// The value dates of the compounded fixings, from the accrual start to
+    /// the accrual end included.
+    pub fn value_dates(&self) -> Vec<Date> {
+        let mut dates = vec![self.base.accrual_start_date];
+        let end = self.base.accrual_end_date;
+        while *dates.last().unwrap() < end {
+            let next = self.index.maturity_date(*dates.last().unwrap());
+            dates.push(if next < end { next } else { end });
+        }
+        dates
+    }
+
+    /// The compounded index rate before gearing and spread.
+    pub fn compounded_fixing(&self) -> Rate {
+        (self.compound_factor(self.base.accrual_end_date) - 1.0) / self.accrual_period()
+    }
+
+    /// Growth of one unit from the accrual start to `date` at the daily
+    /// fixings, the last one accruing up to `date` only.
+    fn compound_factor(&self, date: Date) -> f64 {
+        let dc = self.base.day_counter;
+        let mut compound = 1.0;
+        for w in self.value_dates().windows(2) {
+            if w[0] >= date {
+                break;
+            }
+            let end = if w[1] < date { w[1] } else { date };
+            let fixing = self.index.fixing(self.index.fixing_date(w[0]));
+            compound *= 1.0 + fixing * dc.year_fraction(w[0], end, None, None);
+        }
+        compound
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Event for OvernightIndexedCoupon<I, DC> {
+    fn date(&self) -> Date {
+        self.base.payment_date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.base.payment_date < date
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> CashFlow for OvernightIndexedCoupon<I, DC> {
+    fn amount(&self) -> f64 {
+        self.base.nominal * self.rate() * self.accrual_period()
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.base.payment_date < date
+        } else {
+            self.base.payment_date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> Coupon for OvernightIndexedCoupon<I, DC> {
+    fn nominal(&self) -> f64 {
+        self.base.nominal
+    }
+    fn accrual_start_date(&self) -> Date {
+        self.base.accrual_start_date
+    }
+    fn accrual_end_date(&self) -> Date {
+        self.base.accrual_end_date
+    }
+    fn reference_period_start(&self) -> Date {
+        self.base.reference_period_start
+    }
+    fn reference_period_end(&self) -> Date {
+        self.base.reference_period_end
+    }
+    fn accrual_period(&self) -> Time {
+        self.base.day_counter.year_fraction(
+            self.base.accrual_start_date,
+            self.base.accrual_end_date,
+            Some(self.base.reference_period_start),
+            Some(self.base.reference_period_end),
+        )
+    }
+    fn accrual_days(&self) -> i64 {
+        self.base
+            .day_counter
+            .day_count(self.base.accrual_start_date, self.base.accrual_end_date)
+    }
+    fn rate(&self) -> f64 {
+        self.gearing * self.compounded_fixing() + self.spread
+    }
+    fn accrued_period(&self) -> Time {
+        self.accrual_period()
+    }
+    fn accrued_days(&self) -> i64 {
+        self.accrual_days()
+    }
+    fn accrued_amount(&self, date: Date) -> f64 {
+        if date <= self.base.accrual_start_date || date > self.base.payment_date {
+            return 0.0;
+        }
+        let end = if date < self.base.accrual_end_date {
+            date
+        } else {
+            self.base.accrual_end_date
+        };
+        // only the fixings observed so far are compounded.
+        let period =
+            self.base
+                .day_counter
+                .year_fraction(self.base.accrual_start_date, end, None, None);
+        self.base.nominal
+            * (self.gearing * (self.compound_factor(end) - 1.0) + self.spread * period)
+    }
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> FloatingRateCoupon for OvernightIndexedCoupon<I, DC> {
+    fn fixing_dates(&self) -> Vec<Date> {
+        let dates = self.value_dates();
+        dates[..dates.len() - 1]
+            .iter()
+            .map(|&d| self.index.fixing_date(d))
+            .collect()
+    }
+    fn check_fixings(&self) -> Result<(), Vec<QuantLibError>> {
+        check_fixings(self.index.as_ref(), &self.fixing_dates())
+    }
+}
+
+/// Builder of a leg of overnight indexed coupons paid at the end of each
+/// schedule period, or `payment_lag` business days after it.
+pub struct OvernightLeg<I: InterestRateIndex, DC: DayCounter> {
+    pub schedule: Schedule,
+    pub index: Rc<I>,
+    pub day_counter: DC,
+    pub notional: f64,
+    pub gearing: f64,
+    pub spread: f64,
+    pub payment_lag: i64,
+}
+
+impl<I: InterestRateIndex, DC: DayCounter> OvernightLeg<I, DC> {
+    pub fn new(schedule: Schedule, index: Rc<I>, day_counter: DC) -> OvernightLeg<I, DC> {
+        OvernightLeg {
+            schedule,
+            index,
+            day_counter,
+            notional: 1.0,
+            gearing: 1.0,
+            spread: 0.0,
+            payment_lag: 0,
+        }
+    }
+    pub fn with_notional(mut self, notional: f64) -> OvernightLeg<I, DC> {
+        self.notional = notional;
+        self
+    }
+    pub fn with_gearing(mut self, gearing: f64) -> OvernightLeg<I, DC> {
+        self.gearing = gearing;
+        self
+    }
+    pub fn with_spread(mut self, spread: f64) -> OvernightLeg<I, DC> {
+        self.spread = spread;
+        self
+    }
+    /// Pays each coupon `days` index business days after its accrual end.
+    pub fn with_payment_lag(mut self, days: i64) -> OvernightLeg<I, DC> {
+        assert!(days >= 0, "negative payment lag");
+        self.payment_lag = days;
+        self
+    }
+
+    pub fn build(&self) -> Leg<OvernightIndexedCoupon<I, DC>> {
+        self.schedule
+            .dates
+            .windows(2)
+            .map(|w| {
+                let payment_date =
+                    (0..self.payment_lag).fold(w[1], |d, _| self.index.maturity_date(d));
+                let base = Base {
+                    nominal: self.notional,
+                    day_counter: self.day_counter,
+                    payment_date,
+                    accrual_start_date: w[0],
+                    accrual_end_date: w[1],
+                    reference_period_start: w[0],
+                    reference_period_end: w[1],
+                };
+                OvernightIndexedCoupon::new(base, Rc::clone(&self.index), self.gearing, self.spread)
+            })
+            .collect()
+    }
+}
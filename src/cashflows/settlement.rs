@@ -0,0 +1,166 @@
+use super::traits::CashFlow;
+use crate::currencies::Currency;
+use crate::math::Rounding;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{BusinessDayConvention, Calendar, Date, TimeUnit};
+use std::collections::BTreeMap;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PayReceive {
+    Pay,
+    Receive,
+}
+
+/// An amount to be settled, signed positive when received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettlementAmount {
+    /// The contractual payment date of the flow.
+    pub payment_date: Date,
+    /// The business day the amount moves on.
+    pub value_date: Date,
+    /// The last day payment instructions for the value date can be sent.
+    pub cut_off_date: Date,
+    pub currency: Currency,
+    /// The amount in minor units of `currency`, e.g. cents.
+    pub minor_units: i64,
+}
+
+impl SettlementAmount {
+    pub fn amount(&self) -> f64 {
+        self.minor_units as f64 / 10f64.powi(self.currency.minor_units() as i32)
+    }
+
+    pub fn pay_receive(&self) -> PayReceive {
+        if self.minor_units < 0 {
+            PayReceive::Pay
+        } else {
+            PayReceive::Receive
+        }
+    }
+}
+
+/// Turns cash flows into the amounts actually exchanged: rounded to the
+/// minor unit of the settlement currency, on value dates adjusted to the
+/// payment calendar and with the cut-off date of their payment
+/// instructions.
+///
+/// Each flow is rounded on its own before any netting, so that netted
+/// amounts are exact sums of the amounts on the confirmations.
+pub struct SettlementCalculator<C: Cal> {
+    pub calendar: Calendar<C>,
+    pub convention: BusinessDayConvention,
+    pub currency: Currency,
+    pub rounding: Rounding,
+    /// Business days before the value date instructions are due.
+    pub cut_off_days: i64,
+}
+
+impl<C: Cal> SettlementCalculator<C> {
+    pub fn new(calendar: Calendar<C>, currency: Currency) -> SettlementCalculator<C> {
+        SettlementCalculator {
+            calendar,
+            convention: BusinessDayConvention::Following,
+            currency,
+            rounding: Rounding::closest(currency.minor_units()),
+            cut_off_days: 0,
+        }
+    }
+
+    pub fn with_convention(mut self, convention: BusinessDayConvention) -> SettlementCalculator<C> {
+        self.convention = convention;
+        self
+    }
+
+    /// Rounding mode of each flow; the precision is that of the currency.
+    pub fn with_rounding(mut self, rounding: Rounding) -> SettlementCalculator<C> {
+        assert!(
+            rounding.precision == self.currency.minor_units(),
+            "rounding precision differs from the {} minor unit",
+            self.currency.code()
+        );
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_cut_off_days(mut self, days: i64) -> SettlementCalculator<C> {
+        assert!(days >= 0, "negative cut-off");
+        self.cut_off_days = days;
+        self
+    }
+
+    pub fn value_date(&self, payment_date: Date) -> Date {
+        self.calendar
+            .adjust_with_convention(payment_date, self.convention)
+    }
+
+    pub fn cut_off_date(&self, value_date: Date) -> Date {
+        self.calendar.advance(
+            value_date,
+            -self.cut_off_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Preceding,
+            false,
+        )
+    }
+
+    /// The non-zero amounts of `leg` with value dates after `from` and up
+    /// to `to` included, in value date order.
+    pub fn amounts<CF: CashFlow>(
+        &self,
+        leg: &[CF],
+        pay_receive: PayReceive,
+        from: Date,
+        to: Date,
+    ) -> Vec<SettlementAmount> {
+        let sign = match pay_receive {
+            PayReceive::Pay => -1.0,
+            PayReceive::Receive => 1.0,
+        };
+        let mut amounts: Vec<SettlementAmount> = leg
+            .iter()
+            .filter_map(|c| {
+                let value_date = self.value_date(c.date());
+                let minor_units = self.rounding.units(sign * c.amount());
+                if value_date <= from || value_date > to || minor_units == 0 {
+                    return None;
+                }
+                Some(SettlementAmount {
+                    payment_date: c.date(),
+                    value_date,
+                    cut_off_date: self.cut_off_date(value_date),
+                    currency: self.currency,
+                    minor_units,
+                })
+            })
+            .collect();
+        amounts.sort_by_key(|a| a.value_date);
+        amounts
+    }
+
+    /// Nets `amounts` per value date, keeping the earliest payment date of
+    /// each; dates netting to zero are dropped.
+    pub fn net(&self, amounts: &[SettlementAmount]) -> Vec<SettlementAmount> {
+        let mut netted: BTreeMap<Date, SettlementAmount> = BTreeMap::new();
+        for a in amounts {
+            assert!(
+                a.currency == self.currency,
+                "cannot net {} and {} amounts",
+                a.currency.code(),
+                self.currency.code()
+            );
+            netted
+                .entry(a.value_date)
+                .and_modify(|n| {
+                    n.minor_units += a.minor_units;
+                    if a.payment_date < n.payment_date {
+                        n.payment_date = a.payment_date;
+                    }
+                })
+                .or_insert_with(|| a.clone());
+        }
+        netted
+            .into_values()
+            .filter(|a| a.minor_units != 0)
+            .collect()
+    }
+}
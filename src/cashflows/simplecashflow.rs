@@ -0,0 +1,40 @@
+use super::traits::{CashFlow, Event};
+use crate::time::Date;
+
+/// A known amount paid on a date, e.g. a bond redemption.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SimpleCashFlow {
+    pub amount: f64,
+    pub date: Date,
+}
+
+impl SimpleCashFlow {
+    pub fn new(amount: f64, date: Date) -> SimpleCashFlow {
+        SimpleCashFlow { amount, date }
+    }
+}
+
+impl Event for SimpleCashFlow {
+    fn date(&self) -> Date {
+        self.date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.date < date
+    }
+}
+
+impl CashFlow for SimpleCashFlow {
+    fn amount(&self) -> f64 {
+        self.amount
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.date < date
+        } else {
+            self.date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
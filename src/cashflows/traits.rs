@@ -1,5 +1,5 @@
 use crate::definitions::Time;
-use crate::time::{Date, DayCounter};
+use crate::time::Date;
 
 pub trait Event {
     fn date(&self) -> Date;
@@ -14,8 +14,9 @@ pub trait CashFlow: Event {
         None
     }
     fn has_occured(&self, date: Date, include_today: bool) -> bool;
-    fn ex_coupon_date(&self) -> Date {
-        Date::default()
+    /// The date from which the flow trades ex-coupon, if any.
+    fn ex_coupon_date(&self) -> Option<Date> {
+        None
     }
     fn trading_ex_coupon(&self) -> bool;
 }
@@ -45,3 +46,31 @@ pub trait Coupon: CashFlow {
     /// accrued amount at the given date
     fn accrued_amount(&self, _date: Date) -> f64;
 }
+
+impl<T: Event + ?Sized> Event for Box<T> {
+    fn date(&self) -> Date {
+        (**self).date()
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        Event::has_occured(&**self, date)
+    }
+}
+
+/// Boxed flows, so that legs can mix cash flow types.
+impl<T: CashFlow + ?Sized> CashFlow for Box<T> {
+    fn amount(&self) -> f64 {
+        (**self).amount()
+    }
+    fn try_as_coup(&self) -> Option<&dyn Coupon> {
+        (**self).try_as_coup()
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        CashFlow::has_occured(&**self, date, include_today)
+    }
+    fn ex_coupon_date(&self) -> Option<Date> {
+        (**self).ex_coupon_date()
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        (**self).trading_ex_coupon()
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Currency {
     USD,
     CAN,
@@ -12,3 +12,40 @@ pub enum Currency {
     PEN,
     BZR,
 }
+
+impl Currency {
+    /// Business days from the fixing to the value date of the currency's
+    /// interbank deposits: same day for GBP, CAD, AUD and BRL, spot (T+2)
+    /// otherwise.
+    pub fn spot_lag(&self) -> i64 {
+        match self {
+            Currency::GBP | Currency::CAN | Currency::AUD | Currency::BZR => 0,
+            _ => 2,
+        }
+    }
+
+    /// ISO 4217 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::CAN => "CAD",
+            Currency::EUR => "EUR",
+            Currency::AUD => "AUD",
+            Currency::NZD => "NZD",
+            Currency::GBP => "GBP",
+            Currency::CHF => "CHF",
+            Currency::CHY => "CNY",
+            Currency::JPY => "JPY",
+            Currency::PEN => "PEN",
+            Currency::BZR => "BRL",
+        }
+    }
+
+    /// Decimal places of the minor unit amounts settle in.
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Currency::JPY => 0,
+            _ => 2,
+        }
+    }
+}
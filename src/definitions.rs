@@ -3,6 +3,7 @@ use crate::currencies::Currency;
 pub type Time = f64;
 pub type DiscountFactor = f64;
 pub type Rate = f64;
+pub type Volatility = f64;
 
 /// A return type that contains a value denoted in a currency.
 #[derive(Default, Copy, Clone, PartialEq)]
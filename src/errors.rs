@@ -0,0 +1,130 @@
+use crate::definitions::{DiscountFactor, Time};
+use crate::indexes::MissingFixingPolicy;
+use crate::time::Date;
+use std::error::Error;
+use std::fmt;
+
+/// A recoverable failure of a library call, reported by the `checked_*`
+/// variants of methods that otherwise panic.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuantLibError {
+    /// A time before the reference date.
+    NegativeTime { time: Time },
+    /// A time past the end of a curve not allowed to extrapolate.
+    BeyondMaxTime { time: Time, max_time: Time },
+    /// A quote without a value.
+    InvalidQuote { what: String },
+    /// A value outside its domain, e.g. a non positive discount factor.
+    InvalidValue { what: String, value: f64 },
+    /// A period, e.g. of a forward rate, ending before it starts.
+    InvertedPeriod { start: Time, end: Time },
+    /// A structure used before being fully set up.
+    Uninitialized { what: String },
+    /// A past fixing that could not be resolved under the policy in effect.
+    MissingFixing {
+        index: String,
+        fixing_date: Date,
+        evaluation_date: Date,
+        policy: MissingFixingPolicy,
+    },
+    /// A root finder given, or widening to, an interval across which the
+    /// function does not change sign.
+    RootNotBracketed { x_min: f64, x_max: f64 },
+    /// An iterative method that did not reach its accuracy.
+    NotConverged { what: String, iterations: usize },
+    /// A bootstrap given no instruments.
+    NoHelpers,
+    /// A pillar date no curve node can be put at, e.g. one shared by two
+    /// instruments.
+    InvalidPillar { pillar_date: Date, what: String },
+    /// A curve node at which no discount factor reprices its helper.
+    BootstrapFailed {
+        pillar_date: Date,
+        cause: Box<QuantLibError>,
+    },
+}
+
+impl fmt::Display for QuantLibError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuantLibError::NegativeTime { time } => {
+                write!(f, "negative time {} given", time)
+            }
+            QuantLibError::BeyondMaxTime { time, max_time } => {
+                write!(f, "time {} is past the max curve time {}", time, max_time)
+            }
+            QuantLibError::InvalidQuote { what } => write!(f, "invalid {} quote", what),
+            QuantLibError::InvalidValue { what, value } => {
+                write!(f, "invalid {}: {}", what, value)
+            }
+            QuantLibError::InvertedPeriod { start, end } => {
+                write!(
+                    f,
+                    "period ends at time {} before it starts at {}",
+                    end, start
+                )
+            }
+            QuantLibError::Uninitialized { what } => write!(f, "{} not set", what),
+            QuantLibError::MissingFixing {
+                index,
+                fixing_date,
+                evaluation_date,
+                policy,
+            } => match policy {
+                MissingFixingPolicy::UseLastAvailable => write!(
+                    f,
+                    "missing {} fixing for {} and no earlier fixing stored (evaluation date {})",
+                    index, fixing_date, evaluation_date
+                ),
+                _ => write!(
+                    f,
+                    "missing {} fixing for {} (evaluation date {})",
+                    index, fixing_date, evaluation_date
+                ),
+            },
+            QuantLibError::RootNotBracketed { x_min, x_max } => {
+                write!(f, "root not bracketed by [{}, {}]", x_min, x_max)
+            }
+            QuantLibError::NotConverged { what, iterations } => {
+                write!(
+                    f,
+                    "{} failed to converge in {} iterations",
+                    what, iterations
+                )
+            }
+            QuantLibError::NoHelpers => write!(f, "no helpers given"),
+            QuantLibError::InvalidPillar { pillar_date, what } => {
+                write!(f, "invalid pillar {}: {}", pillar_date, what)
+            }
+            QuantLibError::BootstrapFailed { pillar_date, cause } => {
+                write!(f, "bootstrap failed at {}: {}", pillar_date, cause)
+            }
+        }
+    }
+}
+
+impl Error for QuantLibError {}
+
+/// Checks that `t` is after the reference date and, unless extrapolating,
+/// not past `max_time`.
+pub fn check_time_range(t: Time, max_time: Time, extrapolate: bool) -> Result<(), QuantLibError> {
+    if t < 0.0 {
+        return Err(QuantLibError::NegativeTime { time: t });
+    }
+    if !extrapolate && t > max_time {
+        return Err(QuantLibError::BeyondMaxTime { time: t, max_time });
+    }
+    Ok(())
+}
+
+/// Checks that `discount` is a finite positive discount factor.
+pub fn check_discount(discount: DiscountFactor) -> Result<DiscountFactor, QuantLibError> {
+    if discount.is_finite() && discount > 0.0 {
+        Ok(discount)
+    } else {
+        Err(QuantLibError::InvalidValue {
+            what: "discount factor".to_string(),
+            value: discount,
+        })
+    }
+}
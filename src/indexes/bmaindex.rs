@@ -0,0 +1,127 @@
+use super::traits::{Index, InterestRateIndex};
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Calendar, Date, DayCounter, Period, TimeUnit, Weekday};
+use std::rc::Rc;
+
+/// The BMA (SIFMA Municipal Swap) index of weekly resetting tax-exempt
+/// variable rate demand obligations.
+///
+/// The index is fixed on Wednesdays, or on the next business day when the
+/// Wednesday is a holiday, and is effective from the following business
+/// day until the next reset.
+pub struct BmaIndex<C: Cal, Y: YieldTermStructure, DC: DayCounter> {
+    pub fixing_calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub forwarding_curve: Option<Rc<Y>>,
+}
+
+impl<C, Y, DC> BmaIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    pub fn new(
+        fixing_calendar: Calendar<C>,
+        day_counter: DC,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> BmaIndex<C, Y, DC> {
+        BmaIndex {
+            fixing_calendar,
+            day_counter,
+            forwarding_curve,
+        }
+    }
+
+    /// The weekly fixing dates whose rates are in effect between `start`
+    /// and `end`, from the reset in effect at `start` to the one in effect
+    /// at `end`.
+    pub fn fixing_dates(&self, start: Date, end: Date) -> Vec<Date> {
+        assert!(start < end, "empty fixing period");
+        let mut wednesday = previous_wednesday(self.fixing_date(start));
+        let mut dates = vec![];
+        loop {
+            let fixing = self.fixing_calendar.adjust(wednesday);
+            if !dates.is_empty() && self.value_date(fixing) >= end {
+                return dates;
+            }
+            dates.push(fixing);
+            wednesday = wednesday.add_days(7);
+        }
+    }
+}
+
+impl<C, Y, DC> Index for BmaIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    fn name(&self) -> String {
+        "BMA".to_string()
+    }
+    fn is_valid_fixing_date(&self, date: Date) -> bool {
+        // either the last Wednesday, or all days since are holidays.
+        let mut d = previous_wednesday(date);
+        while d < date {
+            if self.fixing_calendar.is_business_day(d) {
+                return false;
+            }
+            d = d.add_days(1);
+        }
+        self.fixing_calendar.is_business_day(date)
+    }
+    fn fixing(&self, fixing_date: Date) -> Rate {
+        self.checked_fixing(fixing_date)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<C, Y, DC> InterestRateIndex for BmaIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    fn tenor(&self) -> Period {
+        Period::new(1, TimeUnit::Weeks)
+    }
+    fn fixing_days(&self) -> i64 {
+        1
+    }
+    fn value_date(&self, fixing_date: Date) -> Date {
+        self.fixing_calendar
+            .advance_by_units(fixing_date, 1, TimeUnit::Days)
+    }
+    fn fixing_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar
+            .advance_by_units(value_date, -1, TimeUnit::Days)
+    }
+    /// The value date of the next reset.
+    fn maturity_date(&self, value_date: Date) -> Date {
+        let next = previous_wednesday(self.fixing_date(value_date).add_days(7));
+        self.value_date(self.fixing_calendar.adjust(next))
+    }
+    fn forecast_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError> {
+        let curve = self
+            .forwarding_curve
+            .as_ref()
+            .ok_or_else(|| QuantLibError::Uninitialized {
+                what: format!("forwarding curve of {}", self.name()),
+            })?;
+        let d1 = self.value_date(fixing_date);
+        let d2 = self.maturity_date(d1);
+        let t = self.day_counter.year_fraction(d1, d2, None, None);
+        assert!(t > 0.0, "non positive index period");
+        Ok((curve.discount(d1, true) / curve.discount(d2, true) - 1.0) / t)
+    }
+}
+
+/// The Wednesday on or before `date`.
+fn previous_wednesday(date: Date) -> Date {
+    let days = (date.weekday() as i64 - Weekday::Wednesday as i64 + 7) % 7;
+    date.add_days(-days)
+}
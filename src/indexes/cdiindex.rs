@@ -0,0 +1,75 @@
+use super::traits::{Index, InterestRateIndex};
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Business252, Calendar, Date, DayCounter, Period, TimeUnit};
+use std::rc::Rc;
+
+/// The Brazilian CDI overnight rate (Certificado de Depósito
+/// Interbancário), quoted as an annual rate compounded exponentially over
+/// business/252 days: one business day accrues `(1 + cdi)^(1/252)`.
+pub struct CdiIndex<C: Cal, Y: YieldTermStructure> {
+    pub fixing_calendar: Calendar<C>,
+    pub forwarding_curve: Option<Rc<Y>>,
+}
+
+impl<C: Cal, Y: YieldTermStructure> CdiIndex<C, Y> {
+    pub fn new(fixing_calendar: Calendar<C>, forwarding_curve: Option<Rc<Y>>) -> CdiIndex<C, Y> {
+        CdiIndex {
+            fixing_calendar,
+            forwarding_curve,
+        }
+    }
+
+    pub fn day_counter(&self) -> Business252<C> {
+        Business252 {
+            calendar: self.fixing_calendar,
+        }
+    }
+}
+
+impl<C: Cal, Y: YieldTermStructure> Index for CdiIndex<C, Y> {
+    fn name(&self) -> String {
+        "CDI".to_string()
+    }
+    fn is_valid_fixing_date(&self, date: Date) -> bool {
+        self.fixing_calendar.is_business_day(date)
+    }
+    fn fixing(&self, fixing_date: Date) -> Rate {
+        self.checked_fixing(fixing_date)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<C: Cal, Y: YieldTermStructure> InterestRateIndex for CdiIndex<C, Y> {
+    fn tenor(&self) -> Period {
+        Period::new(1, TimeUnit::Days)
+    }
+    fn fixing_days(&self) -> i64 {
+        0
+    }
+    fn value_date(&self, fixing_date: Date) -> Date {
+        fixing_date
+    }
+    fn fixing_date(&self, value_date: Date) -> Date {
+        value_date
+    }
+    fn maturity_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar
+            .advance_by_units(value_date, 1, TimeUnit::Days)
+    }
+    fn forecast_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError> {
+        let curve = self
+            .forwarding_curve
+            .as_ref()
+            .ok_or_else(|| QuantLibError::Uninitialized {
+                what: format!("forwarding curve of {}", self.name()),
+            })?;
+        let d1 = self.value_date(fixing_date);
+        let d2 = self.maturity_date(d1);
+        let t = self.day_counter().year_fraction(d1, d2, None, None);
+        assert!(t > 0.0, "non positive index period");
+        Ok((curve.discount(d1, true) / curve.discount(d2, true)).powf(1.0 / t) - 1.0)
+    }
+}
@@ -0,0 +1,210 @@
+use crate::instruments::VanillaOption;
+use crate::time::Date;
+use std::collections::BTreeMap;
+
+/// What a corporate action does to one share of the underlying.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CorporateActionKind {
+    /// `ratio` new shares for each old one, e.g. 2 for a two-for-one
+    /// split or 0.1 for a one-for-ten reverse split.
+    Split { ratio: f64 },
+    /// Extraordinary cash amount per share, not part of the dividend
+    /// yield.
+    SpecialDividend { amount: f64 },
+    /// Distribution of shares in a new company, leaving the share with
+    /// the `retained` fraction of its cum price.
+    SpinOff { retained: f64 },
+}
+
+/// A corporate action going ex on `ex_date`: prices from that date on are
+/// quoted after it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CorporateAction {
+    pub ex_date: Date,
+    pub kind: CorporateActionKind,
+}
+
+impl CorporateAction {
+    pub fn new(ex_date: Date, kind: CorporateActionKind) -> CorporateAction {
+        match kind {
+            CorporateActionKind::Split { ratio } => {
+                assert!(ratio > 0.0, "non positive split ratio")
+            }
+            CorporateActionKind::SpecialDividend { amount } => {
+                assert!(amount > 0.0, "non positive special dividend")
+            }
+            CorporateActionKind::SpinOff { retained } => assert!(
+                retained > 0.0 && retained <= 1.0,
+                "retained fraction outside (0, 1]"
+            ),
+        }
+        CorporateAction { ex_date, kind }
+    }
+
+    /// The factor turning a price quoted before the ex date, `cum_price`
+    /// the last of them, into one comparable with prices after it.
+    pub fn price_factor(&self, cum_price: f64) -> f64 {
+        match self.kind {
+            CorporateActionKind::Split { ratio } => 1.0 / ratio,
+            CorporateActionKind::SpecialDividend { amount } => {
+                assert!(amount < cum_price, "special dividend above the cum price");
+                (cum_price - amount) / cum_price
+            }
+            CorporateActionKind::SpinOff { retained } => retained,
+        }
+    }
+}
+
+/// What an adjustment was applied to.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AdjustmentTarget {
+    FixingHistory,
+    Strike,
+    Forward,
+}
+
+/// Record of a corporate action applied to a value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AdjustmentEvent {
+    pub action: CorporateAction,
+    pub target: AdjustmentTarget,
+    /// The value before and after the action: for fixing histories the last
+    /// fixing before the ex date.
+    pub before: f64,
+    pub after: f64,
+}
+
+/// Corporate actions of an equity underlying, adjusting fixing histories,
+/// option terms and forwards, with a log of the adjustments made.
+#[derive(Clone, Debug, Default)]
+pub struct CorporateActions {
+    /// Actions in ex date order.
+    pub actions: Vec<CorporateAction>,
+    log: Vec<AdjustmentEvent>,
+}
+
+impl CorporateActions {
+    pub fn new() -> CorporateActions {
+        CorporateActions::default()
+    }
+
+    pub fn with_action(mut self, ex_date: Date, kind: CorporateActionKind) -> CorporateActions {
+        self.add(CorporateAction::new(ex_date, kind));
+        self
+    }
+
+    /// Adds `action`, after the actions going ex on the same date.
+    pub fn add(&mut self, action: CorporateAction) {
+        let i = self
+            .actions
+            .partition_point(|a| a.ex_date <= action.ex_date);
+        self.actions.insert(i, action);
+    }
+
+    /// The actions going ex after `from` and up to `to` included.
+    pub fn between(&self, from: Date, to: Date) -> Vec<CorporateAction> {
+        self.actions
+            .iter()
+            .filter(|a| a.ex_date > from && a.ex_date <= to)
+            .cloned()
+            .collect()
+    }
+
+    /// The adjustments made so far, in the order they were made.
+    pub fn log(&self) -> &[AdjustmentEvent] {
+        &self.log
+    }
+
+    pub fn clear_log(&mut self) {
+        self.log.clear();
+    }
+
+    fn record(
+        &mut self,
+        action: CorporateAction,
+        target: AdjustmentTarget,
+        before: f64,
+        after: f64,
+    ) {
+        self.log.push(AdjustmentEvent {
+            action,
+            target,
+            before,
+            after,
+        });
+    }
+
+    /// `fixings` back-adjusted to be comparable with prices after the last
+    /// action: fixings before each ex date are scaled by its price factor,
+    /// the cum price being the last fixing before it. Actions before the
+    /// first fixing are ignored.
+    pub fn adjust_fixings(&mut self, fixings: &BTreeMap<Date, f64>) -> BTreeMap<Date, f64> {
+        let mut adjusted = fixings.clone();
+        for action in self.actions.clone() {
+            let cum = match adjusted.range(..action.ex_date).next_back() {
+                Some((_, f)) => *f,
+                None => continue,
+            };
+            let factor = action.price_factor(cum);
+            for (_, f) in adjusted.range_mut(..action.ex_date) {
+                *f *= factor;
+            }
+            self.record(action, AdjustmentTarget::FixingHistory, cum, cum * factor);
+        }
+        adjusted
+    }
+
+    /// The terms of `option` after the actions going ex after `from` and
+    /// up to its exercise, with the number of adjusted options replacing
+    /// one original option.
+    ///
+    /// Splits divide the strike and multiply the options, special
+    /// dividends are taken off the strike and spin-offs scale it by the
+    /// retained fraction (the ratio method).
+    pub fn adjust_option(&mut self, option: &VanillaOption, from: Date) -> (VanillaOption, f64) {
+        let mut adjusted = *option;
+        let mut multiplier = 1.0;
+        for action in self.between(from, option.exercise_date) {
+            let before = adjusted.strike;
+            match action.kind {
+                CorporateActionKind::Split { ratio } => {
+                    adjusted.strike /= ratio;
+                    multiplier *= ratio;
+                }
+                CorporateActionKind::SpecialDividend { amount } => {
+                    adjusted.strike = (adjusted.strike - amount).max(0.0);
+                }
+                CorporateActionKind::SpinOff { retained } => adjusted.strike *= retained,
+            }
+            self.record(action, AdjustmentTarget::Strike, before, adjusted.strike);
+        }
+        (adjusted, multiplier)
+    }
+
+    /// The forward for `maturity` of the price quoted then, from `forward`,
+    /// the forward of the unadjusted price, and the actions going ex after
+    /// `from`. `growth(d)` is the carry of the forward from `d` to
+    /// `maturity`, by which special dividends paid on `d` grow.
+    pub fn adjust_forward<F: Fn(Date) -> f64>(
+        &mut self,
+        forward: f64,
+        from: Date,
+        maturity: Date,
+        growth: F,
+    ) -> f64 {
+        let mut adjusted = forward;
+        for action in self.between(from, maturity) {
+            let before = adjusted;
+            adjusted = match action.kind {
+                CorporateActionKind::Split { ratio } => adjusted / ratio,
+                CorporateActionKind::SpecialDividend { amount } => {
+                    adjusted - amount * growth(action.ex_date)
+                }
+                CorporateActionKind::SpinOff { retained } => adjusted * retained,
+            };
+            assert!(adjusted > 0.0, "non positive adjusted forward");
+            self.record(action, AdjustmentTarget::Forward, before, adjusted);
+        }
+        adjusted
+    }
+}
@@ -0,0 +1,67 @@
+/// Member of an equity index with its share of the index value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constituent {
+    pub name: String,
+    pub weight: f64,
+    pub spot: f64,
+}
+
+impl Constituent {
+    pub fn new(name: &str, weight: f64, spot: f64) -> Constituent {
+        assert!(weight >= 0.0, "negative weight");
+        assert!(spot > 0.0, "non positive spot");
+        Constituent {
+            name: name.to_string(),
+            weight,
+            spot,
+        }
+    }
+}
+
+/// Equity index as a basket of constituents; weights are normalized to
+/// sum to one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityIndex {
+    pub name: String,
+    pub level: f64,
+    pub constituents: Vec<Constituent>,
+}
+
+impl EquityIndex {
+    pub fn new(name: &str, level: f64, constituents: Vec<Constituent>) -> EquityIndex {
+        assert!(level > 0.0, "non positive index level");
+        let total: f64 = constituents.iter().map(|c| c.weight).sum();
+        assert!(total > 0.0, "no weighted constituents given");
+        let constituents = constituents
+            .into_iter()
+            .map(|c| Constituent {
+                weight: c.weight / total,
+                ..c
+            })
+            .collect();
+        EquityIndex {
+            name: name.to_string(),
+            level,
+            constituents,
+        }
+    }
+
+    pub fn weights(&self) -> Vec<f64> {
+        self.constituents.iter().map(|c| c.weight).collect()
+    }
+
+    /// The index level implied by moving each constituent to `spots`.
+    pub fn level_at(&self, spots: &[f64]) -> f64 {
+        assert!(
+            spots.len() == self.constituents.len(),
+            "one spot per constituent needed"
+        );
+        self.level
+            * self
+                .constituents
+                .iter()
+                .zip(spots)
+                .map(|(c, s)| c.weight * s / c.spot)
+                .sum::<f64>()
+    }
+}
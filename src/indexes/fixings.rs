@@ -0,0 +1,33 @@
+use super::traits::InterestRateIndex;
+use crate::errors::QuantLibError;
+use crate::time::Date;
+
+/// What to do when a fixing dated before the evaluation date was never
+/// stored on an index.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MissingFixingPolicy {
+    /// Report the missing fixing.
+    Error,
+    /// Forecast the fixing from the index curve as if it were in the
+    /// future.
+    UseForecast,
+    /// Use the latest fixing stored before the missing one.
+    UseLastAvailable,
+}
+
+/// Resolves the fixings of `index` at each of `dates`, reporting every one
+/// that cannot be rather than only the first.
+pub fn check_fixings<I: InterestRateIndex>(
+    index: &I,
+    dates: &[Date],
+) -> Result<(), Vec<QuantLibError>> {
+    let errors: Vec<QuantLibError> = dates
+        .iter()
+        .filter_map(|&d| index.checked_fixing(d).err())
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,253 @@
+use super::indexmanager::IndexManager;
+use super::traits::{Index, InterestRateIndex};
+use crate::currencies::Currency;
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::patterns::{ChangeFlag, Observable, Observer};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual360, BusinessDayConvention, Calendar, Date, DayCounter, Period, TimeUnit};
+use std::rc::Rc;
+
+/// Interbank offered rate index, e.g. Euribor or Stibor, forecast from a
+/// forwarding curve.
+pub struct IborIndex<C: Cal, Y: YieldTermStructure, DC: DayCounter> {
+    pub family_name: String,
+    pub tenor: Period,
+    pub fixing_days: i64,
+    pub fixing_calendar: Calendar<C>,
+    pub convention: BusinessDayConvention,
+    pub end_of_month: bool,
+    pub day_counter: DC,
+    pub forwarding_curve: Option<Rc<Y>>,
+    changes: Rc<ChangeFlag>,
+}
+
+impl<C, Y, DC> IborIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    pub fn new(
+        family_name: &str,
+        tenor: Period,
+        fixing_days: i64,
+        fixing_calendar: Calendar<C>,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        day_counter: DC,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, DC> {
+        let changes = ChangeFlag::new();
+        if let Some(observable) = forwarding_curve.as_ref().and_then(|c| c.observable()) {
+            observable.register_observer(Rc::clone(&changes) as Rc<dyn Observer>);
+        }
+        let index = IborIndex {
+            family_name: family_name.to_string(),
+            tenor,
+            fixing_days,
+            fixing_calendar,
+            convention,
+            end_of_month,
+            day_counter,
+            forwarding_curve,
+            changes,
+        };
+        IndexManager::observable(&index.name())
+            .register_observer(Rc::clone(&index.changes) as Rc<dyn Observer>);
+        index
+    }
+
+    /// Ibor index fixing at the spot lag of `currency`, e.g. same day for
+    /// GBP Libor and two business days for Euribor.
+    pub fn for_currency(
+        family_name: &str,
+        tenor: Period,
+        currency: Currency,
+        fixing_calendar: Calendar<C>,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        day_counter: DC,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, DC> {
+        IborIndex::new(
+            family_name,
+            tenor,
+            currency.spot_lag(),
+            fixing_calendar,
+            convention,
+            end_of_month,
+            day_counter,
+            forwarding_curve,
+        )
+    }
+
+    /// Overnight index, e.g. ESTR or SOFR, fixing a one business day rate
+    /// with no fixing lag.
+    pub fn overnight(
+        family_name: &str,
+        fixing_calendar: Calendar<C>,
+        day_counter: DC,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, DC> {
+        IborIndex::new(
+            family_name,
+            Period::new(1, TimeUnit::Days),
+            0,
+            fixing_calendar,
+            BusinessDayConvention::Following,
+            false,
+            day_counter,
+            forwarding_curve,
+        )
+    }
+
+    pub fn is_overnight(&self) -> bool {
+        self.tenor == Period::new(1, TimeUnit::Days)
+    }
+}
+
+/// Overnight indexes are Ibor indexes with a one day tenor.
+pub type OvernightIndex<C, Y, DC = Actual360> = IborIndex<C, Y, DC>;
+
+/// Market conventions of the common Ibor and overnight indexes, on the
+/// given fixing calendar (TARGET, London or New York in practice).
+impl<C, Y> IborIndex<C, Y, Actual360>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+{
+    /// Euribor: two fixing days, modified following and end of month
+    /// adjustments for monthly tenors, following for weekly ones.
+    pub fn euribor(
+        tenor: Period,
+        fixing_calendar: Calendar<C>,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, Actual360> {
+        IborIndex::interbank("Euribor", tenor, fixing_calendar, forwarding_curve)
+    }
+
+    /// USD Libor, with the same conventions as Euribor.
+    pub fn usd_libor(
+        tenor: Period,
+        fixing_calendar: Calendar<C>,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, Actual360> {
+        IborIndex::interbank("USDLibor", tenor, fixing_calendar, forwarding_curve)
+    }
+
+    pub fn sofr(
+        fixing_calendar: Calendar<C>,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> OvernightIndex<C, Y> {
+        IborIndex::overnight("SOFR", fixing_calendar, Actual360, forwarding_curve)
+    }
+
+    pub fn estr(
+        fixing_calendar: Calendar<C>,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> OvernightIndex<C, Y> {
+        IborIndex::overnight("ESTR", fixing_calendar, Actual360, forwarding_curve)
+    }
+
+    fn interbank(
+        family_name: &str,
+        tenor: Period,
+        fixing_calendar: Calendar<C>,
+        forwarding_curve: Option<Rc<Y>>,
+    ) -> IborIndex<C, Y, Actual360> {
+        let monthly = matches!(tenor.units, TimeUnit::Months | TimeUnit::Years);
+        let convention = if monthly {
+            BusinessDayConvention::ModifiedFollowing
+        } else {
+            BusinessDayConvention::Following
+        };
+        IborIndex::new(
+            family_name,
+            tenor,
+            2,
+            fixing_calendar,
+            convention,
+            monthly,
+            Actual360,
+            forwarding_curve,
+        )
+    }
+}
+
+impl<C, Y, DC> Index for IborIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    fn name(&self) -> String {
+        if self.is_overnight() {
+            format!("{}ON", self.family_name)
+        } else {
+            format!("{}{}", self.family_name, self.tenor)
+        }
+    }
+    fn is_valid_fixing_date(&self, date: Date) -> bool {
+        self.fixing_calendar.is_business_day(date)
+    }
+    fn fixing(&self, fixing_date: Date) -> Rate {
+        self.checked_fixing(fixing_date)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    fn observable(&self) -> Option<&Observable> {
+        Some(self.changes.observable())
+    }
+}
+
+impl<C, Y, DC> InterestRateIndex for IborIndex<C, Y, DC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+{
+    fn tenor(&self) -> Period {
+        self.tenor
+    }
+    fn fixing_days(&self) -> i64 {
+        self.fixing_days
+    }
+    fn value_date(&self, fixing_date: Date) -> Date {
+        self.fixing_calendar
+            .advance_by_units(fixing_date, self.fixing_days, TimeUnit::Days)
+    }
+    /// Same day indexes fix on the business day on or before a holiday
+    /// value date.
+    fn fixing_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            -self.fixing_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Preceding,
+            false,
+        )
+    }
+    fn maturity_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            self.tenor.length,
+            self.tenor.units,
+            self.convention,
+            self.end_of_month,
+        )
+    }
+    fn forecast_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError> {
+        let curve = self
+            .forwarding_curve
+            .as_ref()
+            .ok_or_else(|| QuantLibError::Uninitialized {
+                what: format!("forwarding curve of {}", self.name()),
+            })?;
+        let d1 = self.value_date(fixing_date);
+        let d2 = self.maturity_date(d1);
+        let t = self.day_counter.year_fraction(d1, d2, None, None);
+        assert!(t > 0.0, "non positive index period");
+        Ok((curve.discount(d1, true) / curve.discount(d2, true) - 1.0) / t)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::patterns::Observable;
+use crate::time::Date;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// The fixing histories of the indexes of the calling thread, by index
+/// name.
+///
+/// Indexes store no fixings of their own: every index with a given name,
+/// e.g. the Euribor6M forecasting a curve and the one of a coupon, reads
+/// and adds the same history, so that a fixing added through any of them
+/// is seen by all. Indexes hold `Rc`s and stay on their thread, and so do
+/// the histories; each thread starts with none.
+pub struct IndexManager;
+
+#[derive(Default)]
+struct History {
+    fixings: BTreeMap<Date, f64>,
+    observable: Rc<Observable>,
+}
+
+thread_local! {
+    static HISTORIES: RefCell<BTreeMap<String, History>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+impl IndexManager {
+    /// Stores the fixing of `name` at `date`, replacing any previous one,
+    /// and notifies the observers of `name`.
+    pub fn add_fixing(name: &str, date: Date, fixing: f64) {
+        let observable = HISTORIES.with(|h| {
+            let mut histories = h.borrow_mut();
+            let history = histories.entry(name.to_string()).or_default();
+            history.fixings.insert(date, fixing);
+            Rc::clone(&history.observable)
+        });
+        observable.notify_observers();
+    }
+
+    /// The stored fixing of `name` at `date`.
+    pub fn fixing(name: &str, date: Date) -> Option<f64> {
+        HISTORIES.with(|h| {
+            h.borrow()
+                .get(name)
+                .and_then(|history| history.fixings.get(&date).cloned())
+        })
+    }
+
+    /// The latest stored fixing of `name` on or before `date`.
+    pub fn last_fixing(name: &str, date: Date) -> Option<(Date, f64)> {
+        HISTORIES.with(|h| {
+            h.borrow().get(name).and_then(|history| {
+                history
+                    .fixings
+                    .range(..=date)
+                    .next_back()
+                    .map(|(d, f)| (*d, *f))
+            })
+        })
+    }
+
+    /// A copy of the stored fixings of `name` by date.
+    pub fn history(name: &str) -> BTreeMap<Date, f64> {
+        HISTORIES.with(|h| {
+            h.borrow()
+                .get(name)
+                .map(|history| history.fixings.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn has_history(name: &str) -> bool {
+        HISTORIES.with(|h| {
+            h.borrow()
+                .get(name)
+                .is_some_and(|history| !history.fixings.is_empty())
+        })
+    }
+
+    /// Removes the fixings of `name` and notifies its observers.
+    pub fn clear_history(name: &str) {
+        let observable = HISTORIES.with(|h| {
+            h.borrow_mut().get_mut(name).map(|history| {
+                history.fixings.clear();
+                Rc::clone(&history.observable)
+            })
+        });
+        if let Some(observable) = observable {
+            observable.notify_observers();
+        }
+    }
+
+    /// Removes the fixings of every index.
+    pub fn clear_histories() {
+        let names: Vec<String> = HISTORIES.with(|h| h.borrow().keys().cloned().collect());
+        for name in names {
+            IndexManager::clear_history(&name);
+        }
+    }
+
+    /// Notifies of the fixings added to or cleared from `name`.
+    pub fn observable(name: &str) -> Rc<Observable> {
+        HISTORIES.with(|h| {
+            Rc::clone(
+                &h.borrow_mut()
+                    .entry(name.to_string())
+                    .or_default()
+                    .observable,
+            )
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use super::traits::Index;
+use crate::errors::QuantLibError;
+use crate::termstructures::ZeroInflationCurve;
+use crate::time::Date;
+use std::rc::Rc;
+
+/// Consumer price index published monthly, e.g. IPCA. Fixings are keyed
+/// by the first day of the reference month.
+pub struct ZeroInflationIndex {
+    pub family_name: String,
+    /// Forecasts the months not yet fixed.
+    pub inflation_curve: Option<Rc<ZeroInflationCurve>>,
+}
+
+impl ZeroInflationIndex {
+    pub fn new(family_name: &str) -> ZeroInflationIndex {
+        ZeroInflationIndex {
+            family_name: family_name.to_string(),
+            inflation_curve: None,
+        }
+    }
+
+    pub fn with_inflation_curve(mut self, curve: Rc<ZeroInflationCurve>) -> ZeroInflationIndex {
+        self.inflation_curve = Some(curve);
+        self
+    }
+
+    /// The Brazilian IPCA (Índice Nacional de Preços ao Consumidor Amplo).
+    pub fn ipca() -> ZeroInflationIndex {
+        ZeroInflationIndex::new("IPCA")
+    }
+
+    /// The euro area HICP excluding tobacco.
+    pub fn hicpx() -> ZeroInflationIndex {
+        ZeroInflationIndex::new("HICPx")
+    }
+
+    /// The French CPI excluding tobacco.
+    pub fn french_cpi() -> ZeroInflationIndex {
+        ZeroInflationIndex::new("FRCPI")
+    }
+
+    /// The UK retail price index, which LPI indexation is collared on.
+    pub fn uk_rpi() -> ZeroInflationIndex {
+        ZeroInflationIndex::new("UKRPI")
+    }
+
+    /// The first day of the month of `date`.
+    pub fn reference_month(date: Date) -> Date {
+        Date::new(1, date.month(), date.year() as i32)
+    }
+
+    /// The fixing of the month `months` before the month of `date`.
+    pub fn lagged_fixing(&self, date: Date, months: i64) -> f64 {
+        self.fixing(ZeroInflationIndex::reference_month(date).add_months(-months))
+    }
+
+    /// The fixing of the month of `date` if published, else its forecast
+    /// off the inflation curve from the fixing of the curve's base month.
+    pub fn forecast_fixing(&self, date: Date) -> Result<f64, QuantLibError> {
+        let month = ZeroInflationIndex::reference_month(date);
+        if let Some(f) = self.stored_fixing(month) {
+            return Ok(f);
+        }
+        let curve = self
+            .inflation_curve
+            .as_ref()
+            .ok_or_else(|| QuantLibError::Uninitialized {
+                what: format!("inflation curve of {}", self.family_name),
+            })?;
+        Ok(self.fixing(curve.base_date) * curve.index_ratio(month))
+    }
+}
+
+impl Index for ZeroInflationIndex {
+    fn name(&self) -> String {
+        self.family_name.clone()
+    }
+    fn is_valid_fixing_date(&self, date: Date) -> bool {
+        date.day_of_month() == 1
+    }
+    /// The fixing of the month of `fixing_date`. Price indexes are not
+    /// forecast, so projected values must be added as fixings.
+    fn fixing(&self, fixing_date: Date) -> f64 {
+        let month = ZeroInflationIndex::reference_month(fixing_date);
+        match self.stored_fixing(month) {
+            Some(f) => f,
+            None => panic!(
+                "missing {} fixing for {:?} {}",
+                self.family_name,
+                month.month(),
+                month.year()
+            ),
+        }
+    }
+}
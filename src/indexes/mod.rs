@@ -0,0 +1,23 @@
+pub mod bmaindex;
+pub mod cdiindex;
+pub mod corporateactions;
+pub mod equityindex;
+pub mod fixings;
+pub mod iborindex;
+pub mod indexmanager;
+pub mod inflationindex;
+pub mod swapindex;
+pub mod traits;
+
+pub use self::bmaindex::BmaIndex;
+pub use self::cdiindex::CdiIndex;
+pub use self::corporateactions::{
+    AdjustmentEvent, AdjustmentTarget, CorporateAction, CorporateActionKind, CorporateActions,
+};
+pub use self::equityindex::{Constituent, EquityIndex};
+pub use self::fixings::{check_fixings, MissingFixingPolicy};
+pub use self::iborindex::{IborIndex, OvernightIndex};
+pub use self::indexmanager::IndexManager;
+pub use self::inflationindex::ZeroInflationIndex;
+pub use self::swapindex::{SwapIndex, SwapIndexSwap};
+pub use self::traits::{Index, InterestRateIndex};
@@ -0,0 +1,206 @@
+use super::traits::{Index, InterestRateIndex};
+use super::IborIndex;
+use crate::cashflows::{Base, FixedRateCoupon, IborCoupon, IborLeg};
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::instruments::{SwapType, VanillaSwap};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::{Compounding, InterestRate};
+use crate::time::traits::Calendar as Cal;
+use crate::time::{
+    BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Frequency, Period, Schedule,
+    TimeUnit,
+};
+use std::rc::Rc;
+
+/// The swap underlying a swap index fixing.
+pub type SwapIndexSwap<C, Y, DC, IDC> =
+    VanillaSwap<FixedRateCoupon<DC>, IborCoupon<IborIndex<C, Y, IDC>, IDC>>;
+
+/// Swap rate index, e.g. EUR 10Y vs 6M Euribor: the fair fixed rate of a
+/// spot-starting swap of the given `tenor` against an Ibor index.
+///
+/// Rates are forecast from the forwarding curve of the Ibor index and the
+/// discounting curve, which defaults to the same curve.
+pub struct SwapIndex<C: Cal, Y: YieldTermStructure, DC: DayCounter, IDC: DayCounter> {
+    pub family_name: String,
+    pub tenor: Period,
+    pub fixing_days: i64,
+    pub fixing_calendar: Calendar<C>,
+    pub fixed_leg_tenor: Period,
+    pub fixed_leg_convention: BusinessDayConvention,
+    pub fixed_leg_day_counter: DC,
+    pub ibor_index: Rc<IborIndex<C, Y, IDC>>,
+    pub discounting_curve: Option<Rc<Y>>,
+}
+
+impl<C, Y, DC, IDC> SwapIndex<C, Y, DC, IDC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+    IDC: DayCounter,
+{
+    pub fn new(
+        family_name: &str,
+        tenor: Period,
+        fixing_days: i64,
+        fixing_calendar: Calendar<C>,
+        fixed_leg_tenor: Period,
+        fixed_leg_convention: BusinessDayConvention,
+        fixed_leg_day_counter: DC,
+        ibor_index: Rc<IborIndex<C, Y, IDC>>,
+    ) -> SwapIndex<C, Y, DC, IDC> {
+        SwapIndex {
+            family_name: family_name.to_string(),
+            tenor,
+            fixing_days,
+            fixing_calendar,
+            fixed_leg_tenor,
+            fixed_leg_convention,
+            fixed_leg_day_counter,
+            ibor_index,
+            discounting_curve: None,
+        }
+    }
+
+    /// Discounts the underlying swap on `curve` instead of the forwarding
+    /// curve of the Ibor index.
+    pub fn with_discounting_curve(mut self, curve: Rc<Y>) -> SwapIndex<C, Y, DC, IDC> {
+        self.discounting_curve = Some(curve);
+        self
+    }
+
+    /// The discounting curve, by default the forwarding curve of the Ibor
+    /// index.
+    pub fn discount_curve(&self) -> Result<&Y, QuantLibError> {
+        self.discounting_curve
+            .as_ref()
+            .or(self.ibor_index.forwarding_curve.as_ref())
+            .map(Rc::as_ref)
+            .ok_or_else(|| QuantLibError::Uninitialized {
+                what: format!("discounting curve of {}", self.name()),
+            })
+    }
+
+    /// The payer swap of unit notional fixed at `fixing_date` and paying
+    /// `fixed_rate`.
+    pub fn underlying_swap(
+        &self,
+        fixing_date: Date,
+        fixed_rate: Rate,
+    ) -> SwapIndexSwap<C, Y, DC, IDC> {
+        let start = self.value_date(fixing_date);
+        let end = self.maturity_date(start);
+        let schedule = |tenor: Period, convention: BusinessDayConvention| {
+            Schedule::new(
+                start,
+                end,
+                tenor,
+                self.fixing_calendar,
+                convention,
+                convention,
+                DateGenerator::Backward,
+                self.ibor_index.end_of_month,
+            )
+        };
+
+        let fixed_schedule = schedule(self.fixed_leg_tenor, self.fixed_leg_convention);
+        let rate = InterestRate::new(
+            fixed_rate,
+            self.fixed_leg_day_counter,
+            Compounding::Simple,
+            Frequency::Annual,
+        );
+        let fixed_leg = fixed_schedule
+            .dates
+            .windows(2)
+            .map(|w| {
+                let base = Base {
+                    nominal: 1.0,
+                    day_counter: self.fixed_leg_day_counter,
+                    payment_date: w[1],
+                    accrual_start_date: w[0],
+                    accrual_end_date: w[1],
+                    reference_period_start: w[0],
+                    reference_period_end: w[1],
+                };
+                FixedRateCoupon::new(base, rate)
+            })
+            .collect();
+
+        let floating_schedule = schedule(self.ibor_index.tenor, self.ibor_index.convention);
+        let floating_leg = IborLeg::new(
+            floating_schedule,
+            Rc::clone(&self.ibor_index),
+            self.ibor_index.day_counter,
+        )
+        .build();
+        VanillaSwap::new(SwapType::Payer, fixed_leg, fixed_rate, floating_leg, 0.0)
+    }
+}
+
+impl<C, Y, DC, IDC> Index for SwapIndex<C, Y, DC, IDC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+    IDC: DayCounter,
+{
+    fn name(&self) -> String {
+        format!("{}{}", self.family_name, self.tenor)
+    }
+    fn is_valid_fixing_date(&self, date: Date) -> bool {
+        self.fixing_calendar.is_business_day(date)
+    }
+    fn fixing(&self, fixing_date: Date) -> Rate {
+        self.checked_fixing(fixing_date)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<C, Y, DC, IDC> InterestRateIndex for SwapIndex<C, Y, DC, IDC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+    IDC: DayCounter,
+{
+    fn tenor(&self) -> Period {
+        self.tenor
+    }
+    fn fixing_days(&self) -> i64 {
+        self.fixing_days
+    }
+    fn value_date(&self, fixing_date: Date) -> Date {
+        self.fixing_calendar
+            .advance_by_units(fixing_date, self.fixing_days, TimeUnit::Days)
+    }
+    fn fixing_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            -self.fixing_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Preceding,
+            false,
+        )
+    }
+    fn maturity_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            self.tenor.length,
+            self.tenor.units,
+            self.fixed_leg_convention,
+            self.ibor_index.end_of_month,
+        )
+    }
+    fn forecast_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError> {
+        if self.ibor_index.forwarding_curve.is_none() {
+            return Err(QuantLibError::Uninitialized {
+                what: format!("forwarding curve of {}", self.ibor_index.name()),
+            });
+        }
+        let swap = self.underlying_swap(fixing_date, 0.0);
+        Ok(swap.fair_rate(self.discount_curve()?, self.value_date(fixing_date)))
+    }
+}
@@ -0,0 +1,85 @@
+use super::fixings::MissingFixingPolicy;
+use super::indexmanager::IndexManager;
+use crate::definitions::Rate;
+use crate::errors::QuantLibError;
+use crate::patterns::Observable;
+use crate::settings::PricingContext;
+use crate::time::{Date, Period};
+use std::collections::BTreeMap;
+
+/// An index whose value is observed (fixed) on given dates.
+pub trait Index {
+    fn name(&self) -> String;
+    /// Whether the index can be fixed on `date`.
+    fn is_valid_fixing_date(&self, date: Date) -> bool;
+    /// The stored fixing at `fixing_date` if any, a forecast otherwise.
+    fn fixing(&self, fixing_date: Date) -> Rate;
+    /// Stores a past fixing in the history shared by the indexes of the
+    /// same name.
+    fn add_fixing(&self, fixing_date: Date, fixing: Rate) {
+        assert!(
+            self.is_valid_fixing_date(fixing_date),
+            "invalid fixing date for {}",
+            self.name()
+        );
+        IndexManager::add_fixing(&self.name(), fixing_date, fixing);
+    }
+    /// The stored fixings by fixing date.
+    fn fixing_history(&self) -> BTreeMap<Date, Rate> {
+        IndexManager::history(&self.name())
+    }
+    /// The stored fixing at `fixing_date`, if any.
+    fn stored_fixing(&self, fixing_date: Date) -> Option<Rate> {
+        IndexManager::fixing(&self.name(), fixing_date)
+    }
+    /// Notifies of new fixings and of changes of the forecasting curve.
+    fn observable(&self) -> Option<&Observable> {
+        None
+    }
+    /// The latest stored fixing on or before `date`.
+    fn last_fixing(&self, date: Date) -> Option<(Date, Rate)> {
+        IndexManager::last_fixing(&self.name(), date)
+    }
+}
+
+/// An index fixing an interest rate over a tenor starting at the value
+/// date.
+pub trait InterestRateIndex: Index {
+    fn tenor(&self) -> Period;
+    fn fixing_days(&self) -> i64;
+    /// The start of the deposit period fixed at `fixing_date`.
+    fn value_date(&self, fixing_date: Date) -> Date;
+    /// The fixing date of the deposit period starting at `value_date`.
+    fn fixing_date(&self, value_date: Date) -> Date;
+    /// The end of the deposit period starting at `value_date`.
+    fn maturity_date(&self, value_date: Date) -> Date;
+    /// The rate implied by the forwarding curve for `fixing_date`, or an
+    /// error if the index has no curve to forecast on.
+    fn forecast_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError>;
+
+    /// The stored fixing at `fixing_date`, or a forecast from the
+    /// evaluation date of the current `PricingContext` on. Fixings missing
+    /// before it are resolved by the context's `MissingFixingPolicy`.
+    fn checked_fixing(&self, fixing_date: Date) -> Result<Rate, QuantLibError> {
+        if let Some(f) = self.stored_fixing(fixing_date) {
+            return Ok(f);
+        }
+        let context = PricingContext::current();
+        if fixing_date >= context.evaluation_date {
+            return self.forecast_fixing(fixing_date);
+        }
+        let error = QuantLibError::MissingFixing {
+            index: self.name(),
+            fixing_date,
+            evaluation_date: context.evaluation_date,
+            policy: context.missing_fixing_policy,
+        };
+        match context.missing_fixing_policy {
+            MissingFixingPolicy::Error => Err(error),
+            MissingFixingPolicy::UseForecast => self.forecast_fixing(fixing_date),
+            MissingFixingPolicy::UseLastAvailable => {
+                self.last_fixing(fixing_date).map(|(_, f)| f).ok_or(error)
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::cashflows::{self as cf, CashFlow, Leg};
+use crate::definitions::Rate;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::{Date, DayCounter};
+
+/// How the floating leg of an asset swap is sized.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AssetSwapType {
+    /// The investor pays par for the bond and the floating leg runs on
+    /// the bond notional.
+    ParPar,
+    /// The investor pays the dirty price and the floating leg runs on the
+    /// proceeds, with the notionals exchanged at maturity.
+    Proceeds,
+}
+
+/// Asset swap package on a fixed rate bond: the investor buys the bond
+/// and pays its coupons away against the floating index plus a spread.
+///
+/// `bond_cashflows` are the bond coupons, the notional being redeemed on
+/// the last of them; `floating_dates` are the floating leg period dates,
+/// starting on the settlement date of the package. Values are forward
+/// values at the settlement date.
+pub struct AssetSwap<CF: CashFlow, DC: DayCounter> {
+    pub asset_swap_type: AssetSwapType,
+    pub bond_cashflows: Leg<CF>,
+    pub notional: f64,
+    /// Dirty price in percent of the notional.
+    pub dirty_price: f64,
+    pub floating_dates: Vec<Date>,
+    pub floating_day_counter: DC,
+}
+
+impl<CF: CashFlow, DC: DayCounter> AssetSwap<CF, DC> {
+    pub fn new(
+        asset_swap_type: AssetSwapType,
+        bond_cashflows: Leg<CF>,
+        notional: f64,
+        dirty_price: f64,
+        floating_dates: Vec<Date>,
+        floating_day_counter: DC,
+    ) -> AssetSwap<CF, DC> {
+        assert!(!bond_cashflows.is_empty(), "no bond cash flows given");
+        assert!(floating_dates.len() >= 2, "no floating periods given");
+        assert!(dirty_price > 0.0, "non positive bond price");
+        AssetSwap {
+            asset_swap_type,
+            bond_cashflows,
+            notional,
+            dirty_price,
+            floating_dates,
+            floating_day_counter,
+        }
+    }
+
+    pub fn settlement_date(&self) -> Date {
+        self.floating_dates[0]
+    }
+
+    pub fn maturity_date(&self) -> Date {
+        self.bond_cashflows.iter().map(|c| c.date()).max().unwrap()
+    }
+
+    /// Dirty price of the bond off `discount_curve`, in percent.
+    pub fn model_price<Y: YieldTermStructure>(&self, discount_curve: &Y) -> f64 {
+        let settlement = self.settlement_date();
+        let value = cf::npv(&self.bond_cashflows, discount_curve, false, settlement)
+            + self.notional * discount_curve.discount(self.maturity_date(), true);
+        100.0 * value / (self.notional * discount_curve.discount(settlement, true))
+    }
+
+    /// Value of the floating leg without spread on a unit notional, and
+    /// its annuity.
+    fn floating_leg<Y: YieldTermStructure, F: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        forwarding_curve: &F,
+    ) -> (f64, f64) {
+        let settlement = discount_curve.discount(self.settlement_date(), true);
+        self.floating_dates
+            .windows(2)
+            .fold((0.0, 0.0), |(value, annuity), w| {
+                let tau = self
+                    .floating_day_counter
+                    .year_fraction(w[0], w[1], None, None);
+                let growth =
+                    forwarding_curve.discount(w[0], true) / forwarding_curve.discount(w[1], true);
+                let discount = discount_curve.discount(w[1], true) / settlement;
+                (value + discount * (growth - 1.0), annuity + tau * discount)
+            })
+    }
+
+    /// The spread over the floating index making the package worth zero.
+    pub fn spread<Y: YieldTermStructure, F: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        forwarding_curve: &F,
+    ) -> Rate {
+        let settlement = self.settlement_date();
+        let coupons = cf::npv(&self.bond_cashflows, discount_curve, false, settlement)
+            / (self.notional * discount_curve.discount(settlement, true));
+        let (floating, annuity) = self.floating_leg(discount_curve, forwarding_curve);
+        let price = self.dirty_price / 100.0;
+        match self.asset_swap_type {
+            AssetSwapType::ParPar => (coupons + 1.0 - price - floating) / annuity,
+            AssetSwapType::Proceeds => {
+                let redemption = discount_curve.discount(self.maturity_date(), true)
+                    / discount_curve.discount(settlement, true);
+                (coupons + (1.0 - price) * redemption - price * floating) / (price * annuity)
+            }
+        }
+    }
+
+    /// Par swap rate with a fixed leg on the bond coupon periods against
+    /// the floating leg of the package.
+    pub fn matched_swap_rate<Y: YieldTermStructure, F: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        forwarding_curve: &F,
+    ) -> Rate {
+        let settlement = self.settlement_date();
+        let annuity: f64 = self
+            .bond_cashflows
+            .iter()
+            .filter(|c| c.date() > settlement)
+            .filter_map(|c| c.try_as_coup())
+            .map(|c| c.accrual_period() * discount_curve.discount(c.date(), true))
+            .sum::<f64>()
+            / discount_curve.discount(settlement, true);
+        assert!(annuity > 0.0, "no coupons after settlement");
+        let (floating, _) = self.floating_leg(discount_curve, forwarding_curve);
+        floating / annuity
+    }
+
+    /// Matched maturity swap spread: the bond yield over the par swap rate
+    /// of the same coupon schedule.
+    pub fn swap_spread<Y: YieldTermStructure, F: YieldTermStructure>(
+        &self,
+        bond_yield: Rate,
+        discount_curve: &Y,
+        forwarding_curve: &F,
+    ) -> Rate {
+        bond_yield - self.matched_swap_rate(discount_curve, forwarding_curve)
+    }
+}
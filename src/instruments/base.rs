@@ -10,12 +10,20 @@ pub struct Base<PE: PricingEngine> {
     lazy: LazyObject,
     npv: Money,
     error_estimate: Money,
-    valuation_date: Date,
+    valuation_date: Option<Date>,
     additional_results: HashMap<String, Money>,
     engine: PE,
     has_engine: bool,
 }
 
+impl<PE: PricingEngine> Base<PE> {
+    /// The recalculation state, to register with market data and to
+    /// freeze or unfreeze the cached results.
+    pub fn lazy(&self) -> &LazyObject {
+        &self.lazy
+    }
+}
+
 impl<PE> Instrument for Base<PE>
 where
     PE: PricingEngine,
@@ -36,8 +44,7 @@ where
     /// returns the date the net present value refers to.
     fn valuation_date(&mut self) -> Date {
         self.calculate();
-        assert!(self.valuation_date != Date::default());
-        self.valuation_date
+        self.valuation_date.expect("valuation date not provided")
     }
     /// returns any additional result returned by the pricing engine.
     fn result(&mut self, tag: String) -> Result<Money, &str> {
@@ -88,23 +95,20 @@ where
     }
 
     fn calculate(&mut self) {
-        if !self.lazy.calculated {
+        if self.lazy.calculate() {
             if self.is_expired() {
                 self.setup_expired();
-                self.lazy.calculated = true;
             } else {
-                self.lazy.calculate();
+                self.perform_calculations();
             }
         }
     }
-    ///
     fn setup_expired(&mut self) {
         self.npv = Money::default();
         self.error_estimate = Money::default();
-        self.valuation_date = Date::default();
+        self.valuation_date = None;
         self.additional_results.clear();
     }
-    ///
     fn perform_calculations(&mut self) {
         assert!(self.has_engine);
         self.engine.reset();
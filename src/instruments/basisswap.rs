@@ -0,0 +1,105 @@
+use crate::cashflows::{self as cf, CashFlow, Leg};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+use crate::utils::trace::Level;
+
+/// Side of a swap, named after the leg being paid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SwapType {
+    Payer,
+    Receiver,
+}
+
+/// Single-currency floating-for-floating swap, e.g. a 3M vs 6M Ibor tenor
+/// basis swap or an overnight vs Ibor basis swap.
+///
+/// The basis `spread` is paid on top of the index of the spread leg, whose
+/// coupons must have been built with it; `Payer` pays the spread leg.
+pub struct BasisSwap<CF1: CashFlow, CF2: CashFlow> {
+    pub swap_type: SwapType,
+    pub spread_leg: Leg<CF1>,
+    pub other_leg: Leg<CF2>,
+    pub spread: f64,
+}
+
+impl<CF1: CashFlow, CF2: CashFlow> BasisSwap<CF1, CF2> {
+    pub fn new(
+        swap_type: SwapType,
+        spread_leg: Leg<CF1>,
+        other_leg: Leg<CF2>,
+        spread: f64,
+    ) -> BasisSwap<CF1, CF2> {
+        assert!(
+            !spread_leg.is_empty() && !other_leg.is_empty(),
+            "empty swap leg"
+        );
+        BasisSwap {
+            swap_type,
+            spread_leg,
+            other_leg,
+            spread,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        match self.swap_type {
+            SwapType::Payer => -1.0,
+            SwapType::Receiver => 1.0,
+        }
+    }
+
+    /// Value of the spread leg, positive whichever side is taken.
+    pub fn spread_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.spread_leg, discount_curve, false, settlement)
+    }
+    /// Value of the other leg, positive whichever side is taken.
+    pub fn other_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.other_leg, discount_curve, false, settlement)
+    }
+    /// Basis point sensitivity of the spread leg, positive whichever side
+    /// is taken.
+    pub fn spread_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.spread_leg, discount_curve, false, settlement)
+    }
+
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "basis_swap.npv");
+        self.sign()
+            * (self.spread_leg_npv(discount_curve, settlement)
+                - self.other_leg_npv(discount_curve, settlement))
+    }
+
+    /// The NPV of the flows paid after `settlement` expressed as of
+    /// `npv_date`, e.g. the forward value at the spot date for a swap
+    /// quoted for spot settlement.
+    pub fn npv_at<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+        npv_date: Date,
+    ) -> f64 {
+        self.npv(discount_curve, settlement) / discount_curve.discount(npv_date, true)
+    }
+
+    /// The spread making the swap worth zero.
+    pub fn fair_spread<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        const BASIS_POINT: f64 = 1.0e-4;
+        let npv = self.spread_leg_npv(discount_curve, settlement)
+            - self.other_leg_npv(discount_curve, settlement);
+        let bps = self.spread_leg_bps(discount_curve, settlement);
+        assert!(bps != 0.0, "spread leg has no sensitivity to the spread");
+        self.spread - npv / bps * BASIS_POINT
+    }
+}
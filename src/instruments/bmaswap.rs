@@ -0,0 +1,118 @@
+use super::SwapType;
+use crate::cashflows::{self as cf, CashFlow, Leg};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+
+const BASIS_POINT: f64 = 1.0e-4;
+
+/// BMA vs Libor basis swap, exchanging average weekly BMA resets for a
+/// fraction of Libor plus a spread; `Payer` pays the BMA leg.
+///
+/// The Libor leg coupons must have been built with `libor_fraction` as
+/// gearing and `libor_spread` as spread.
+pub struct BmaSwap<L: CashFlow, B: CashFlow> {
+    pub swap_type: SwapType,
+    pub libor_leg: Leg<L>,
+    pub libor_fraction: f64,
+    pub libor_spread: f64,
+    pub bma_leg: Leg<B>,
+}
+
+impl<L: CashFlow, B: CashFlow> BmaSwap<L, B> {
+    pub fn new(
+        swap_type: SwapType,
+        libor_leg: Leg<L>,
+        libor_fraction: f64,
+        libor_spread: f64,
+        bma_leg: Leg<B>,
+    ) -> BmaSwap<L, B> {
+        assert!(
+            !libor_leg.is_empty() && !bma_leg.is_empty(),
+            "empty swap leg"
+        );
+        assert!(libor_fraction != 0.0, "null Libor fraction");
+        BmaSwap {
+            swap_type,
+            libor_leg,
+            libor_fraction,
+            libor_spread,
+            bma_leg,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        match self.swap_type {
+            SwapType::Payer => 1.0,
+            SwapType::Receiver => -1.0,
+        }
+    }
+
+    /// Value of the Libor leg, positive whichever side is taken.
+    pub fn libor_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.libor_leg, discount_curve, false, settlement)
+    }
+    /// Value of the BMA leg, positive whichever side is taken.
+    pub fn bma_leg_npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        cf::npv(&self.bma_leg, discount_curve, false, settlement)
+    }
+    pub fn libor_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.libor_leg, discount_curve, false, settlement)
+    }
+
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        self.sign()
+            * (self.libor_leg_npv(discount_curve, settlement)
+                - self.bma_leg_npv(discount_curve, settlement))
+    }
+
+    /// The NPV of the flows paid after `settlement` expressed as of
+    /// `npv_date`, e.g. the forward value at the spot date for a swap
+    /// quoted for spot settlement.
+    pub fn npv_at<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+        npv_date: Date,
+    ) -> f64 {
+        self.npv(discount_curve, settlement) / discount_curve.discount(npv_date, true)
+    }
+
+    /// The fraction of Libor making the swap worth zero at the current
+    /// spread, i.e. the BMA/Libor ratio quoted by municipal desks.
+    pub fn fair_libor_fraction<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        let spread_npv =
+            self.libor_spread * self.libor_leg_bps(discount_curve, settlement) / BASIS_POINT;
+        let pure_libor_npv = self.libor_leg_npv(discount_curve, settlement) - spread_npv;
+        assert!(
+            pure_libor_npv != 0.0,
+            "Libor leg has no sensitivity to the fraction"
+        );
+        self.libor_fraction * (self.bma_leg_npv(discount_curve, settlement) - spread_npv)
+            / pure_libor_npv
+    }
+
+    /// The Libor spread making the swap worth zero at the current fraction.
+    pub fn fair_libor_spread<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        let npv = self.libor_leg_npv(discount_curve, settlement)
+            - self.bma_leg_npv(discount_curve, settlement);
+        let bps = self.libor_leg_bps(discount_curve, settlement);
+        assert!(bps != 0.0, "Libor leg has no sensitivity to the spread");
+        self.libor_spread - npv / bps * BASIS_POINT
+    }
+}
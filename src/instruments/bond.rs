@@ -4,6 +4,7 @@ use crate::cashflows::{CashFlow, Leg};
 use crate::definitions::{Money, Rate};
 use crate::pricingengines::bondfunctions;
 use crate::pricingengines::{Arguments, PricingEngine, Results};
+use crate::settings::PricingContext;
 use crate::termstructures::Compounding;
 use crate::time::date as df;
 use crate::time::traits::Calendar as Cal;
@@ -25,7 +26,7 @@ where
     pub settlement_days: i64,
     pub calendar: Calendar<C>,
     pub cashflows: Leg<CF>,
-    pub issue_date: Date,
+    pub issue_date: Option<Date>,
     // always computed
     pub redemptions: Leg<CF>,
     pub notionals: Vec<f64>,
@@ -44,19 +45,18 @@ where
     CF: CashFlow,
     PE: PricingEngine + Default,
 {
-    ///
     pub fn new(
         settlement_days: i64,
         calendar: Calendar<C>,
         coupons: Leg<CF>,
-        issue_date: Date,
+        issue_date: Option<Date>,
     ) -> Bond<C, CF, PE> {
         // build.
         let mut b = Bond {
-            settlement_days: settlement_days,
-            calendar: calendar,
+            settlement_days,
+            calendar,
             cashflows: coupons,
-            issue_date: issue_date,
+            issue_date,
             notionals: vec![],
             notional_schedule: vec![],
             redemptions: vec![],
@@ -73,38 +73,35 @@ where
         }
 
         // TODO: add observer.
-        return b;
+        b
     }
 
-    ///
     pub fn new_today(settlement_days: i64, calendar: Calendar<C>) -> Bond<C, CF, PE> {
-        Bond::new(settlement_days, calendar, vec![], Date::default())
+        Bond::new(settlement_days, calendar, vec![], None)
     }
 
-    ///
     pub fn new_with_issue_date(
         settlement_days: i64,
         calendar: Calendar<C>,
         issue_date: Date,
     ) -> Bond<C, CF, PE> {
-        Bond::new(settlement_days, calendar, vec![], issue_date)
+        Bond::new(settlement_days, calendar, vec![], Some(issue_date))
     }
 
-    ///
     pub fn new_non_amortizing(
         settlement_days: i64,
         calendar: Calendar<C>,
         face_amount: f64,
         maturity_date: Date,
         cashflows: Leg<CF>,
-        issue_date: Date,
+        issue_date: Option<Date>,
     ) -> Bond<C, CF, PE> {
         // build.
         let mut b = Bond {
-            settlement_days: settlement_days,
-            calendar: calendar,
-            cashflows: cashflows,
-            issue_date: issue_date,
+            settlement_days,
+            calendar,
+            cashflows,
+            issue_date,
             notionals: vec![],
             notional_schedule: vec![],
             redemptions: vec![],
@@ -114,7 +111,7 @@ where
         };
 
         if !b.cashflows.is_empty() {
-            b.notional_schedule.push(Date::default());
+            b.notional_schedule.push(Date::min_date());
             b.notionals.push(face_amount);
             b.notional_schedule.push(maturity_date);
             b.notionals.push(0.0);
@@ -132,10 +129,9 @@ where
         }
         // TODO: add observer.
 
-        return b;
+        b
     }
 
-    ///
     pub fn new_non_amortizing_today(
         settlement_days: i64,
         calendar: Calendar<C>,
@@ -148,11 +144,10 @@ where
             face_amount,
             maturity_date,
             vec![],
-            Date::default(),
+            None,
         )
     }
 
-    ///
     pub fn new_non_amortizing_with_issue_date(
         settlement_days: i64,
         calendar: Calendar<C>,
@@ -166,7 +161,7 @@ where
             face_amount,
             maturity_date,
             vec![],
-            issue_date,
+            Some(issue_date),
         )
     }
 
@@ -174,35 +169,29 @@ where
     // Getters.
     //
     pub fn face_amount(&self) -> f64 {
-        *self.notionals.get(0).unwrap()
+        *self.notionals.first().unwrap()
     }
 
-    ///
+    /// Settlement date for a trade on `d`, by default the evaluation date of
+    /// the current `PricingContext`.
     pub fn settlement_date(&self, d: Option<Date>) -> Date {
-        let mut date = Date::default();
-        if d.is_none() {
-            // TODO: should be global eval date.
-            date = Date::default()
-        }
+        let date = d.unwrap_or_else(|| PricingContext::current().evaluation_date);
 
         // usually, the settlement is at T+n...
-        let settlement =
-            self.calendar
-                .advance_by_units(date, self.settlement_days as usize, TimeUnit::Days);
+        let settlement = self
+            .calendar
+            .advance_by_units(date, self.settlement_days, TimeUnit::Days);
         // ...but the bond won't be traded until the issue date (if given.)
-        if self.issue_date == Date::default() {
-            return settlement;
-        } else {
-            df::max(settlement, self.issue_date)
+        match self.issue_date {
+            Some(issue_date) => df::max(settlement, issue_date),
+            None => settlement,
         }
     }
 
-    ///
     pub fn notional(&self, date: Option<Date>) -> f64 {
-        let d = if date.is_none() {
-            self.settlement_date(date)
-        } else {
-            date.unwrap()
+        let d = match date {
+            Some(d) => d,
+            None => self.settlement_date(date),
         };
 
         if d > *self.notional_schedule.last().unwrap() {
@@ -212,7 +201,7 @@ where
 
         // After the check above, d is between the schedule
         // boundaries.  We search starting from the second notional
-        // date, since the first is null.  After the call to
+        // date, since the first is the min date.  After the call to
         // lower_bound, *i is the earliest date which is greater or
         // equal than d.  Its index is greater or equal to 1.
         let mut idx = 0;
@@ -220,57 +209,49 @@ where
             if nd >= &d {
                 break;
             }
-            idx = idx + 1;
+            idx += 1;
         }
         assert!(idx != 0);
         if d < self.notional_schedule[idx] {
             // no doubt about what to return
-            let ret = self.notionals[idx - 1];
-            return ret;
+            self.notionals[idx - 1]
         } else {
             // d is equal to a redemption date.
             // As per bond conventions, the payment has occurred;
             // the bond already changed notional.
-            return self.notionals[idx];
+            self.notionals[idx]
         }
     }
 
-    ///
     pub fn redemption(&self) -> Option<&CF> {
         assert!(self.redemptions.len() == 1);
         self.redemptions.last()
     }
 
-    ///
     pub fn start_date(&self) -> Date {
         bondfunctions::start_date(self)
     }
 
-    ///
     pub fn maturity_date(&self) -> Date {
-        if self.maturity_date.is_some() {
-            return self.maturity_date.unwrap();
+        if let Some(date) = self.maturity_date {
+            return date;
         }
         bondfunctions::maturity_date(self)
     }
 
-    ///
-    pub fn is_tradeable(&self, d: Date) -> bool {
+    pub fn is_tradeable(&self, d: Option<Date>) -> bool {
         bondfunctions::is_tradeable(self, d)
     }
 
-    ///
     fn add_redemptions_to_cashflows(&self) {}
 
     // Calculations.
     // ==============
 
-    ///
     pub fn clean_price(&mut self) -> f64 {
         self.dirty_price() - self.accrued_amount(self.settlement_date(None))
     }
 
-    ///
     pub fn dirty_price(&mut self) -> f64 {
         let current_notional = self.notional(Some(self.settlement_date(None)));
         if current_notional == 0.0 {
@@ -279,13 +260,11 @@ where
         self.settlement_value() * 100.0 / current_notional
     }
 
-    ///
     pub fn settlement_value(&mut self) -> f64 {
         self.calculate();
         self.settlement_value.unwrap()
     }
 
-    ///
     pub fn settlement_value_from_clean(&mut self, clean_price: f64) -> f64 {
         let dirty_price = clean_price + self.accrued_amount(self.settlement_date(None));
         dirty_price / 100.0 * self.notional(Some(self.settlement_date(None)))
@@ -371,7 +350,6 @@ where
         )
     }
 
-    ///
     pub fn accrued_amount(&self, settlement_date: Date) -> f64 {
         let current_notional = self.notional(Some(settlement_date));
         if current_notional == 0.0 {
@@ -388,11 +366,11 @@ where
         bondfunctions::previous_coupon_rate(self, settlement_date)
     }
 
-    pub fn next_cashflow_date(&self, settlement_date: Date) -> Date {
+    pub fn next_cashflow_date(&self, settlement_date: Date) -> Option<Date> {
         bondfunctions::next_cashflow_date(self, settlement_date)
     }
 
-    pub fn previous_cashflow_date(&self, settlement_date: Date) -> Date {
+    pub fn previous_cashflow_date(&self, settlement_date: Date) -> Option<Date> {
         bondfunctions::previous_cashflow_date(self, settlement_date)
     }
 }
@@ -424,11 +402,14 @@ where
     fn additional_results(&self) -> &HashMap<String, Money> {
         self.base.additional_results()
     }
-    /// returns whether the instrument might have value greater than zero.
+    /// returns whether the instrument might have value greater than zero,
+    /// i.e. whether a cash flow is left at the evaluation date of the
+    /// current `PricingContext`.
     fn is_expired(&self) -> bool {
-        unimplemented!();
-        //TODO: make sure this is implemented.
-        false
+        let context = PricingContext::current();
+        self.cashflows.iter().all(|c| {
+            CashFlow::has_occured(c, context.evaluation_date, context.include_todays_cashflows)
+        })
     }
     /// set the pricing engine to be used.
     fn set_pricing_engine(&mut self, engine: Self::E) {
@@ -450,11 +431,9 @@ where
     fn calculate(&mut self) {
         self.base.calculate()
     }
-    ///
     fn setup_expired(&mut self) {
         self.base.setup_expired()
     }
-    ///
     fn perform_calculations(&mut self) {
         self.base.perform_calculations()
     }
@@ -1,11 +1,157 @@
-use super::super::bond::Bond;
-use crate::cashflows::CashFlow;
-use crate::pricingengines::PricingEngine;
-use crate::time::traits::Calendar as Cal;
-use crate::time::{DayCounter, Frequency};
-
-pub struct FixedRateBond<C: Cal, CF: CashFlow, DC: DayCounter, PE: PricingEngine> {
-    pub bond: Bond<C, CF, PE>,
+use crate::cashflows::analysis;
+pub use crate::cashflows::analysis::Duration;
+use crate::cashflows::{CashFlow, Event, FixedRateCoupon, FixedRateLeg, Leg, SimpleCashFlow};
+use crate::definitions::Rate;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::{Compounding, InterestRate};
+use crate::time::{Date, DayCounter, Frequency, Schedule};
+
+/// Bond paying fixed rate coupons on a schedule and its face amount, times
+/// the redemption per 100, on the last schedule date.
+///
+/// Prices are per 100 of face. Coupons paid on or before settlement belong
+/// to the seller, and yields are expressed on the coupon day counter.
+pub struct FixedRateBond<DC: DayCounter> {
+    pub coupons: Leg<FixedRateCoupon<DC>>,
+    pub face_amount: f64,
+    /// Redemption per 100 of face.
+    pub redemption: f64,
+    pub issue_date: Date,
+    pub maturity_date: Date,
     pub frequency: Frequency,
     pub day_counter: DC,
 }
+
+impl<DC: DayCounter> FixedRateBond<DC> {
+    pub fn new(
+        schedule: Schedule,
+        coupon: Rate,
+        day_counter: DC,
+        frequency: Frequency,
+        face_amount: f64,
+    ) -> FixedRateBond<DC> {
+        assert!(face_amount > 0.0, "non positive face amount");
+        assert!(schedule.dates.len() > 1, "schedule without coupon periods");
+        let coupons = FixedRateLeg::new(schedule.clone(), day_counter)
+            .with_notional(face_amount)
+            .with_rate(coupon)
+            .build();
+        FixedRateBond {
+            coupons,
+            face_amount,
+            redemption: 100.0,
+            issue_date: schedule.dates[0],
+            maturity_date: schedule.dates[schedule.dates.len() - 1],
+            frequency,
+            day_counter,
+        }
+    }
+
+    pub fn with_redemption(mut self, redemption: f64) -> FixedRateBond<DC> {
+        assert!(redemption > 0.0, "non positive redemption");
+        self.redemption = redemption;
+        self
+    }
+
+    /// Payments after `settlement`, the redemption last.
+    pub fn cashflows(&self, settlement: Date) -> Vec<SimpleCashFlow> {
+        let mut flows: Vec<SimpleCashFlow> = self
+            .coupons
+            .iter()
+            .filter(|c| !CashFlow::has_occured(*c, settlement, false))
+            .map(|c| SimpleCashFlow::new(c.amount(), c.date()))
+            .collect();
+        if self.maturity_date > settlement {
+            flows.push(SimpleCashFlow::new(
+                self.face_amount * self.redemption / 100.0,
+                self.maturity_date,
+            ));
+        }
+        flows
+    }
+
+    /// Interest accrued at `settlement`, per 100 of face.
+    pub fn accrued_amount(&self, settlement: Date) -> f64 {
+        analysis::accrued_amount(&self.coupons, settlement) * 100.0 / self.face_amount
+    }
+
+    /// Present value at the curve reference date of the payments after
+    /// `settlement`.
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        analysis::npv(
+            &self.cashflows(settlement),
+            discount_curve,
+            false,
+            settlement,
+        )
+    }
+
+    /// Price per 100 of face, including accrued interest, for settlement on
+    /// `settlement`.
+    pub fn dirty_price<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        self.npv(discount_curve, settlement) / discount_curve.discount(settlement, true) * 100.0
+            / self.face_amount
+    }
+
+    pub fn clean_price<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        self.dirty_price(discount_curve, settlement) - self.accrued_amount(settlement)
+    }
+
+    fn interest_rate(&self, y: Rate, comp: Compounding) -> InterestRate<DC> {
+        InterestRate::new(y, self.day_counter, comp, self.frequency)
+    }
+
+    /// Dirty price per 100 of face discounting the payments at yield `y`.
+    pub fn dirty_price_from_yield(&self, y: Rate, comp: Compounding, settlement: Date) -> f64 {
+        let rate = self.interest_rate(y, comp);
+        analysis::npv_at_yield(&self.cashflows(settlement), &rate, false, settlement) * 100.0
+            / self.face_amount
+    }
+
+    pub fn clean_price_from_yield(&self, y: Rate, comp: Compounding, settlement: Date) -> f64 {
+        self.dirty_price_from_yield(y, comp, settlement) - self.accrued_amount(settlement)
+    }
+
+    /// The yield at which the payments after `settlement` are worth
+    /// `clean_price`.
+    pub fn yield_to_maturity(&self, clean_price: f64, comp: Compounding, settlement: Date) -> Rate {
+        const ACCURACY: f64 = 1.0e-12;
+        assert!(clean_price > 0.0, "non positive price");
+        assert!(settlement < self.maturity_date, "bond already matured");
+        let dirty = (clean_price + self.accrued_amount(settlement)) * self.face_amount / 100.0;
+        analysis::yield_rate(
+            &self.cashflows(settlement),
+            dirty,
+            self.day_counter,
+            comp,
+            self.frequency,
+            false,
+            settlement,
+            ACCURACY,
+        )
+    }
+
+    /// Duration of the payments after `settlement` at yield `y`, in years.
+    pub fn duration(
+        &self,
+        y: Rate,
+        comp: Compounding,
+        duration: Duration,
+        settlement: Date,
+    ) -> f64 {
+        let rate = self.interest_rate(y, comp);
+        analysis::duration(
+            &self.cashflows(settlement),
+            &rate,
+            duration,
+            false,
+            settlement,
+        )
+    }
+
+    /// Second derivative of the dirty price in yield relative to the price.
+    pub fn convexity(&self, y: Rate, comp: Compounding, settlement: Date) -> f64 {
+        let rate = self.interest_rate(y, comp);
+        analysis::convexity(&self.cashflows(settlement), &rate, false, settlement)
+    }
+}
@@ -1,3 +1,5 @@
 pub mod fixedrate;
+pub mod ntnb;
 
-pub use self::fixedrate::FixedRateBond;
+pub use self::fixedrate::{Duration, FixedRateBond};
+pub use self::ntnb::{NtnB, NTNB_BASE_VNA};
@@ -0,0 +1,111 @@
+use crate::indexes::{Index, ZeroInflationIndex};
+use crate::math::{bracket, NewtonSafe, Solver1D};
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Business252, Calendar, Date, DayCounter, Month};
+
+/// The nominal value of an NTN-B on its 15 July 2000 base date.
+pub const NTNB_BASE_VNA: f64 = 1000.0;
+
+/// Brazilian Treasury inflation-linked note (Nota do Tesouro Nacional,
+/// série B), paying 6% a year real in semiannual coupons and the principal
+/// on the VNA, the nominal value updated by IPCA since 15 July 2000.
+///
+/// Following ANBIMA, the bond is quoted as a percentage of the VNA
+/// (cotação) discounted at an annual real yield over business/252 days.
+pub struct NtnB<C: Cal> {
+    pub issue_date: Date,
+    pub maturity: Date,
+    /// Unadjusted coupon dates, every six months back from maturity.
+    pub coupon_dates: Vec<Date>,
+    pub calendar: Calendar<C>,
+}
+
+impl<C: Cal> NtnB<C> {
+    pub fn new(issue_date: Date, maturity: Date, calendar: Calendar<C>) -> NtnB<C> {
+        assert!(issue_date < maturity, "maturity before issue");
+        let mut coupon_dates = vec![];
+        let mut d = maturity;
+        while d > issue_date {
+            coupon_dates.push(d);
+            d = d.add_months(-6);
+        }
+        coupon_dates.reverse();
+        NtnB {
+            issue_date,
+            maturity,
+            coupon_dates,
+            calendar,
+        }
+    }
+
+    /// The semiannual coupon per unit of VNA, `1.06^(1/2) - 1`.
+    pub fn coupon() -> f64 {
+        1.06f64.sqrt() - 1.0
+    }
+
+    pub fn day_counter(&self) -> Business252<C> {
+        Business252 {
+            calendar: self.calendar,
+        }
+    }
+
+    pub fn payment_dates(&self) -> Vec<Date> {
+        self.coupon_dates
+            .iter()
+            .map(|&d| self.calendar.adjust(d))
+            .collect()
+    }
+
+    /// The VNA at `date`: the base value grown by the IPCA of the month
+    /// before each 15th, with the running month's inflation accrued pro
+    /// rata in business days between the 15ths around `date`. Fixings must
+    /// include projections for months not yet published.
+    pub fn vna(&self, date: Date, ipca: &ZeroInflationIndex) -> f64 {
+        let mut anniversary = Date::new(15, date.month(), date.year() as i32);
+        if date < anniversary {
+            anniversary = anniversary.add_months(-1);
+        }
+        let next = anniversary.add_months(1);
+        let base = ipca.fixing(Date::new(1, Month::June, 2000));
+        let last = ipca.lagged_fixing(anniversary, 1);
+        let vna = NTNB_BASE_VNA * last / base;
+        if date == anniversary {
+            return vna;
+        }
+        let dc = self.day_counter();
+        let accrued =
+            dc.day_count(anniversary, date) as f64 / dc.day_count(anniversary, next) as f64;
+        vna * (ipca.lagged_fixing(next, 1) / last).powf(accrued)
+    }
+
+    /// The price as a fraction of the VNA of the cash flows paid after
+    /// `settlement`, at the given annual real yield.
+    pub fn quotation(&self, real_yield: f64, settlement: Date) -> f64 {
+        let dc = self.day_counter();
+        let payments = self.payment_dates();
+        let n = payments.len();
+        payments
+            .iter()
+            .enumerate()
+            .filter(|(_, &d)| d > settlement)
+            .map(|(i, &d)| {
+                let amount = NtnB::<C>::coupon() + if i == n - 1 { 1.0 } else { 0.0 };
+                amount / (1.0 + real_yield).powf(dc.year_fraction(settlement, d, None, None))
+            })
+            .sum()
+    }
+
+    /// The real yield matching a quotation, by safeguarded Newton
+    /// iterations from 6%.
+    pub fn real_yield(&self, quotation: f64, settlement: Date) -> f64 {
+        const ACCURACY: f64 = 1.0e-12;
+        let error = |y: f64| self.quotation(y, settlement) - quotation;
+        let (low, high) = bracket(error, 0.06, 0.01, 100);
+        NewtonSafe::new(ACCURACY).solve(error, low, high)
+    }
+
+    /// The price of one bond in BRL.
+    pub fn price(&self, real_yield: f64, settlement: Date, ipca: &ZeroInflationIndex) -> f64 {
+        self.vna(settlement, ipca) * self.quotation(real_yield, settlement)
+    }
+}
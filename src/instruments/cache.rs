@@ -0,0 +1,117 @@
+use super::traits::Instrument;
+use crate::definitions::Money;
+use crate::quotes::MarketVersion;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Hit/miss counters of pricing caches, shareable across instruments so a
+/// service can report one hit rate for its whole book.
+#[derive(Debug, Default)]
+pub struct CacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheMetrics {
+    pub fn new() -> Arc<CacheMetrics> {
+        Arc::new(CacheMetrics::default())
+    }
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+    /// Share of requests served from cache, zero when nothing was requested.
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits() as f64;
+        let total = hits + self.misses() as f64;
+        if total == 0.0 {
+            0.0
+        } else {
+            hits / total
+        }
+    }
+    pub fn reset(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Instrument wrapper that skips re-pricing while neither the market nor
+/// the trade has changed.
+///
+/// The cached NPV is keyed by the `MarketVersion` the instrument's quotes
+/// bump and by a trade version bumped by every call to `modify`.
+pub struct CachedInstrument<I: Instrument> {
+    instrument: I,
+    market: MarketVersion,
+    trade_version: u64,
+    cached: Option<(u64, u64, Money)>,
+    metrics: Arc<CacheMetrics>,
+}
+
+impl<I: Instrument> CachedInstrument<I> {
+    pub fn new(instrument: I, market: MarketVersion) -> CachedInstrument<I> {
+        CachedInstrument {
+            instrument,
+            market,
+            trade_version: 0,
+            cached: None,
+            metrics: CacheMetrics::new(),
+        }
+    }
+
+    /// Reports hits and misses into shared metrics.
+    pub fn with_metrics(mut self, metrics: Arc<CacheMetrics>) -> CachedInstrument<I> {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn instrument(&self) -> &I {
+        &self.instrument
+    }
+
+    pub fn metrics(&self) -> &Arc<CacheMetrics> {
+        &self.metrics
+    }
+
+    pub fn trade_version(&self) -> u64 {
+        self.trade_version
+    }
+
+    /// Amends the trade; the next `npv` call re-prices.
+    pub fn modify<F: FnOnce(&mut I)>(&mut self, f: F) {
+        f(&mut self.instrument);
+        self.trade_version += 1;
+    }
+
+    /// Drops the cached value.
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+
+    /// Whether the next `npv` call would be served from cache.
+    pub fn is_cached(&self) -> bool {
+        match self.cached {
+            Some((market, trade, _)) => {
+                market == self.market.current() && trade == self.trade_version
+            }
+            None => false,
+        }
+    }
+
+    /// The NPV, re-priced only if the market or the trade changed.
+    pub fn npv(&mut self) -> Money {
+        if self.is_cached() {
+            self.metrics.hits.fetch_add(1, Ordering::Relaxed);
+            return self.cached.unwrap().2;
+        }
+        self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+        // read the version first so that a concurrent bump forces a re-price.
+        let market = self.market.current();
+        let npv = self.instrument.npv();
+        self.cached = Some((market, self.trade_version, npv));
+        npv
+    }
+}
@@ -0,0 +1,51 @@
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Business252, Calendar, Date, DayCounter, Month};
+
+/// Value of a DI1 contract at maturity, in BRL.
+pub const DI_FUTURE_NOTIONAL: f64 = 100_000.0;
+
+/// B3 one-day interbank deposit future (DI1) on the CDI rate.
+///
+/// Contracts mature on the first business day of their month and are
+/// quoted as the annual exponential business/252 rate to maturity; the
+/// unit price (PU) is the notional discounted at that rate.
+#[derive(Copy, Clone)]
+pub struct DiFuture<C: Cal> {
+    pub maturity: Date,
+    pub day_counter: Business252<C>,
+}
+
+impl<C: Cal> DiFuture<C> {
+    pub fn new(maturity: Date, calendar: Calendar<C>) -> DiFuture<C> {
+        DiFuture {
+            maturity,
+            day_counter: Business252 { calendar },
+        }
+    }
+
+    /// The contract of the given month and year, e.g. the January 2025
+    /// contract (DI1F25).
+    pub fn contract(month: Month, year: i32, calendar: Calendar<C>) -> DiFuture<C> {
+        DiFuture::new(calendar.adjust(Date::new(1, month, year)), calendar)
+    }
+
+    /// Business days from `settlement` to maturity.
+    pub fn business_days(&self, settlement: Date) -> i64 {
+        self.day_counter.day_count(settlement, self.maturity)
+    }
+
+    pub fn unit_price(&self, rate: f64, settlement: Date) -> f64 {
+        let t = self
+            .day_counter
+            .year_fraction(settlement, self.maturity, None, None);
+        DI_FUTURE_NOTIONAL / (1.0 + rate).powf(t)
+    }
+
+    pub fn implied_rate(&self, unit_price: f64, settlement: Date) -> f64 {
+        let t = self
+            .day_counter
+            .year_fraction(settlement, self.maturity, None, None);
+        assert!(t > 0.0, "expired contract");
+        (DI_FUTURE_NOTIONAL / unit_price).powf(1.0 / t) - 1.0
+    }
+}
@@ -0,0 +1,72 @@
+use crate::definitions::Time;
+use crate::time::Date;
+
+/// When an option can be exercised.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Exercise {
+    /// On its expiry only.
+    European(Date),
+    /// On any date from `earliest` to `latest`.
+    American { earliest: Date, latest: Date },
+    /// On the given increasing dates.
+    Bermudan(Vec<Date>),
+}
+
+impl Exercise {
+    pub fn bermudan(dates: Vec<Date>) -> Exercise {
+        assert!(
+            !dates.is_empty() && dates.windows(2).all(|w| w[0] < w[1]),
+            "exercise dates must be given in increasing order"
+        );
+        Exercise::Bermudan(dates)
+    }
+
+    /// The last date the option can be exercised, its expiry.
+    pub fn last_date(&self) -> Date {
+        match self {
+            Exercise::European(date) => *date,
+            Exercise::American { latest, .. } => *latest,
+            Exercise::Bermudan(dates) => dates[dates.len() - 1],
+        }
+    }
+
+    /// Whether the option can be exercised before its expiry.
+    pub fn is_early(&self) -> bool {
+        match self {
+            Exercise::European(_) => false,
+            Exercise::American { earliest, latest } => earliest < latest,
+            Exercise::Bermudan(dates) => dates.len() > 1,
+        }
+    }
+
+    /// Whether the option can be exercised at each of the `steps + 1`
+    /// times of a grid of step `dt` from today, `time_of` giving the time
+    /// of a date. Bermudan dates exercise at the nearest grid time.
+    pub fn exercise_steps<F: Fn(Date) -> Time>(
+        &self,
+        time_of: F,
+        dt: Time,
+        steps: usize,
+    ) -> Vec<bool> {
+        let mut exercisable = vec![false; steps + 1];
+        exercisable[steps] = true;
+        let step_of = |t: Time| ((t / dt).round().max(0.0) as usize).min(steps);
+        match self {
+            Exercise::European(_) => {}
+            Exercise::American { earliest, .. } => {
+                for e in &mut exercisable[step_of(time_of(*earliest))..] {
+                    *e = true;
+                }
+            }
+            Exercise::Bermudan(dates) => {
+                for d in dates {
+                    let t = time_of(*d);
+                    if t >= 0.0 {
+                        exercisable[step_of(t)] = true;
+                    }
+                }
+            }
+        }
+        exercisable
+    }
+}
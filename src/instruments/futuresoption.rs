@@ -0,0 +1,43 @@
+use super::OptionType;
+use crate::definitions::Time;
+
+/// How the premium of a futures option changes hands.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PremiumSettlement {
+    /// Paid when the option is bought, so its value is discounted.
+    Upfront,
+    /// Futures-style: marked to market daily with no premium paid, so the
+    /// value is the undiscounted expected payoff.
+    Margined,
+}
+
+/// European option on a futures price, e.g. on interest rate, bond or
+/// commodity futures.
+#[derive(Copy, Clone, Debug)]
+pub struct FuturesOption {
+    pub option_type: OptionType,
+    pub strike: f64,
+    pub expiry: Time,
+    pub settlement: PremiumSettlement,
+}
+
+impl FuturesOption {
+    pub fn new(
+        option_type: OptionType,
+        strike: f64,
+        expiry: Time,
+        settlement: PremiumSettlement,
+    ) -> FuturesOption {
+        assert!(expiry >= 0.0, "negative expiry");
+        FuturesOption {
+            option_type,
+            strike,
+            expiry,
+            settlement,
+        }
+    }
+
+    pub fn payoff(&self, futures_price: f64) -> f64 {
+        (self.option_type.sign() * (futures_price - self.strike)).max(0.0)
+    }
+}
@@ -0,0 +1,152 @@
+use super::OptionType;
+use crate::definitions::{Rate, Time};
+use crate::errors::QuantLibError;
+use crate::indexes::ZeroInflationIndex;
+use crate::time::Date;
+use std::rc::Rc;
+
+/// Zero coupon inflation cap, as a call, or floor, as a put, on the
+/// growth of `index` from the fixing `observation_lag` months before the
+/// start to the one as many months before maturity. Pays, at maturity,
+/// the nominal times the excess of the index ratio over `(1 + K)^n`, or
+/// its shortfall for a floor, for the strike `K` and the `n` years
+/// between the fixings.
+pub struct ZeroCouponInflationCapFloor {
+    pub option_type: OptionType,
+    pub index: Rc<ZeroInflationIndex>,
+    pub nominal: f64,
+    pub strike: Rate,
+    pub start_date: Date,
+    pub maturity: Date,
+    pub observation_lag: i64,
+}
+
+impl ZeroCouponInflationCapFloor {
+    pub fn new(
+        option_type: OptionType,
+        index: Rc<ZeroInflationIndex>,
+        nominal: f64,
+        strike: Rate,
+        start_date: Date,
+        maturity: Date,
+        observation_lag: i64,
+    ) -> ZeroCouponInflationCapFloor {
+        assert!(start_date < maturity, "maturity before start");
+        assert!(strike > -1.0, "strike below -100%");
+        assert!(observation_lag >= 0, "negative observation lag");
+        ZeroCouponInflationCapFloor {
+            option_type,
+            index,
+            nominal,
+            strike,
+            start_date,
+            maturity,
+            observation_lag,
+        }
+    }
+
+    pub fn base_fixing_date(&self) -> Date {
+        lagged_month(self.start_date, self.observation_lag)
+    }
+
+    pub fn fixing_date(&self) -> Date {
+        lagged_month(self.maturity, self.observation_lag)
+    }
+
+    /// Years between the fixing months.
+    pub fn years(&self) -> Time {
+        months_between(self.base_fixing_date(), self.fixing_date()) / 12.0
+    }
+
+    /// The index ratio struck at, `(1 + K)^n`.
+    pub fn strike_ratio(&self) -> f64 {
+        (1.0 + self.strike).powf(self.years())
+    }
+
+    /// The forecast index ratio between the fixings.
+    pub fn index_ratio(&self) -> Result<f64, QuantLibError> {
+        Ok(self.index.forecast_fixing(self.fixing_date())?
+            / self.index.forecast_fixing(self.base_fixing_date())?)
+    }
+
+    pub fn payoff(&self, index_ratio: f64) -> f64 {
+        self.nominal * (self.option_type.sign() * (index_ratio - self.strike_ratio())).max(0.0)
+    }
+}
+
+/// Option on the spread between the growths of two price indexes over
+/// the same fixings, e.g. of the euro area HICPx over French CPI. Pays,
+/// at maturity, the nominal times the excess of the first index ratio
+/// over the second one plus `strike`, or the reverse for a put.
+pub struct InflationSpreadOption {
+    pub option_type: OptionType,
+    pub first: Rc<ZeroInflationIndex>,
+    pub second: Rc<ZeroInflationIndex>,
+    pub nominal: f64,
+    pub strike: f64,
+    pub start_date: Date,
+    pub maturity: Date,
+    pub observation_lag: i64,
+}
+
+impl InflationSpreadOption {
+    pub fn new(
+        option_type: OptionType,
+        first: Rc<ZeroInflationIndex>,
+        second: Rc<ZeroInflationIndex>,
+        nominal: f64,
+        strike: f64,
+        start_date: Date,
+        maturity: Date,
+        observation_lag: i64,
+    ) -> InflationSpreadOption {
+        assert!(start_date < maturity, "maturity before start");
+        assert!(observation_lag >= 0, "negative observation lag");
+        InflationSpreadOption {
+            option_type,
+            first,
+            second,
+            nominal,
+            strike,
+            start_date,
+            maturity,
+            observation_lag,
+        }
+    }
+
+    pub fn base_fixing_date(&self) -> Date {
+        lagged_month(self.start_date, self.observation_lag)
+    }
+
+    pub fn fixing_date(&self) -> Date {
+        lagged_month(self.maturity, self.observation_lag)
+    }
+
+    /// Years between the fixing months.
+    pub fn years(&self) -> Time {
+        months_between(self.base_fixing_date(), self.fixing_date()) / 12.0
+    }
+
+    /// The forecast index ratios of the first and second index.
+    pub fn index_ratios(&self) -> Result<(f64, f64), QuantLibError> {
+        let ratio = |index: &ZeroInflationIndex| -> Result<f64, QuantLibError> {
+            Ok(index.forecast_fixing(self.fixing_date())?
+                / index.forecast_fixing(self.base_fixing_date())?)
+        };
+        Ok((ratio(&self.first)?, ratio(&self.second)?))
+    }
+
+    pub fn payoff(&self, first_ratio: f64, second_ratio: f64) -> f64 {
+        let spread = first_ratio - second_ratio - self.strike;
+        self.nominal * (self.option_type.sign() * spread).max(0.0)
+    }
+}
+
+fn lagged_month(date: Date, lag: i64) -> Date {
+    ZeroInflationIndex::reference_month(date).add_months(-lag)
+}
+
+fn months_between(from: Date, to: Date) -> f64 {
+    let years = to.year() as i64 - from.year() as i64;
+    (12 * years + to.month() as i64 - from.month() as i64) as f64
+}
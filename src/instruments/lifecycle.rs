@@ -0,0 +1,329 @@
+use super::bond::Bond;
+use super::{
+    BasisSwap, BmaSwap, DiFuture, EarlyExerciseOption, Exercise, FixedRateBond, NonstandardSwap,
+    NtnB, VanillaOption, VanillaSwap,
+};
+use crate::cashflows::{CashFlow, FloatingRateCoupon};
+use crate::definitions::Time;
+use crate::pricingengines::PricingEngine;
+use crate::settings::PricingContext;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{BusinessDayConvention, Date, DayCounter, TimeUnit};
+
+/// What happens on a lifecycle date; events of one date sort in this
+/// order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LifecycleEventKind {
+    /// An index fixing the instrument needs.
+    Fixing,
+    /// A date an option can be exercised on before its expiry.
+    Exercise,
+    /// Last trading or exercise date.
+    Expiry,
+    /// Final settlement of a contract.
+    Settlement,
+    /// A cash flow payment.
+    Payment,
+}
+
+/// Dated event of an instrument, with the part of its term sheet it comes
+/// from, e.g. "fixed leg".
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LifecycleEvent {
+    pub date: Date,
+    pub kind: LifecycleEventKind,
+    pub source: &'static str,
+}
+
+/// Term sheet events of an instrument, for operational workflows.
+pub trait Lifecycle {
+    /// All events, in date order.
+    fn events(&self) -> Vec<LifecycleEvent>;
+
+    /// The events from `from` up to `to`, both included.
+    fn upcoming_events(&self, from: Date, to: Date) -> Vec<LifecycleEvent> {
+        self.events()
+            .into_iter()
+            .filter(|e| e.date >= from && e.date <= to)
+            .collect()
+    }
+
+    /// The first event on or after `from`.
+    fn next_event(&self, from: Date) -> Option<LifecycleEvent> {
+        self.events().into_iter().find(|e| e.date >= from)
+    }
+
+    /// Whether every event has occurred by `date`; events on `date` count
+    /// as occurred unless `include_date_events`, as for cash flows.
+    fn is_expired_at(&self, date: Date, include_date_events: bool) -> bool {
+        self.events()
+            .iter()
+            .all(|e| e.date < date || (e.date == date && !include_date_events))
+    }
+
+    /// Whether every event has occurred by the evaluation date of the
+    /// current `PricingContext`.
+    fn is_expired(&self) -> bool {
+        let context = PricingContext::current();
+        self.is_expired_at(context.evaluation_date, context.include_todays_cashflows)
+    }
+}
+
+/// Why a valuation returned what it did.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ValuationStatus {
+    /// Priced by the engine.
+    Live,
+    /// Every event occurred by the evaluation date.
+    Expired,
+    /// Still alive, but the remaining flows are paid on or before the
+    /// settlement date, so none is bought.
+    PastSettlement,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Valuation {
+    pub npv: f64,
+    pub status: ValuationStatus,
+}
+
+/// Values `instrument` with `pricer` when it is live and at zero, with the
+/// reason in the status, when it is expired or has no flow left after
+/// `settlement`, instead of leaving engines to fail on empty legs.
+pub fn valuation<I, F>(instrument: &I, settlement: Date, pricer: F) -> Valuation
+where
+    I: Lifecycle,
+    F: FnOnce(&I) -> f64,
+{
+    let status = if instrument.is_expired() {
+        ValuationStatus::Expired
+    } else if settled(&instrument.events(), |d| d <= settlement) {
+        ValuationStatus::PastSettlement
+    } else {
+        ValuationStatus::Live
+    };
+    valued(instrument, status, pricer)
+}
+
+/// `valuation` for engines pricing on curves, as of their reference date
+/// rather than the evaluation date, `time_of` giving the time of a date
+/// from it. Events at negative times have occurred, those at time zero as
+/// for the evaluation date, and flows are bought for settlement at time
+/// zero.
+pub fn curve_valuation<I, T, F>(instrument: &I, time_of: T, pricer: F) -> Valuation
+where
+    I: Lifecycle,
+    T: Fn(Date) -> Time,
+    F: FnOnce(&I) -> f64,
+{
+    let include = PricingContext::current().include_todays_cashflows;
+    let events = instrument.events();
+    let status = if events.iter().all(|e| {
+        let t = time_of(e.date);
+        t < 0.0 || (t == 0.0 && !include)
+    }) {
+        ValuationStatus::Expired
+    } else if settled(&events, |d| time_of(d) <= 0.0) {
+        ValuationStatus::PastSettlement
+    } else {
+        ValuationStatus::Live
+    };
+    valued(instrument, status, pricer)
+}
+
+/// Whether every payment and settlement is `before` settlement.
+fn settled<B: Fn(Date) -> bool>(events: &[LifecycleEvent], before: B) -> bool {
+    events.iter().all(|e| {
+        before(e.date)
+            || !(e.kind == LifecycleEventKind::Payment || e.kind == LifecycleEventKind::Settlement)
+    })
+}
+
+fn valued<I, F: FnOnce(&I) -> f64>(
+    instrument: &I,
+    status: ValuationStatus,
+    pricer: F,
+) -> Valuation {
+    let npv = match status {
+        ValuationStatus::Live => pricer(instrument),
+        _ => 0.0,
+    };
+    Valuation { npv, status }
+}
+
+fn payments<CF: CashFlow>(leg: &[CF], source: &'static str) -> Vec<LifecycleEvent> {
+    leg.iter()
+        .map(|c| LifecycleEvent {
+            date: c.date(),
+            kind: LifecycleEventKind::Payment,
+            source,
+        })
+        .collect()
+}
+
+/// Fixing and payment events of a floating leg.
+fn floating<CF: FloatingRateCoupon>(leg: &[CF], source: &'static str) -> Vec<LifecycleEvent> {
+    let mut events: Vec<LifecycleEvent> = leg
+        .iter()
+        .flat_map(|c| c.fixing_dates())
+        .map(|date| LifecycleEvent {
+            date,
+            kind: LifecycleEventKind::Fixing,
+            source,
+        })
+        .collect();
+    events.extend(payments(leg, source));
+    events
+}
+
+fn sorted(mut events: Vec<LifecycleEvent>) -> Vec<LifecycleEvent> {
+    events.sort_by_key(|e| (e.date, e.kind));
+    events.dedup();
+    events
+}
+
+impl<FX: CashFlow, FL: FloatingRateCoupon> Lifecycle for VanillaSwap<FX, FL> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = payments(&self.fixed_leg, "fixed leg");
+        events.extend(floating(&self.floating_leg, "floating leg"));
+        sorted(events)
+    }
+}
+
+impl<CF1: FloatingRateCoupon, CF2: FloatingRateCoupon> Lifecycle for BasisSwap<CF1, CF2> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = floating(&self.spread_leg, "spread leg");
+        events.extend(floating(&self.other_leg, "other leg"));
+        sorted(events)
+    }
+}
+
+impl<L: FloatingRateCoupon, B: FloatingRateCoupon> Lifecycle for BmaSwap<L, B> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = floating(&self.libor_leg, "libor leg");
+        events.extend(floating(&self.bma_leg, "bma leg"));
+        sorted(events)
+    }
+}
+
+/// The contract trades up to the business day before maturity, when it
+/// settles.
+impl<C: Cal> Lifecycle for DiFuture<C> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let last_trading = self.day_counter.calendar.advance(
+            self.maturity,
+            -1,
+            TimeUnit::Days,
+            BusinessDayConvention::Preceding,
+            false,
+        );
+        vec![
+            LifecycleEvent {
+                date: last_trading,
+                kind: LifecycleEventKind::Expiry,
+                source: "contract",
+            },
+            LifecycleEvent {
+                date: self.maturity,
+                kind: LifecycleEventKind::Settlement,
+                source: "contract",
+            },
+        ]
+    }
+}
+
+impl<FX: CashFlow, FL: FloatingRateCoupon> Lifecycle for NonstandardSwap<FX, FL> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = payments(&self.fixed_leg, "fixed leg");
+        events.extend(floating(&self.floating_leg, "floating leg"));
+        sorted(events)
+    }
+}
+
+fn redemption(date: Date) -> LifecycleEvent {
+    LifecycleEvent {
+        date,
+        kind: LifecycleEventKind::Payment,
+        source: "redemption",
+    }
+}
+
+impl<DC: DayCounter> Lifecycle for FixedRateBond<DC> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = payments(&self.coupons, "coupons");
+        events.push(redemption(self.maturity_date));
+        sorted(events)
+    }
+}
+
+/// Coupons and principal are paid on the business days of the coupon
+/// dates.
+impl<C: Cal> Lifecycle for NtnB<C> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events: Vec<LifecycleEvent> = self
+            .payment_dates()
+            .into_iter()
+            .map(|date| LifecycleEvent {
+                date,
+                kind: LifecycleEventKind::Payment,
+                source: "coupons",
+            })
+            .collect();
+        events.push(redemption(self.calendar.adjust(self.maturity)));
+        sorted(events)
+    }
+}
+
+impl<C: Cal, CF: CashFlow, PE: PricingEngine> Lifecycle for Bond<C, CF, PE> {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let mut events = payments(&self.cashflows, "coupons");
+        events.extend(payments(&self.redemptions, "redemption"));
+        sorted(events)
+    }
+}
+
+/// Expiry and settlement of the payoff on the same date.
+fn expiry(date: Date) -> Vec<LifecycleEvent> {
+    vec![
+        LifecycleEvent {
+            date,
+            kind: LifecycleEventKind::Expiry,
+            source: "option",
+        },
+        LifecycleEvent {
+            date,
+            kind: LifecycleEventKind::Settlement,
+            source: "option",
+        },
+    ]
+}
+
+impl Lifecycle for VanillaOption {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        expiry(self.exercise_date)
+    }
+}
+
+/// Exercise events on the first American date and the Bermudan dates
+/// before the expiry.
+impl Lifecycle for EarlyExerciseOption {
+    fn events(&self) -> Vec<LifecycleEvent> {
+        let last = self.exercise.last_date();
+        let early = match &self.exercise {
+            Exercise::European(_) => vec![],
+            Exercise::American { earliest, .. } => vec![*earliest],
+            Exercise::Bermudan(dates) => dates.clone(),
+        };
+        let mut events: Vec<LifecycleEvent> = early
+            .into_iter()
+            .filter(|d| *d < last)
+            .map(|date| LifecycleEvent {
+                date,
+                kind: LifecycleEventKind::Exercise,
+                source: "option",
+            })
+            .collect();
+        events.extend(expiry(last));
+        events
+    }
+}
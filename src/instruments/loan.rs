@@ -0,0 +1,178 @@
+use crate::cashflows::{CashFlow, Event};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::{Date, Frequency};
+
+/// How a loan repays its principal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Amortization {
+    /// Equal payments of interest and principal.
+    Annuity,
+    /// Equal principal repayments.
+    Linear,
+    /// Interest only, principal at maturity.
+    Bullet,
+}
+
+/// Projected loan payment; `outstanding` is the balance after it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LoanPayment {
+    pub date: Date,
+    pub interest: f64,
+    pub principal: f64,
+    pub prepayment: f64,
+    pub outstanding: f64,
+}
+
+impl Event for LoanPayment {
+    fn date(&self) -> Date {
+        self.date
+    }
+    fn has_occured(&self, date: Date) -> bool {
+        self.date < date
+    }
+}
+
+impl CashFlow for LoanPayment {
+    fn amount(&self) -> f64 {
+        self.interest + self.principal + self.prepayment
+    }
+    fn has_occured(&self, date: Date, include_today: bool) -> bool {
+        if include_today {
+            self.date < date
+        } else {
+            self.date <= date
+        }
+    }
+    fn trading_ex_coupon(&self) -> bool {
+        false
+    }
+}
+
+/// Present value of the payments after `settlement`, discounted on
+/// `discount_curve` with a continuously compounded `spread` on top.
+pub fn npv_with_spread<Y: YieldTermStructure>(
+    payments: &[LoanPayment],
+    discount_curve: &Y,
+    spread: f64,
+    settlement: Date,
+) -> f64 {
+    payments
+        .iter()
+        .filter(|p| !CashFlow::has_occured(*p, settlement, false))
+        .map(|p| {
+            let t = discount_curve.time_from_reference(p.date);
+            p.amount() * discount_curve.discount(p.date, true) * (-spread * t).exp()
+        })
+        .sum()
+}
+
+/// Fixed rate loan paying `rate / frequency` on the outstanding balance
+/// each period, on unadjusted dates rolled from `start_date`.
+#[derive(Copy, Clone, Debug)]
+pub struct AmortizingLoan {
+    pub notional: f64,
+    pub rate: f64,
+    pub frequency: Frequency,
+    pub start_date: Date,
+    pub periods: usize,
+    pub amortization: Amortization,
+}
+
+impl AmortizingLoan {
+    pub fn new(
+        notional: f64,
+        rate: f64,
+        frequency: Frequency,
+        start_date: Date,
+        periods: usize,
+        amortization: Amortization,
+    ) -> AmortizingLoan {
+        assert!(notional > 0.0, "non positive notional");
+        assert!(periods > 0, "no periods given");
+        assert!(
+            12 % months_per_period(frequency) == 0,
+            "frequency must divide the year in whole months"
+        );
+        AmortizingLoan {
+            notional,
+            rate,
+            frequency,
+            start_date,
+            periods,
+            amortization,
+        }
+    }
+
+    pub fn payment_date(&self, period: usize) -> Date {
+        self.start_date
+            .add_months(months_per_period(self.frequency) * period as i64)
+    }
+
+    pub fn maturity_date(&self) -> Date {
+        self.payment_date(self.periods)
+    }
+
+    pub fn periodic_rate(&self) -> f64 {
+        self.rate / self.frequency.to_float()
+    }
+
+    /// Total payment of an annuity loan each period.
+    pub fn annuity_payment(&self) -> f64 {
+        annuity(self.notional, self.periodic_rate(), self.periods)
+    }
+
+    pub fn payments(&self) -> Vec<LoanPayment> {
+        let r = self.periodic_rate();
+        let mut outstanding = self.notional;
+        (1..=self.periods)
+            .map(|k| {
+                let interest = outstanding * r;
+                let principal = match self.amortization {
+                    Amortization::Annuity => self.annuity_payment() - interest,
+                    Amortization::Linear => self.notional / self.periods as f64,
+                    Amortization::Bullet => 0.0,
+                };
+                // the last payment repays what is left: all of it for a bullet, and
+                // any rounding otherwise
+                let principal = if k == self.periods {
+                    outstanding
+                } else {
+                    principal
+                };
+                outstanding -= principal;
+                LoanPayment {
+                    date: self.payment_date(k),
+                    interest,
+                    principal,
+                    prepayment: 0.0,
+                    outstanding,
+                }
+            })
+            .collect()
+    }
+
+    pub fn npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        spread: f64,
+        settlement: Date,
+    ) -> f64 {
+        npv_with_spread(&self.payments(), discount_curve, spread, settlement)
+    }
+}
+
+/// Payment of `periods` equal instalments repaying `notional` at the
+/// periodic rate `r`.
+pub fn annuity(notional: f64, r: f64, periods: usize) -> f64 {
+    if r == 0.0 {
+        notional / periods as f64
+    } else {
+        notional * r / (1.0 - (1.0 + r).powi(-(periods as i32)))
+    }
+}
+
+fn months_per_period(frequency: Frequency) -> i64 {
+    let n = frequency.to_float();
+    assert!(n >= 1.0, "at least one payment a year needed");
+    (12.0 / n) as i64
+}
@@ -0,0 +1,57 @@
+use super::SwapType;
+use crate::cashflows::{self as cf, CashFlow, Leg, LpiCashFlow};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+use crate::utils::trace::Level;
+
+/// Swap of a leg of LPI indexed amounts against a fixed leg, e.g. a
+/// pension scheme hedging its LPI linked liabilities; `Payer` pays the
+/// LPI leg.
+pub struct LpiSwap<CF: CashFlow> {
+    pub swap_type: SwapType,
+    pub lpi_leg: Leg<LpiCashFlow>,
+    pub fixed_leg: Leg<CF>,
+}
+
+impl<CF: CashFlow> LpiSwap<CF> {
+    pub fn new(swap_type: SwapType, lpi_leg: Leg<LpiCashFlow>, fixed_leg: Leg<CF>) -> LpiSwap<CF> {
+        assert!(
+            !lpi_leg.is_empty() && !fixed_leg.is_empty(),
+            "empty swap leg"
+        );
+        LpiSwap {
+            swap_type,
+            lpi_leg,
+            fixed_leg,
+        }
+    }
+
+    /// `1` if the LPI leg is received, `-1` if paid.
+    pub fn sign(&self) -> f64 {
+        match self.swap_type {
+            SwapType::Payer => -1.0,
+            SwapType::Receiver => 1.0,
+        }
+    }
+
+    pub fn fixed_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.fixed_leg, discount_curve, false, settlement)
+    }
+
+    /// Value of the LPI leg on the forecast fixings, with the collars at
+    /// their intrinsic value.
+    pub fn lpi_leg_npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        cf::npv(&self.lpi_leg, discount_curve, false, settlement)
+    }
+
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "lpi_swap.npv");
+        self.sign()
+            * (self.lpi_leg_npv(discount_curve, settlement)
+                - self.fixed_leg_npv(discount_curve, settlement))
+    }
+}
@@ -1,8 +1,47 @@
+pub mod assetswap;
 pub mod base;
+pub mod basisswap;
+pub mod bmaswap;
 pub mod bond;
 mod bonds;
+pub mod cache;
+pub mod difuture;
+pub mod exercise;
+pub mod futuresoption;
+pub mod inflationcapfloor;
+pub mod lifecycle;
+pub mod loan;
+pub mod lpiswap;
+pub mod mortgage;
+pub mod nonstandardswap;
+pub mod option;
 pub mod traits;
+pub mod vanillaoption;
+pub mod vanillaswap;
+pub mod volatility;
 
+pub use self::assetswap::{AssetSwap, AssetSwapType};
 pub use self::base::Base;
+pub use self::basisswap::{BasisSwap, SwapType};
+pub use self::bmaswap::BmaSwap;
 pub use self::bonds::*;
+pub use self::cache::{CacheMetrics, CachedInstrument};
+pub use self::difuture::{DiFuture, DI_FUTURE_NOTIONAL};
+pub use self::exercise::Exercise;
+pub use self::futuresoption::{FuturesOption, PremiumSettlement};
+pub use self::inflationcapfloor::{InflationSpreadOption, ZeroCouponInflationCapFloor};
+pub use self::lifecycle::{
+    curve_valuation, valuation, Lifecycle, LifecycleEvent, LifecycleEventKind, Valuation,
+    ValuationStatus,
+};
+pub use self::loan::{Amortization, AmortizingLoan, LoanPayment};
+pub use self::lpiswap::LpiSwap;
+pub use self::mortgage::{MortgagePool, PrepaymentModel};
+pub use self::nonstandardswap::NonstandardSwap;
+pub use self::option::OptionType;
 pub use self::traits::*;
+pub use self::vanillaoption::{EarlyExerciseOption, VanillaOption};
+pub use self::vanillaswap::VanillaSwap;
+pub use self::volatility::{
+    ForwardVolatilityAgreement, VolatilityIndexFuture, VolatilityIndexOption,
+};
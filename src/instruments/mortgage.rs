@@ -0,0 +1,121 @@
+use super::loan::{annuity, npv_with_spread, Amortization, AmortizingLoan, LoanPayment};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::{Date, Frequency};
+
+/// Voluntary prepayment speed of a mortgage pool.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PrepaymentModel {
+    /// Constant annual prepayment rate.
+    Cpr(f64),
+    /// Multiple of the PSA benchmark, whose annual rate rises by 0.2% a
+    /// month to 6% at month 30 and stays there.
+    Psa(f64),
+}
+
+impl PrepaymentModel {
+    /// Annual prepayment rate in the given month of the pool's life,
+    /// counted from one.
+    pub fn cpr(&self, month: usize) -> f64 {
+        match *self {
+            PrepaymentModel::Cpr(cpr) => cpr,
+            PrepaymentModel::Psa(speed) => speed * 0.06 * (month.min(30) as f64) / 30.0,
+        }
+    }
+
+    /// Fraction of the balance left after the scheduled principal that is
+    /// prepaid in the given month.
+    pub fn single_monthly_mortality(&self, month: usize) -> f64 {
+        let cpr = self.cpr(month).min(1.0);
+        1.0 - (1.0 - cpr).powf(1.0 / 12.0)
+    }
+}
+
+/// Pool of level payment mortgages with monthly payments, unscheduled
+/// principal paid according to `prepayment`.
+#[derive(Copy, Clone, Debug)]
+pub struct MortgagePool {
+    pub loan: AmortizingLoan,
+    pub prepayment: PrepaymentModel,
+    /// Months the pool has already seasoned at `loan.start_date`.
+    pub age: usize,
+}
+
+impl MortgagePool {
+    pub fn new(
+        balance: f64,
+        rate: f64,
+        start_date: Date,
+        months: usize,
+        prepayment: PrepaymentModel,
+    ) -> MortgagePool {
+        MortgagePool {
+            loan: AmortizingLoan::new(
+                balance,
+                rate,
+                Frequency::Monthly,
+                start_date,
+                months,
+                Amortization::Annuity,
+            ),
+            prepayment,
+            age: 0,
+        }
+    }
+
+    pub fn with_age(mut self, age: usize) -> MortgagePool {
+        self.age = age;
+        self
+    }
+
+    /// Projected monthly flows; the scheduled payment is reset each month
+    /// on the remaining balance and term.
+    pub fn payments(&self) -> Vec<LoanPayment> {
+        let r = self.loan.periodic_rate();
+        let n = self.loan.periods;
+        let mut outstanding = self.loan.notional;
+        let mut payments = Vec::with_capacity(n);
+        for k in 1..=n {
+            let interest = outstanding * r;
+            let principal = annuity(outstanding, r, n - k + 1) - interest;
+            let smm = self.prepayment.single_monthly_mortality(self.age + k);
+            let prepayment = if k == n {
+                0.0
+            } else {
+                smm * (outstanding - principal)
+            };
+            outstanding -= principal + prepayment;
+            payments.push(LoanPayment {
+                date: self.loan.payment_date(k),
+                interest,
+                principal,
+                prepayment,
+                outstanding: outstanding.max(0.0),
+            });
+            if outstanding <= 0.0 {
+                break;
+            }
+        }
+        payments
+    }
+
+    /// Average time in years until principal is returned, scheduled and
+    /// prepaid.
+    pub fn weighted_average_life(&self) -> f64 {
+        let payments = self.payments();
+        let weighted: f64 = payments
+            .iter()
+            .enumerate()
+            .map(|(k, p)| (k + 1) as f64 / 12.0 * (p.principal + p.prepayment))
+            .sum();
+        weighted / self.loan.notional
+    }
+
+    pub fn npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        spread: f64,
+        settlement: Date,
+    ) -> f64 {
+        npv_with_spread(&self.payments(), discount_curve, spread, settlement)
+    }
+}
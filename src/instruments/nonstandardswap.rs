@@ -0,0 +1,155 @@
+use super::SwapType;
+use crate::cashflows::{self as cf, analysis, CashFlow, Leg};
+use crate::definitions::Rate;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+
+const BASIS_POINT: f64 = 1.0e-4;
+
+/// Fixed-for-floating swap whose fixed rate and notionals may change from
+/// one period to the next, e.g. step-up coupons or an amortizing or
+/// accreting notional; `Payer` pays the fixed leg.
+///
+/// The legs are built on their own schedules, which may start forward
+/// and pay at different frequencies. The floating leg coupons must
+/// include `spread`.
+pub struct NonstandardSwap<FX: CashFlow, FL: CashFlow> {
+    pub swap_type: SwapType,
+    pub fixed_leg: Leg<FX>,
+    pub floating_leg: Leg<FL>,
+    pub spread: f64,
+}
+
+impl<FX: CashFlow, FL: CashFlow> NonstandardSwap<FX, FL> {
+    pub fn new(
+        swap_type: SwapType,
+        fixed_leg: Leg<FX>,
+        floating_leg: Leg<FL>,
+        spread: f64,
+    ) -> NonstandardSwap<FX, FL> {
+        assert!(
+            !fixed_leg.is_empty() && !floating_leg.is_empty(),
+            "empty swap leg"
+        );
+        NonstandardSwap {
+            swap_type,
+            fixed_leg,
+            floating_leg,
+            spread,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        match self.swap_type {
+            SwapType::Payer => 1.0,
+            SwapType::Receiver => -1.0,
+        }
+    }
+
+    /// The fixed rate of each fixed coupon.
+    pub fn fixed_rates(&self) -> Vec<Rate> {
+        self.fixed_leg
+            .iter()
+            .filter_map(|c| c.try_as_coup())
+            .map(|c| c.rate())
+            .collect()
+    }
+    /// The notional of each fixed coupon.
+    pub fn fixed_notionals(&self) -> Vec<f64> {
+        self.fixed_leg
+            .iter()
+            .filter_map(|c| c.try_as_coup())
+            .map(|c| c.nominal())
+            .collect()
+    }
+    /// The notional of each floating coupon.
+    pub fn floating_notionals(&self) -> Vec<f64> {
+        self.floating_leg
+            .iter()
+            .filter_map(|c| c.try_as_coup())
+            .map(|c| c.nominal())
+            .collect()
+    }
+
+    /// Value of the fixed leg, positive whichever side is taken.
+    pub fn fixed_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.fixed_leg, discount_curve, false, settlement)
+    }
+    /// Value of the floating leg, positive whichever side is taken.
+    pub fn floating_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.floating_leg, discount_curve, false, settlement)
+    }
+    pub fn fixed_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.fixed_leg, discount_curve, false, settlement)
+    }
+    pub fn floating_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.floating_leg, discount_curve, false, settlement)
+    }
+
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        self.sign()
+            * (self.floating_leg_npv(discount_curve, settlement)
+                - self.fixed_leg_npv(discount_curve, settlement))
+    }
+
+    /// The single fixed rate which, replacing the rate of every fixed
+    /// coupon, makes the swap worth zero.
+    pub fn fair_rate<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        fair_rate(
+            &self.fixed_leg,
+            self.floating_leg_npv(discount_curve, settlement),
+            discount_curve,
+            false,
+            settlement,
+        )
+    }
+
+    /// The floating leg spread making the swap worth zero.
+    pub fn fair_spread<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        let npv = self.floating_leg_npv(discount_curve, settlement)
+            - self.fixed_leg_npv(discount_curve, settlement);
+        let bps = self.floating_leg_bps(discount_curve, settlement);
+        assert!(bps != 0.0, "floating leg has no sensitivity to the spread");
+        self.spread - npv / bps * BASIS_POINT
+    }
+}
+
+/// The flat coupon rate of `fixed_leg` giving it, with its other flows,
+/// the value `floating_npv`.
+pub(crate) fn fair_rate<FX: CashFlow, Y: YieldTermStructure>(
+    fixed_leg: &[FX],
+    floating_npv: f64,
+    discount_curve: &Y,
+    include_settlement_date_flows: bool,
+    settlement: Date,
+) -> Rate {
+    let others: f64 = fixed_leg
+        .iter()
+        .filter(|c| c.try_as_coup().is_none())
+        .filter(|c| !CashFlow::has_occured(*c, settlement, include_settlement_date_flows))
+        .map(|c| c.amount() * discount_curve.discount(c.date(), true))
+        .sum();
+    analysis::atm_rate(
+        fixed_leg,
+        discount_curve,
+        include_settlement_date_flows,
+        settlement,
+        Some(floating_npv - others),
+    )
+}
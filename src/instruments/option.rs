@@ -0,0 +1,16 @@
+/// Right of an option.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+impl OptionType {
+    /// `1` for calls and `-1` for puts.
+    pub fn sign(&self) -> f64 {
+        match self {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::definitions::Money;
-use crate::time::Date;
 use crate::pricingengines::{Arguments, PricingEngine, Results};
+use crate::time::Date;
 use std::collections::HashMap;
 
 /// Instrument trait.
@@ -30,10 +30,7 @@ pub trait Instrument {
     /// instrument, this method should be overridden to read from
     /// it. This is mandatory in case a pricing engine is used.
     fn fetch_results<R: Results>(&mut self, results: R);
-    ///
     fn calculate(&mut self);
-    ///
     fn setup_expired(&mut self);
-    ///
     fn perform_calculations(&mut self);
 }
@@ -0,0 +1,59 @@
+use super::{Exercise, OptionType};
+use crate::time::Date;
+
+/// European option on a spot price, e.g. on a stock or an equity index.
+#[derive(Copy, Clone, Debug)]
+pub struct VanillaOption {
+    pub option_type: OptionType,
+    pub strike: f64,
+    pub exercise_date: Date,
+}
+
+impl VanillaOption {
+    pub fn new(option_type: OptionType, strike: f64, exercise_date: Date) -> VanillaOption {
+        assert!(strike >= 0.0, "negative strike");
+        VanillaOption {
+            option_type,
+            strike,
+            exercise_date,
+        }
+    }
+
+    pub fn payoff(&self, spot: f64) -> f64 {
+        (self.option_type.sign() * (spot - self.strike)).max(0.0)
+    }
+}
+
+/// Option on a spot price which may be exercisable before its expiry,
+/// e.g. an American put on a stock.
+#[derive(Clone, Debug)]
+pub struct EarlyExerciseOption {
+    pub option_type: OptionType,
+    pub strike: f64,
+    pub exercise: Exercise,
+}
+
+impl EarlyExerciseOption {
+    pub fn new(option_type: OptionType, strike: f64, exercise: Exercise) -> EarlyExerciseOption {
+        assert!(strike >= 0.0, "negative strike");
+        EarlyExerciseOption {
+            option_type,
+            strike,
+            exercise,
+        }
+    }
+
+    pub fn payoff(&self, spot: f64) -> f64 {
+        (self.option_type.sign() * (spot - self.strike)).max(0.0)
+    }
+}
+
+impl From<VanillaOption> for EarlyExerciseOption {
+    fn from(option: VanillaOption) -> EarlyExerciseOption {
+        EarlyExerciseOption::new(
+            option.option_type,
+            option.strike,
+            Exercise::European(option.exercise_date),
+        )
+    }
+}
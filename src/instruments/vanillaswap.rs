@@ -0,0 +1,114 @@
+use super::SwapType;
+use crate::cashflows::{self as cf, CashFlow, Leg};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+
+const BASIS_POINT: f64 = 1.0e-4;
+
+/// Fixed-for-floating interest rate swap; `Payer` pays the fixed leg.
+///
+/// The fixed leg coupons must accrue `fixed_rate` and the floating leg
+/// coupons must include `spread`.
+pub struct VanillaSwap<FX: CashFlow, FL: CashFlow> {
+    pub swap_type: SwapType,
+    pub fixed_leg: Leg<FX>,
+    pub fixed_rate: f64,
+    pub floating_leg: Leg<FL>,
+    pub spread: f64,
+}
+
+impl<FX: CashFlow, FL: CashFlow> VanillaSwap<FX, FL> {
+    pub fn new(
+        swap_type: SwapType,
+        fixed_leg: Leg<FX>,
+        fixed_rate: f64,
+        floating_leg: Leg<FL>,
+        spread: f64,
+    ) -> VanillaSwap<FX, FL> {
+        assert!(
+            !fixed_leg.is_empty() && !floating_leg.is_empty(),
+            "empty swap leg"
+        );
+        VanillaSwap {
+            swap_type,
+            fixed_leg,
+            fixed_rate,
+            floating_leg,
+            spread,
+        }
+    }
+
+    fn sign(&self) -> f64 {
+        match self.swap_type {
+            SwapType::Payer => 1.0,
+            SwapType::Receiver => -1.0,
+        }
+    }
+
+    /// Value of the fixed leg, positive whichever side is taken.
+    pub fn fixed_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.fixed_leg, discount_curve, false, settlement)
+    }
+    /// Value of the floating leg, positive whichever side is taken.
+    pub fn floating_leg_npv<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::npv(&self.floating_leg, discount_curve, false, settlement)
+    }
+    pub fn fixed_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.fixed_leg, discount_curve, false, settlement)
+    }
+    pub fn floating_leg_bps<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> f64 {
+        cf::bps(&self.floating_leg, discount_curve, false, settlement)
+    }
+
+    pub fn npv<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        self.sign()
+            * (self.floating_leg_npv(discount_curve, settlement)
+                - self.fixed_leg_npv(discount_curve, settlement))
+    }
+
+    /// The NPV of the flows paid after `settlement` expressed as of
+    /// `npv_date`, e.g. the forward value at the spot date for a swap
+    /// quoted for spot settlement.
+    pub fn npv_at<Y: YieldTermStructure>(
+        &self,
+        discount_curve: &Y,
+        settlement: Date,
+        npv_date: Date,
+    ) -> f64 {
+        self.npv(discount_curve, settlement) / discount_curve.discount(npv_date, true)
+    }
+
+    /// The fixed rate making the swap worth zero.
+    pub fn fair_rate<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        let npv = self.fixed_leg_npv(discount_curve, settlement)
+            - self.floating_leg_npv(discount_curve, settlement);
+        let bps = self.fixed_leg_bps(discount_curve, settlement);
+        assert!(bps != 0.0, "fixed leg has no sensitivity to the rate");
+        self.fixed_rate - npv / bps * BASIS_POINT
+    }
+
+    /// The floating leg spread making the swap worth zero.
+    pub fn fair_spread<Y: YieldTermStructure>(&self, discount_curve: &Y, settlement: Date) -> f64 {
+        let npv = self.floating_leg_npv(discount_curve, settlement)
+            - self.fixed_leg_npv(discount_curve, settlement);
+        let bps = self.floating_leg_bps(discount_curve, settlement);
+        assert!(bps != 0.0, "floating leg has no sensitivity to the spread");
+        self.spread - npv / bps * BASIS_POINT
+    }
+}
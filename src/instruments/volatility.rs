@@ -0,0 +1,99 @@
+use super::OptionType;
+use crate::definitions::{DiscountFactor, Time, Volatility};
+use crate::termstructures::traits::BlackVolTermStructure;
+
+/// Forward volatility agreement: at `expiry` pays `notional` times the
+/// difference between the implied volatility of the period from
+/// `start` to `expiry` and the agreed `strike` volatility.
+#[derive(Copy, Clone, Debug)]
+pub struct ForwardVolatilityAgreement {
+    pub notional: f64,
+    pub strike: Volatility,
+    pub start: Time,
+    pub expiry: Time,
+}
+
+impl ForwardVolatilityAgreement {
+    pub fn new(
+        notional: f64,
+        strike: Volatility,
+        start: Time,
+        expiry: Time,
+    ) -> ForwardVolatilityAgreement {
+        assert!(start >= 0.0 && expiry > start, "invalid forward period");
+        ForwardVolatilityAgreement {
+            notional,
+            strike,
+            start,
+            expiry,
+        }
+    }
+
+    /// Forward volatility implied by the surface at `atm_strike`.
+    pub fn fair_volatility<V: BlackVolTermStructure>(
+        &self,
+        vol: &V,
+        atm_strike: f64,
+    ) -> Volatility {
+        vol.black_forward_vol(self.start, self.expiry, atm_strike)
+    }
+
+    pub fn npv<V: BlackVolTermStructure>(
+        &self,
+        vol: &V,
+        atm_strike: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        self.notional * (self.fair_volatility(vol, atm_strike) - self.strike) * discount
+    }
+}
+
+/// Future settling at `expiry` on a volatility index such as the VIX, i.e.
+/// on the square root of the fair variance over the following `tenor`.
+#[derive(Copy, Clone, Debug)]
+pub struct VolatilityIndexFuture {
+    pub expiry: Time,
+    pub tenor: Time,
+    /// Currency amount per index point.
+    pub multiplier: f64,
+}
+
+impl VolatilityIndexFuture {
+    pub fn new(expiry: Time, tenor: Time, multiplier: f64) -> VolatilityIndexFuture {
+        assert!(expiry >= 0.0 && tenor > 0.0, "invalid future period");
+        VolatilityIndexFuture {
+            expiry,
+            tenor,
+            multiplier,
+        }
+    }
+
+    /// Futures price, in volatility units, from the mean and variance of
+    /// the squared index at expiry; the concavity of the square root is
+    /// taken into account to second order.
+    pub fn fair_value(mean_variance: f64, variance_of_variance: f64) -> Volatility {
+        assert!(mean_variance > 0.0, "non positive expected variance");
+        assert!(variance_of_variance >= 0.0, "negative variance of variance");
+        mean_variance.sqrt() - variance_of_variance / (8.0 * mean_variance.powf(1.5))
+    }
+}
+
+/// European option on a volatility index, settled on the index futures
+/// price at `expiry`.
+#[derive(Copy, Clone, Debug)]
+pub struct VolatilityIndexOption {
+    pub option_type: OptionType,
+    pub strike: Volatility,
+    pub expiry: Time,
+}
+
+impl VolatilityIndexOption {
+    pub fn new(option_type: OptionType, strike: Volatility, expiry: Time) -> VolatilityIndexOption {
+        assert!(expiry >= 0.0, "negative expiry");
+        VolatilityIndexOption {
+            option_type,
+            strike,
+            expiry,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Library to used for quantitative finance.
 #![allow(dead_code)]
+#![allow(clippy::too_many_arguments, clippy::module_inception)]
 #![deny(//missing_docs,
         unsafe_code,
         unused_import_braces,
@@ -9,11 +10,28 @@
 pub mod cashflows;
 pub mod currencies;
 pub mod definitions;
+pub mod errors;
+pub mod indexes;
 pub mod instruments;
+pub mod math;
+pub mod methods;
+pub mod models;
 pub mod patterns;
+pub mod persistence;
 pub mod pricingengines;
+pub mod processes;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod quotes;
+pub mod reports;
+pub mod risk;
+pub mod settings;
 pub mod termstructures;
+pub mod testutils;
 pub mod time;
+pub mod utils;
+#[cfg(feature = "viz")]
+pub mod viz;
+pub mod xva;
 
 pub use self::time::*;
@@ -0,0 +1,139 @@
+use super::{Matrix, SymmetricEigen};
+
+/// Splits a covariance matrix into the standard deviations and the
+/// correlation matrix.
+pub fn covariance_to_correlation(covariance: &Matrix) -> (Vec<f64>, Matrix) {
+    let n = covariance.rows();
+    assert!(n == covariance.columns(), "matrix is not square");
+    let stdevs: Vec<f64> = (0..n)
+        .map(|i| {
+            assert!(covariance[(i, i)] > 0.0, "non positive variance");
+            covariance[(i, i)].sqrt()
+        })
+        .collect();
+    let mut correlation = Matrix::new(n, n, 0.0);
+    for i in 0..n {
+        for j in 0..n {
+            correlation[(i, j)] = if i == j {
+                1.0
+            } else {
+                covariance[(i, j)] / (stdevs[i] * stdevs[j])
+            };
+        }
+    }
+    (stdevs, correlation)
+}
+
+pub fn correlation_to_covariance(stdevs: &[f64], correlation: &Matrix) -> Matrix {
+    let n = stdevs.len();
+    assert!(
+        correlation.rows() == n && correlation.columns() == n,
+        "volatilities and correlation size mismatch"
+    );
+    let mut covariance = Matrix::new(n, n, 0.0);
+    for i in 0..n {
+        for j in 0..n {
+            covariance[(i, j)] = stdevs[i] * stdevs[j] * correlation[(i, j)];
+        }
+    }
+    covariance
+}
+
+/// The closest correlation matrix to `m` in the Frobenius norm, found by
+/// Higham's alternating projections with Dykstra's correction; repairs
+/// estimated matrices that are not positive semi-definite.
+pub fn nearest_correlation_matrix(m: &Matrix, tolerance: f64, max_iterations: usize) -> Matrix {
+    let n = m.rows();
+    assert!(n == m.columns(), "matrix is not square");
+    let mut y = m.clone();
+    let mut correction = Matrix::new(n, n, 0.0);
+    for _ in 0..max_iterations {
+        let mut r = y.clone();
+        for i in 0..n {
+            for j in 0..n {
+                r[(i, j)] -= correction[(i, j)];
+            }
+        }
+        let x = positive_part(&r);
+        for i in 0..n {
+            for j in 0..n {
+                correction[(i, j)] = x[(i, j)] - r[(i, j)];
+            }
+        }
+        let mut next = x.clone();
+        for i in 0..n {
+            next[(i, i)] = 1.0;
+        }
+        let change = frobenius_distance(&next, &y) / frobenius_norm(&next);
+        y = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    y
+}
+
+/// Pseudo square root `B` with `rank` columns such that `B B'` is the
+/// correlation matrix closest to `correlation` spanned by its largest
+/// principal components; rows are rescaled to keep unit variances.
+pub fn rank_reduced_sqrt(correlation: &Matrix, rank: usize) -> Matrix {
+    let n = correlation.rows();
+    assert!(
+        rank >= 1 && rank <= n,
+        "rank must be between 1 and the size"
+    );
+    let eigen = SymmetricEigen::new(correlation);
+    let mut b = Matrix::new(n, rank, 0.0);
+    for i in 0..n {
+        for k in 0..rank {
+            b[(i, k)] = eigen.vectors[(i, k)] * eigen.values[k].max(0.0).sqrt();
+        }
+        let norm = (0..rank).map(|k| b[(i, k)] * b[(i, k)]).sum::<f64>().sqrt();
+        assert!(norm > 0.0, "rank too low for row {}", i);
+        for k in 0..rank {
+            b[(i, k)] /= norm;
+        }
+    }
+    b
+}
+
+/// The correlation matrix `B B'` of `rank_reduced_sqrt`.
+pub fn rank_reduced_correlation(correlation: &Matrix, rank: usize) -> Matrix {
+    let b = rank_reduced_sqrt(correlation, rank);
+    b.mul(&b.transpose())
+}
+
+/// Projection on the positive semi-definite matrices.
+fn positive_part(m: &Matrix) -> Matrix {
+    let n = m.rows();
+    let eigen = SymmetricEigen::new(m);
+    let mut x = Matrix::new(n, n, 0.0);
+    for k in 0..n {
+        let lambda = eigen.values[k].max(0.0);
+        if lambda == 0.0 {
+            continue;
+        }
+        for i in 0..n {
+            for j in 0..n {
+                x[(i, j)] += lambda * eigen.vectors[(i, k)] * eigen.vectors[(j, k)];
+            }
+        }
+    }
+    x
+}
+
+fn frobenius_norm(m: &Matrix) -> f64 {
+    (0..m.rows())
+        .flat_map(|i| m.row(i).iter())
+        .map(|x| x * x)
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn frobenius_distance(a: &Matrix, b: &Matrix) -> f64 {
+    (0..a.rows())
+        .flat_map(|i| a.row(i).iter().zip(b.row(i)))
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
@@ -0,0 +1,217 @@
+use std::f64::consts::PI;
+
+/// Standard normal density.
+pub fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Standard normal cumulative distribution, accurate to double precision
+/// (Hart 1968, as given by West 2005).
+pub fn normal_cdf(x: f64) -> f64 {
+    let y = x.abs();
+    let c = if y > 37.0 {
+        0.0
+    } else {
+        let e = (-0.5 * y * y).exp();
+        if y < 7.071_067_811_865_47 {
+            let mut n = 3.526_249_659_989_11e-2 * y + 0.700_383_064_443_688;
+            n = n * y + 6.373_962_203_531_65;
+            n = n * y + 33.912_866_078_383;
+            n = n * y + 112.079_291_497_871;
+            n = n * y + 221.213_596_169_931;
+            n = n * y + 220.206_867_912_376;
+            let mut d = 8.838_834_764_831_84e-2 * y + 1.755_667_163_182_64;
+            d = d * y + 16.064_177_579_207;
+            d = d * y + 86.780_732_202_946_1;
+            d = d * y + 296.564_248_779_674;
+            d = d * y + 637.333_633_378_831;
+            d = d * y + 793.826_512_519_948;
+            d = d * y + 440.413_735_824_752;
+            e * n / d
+        } else {
+            let mut b = y + 0.65;
+            b = y + 4.0 / b;
+            b = y + 3.0 / b;
+            b = y + 2.0 / b;
+            b = y + 1.0 / b;
+            e / b / 2.506_628_274_631
+        }
+    };
+    if x > 0.0 {
+        1.0 - c
+    } else {
+        c
+    }
+}
+
+/// Inverse of the standard normal cumulative distribution for `p` in
+/// (0, 1): Acklam's rational approximation refined by one Halley step.
+pub fn inverse_normal_cdf(p: f64) -> f64 {
+    assert!(p > 0.0 && p < 1.0, "probability must be in (0, 1)");
+    let a = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    let b = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    let c = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    let d = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    let tail = |q: f64| {
+        (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    };
+    let low = 0.024_25;
+    let x = if p < low {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - low {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q
+            / (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
+    };
+    let e = normal_cdf(x) - p;
+    let u = e / normal_pdf(x);
+    x - u / (1.0 + 0.5 * x * u)
+}
+
+/// Logarithm of the gamma function for positive arguments (Lanczos
+/// approximation, g = 7).
+pub fn ln_gamma(x: f64) -> f64 {
+    assert!(x > 0.0, "non positive argument");
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // reflection formula
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut a = COEFFICIENTS[0];
+    let t = x + 7.5;
+    for (i, c) in COEFFICIENTS.iter().enumerate().skip(1) {
+        a += c / (x + i as f64);
+    }
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// Regularized lower incomplete gamma function `P(a, x)`, by its series
+/// below `a + 1` and its continued fraction above.
+pub fn incomplete_gamma_p(a: f64, x: f64) -> f64 {
+    assert!(a > 0.0, "non positive shape");
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let prefactor = (a * x.ln() - x - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut n = a;
+        for _ in 0..10_000 {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * 1.0e-16 {
+                break;
+            }
+        }
+        (sum * prefactor).min(1.0)
+    } else {
+        // modified Lentz evaluation of the continued fraction for Q(a, x)
+        let tiny = 1.0e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..10_000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1.0e-16 {
+                break;
+            }
+        }
+        (1.0 - prefactor * h).max(0.0)
+    }
+}
+
+/// Chi-squared cumulative distribution with `k` degrees of freedom.
+pub fn chi_squared_cdf(x: f64, k: f64) -> f64 {
+    incomplete_gamma_p(0.5 * k, 0.5 * x)
+}
+
+/// Non-central chi-squared cumulative distribution with `k` degrees of
+/// freedom and non-centrality `lambda`, as the Poisson mixture of central
+/// ones summed outwards from its largest weight.
+pub fn non_central_chi_squared_cdf(x: f64, k: f64, lambda: f64) -> f64 {
+    assert!(k > 0.0, "non positive degrees of freedom");
+    assert!(lambda >= 0.0, "negative non-centrality");
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let half = 0.5 * lambda;
+    if half == 0.0 {
+        return chi_squared_cdf(x, k);
+    }
+    let mode = half.floor();
+    let weight = |j: f64| (-half + j * half.ln() - ln_gamma(j + 1.0)).exp();
+    let term = |j: f64| weight(j) * chi_squared_cdf(x, k + 2.0 * j);
+    let mut sum = term(mode);
+    let mut j = mode + 1.0;
+    loop {
+        sum += term(j);
+        if weight(j) < 1.0e-16 {
+            break;
+        }
+        j += 1.0;
+    }
+    let mut j = mode - 1.0;
+    while j >= 0.0 {
+        sum += term(j);
+        if weight(j) < 1.0e-16 {
+            break;
+        }
+        j -= 1.0;
+    }
+    sum.min(1.0)
+}
@@ -0,0 +1,84 @@
+use super::Matrix;
+
+/// Eigenvalues of a symmetric matrix in decreasing order, with the
+/// matching orthonormal eigenvectors as the columns of `vectors`.
+#[derive(Clone, Debug)]
+pub struct SymmetricEigen {
+    pub values: Vec<f64>,
+    pub vectors: Matrix,
+}
+
+impl SymmetricEigen {
+    /// Decomposes `m` by cyclic Jacobi rotations, which is accurate for the
+    /// small, dense matrices of correlation and curve factor models.
+    pub fn new(m: &Matrix) -> SymmetricEigen {
+        let n = m.rows();
+        assert!(n == m.columns(), "matrix is not square");
+        for i in 0..n {
+            for j in 0..i {
+                assert!(
+                    (m[(i, j)] - m[(j, i)]).abs()
+                        <= 1.0e-12 * (m[(i, j)].abs() + m[(j, i)].abs()).max(1.0),
+                    "matrix is not symmetric"
+                );
+            }
+        }
+        let mut a = m.clone();
+        let mut v = Matrix::identity(n);
+        let scale: f64 = (0..n)
+            .map(|i| a[(i, i)] * a[(i, i)])
+            .sum::<f64>()
+            .max(1.0e-300);
+        for _ in 0..100 {
+            let off: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[(i, j)] * a[(i, j)])
+                .sum();
+            if off <= 1.0e-30 * scale {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[(p, q)] == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(j, j)].partial_cmp(&a[(i, i)]).unwrap());
+        let values = order.iter().map(|&i| a[(i, i)]).collect();
+        let mut vectors = Matrix::new(n, n, 0.0);
+        for (column, &i) in order.iter().enumerate() {
+            // fix the sign so that the largest component is positive.
+            let largest = (0..n)
+                .max_by(|&x, &y| v[(x, i)].abs().partial_cmp(&v[(y, i)].abs()).unwrap())
+                .unwrap();
+            let sign = v[(largest, i)].signum();
+            for k in 0..n {
+                vectors[(k, column)] = sign * v[(k, i)];
+            }
+        }
+        SymmetricEigen { values, vectors }
+    }
+}
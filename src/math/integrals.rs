@@ -0,0 +1,14 @@
+use super::precision::Real;
+
+/// Integral of `f` from `a` to `b` by Simpson's rule on `intervals`
+/// (made even) intervals.
+pub fn simpson<R: Real, F: Fn(R) -> R>(f: F, a: R, b: R, intervals: usize) -> R {
+    let n = intervals.max(2).div_ceil(2) * 2;
+    let h = (b - a) / R::from_f64(n as f64);
+    let mut sum = f(a) + f(b);
+    for i in 1..n {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum = sum + R::from_f64(weight) * f(a + h * R::from_f64(i as f64));
+    }
+    sum * h / R::from_f64(3.0)
+}
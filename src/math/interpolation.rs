@@ -0,0 +1,452 @@
+use super::{linear_least_squares, Matrix};
+
+/// Scheme used to interpolate between nodes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Interpolation {
+    Linear,
+    /// Linear in the logarithm of the values, which gives piecewise flat
+    /// forwards on discount factors.
+    LogLinear,
+    /// Cubic spline with zero second derivative at both ends.
+    NaturalCubic,
+    /// Natural cubic spline with its node derivatives limited by the Hyman
+    /// filter, so that it is monotonic wherever the data are; on discount
+    /// factors this rules out negative forwards.
+    MonotonicCubic,
+    /// Hagan and West's convex monotone scheme on the logarithm of the
+    /// values, whose slope is piecewise quadratic and continuous; on
+    /// discount factors the forwards stay positive and keep the averages
+    /// between nodes.
+    ConvexMonotone,
+    /// Sum of Gaussian kernels of the given bandwidth centred on the nodes,
+    /// weighted to go through them; smooth for sparse, unevenly spaced
+    /// data.
+    Kernel {
+        bandwidth: f64,
+    },
+    /// Natural cubic spline on `knots` equally spaced knots fitted to the
+    /// nodes by least squares, which smooths noisy data instead of going
+    /// through it.
+    LeastSquaresSpline {
+        knots: usize,
+    },
+}
+
+/// Interpolation of `ys` over increasing `xs`, extrapolated with the first
+/// and last segments.
+#[derive(Clone, Debug)]
+pub struct Interpolator {
+    pub interpolation: Interpolation,
+    pub xs: Vec<f64>,
+    pub ys: Vec<f64>,
+    /// Node derivatives of the cubic schemes, of the logarithm for the
+    /// convex monotone one, or the kernel weights.
+    coefficients: Vec<f64>,
+    /// The spline fitted by least squares.
+    fit: Option<Box<Interpolator>>,
+}
+
+impl Interpolator {
+    pub fn new(interpolation: Interpolation, xs: Vec<f64>, ys: Vec<f64>) -> Interpolator {
+        assert!(xs.len() >= 2, "at least two nodes needed");
+        assert!(xs.len() == ys.len(), "abscissae and values size mismatch");
+        assert!(
+            xs.windows(2).all(|x| x[0] < x[1]),
+            "abscissae must be increasing"
+        );
+        if interpolation == Interpolation::LogLinear
+            || interpolation == Interpolation::ConvexMonotone
+        {
+            assert!(ys.iter().all(|y| *y > 0.0), "non positive value");
+        }
+        let mut fit = None;
+        let coefficients = match interpolation {
+            Interpolation::NaturalCubic => natural_spline_slopes(&xs, &ys),
+            Interpolation::MonotonicCubic => {
+                hyman_filter(&xs, &ys, natural_spline_slopes(&xs, &ys))
+            }
+            Interpolation::ConvexMonotone => convex_monotone_slopes(&xs, &ys),
+            Interpolation::Kernel { bandwidth } => kernel_weights(&xs, &ys, bandwidth),
+            Interpolation::LeastSquaresSpline { knots } => {
+                fit = Some(Box::new(least_squares_spline(&xs, &ys, knots)));
+                vec![]
+            }
+            _ => vec![],
+        };
+        Interpolator {
+            interpolation,
+            xs,
+            ys,
+            coefficients,
+            fit,
+        }
+    }
+
+    pub fn value(&self, x: f64) -> f64 {
+        if let Some(fit) = &self.fit {
+            return fit.value(x);
+        }
+        if let Interpolation::Kernel { bandwidth } = self.interpolation {
+            return self
+                .xs
+                .iter()
+                .zip(&self.coefficients)
+                .map(|(xj, a)| a * gaussian((x - xj) / bandwidth))
+                .sum();
+        }
+        let i = self.segment(x);
+        let (x1, x2) = (self.xs[i], self.xs[i + 1]);
+        let (y1, y2) = (self.ys[i], self.ys[i + 1]);
+        let h = x2 - x1;
+        match self.interpolation {
+            Interpolation::Linear => y1 + (y2 - y1) * (x - x1) / h,
+            Interpolation::LogLinear => (y1.ln() + (y2.ln() - y1.ln()) * (x - x1) / h).exp(),
+            Interpolation::ConvexMonotone => {
+                let (slope, g0, g1) = self.convex_monotone_segment(i);
+                let u = (x - x1) / h;
+                (y1.ln() + h * (slope * u + convex_monotone_integral(g0, g1, u))).exp()
+            }
+            Interpolation::Kernel { .. } | Interpolation::LeastSquaresSpline { .. } => {
+                unreachable!()
+            }
+            Interpolation::NaturalCubic | Interpolation::MonotonicCubic => {
+                let t = (x - x1) / h;
+                let (m1, m2) = (self.coefficients[i], self.coefficients[i + 1]);
+                (2.0 * t * t * t - 3.0 * t * t + 1.0) * y1
+                    + (t * t * t - 2.0 * t * t + t) * h * m1
+                    + (-2.0 * t * t * t + 3.0 * t * t) * y2
+                    + (t * t * t - t * t) * h * m2
+            }
+        }
+    }
+
+    pub fn derivative(&self, x: f64) -> f64 {
+        if let Some(fit) = &self.fit {
+            return fit.derivative(x);
+        }
+        if let Interpolation::Kernel { bandwidth } = self.interpolation {
+            return self
+                .xs
+                .iter()
+                .zip(&self.coefficients)
+                .map(|(xj, a)| {
+                    let u = (x - xj) / bandwidth;
+                    -a * u / bandwidth * gaussian(u)
+                })
+                .sum();
+        }
+        let i = self.segment(x);
+        let (x1, x2) = (self.xs[i], self.xs[i + 1]);
+        let (y1, y2) = (self.ys[i], self.ys[i + 1]);
+        let h = x2 - x1;
+        match self.interpolation {
+            Interpolation::Linear => (y2 - y1) / h,
+            Interpolation::LogLinear => self.value(x) * (y2.ln() - y1.ln()) / h,
+            Interpolation::ConvexMonotone => {
+                let (slope, g0, g1) = self.convex_monotone_segment(i);
+                let u = (x - x1) / h;
+                self.value(x) * (slope + convex_monotone_deviation(g0, g1, u))
+            }
+            Interpolation::Kernel { .. } | Interpolation::LeastSquaresSpline { .. } => {
+                unreachable!()
+            }
+            Interpolation::NaturalCubic | Interpolation::MonotonicCubic => {
+                let t = (x - x1) / h;
+                let (m1, m2) = (self.coefficients[i], self.coefficients[i + 1]);
+                ((6.0 * t * t - 6.0 * t) * y1 + (-6.0 * t * t + 6.0 * t) * y2) / h
+                    + (3.0 * t * t - 4.0 * t + 1.0) * m1
+                    + (3.0 * t * t - 2.0 * t) * m2
+            }
+        }
+    }
+
+    /// Average log-slope of segment `i` and the deviations from it of the
+    /// node log-slopes at its ends.
+    fn convex_monotone_segment(&self, i: usize) -> (f64, f64, f64) {
+        let h = self.xs[i + 1] - self.xs[i];
+        let slope = (self.ys[i + 1].ln() - self.ys[i].ln()) / h;
+        (
+            slope,
+            self.coefficients[i] - slope,
+            self.coefficients[i + 1] - slope,
+        )
+    }
+
+    /// Index of the segment used at `x`.
+    fn segment(&self, x: f64) -> usize {
+        let n = self.xs.len();
+        match self.xs.iter().position(|&xi| xi > x) {
+            Some(0) => 0,
+            Some(i) => i - 1,
+            None => n - 2,
+        }
+    }
+}
+
+/// One dimensional interpolation of `ys` over increasing `xs`, for code
+/// generic in the scheme; the counterpart of `Interpolation2D`.
+pub trait Interpolation1D {
+    fn new(xs: Vec<f64>, ys: Vec<f64>) -> Self
+    where
+        Self: Sized;
+    fn xs(&self) -> &[f64];
+    fn ys(&self) -> &[f64];
+    fn value(&self, x: f64) -> f64;
+    fn derivative(&self, x: f64) -> f64;
+}
+
+macro_rules! scheme {
+    ($(#[$doc:meta])* $name:ident, $interpolation:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            interpolator: Interpolator,
+        }
+
+        impl Interpolation1D for $name {
+            fn new(xs: Vec<f64>, ys: Vec<f64>) -> $name {
+                $name {
+                    interpolator: Interpolator::new($interpolation, xs, ys),
+                }
+            }
+            fn xs(&self) -> &[f64] {
+                &self.interpolator.xs
+            }
+            fn ys(&self) -> &[f64] {
+                &self.interpolator.ys
+            }
+            fn value(&self, x: f64) -> f64 {
+                self.interpolator.value(x)
+            }
+            fn derivative(&self, x: f64) -> f64 {
+                self.interpolator.derivative(x)
+            }
+        }
+    };
+}
+
+scheme!(LinearInterpolation, Interpolation::Linear);
+scheme!(
+    /// Piecewise flat forwards on discount factors.
+    LogLinearInterpolation,
+    Interpolation::LogLinear
+);
+scheme!(
+    /// Cubic spline with zero second derivative at both ends.
+    CubicNaturalSpline,
+    Interpolation::NaturalCubic
+);
+scheme!(
+    /// Hagan and West's convex monotone scheme on the logarithm of the
+    /// values.
+    MonotoneConvex,
+    Interpolation::ConvexMonotone
+);
+
+/// Node derivatives of the natural cubic spline through the nodes, found
+/// from the tridiagonal system for the second derivatives.
+fn natural_spline_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let h: Vec<f64> = xs.windows(2).map(|x| x[1] - x[0]).collect();
+    let s: Vec<f64> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+    // Thomas algorithm on the interior nodes; the end second derivatives
+    // are zero.
+    let mut second = vec![0.0; n];
+    if n > 2 {
+        let mut diagonal = vec![0.0; n];
+        let mut rhs = vec![0.0; n];
+        for i in 1..n - 1 {
+            diagonal[i] = 2.0 * (h[i - 1] + h[i]);
+            rhs[i] = 6.0 * (s[i] - s[i - 1]);
+        }
+        for i in 2..n - 1 {
+            let w = h[i - 1] / diagonal[i - 1];
+            diagonal[i] -= w * h[i - 1];
+            rhs[i] -= w * rhs[i - 1];
+        }
+        second[n - 2] = rhs[n - 2] / diagonal[n - 2];
+        for i in (1..n - 2).rev() {
+            second[i] = (rhs[i] - h[i] * second[i + 1]) / diagonal[i];
+        }
+    }
+
+    let mut slopes: Vec<f64> = (0..n - 1)
+        .map(|i| s[i] - h[i] * (2.0 * second[i] + second[i + 1]) / 6.0)
+        .collect();
+    slopes.push(s[n - 2] + h[n - 2] * (second[n - 2] + 2.0 * second[n - 1]) / 6.0);
+    slopes
+}
+
+fn gaussian(u: f64) -> f64 {
+    (-0.5 * u * u).exp()
+}
+
+/// Kernel weights reproducing the values at the nodes.
+fn kernel_weights(xs: &[f64], ys: &[f64], bandwidth: f64) -> Vec<f64> {
+    assert!(bandwidth > 0.0, "non positive bandwidth");
+    let n = xs.len();
+    let mut k = Matrix::new(n, n, 0.0);
+    for i in 0..n {
+        for j in 0..n {
+            k[(i, j)] = gaussian((xs[i] - xs[j]) / bandwidth);
+        }
+    }
+    linear_least_squares(&k, ys)
+}
+
+/// Natural cubic spline on equally spaced knots over the node range whose
+/// knot values minimize the squared errors at the nodes; the spline is
+/// linear in its knot values, so each column of the design is the spline
+/// of a unit vector.
+fn least_squares_spline(xs: &[f64], ys: &[f64], knots: usize) -> Interpolator {
+    assert!(knots >= 2, "at least two knots needed");
+    assert!(knots <= xs.len(), "more knots than nodes");
+    let (first, last) = (xs[0], xs[xs.len() - 1]);
+    let grid: Vec<f64> = (0..knots)
+        .map(|j| first + (last - first) * j as f64 / (knots - 1) as f64)
+        .collect();
+    let mut design = Matrix::new(xs.len(), knots, 0.0);
+    for j in 0..knots {
+        let mut unit = vec![0.0; knots];
+        unit[j] = 1.0;
+        let basis = Interpolator::new(Interpolation::NaturalCubic, grid.clone(), unit);
+        for (i, x) in xs.iter().enumerate() {
+            design[(i, j)] = basis.value(*x);
+        }
+    }
+    let values = linear_least_squares(&design, ys);
+    Interpolator::new(Interpolation::NaturalCubic, grid, values)
+}
+
+/// Limits the node derivatives of a cubic so that it is monotonic on every
+/// segment where the data are (Hyman 1983).
+fn hyman_filter(xs: &[f64], ys: &[f64], mut slopes: Vec<f64>) -> Vec<f64> {
+    let n = xs.len();
+    let s: Vec<f64> = (0..n - 1)
+        .map(|i| (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i]))
+        .collect();
+    let limit = |m: f64, secant: f64, bound: f64| {
+        if secant == 0.0 {
+            0.0
+        } else {
+            secant.signum() * (secant.signum() * m).max(0.0).min(bound)
+        }
+    };
+    slopes[0] = limit(slopes[0], s[0], 3.0 * s[0].abs());
+    for i in 1..n - 1 {
+        slopes[i] = if s[i - 1] * s[i] > 0.0 {
+            limit(slopes[i], s[i], 3.0 * s[i - 1].abs().min(s[i].abs()))
+        } else {
+            0.0
+        };
+    }
+    slopes[n - 1] = limit(slopes[n - 1], s[n - 2], 3.0 * s[n - 2].abs());
+    slopes
+}
+
+/// Node log-slopes of the convex monotone scheme, averaged from the
+/// neighbouring segments and collared to keep the sign of the data.
+fn convex_monotone_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let s: Vec<f64> = (0..n - 1)
+        .map(|i| (ys[i + 1].ln() - ys[i].ln()) / (xs[i + 1] - xs[i]))
+        .collect();
+    let mut f = vec![0.0; n];
+    for i in 1..n - 1 {
+        let w = (xs[i] - xs[i - 1]) / (xs[i + 1] - xs[i - 1]);
+        f[i] = w * s[i] + (1.0 - w) * s[i - 1];
+    }
+    if n > 2 {
+        f[0] = s[0] - 0.5 * (f[1] - s[0]);
+        f[n - 1] = s[n - 2] - 0.5 * (f[n - 2] - s[n - 2]);
+    } else {
+        f[0] = s[0];
+        f[1] = s[0];
+    }
+    // when all segments slope the same way, keep the node slopes between
+    // zero and twice the neighbouring averages.
+    let sign = s[0].signum();
+    if s.iter().all(|x| x.signum() == sign && *x != 0.0) {
+        let collar = |x: f64, bound: f64| sign * (sign * x).max(0.0).min(2.0 * bound);
+        f[0] = collar(f[0], s[0].abs());
+        for i in 1..n - 1 {
+            f[i] = collar(f[i], s[i - 1].abs().min(s[i].abs()));
+        }
+        f[n - 1] = collar(f[n - 1], s[n - 2].abs());
+    }
+    f
+}
+
+/// Deviation of the convex monotone slope from the segment average at
+/// `u` in [0, 1], given the deviations `g0` and `g1` at the ends; its
+/// integral over the segment is zero.
+fn convex_monotone_deviation(g0: f64, g1: f64, u: f64) -> f64 {
+    if g0 == 0.0 && g1 == 0.0 {
+        return 0.0;
+    }
+    if g0 == 0.0
+        || g1 == 0.0
+        || (g0 < 0.0 && -0.5 * g0 <= g1 && g1 <= -2.0 * g0)
+        || (g0 > 0.0 && -0.5 * g0 >= g1 && g1 >= -2.0 * g0)
+    {
+        g0 * (1.0 - 4.0 * u + 3.0 * u * u) + g1 * (-2.0 * u + 3.0 * u * u)
+    } else if (g0 < 0.0 && g1 > -2.0 * g0) || (g0 > 0.0 && g1 < -2.0 * g0) {
+        let eta = (g1 + 2.0 * g0) / (g1 - g0);
+        if u <= eta {
+            g0
+        } else {
+            g0 + (g1 - g0) * ((u - eta) / (1.0 - eta)).powi(2)
+        }
+    } else if (g0 > 0.0 && g1 < 0.0) || (g0 < 0.0 && g1 > 0.0) {
+        let eta = 3.0 * g1 / (g1 - g0);
+        if u < eta {
+            g1 + (g0 - g1) * ((eta - u) / eta).powi(2)
+        } else {
+            g1
+        }
+    } else {
+        let eta = g1 / (g0 + g1);
+        let a = -g0 * g1 / (g0 + g1);
+        if u <= eta {
+            a + (g0 - a) * ((eta - u) / eta).powi(2)
+        } else {
+            a + (g1 - a) * ((u - eta) / (1.0 - eta)).powi(2)
+        }
+    }
+}
+
+/// Integral of `convex_monotone_deviation` from 0 to `u`.
+fn convex_monotone_integral(g0: f64, g1: f64, u: f64) -> f64 {
+    if g0 == 0.0 && g1 == 0.0 {
+        return 0.0;
+    }
+    if g0 == 0.0
+        || g1 == 0.0
+        || (g0 < 0.0 && -0.5 * g0 <= g1 && g1 <= -2.0 * g0)
+        || (g0 > 0.0 && -0.5 * g0 >= g1 && g1 >= -2.0 * g0)
+    {
+        g0 * (u - 2.0 * u * u + u * u * u) + g1 * (-u * u + u * u * u)
+    } else if (g0 < 0.0 && g1 > -2.0 * g0) || (g0 > 0.0 && g1 < -2.0 * g0) {
+        let eta = (g1 + 2.0 * g0) / (g1 - g0);
+        if u <= eta {
+            g0 * u
+        } else {
+            g0 * u + (g1 - g0) * (u - eta).powi(3) / (3.0 * (1.0 - eta).powi(2))
+        }
+    } else if (g0 > 0.0 && g1 < 0.0) || (g0 < 0.0 && g1 > 0.0) {
+        let eta = 3.0 * g1 / (g1 - g0);
+        let v = ((eta - u) / eta).max(0.0);
+        g1 * u + (g0 - g1) * eta / 3.0 * (1.0 - v.powi(3))
+    } else {
+        let eta = g1 / (g0 + g1);
+        let a = -g0 * g1 / (g0 + g1);
+        if u <= eta {
+            a * u + (g0 - a) * eta / 3.0 * (1.0 - ((eta - u) / eta).powi(3))
+        } else {
+            a * u
+                + (g0 - a) * eta / 3.0
+                + (g1 - a) * (u - eta).powi(3) / (3.0 * (1.0 - eta).powi(2))
+        }
+    }
+}
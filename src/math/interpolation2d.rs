@@ -0,0 +1,93 @@
+use super::{Interpolation, Interpolator, Matrix};
+
+/// Interpolation of `z[(j, i)] = f(xs[i], ys[j])` over a rectangular
+/// grid, extrapolated with the edge cells.
+pub trait Interpolation2D {
+    fn new(xs: Vec<f64>, ys: Vec<f64>, zs: Matrix) -> Self
+    where
+        Self: Sized;
+    fn xs(&self) -> &[f64];
+    fn ys(&self) -> &[f64];
+    fn value(&self, x: f64, y: f64) -> f64;
+}
+
+fn check_grid(xs: &[f64], ys: &[f64], zs: &Matrix) {
+    assert!(xs.len() >= 2 && ys.len() >= 2, "at least a 2x2 grid needed");
+    assert!(
+        zs.rows() == ys.len() && zs.columns() == xs.len(),
+        "grid and values size mismatch"
+    );
+    assert!(
+        xs.windows(2).all(|x| x[0] < x[1]) && ys.windows(2).all(|y| y[0] < y[1]),
+        "grid abscissae must be increasing"
+    );
+}
+
+/// Index of the cell edge at or before `x`, clamped to the grid.
+fn cell(xs: &[f64], x: f64) -> usize {
+    match xs.iter().position(|&xi| xi > x) {
+        Some(0) => 0,
+        Some(i) => i - 1,
+        None => xs.len() - 2,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BilinearInterpolation {
+    pub xs: Vec<f64>,
+    pub ys: Vec<f64>,
+    pub zs: Matrix,
+}
+
+impl Interpolation2D for BilinearInterpolation {
+    fn new(xs: Vec<f64>, ys: Vec<f64>, zs: Matrix) -> BilinearInterpolation {
+        check_grid(&xs, &ys, &zs);
+        BilinearInterpolation { xs, ys, zs }
+    }
+    fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+    fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+    fn value(&self, x: f64, y: f64) -> f64 {
+        let (i, j) = (cell(&self.xs, x), cell(&self.ys, y));
+        let u = (x - self.xs[i]) / (self.xs[i + 1] - self.xs[i]);
+        let v = (y - self.ys[j]) / (self.ys[j + 1] - self.ys[j]);
+        let z = &self.zs;
+        (1.0 - u) * (1.0 - v) * z[(j, i)]
+            + u * (1.0 - v) * z[(j, i + 1)]
+            + (1.0 - u) * v * z[(j + 1, i)]
+            + u * v * z[(j + 1, i + 1)]
+    }
+}
+
+/// Natural cubic splines along `x` on each grid row, then along `y`
+/// through the row values.
+#[derive(Clone, Debug)]
+pub struct BicubicInterpolation {
+    pub xs: Vec<f64>,
+    pub ys: Vec<f64>,
+    pub zs: Matrix,
+    rows: Vec<Interpolator>,
+}
+
+impl Interpolation2D for BicubicInterpolation {
+    fn new(xs: Vec<f64>, ys: Vec<f64>, zs: Matrix) -> BicubicInterpolation {
+        check_grid(&xs, &ys, &zs);
+        let rows = (0..ys.len())
+            .map(|j| Interpolator::new(Interpolation::NaturalCubic, xs.clone(), zs.row(j).to_vec()))
+            .collect();
+        BicubicInterpolation { xs, ys, zs, rows }
+    }
+    fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+    fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+    fn value(&self, x: f64, y: f64) -> f64 {
+        let column = self.rows.iter().map(|r| r.value(x)).collect();
+        Interpolator::new(Interpolation::NaturalCubic, self.ys.clone(), column).value(y)
+    }
+}
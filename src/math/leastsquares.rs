@@ -0,0 +1,60 @@
+use super::Matrix;
+
+/// Solves the linear least-squares problem `min |A x - b|` by Householder
+/// QR decomposition.
+///
+/// Columns of `A` that are numerically dependent on the previous ones get a
+/// zero coefficient instead of making the problem blow up, which is what a
+/// regression on simulated paths needs when a basis function degenerates.
+pub fn linear_least_squares(a: &Matrix, b: &[f64]) -> Vec<f64> {
+    let m = a.rows();
+    let n = a.columns();
+    assert!(m == b.len(), "design matrix and target size mismatch");
+
+    let mut r = a.clone();
+    let mut y = b.to_vec();
+    let mut diagonal = vec![0.0; n];
+    let scale = (0..n)
+        .map(|j| r.column(j).iter().map(|x| x * x).sum::<f64>().sqrt())
+        .fold(0.0, f64::max);
+    let tolerance = 1.0e-12 * scale.max(1.0);
+
+    for k in 0..n.min(m) {
+        let norm = (k..m).map(|i| r[(i, k)] * r[(i, k)]).sum::<f64>().sqrt();
+        if norm <= tolerance {
+            continue;
+        }
+        let alpha = if r[(k, k)] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+        v[0] -= alpha;
+        let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+        if v_norm2 == 0.0 {
+            diagonal[k] = alpha;
+            continue;
+        }
+        for j in k..n {
+            let dot: f64 = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+            let f = 2.0 * dot / v_norm2;
+            for i in k..m {
+                r[(i, j)] -= f * v[i - k];
+            }
+        }
+        let dot: f64 = (k..m).map(|i| v[i - k] * y[i]).sum();
+        let f = 2.0 * dot / v_norm2;
+        for i in k..m {
+            y[i] -= f * v[i - k];
+        }
+        diagonal[k] = r[(k, k)];
+    }
+
+    // back substitution on the upper triangle.
+    let mut x = vec![0.0; n];
+    for k in (0..n.min(m)).rev() {
+        if diagonal[k].abs() <= tolerance {
+            continue;
+        }
+        let s: f64 = (k + 1..n).map(|j| r[(k, j)] * x[j]).sum();
+        x[k] = (y[k] - s) / diagonal[k];
+    }
+    x
+}
@@ -0,0 +1,109 @@
+use std::ops::{Index, IndexMut};
+
+/// Dense, row-major matrix of `f64`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    columns: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Creates a `rows` x `columns` matrix filled with `value`.
+    pub fn new(rows: usize, columns: usize, value: f64) -> Matrix {
+        Matrix {
+            rows,
+            columns,
+            data: vec![value; rows * columns],
+        }
+    }
+
+    /// Creates a square identity matrix.
+    pub fn identity(size: usize) -> Matrix {
+        let mut m = Matrix::new(size, size, 0.0);
+        for i in 0..size {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    /// Builds a matrix from its rows; all rows must have the same length.
+    pub fn from_rows(rows: &[Vec<f64>]) -> Matrix {
+        let columns = rows.first().map_or(0, |r| r.len());
+        let mut data = Vec::with_capacity(rows.len() * columns);
+        for r in rows {
+            assert!(r.len() == columns, "rows must have the same length");
+            data.extend_from_slice(r);
+        }
+        Matrix {
+            rows: rows.len(),
+            columns,
+            data,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row(&self, i: usize) -> &[f64] {
+        &self.data[i * self.columns..(i + 1) * self.columns]
+    }
+
+    pub fn column(&self, j: usize) -> Vec<f64> {
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut t = Matrix::new(self.columns, self.rows, 0.0);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    /// Matrix product `self * other`.
+    pub fn mul(&self, other: &Matrix) -> Matrix {
+        assert!(self.columns == other.rows, "matrix dimensions mismatch");
+        let mut m = Matrix::new(self.rows, other.columns, 0.0);
+        for i in 0..self.rows {
+            for k in 0..self.columns {
+                let a = self[(i, k)];
+                if a == 0.0 {
+                    continue;
+                }
+                for j in 0..other.columns {
+                    m[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        m
+    }
+
+    /// Matrix-vector product `self * v`.
+    pub fn mul_vec(&self, v: &[f64]) -> Vec<f64> {
+        assert!(self.columns == v.len(), "matrix dimensions mismatch");
+        (0..self.rows)
+            .map(|i| self.row(i).iter().zip(v).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.data[i * self.columns + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.data[i * self.columns + j]
+    }
+}
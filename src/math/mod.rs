@@ -0,0 +1,43 @@
+pub mod correlation;
+pub mod distributions;
+pub mod eigen;
+pub mod integrals;
+pub mod interpolation;
+pub mod interpolation2d;
+pub mod leastsquares;
+pub mod matrix;
+pub mod optimization;
+pub mod pca;
+pub mod precision;
+pub mod randomnumbers;
+pub mod rounding;
+pub mod solvers1d;
+
+pub use self::correlation::{
+    correlation_to_covariance, covariance_to_correlation, nearest_correlation_matrix,
+    rank_reduced_correlation, rank_reduced_sqrt,
+};
+pub use self::distributions::{
+    chi_squared_cdf, incomplete_gamma_p, inverse_normal_cdf, ln_gamma, non_central_chi_squared_cdf,
+    normal_cdf, normal_pdf,
+};
+pub use self::eigen::SymmetricEigen;
+pub use self::integrals::simpson;
+pub use self::interpolation::{
+    CubicNaturalSpline, Interpolation, Interpolation1D, Interpolator, LinearInterpolation,
+    LogLinearInterpolation, MonotoneConvex,
+};
+pub use self::interpolation2d::{BicubicInterpolation, BilinearInterpolation, Interpolation2D};
+pub use self::leastsquares::linear_least_squares;
+pub use self::matrix::Matrix;
+pub use self::optimization::simplex_minimize;
+pub use self::pca::PrincipalComponents;
+pub use self::precision::{DoubleDouble, Real};
+pub use self::randomnumbers::{
+    BoxMullerGaussianRng, InverseCumulativeRng, InverseCumulativeRsg, MersenneTwister,
+    RandomSequenceGenerator, Rng, Rsg, SobolRsg, SplitMix64,
+};
+pub use self::rounding::{Rounding, RoundingMode};
+pub use self::solvers1d::{
+    bracket, checked_bracket, Bisection, Brent, NewtonSafe, Secant, Solver1D,
+};
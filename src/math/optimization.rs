@@ -0,0 +1,112 @@
+use crate::utils::trace::Level;
+
+/// Minimizes `f` with the Nelder-Mead downhill simplex method, starting from
+/// `x0` with an initial simplex of edge `step`.
+///
+/// Stops when the function values on the simplex agree within `tolerance`
+/// or after `max_iterations`, returning the best vertex found. No
+/// derivatives are needed, which suits calibrations to noisy market data;
+/// constraints are best handled by a change of variables in `f`.
+pub fn simplex_minimize<F: Fn(&[f64]) -> f64>(
+    f: F,
+    x0: &[f64],
+    step: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Vec<f64> {
+    let n = x0.len();
+    assert!(n > 0, "empty starting point");
+    let _span = crate::trace_span!(Level::Debug, "simplex_minimize", dimension = n);
+    let mut vertices = vec![x0.to_vec()];
+    for i in 0..n {
+        let mut x = x0.to_vec();
+        x[i] += step;
+        vertices.push(x);
+    }
+    let mut values: Vec<f64> = vertices.iter().map(|x| f(x)).collect();
+
+    let mut iterations = 0;
+    let mut converged = false;
+    for iteration in 0..max_iterations {
+        iterations = iteration + 1;
+        let mut order: Vec<usize> = (0..=n).collect();
+        order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap());
+        vertices = order.iter().map(|&i| vertices[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+
+        let spread = (values[n] - values[0]).abs();
+        crate::trace_event!(
+            Level::Trace,
+            "simplex.iteration",
+            iteration = iteration,
+            best = values[0],
+            spread = spread
+        );
+        if spread <= tolerance * (values[0].abs() + values[n].abs()).max(1.0e-20) {
+            converged = true;
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..n)
+            .map(|j| vertices[..n].iter().map(|x| x[j]).sum::<f64>() / n as f64)
+            .collect();
+        let along = |t: f64| -> Vec<f64> {
+            centroid
+                .iter()
+                .zip(&vertices[n])
+                .map(|(c, w)| c + t * (w - c))
+                .collect()
+        };
+
+        let reflected = along(-1.0);
+        let fr = f(&reflected);
+        if fr < values[0] {
+            let expanded = along(-2.0);
+            let fe = f(&expanded);
+            if fe < fr {
+                vertices[n] = expanded;
+                values[n] = fe;
+            } else {
+                vertices[n] = reflected;
+                values[n] = fr;
+            }
+        } else if fr < values[n - 1] {
+            vertices[n] = reflected;
+            values[n] = fr;
+        } else {
+            let contracted = if fr < values[n] {
+                along(-0.5)
+            } else {
+                along(0.5)
+            };
+            let fc = f(&contracted);
+            if fc < values[n].min(fr) {
+                vertices[n] = contracted;
+                values[n] = fc;
+            } else {
+                // shrink towards the best vertex.
+                for i in 1..=n {
+                    let shrunk: Vec<f64> = vertices[0]
+                        .iter()
+                        .zip(&vertices[i])
+                        .map(|(b, x)| b + 0.5 * (x - b))
+                        .collect();
+                    values[i] = f(&shrunk);
+                    vertices[i] = shrunk;
+                }
+            }
+        }
+    }
+
+    let best = (0..=n)
+        .min_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap())
+        .unwrap();
+    crate::trace_event!(
+        Level::Debug,
+        "simplex.finished",
+        iterations = iterations,
+        converged = converged,
+        best = values[best]
+    );
+    vertices[best].clone()
+}
@@ -0,0 +1,75 @@
+use super::{Matrix, SymmetricEigen};
+
+/// Principal components of observations given as the rows of a matrix,
+/// with one column per variable.
+#[derive(Clone, Debug)]
+pub struct PrincipalComponents {
+    pub mean: Vec<f64>,
+    /// Variance along each component, largest first.
+    pub variances: Vec<f64>,
+    /// Unit loadings of the components on the variables, one column per
+    /// component.
+    pub loadings: Matrix,
+}
+
+impl PrincipalComponents {
+    pub fn new(observations: &Matrix) -> PrincipalComponents {
+        let (m, n) = (observations.rows(), observations.columns());
+        assert!(m >= 2, "at least two observations needed");
+        let mean: Vec<f64> = (0..n)
+            .map(|j| observations.column(j).iter().sum::<f64>() / m as f64)
+            .collect();
+        let mut covariance = Matrix::new(n, n, 0.0);
+        for i in 0..n {
+            for j in 0..=i {
+                let c = (0..m)
+                    .map(|k| (observations[(k, i)] - mean[i]) * (observations[(k, j)] - mean[j]))
+                    .sum::<f64>()
+                    / (m - 1) as f64;
+                covariance[(i, j)] = c;
+                covariance[(j, i)] = c;
+            }
+        }
+        let eigen = SymmetricEigen::new(&covariance);
+        PrincipalComponents {
+            mean,
+            variances: eigen.values.iter().map(|v| v.max(0.0)).collect(),
+            loadings: eigen.vectors,
+        }
+    }
+
+    /// Share of the total variance explained by each component.
+    pub fn explained_variance_ratio(&self) -> Vec<f64> {
+        let total: f64 = self.variances.iter().sum();
+        self.variances.iter().map(|v| v / total).collect()
+    }
+
+    /// The number of leading components explaining at least `ratio` of
+    /// the total variance.
+    pub fn components_for(&self, ratio: f64) -> usize {
+        let mut explained = 0.0;
+        for (k, r) in self.explained_variance_ratio().iter().enumerate() {
+            explained += r;
+            if explained >= ratio - 1.0e-12 {
+                return k + 1;
+            }
+        }
+        self.variances.len()
+    }
+
+    /// Coordinates of the centred observations on each component, one row
+    /// per observation.
+    pub fn scores(&self, observations: &Matrix) -> Matrix {
+        let n = self.mean.len();
+        assert!(observations.columns() == n, "variable count mismatch");
+        let mut scores = Matrix::new(observations.rows(), n, 0.0);
+        for k in 0..observations.rows() {
+            for c in 0..n {
+                scores[(k, c)] = (0..n)
+                    .map(|j| (observations[(k, j)] - self.mean[j]) * self.loadings[(j, c)])
+                    .sum();
+            }
+        }
+        scores
+    }
+}
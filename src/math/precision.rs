@@ -0,0 +1,234 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Floating point backend of the numerical kernels, the one dimensional
+/// solvers and `simpson`, so that a result sensitive to round-off can be
+/// checked again in extended precision by switching the type parameter
+/// from `f64` to `DoubleDouble`.
+pub trait Real:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn from_f64(x: f64) -> Self;
+    /// The nearest `f64`.
+    fn to_f64(self) -> f64;
+    fn abs(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn is_nan(self) -> bool;
+    /// Relative spacing of the representable numbers near one.
+    fn epsilon() -> f64;
+}
+
+impl Real for f64 {
+    fn from_f64(x: f64) -> f64 {
+        x
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn abs(self) -> f64 {
+        f64::abs(self)
+    }
+    fn exp(self) -> f64 {
+        f64::exp(self)
+    }
+    fn ln(self) -> f64 {
+        f64::ln(self)
+    }
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+    fn epsilon() -> f64 {
+        f64::EPSILON
+    }
+}
+
+/// Unevaluated sum `hi + lo` of two doubles with `|lo| <= ulp(hi) / 2`,
+/// giving about 32 significant digits (Dekker, Bailey's QD library).
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+const LN_2: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::LN_2,
+    lo: 2.319_046_813_846_299_6e-17,
+};
+
+/// `a + b` and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// `two_sum` for `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// `a b` and its rounding error.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    pub fn new(hi: f64, lo: f64) -> DoubleDouble {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    fn from_parts((hi, lo): (f64, f64)) -> DoubleDouble {
+        DoubleDouble { hi, lo }
+    }
+
+    /// Exact product with a power of two.
+    fn scale(self, factor: f64) -> DoubleDouble {
+        DoubleDouble {
+            hi: self.hi * factor,
+            lo: self.lo * factor,
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> DoubleDouble {
+        DoubleDouble { hi: x, lo: 0.0 }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+    fn add(self, other: DoubleDouble) -> DoubleDouble {
+        let (s, e) = two_sum(self.hi, other.hi);
+        let (t, f) = two_sum(self.lo, other.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        DoubleDouble::from_parts(quick_two_sum(s, e + f))
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+    fn neg(self) -> DoubleDouble {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+    fn sub(self, other: DoubleDouble) -> DoubleDouble {
+        self + (-other)
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+    fn mul(self, other: DoubleDouble) -> DoubleDouble {
+        let (p, e) = two_prod(self.hi, other.hi);
+        let e = e + (self.hi * other.lo + self.lo * other.hi);
+        DoubleDouble::from_parts(quick_two_sum(p, e))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+    /// Long division with three quotient digits.
+    fn div(self, other: DoubleDouble) -> DoubleDouble {
+        let q1 = self.hi / other.hi;
+        let r = self - other * DoubleDouble::from(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other * DoubleDouble::from(q2);
+        let q3 = r.hi / other.hi;
+        DoubleDouble::from_parts(quick_two_sum(q1, q2)) + DoubleDouble::from(q3)
+    }
+}
+
+impl Real for DoubleDouble {
+    fn from_f64(x: f64) -> DoubleDouble {
+        DoubleDouble::from(x)
+    }
+    fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+    fn abs(self) -> DoubleDouble {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Reduces the argument by multiples of ln 2 and a further 2^-10,
+    /// sums the Taylor series of `expm1` and squares back up.
+    fn exp(self) -> DoubleDouble {
+        if self.hi > 709.0 {
+            return DoubleDouble::from(f64::INFINITY);
+        }
+        if self.hi < -745.0 {
+            return DoubleDouble::from(0.0);
+        }
+        let k = (self.hi / LN_2.hi).round();
+        let r = (self - LN_2 * DoubleDouble::from(k)).scale(1.0 / 1024.0);
+        let mut term = r;
+        let mut s = r;
+        for n in 2..30 {
+            term = term * r / DoubleDouble::from(n as f64);
+            s = s + term;
+            if term.hi.abs() < 1.0e-36 {
+                break;
+            }
+        }
+        // expm1(2x) = expm1(x) (2 + expm1(x))
+        for _ in 0..10 {
+            s = s.scale(2.0) + s * s;
+        }
+        (s + DoubleDouble::from(1.0)).scale(2f64.powi(k as i32))
+    }
+
+    /// Newton steps on `exp(y) = x` from the double precision logarithm.
+    fn ln(self) -> DoubleDouble {
+        assert!(self.hi > 0.0, "logarithm of a non positive number");
+        let one = DoubleDouble::from(1.0);
+        let mut y = DoubleDouble::from(self.hi.ln());
+        for _ in 0..2 {
+            y = y + self * (-y).exp() - one;
+        }
+        y
+    }
+
+    /// Karp and Markstein's step from the double precision root.
+    fn sqrt(self) -> DoubleDouble {
+        assert!(self.hi >= 0.0, "square root of a negative number");
+        if self.hi == 0.0 {
+            return DoubleDouble::from(0.0);
+        }
+        let x = 1.0 / self.hi.sqrt();
+        let ax = DoubleDouble::from(self.hi * x);
+        ax + DoubleDouble::from((self - ax * ax).hi * x * 0.5)
+    }
+
+    fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+
+    fn epsilon() -> f64 {
+        4.93e-32
+    }
+}
@@ -0,0 +1,73 @@
+use super::{Rng, Rsg};
+use crate::math::inverse_normal_cdf;
+
+/// Standard normals from pairs of uniforms of `rng` by the Box-Muller
+/// transform.
+#[derive(Clone, Debug)]
+pub struct BoxMullerGaussianRng<R: Rng> {
+    pub rng: R,
+    spare: Option<f64>,
+}
+
+impl<R: Rng> BoxMullerGaussianRng<R> {
+    pub fn new(rng: R) -> BoxMullerGaussianRng<R> {
+        BoxMullerGaussianRng { rng, spare: None }
+    }
+}
+
+impl<R: Rng> Rng for BoxMullerGaussianRng<R> {
+    fn next_sample(&mut self) -> f64 {
+        if let Some(z) = self.spare.take() {
+            return z;
+        }
+        let radius = (-2.0 * self.rng.next_sample().ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.rng.next_sample();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+/// Standard normals by inverting the normal distribution at the uniforms
+/// of `rng`.
+#[derive(Clone)]
+pub struct InverseCumulativeRng<R: Rng> {
+    pub rng: R,
+}
+
+impl<R: Rng> InverseCumulativeRng<R> {
+    pub fn new(rng: R) -> InverseCumulativeRng<R> {
+        InverseCumulativeRng { rng }
+    }
+}
+
+impl<R: Rng> Rng for InverseCumulativeRng<R> {
+    fn next_sample(&mut self) -> f64 {
+        inverse_normal_cdf(self.rng.next_sample())
+    }
+}
+
+/// Sequences of standard normals inverted from the uniform sequences of
+/// `rsg`, keeping the low discrepancy of a quasi-random one.
+#[derive(Clone)]
+pub struct InverseCumulativeRsg<S: Rsg> {
+    pub rsg: S,
+}
+
+impl<S: Rsg> InverseCumulativeRsg<S> {
+    pub fn new(rsg: S) -> InverseCumulativeRsg<S> {
+        InverseCumulativeRsg { rsg }
+    }
+}
+
+impl<S: Rsg> Rsg for InverseCumulativeRsg<S> {
+    fn dimension(&self) -> usize {
+        self.rsg.dimension()
+    }
+    fn next_sequence(&mut self) -> Vec<f64> {
+        self.rsg
+            .next_sequence()
+            .into_iter()
+            .map(inverse_normal_cdf)
+            .collect()
+    }
+}
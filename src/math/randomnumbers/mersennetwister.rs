@@ -0,0 +1,60 @@
+use super::Rng;
+
+const N: usize = 624;
+const M: usize = 397;
+const MATRIX_A: u32 = 0x9908_b0df;
+const UPPER_MASK: u32 = 0x8000_0000;
+const LOWER_MASK: u32 = 0x7fff_ffff;
+
+/// Mersenne Twister MT19937 uniform generator, with period `2^19937 - 1`.
+/// Its uniforms lie in the open interval (0, 1).
+#[derive(Clone)]
+pub struct MersenneTwister {
+    state: Vec<u32>,
+    index: usize,
+}
+
+impl MersenneTwister {
+    pub fn new(seed: u32) -> MersenneTwister {
+        let mut state = vec![0u32; N];
+        state[0] = seed;
+        for i in 1..N {
+            let previous = state[i - 1];
+            state[i] = 1_812_433_253u32
+                .wrapping_mul(previous ^ (previous >> 30))
+                .wrapping_add(i as u32);
+        }
+        MersenneTwister { state, index: N }
+    }
+
+    fn twist(&mut self) {
+        for i in 0..N {
+            let y = (self.state[i] & UPPER_MASK) | (self.state[(i + 1) % N] & LOWER_MASK);
+            let mut next = self.state[(i + M) % N] ^ (y >> 1);
+            if y & 1 != 0 {
+                next ^= MATRIX_A;
+            }
+            self.state[i] = next;
+        }
+        self.index = 0;
+    }
+
+    /// The next 32 bit output.
+    pub fn next_int32(&mut self) -> u32 {
+        if self.index >= N {
+            self.twist();
+        }
+        let mut y = self.state[self.index];
+        self.index += 1;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }
+}
+
+impl Rng for MersenneTwister {
+    fn next_sample(&mut self) -> f64 {
+        (f64::from(self.next_int32()) + 0.5) / 4_294_967_296.0
+    }
+}
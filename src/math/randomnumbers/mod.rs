@@ -0,0 +1,11 @@
+pub mod gaussianrng;
+pub mod mersennetwister;
+pub mod sobolrsg;
+pub mod splitmix;
+pub mod traits;
+
+pub use self::gaussianrng::{BoxMullerGaussianRng, InverseCumulativeRng, InverseCumulativeRsg};
+pub use self::mersennetwister::MersenneTwister;
+pub use self::sobolrsg::SobolRsg;
+pub use self::splitmix::SplitMix64;
+pub use self::traits::{RandomSequenceGenerator, Rng, Rsg};
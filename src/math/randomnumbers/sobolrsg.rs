@@ -0,0 +1,105 @@
+use super::Rsg;
+
+const BITS: usize = 32;
+
+/// Degree, coefficients and initial direction numbers of the primitive
+/// polynomials of the dimensions after the first, from Joe and Kuo's
+/// `new-joe-kuo-6.21201` table.
+const DIRECTIONS: [(usize, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Sobol low-discrepancy sequences in up to 21 dimensions, generated in
+/// Gray code order from the point after the origin.
+#[derive(Clone, Debug)]
+pub struct SobolRsg {
+    /// Direction integers of each dimension, the k-th with its leading
+    /// bit at the k-th position from the top.
+    directions: Vec<[u32; BITS]>,
+    integers: Vec<u32>,
+    count: u32,
+}
+
+impl SobolRsg {
+    pub fn new(dimension: usize) -> SobolRsg {
+        assert!(
+            dimension > 0 && dimension <= DIRECTIONS.len() + 1,
+            "Sobol dimension out of range"
+        );
+        let mut directions = vec![[0u32; BITS]];
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (BITS - 1 - k);
+        }
+        for &(degree, a, m) in &DIRECTIONS[..dimension - 1] {
+            let mut v = [0u32; BITS];
+            for k in 0..BITS {
+                v[k] = if k < degree {
+                    m[k] << (BITS - 1 - k)
+                } else {
+                    let mut next = v[k - degree] ^ (v[k - degree] >> degree);
+                    for j in 1..degree {
+                        if (a >> (degree - 1 - j)) & 1 == 1 {
+                            next ^= v[k - j];
+                        }
+                    }
+                    next
+                };
+            }
+            directions.push(v);
+        }
+        SobolRsg {
+            directions,
+            integers: vec![0; dimension],
+            count: 0,
+        }
+    }
+
+    /// Skips the next `points` points.
+    pub fn skip_to(&mut self, points: u32) {
+        for _ in 0..points {
+            self.next_integers();
+        }
+    }
+
+    fn next_integers(&mut self) {
+        assert!(self.count < u32::MAX, "Sobol sequence exhausted");
+        // the bit flipped between the Gray codes of count and count + 1
+        let bit = self.count.trailing_ones() as usize;
+        for (x, v) in self.integers.iter_mut().zip(&self.directions) {
+            *x ^= v[bit];
+        }
+        self.count += 1;
+    }
+}
+
+impl Rsg for SobolRsg {
+    fn dimension(&self) -> usize {
+        self.integers.len()
+    }
+    fn next_sequence(&mut self) -> Vec<f64> {
+        self.next_integers();
+        self.integers
+            .iter()
+            .map(|x| f64::from(*x) / 4_294_967_296.0)
+            .collect()
+    }
+}
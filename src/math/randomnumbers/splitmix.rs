@@ -0,0 +1,41 @@
+use super::Rng;
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// SplitMix64 uniform generator, small and seedable from any 64 bit value,
+/// with period `2^64`. Its uniforms lie in the open interval (0, 1).
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    /// The generator of the `stream`-th stream of `seed`, its seed hashed
+    /// so that the streams start far apart in the sequence, e.g. one per
+    /// batch of a parallel simulation.
+    pub fn stream(seed: u64, stream: usize) -> SplitMix64 {
+        SplitMix64::new(mix(seed ^ (stream as u64 + 1).wrapping_mul(GOLDEN_GAMMA)))
+    }
+
+    /// The next 64 bit output.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix(self.state)
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_sample(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+}
+
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,36 @@
+/// Generator of random numbers, one at a time.
+pub trait Rng {
+    fn next_sample(&mut self) -> f64;
+}
+
+/// Generator of sequences of a fixed dimension, e.g. the draws of one
+/// path, which a low-discrepancy generator does not produce independently.
+pub trait Rsg {
+    fn dimension(&self) -> usize;
+    fn next_sequence(&mut self) -> Vec<f64>;
+}
+
+/// Sequences of `dimension` consecutive draws of `rng`.
+#[derive(Clone, Debug)]
+pub struct RandomSequenceGenerator<R: Rng> {
+    pub rng: R,
+    pub dimension: usize,
+}
+
+impl<R: Rng> RandomSequenceGenerator<R> {
+    pub fn new(rng: R, dimension: usize) -> RandomSequenceGenerator<R> {
+        assert!(dimension > 0, "zero dimension");
+        RandomSequenceGenerator { rng, dimension }
+    }
+}
+
+impl<R: Rng> Rsg for RandomSequenceGenerator<R> {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+    fn next_sequence(&mut self) -> Vec<f64> {
+        (0..self.dimension)
+            .map(|_| self.rng.next_sample())
+            .collect()
+    }
+}
@@ -0,0 +1,67 @@
+/// How a value is rounded to the last kept digit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RoundingMode {
+    /// Away from zero.
+    Up,
+    /// Towards zero.
+    Down,
+    /// To the nearest digit, halves away from zero.
+    Closest,
+    /// To the nearest digit, halves to the even one (banker's rounding).
+    HalfEven,
+}
+
+/// Rounding to a number of decimal places.
+///
+/// Values are rounded in units of the last kept digit, treating scaled
+/// values within `1e-9` of a half or a whole unit as exactly on it, so
+/// that binary representation errors such as `2.675` being stored as
+/// `2.67499...` do not change the result.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rounding {
+    pub precision: u32,
+    pub mode: RoundingMode,
+}
+
+const SNAP: f64 = 1.0e-9;
+
+impl Rounding {
+    pub fn new(precision: u32, mode: RoundingMode) -> Rounding {
+        Rounding { precision, mode }
+    }
+
+    pub fn closest(precision: u32) -> Rounding {
+        Rounding::new(precision, RoundingMode::Closest)
+    }
+
+    /// `x` rounded, as an integer number of units of the last digit.
+    pub fn units(&self, x: f64) -> i64 {
+        let scaled = x.abs() * 10f64.powi(self.precision as i32);
+        let whole = scaled.floor();
+        let fraction = scaled - whole;
+        let units = if fraction < SNAP {
+            whole
+        } else if fraction > 1.0 - SNAP {
+            whole + 1.0
+        } else {
+            let half = (fraction - 0.5).abs() < SNAP;
+            let up = match self.mode {
+                RoundingMode::Up => true,
+                RoundingMode::Down => false,
+                RoundingMode::Closest => half || fraction > 0.5,
+                RoundingMode::HalfEven if half => whole % 2.0 == 1.0,
+                RoundingMode::HalfEven => fraction > 0.5,
+            };
+            if up {
+                whole + 1.0
+            } else {
+                whole
+            }
+        };
+        x.signum() as i64 * units as i64
+    }
+
+    pub fn round(&self, x: f64) -> f64 {
+        self.units(x) as f64 / 10f64.powi(self.precision as i32)
+    }
+}
@@ -0,0 +1,447 @@
+//! One dimensional root finders for the implied quantities of the
+//! library: yields, implied rates and volatilities. All of them work on a
+//! bracket `[x_min, x_max]` across which the function changes sign, which
+//! `bracket` can search for from a guess, in any `Real` precision.
+
+use super::precision::Real;
+use crate::errors::QuantLibError;
+
+const DEFAULT_MAX_ITERATIONS: usize = 100;
+/// Growth of the search interval at each step of `bracket`.
+const GROWTH: f64 = 1.6;
+
+/// Root finder of a function of one variable.
+pub trait Solver1D {
+    /// A root of `f` within `[x_min, x_max]`, across which `f` changes
+    /// sign, or why none was found.
+    fn checked_solve<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError>;
+
+    /// `checked_solve`, panicking when no root is found.
+    fn solve<R: Real, F: Fn(R) -> R>(&self, f: F, x_min: R, x_max: R) -> R {
+        self.checked_solve(f, x_min, x_max)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// A root of `f` near `guess`, from the first bracket found widening
+    /// `[guess - step, guess + step]`.
+    fn checked_solve_from_guess<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        guess: R,
+        step: R,
+    ) -> Result<R, QuantLibError> {
+        let (x_min, x_max) = checked_bracket(&f, guess, step, DEFAULT_MAX_ITERATIONS)?;
+        self.checked_solve(f, x_min, x_max)
+    }
+
+    fn solve_from_guess<R: Real, F: Fn(R) -> R>(&self, f: F, guess: R, step: R) -> R {
+        self.checked_solve_from_guess(f, guess, step)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// An interval across which `f` changes sign, grown from `[guess - step,
+/// guess + step]` on the side where `f` is smaller in size.
+pub fn bracket<R: Real, F: Fn(R) -> R>(f: F, guess: R, step: R, max_iterations: usize) -> (R, R) {
+    checked_bracket(f, guess, step, max_iterations).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// `bracket`, or the last interval tried if none is found.
+pub fn checked_bracket<R: Real, F: Fn(R) -> R>(
+    f: F,
+    guess: R,
+    step: R,
+    max_iterations: usize,
+) -> Result<(R, R), QuantLibError> {
+    let (zero, growth) = (R::from_f64(0.0), R::from_f64(GROWTH));
+    assert!(step > zero, "non positive step");
+    let (mut x_min, mut x_max) = (guess - step, guess + step);
+    let (mut f_min, mut f_max) = (f(x_min), f(x_max));
+    for _ in 0..max_iterations {
+        if f_min * f_max <= zero {
+            return Ok((x_min, x_max));
+        }
+        if f_min.abs() < f_max.abs() {
+            x_min = x_min + growth * (x_min - x_max);
+            f_min = f(x_min);
+        } else {
+            x_max = x_max + growth * (x_max - x_min);
+            f_max = f(x_max);
+        }
+    }
+    Err(not_bracketed(x_min, x_max))
+}
+
+/// The bracket ends evaluated.
+enum Ends<R> {
+    /// An end that is already a root.
+    Root(R),
+    /// The ends ordered so that `f` is negative at the first.
+    Bracket(R, R),
+}
+
+fn oriented<R: Real, F: Fn(R) -> R>(f: &F, x_min: R, x_max: R) -> Result<Ends<R>, QuantLibError> {
+    assert!(x_min < x_max, "invalid bracket");
+    let zero = R::from_f64(0.0);
+    let (f_min, f_max) = (f(x_min), f(x_max));
+    if f_min == zero {
+        return Ok(Ends::Root(x_min));
+    }
+    if f_max == zero {
+        return Ok(Ends::Root(x_max));
+    }
+    if f_min.is_nan() || f_max.is_nan() || (f_min > zero) == (f_max > zero) {
+        return Err(not_bracketed(x_min, x_max));
+    }
+    if f_min < zero {
+        Ok(Ends::Bracket(x_min, x_max))
+    } else {
+        Ok(Ends::Bracket(x_max, x_min))
+    }
+}
+
+fn not_bracketed<R: Real>(x_min: R, x_max: R) -> QuantLibError {
+    QuantLibError::RootNotBracketed {
+        x_min: x_min.to_f64(),
+        x_max: x_max.to_f64(),
+    }
+}
+
+fn not_converged(solver: &str, iterations: usize) -> QuantLibError {
+    QuantLibError::NotConverged {
+        what: solver.to_string(),
+        iterations,
+    }
+}
+
+fn min<R: Real>(a: R, b: R) -> R {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// `size` with the sign of `sign`.
+fn with_sign<R: Real>(size: R, sign: R) -> R {
+    if sign < R::from_f64(0.0) {
+        -size.abs()
+    } else {
+        size.abs()
+    }
+}
+
+/// Brent's method: inverse quadratic interpolation, secant steps and
+/// bisection when those fail to shrink the bracket.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Brent {
+    pub accuracy: f64,
+    pub max_iterations: usize,
+}
+
+impl Brent {
+    pub fn new(accuracy: f64) -> Brent {
+        assert!(accuracy > 0.0, "non positive accuracy");
+        Brent {
+            accuracy,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Brent {
+        self.max_iterations = max_iterations;
+        self
+    }
+}
+
+impl Solver1D for Brent {
+    fn checked_solve<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError> {
+        if let Ends::Root(root) = oriented(&f, x_min, x_max)? {
+            return Ok(root);
+        }
+        let (zero, one, two, three) = (
+            R::from_f64(0.0),
+            R::from_f64(1.0),
+            R::from_f64(2.0),
+            R::from_f64(3.0),
+        );
+        let half = R::from_f64(0.5);
+        let (mut a, mut b) = (x_min, x_max);
+        let (mut fa, mut fb) = (f(a), f(b));
+        let (mut c, mut fc) = (b, fb);
+        let (mut d, mut e) = (b - a, b - a);
+        for _ in 0..self.max_iterations {
+            if (fb > zero && fc > zero) || (fb < zero && fc < zero) {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+            let tolerance =
+                R::from_f64(2.0 * R::epsilon()) * b.abs() + R::from_f64(0.5 * self.accuracy);
+            let middle = half * (c - b);
+            if middle.abs() <= tolerance || fb == zero {
+                return Ok(b);
+            }
+            if e.abs() >= tolerance && fa.abs() > fb.abs() {
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (two * middle * s, one - s)
+                } else {
+                    let (q, r) = (fa / fc, fb / fc);
+                    (
+                        s * (two * middle * q * (q - r) - (b - a) * (r - one)),
+                        (q - one) * (r - one) * (s - one),
+                    )
+                };
+                if p > zero {
+                    q = -q;
+                }
+                p = p.abs();
+                let bound = min(three * middle * q - (tolerance * q).abs(), (e * q).abs());
+                if two * p < bound {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = middle;
+                    e = d;
+                }
+            } else {
+                d = middle;
+                e = d;
+            }
+            a = b;
+            fa = fb;
+            b = b + if d.abs() > tolerance {
+                d
+            } else {
+                with_sign(tolerance, middle)
+            };
+            fb = f(b);
+        }
+        Err(not_converged("Brent solver", self.max_iterations))
+    }
+}
+
+/// Newton steps kept within the bracket, bisecting when a step would
+/// leave it or does not halve the error fast enough.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NewtonSafe {
+    pub accuracy: f64,
+    pub max_iterations: usize,
+}
+
+impl NewtonSafe {
+    pub fn new(accuracy: f64) -> NewtonSafe {
+        assert!(accuracy > 0.0, "non positive accuracy");
+        NewtonSafe {
+            accuracy,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> NewtonSafe {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// A root of `f` within `[x_min, x_max]`, `derivative` being its
+    /// derivative.
+    pub fn solve_with_derivative<R, F, D>(&self, f: F, derivative: D, x_min: R, x_max: R) -> R
+    where
+        R: Real,
+        F: Fn(R) -> R,
+        D: Fn(R) -> R,
+    {
+        self.checked_solve_with_derivative(f, derivative, x_min, x_max)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn checked_solve_with_derivative<R, F, D>(
+        &self,
+        f: F,
+        derivative: D,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError>
+    where
+        R: Real,
+        F: Fn(R) -> R,
+        D: Fn(R) -> R,
+    {
+        let (mut low, mut high) = match oriented(&f, x_min, x_max)? {
+            Ends::Bracket(low, high) => (low, high),
+            Ends::Root(root) => return Ok(root),
+        };
+        let (zero, half, two) = (R::from_f64(0.0), R::from_f64(0.5), R::from_f64(2.0));
+        let mut root = half * (x_min + x_max);
+        let mut dx_old = x_max - x_min;
+        let mut dx = dx_old;
+        let mut froot = f(root);
+        let mut dfroot = derivative(root);
+        for _ in 0..self.max_iterations {
+            let outside = ((root - high) * dfroot - froot) * ((root - low) * dfroot - froot) > zero;
+            if outside || (two * froot).abs() > (dx_old * dfroot).abs() {
+                dx_old = dx;
+                dx = half * (high - low);
+                root = low + dx;
+            } else {
+                dx_old = dx;
+                dx = froot / dfroot;
+                root = root - dx;
+            }
+            if dx.abs().to_f64() < self.accuracy {
+                return Ok(root);
+            }
+            froot = f(root);
+            dfroot = derivative(root);
+            if froot < zero {
+                low = root;
+            } else {
+                high = root;
+            }
+        }
+        Err(not_converged("Newton solver", self.max_iterations))
+    }
+}
+
+impl Solver1D for NewtonSafe {
+    /// Takes the derivative by central differences.
+    fn checked_solve<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError> {
+        let step = R::from_f64(1.0e-6) * (x_max - x_min);
+        let two = R::from_f64(2.0);
+        let derivative = |x: R| (f(x + step) - f(x - step)) / (two * step);
+        self.checked_solve_with_derivative(&f, derivative, x_min, x_max)
+    }
+}
+
+/// Halves the bracket until it is narrower than the accuracy.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bisection {
+    pub accuracy: f64,
+    pub max_iterations: usize,
+}
+
+impl Bisection {
+    pub fn new(accuracy: f64) -> Bisection {
+        assert!(accuracy > 0.0, "non positive accuracy");
+        Bisection {
+            accuracy,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Bisection {
+        self.max_iterations = max_iterations;
+        self
+    }
+}
+
+impl Solver1D for Bisection {
+    fn checked_solve<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError> {
+        let (mut low, mut high) = match oriented(&f, x_min, x_max)? {
+            Ends::Bracket(low, high) => (low, high),
+            Ends::Root(root) => return Ok(root),
+        };
+        let (zero, half) = (R::from_f64(0.0), R::from_f64(0.5));
+        for _ in 0..self.max_iterations {
+            let middle = half * (low + high);
+            let value = f(middle);
+            if value == zero || (half * (high - low)).abs().to_f64() < self.accuracy {
+                return Ok(middle);
+            }
+            if value < zero {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Err(not_converged("bisection", self.max_iterations))
+    }
+}
+
+/// Secant steps from the bracket end nearer the root. Faster than Brent's
+/// method on smooth functions, but the steps are not kept within the
+/// bracket, so it may fail where a bracketing solver would not.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Secant {
+    pub accuracy: f64,
+    pub max_iterations: usize,
+}
+
+impl Secant {
+    pub fn new(accuracy: f64) -> Secant {
+        assert!(accuracy > 0.0, "non positive accuracy");
+        Secant {
+            accuracy,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Secant {
+        self.max_iterations = max_iterations;
+        self
+    }
+}
+
+impl Solver1D for Secant {
+    fn checked_solve<R: Real, F: Fn(R) -> R>(
+        &self,
+        f: F,
+        x_min: R,
+        x_max: R,
+    ) -> Result<R, QuantLibError> {
+        if let Ends::Root(root) = oriented(&f, x_min, x_max)? {
+            return Ok(root);
+        }
+        let zero = R::from_f64(0.0);
+        let (f_min, f_max) = (f(x_min), f(x_max));
+        let (mut x0, mut f0, mut x1, mut f1) = if f_min.abs() < f_max.abs() {
+            (x_max, f_max, x_min, f_min)
+        } else {
+            (x_min, f_min, x_max, f_max)
+        };
+        for _ in 0..self.max_iterations {
+            if f1 == f0 {
+                break;
+            }
+            let dx = f1 * (x1 - x0) / (f1 - f0);
+            x0 = x1;
+            f0 = f1;
+            x1 = x1 - dx;
+            f1 = f(x1);
+            if dx.abs().to_f64() < self.accuracy || f1 == zero {
+                return Ok(x1);
+            }
+        }
+        Err(not_converged("secant solver", self.max_iterations))
+    }
+}
@@ -0,0 +1,84 @@
+/// Increasing grid of locations of one space dimension.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mesher1D {
+    pub locations: Vec<f64>,
+}
+
+impl Mesher1D {
+    pub fn new(locations: Vec<f64>) -> Mesher1D {
+        assert!(locations.len() >= 3, "at least three locations needed");
+        assert!(
+            locations.windows(2).all(|w| w[0] < w[1]),
+            "locations must be increasing"
+        );
+        Mesher1D { locations }
+    }
+
+    /// `size` equally spaced locations from `start` to `end`.
+    pub fn uniform(start: f64, end: f64, size: usize) -> Mesher1D {
+        assert!(start < end, "empty mesher range");
+        assert!(size >= 3, "at least three locations needed");
+        let dx = (end - start) / (size - 1) as f64;
+        let mut locations: Vec<f64> = (0..size).map(|i| start + i as f64 * dx).collect();
+        locations[size - 1] = end;
+        Mesher1D::new(locations)
+    }
+
+    /// `size` locations from `start` to `end` concentrated around
+    /// `center`, spaced by a sinh transform of a uniform grid; the smaller
+    /// `density` the denser the locations near `center`.
+    pub fn concentrating(start: f64, end: f64, size: usize, center: f64, density: f64) -> Mesher1D {
+        assert!(start < end, "empty mesher range");
+        assert!(size >= 3, "at least three locations needed");
+        assert!(density > 0.0, "non positive density");
+        let alpha = density * (end - start);
+        let c1 = ((start - center) / alpha).asinh();
+        let c2 = ((end - center) / alpha).asinh();
+        let mut locations: Vec<f64> = (0..size)
+            .map(|i| {
+                let u = i as f64 / (size - 1) as f64;
+                center + alpha * (c1 + (c2 - c1) * u).sinh()
+            })
+            .collect();
+        locations[0] = start;
+        locations[size - 1] = end;
+        Mesher1D::new(locations)
+    }
+
+    pub fn size(&self) -> usize {
+        self.locations.len()
+    }
+
+    /// Distance to the next location, from an inner location `i`.
+    pub fn dplus(&self, i: usize) -> f64 {
+        self.locations[i + 1] - self.locations[i]
+    }
+
+    /// Distance to the previous location, from an inner location `i`.
+    pub fn dminus(&self, i: usize) -> f64 {
+        self.locations[i] - self.locations[i - 1]
+    }
+
+    /// Quadratic interpolation of `values` at the locations through the
+    /// three locations nearest `x`.
+    pub fn interpolate(&self, values: &[f64], x: f64) -> f64 {
+        assert!(
+            values.len() == self.size(),
+            "values and mesher size mismatch"
+        );
+        let n = self.size();
+        let i = self.locations.iter().position(|l| *l >= x).unwrap_or(n - 1);
+        let j = i.clamp(1, n - 2);
+        let x0 = &self.locations[j - 1..=j + 1];
+        let y0 = &values[j - 1..=j + 1];
+        (0..3)
+            .map(|a| {
+                let weight: f64 = (0..3)
+                    .filter(|b| *b != a)
+                    .map(|b| (x - x0[b]) / (x0[a] - x0[b]))
+                    .product();
+                weight * y0[a]
+            })
+            .sum()
+    }
+}
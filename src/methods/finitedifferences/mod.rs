@@ -0,0 +1,7 @@
+pub mod mesher;
+pub mod schemes;
+pub mod tridiagonaloperator;
+
+pub use self::mesher::Mesher1D;
+pub use self::schemes::{EarlyExerciseMethod, FdScheme};
+pub use self::tridiagonaloperator::TridiagonalOperator;
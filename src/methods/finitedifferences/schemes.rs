@@ -0,0 +1,90 @@
+use super::TridiagonalOperator;
+use crate::definitions::Time;
+
+/// Penalty on the values below their lower bound.
+const PENALTY: f64 = 1.0e8;
+/// Absolute tolerance of projected SOR.
+const PSOR_TOLERANCE: f64 = 1.0e-12;
+
+/// Time stepping of `dV/dtau = L V` in the time `tau` to maturity, i.e.
+/// back from the payoff.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FdScheme {
+    /// Fully implicit, first order in time, damping the oscillations of
+    /// non smooth payoffs.
+    ImplicitEuler,
+    /// Half implicit, half explicit, second order in time.
+    CrankNicolson,
+}
+
+impl FdScheme {
+    /// Weight of the implicit part of a step.
+    pub fn theta(&self) -> f64 {
+        match self {
+            FdScheme::ImplicitEuler => 1.0,
+            FdScheme::CrankNicolson => 0.5,
+        }
+    }
+
+    /// The values a step `dt` back from `values` under the operator
+    /// `op`, kept above `lower_bound` by `method` if one is given, e.g.
+    /// the exercise value of an American option.
+    pub fn step(
+        &self,
+        op: &TridiagonalOperator,
+        values: &[f64],
+        dt: Time,
+        lower_bound: Option<(&[f64], EarlyExerciseMethod)>,
+    ) -> Vec<f64> {
+        let theta = self.theta();
+        let n = op.size();
+        let identity = TridiagonalOperator::identity(n);
+        let rhs = if theta < 1.0 {
+            identity.plus(&op.scaled((1.0 - theta) * dt)).apply(values)
+        } else {
+            values.to_vec()
+        };
+        let lhs = identity.plus(&op.scaled(-theta * dt));
+        match lower_bound {
+            None => lhs.solve_for(&rhs),
+            Some((bound, EarlyExerciseMethod::Psor { omega })) => {
+                lhs.solve_psor(&rhs, bound, values, omega, PSOR_TOLERANCE)
+            }
+            Some((bound, EarlyExerciseMethod::Penalty)) => penalized(&lhs, &rhs, bound),
+        }
+    }
+}
+
+/// How a lower bound on the values, e.g. the exercise value of an American
+/// option, is imposed in each step.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EarlyExerciseMethod {
+    /// Penalizing the values below the bound, iterating on the set of
+    /// those until it settles.
+    Penalty,
+    /// Projected successive over-relaxation with relaxation `omega` in
+    /// (0, 2).
+    Psor { omega: f64 },
+}
+
+fn penalized(lhs: &TridiagonalOperator, rhs: &[f64], bound: &[f64]) -> Vec<f64> {
+    let mut x = lhs.solve_for(rhs);
+    let mut active: Vec<bool> = vec![false; x.len()];
+    for _ in 0..x.len() + 1 {
+        let next: Vec<bool> = x.iter().zip(bound).map(|(v, b)| v < b).collect();
+        if next == active {
+            break;
+        }
+        active = next;
+        let mut op = lhs.clone();
+        let mut b = rhs.to_vec();
+        for (i, a) in active.iter().enumerate() {
+            if *a {
+                op.diagonal[i] += PENALTY;
+                b[i] += PENALTY * bound[i];
+            }
+        }
+        x = op.solve_for(&b);
+    }
+    x.iter().zip(bound).map(|(v, b)| v.max(*b)).collect()
+}
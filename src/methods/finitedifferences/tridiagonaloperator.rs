@@ -0,0 +1,166 @@
+use super::Mesher1D;
+
+/// Tridiagonal operator on the values at the locations of a mesher, e.g.
+/// a finite difference discretization of a one dimensional differential
+/// operator. `lower[0]` and `upper[n - 1]` are not used.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TridiagonalOperator {
+    pub lower: Vec<f64>,
+    pub diagonal: Vec<f64>,
+    pub upper: Vec<f64>,
+}
+
+impl TridiagonalOperator {
+    pub fn new(lower: Vec<f64>, diagonal: Vec<f64>, upper: Vec<f64>) -> TridiagonalOperator {
+        assert!(
+            lower.len() == diagonal.len() && upper.len() == diagonal.len(),
+            "diagonals size mismatch"
+        );
+        TridiagonalOperator {
+            lower,
+            diagonal,
+            upper,
+        }
+    }
+
+    pub fn identity(size: usize) -> TridiagonalOperator {
+        TridiagonalOperator::new(vec![0.0; size], vec![1.0; size], vec![0.0; size])
+    }
+
+    /// Central first derivative on the inner locations of `mesher`, its
+    /// boundary rows being zero.
+    pub fn first_derivative(mesher: &Mesher1D) -> TridiagonalOperator {
+        let n = mesher.size();
+        let mut op = TridiagonalOperator::new(vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        for i in 1..n - 1 {
+            let (hm, hp) = (mesher.dminus(i), mesher.dplus(i));
+            op.lower[i] = -hp / (hm * (hm + hp));
+            op.diagonal[i] = (hp - hm) / (hm * hp);
+            op.upper[i] = hm / (hp * (hm + hp));
+        }
+        op
+    }
+
+    /// Central second derivative on the inner locations of `mesher`, its
+    /// boundary rows being zero.
+    pub fn second_derivative(mesher: &Mesher1D) -> TridiagonalOperator {
+        let n = mesher.size();
+        let mut op = TridiagonalOperator::new(vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        for i in 1..n - 1 {
+            let (hm, hp) = (mesher.dminus(i), mesher.dplus(i));
+            op.lower[i] = 2.0 / (hm * (hm + hp));
+            op.diagonal[i] = -2.0 / (hm * hp);
+            op.upper[i] = 2.0 / (hp * (hm + hp));
+        }
+        op
+    }
+
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The sum of this operator and `other`.
+    pub fn plus(&self, other: &TridiagonalOperator) -> TridiagonalOperator {
+        assert!(self.size() == other.size(), "operators size mismatch");
+        let sum = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x + y).collect();
+        TridiagonalOperator::new(
+            sum(&self.lower, &other.lower),
+            sum(&self.diagonal, &other.diagonal),
+            sum(&self.upper, &other.upper),
+        )
+    }
+
+    /// This operator times `a`.
+    pub fn scaled(&self, a: f64) -> TridiagonalOperator {
+        let scale = |v: &[f64]| v.iter().map(|x| a * x).collect();
+        TridiagonalOperator::new(
+            scale(&self.lower),
+            scale(&self.diagonal),
+            scale(&self.upper),
+        )
+    }
+
+    pub fn apply(&self, v: &[f64]) -> Vec<f64> {
+        let n = self.size();
+        assert!(v.len() == n, "operator and vector size mismatch");
+        (0..n)
+            .map(|i| {
+                let mut x = self.diagonal[i] * v[i];
+                if i > 0 {
+                    x += self.lower[i] * v[i - 1];
+                }
+                if i + 1 < n {
+                    x += self.upper[i] * v[i + 1];
+                }
+                x
+            })
+            .collect()
+    }
+
+    /// The `x` with `apply(x)` equal to `rhs`, by the Thomas algorithm.
+    pub fn solve_for(&self, rhs: &[f64]) -> Vec<f64> {
+        let n = self.size();
+        assert!(rhs.len() == n, "operator and vector size mismatch");
+        let mut upper = vec![0.0; n];
+        let mut x = vec![0.0; n];
+        let mut pivot = self.diagonal[0];
+        assert!(pivot != 0.0, "singular operator");
+        x[0] = rhs[0] / pivot;
+        for i in 1..n {
+            upper[i - 1] = self.upper[i - 1] / pivot;
+            pivot = self.diagonal[i] - self.lower[i] * upper[i - 1];
+            assert!(pivot != 0.0, "singular operator");
+            x[i] = (rhs[i] - self.lower[i] * x[i - 1]) / pivot;
+        }
+        for i in (0..n - 1).rev() {
+            x[i] -= upper[i] * x[i + 1];
+        }
+        x
+    }
+
+    /// The `x` no less than `lower_bound` with `apply(x) - rhs` zero
+    /// wherever `x` is above the bound and non negative elsewhere, by
+    /// projected successive over-relaxation from `guess`.
+    pub fn solve_psor(
+        &self,
+        rhs: &[f64],
+        lower_bound: &[f64],
+        guess: &[f64],
+        omega: f64,
+        tolerance: f64,
+    ) -> Vec<f64> {
+        let n = self.size();
+        assert!(
+            rhs.len() == n && lower_bound.len() == n && guess.len() == n,
+            "operator and vector size mismatch"
+        );
+        assert!(omega > 0.0 && omega < 2.0, "relaxation out of (0, 2)");
+        let mut x: Vec<f64> = guess
+            .iter()
+            .zip(lower_bound)
+            .map(|(g, b)| g.max(*b))
+            .collect();
+        for _ in 0..MAX_PSOR_ITERATIONS {
+            let mut error: f64 = 0.0;
+            for i in 0..n {
+                let mut r = rhs[i];
+                if i > 0 {
+                    r -= self.lower[i] * x[i - 1];
+                }
+                if i + 1 < n {
+                    r -= self.upper[i] * x[i + 1];
+                }
+                let gauss_seidel = r / self.diagonal[i];
+                let next = (x[i] + omega * (gauss_seidel - x[i])).max(lower_bound[i]);
+                error = error.max((next - x[i]).abs());
+                x[i] = next;
+            }
+            if error < tolerance {
+                return x;
+            }
+        }
+        panic!("projected SOR did not converge");
+    }
+}
+
+const MAX_PSOR_ITERATIONS: usize = 10_000;
@@ -0,0 +1,90 @@
+use crate::definitions::{Rate, Time, Volatility};
+
+/// How the up and down moves and their probabilities are chosen.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BinomialTreeType {
+    /// Cox-Ross-Rubinstein: moves of `exp(+-sigma sqrt(dt))`, recombining
+    /// around the spot, with the probability matching the forward.
+    CoxRossRubinstein,
+    /// Jarrow-Rudd: equal probabilities, the moves centred on the
+    /// lognormal drift.
+    JarrowRudd,
+    /// Tian: moves and probability matching the first three moments of
+    /// the lognormal step.
+    Tian,
+}
+
+/// Recombining binomial tree of a lognormal spot, with the same moves
+/// and probabilities at every step.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BinomialTree {
+    pub spot: f64,
+    pub dt: Time,
+    pub steps: usize,
+    /// Growth factors of the up and down moves.
+    pub up: f64,
+    pub down: f64,
+    /// Probability of the up move.
+    pub probability: f64,
+}
+
+impl BinomialTree {
+    /// Tree of `steps` steps to `maturity` of the spot growing at the
+    /// carry `r - q` with volatility `sigma`.
+    pub fn new(
+        tree_type: BinomialTreeType,
+        spot: f64,
+        carry: Rate,
+        sigma: Volatility,
+        maturity: Time,
+        steps: usize,
+    ) -> BinomialTree {
+        assert!(spot > 0.0, "non positive spot");
+        assert!(sigma > 0.0, "non positive volatility");
+        assert!(maturity > 0.0 && steps > 0, "empty tree");
+        let dt = maturity / steps as f64;
+        let growth = (carry * dt).exp();
+        let (up, down, probability) = match tree_type {
+            BinomialTreeType::CoxRossRubinstein => {
+                let up = (sigma * dt.sqrt()).exp();
+                let down = 1.0 / up;
+                (up, down, (growth - down) / (up - down))
+            }
+            BinomialTreeType::JarrowRudd => {
+                let drift = (carry - 0.5 * sigma * sigma) * dt;
+                let dx = sigma * dt.sqrt();
+                ((drift + dx).exp(), (drift - dx).exp(), 0.5)
+            }
+            BinomialTreeType::Tian => {
+                let v = (sigma * sigma * dt).exp();
+                let root = (v * v + 2.0 * v - 3.0).sqrt();
+                let up = 0.5 * growth * v * (v + 1.0 + root);
+                let down = 0.5 * growth * v * (v + 1.0 - root);
+                (up, down, (growth - down) / (up - down))
+            }
+        };
+        assert!(
+            probability > 0.0 && probability < 1.0,
+            "time step too large for the carry"
+        );
+        BinomialTree {
+            spot,
+            dt,
+            steps,
+            up,
+            down,
+            probability,
+        }
+    }
+
+    /// The spot at node `j` of step `i`, after `j` up moves.
+    pub fn underlying(&self, i: usize, j: usize) -> f64 {
+        assert!(j <= i, "node out of the tree");
+        self.spot * self.up.powi(j as i32) * self.down.powi((i - j) as i32)
+    }
+
+    /// Time of step `i`.
+    pub fn time(&self, i: usize) -> Time {
+        i as f64 * self.dt
+    }
+}
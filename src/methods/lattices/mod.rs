@@ -0,0 +1,3 @@
+pub mod binomialtree;
+
+pub use self::binomialtree::{BinomialTree, BinomialTreeType};
@@ -0,0 +1,3 @@
+pub mod finitedifferences;
+pub mod lattices;
+pub mod montecarlo;
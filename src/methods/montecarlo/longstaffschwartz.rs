@@ -0,0 +1,42 @@
+use super::LsmBasisSystem;
+use crate::math::{linear_least_squares, Matrix};
+
+/// Conditional expectation estimator fitted by least-squares regression of
+/// realized path values on a set of basis functions of the path state.
+#[derive(Clone, Debug)]
+pub struct LsmRegression {
+    pub basis: LsmBasisSystem,
+    pub coefficients: Vec<f64>,
+}
+
+impl LsmRegression {
+    /// Fits `E[target | state]` on the given sample. When the sample is too
+    /// small to identify every coefficient the estimator degrades to the
+    /// sample mean.
+    pub fn fit(basis: &LsmBasisSystem, states: &[&[f64]], targets: &[f64]) -> LsmRegression {
+        assert!(states.len() == targets.len(), "states and targets mismatch");
+        let mut coefficients = vec![0.0; basis.size()];
+        if states.len() < basis.size() {
+            if !targets.is_empty() {
+                coefficients[0] = targets.iter().sum::<f64>() / targets.len() as f64;
+            }
+        } else {
+            let rows: Vec<Vec<f64>> = states.iter().map(|x| basis.values(x)).collect();
+            coefficients = linear_least_squares(&Matrix::from_rows(&rows), targets);
+        }
+        LsmRegression {
+            basis: basis.clone(),
+            coefficients,
+        }
+    }
+
+    /// The estimated conditional expectation at `state`.
+    pub fn value(&self, state: &[f64]) -> f64 {
+        self.basis
+            .values(state)
+            .iter()
+            .zip(&self.coefficients)
+            .map(|(v, c)| v * c)
+            .sum()
+    }
+}
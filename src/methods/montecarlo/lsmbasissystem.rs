@@ -0,0 +1,120 @@
+/// Polynomial family used to build regression basis functions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PolynomialType {
+    Monomial,
+    Laguerre,
+    Hermite,
+    Hyperbolic,
+    Legendre,
+    Chebyshev,
+    Chebyshev2nd,
+}
+
+/// Basis functions for least-squares Monte Carlo regressions.
+///
+/// For a state of dimension `dimension` the system contains every product of
+/// one-dimensional polynomials whose total degree does not exceed `order`,
+/// the constant included.
+#[derive(Clone, Debug)]
+pub struct LsmBasisSystem {
+    pub polynomial_type: PolynomialType,
+    pub order: usize,
+    pub dimension: usize,
+    terms: Vec<Vec<usize>>,
+}
+
+impl LsmBasisSystem {
+    /// One-dimensional basis of polynomials of degree `0..=order`.
+    pub fn path_basis_system(order: usize, polynomial_type: PolynomialType) -> LsmBasisSystem {
+        LsmBasisSystem::multi_path_basis_system(1, order, polynomial_type)
+    }
+
+    /// Multi-dimensional basis of products with total degree `<= order`.
+    pub fn multi_path_basis_system(
+        dimension: usize,
+        order: usize,
+        polynomial_type: PolynomialType,
+    ) -> LsmBasisSystem {
+        assert!(dimension > 0, "basis dimension must be positive");
+        let mut terms = vec![];
+        let mut current = vec![0; dimension];
+        Self::collect_terms(0, order, &mut current, &mut terms);
+        terms.sort_by_key(|t| t.iter().sum::<usize>());
+        LsmBasisSystem {
+            polynomial_type,
+            order,
+            dimension,
+            terms,
+        }
+    }
+
+    fn collect_terms(
+        position: usize,
+        remaining: usize,
+        current: &mut Vec<usize>,
+        terms: &mut Vec<Vec<usize>>,
+    ) {
+        if position == current.len() {
+            terms.push(current.clone());
+            return;
+        }
+        for degree in 0..=remaining {
+            current[position] = degree;
+            Self::collect_terms(position + 1, remaining - degree, current, terms);
+        }
+        current[position] = 0;
+    }
+
+    /// The number of basis functions.
+    pub fn size(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Evaluates every basis function at the state `x`.
+    pub fn values(&self, x: &[f64]) -> Vec<f64> {
+        assert!(x.len() == self.dimension, "state dimension mismatch");
+        let single: Vec<Vec<f64>> = x
+            .iter()
+            .map(|xi| {
+                (0..=self.order)
+                    .map(|k| polynomial(self.polynomial_type, k, *xi))
+                    .collect()
+            })
+            .collect();
+        self.terms
+            .iter()
+            .map(|t| t.iter().enumerate().map(|(i, k)| single[i][*k]).product())
+            .collect()
+    }
+}
+
+/// Evaluates the polynomial of degree `k` of the given family at `x`.
+pub fn polynomial(polynomial_type: PolynomialType, k: usize, x: f64) -> f64 {
+    if k == 0 {
+        return 1.0;
+    }
+    if polynomial_type == PolynomialType::Monomial {
+        return x.powi(k as i32);
+    }
+    if polynomial_type == PolynomialType::Hyperbolic {
+        return (1.0 / (1.0 + x)).powi(k as i32);
+    }
+    let (mut p0, mut p1) = match polynomial_type {
+        PolynomialType::Laguerre => (1.0, 1.0 - x),
+        PolynomialType::Hermite => (1.0, 2.0 * x),
+        PolynomialType::Chebyshev2nd => (1.0, 2.0 * x),
+        _ => (1.0, x),
+    };
+    for n in 1..k {
+        let nf = n as f64;
+        let p2 = match polynomial_type {
+            PolynomialType::Laguerre => ((2.0 * nf + 1.0 - x) * p1 - nf * p0) / (nf + 1.0),
+            PolynomialType::Hermite => 2.0 * x * p1 - 2.0 * nf * p0,
+            PolynomialType::Legendre => ((2.0 * nf + 1.0) * x * p1 - nf * p0) / (nf + 1.0),
+            _ => 2.0 * x * p1 - p0,
+        };
+        p0 = p1;
+        p1 = p2;
+    }
+    p1
+}
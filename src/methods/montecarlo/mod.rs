@@ -0,0 +1,17 @@
+pub mod longstaffschwartz;
+pub mod lsmbasissystem;
+pub mod montecarlomodel;
+pub mod parallelmc;
+pub mod path;
+pub mod pathgenerator;
+pub mod pathpricer;
+pub mod shortratemc;
+
+pub use self::longstaffschwartz::LsmRegression;
+pub use self::lsmbasissystem::{LsmBasisSystem, PolynomialType};
+pub use self::montecarlomodel::{MonteCarloModel, PseudoRandomNormals, Statistics};
+pub use self::parallelmc::ParallelMonteCarlo;
+pub use self::path::Path;
+pub use self::pathgenerator::PathGenerator;
+pub use self::pathpricer::PathPricer;
+pub use self::shortratemc::{MonteCarloResult, Numeraire, ShortRateMcEngine, ShortRatePath};
@@ -0,0 +1,145 @@
+use super::{MonteCarloResult, PathGenerator, PathPricer};
+use crate::math::{BoxMullerGaussianRng, RandomSequenceGenerator, Rsg, SplitMix64};
+use crate::processes::StochasticProcess1D;
+use crate::utils::trace::Level;
+
+/// Running mean and variance of the samples added.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub samples: usize,
+    pub sum: f64,
+    pub sum_squares: f64,
+}
+
+impl Statistics {
+    pub fn add(&mut self, value: f64) {
+        self.samples += 1;
+        self.sum += value;
+        self.sum_squares += value * value;
+    }
+    /// Adds the samples of `other`.
+    pub fn merge(&mut self, other: &Statistics) {
+        self.samples += other.samples;
+        self.sum += other.sum;
+        self.sum_squares += other.sum_squares;
+    }
+    pub fn mean(&self) -> f64 {
+        assert!(self.samples > 0, "no samples");
+        self.sum / self.samples as f64
+    }
+    /// Unbiased sample variance.
+    pub fn variance(&self) -> f64 {
+        assert!(self.samples > 1, "not enough samples");
+        let n = self.samples as f64;
+        ((self.sum_squares - self.sum * self.sum / n) / (n - 1.0)).max(0.0)
+    }
+    /// Standard error of the mean.
+    pub fn error_estimate(&self) -> f64 {
+        (self.variance() / self.samples as f64).sqrt()
+    }
+}
+
+/// Standard normal draws of the default pseudo-random generator.
+pub type PseudoRandomNormals = RandomSequenceGenerator<BoxMullerGaussianRng<SplitMix64>>;
+
+/// Prices paths from `generator` with `pricer`, accumulating the values
+/// in `statistics`; antithetic pairs count as one sample. The normals of
+/// each path are a sequence of `rsg`, e.g. pseudo-random or Sobol ones.
+pub struct MonteCarloModel<P: StochasticProcess1D, PP: PathPricer, S: Rsg = PseudoRandomNormals> {
+    pub generator: PathGenerator<P>,
+    pub pricer: PP,
+    pub antithetic: bool,
+    pub statistics: Statistics,
+    rsg: S,
+}
+
+impl<P: StochasticProcess1D, PP: PathPricer> MonteCarloModel<P, PP> {
+    /// A model on pseudo-random normals seeded with `seed`.
+    pub fn new(generator: PathGenerator<P>, pricer: PP, seed: u64) -> MonteCarloModel<P, PP> {
+        let rsg = RandomSequenceGenerator::new(
+            BoxMullerGaussianRng::new(SplitMix64::new(seed)),
+            generator.times.len(),
+        );
+        MonteCarloModel::with_rsg(generator, pricer, rsg)
+    }
+}
+
+impl<P: StochasticProcess1D, PP: PathPricer, S: Rsg> MonteCarloModel<P, PP, S> {
+    /// A model on the normal sequences of `rsg`, one draw per time of the
+    /// generator.
+    pub fn with_rsg(generator: PathGenerator<P>, pricer: PP, rsg: S) -> MonteCarloModel<P, PP, S> {
+        assert!(
+            rsg.dimension() == generator.times.len(),
+            "one draw per time needed"
+        );
+        MonteCarloModel {
+            generator,
+            pricer,
+            antithetic: false,
+            statistics: Statistics::default(),
+            rsg,
+        }
+    }
+
+    pub fn with_antithetic(mut self) -> MonteCarloModel<P, PP, S> {
+        self.antithetic = true;
+        self
+    }
+
+    pub fn add_samples(&mut self, samples: usize) {
+        let _span = crate::trace_span!(
+            Level::Debug,
+            "monte_carlo_model.add_samples",
+            samples = samples
+        );
+        for _ in 0..samples {
+            let normals = self.generator.draws(&mut self.rsg);
+            let mut value = self.pricer.price(&self.generator.path(&normals));
+            if self.antithetic {
+                let flipped: Vec<f64> = normals.iter().map(|z| -z).collect();
+                value = 0.5 * (value + self.pricer.price(&self.generator.path(&flipped)));
+            }
+            self.statistics.add(value);
+        }
+    }
+
+    pub fn result(&self) -> MonteCarloResult {
+        MonteCarloResult {
+            value: self.statistics.mean(),
+            error_estimate: self.statistics.error_estimate(),
+        }
+    }
+
+    /// Adds samples until the error estimate falls to `tolerance`,
+    /// starting from `min_samples` and sizing each further batch from the
+    /// error so far.
+    pub fn value_with_tolerance(
+        &mut self,
+        tolerance: f64,
+        min_samples: usize,
+        max_samples: usize,
+    ) -> MonteCarloResult {
+        assert!(tolerance > 0.0, "non positive tolerance");
+        assert!(
+            min_samples > 1 && min_samples <= max_samples,
+            "invalid sample bounds"
+        );
+        if self.statistics.samples < min_samples {
+            self.add_samples(min_samples - self.statistics.samples);
+        }
+        loop {
+            let error = self.statistics.error_estimate();
+            if error <= tolerance {
+                return self.result();
+            }
+            let samples = self.statistics.samples;
+            assert!(
+                samples < max_samples,
+                "max samples reached before the tolerance"
+            );
+            let ratio = error / tolerance;
+            let needed = (samples as f64 * ratio * ratio * 1.1).ceil() as usize;
+            self.add_samples(needed.min(max_samples).max(samples + 1) - samples);
+        }
+    }
+}
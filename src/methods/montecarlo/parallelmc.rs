@@ -0,0 +1,94 @@
+use super::{MonteCarloModel, MonteCarloResult, PathGenerator, PathPricer, Statistics};
+use crate::definitions::Time;
+use crate::math::{BoxMullerGaussianRng, RandomSequenceGenerator, SplitMix64};
+use crate::processes::StochasticProcess1D;
+use crate::utils::trace::Level;
+use crate::utils::Parallelism;
+use std::rc::Rc;
+
+/// Simulates the samples of a Monte Carlo model in `batches` batches, the
+/// k-th on its own stream `SplitMix64::stream(seed, k)`, over the threads of
+/// `parallelism`, merging their statistics in batch order.
+///
+/// Results depend on the seed and the number of batches only, not on the
+/// threads they run on. Each batch prices paths on its own copy of the
+/// process.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ParallelMonteCarlo {
+    pub batches: usize,
+    pub parallelism: Parallelism,
+}
+
+impl ParallelMonteCarlo {
+    pub fn new(batches: usize, parallelism: Parallelism) -> ParallelMonteCarlo {
+        assert!(batches > 0, "no batches");
+        ParallelMonteCarlo {
+            batches,
+            parallelism,
+        }
+    }
+
+    /// Statistics of `samples` values of `pricer` on paths of `process` on
+    /// the simulation `times`.
+    pub fn statistics<P, PP>(
+        &self,
+        process: &P,
+        times: &[Time],
+        pricer: &PP,
+        samples: usize,
+        seed: u64,
+        antithetic: bool,
+    ) -> Statistics
+    where
+        P: StochasticProcess1D + Clone + Sync,
+        PP: PathPricer + Clone + Sync,
+    {
+        let _span = crate::trace_span!(
+            Level::Info,
+            "monte_carlo.parallel.statistics",
+            samples = samples,
+            batches = self.batches
+        );
+        assert!(samples >= self.batches, "fewer samples than batches");
+        let batches: Vec<usize> = (0..self.batches).collect();
+        let batch_statistics = self.parallelism.map(&batches, |&k| {
+            let size = samples / self.batches + usize::from(k < samples % self.batches);
+            let generator = PathGenerator::new(Rc::new(process.clone()), times.to_vec());
+            let rsg = RandomSequenceGenerator::new(
+                BoxMullerGaussianRng::new(SplitMix64::stream(seed, k)),
+                times.len(),
+            );
+            let mut model = MonteCarloModel::with_rsg(generator, pricer.clone(), rsg);
+            if antithetic {
+                model = model.with_antithetic();
+            }
+            model.add_samples(size);
+            model.statistics
+        });
+        let mut statistics = Statistics::default();
+        for s in &batch_statistics {
+            statistics.merge(s);
+        }
+        statistics
+    }
+
+    pub fn result<P, PP>(
+        &self,
+        process: &P,
+        times: &[Time],
+        pricer: &PP,
+        samples: usize,
+        seed: u64,
+        antithetic: bool,
+    ) -> MonteCarloResult
+    where
+        P: StochasticProcess1D + Clone + Sync,
+        PP: PathPricer + Clone + Sync,
+    {
+        let statistics = self.statistics(process, times, pricer, samples, seed, antithetic);
+        MonteCarloResult {
+            value: statistics.mean(),
+            error_estimate: statistics.error_estimate(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::definitions::Time;
+
+/// Values of a simulated process on its time grid, `times[0]` being
+/// today.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path {
+    pub times: Vec<Time>,
+    pub values: Vec<f64>,
+}
+
+impl Path {
+    pub fn new(times: Vec<Time>, values: Vec<f64>) -> Path {
+        assert!(
+            times.len() == values.len(),
+            "times and values size mismatch"
+        );
+        Path { times, values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// The initial value.
+    pub fn front(&self) -> f64 {
+        self.values[0]
+    }
+    /// The value at the end of the grid.
+    pub fn back(&self) -> f64 {
+        self.values[self.values.len() - 1]
+    }
+}
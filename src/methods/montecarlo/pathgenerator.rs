@@ -0,0 +1,55 @@
+use super::Path;
+use crate::definitions::Time;
+use crate::math::Rsg;
+use crate::processes::StochasticProcess1D;
+use std::rc::Rc;
+
+/// Generates paths of `process` on the simulation times after today,
+/// one normal draw per step.
+pub struct PathGenerator<P: StochasticProcess1D> {
+    pub process: Rc<P>,
+    pub times: Vec<Time>,
+}
+
+impl<P: StochasticProcess1D> PathGenerator<P> {
+    pub fn new(process: Rc<P>, times: Vec<Time>) -> PathGenerator<P> {
+        assert!(
+            !times.is_empty() && times[0] > 0.0 && times.windows(2).all(|w| w[0] < w[1]),
+            "times must be positive and increasing"
+        );
+        PathGenerator { process, times }
+    }
+
+    /// `steps` equal steps up to `maturity`.
+    pub fn uniform(process: Rc<P>, maturity: Time, steps: usize) -> PathGenerator<P> {
+        assert!(steps > 0, "no time steps");
+        let dt = maturity / steps as f64;
+        PathGenerator::new(process, (1..=steps).map(|i| i as f64 * dt).collect())
+    }
+
+    /// The path driven by one normal draw per simulation time.
+    pub fn path(&self, normals: &[f64]) -> Path {
+        assert!(
+            normals.len() == self.times.len(),
+            "one draw per time needed"
+        );
+        let mut times = vec![0.0];
+        let mut values = vec![self.process.x0()];
+        for (&t, dw) in self.times.iter().zip(normals) {
+            let (t0, x0) = (times[times.len() - 1], values[values.len() - 1]);
+            values.push(self.process.evolve(t0, x0, t - t0, *dw));
+            times.push(t);
+        }
+        Path::new(times, values)
+    }
+
+    /// Draws the normals of the next path from `rsg`, one sequence per
+    /// path.
+    pub fn draws<S: Rsg>(&self, rsg: &mut S) -> Vec<f64> {
+        assert!(
+            rsg.dimension() == self.times.len(),
+            "one draw per time needed"
+        );
+        rsg.next_sequence()
+    }
+}
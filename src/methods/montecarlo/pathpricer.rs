@@ -0,0 +1,6 @@
+use super::Path;
+
+/// Value today of a payoff on one simulated path.
+pub trait PathPricer {
+    fn price(&self, path: &Path) -> f64;
+}
@@ -0,0 +1,228 @@
+use crate::definitions::{DiscountFactor, Rate, Time, Volatility};
+use crate::math::{BoxMullerGaussianRng, RandomSequenceGenerator, Rsg, SplitMix64};
+use crate::models::{OneFactorAffineModel, ShortRateDynamics};
+use crate::utils::trace::Level;
+
+/// Asset the simulation prices in units of, choosing the measure the short
+/// rate is simulated under.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Numeraire {
+    /// The continuously compounded bank account (risk neutral measure).
+    BankAccount,
+    /// The discount bond maturing at the given time (T-forward measure);
+    /// the grid must not go past it.
+    ForwardBond(Time),
+    /// Sum of `accruals[i]` discount bonds maturing at `payment_times[i]`
+    /// (swap measure); the grid must not go past the first payment.
+    Annuity {
+        payment_times: Vec<Time>,
+        accruals: Vec<f64>,
+    },
+}
+
+impl Numeraire {
+    /// Value at `t` given the short rate and the bank account there.
+    pub fn value<M: OneFactorAffineModel + ?Sized>(
+        &self,
+        model: &M,
+        t: Time,
+        rate: Rate,
+        bank_account: f64,
+    ) -> f64 {
+        match self {
+            Numeraire::BankAccount => bank_account,
+            Numeraire::ForwardBond(maturity) => {
+                assert!(t <= *maturity, "time past the numeraire bond maturity");
+                model.discount_bond(t, *maturity, rate)
+            }
+            Numeraire::Annuity {
+                payment_times,
+                accruals,
+            } => {
+                assert!(
+                    !payment_times.is_empty() && payment_times.len() == accruals.len(),
+                    "payment times and accruals size mismatch"
+                );
+                assert!(t <= payment_times[0], "time past the first annuity payment");
+                payment_times
+                    .iter()
+                    .zip(accruals)
+                    .map(|(&p, tau)| tau * model.discount_bond(t, p, rate))
+                    .sum()
+            }
+        }
+    }
+
+    /// Lognormal volatility of the numeraire at `t`; the Brownian motion
+    /// under its measure is `dW - volatility dt`.
+    pub fn volatility<M: ShortRateDynamics + ?Sized>(&self, model: &M, t: Time, rate: Rate) -> f64 {
+        let sigma = model.short_rate_volatility(t);
+        match self {
+            Numeraire::BankAccount => 0.0,
+            Numeraire::ForwardBond(maturity) => bond_volatility(model, sigma, t, *maturity, rate),
+            Numeraire::Annuity {
+                payment_times,
+                accruals,
+            } => {
+                let annuity = self.value(model, t, rate, 1.0);
+                payment_times
+                    .iter()
+                    .zip(accruals)
+                    .map(|(&p, tau)| {
+                        tau * model.discount_bond(t, p, rate) / annuity
+                            * bond_volatility(model, sigma, t, p, rate)
+                    })
+                    .sum()
+            }
+        }
+    }
+}
+
+/// Lognormal volatility of the discount bond to `maturity`, from its rate
+/// sensitivity by central differences.
+fn bond_volatility<M: OneFactorAffineModel + ?Sized>(
+    model: &M,
+    sigma: Volatility,
+    t: Time,
+    maturity: Time,
+    rate: Rate,
+) -> f64 {
+    let h = 1.0e-4;
+    let up = model.discount_bond(t, maturity, rate + h).ln();
+    let down = model.discount_bond(t, maturity, rate - h).ln();
+    sigma * (up - down) / (2.0 * h)
+}
+
+/// Simulated short rates with the deflators `N(0) / N(t)` of the numeraire
+/// to each time, `times[0]` being today; a cash flow known at `times[i]` is
+/// worth its mean times `deflators[i]` today.
+#[derive(Clone, Debug)]
+pub struct ShortRatePath {
+    pub times: Vec<Time>,
+    pub rates: Vec<Rate>,
+    pub deflators: Vec<f64>,
+}
+
+impl ShortRatePath {
+    /// Price at `times[i]` of the discount bond maturing at `maturity`.
+    pub fn bond<M: OneFactorAffineModel + ?Sized>(
+        &self,
+        model: &M,
+        i: usize,
+        maturity: Time,
+    ) -> DiscountFactor {
+        model.discount_bond(self.times[i], maturity, self.rates[i])
+    }
+
+    /// Simple forward rate fixed at `times[i]` for the period to `end`, as
+    /// an Ibor fixing.
+    pub fn fixing<M: OneFactorAffineModel + ?Sized>(&self, model: &M, i: usize, end: Time) -> Rate {
+        let tau = end - self.times[i];
+        assert!(tau > 0.0, "fixing period must end after the fixing time");
+        (1.0 / self.bond(model, i, end) - 1.0) / tau
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MonteCarloResult {
+    pub value: f64,
+    pub error_estimate: f64,
+}
+
+/// Monte Carlo engine simulating a short rate model on a time grid; rates
+/// are stepped exactly under the bank account measure, with the drift
+/// change to other numeraires and the bank account integral taken on the
+/// grid, so the grid should be fine enough for the latter two.
+pub struct ShortRateMcEngine<M: ShortRateDynamics> {
+    pub model: M,
+    pub times: Vec<Time>,
+    pub paths: usize,
+    pub seed: u64,
+    pub antithetic: bool,
+    pub numeraire: Numeraire,
+}
+
+impl<M: ShortRateDynamics> ShortRateMcEngine<M> {
+    /// `times` are the simulation dates after today.
+    pub fn new(model: M, times: Vec<Time>, paths: usize, seed: u64) -> ShortRateMcEngine<M> {
+        assert!(paths > 0, "no paths requested");
+        assert!(
+            !times.is_empty() && times[0] > 0.0 && times.windows(2).all(|w| w[0] < w[1]),
+            "times must be positive and increasing"
+        );
+        ShortRateMcEngine {
+            model,
+            times,
+            paths,
+            seed,
+            antithetic: false,
+            numeraire: Numeraire::BankAccount,
+        }
+    }
+
+    pub fn with_antithetic(mut self) -> ShortRateMcEngine<M> {
+        self.antithetic = true;
+        self
+    }
+
+    pub fn with_numeraire(mut self, numeraire: Numeraire) -> ShortRateMcEngine<M> {
+        self.numeraire = numeraire;
+        self
+    }
+
+    /// The path driven by one normal draw per simulation time.
+    pub fn path(&self, normals: &[f64]) -> ShortRatePath {
+        assert!(
+            normals.len() == self.times.len(),
+            "one draw per time needed"
+        );
+        let (model, numeraire) = (&self.model, &self.numeraire);
+        let mut times = vec![0.0];
+        let mut rates = vec![model.initial_rate()];
+        let today = numeraire.value(model, 0.0, rates[0], 1.0);
+        let mut deflators = vec![1.0];
+        let mut integral = 0.0;
+        for (&t, z) in self.times.iter().zip(normals) {
+            let (t0, r0) = (times[times.len() - 1], rates[rates.len() - 1]);
+            let drift = model.short_rate_volatility(t0) * numeraire.volatility(model, t0, r0);
+            let r = model.evolve(t0, t, r0, *z) + drift * (t - t0);
+            integral += 0.5 * (r0 + r) * (t - t0);
+            times.push(t);
+            rates.push(r);
+            deflators.push(today / numeraire.value(model, t, r, integral.exp()));
+        }
+        ShortRatePath {
+            times,
+            rates,
+            deflators,
+        }
+    }
+
+    /// Mean of `payoff`, the value deflated to today along each path;
+    /// antithetic pairs count as one sample in the error estimate.
+    pub fn npv<F: Fn(&ShortRatePath) -> f64>(&self, payoff: F) -> MonteCarloResult {
+        let _span = crate::trace_span!(Level::Info, "short_rate_mc.npv", paths = self.paths);
+        let mut rsg = RandomSequenceGenerator::new(
+            BoxMullerGaussianRng::new(SplitMix64::new(self.seed)),
+            self.times.len(),
+        );
+        let (mut sum, mut sum2) = (0.0, 0.0);
+        for _ in 0..self.paths {
+            let normals = rsg.next_sequence();
+            let mut value = payoff(&self.path(&normals));
+            if self.antithetic {
+                let flipped: Vec<f64> = normals.iter().map(|z| -z).collect();
+                value = 0.5 * (value + payoff(&self.path(&flipped)));
+            }
+            sum += value;
+            sum2 += value * value;
+        }
+        let n = self.paths as f64;
+        let value = sum / n;
+        let variance = (sum2 / n - value * value).max(0.0);
+        MonteCarloResult {
+            value,
+            error_estimate: (variance / n).sqrt(),
+        }
+    }
+}
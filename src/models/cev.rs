@@ -0,0 +1,92 @@
+use crate::definitions::{DiscountFactor, Time, Volatility};
+use crate::instruments::OptionType;
+use crate::math::{incomplete_gamma_p, non_central_chi_squared_cdf};
+
+/// Constant elasticity of variance dynamics `dF = sigma F^beta dW` of a
+/// forward, with `0 <= beta < 1` and absorption at zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CevModel {
+    pub sigma: Volatility,
+    pub beta: f64,
+}
+
+impl CevModel {
+    pub fn new(sigma: Volatility, beta: f64) -> CevModel {
+        assert!(sigma > 0.0, "non positive volatility");
+        assert!((0.0..1.0).contains(&beta), "beta must be in [0, 1)");
+        CevModel { sigma, beta }
+    }
+
+    /// `F^(2(1 - beta)) / ((1 - beta)^2 sigma^2 t)`, the non-centrality
+    /// scale of the transition density.
+    fn scaled(&self, x: f64, t: Time) -> f64 {
+        let e = 1.0 - self.beta;
+        x.powf(2.0 * e) / (e * e * self.sigma * self.sigma * t)
+    }
+
+    /// Probability that the forward has been absorbed at zero by `t`.
+    pub fn absorption_probability(&self, forward: f64, t: Time) -> f64 {
+        assert!(forward > 0.0, "non positive forward");
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let nu = 0.5 / (1.0 - self.beta);
+        1.0 - incomplete_gamma_p(nu, 0.5 * self.scaled(forward, t))
+    }
+
+    /// Analytic European price (Schroder 1989) from non-central
+    /// chi-squared distributions.
+    pub fn vanilla_price(
+        &self,
+        option_type: OptionType,
+        strike: f64,
+        forward: f64,
+        t: Time,
+        discount: DiscountFactor,
+    ) -> f64 {
+        assert!(forward > 0.0, "non positive forward");
+        assert!(strike >= 0.0, "negative strike");
+        if t <= 0.0 || strike == 0.0 {
+            return (option_type.sign() * (forward - strike)).max(0.0) * discount;
+        }
+        let a = self.scaled(strike, t);
+        let b = 1.0 / (1.0 - self.beta);
+        let c = self.scaled(forward, t);
+        let p1 = non_central_chi_squared_cdf(a, b + 2.0, c);
+        let p2 = non_central_chi_squared_cdf(c, b, a);
+        match option_type {
+            OptionType::Call => discount * (forward * (1.0 - p1) - strike * p2),
+            OptionType::Put => discount * (strike * (1.0 - p2) - forward * p1),
+        }
+    }
+
+    /// Euler step driven by the Brownian increment `dw` over it; a
+    /// forward reaching zero stays there.
+    pub fn evolve(&self, forward: f64, dw: f64) -> f64 {
+        if forward <= 0.0 {
+            return 0.0;
+        }
+        (forward + self.sigma * forward.powf(self.beta) * dw).max(0.0)
+    }
+
+    /// Forwards at `times` from `forward` at time zero, given one standard
+    /// normal draw per step.
+    pub fn simulate(&self, forward: f64, times: &[Time], normals: &[f64]) -> Vec<f64> {
+        assert!(
+            times.len() == normals.len(),
+            "times and normals size mismatch"
+        );
+        let mut f = forward;
+        let mut t0 = 0.0;
+        times
+            .iter()
+            .zip(normals)
+            .map(|(&t, z)| {
+                assert!(t > t0, "times must be increasing");
+                f = self.evolve(f, z * (t - t0).sqrt());
+                t0 = t;
+                f
+            })
+            .collect()
+    }
+}
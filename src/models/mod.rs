@@ -0,0 +1,10 @@
+pub mod cev;
+pub mod shortrate;
+pub mod volatility;
+
+pub use self::cev::CevModel;
+pub use self::shortrate::{HoLee, HullWhite, OneFactorAffineModel, ShortRateDynamics, Vasicek};
+pub use self::volatility::{
+    close_to_close_volatility, ewma_volatility, garman_klass_volatility, log_returns,
+    parkinson_volatility, Garch11, IntervalPrice,
+};
@@ -0,0 +1,269 @@
+use crate::definitions::{DiscountFactor, Rate, Time, Volatility};
+use crate::instruments::OptionType;
+use crate::pricingengines::black_formula;
+use crate::termstructures::traits::YieldTermStructure;
+
+/// One-factor short rate model with affine discount bond prices
+/// `P(t, T) = A(t, T) exp(-B(t, T) r(t))` and Gaussian rates, so that
+/// bond options follow Jamshidian's Black-like formula.
+pub trait OneFactorAffineModel {
+    /// Price at `t` of the discount bond maturing at `maturity` when the
+    /// short rate is `rate`.
+    fn discount_bond(&self, t: Time, maturity: Time, rate: Rate) -> DiscountFactor;
+
+    /// Discount factor to `t` as of today.
+    fn discount(&self, t: Time) -> DiscountFactor;
+
+    /// Standard deviation of `ln P(expiry, maturity)`.
+    fn discount_bond_volatility(&self, expiry: Time, maturity: Time) -> f64;
+
+    /// European option expiring at `expiry` on the discount bond maturing
+    /// at `maturity`, per unit notional.
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: f64,
+        expiry: Time,
+        maturity: Time,
+    ) -> f64 {
+        assert!(
+            maturity > expiry,
+            "bond must mature after the option expiry"
+        );
+        let (pt, ps) = (self.discount(expiry), self.discount(maturity));
+        black_formula(
+            option_type,
+            strike,
+            ps / pt,
+            self.discount_bond_volatility(expiry, maturity),
+            pt,
+            0.0,
+        )
+    }
+
+    /// Cap (`Call`) or floor (`Put`) at `strike` on the simple forward rates
+    /// of the periods between consecutive `times`, each caplet valued as an
+    /// option on the discount bond over its period.
+    fn cap_floor(
+        &self,
+        option_type: OptionType,
+        strike: Rate,
+        times: &[Time],
+        nominal: f64,
+    ) -> f64 {
+        assert!(times.len() >= 2, "at least one period needed");
+        let bond_option = match option_type {
+            OptionType::Call => OptionType::Put,
+            OptionType::Put => OptionType::Call,
+        };
+        times
+            .windows(2)
+            .map(|w| {
+                let tau = w[1] - w[0];
+                let k = 1.0 + strike * tau;
+                nominal * k * self.discount_bond_option(bond_option, 1.0 / k, w[0], w[1])
+            })
+            .sum()
+    }
+}
+
+/// Exact transition of a Gaussian short rate, for path simulation.
+pub trait ShortRateDynamics: OneFactorAffineModel {
+    fn initial_rate(&self) -> Rate;
+
+    /// Short rate at `t1` from `rate` at `t0`, given a standard normal draw.
+    fn evolve(&self, t0: Time, t1: Time, rate: Rate, z: f64) -> Rate;
+
+    /// Diffusion coefficient of the short rate at `t`.
+    fn short_rate_volatility(&self, t: Time) -> Volatility;
+}
+
+/// Vasicek model `dr = a (b - r) dt + sigma dW`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vasicek {
+    pub r0: Rate,
+    pub a: f64,
+    pub b: Rate,
+    pub sigma: Volatility,
+}
+
+impl Vasicek {
+    pub fn new(r0: Rate, a: f64, b: Rate, sigma: Volatility) -> Vasicek {
+        assert!(a > 0.0, "non positive mean reversion");
+        assert!(sigma >= 0.0, "negative volatility");
+        Vasicek { r0, a, b, sigma }
+    }
+
+    fn b_factor(&self, tau: Time) -> f64 {
+        (1.0 - (-self.a * tau).exp()) / self.a
+    }
+}
+
+impl OneFactorAffineModel for Vasicek {
+    fn discount_bond(&self, t: Time, maturity: Time, rate: Rate) -> DiscountFactor {
+        let tau = maturity - t;
+        let b = self.b_factor(tau);
+        let (a, s2) = (self.a, self.sigma * self.sigma);
+        let ln_a = (b - tau) * (a * a * self.b - 0.5 * s2) / (a * a) - s2 * b * b / (4.0 * a);
+        (ln_a - b * rate).exp()
+    }
+
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.discount_bond(0.0, t, self.r0)
+    }
+
+    fn discount_bond_volatility(&self, expiry: Time, maturity: Time) -> f64 {
+        let a = self.a;
+        self.sigma
+            * self.b_factor(maturity - expiry)
+            * ((1.0 - (-2.0 * a * expiry).exp()) / (2.0 * a)).sqrt()
+    }
+}
+
+impl ShortRateDynamics for Vasicek {
+    fn initial_rate(&self) -> Rate {
+        self.r0
+    }
+
+    fn evolve(&self, t0: Time, t1: Time, rate: Rate, z: f64) -> Rate {
+        let decay = (-self.a * (t1 - t0)).exp();
+        let sd = self.sigma * ((1.0 - decay * decay) / (2.0 * self.a)).sqrt();
+        self.b + (rate - self.b) * decay + sd * z
+    }
+
+    fn short_rate_volatility(&self, _t: Time) -> Volatility {
+        self.sigma
+    }
+}
+
+/// Instantaneous forward rate of `curve` at `t` by central differences.
+fn instantaneous_forward<Y: YieldTermStructure>(curve: &Y, t: Time) -> Rate {
+    let h = 1.0e-4;
+    let t1 = (t - h).max(0.0);
+    let t2 = t1 + 2.0 * h;
+    (curve.discount_with_time(t1, true) / curve.discount_with_time(t2, true)).ln() / (t2 - t1)
+}
+
+/// Ho-Lee model `dr = theta(t) dt + sigma dW`, with the drift fitted to
+/// the initial curve.
+pub struct HoLee<Y: YieldTermStructure> {
+    pub curve: Y,
+    pub sigma: Volatility,
+}
+
+impl<Y: YieldTermStructure> HoLee<Y> {
+    pub fn new(curve: Y, sigma: Volatility) -> HoLee<Y> {
+        assert!(sigma >= 0.0, "negative volatility");
+        HoLee { curve, sigma }
+    }
+
+    /// Instantaneous forward rate of the initial curve at `t`.
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        instantaneous_forward(&self.curve, t)
+    }
+}
+
+impl<Y: YieldTermStructure> OneFactorAffineModel for HoLee<Y> {
+    fn discount_bond(&self, t: Time, maturity: Time, rate: Rate) -> DiscountFactor {
+        let tau = maturity - t;
+        let forward = self.discount(maturity) / self.discount(t);
+        forward
+            * (tau * self.instantaneous_forward(t)
+                - 0.5 * self.sigma * self.sigma * t * tau * tau
+                - tau * rate)
+                .exp()
+    }
+
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.curve.discount_with_time(t, true)
+    }
+
+    fn discount_bond_volatility(&self, expiry: Time, maturity: Time) -> f64 {
+        self.sigma * (maturity - expiry) * expiry.sqrt()
+    }
+}
+
+impl<Y: YieldTermStructure> ShortRateDynamics for HoLee<Y> {
+    fn initial_rate(&self) -> Rate {
+        self.instantaneous_forward(0.0)
+    }
+
+    fn evolve(&self, t0: Time, t1: Time, rate: Rate, z: f64) -> Rate {
+        // r(t) = f(0, t) + sigma^2 t^2 / 2 + sigma W(t)
+        let drift = |t: Time| self.instantaneous_forward(t) + 0.5 * self.sigma * self.sigma * t * t;
+        rate + drift(t1) - drift(t0) + self.sigma * (t1 - t0).sqrt() * z
+    }
+
+    fn short_rate_volatility(&self, _t: Time) -> Volatility {
+        self.sigma
+    }
+}
+
+/// Hull-White model `dr = (theta(t) - a r) dt + sigma dW`, with the drift
+/// fitted to the initial curve.
+pub struct HullWhite<Y: YieldTermStructure> {
+    pub curve: Y,
+    pub a: f64,
+    pub sigma: Volatility,
+}
+
+impl<Y: YieldTermStructure> HullWhite<Y> {
+    pub fn new(curve: Y, a: f64, sigma: Volatility) -> HullWhite<Y> {
+        assert!(a > 0.0, "non positive mean reversion");
+        assert!(sigma >= 0.0, "negative volatility");
+        HullWhite { curve, a, sigma }
+    }
+
+    fn b_factor(&self, tau: Time) -> f64 {
+        (1.0 - (-self.a * tau).exp()) / self.a
+    }
+
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        instantaneous_forward(&self.curve, t)
+    }
+
+    /// Deterministic part of the short rate: `r(t) = x(t) + alpha(t)` with
+    /// `x` an Ornstein-Uhlenbeck process started at zero.
+    pub fn alpha(&self, t: Time) -> Rate {
+        let s = self.sigma / self.a * (1.0 - (-self.a * t).exp());
+        self.instantaneous_forward(t) + 0.5 * s * s
+    }
+}
+
+impl<Y: YieldTermStructure> OneFactorAffineModel for HullWhite<Y> {
+    fn discount_bond(&self, t: Time, maturity: Time, rate: Rate) -> DiscountFactor {
+        let b = self.b_factor(maturity - t);
+        let forward = self.discount(maturity) / self.discount(t);
+        let variance =
+            self.sigma * self.sigma / (4.0 * self.a) * (1.0 - (-2.0 * self.a * t).exp()) * b * b;
+        forward * (b * self.instantaneous_forward(t) - variance - b * rate).exp()
+    }
+
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.curve.discount_with_time(t, true)
+    }
+
+    fn discount_bond_volatility(&self, expiry: Time, maturity: Time) -> f64 {
+        let a = self.a;
+        self.sigma
+            * self.b_factor(maturity - expiry)
+            * ((1.0 - (-2.0 * a * expiry).exp()) / (2.0 * a)).sqrt()
+    }
+}
+
+impl<Y: YieldTermStructure> ShortRateDynamics for HullWhite<Y> {
+    fn initial_rate(&self) -> Rate {
+        self.alpha(0.0)
+    }
+
+    fn evolve(&self, t0: Time, t1: Time, rate: Rate, z: f64) -> Rate {
+        let decay = (-self.a * (t1 - t0)).exp();
+        let sd = self.sigma * ((1.0 - decay * decay) / (2.0 * self.a)).sqrt();
+        let x = rate - self.alpha(t0);
+        x * decay + sd * z + self.alpha(t1)
+    }
+
+    fn short_rate_volatility(&self, _t: Time) -> Volatility {
+        self.sigma
+    }
+}
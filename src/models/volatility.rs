@@ -0,0 +1,207 @@
+use crate::definitions::Volatility;
+use crate::math::simplex_minimize;
+use crate::time::TimeSeries;
+use crate::utils::trace::Level;
+use crate::utils::Parallelism;
+
+/// Prices observed over an interval, e.g. a trading day.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IntervalPrice {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl IntervalPrice {
+    pub fn new(open: f64, high: f64, low: f64, close: f64) -> IntervalPrice {
+        assert!(low > 0.0, "non positive price");
+        assert!(
+            low <= open.min(close) && high >= open.max(close),
+            "open and close must be within the low and high"
+        );
+        IntervalPrice {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+/// Log returns between consecutive observations, dated at the end of each
+/// period.
+pub fn log_returns(closes: &TimeSeries<f64>) -> TimeSeries<f64> {
+    let mut returns = TimeSeries::new();
+    let observations: Vec<(_, _)> = closes.iter().collect();
+    for w in observations.windows(2) {
+        assert!(*w[0].1 > 0.0 && *w[1].1 > 0.0, "non positive price");
+        returns.insert(*w[1].0, (w[1].1 / w[0].1).ln());
+    }
+    returns
+}
+
+/// Annualized sample standard deviation of the log returns of `closes`,
+/// observed `periods_per_year` times a year.
+pub fn close_to_close_volatility(closes: &TimeSeries<f64>, periods_per_year: f64) -> Volatility {
+    let returns = log_returns(closes).values();
+    let n = returns.len();
+    assert!(n >= 2, "at least three prices needed");
+    let mean = returns.iter().sum::<f64>() / n as f64;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    (variance * periods_per_year).sqrt()
+}
+
+/// Parkinson's estimator from the high-low range, assuming no drift.
+pub fn parkinson_volatility(
+    prices: &TimeSeries<IntervalPrice>,
+    periods_per_year: f64,
+) -> Volatility {
+    assert!(!prices.is_empty(), "no prices given");
+    let n = prices.len() as f64;
+    let sum: f64 = prices
+        .iter()
+        .map(|(_, p)| (p.high / p.low).ln().powi(2))
+        .sum();
+    (sum / (4.0 * n * 2.0_f64.ln()) * periods_per_year).sqrt()
+}
+
+/// Garman and Klass's estimator from open, high, low and close prices.
+pub fn garman_klass_volatility(
+    prices: &TimeSeries<IntervalPrice>,
+    periods_per_year: f64,
+) -> Volatility {
+    assert!(!prices.is_empty(), "no prices given");
+    let n = prices.len() as f64;
+    let sum: f64 = prices
+        .iter()
+        .map(|(_, p)| {
+            0.5 * (p.high / p.low).ln().powi(2)
+                - (2.0 * 2.0_f64.ln() - 1.0) * (p.close / p.open).ln().powi(2)
+        })
+        .sum();
+    (sum / n * periods_per_year).sqrt()
+}
+
+/// Exponentially weighted (RiskMetrics) volatility after each return,
+/// with `sigma2 = lambda sigma2 + (1 - lambda) r^2` started from the first
+/// squared return and annualized.
+pub fn ewma_volatility(
+    closes: &TimeSeries<f64>,
+    lambda: f64,
+    periods_per_year: f64,
+) -> TimeSeries<Volatility> {
+    assert!(lambda > 0.0 && lambda < 1.0, "lambda must be in (0, 1)");
+    let mut estimates = TimeSeries::new();
+    let mut variance: Option<f64> = None;
+    for (date, r) in log_returns(closes).iter() {
+        let v = match variance {
+            None => r * r,
+            Some(v) => lambda * v + (1.0 - lambda) * r * r,
+        };
+        variance = Some(v);
+        estimates.insert(*date, (v * periods_per_year).sqrt());
+    }
+    estimates
+}
+
+/// GARCH(1,1) per-period variance `sigma2 = omega + alpha r^2 + beta
+/// sigma2` of demeaned returns.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Garch11 {
+    pub omega: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl Garch11 {
+    pub fn new(omega: f64, alpha: f64, beta: f64) -> Garch11 {
+        assert!(omega > 0.0, "non positive omega");
+        assert!(alpha >= 0.0 && beta >= 0.0, "negative alpha or beta");
+        assert!(alpha + beta < 1.0, "alpha + beta must be below one");
+        Garch11 { omega, alpha, beta }
+    }
+
+    /// Fits the parameters to `returns` by maximum likelihood under
+    /// normal innovations.
+    pub fn fit(returns: &[f64]) -> Garch11 {
+        assert!(returns.len() >= 10, "too few returns to fit");
+        let _span = crate::trace_span!(Level::Info, "garch11.fit", returns = returns.len());
+        let sample = returns.iter().map(|r| r * r).sum::<f64>() / returns.len() as f64;
+        // omega = sample variance * (1 - alpha - beta) keeps the long run
+        // variance near the sample one at the start.
+        let parameters = |x: &[f64]| {
+            let persistence = logistic(x[0]);
+            let alpha = persistence * logistic(x[1]);
+            let beta = persistence - alpha;
+            let omega = sample * (1.0 - persistence) * x[2].exp();
+            (omega, alpha, beta)
+        };
+        let objective = |x: &[f64]| {
+            let (omega, alpha, beta) = parameters(x);
+            -Garch11 { omega, alpha, beta }.log_likelihood(returns)
+        };
+        let x = simplex_minimize(objective, &[2.0, -2.0, 0.0], 0.5, 1.0e-12, 5000);
+        let (omega, alpha, beta) = parameters(&x);
+        crate::trace_event!(
+            Level::Debug,
+            "garch11.fitted",
+            omega = omega,
+            alpha = alpha,
+            beta = beta
+        );
+        Garch11::new(omega, alpha, beta)
+    }
+
+    /// Fits the parameters to each of `series`, e.g. the returns of the
+    /// assets of a portfolio, under `parallelism`.
+    pub fn fit_all(series: &[Vec<f64>], parallelism: Parallelism) -> Vec<Garch11> {
+        parallelism.map(series, |returns| Garch11::fit(returns))
+    }
+
+    /// Fits the parameters to the demeaned log returns of `closes`.
+    pub fn fit_prices(closes: &TimeSeries<f64>) -> Garch11 {
+        let returns = log_returns(closes).values();
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let demeaned: Vec<f64> = returns.iter().map(|r| r - mean).collect();
+        Garch11::fit(&demeaned)
+    }
+
+    pub fn long_run_variance(&self) -> f64 {
+        self.omega / (1.0 - self.alpha - self.beta)
+    }
+
+    /// Conditional variances before each return, started from the long
+    /// run variance.
+    pub fn variances(&self, returns: &[f64]) -> Vec<f64> {
+        let mut v = self.long_run_variance();
+        returns
+            .iter()
+            .map(|r| {
+                let current = v;
+                v = self.omega + self.alpha * r * r + self.beta * v;
+                current
+            })
+            .collect()
+    }
+
+    /// Gaussian log-likelihood of `returns`, up to a constant.
+    pub fn log_likelihood(&self, returns: &[f64]) -> f64 {
+        self.variances(returns)
+            .iter()
+            .zip(returns)
+            .map(|(v, r)| -0.5 * (v.ln() + r * r / v))
+            .sum()
+    }
+
+    /// Expected variance `h` periods after one of `variance`, reverting to
+    /// the long run variance.
+    pub fn forecast(&self, variance: f64, h: usize) -> f64 {
+        let long_run = self.long_run_variance();
+        long_run + (self.alpha + self.beta).powi(h as i32) * (variance - long_run)
+    }
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
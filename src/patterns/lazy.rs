@@ -1,14 +1,120 @@
-#[derive(Default, Clone)]
+use super::observable::Observable;
+use super::observer::Observer;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct LazyState {
+    calculated: Cell<bool>,
+    frozen: Cell<bool>,
+    forced: Cell<bool>,
+    always_forward: Cell<bool>,
+    observable: Observable,
+}
+
+impl Observer for LazyState {
+    fn update(&self) {
+        // results nobody asked for yet have no observers to warn again
+        if self.calculated.get() || self.always_forward.get() {
+            self.calculated.set(false);
+            if !self.frozen.get() {
+                self.observable.notify_observers();
+            }
+        }
+    }
+}
+
+/// Calculation state of an object caching results computed from its
+/// inputs, QuantLib's `LazyObject`.
+///
+/// The owner registers the lazy object with the observables of its inputs
+/// and asks `calculate` before returning results; notifications mark the
+/// results stale and are passed on to the owner's own observers. A frozen
+/// object keeps its results whatever its inputs do until it is unfrozen
+/// or explicitly recalculated, so that batch jobs can bump many quotes
+/// before a single revaluation pass.
+///
+/// Clones start calculated afresh and without registrations.
+#[derive(Default)]
 pub struct LazyObject {
-    pub calculated: bool,
-    pub frozen: bool,
-    pub always_forward: bool,
+    state: Rc<LazyState>,
 }
 
 impl LazyObject {
-    pub fn update(&mut self) {}
-    pub fn recalculate(&mut self) {}
-    pub fn calculate(&mut self) {}
-    pub fn freeze(&mut self) {}
-    pub fn unfreeze(&mut self) {}
+    pub fn new() -> LazyObject {
+        LazyObject::default()
+    }
+
+    /// Forwards every notification, not only the first one after a
+    /// calculation.
+    pub fn with_always_forward(self) -> LazyObject {
+        self.state.always_forward.set(true);
+        self
+    }
+
+    /// Observes `observable`, one of the inputs of the owner.
+    pub fn register_with(&self, observable: &Observable) {
+        observable.register_observer(Rc::clone(&self.state) as Rc<dyn Observer>);
+    }
+
+    /// Notifies the observers of the owner.
+    pub fn observable(&self) -> &Observable {
+        &self.state.observable
+    }
+
+    pub fn is_calculated(&self) -> bool {
+        self.state.calculated.get()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.state.frozen.get()
+    }
+
+    /// Marks the results stale as if an input had changed.
+    pub fn update(&self) {
+        self.state.update();
+    }
+
+    /// Whether the owner has to perform its calculations now, marking them
+    /// done if so. Frozen objects keep their results unless recalculated.
+    pub fn calculate(&self) -> bool {
+        let state = &self.state;
+        if state.forced.replace(false) || (!state.calculated.get() && !state.frozen.get()) {
+            state.calculated.set(true);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Forces the next `calculate`, even when frozen, and notifies the
+    /// observers of the owner.
+    pub fn recalculate(&self) {
+        self.state.forced.set(true);
+        self.state.calculated.set(false);
+        self.state.observable.notify_observers();
+    }
+
+    /// Ignores notifications and keeps the current results.
+    pub fn freeze(&self) {
+        self.state.frozen.set(true);
+    }
+
+    /// Resumes following the inputs; results made stale while frozen are
+    /// recalculated and observers notified.
+    pub fn unfreeze(&self) {
+        if self.state.frozen.replace(false) {
+            self.state.observable.notify_observers();
+        }
+    }
+}
+
+impl Clone for LazyObject {
+    fn clone(&self) -> LazyObject {
+        let lazy = LazyObject::new();
+        lazy.state
+            .always_forward
+            .set(self.state.always_forward.get());
+        lazy
+    }
 }
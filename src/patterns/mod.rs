@@ -1,3 +1,7 @@
 pub mod lazy;
+pub mod observable;
+pub mod observer;
 
 pub use self::lazy::LazyObject;
+pub use self::observable::Observable;
+pub use self::observer::{ChangeFlag, Observer};
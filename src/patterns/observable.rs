@@ -0,0 +1,70 @@
+use super::observer::Observer;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+/// Notifies registered observers of changes.
+///
+/// Observers are held weakly, so that dropping one unregisters it. Clones
+/// start without observers: registrations are with a given object, not
+/// with its copies.
+#[derive(Default)]
+pub struct Observable {
+    observers: RefCell<Vec<Weak<dyn Observer>>>,
+}
+
+impl Observable {
+    pub fn new() -> Observable {
+        Observable::default()
+    }
+
+    /// Registers `observer`, once however many times it is passed.
+    pub fn register_observer(&self, observer: Rc<dyn Observer>) {
+        let weak = Rc::downgrade(&observer);
+        let mut observers = self.observers.borrow_mut();
+        if !observers.iter().any(|o| o.ptr_eq(&weak)) {
+            observers.push(weak);
+        }
+    }
+
+    pub fn unregister_observer(&self, observer: &Rc<dyn Observer>) {
+        let weak = Rc::downgrade(observer);
+        self.observers.borrow_mut().retain(|o| !o.ptr_eq(&weak));
+    }
+
+    /// Calls `update` on every live observer, dropping the others.
+    pub fn notify_observers(&self) {
+        let live: Vec<Rc<dyn Observer>> = {
+            let mut observers = self.observers.borrow_mut();
+            observers.retain(|o| o.strong_count() > 0);
+            observers.iter().filter_map(Weak::upgrade).collect()
+        };
+        // observers may register or unregister while being updated
+        for observer in live {
+            observer.update();
+        }
+    }
+
+    /// The number of live observers.
+    pub fn observer_count(&self) -> usize {
+        self.observers
+            .borrow()
+            .iter()
+            .filter(|o| o.strong_count() > 0)
+            .count()
+    }
+}
+
+impl Clone for Observable {
+    fn clone(&self) -> Observable {
+        Observable::new()
+    }
+}
+
+impl fmt::Debug for Observable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Observable")
+            .field("observers", &self.observer_count())
+            .finish()
+    }
+}
@@ -0,0 +1,48 @@
+use super::observable::Observable;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Receives the notifications of the observables it is registered with.
+pub trait Observer {
+    fn update(&self);
+}
+
+/// Observer recording that something it depends on changed, and passing
+/// the notification on to its own observers.
+///
+/// Objects computed from market data (term structures, indexes,
+/// instruments) hold one, register it with their inputs and expose its
+/// `observable` to their dependents, so that a quote change flags the
+/// whole chain for recalculation.
+#[derive(Default)]
+pub struct ChangeFlag {
+    changed: Cell<bool>,
+    observable: Observable,
+}
+
+impl ChangeFlag {
+    pub fn new() -> Rc<ChangeFlag> {
+        Rc::new(ChangeFlag::default())
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.changed.get()
+    }
+
+    /// Clears the flag and returns whether it was set.
+    pub fn take(&self) -> bool {
+        self.changed.replace(false)
+    }
+
+    /// Notifies the observers of the owner of the flag.
+    pub fn observable(&self) -> &Observable {
+        &self.observable
+    }
+}
+
+impl Observer for ChangeFlag {
+    fn update(&self) {
+        self.changed.set(true);
+        self.observable.notify_observers();
+    }
+}
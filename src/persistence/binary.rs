@@ -0,0 +1,135 @@
+use crate::time::{Date, Month};
+
+/// Largest day offset from the epoch accepted when decoding a date.
+const MAX_DAY_OFFSET: i64 = 3_000_000;
+
+fn epoch() -> Date {
+    Date::new(1, Month::January, 1970)
+}
+
+/// 64-bit FNV-1a hash, used to detect corrupted or truncated payloads.
+/// It guards against accidents, not against tampering.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in bytes {
+        hash ^= u64::from(*b);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Appends little-endian encoded values to a byte buffer.
+#[derive(Clone, Debug, Default)]
+pub struct BinaryWriter {
+    pub buffer: Vec<u8>,
+}
+
+impl BinaryWriter {
+    pub fn new() -> BinaryWriter {
+        BinaryWriter::default()
+    }
+    pub fn put_u8(&mut self, x: u8) {
+        self.buffer.push(x);
+    }
+    pub fn put_u16(&mut self, x: u16) {
+        self.buffer.extend_from_slice(&x.to_le_bytes());
+    }
+    pub fn put_u64(&mut self, x: u64) {
+        self.buffer.extend_from_slice(&x.to_le_bytes());
+    }
+    pub fn put_i64(&mut self, x: i64) {
+        self.buffer.extend_from_slice(&x.to_le_bytes());
+    }
+    pub fn put_f64(&mut self, x: f64) {
+        self.buffer.extend_from_slice(&x.to_bits().to_le_bytes());
+    }
+    /// Length-prefixed UTF-8 string.
+    pub fn put_str(&mut self, s: &str) {
+        self.put_u64(s.len() as u64);
+        self.buffer.extend_from_slice(s.as_bytes());
+    }
+    /// Days since 1970-01-01.
+    pub fn put_date(&mut self, date: Date) {
+        self.put_i64(date.sub(epoch()));
+    }
+    /// Length-prefixed sequence of numbers.
+    pub fn put_f64s(&mut self, values: &[f64]) {
+        self.put_u64(values.len() as u64);
+        for x in values {
+            self.put_f64(*x);
+        }
+    }
+}
+
+/// Reads values encoded by `BinaryWriter`, failing instead of panicking on
+/// malformed input.
+#[derive(Clone, Debug)]
+pub struct BinaryReader<'a> {
+    pub bytes: &'a [u8],
+    pub position: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> BinaryReader<'a> {
+        BinaryReader { bytes, position: 0 }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], &'static str> {
+        if self.bytes.len() - self.position < n {
+            return Err("unexpected end of data");
+        }
+        let slice = &self.bytes[self.position..self.position + n];
+        self.position += n;
+        Ok(slice)
+    }
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], &'static str> {
+        let mut a = [0; N];
+        a.copy_from_slice(self.take(N)?);
+        Ok(a)
+    }
+
+    pub fn get_u8(&mut self) -> Result<u8, &'static str> {
+        Ok(self.take(1)?[0])
+    }
+    pub fn get_u16(&mut self) -> Result<u16, &'static str> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+    pub fn get_u64(&mut self) -> Result<u64, &'static str> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+    pub fn get_i64(&mut self) -> Result<i64, &'static str> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+    pub fn get_f64(&mut self) -> Result<f64, &'static str> {
+        Ok(f64::from_bits(self.get_u64()?))
+    }
+    /// A length prefix, checked against the remaining data so that a
+    /// corrupted length cannot trigger a huge allocation.
+    fn get_len(&mut self, item_size: usize) -> Result<usize, &'static str> {
+        let n = self.get_u64()?;
+        let remaining = (self.bytes.len() - self.position) as u64;
+        if n.saturating_mul(item_size as u64) > remaining {
+            return Err("unexpected end of data");
+        }
+        Ok(n as usize)
+    }
+    pub fn get_str(&mut self) -> Result<String, &'static str> {
+        let n = self.get_len(1)?;
+        String::from_utf8(self.take(n)?.to_vec()).map_err(|_| "invalid UTF-8 string")
+    }
+    pub fn get_date(&mut self) -> Result<Date, &'static str> {
+        let days = self.get_i64()?;
+        if days.abs() > MAX_DAY_OFFSET {
+            return Err("date out of range");
+        }
+        Ok(epoch().add_days(days))
+    }
+    pub fn get_f64s(&mut self) -> Result<Vec<f64>, &'static str> {
+        let n = self.get_len(8)?;
+        (0..n).map(|_| self.get_f64()).collect()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod binary;
+pub mod snapshot;
+
+pub use self::binary::{fnv1a, BinaryReader, BinaryWriter};
+pub use self::snapshot::{CurveSnapshot, MarketState, ModelSnapshot, SCHEMA_VERSION};
@@ -0,0 +1,277 @@
+use super::binary::{fnv1a, BinaryReader, BinaryWriter};
+use crate::definitions::{DiscountFactor, Time};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Leading bytes of every market state file.
+pub const MAGIC: &[u8; 4] = b"QLMS";
+/// Version of the layout written by `MarketState::to_bytes`. Readers accept
+/// every version up to this one.
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// Discount factors of a bootstrapped curve at its nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveSnapshot {
+    pub name: String,
+    pub reference_date: Date,
+    pub times: Vec<Time>,
+    pub discounts: Vec<DiscountFactor>,
+}
+
+impl CurveSnapshot {
+    pub fn new(
+        name: &str,
+        reference_date: Date,
+        times: Vec<Time>,
+        discounts: Vec<DiscountFactor>,
+    ) -> CurveSnapshot {
+        assert!(!times.is_empty(), "no curve nodes given");
+        assert!(
+            times.len() == discounts.len(),
+            "times and discounts size mismatch"
+        );
+        assert!(
+            times.windows(2).all(|t| t[0] < t[1]),
+            "node times must be increasing"
+        );
+        assert!(discounts.iter().all(|d| *d > 0.0), "non positive discount");
+        CurveSnapshot {
+            name: name.to_string(),
+            reference_date,
+            times,
+            discounts,
+        }
+    }
+
+    /// Snapshot of `curve` sampled at the given node times.
+    pub fn from_curve<Y: YieldTermStructure>(
+        name: &str,
+        curve: &mut Y,
+        times: Vec<Time>,
+    ) -> CurveSnapshot {
+        let reference_date = curve.reference_date();
+        let discounts = times
+            .iter()
+            .map(|t| curve.discount_with_time(*t, true))
+            .collect();
+        CurveSnapshot::new(name, reference_date, times, discounts)
+    }
+
+    /// Discount factor at `t`, interpolated log-linearly between nodes and
+    /// with flat forward extrapolation.
+    pub fn discount(&self, t: Time) -> DiscountFactor {
+        let n = self.times.len();
+        let (i, j) = match self.times.iter().position(|x| *x >= t) {
+            Some(0) if self.times[0] > 0.0 => {
+                let rate = -self.discounts[0].ln() / self.times[0];
+                return (-rate * t).exp();
+            }
+            Some(0) => (0, 1.min(n - 1)),
+            Some(i) => (i - 1, i),
+            None if n == 1 => (0, 0),
+            None => (n - 2, n - 1),
+        };
+        if i == j {
+            return self.discounts[i];
+        }
+        let (t1, t2) = (self.times[i], self.times[j]);
+        let (l1, l2) = (self.discounts[i].ln(), self.discounts[j].ln());
+        (l1 + (l2 - l1) * (t - t1) / (t2 - t1)).exp()
+    }
+
+    fn write(&self, w: &mut BinaryWriter) {
+        w.put_str(&self.name);
+        w.put_date(self.reference_date);
+        w.put_f64s(&self.times);
+        w.put_f64s(&self.discounts);
+    }
+
+    fn read(r: &mut BinaryReader) -> Result<CurveSnapshot, &'static str> {
+        let name = r.get_str()?;
+        let reference_date = r.get_date()?;
+        let times = r.get_f64s()?;
+        let discounts = r.get_f64s()?;
+        let valid = !times.is_empty()
+            && times.len() == discounts.len()
+            && times.windows(2).all(|t| t[0] < t[1])
+            && discounts.iter().all(|d| *d > 0.0);
+        if !valid {
+            return Err("invalid curve nodes");
+        }
+        Ok(CurveSnapshot {
+            name,
+            reference_date,
+            times,
+            discounts,
+        })
+    }
+}
+
+/// Named parameters of a calibrated model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelSnapshot {
+    pub name: String,
+    pub parameters: Vec<(String, f64)>,
+}
+
+impl ModelSnapshot {
+    pub fn new(name: &str, parameters: Vec<(String, f64)>) -> ModelSnapshot {
+        ModelSnapshot {
+            name: name.to_string(),
+            parameters,
+        }
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameters
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, x)| *x)
+    }
+
+    fn write(&self, w: &mut BinaryWriter) {
+        w.put_str(&self.name);
+        w.put_u64(self.parameters.len() as u64);
+        for (name, x) in &self.parameters {
+            w.put_str(name);
+            w.put_f64(*x);
+        }
+    }
+
+    fn read(r: &mut BinaryReader) -> Result<ModelSnapshot, &'static str> {
+        let name = r.get_str()?;
+        let n = r.get_u64()?;
+        let mut parameters = Vec::new();
+        for _ in 0..n {
+            parameters.push((r.get_str()?, r.get_f64()?));
+        }
+        Ok(ModelSnapshot { name, parameters })
+    }
+}
+
+/// Bootstrapped curves and calibrated models as of a date, persisted in a
+/// compact binary format so that a pricing service can warm-start from the
+/// last known good market state.
+///
+/// The file holds the magic bytes, the schema version, the payload length
+/// and an FNV-1a hash of the payload, followed by the payload; all numbers
+/// are little endian.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketState {
+    pub as_of: Date,
+    /// The `MarketVersion` the state was built from.
+    pub market_version: u64,
+    pub curves: Vec<CurveSnapshot>,
+    pub models: Vec<ModelSnapshot>,
+}
+
+impl MarketState {
+    pub fn new(as_of: Date, market_version: u64) -> MarketState {
+        MarketState {
+            as_of,
+            market_version,
+            curves: vec![],
+            models: vec![],
+        }
+    }
+
+    pub fn with_curve(mut self, curve: CurveSnapshot) -> MarketState {
+        self.curves.push(curve);
+        self
+    }
+
+    pub fn with_model(mut self, model: ModelSnapshot) -> MarketState {
+        self.models.push(model);
+        self
+    }
+
+    pub fn curve(&self, name: &str) -> Option<&CurveSnapshot> {
+        self.curves.iter().find(|c| c.name == name)
+    }
+
+    pub fn model(&self, name: &str) -> Option<&ModelSnapshot> {
+        self.models.iter().find(|m| m.name == name)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut payload = BinaryWriter::new();
+        payload.put_date(self.as_of);
+        payload.put_u64(self.market_version);
+        payload.put_u64(self.curves.len() as u64);
+        for c in &self.curves {
+            c.write(&mut payload);
+        }
+        payload.put_u64(self.models.len() as u64);
+        for m in &self.models {
+            m.write(&mut payload);
+        }
+
+        let mut w = BinaryWriter::new();
+        w.buffer.extend_from_slice(MAGIC);
+        w.put_u16(SCHEMA_VERSION);
+        w.put_u64(payload.buffer.len() as u64);
+        w.put_u64(fnv1a(&payload.buffer));
+        w.buffer.extend_from_slice(&payload.buffer);
+        w.buffer
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<MarketState, &'static str> {
+        let mut r = BinaryReader::new(bytes);
+        if bytes.len() < MAGIC.len() || &bytes[..MAGIC.len()] != MAGIC {
+            return Err("not a market state file");
+        }
+        r.position = MAGIC.len();
+        let version = r.get_u16()?;
+        if version == 0 || version > SCHEMA_VERSION {
+            return Err("unsupported schema version");
+        }
+        let length = r.get_u64()?;
+        let hash = r.get_u64()?;
+        let payload = &bytes[r.position..];
+        if payload.len() as u64 != length {
+            return Err("payload length mismatch");
+        }
+        if fnv1a(payload) != hash {
+            return Err("integrity hash mismatch");
+        }
+
+        let mut r = BinaryReader::new(payload);
+        // version 1 is the only layout so far; later versions dispatch here.
+        let state = MarketState::read_v1(&mut r)?;
+        if !r.is_at_end() {
+            return Err("trailing data after payload");
+        }
+        Ok(state)
+    }
+
+    fn read_v1(r: &mut BinaryReader) -> Result<MarketState, &'static str> {
+        let as_of = r.get_date()?;
+        let market_version = r.get_u64()?;
+        let mut state = MarketState::new(as_of, market_version);
+        for _ in 0..r.get_u64()? {
+            state.curves.push(CurveSnapshot::read(r)?);
+        }
+        for _ in 0..r.get_u64()? {
+            state.models.push(ModelSnapshot::read(r)?);
+        }
+        Ok(state)
+    }
+
+    /// Writes the state to `path` through a temporary file renamed into
+    /// place, so that an interrupted save leaves the previous file intact.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, self.to_bytes())?;
+        fs::rename(&tmp, path)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<MarketState> {
+        let bytes = fs::read(path)?;
+        MarketState::from_bytes(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
@@ -0,0 +1,146 @@
+use super::blackformula::black_formula;
+use crate::definitions::Time;
+use crate::instruments::{curve_valuation, Valuation, ValuationStatus, VanillaOption};
+use crate::math::{normal_cdf, normal_pdf};
+use crate::termstructures::traits::{BlackVolTermStructure, YieldTermStructure};
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Value and sensitivities of a European option, theta per year and rhos
+/// per unit parallel shift of the continuously compounded zero rates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EuropeanGreeks {
+    pub npv: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+    pub dividend_rho: f64,
+    pub status: ValuationStatus,
+}
+
+/// Black-Scholes-Merton engine: the spot grows at the risk-free minus the
+/// dividend yield to the forward, which is lognormal with the Black
+/// volatility at the strike. Times are measured on the risk-free curve,
+/// and options expired by its reference date are worth zero.
+pub struct AnalyticEuropeanEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub risk_free: Rc<R>,
+    pub dividend: Rc<D>,
+    pub volatility: Rc<V>,
+}
+
+impl<R, D, V> AnalyticEuropeanEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub fn new(
+        risk_free: Rc<R>,
+        dividend: Rc<D>,
+        volatility: Rc<V>,
+    ) -> AnalyticEuropeanEngine<R, D, V> {
+        AnalyticEuropeanEngine {
+            risk_free,
+            dividend,
+            volatility,
+        }
+    }
+
+    fn expiry(&self, option: &VanillaOption) -> Time {
+        self.risk_free.time_from_reference(option.exercise_date)
+    }
+
+    /// The value of `option`, zero once expired.
+    pub fn valuation(&self, option: &VanillaOption, spot: f64) -> Valuation {
+        let _span = crate::trace_span!(Level::Debug, "vanilla_option.analytic_european.npv");
+        curve_valuation(
+            option,
+            |d| self.risk_free.time_from_reference(d),
+            |o| self.price(o, spot),
+        )
+    }
+
+    pub fn npv(&self, option: &VanillaOption, spot: f64) -> f64 {
+        self.valuation(option, spot).npv
+    }
+
+    fn price(&self, option: &VanillaOption, spot: f64) -> f64 {
+        let t = self.expiry(option);
+        let discount = self.risk_free.discount(option.exercise_date, true);
+        let forward = spot * self.dividend.discount(option.exercise_date, true) / discount;
+        black_formula(
+            option.option_type,
+            option.strike,
+            forward,
+            self.volatility.black_variance(t, option.strike).sqrt(),
+            discount,
+            0.0,
+        )
+    }
+
+    /// Analytic Greeks; theta holds the curves' zero rates and the total
+    /// variance rate fixed. All are zero once the option expired.
+    pub fn greeks(&self, option: &VanillaOption, spot: f64) -> EuropeanGreeks {
+        let Valuation { npv, status } = self.valuation(option, spot);
+        if status != ValuationStatus::Live {
+            return EuropeanGreeks {
+                npv,
+                delta: 0.0,
+                gamma: 0.0,
+                vega: 0.0,
+                theta: 0.0,
+                rho: 0.0,
+                dividend_rho: 0.0,
+                status,
+            };
+        }
+        let t = self.expiry(option);
+        let w = option.option_type.sign();
+        let k = option.strike;
+        let discount = self.risk_free.discount(option.exercise_date, true);
+        let dividend_discount = self.dividend.discount(option.exercise_date, true);
+        let std_dev = self.volatility.black_variance(t, k).sqrt();
+        if std_dev == 0.0 || k == 0.0 {
+            let forward = spot * dividend_discount / discount;
+            let itm = if w * (forward - k) > 0.0 { 1.0 } else { 0.0 };
+            return EuropeanGreeks {
+                npv,
+                delta: w * itm * dividend_discount,
+                gamma: 0.0,
+                vega: 0.0,
+                theta: 0.0,
+                rho: w * itm * k * t * discount,
+                dividend_rho: -w * itm * spot * t * dividend_discount,
+                status,
+            };
+        }
+        let forward = spot * dividend_discount / discount;
+        let d1 = (forward / k).ln() / std_dev + 0.5 * std_dev;
+        let d2 = d1 - std_dev;
+        let (r, q) = (-discount.ln() / t, -dividend_discount.ln() / t);
+        let sigma = std_dev / t.sqrt();
+
+        let delta = w * dividend_discount * normal_cdf(w * d1);
+        let gamma = dividend_discount * normal_pdf(d1) / (spot * std_dev);
+        let vega = spot * dividend_discount * normal_pdf(d1) * t.sqrt();
+        // the Black-Scholes equation, with the calendar ageing the option
+        let theta = r * npv - (r - q) * spot * delta - 0.5 * sigma * sigma * spot * spot * gamma;
+        EuropeanGreeks {
+            npv,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho: w * k * t * discount * normal_cdf(w * d2),
+            dividend_rho: -w * spot * t * dividend_discount * normal_cdf(w * d1),
+            status,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::instruments::{curve_valuation, EarlyExerciseOption, Valuation};
+use crate::methods::lattices::{BinomialTree, BinomialTreeType};
+use crate::termstructures::traits::{BlackVolTermStructure, YieldTermStructure};
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Binomial tree engine for options with European, American or Bermudan
+/// exercise on a Black-Scholes-Merton spot.
+///
+/// The tree runs at the zero rates of the curves and the Black volatility
+/// at the strike to expiry, times being measured on the risk-free curve.
+/// Bermudan dates exercise on the nearest step, and options expired by
+/// the reference date of the risk-free curve are worth zero.
+pub struct BinomialVanillaEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub risk_free: Rc<R>,
+    pub dividend: Rc<D>,
+    pub volatility: Rc<V>,
+    pub tree_type: BinomialTreeType,
+    pub steps: usize,
+}
+
+impl<R, D, V> BinomialVanillaEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub fn new(
+        risk_free: Rc<R>,
+        dividend: Rc<D>,
+        volatility: Rc<V>,
+        tree_type: BinomialTreeType,
+        steps: usize,
+    ) -> BinomialVanillaEngine<R, D, V> {
+        assert!(steps > 0, "no time steps");
+        BinomialVanillaEngine {
+            risk_free,
+            dividend,
+            volatility,
+            tree_type,
+            steps,
+        }
+    }
+
+    /// The option's tree, with `spot` at its root, none once expired.
+    pub fn tree(&self, option: &EarlyExerciseOption, spot: f64) -> Option<BinomialTree> {
+        let expiry = option.exercise.last_date();
+        let t = self.risk_free.time_from_reference(expiry);
+        if t <= 0.0 {
+            return None;
+        }
+        let r = -self.risk_free.discount(expiry, true).ln() / t;
+        let q = -self.dividend.discount(expiry, true).ln() / t;
+        let sigma = self.volatility.black_vol(t, option.strike);
+        Some(BinomialTree::new(
+            self.tree_type,
+            spot,
+            r - q,
+            sigma,
+            t,
+            self.steps,
+        ))
+    }
+
+    /// The value of `option`, zero once expired.
+    pub fn valuation(&self, option: &EarlyExerciseOption, spot: f64) -> Valuation {
+        let _span = crate::trace_span!(
+            Level::Debug,
+            "vanilla_option.binomial.npv",
+            steps = self.steps
+        );
+        curve_valuation(
+            option,
+            |d| self.risk_free.time_from_reference(d),
+            |o| self.price(o, spot),
+        )
+    }
+
+    pub fn npv(&self, option: &EarlyExerciseOption, spot: f64) -> f64 {
+        self.valuation(option, spot).npv
+    }
+
+    fn price(&self, option: &EarlyExerciseOption, spot: f64) -> f64 {
+        let tree = match self.tree(option, spot) {
+            Some(tree) => tree,
+            None => return 0.0,
+        };
+        let exercisable = option.exercise.exercise_steps(
+            |d| self.risk_free.time_from_reference(d),
+            tree.dt,
+            tree.steps,
+        );
+        let discount = (self
+            .risk_free
+            .discount(option.exercise.last_date(), true)
+            .ln()
+            / tree.steps as f64)
+            .exp();
+        let (p, n) = (tree.probability, tree.steps);
+        let mut values: Vec<f64> = (0..=n)
+            .map(|j| option.payoff(tree.underlying(n, j)))
+            .collect();
+        for i in (0..n).rev() {
+            for j in 0..=i {
+                let continuation = discount * (p * values[j + 1] + (1.0 - p) * values[j]);
+                values[j] = if exercisable[i] {
+                    continuation.max(option.payoff(tree.underlying(i, j)))
+                } else {
+                    continuation
+                };
+            }
+            values.truncate(i + 1);
+        }
+        values[0]
+    }
+}
@@ -0,0 +1,46 @@
+use crate::definitions::DiscountFactor;
+use crate::instruments::OptionType;
+use crate::math::{normal_cdf, normal_pdf};
+
+/// Black (1976) price of an option on a forward, with an optional shift
+/// making the forward displaced-lognormal: `forward + displacement` is
+/// lognormal with total standard deviation `std_dev`.
+pub fn black_formula(
+    option_type: OptionType,
+    strike: f64,
+    forward: f64,
+    std_dev: f64,
+    discount: DiscountFactor,
+    displacement: f64,
+) -> f64 {
+    assert!(std_dev >= 0.0, "negative standard deviation");
+    let (f, k) = (forward + displacement, strike + displacement);
+    assert!(f > 0.0, "displaced forward must be positive");
+    let w = option_type.sign();
+    if k <= 0.0 || std_dev == 0.0 {
+        // intrinsic value: a non-positive displaced strike is always
+        // exercised for calls and never for puts.
+        return (w * (f - k)).max(0.0) * discount;
+    }
+    let d1 = (f / k).ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    discount * w * (f * normal_cdf(w * d1) - k * normal_cdf(w * d2))
+}
+
+/// Bachelier price of an option on a normally distributed forward with
+/// total standard deviation `std_dev`, in price units.
+pub fn bachelier_formula(
+    option_type: OptionType,
+    strike: f64,
+    forward: f64,
+    std_dev: f64,
+    discount: DiscountFactor,
+) -> f64 {
+    assert!(std_dev >= 0.0, "negative standard deviation");
+    let w = option_type.sign();
+    if std_dev == 0.0 {
+        return (w * (forward - strike)).max(0.0) * discount;
+    }
+    let d = (forward - strike) / std_dev;
+    discount * (w * (forward - strike) * normal_cdf(w * d) + std_dev * normal_pdf(d))
+}
@@ -19,69 +19,99 @@ pub fn maturity_date<C: Cal, CF: CashFlow, PE: PricingEngine>(bond: &Bond<C, CF,
 
 pub fn is_tradeable<C: Cal, CF: CashFlow, PE: PricingEngine + Default>(
     bond: &Bond<C, CF, PE>,
-    mut settlement_date: Date,
+    settlement_date: Option<Date>,
 ) -> bool {
-    if settlement_date == Date::default() {
-        settlement_date = bond.settlement_date(None);
-    }
+    let settlement_date = settlement_date.unwrap_or_else(|| bond.settlement_date(None));
     bond.notional(Some(settlement_date)) != 0.0
 }
 
 pub fn yield_with<C: Cal, CF: CashFlow, PE: PricingEngine, DC: DayCounter>(
-    bond: &Bond<C, CF, PE>,
-    clean_price: f64,
-    day_counter: DC,
-    comp: Compounding,
-    freq: Frequency,
-    settlement_date: Date,
-    accuracy: f64,
-    max_evaluations: usize,
+    _bond: &Bond<C, CF, PE>,
+    _clean_price: f64,
+    _day_counter: DC,
+    _comp: Compounding,
+    _freq: Frequency,
+    _settlement_date: Date,
+    _accuracy: f64,
+    _max_evaluations: usize,
 ) -> f64 {
     0.0
 }
 
 pub fn clean_price<C: Cal, CF: CashFlow, PE: PricingEngine, DC: DayCounter>(
-    bond: Bond<C, CF, PE>,
-    y: Rate,
-    day_counter: DC,
-    comp: Compounding,
-    freq: Frequency,
-    settlement: Date,
+    _bond: Bond<C, CF, PE>,
+    _y: Rate,
+    _day_counter: DC,
+    _comp: Compounding,
+    _freq: Frequency,
+    _settlement: Date,
 ) -> f64 {
     0.0
 }
 
-pub fn accrued_amount<C: Cal, CF: CashFlow, PE: PricingEngine>(
+/// Interest accrued at `settlement_date` per 100 of the outstanding
+/// notional.
+pub fn accrued_amount<C: Cal, CF: CashFlow, PE: PricingEngine + Default>(
     bond: &Bond<C, CF, PE>,
     settlement_date: Date,
 ) -> f64 {
-    0.0
+    let accrued: f64 = bond
+        .cashflows
+        .iter()
+        .filter_map(|c| c.try_as_coup())
+        .map(|c| c.accrued_amount(settlement_date))
+        .sum();
+    accrued * 100.0 / bond.notional(Some(settlement_date))
+}
+
+/// Total rate of the coupons paid on `date`.
+fn coupon_rate<C: Cal, CF: CashFlow, PE: PricingEngine>(
+    bond: &Bond<C, CF, PE>,
+    date: Date,
+) -> Rate {
+    bond.cashflows
+        .iter()
+        .filter(|c| c.date() == date)
+        .filter_map(|c| c.try_as_coup())
+        .map(|c| c.rate())
+        .sum()
 }
 
 pub fn next_coupon_rate<C: Cal, CF: CashFlow, PE: PricingEngine>(
     bond: &Bond<C, CF, PE>,
     settlement_date: Date,
 ) -> Rate {
-    0.0
+    next_cashflow_date(bond, settlement_date).map_or(0.0, |d| coupon_rate(bond, d))
 }
 
 pub fn previous_coupon_rate<C: Cal, CF: CashFlow, PE: PricingEngine>(
     bond: &Bond<C, CF, PE>,
     settlement_date: Date,
 ) -> Rate {
-    0.0
+    previous_cashflow_date(bond, settlement_date).map_or(0.0, |d| coupon_rate(bond, d))
 }
 
+/// Payment date of the first flow not yet paid at `settlement_date`, if
+/// any is left.
 pub fn next_cashflow_date<C: Cal, CF: CashFlow, PE: PricingEngine>(
     bond: &Bond<C, CF, PE>,
     settlement_date: Date,
-) -> Date {
-    Date::default()
+) -> Option<Date> {
+    bond.cashflows
+        .iter()
+        .filter(|c| !CashFlow::has_occured(*c, settlement_date, false))
+        .map(|c| c.date())
+        .min()
 }
 
+/// Payment date of the last flow paid by `settlement_date`, if any was.
 pub fn previous_cashflow_date<C: Cal, CF: CashFlow, PE: PricingEngine>(
     bond: &Bond<C, CF, PE>,
     settlement_date: Date,
-) -> Date {
-    Date::default()
+) -> Option<Date> {
+    bond.cashflows
+        .iter()
+        .filter(|c| CashFlow::has_occured(*c, settlement_date, false))
+        .map(|c| c.date())
+        .max()
 }
@@ -2,4 +2,3 @@ pub mod bondfunctions;
 pub mod discountingbondengine;
 
 pub use self::bondfunctions::*;
-pub use self::discountingbondengine::*;
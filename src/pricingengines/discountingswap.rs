@@ -0,0 +1,156 @@
+use crate::cashflows::{self as cf, CashFlow};
+use crate::instruments::nonstandardswap::fair_rate;
+use crate::instruments::{NonstandardSwap, SwapType, VanillaSwap};
+use crate::settings::PricingContext;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+use std::rc::Rc;
+
+const BASIS_POINT: f64 = 1.0e-4;
+
+/// Value of a swap and of its legs; leg values and BPS are positive
+/// whichever side is taken. The fair rate or spread is NaN when its leg has
+/// no flow left after settlement.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SwapResults {
+    pub npv: f64,
+    pub fixed_leg_npv: f64,
+    pub floating_leg_npv: f64,
+    pub fixed_leg_bps: f64,
+    pub floating_leg_bps: f64,
+    pub fair_rate: f64,
+    pub fair_spread: f64,
+    /// The date values are expressed as of.
+    pub npv_date: Date,
+}
+
+/// Prices both legs of a swap off one discounting curve; floating coupons
+/// are projected on the forwarding curves of their indexes, which may
+/// differ from it.
+///
+/// Flows paid on or before the settlement date are left out, and values
+/// are expressed as of the npv date. Both default to the evaluation date
+/// of the current `PricingContext`, which also decides whether flows paid
+/// on the settlement date are still included.
+pub struct DiscountingSwapEngine<Y: YieldTermStructure> {
+    pub discount_curve: Rc<Y>,
+    pub settlement_date: Option<Date>,
+    pub npv_date: Option<Date>,
+    pub include_settlement_date_flows: Option<bool>,
+}
+
+impl<Y: YieldTermStructure> DiscountingSwapEngine<Y> {
+    pub fn new(discount_curve: Rc<Y>) -> DiscountingSwapEngine<Y> {
+        DiscountingSwapEngine {
+            discount_curve,
+            settlement_date: None,
+            npv_date: None,
+            include_settlement_date_flows: None,
+        }
+    }
+
+    pub fn with_settlement_date(mut self, settlement_date: Date) -> DiscountingSwapEngine<Y> {
+        self.settlement_date = Some(settlement_date);
+        self
+    }
+
+    pub fn with_npv_date(mut self, npv_date: Date) -> DiscountingSwapEngine<Y> {
+        self.npv_date = Some(npv_date);
+        self
+    }
+
+    pub fn with_settlement_date_flows(mut self, include: bool) -> DiscountingSwapEngine<Y> {
+        self.include_settlement_date_flows = Some(include);
+        self
+    }
+
+    pub fn calculate<FX: CashFlow, FL: CashFlow>(&self, swap: &VanillaSwap<FX, FL>) -> SwapResults {
+        let mut results = self.leg_results(swap.swap_type, &swap.fixed_leg, &swap.floating_leg);
+        results.fair_rate = if results.fixed_leg_bps != 0.0 {
+            swap.fixed_rate
+                - (results.fixed_leg_npv - results.floating_leg_npv) / results.fixed_leg_bps
+                    * BASIS_POINT
+        } else {
+            f64::NAN
+        };
+        results.fair_spread = fair_spread(swap.spread, &results);
+        results
+    }
+
+    /// Results of a swap with varying rates or notionals, the fair rate
+    /// being the single fixed rate replacing all of its coupon rates.
+    pub fn calculate_nonstandard<FX: CashFlow, FL: CashFlow>(
+        &self,
+        swap: &NonstandardSwap<FX, FL>,
+    ) -> SwapResults {
+        let mut results = self.leg_results(swap.swap_type, &swap.fixed_leg, &swap.floating_leg);
+        let (settlement, include) = self.settlement();
+        let scale = 1.0 / self.discount_curve.discount(results.npv_date, true);
+        results.fair_rate = if results.fixed_leg_bps != 0.0 {
+            fair_rate(
+                &swap.fixed_leg,
+                results.floating_leg_npv / scale,
+                self.discount_curve.as_ref(),
+                include,
+                settlement,
+            )
+        } else {
+            f64::NAN
+        };
+        results.fair_spread = fair_spread(swap.spread, &results);
+        results
+    }
+
+    /// The settlement date and whether its flows are included.
+    fn settlement(&self) -> (Date, bool) {
+        let context = PricingContext::current();
+        let settlement = self.settlement_date.unwrap_or(context.evaluation_date);
+        let include = self
+            .include_settlement_date_flows
+            .unwrap_or(context.include_todays_cashflows);
+        (settlement, include)
+    }
+
+    /// Leg values and BPS, with the fair rate and spread left at zero.
+    fn leg_results<FX: CashFlow, FL: CashFlow>(
+        &self,
+        swap_type: SwapType,
+        fixed_leg: &[FX],
+        floating_leg: &[FL],
+    ) -> SwapResults {
+        let (settlement, include) = self.settlement();
+        let npv_date = self
+            .npv_date
+            .unwrap_or(PricingContext::current().evaluation_date);
+        let curve = self.discount_curve.as_ref();
+        let scale = 1.0 / curve.discount(npv_date, true);
+
+        let fixed_leg_npv = cf::npv(fixed_leg, curve, include, settlement) * scale;
+        let floating_leg_npv = cf::npv(floating_leg, curve, include, settlement) * scale;
+        let fixed_leg_bps = cf::bps(fixed_leg, curve, include, settlement) * scale;
+        let floating_leg_bps = cf::bps(floating_leg, curve, include, settlement) * scale;
+        let sign = match swap_type {
+            SwapType::Payer => 1.0,
+            SwapType::Receiver => -1.0,
+        };
+        SwapResults {
+            npv: sign * (floating_leg_npv - fixed_leg_npv),
+            fixed_leg_npv,
+            floating_leg_npv,
+            fixed_leg_bps,
+            floating_leg_bps,
+            fair_rate: 0.0,
+            fair_spread: 0.0,
+            npv_date,
+        }
+    }
+}
+
+fn fair_spread(spread: f64, results: &SwapResults) -> f64 {
+    if results.floating_leg_bps == 0.0 {
+        return f64::NAN;
+    }
+    spread
+        - (results.floating_leg_npv - results.fixed_leg_npv) / results.floating_leg_bps
+            * BASIS_POINT
+}
@@ -0,0 +1,209 @@
+use crate::instruments::{curve_valuation, EarlyExerciseOption, Exercise, OptionType, Valuation};
+use crate::methods::finitedifferences::{
+    EarlyExerciseMethod, FdScheme, Mesher1D, TridiagonalOperator,
+};
+use crate::termstructures::traits::{BlackVolTermStructure, YieldTermStructure};
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Standard deviations of the log spot to expiry the mesher spans either
+/// side of the spot and strike.
+const MESHER_STD_DEVS: f64 = 5.0;
+
+/// Finite difference engine for options with European, American or
+/// Bermudan exercise on a Black-Scholes-Merton spot, solving the pricing
+/// PDE in the log spot.
+///
+/// As `BinomialVanillaEngine`, it runs at the zero rates of the curves and
+/// the Black volatility at the strike to expiry. The mesher of `x_grid`
+/// locations is uniform unless a `concentration` density is given, which
+/// concentrates it around the strike. Boundaries are set to the
+/// discounted intrinsic value of the forward, or the exercise value if
+/// higher once exercise is possible. American options are kept above
+/// their exercise value by `american_method`, Bermudan ones are exercised
+/// on the nearest of the `t_grid` steps. Options expired by the reference
+/// date of the risk-free curve are worth zero.
+pub struct FdBlackScholesVanillaEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub risk_free: Rc<R>,
+    pub dividend: Rc<D>,
+    pub volatility: Rc<V>,
+    pub x_grid: usize,
+    pub t_grid: usize,
+    pub scheme: FdScheme,
+    pub american_method: EarlyExerciseMethod,
+    pub concentration: Option<f64>,
+    /// Implicit Euler steps taken first to damp the payoff's kink, with
+    /// Crank-Nicolson.
+    pub damping_steps: usize,
+}
+
+impl<R, D, V> FdBlackScholesVanillaEngine<R, D, V>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub fn new(
+        risk_free: Rc<R>,
+        dividend: Rc<D>,
+        volatility: Rc<V>,
+        x_grid: usize,
+        t_grid: usize,
+    ) -> FdBlackScholesVanillaEngine<R, D, V> {
+        assert!(x_grid >= 3, "at least three space locations needed");
+        assert!(t_grid > 0, "no time steps");
+        FdBlackScholesVanillaEngine {
+            risk_free,
+            dividend,
+            volatility,
+            x_grid,
+            t_grid,
+            scheme: FdScheme::CrankNicolson,
+            american_method: EarlyExerciseMethod::Psor { omega: 1.2 },
+            concentration: None,
+            damping_steps: 0,
+        }
+    }
+
+    pub fn with_scheme(mut self, scheme: FdScheme) -> FdBlackScholesVanillaEngine<R, D, V> {
+        self.scheme = scheme;
+        self
+    }
+
+    pub fn with_american_method(
+        mut self,
+        method: EarlyExerciseMethod,
+    ) -> FdBlackScholesVanillaEngine<R, D, V> {
+        self.american_method = method;
+        self
+    }
+
+    pub fn with_concentration(mut self, density: f64) -> FdBlackScholesVanillaEngine<R, D, V> {
+        self.concentration = Some(density);
+        self
+    }
+
+    pub fn with_damping_steps(mut self, steps: usize) -> FdBlackScholesVanillaEngine<R, D, V> {
+        self.damping_steps = steps;
+        self
+    }
+
+    /// The log spot mesher of the option, for `spot` today, none once
+    /// expired.
+    pub fn mesher(&self, option: &EarlyExerciseOption, spot: f64) -> Option<Mesher1D> {
+        let t = self
+            .risk_free
+            .time_from_reference(option.exercise.last_date());
+        if t <= 0.0 {
+            return None;
+        }
+        assert!(
+            spot > 0.0 && option.strike > 0.0,
+            "non positive spot or strike"
+        );
+        let sigma = self.volatility.black_vol(t, option.strike);
+        let width = MESHER_STD_DEVS * sigma * t.sqrt();
+        let (x0, k) = (spot.ln(), option.strike.ln());
+        let (start, end) = (x0.min(k) - width, x0.max(k) + width);
+        Some(match self.concentration {
+            Some(density) => Mesher1D::concentrating(start, end, self.x_grid, k, density),
+            None => Mesher1D::uniform(start, end, self.x_grid),
+        })
+    }
+
+    /// The value of `option`, zero once expired.
+    pub fn valuation(&self, option: &EarlyExerciseOption, spot: f64) -> Valuation {
+        let _span = crate::trace_span!(
+            Level::Debug,
+            "vanilla_option.fd.npv",
+            x_grid = self.x_grid,
+            t_grid = self.t_grid
+        );
+        curve_valuation(
+            option,
+            |d| self.risk_free.time_from_reference(d),
+            |o| self.price(o, spot),
+        )
+    }
+
+    pub fn npv(&self, option: &EarlyExerciseOption, spot: f64) -> f64 {
+        self.valuation(option, spot).npv
+    }
+
+    fn price(&self, option: &EarlyExerciseOption, spot: f64) -> f64 {
+        let mesher = match self.mesher(option, spot) {
+            Some(mesher) => mesher,
+            None => return 0.0,
+        };
+        let expiry = option.exercise.last_date();
+        let t = self.risk_free.time_from_reference(expiry);
+        let r = -self.risk_free.discount(expiry, true).ln() / t;
+        let q = -self.dividend.discount(expiry, true).ln() / t;
+        let sigma = self.volatility.black_vol(t, option.strike);
+
+        // L = sigma^2 / 2 d2/dx2 + (r - q - sigma^2 / 2) d/dx - r on the
+        // inner locations, the boundaries being set apart
+        let n = mesher.size();
+        let mut discounting = TridiagonalOperator::identity(n).scaled(-r);
+        discounting.diagonal[0] = 0.0;
+        discounting.diagonal[n - 1] = 0.0;
+        let op = TridiagonalOperator::second_derivative(&mesher)
+            .scaled(0.5 * sigma * sigma)
+            .plus(
+                &TridiagonalOperator::first_derivative(&mesher).scaled(r - q - 0.5 * sigma * sigma),
+            )
+            .plus(&discounting);
+
+        let spots: Vec<f64> = mesher.locations.iter().map(|x| x.exp()).collect();
+        let exercise_values: Vec<f64> = spots.iter().map(|s| option.payoff(*s)).collect();
+        let dt = t / self.t_grid as f64;
+        let exercisable = option.exercise.exercise_steps(
+            |d| self.risk_free.time_from_reference(d),
+            dt,
+            self.t_grid,
+        );
+        let american = matches!(option.exercise, Exercise::American { .. });
+        let phi = match option.option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+
+        let mut values = exercise_values.clone();
+        for k in 1..=self.t_grid {
+            // the k-th step back ends at the grid time of index `step`
+            let step = self.t_grid - k;
+            let tau = k as f64 * dt;
+            let scheme = if k <= self.damping_steps {
+                FdScheme::ImplicitEuler
+            } else {
+                self.scheme
+            };
+            let bound = if american && exercisable[step] {
+                Some((&exercise_values[..], self.american_method))
+            } else {
+                None
+            };
+            values = scheme.step(&op, &values, dt, bound);
+            if exercisable[step] && !american {
+                for (v, e) in values.iter_mut().zip(&exercise_values) {
+                    *v = v.max(*e);
+                }
+            }
+            for i in &[0, n - 1] {
+                let s = spots[*i];
+                let forward = phi * (s * (-q * tau).exp() - option.strike * (-r * tau).exp());
+                values[*i] = if exercisable[step] {
+                    forward.max(exercise_values[*i])
+                } else {
+                    forward.max(0.0)
+                };
+            }
+        }
+        mesher.interpolate(&values, spot.ln())
+    }
+}
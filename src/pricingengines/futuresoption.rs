@@ -0,0 +1,127 @@
+use super::blackformula::{bachelier_formula, black_formula};
+use crate::definitions::{DiscountFactor, Volatility};
+use crate::instruments::{FuturesOption, PremiumSettlement};
+use crate::math::{normal_cdf, normal_pdf};
+use crate::utils::trace::Level;
+
+/// Discount factor applying to the option value: margined options are not
+/// discounted.
+fn settlement_discount(option: &FuturesOption, discount: DiscountFactor) -> DiscountFactor {
+    match option.settlement {
+        PremiumSettlement::Upfront => discount,
+        PremiumSettlement::Margined => 1.0,
+    }
+}
+
+/// Black (1976) engine with a lognormal, optionally shifted, futures
+/// price; `discount` is the factor from expiry to the premium payment and
+/// only applies to upfront premiums.
+#[derive(Copy, Clone, Debug)]
+pub struct Black76Engine {
+    pub volatility: Volatility,
+    pub displacement: f64,
+}
+
+impl Black76Engine {
+    pub fn new(volatility: Volatility) -> Black76Engine {
+        Black76Engine {
+            volatility,
+            displacement: 0.0,
+        }
+    }
+
+    pub fn with_displacement(mut self, displacement: f64) -> Black76Engine {
+        self.displacement = displacement;
+        self
+    }
+
+    fn d1(&self, option: &FuturesOption, futures_price: f64) -> f64 {
+        let std_dev = self.volatility * option.expiry.sqrt();
+        let f = futures_price + self.displacement;
+        let k = option.strike + self.displacement;
+        (f / k).ln() / std_dev + 0.5 * std_dev
+    }
+
+    pub fn npv(&self, option: &FuturesOption, futures_price: f64, discount: DiscountFactor) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "futures_option.black76.npv");
+        black_formula(
+            option.option_type,
+            option.strike,
+            futures_price,
+            self.volatility * option.expiry.sqrt(),
+            settlement_discount(option, discount),
+            self.displacement,
+        )
+    }
+
+    /// Sensitivity to the futures price.
+    pub fn delta(
+        &self,
+        option: &FuturesOption,
+        futures_price: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        let w = option.option_type.sign();
+        let d1 = self.d1(option, futures_price);
+        settlement_discount(option, discount) * w * normal_cdf(w * d1)
+    }
+
+    /// Sensitivity to the volatility.
+    pub fn vega(
+        &self,
+        option: &FuturesOption,
+        futures_price: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        let d1 = self.d1(option, futures_price);
+        settlement_discount(option, discount)
+            * (futures_price + self.displacement)
+            * normal_pdf(d1)
+            * option.expiry.sqrt()
+    }
+}
+
+/// Bachelier engine with a normal futures price and volatility in price
+/// units, suited to interest rate futures near or below zero rates.
+#[derive(Copy, Clone, Debug)]
+pub struct BachelierEngine {
+    pub volatility: Volatility,
+}
+
+impl BachelierEngine {
+    pub fn new(volatility: Volatility) -> BachelierEngine {
+        BachelierEngine { volatility }
+    }
+
+    pub fn npv(&self, option: &FuturesOption, futures_price: f64, discount: DiscountFactor) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "futures_option.bachelier.npv");
+        bachelier_formula(
+            option.option_type,
+            option.strike,
+            futures_price,
+            self.volatility * option.expiry.sqrt(),
+            settlement_discount(option, discount),
+        )
+    }
+
+    pub fn delta(
+        &self,
+        option: &FuturesOption,
+        futures_price: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        let w = option.option_type.sign();
+        let d = (futures_price - option.strike) / (self.volatility * option.expiry.sqrt());
+        settlement_discount(option, discount) * w * normal_cdf(w * d)
+    }
+
+    pub fn vega(
+        &self,
+        option: &FuturesOption,
+        futures_price: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        let d = (futures_price - option.strike) / (self.volatility * option.expiry.sqrt());
+        settlement_discount(option, discount) * normal_pdf(d) * option.expiry.sqrt()
+    }
+}
@@ -0,0 +1,178 @@
+use super::blackformula::{bachelier_formula, black_formula};
+use crate::definitions::{DiscountFactor, Time};
+use crate::instruments::{InflationSpreadOption, ZeroCouponInflationCapFloor};
+use crate::quotes::{Quote, SimpleQuote};
+use crate::termstructures::traits::{BlackVolTermStructure, YieldTermStructure};
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Forecast index ratio, time to the fixing on `discount` and discount
+/// factor to maturity of `option`.
+fn terms<Y: YieldTermStructure>(
+    option: &ZeroCouponInflationCapFloor,
+    discount: &Y,
+) -> (f64, Time, DiscountFactor) {
+    let t = discount.time_from_reference(option.fixing_date()).max(0.0);
+    (
+        option.index_ratio().unwrap_or_else(|e| panic!("{}", e)),
+        t,
+        discount.discount(option.maturity, true),
+    )
+}
+
+/// Black engine for zero coupon inflation caps and floors, the index ratio
+/// being lognormal with the volatility of `volatility` at the option's
+/// zero coupon strike and the time to its fixing.
+pub struct BlackZeroInflationCapFloorEngine<Y, V>
+where
+    Y: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub discount_curve: Rc<Y>,
+    pub volatility: Rc<V>,
+}
+
+impl<Y, V> BlackZeroInflationCapFloorEngine<Y, V>
+where
+    Y: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub fn new(discount_curve: Rc<Y>, volatility: Rc<V>) -> BlackZeroInflationCapFloorEngine<Y, V> {
+        BlackZeroInflationCapFloorEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    pub fn npv(&self, option: &ZeroCouponInflationCapFloor) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "inflation_cap_floor.black.npv");
+        let (forward, t, discount) = terms(option, self.discount_curve.as_ref());
+        let std_dev = self.volatility.black_variance(t, option.strike).sqrt();
+        option.nominal
+            * black_formula(
+                option.option_type,
+                option.strike_ratio(),
+                forward,
+                std_dev,
+                discount,
+                0.0,
+            )
+    }
+}
+
+/// Bachelier engine for zero coupon inflation caps and floors, the index
+/// ratio being normal with the volatility of `volatility`, in index ratio
+/// units, at the option's zero coupon strike and the time to its fixing.
+pub struct BachelierZeroInflationCapFloorEngine<Y, V>
+where
+    Y: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub discount_curve: Rc<Y>,
+    pub volatility: Rc<V>,
+}
+
+impl<Y, V> BachelierZeroInflationCapFloorEngine<Y, V>
+where
+    Y: YieldTermStructure,
+    V: BlackVolTermStructure,
+{
+    pub fn new(
+        discount_curve: Rc<Y>,
+        volatility: Rc<V>,
+    ) -> BachelierZeroInflationCapFloorEngine<Y, V> {
+        BachelierZeroInflationCapFloorEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    pub fn npv(&self, option: &ZeroCouponInflationCapFloor) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "inflation_cap_floor.bachelier.npv");
+        let (forward, t, discount) = terms(option, self.discount_curve.as_ref());
+        let std_dev = self.volatility.black_variance(t, option.strike).sqrt();
+        option.nominal
+            * bachelier_formula(
+                option.option_type,
+                option.strike_ratio(),
+                forward,
+                std_dev,
+                discount,
+            )
+    }
+}
+
+/// Kirk's approximation for inflation spread options: the second index
+/// ratio plus the strike is taken as lognormal, so that the option is a
+/// Black option on the ratio of the two. Each index ratio has the
+/// lognormal volatility of its surface at its forward zero coupon rate,
+/// and the two the correlation `correlation`.
+pub struct KirkInflationSpreadEngine<Y, V1, V2, Q = SimpleQuote>
+where
+    Y: YieldTermStructure,
+    V1: BlackVolTermStructure,
+    V2: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub discount_curve: Rc<Y>,
+    pub first_volatility: Rc<V1>,
+    pub second_volatility: Rc<V2>,
+    pub correlation: Q,
+}
+
+impl<Y, V1, V2, Q> KirkInflationSpreadEngine<Y, V1, V2, Q>
+where
+    Y: YieldTermStructure,
+    V1: BlackVolTermStructure,
+    V2: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub fn new(
+        discount_curve: Rc<Y>,
+        first_volatility: Rc<V1>,
+        second_volatility: Rc<V2>,
+        correlation: Q,
+    ) -> KirkInflationSpreadEngine<Y, V1, V2, Q> {
+        KirkInflationSpreadEngine {
+            discount_curve,
+            first_volatility,
+            second_volatility,
+            correlation,
+        }
+    }
+
+    pub fn npv(&self, option: &InflationSpreadOption) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "inflation_spread_option.kirk.npv");
+        let rho = self.correlation.value();
+        assert!((-1.0..=1.0).contains(&rho), "correlation out of [-1, 1]");
+        let t = self
+            .discount_curve
+            .time_from_reference(option.fixing_date())
+            .max(0.0);
+        let discount = self.discount_curve.discount(option.maturity, true);
+        let (f1, f2) = option.index_ratios().unwrap_or_else(|e| panic!("{}", e));
+        let shifted = f2 + option.strike;
+        assert!(
+            shifted > 0.0,
+            "second index ratio plus strike must be positive"
+        );
+        // the volatilities are read at the forward zero coupon rates
+        let years = option.years();
+        let atm = |f: f64| f.powf(1.0 / years) - 1.0;
+        let (s1, s2) = (
+            self.first_volatility.black_vol(t, atm(f1)),
+            self.second_volatility.black_vol(t, atm(f2)),
+        );
+        let w = f2 / shifted;
+        let sigma = (s1 * s1 - 2.0 * rho * s1 * s2 * w + s2 * s2 * w * w).sqrt();
+        option.nominal
+            * black_formula(
+                option.option_type,
+                shifted,
+                f1,
+                sigma * t.sqrt(),
+                discount,
+                0.0,
+            )
+    }
+}
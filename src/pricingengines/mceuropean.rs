@@ -0,0 +1,158 @@
+use crate::definitions::Time;
+use crate::instruments::{curve_valuation, OptionType, Valuation, VanillaOption};
+use crate::methods::montecarlo::{
+    MonteCarloModel, MonteCarloResult, ParallelMonteCarlo, Path, PathGenerator, PathPricer,
+};
+use crate::processes::StochasticProcess1D;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Discounted payoff of a European option on the last value of a path.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EuropeanPathPricer {
+    pub option_type: OptionType,
+    pub strike: f64,
+    pub discount: f64,
+}
+
+impl PathPricer for EuropeanPathPricer {
+    fn price(&self, path: &Path) -> f64 {
+        let payoff = (self.option_type.sign() * (path.back() - self.strike)).max(0.0);
+        self.discount * payoff
+    }
+}
+
+/// Monte Carlo engine for European options on the spot simulated by
+/// `process`, discounted on `risk_free`, on which times are measured.
+/// Options expired by its reference date are worth zero, without error.
+pub struct McEuropeanEngine<P: StochasticProcess1D, R: YieldTermStructure> {
+    pub process: Rc<P>,
+    pub risk_free: Rc<R>,
+    pub time_steps: usize,
+    pub samples: usize,
+    pub seed: u64,
+    pub antithetic: bool,
+    /// Error estimate to reach and the most samples to take doing so,
+    /// `samples` being the least.
+    pub tolerance: Option<(f64, usize)>,
+}
+
+impl<P: StochasticProcess1D, R: YieldTermStructure> McEuropeanEngine<P, R> {
+    pub fn new(
+        process: Rc<P>,
+        risk_free: Rc<R>,
+        time_steps: usize,
+        samples: usize,
+        seed: u64,
+    ) -> McEuropeanEngine<P, R> {
+        assert!(samples > 1, "not enough samples");
+        McEuropeanEngine {
+            process,
+            risk_free,
+            time_steps,
+            samples,
+            seed,
+            antithetic: false,
+            tolerance: None,
+        }
+    }
+
+    pub fn with_antithetic(mut self) -> McEuropeanEngine<P, R> {
+        self.antithetic = true;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64, max_samples: usize) -> McEuropeanEngine<P, R> {
+        self.tolerance = Some((tolerance, max_samples));
+        self
+    }
+
+    /// Time to expiry and the path pricer of `option`, which is live.
+    fn path_pricer(&self, option: &VanillaOption) -> (Time, EuropeanPathPricer) {
+        let t = self.risk_free.time_from_reference(option.exercise_date);
+        let pricer = EuropeanPathPricer {
+            option_type: option.option_type,
+            strike: option.strike,
+            discount: self.risk_free.discount(option.exercise_date, true),
+        };
+        (t, pricer)
+    }
+
+    /// Values `option` with `simulate` unless expired.
+    fn valued<F>(&self, option: &VanillaOption, simulate: F) -> (Valuation, MonteCarloResult)
+    where
+        F: FnOnce(&VanillaOption) -> MonteCarloResult,
+    {
+        let mut result = MonteCarloResult {
+            value: 0.0,
+            error_estimate: 0.0,
+        };
+        let valuation = curve_valuation(
+            option,
+            |d| self.risk_free.time_from_reference(d),
+            |o| {
+                result = simulate(o);
+                result.value
+            },
+        );
+        (valuation, result)
+    }
+
+    /// The value of `option`, zero once expired, with its error estimate.
+    pub fn valuation(&self, option: &VanillaOption) -> (Valuation, MonteCarloResult) {
+        let _span = crate::trace_span!(
+            Level::Info,
+            "vanilla_option.mc_european.npv",
+            samples = self.samples
+        );
+        self.valued(option, |o| self.simulate(o))
+    }
+
+    pub fn npv(&self, option: &VanillaOption) -> MonteCarloResult {
+        self.valuation(option).1
+    }
+
+    fn simulate(&self, option: &VanillaOption) -> MonteCarloResult {
+        let (t, pricer) = self.path_pricer(option);
+        let generator = PathGenerator::uniform(Rc::clone(&self.process), t, self.time_steps);
+        let mut model = MonteCarloModel::new(generator, pricer, self.seed);
+        if self.antithetic {
+            model = model.with_antithetic();
+        }
+        match self.tolerance {
+            Some((tolerance, max_samples)) => {
+                model.value_with_tolerance(tolerance, self.samples, max_samples)
+            }
+            None => {
+                model.add_samples(self.samples);
+                model.result()
+            }
+        }
+    }
+
+    /// `npv` with the samples simulated in the batches of `parallel`; the
+    /// tolerance, if any, is not used.
+    pub fn npv_parallel(
+        &self,
+        option: &VanillaOption,
+        parallel: &ParallelMonteCarlo,
+    ) -> MonteCarloResult
+    where
+        P: Clone + Sync,
+    {
+        let simulate = |o: &VanillaOption| {
+            let (t, pricer) = self.path_pricer(o);
+            let times = PathGenerator::uniform(Rc::clone(&self.process), t, self.time_steps).times;
+            parallel.result(
+                self.process.as_ref(),
+                &times,
+                &pricer,
+                self.samples,
+                self.seed,
+                self.antithetic,
+            )
+        };
+        self.valued(option, simulate).1
+    }
+}
@@ -0,0 +1,159 @@
+use crate::cashflows::{CashFlow, LpiCashFlow};
+use crate::definitions::Volatility;
+use crate::indexes::{Index, ZeroInflationIndex};
+use crate::instruments::LpiSwap;
+use crate::math::{BoxMullerGaussianRng, RandomSequenceGenerator, Rsg, SplitMix64};
+use crate::methods::montecarlo::{MonteCarloResult, Statistics};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::Date;
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Monte Carlo engine for LPI legs and swaps under a lognormal price index
+/// with constant volatility `volatility`: each unpublished fixing is the
+/// previous one grown by its forecast ratio, with a lognormal shock of the
+/// variance accrued between the two, so that the forecast fixings are the
+/// expected ones. Times are measured on the discount curve.
+pub struct McLpiEngine<Y: YieldTermStructure> {
+    pub discount_curve: Rc<Y>,
+    pub volatility: Volatility,
+    pub samples: usize,
+    pub seed: u64,
+    /// Whether paths are drawn in antithetic pairs, counting as one
+    /// sample.
+    pub antithetic: bool,
+}
+
+impl<Y: YieldTermStructure> McLpiEngine<Y> {
+    pub fn new(
+        discount_curve: Rc<Y>,
+        volatility: Volatility,
+        samples: usize,
+        seed: u64,
+    ) -> McLpiEngine<Y> {
+        assert!(volatility >= 0.0, "negative volatility");
+        assert!(samples > 1, "at least two samples needed");
+        McLpiEngine {
+            discount_curve,
+            volatility,
+            samples,
+            seed,
+            antithetic: false,
+        }
+    }
+
+    pub fn with_antithetic(mut self) -> McLpiEngine<Y> {
+        self.antithetic = true;
+        self
+    }
+
+    /// Value of the amounts of `leg` paid after `settlement`, all indexed
+    /// to the same index.
+    pub fn leg_npv(&self, leg: &[LpiCashFlow], settlement: Date) -> MonteCarloResult {
+        let _span = crate::trace_span!(Level::Debug, "lpi_leg.mc.npv", samples = self.samples);
+        let flows: Vec<&LpiCashFlow> = leg
+            .iter()
+            .filter(|c| !CashFlow::has_occured(*c, settlement, false))
+            .collect();
+        if flows.is_empty() {
+            return MonteCarloResult {
+                value: 0.0,
+                error_estimate: 0.0,
+            };
+        }
+        let index = &flows[0].index;
+        assert!(
+            flows.iter().all(|c| Rc::ptr_eq(&c.index, index)),
+            "flows indexed to different indexes"
+        );
+
+        // the fixings of all flows, forecast, and the shock of each
+        let mut dates: Vec<Date> = flows
+            .iter()
+            .flat_map(|c| c.fixing_dates.iter().cloned())
+            .collect();
+        dates.sort();
+        dates.dedup();
+        let forecasts: Vec<f64> = dates
+            .iter()
+            .map(|d| index.forecast_fixing(*d))
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|e| panic!("{}", e));
+        let published: Vec<bool> = dates
+            .iter()
+            .map(|d| {
+                index
+                    .stored_fixing(ZeroInflationIndex::reference_month(*d))
+                    .is_some()
+            })
+            .collect();
+        let times: Vec<f64> = dates
+            .iter()
+            .map(|d| self.discount_curve.time_from_reference(*d).max(0.0))
+            .collect();
+        let positions: Vec<Vec<usize>> = flows
+            .iter()
+            .map(|c| {
+                c.fixing_dates
+                    .iter()
+                    .map(|d| dates.binary_search(d).unwrap())
+                    .collect()
+            })
+            .collect();
+        let discounts: Vec<f64> = flows
+            .iter()
+            .map(|c| self.discount_curve.discount(c.payment_date, true))
+            .collect();
+
+        let value = |normals: &[f64]| -> f64 {
+            let mut fixings = forecasts.clone();
+            for j in 1..dates.len() {
+                if published[j] {
+                    continue;
+                }
+                let variance = self.volatility * self.volatility * (times[j] - times[j - 1]);
+                let shock = (variance.sqrt() * normals[j] - 0.5 * variance).exp();
+                fixings[j] = fixings[j - 1] * forecasts[j] / forecasts[j - 1] * shock;
+            }
+            flows
+                .iter()
+                .zip(&positions)
+                .zip(&discounts)
+                .map(|((c, p), d)| {
+                    let path: Vec<f64> = p.iter().map(|j| fixings[*j]).collect();
+                    c.nominal * c.lpi_ratio(&path) * d
+                })
+                .sum()
+        };
+
+        let mut rsg = RandomSequenceGenerator::new(
+            BoxMullerGaussianRng::new(SplitMix64::new(self.seed)),
+            dates.len(),
+        );
+        let mut statistics = Statistics::default();
+        for _ in 0..self.samples {
+            let normals = rsg.next_sequence();
+            let sample = if self.antithetic {
+                let reflected: Vec<f64> = normals.iter().map(|z| -z).collect();
+                0.5 * (value(&normals) + value(&reflected))
+            } else {
+                value(&normals)
+            };
+            statistics.add(sample);
+        }
+        MonteCarloResult {
+            value: statistics.mean(),
+            error_estimate: statistics.error_estimate(),
+        }
+    }
+
+    /// Value of `swap`, the fixed leg being discounted exactly.
+    pub fn npv<CF: CashFlow>(&self, swap: &LpiSwap<CF>, settlement: Date) -> MonteCarloResult {
+        let lpi = self.leg_npv(&swap.lpi_leg, settlement);
+        let fixed = swap.fixed_leg_npv(self.discount_curve.as_ref(), settlement);
+        MonteCarloResult {
+            value: swap.sign() * (lpi.value - fixed),
+            error_estimate: lpi.error_estimate,
+        }
+    }
+}
@@ -1,5 +1,32 @@
+pub mod analyticeuropean;
+pub mod binomialvanilla;
+pub mod blackformula;
 pub mod bond;
+pub mod discountingswap;
+pub mod fdblackscholesvanilla;
+pub mod futuresoption;
+pub mod inflationcapfloor;
+pub mod mceuropean;
+pub mod mclpi;
+pub mod oas;
+pub mod performance;
 pub mod traits;
+pub mod volatility;
 
+pub use self::analyticeuropean::{AnalyticEuropeanEngine, EuropeanGreeks};
+pub use self::binomialvanilla::BinomialVanillaEngine;
+pub use self::blackformula::{bachelier_formula, black_formula};
 pub use self::bond::*;
+pub use self::discountingswap::{DiscountingSwapEngine, SwapResults};
+pub use self::fdblackscholesvanilla::FdBlackScholesVanillaEngine;
+pub use self::futuresoption::{BachelierEngine, Black76Engine};
+pub use self::inflationcapfloor::{
+    BachelierZeroInflationCapFloorEngine, BlackZeroInflationCapFloorEngine,
+    KirkInflationSpreadEngine,
+};
+pub use self::mceuropean::{EuropeanPathPricer, McEuropeanEngine};
+pub use self::mclpi::McLpiEngine;
+pub use self::oas::option_adjusted_spread;
+pub use self::performance::{PerformanceCounters, Stopwatch};
 pub use self::traits::*;
+pub use self::volatility::{DisplacedLognormalEngine, HestonVariance};
@@ -0,0 +1,41 @@
+use crate::definitions::Rate;
+use crate::math::{Brent, Solver1D};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::ZeroSpreadedTermStructure;
+use std::cell::RefCell;
+
+/// Spread accuracy the solver stops at.
+const ACCURACY: f64 = 1.0e-10;
+const MAX_ITERATIONS: usize = 200;
+
+/// Solves for the option adjusted spread: the zero spread over `curve`
+/// at which `pricer` returns `target_price`.
+///
+/// `pricer` is any valuation consuming the discount curve, so that the
+/// model, e.g. a short rate tree for a callable bond or a prepayment
+/// model for an MBS, is left to it; with a plain discounting pricer the
+/// result is the Z-spread. Prices must fall as the spread rises. On
+/// return `curve.spread` is the solution.
+pub fn option_adjusted_spread<Y, F>(
+    curve: &mut ZeroSpreadedTermStructure<Y>,
+    target_price: f64,
+    pricer: F,
+) -> Rate
+where
+    Y: YieldTermStructure,
+    F: FnMut(&ZeroSpreadedTermStructure<Y>) -> f64,
+{
+    let state = RefCell::new((curve, pricer));
+    let error = |spread: Rate| {
+        let (curve, pricer) = &mut *state.borrow_mut();
+        curve.spread = spread;
+        pricer(curve) - target_price
+    };
+    // bracketed widening from a 100bp range around zero
+    let spread = Brent::new(ACCURACY)
+        .with_max_iterations(MAX_ITERATIONS)
+        .checked_solve_from_guess(error, 0.0, 0.01)
+        .unwrap_or_else(|e| panic!("no spread reprices the target: {}", e));
+    error(spread);
+    spread
+}
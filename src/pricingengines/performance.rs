@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+/// Work counters an engine fills while calculating, for profiling.
+///
+/// Counts are always kept; wall-clock timing is only recorded with the
+/// `perfcounters` feature, as reading the clock is not free and not
+/// available on every target.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PerformanceCounters {
+    /// Function, payoff or regression evaluations.
+    pub evaluations: u64,
+    /// Iterations spent in root finders and optimizers.
+    pub solver_iterations: u64,
+    /// Simulated Monte Carlo paths.
+    pub paths: u64,
+    pub elapsed: Option<Duration>,
+}
+
+impl PerformanceCounters {
+    pub fn reset(&mut self) {
+        *self = PerformanceCounters::default();
+    }
+
+    /// Adds the work of another calculation.
+    pub fn merge(&mut self, other: &PerformanceCounters) {
+        self.evaluations += other.evaluations;
+        self.solver_iterations += other.solver_iterations;
+        self.paths += other.paths;
+        self.elapsed = match (self.elapsed, other.elapsed) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Simulation throughput, when timing was recorded.
+    pub fn paths_per_second(&self) -> Option<f64> {
+        self.elapsed
+            .filter(|e| e.as_secs_f64() > 0.0)
+            .map(|e| self.paths as f64 / e.as_secs_f64())
+    }
+}
+
+/// Measures the elapsed time of a calculation when the `perfcounters`
+/// feature is enabled, and does nothing otherwise.
+pub struct Stopwatch {
+    #[cfg(feature = "perfcounters")]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Stopwatch {
+        Stopwatch {
+            #[cfg(feature = "perfcounters")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Option<Duration> {
+        #[cfg(feature = "perfcounters")]
+        {
+            Some(self.start.elapsed())
+        }
+        #[cfg(not(feature = "perfcounters"))]
+        {
+            None
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::PerformanceCounters;
 use crate::definitions::Money;
 use crate::time::Date;
 use std::collections::HashMap;
@@ -26,15 +27,17 @@ pub trait Results {
 pub struct BaseResults {
     pub value: Money,
     pub error_estimate: Money,
-    pub valuation_date: Date,
+    pub valuation_date: Option<Date>,
     pub additional_results: HashMap<String, Money>,
+    pub performance: PerformanceCounters,
 }
 impl Results for BaseResults {
     fn reset(&mut self) {
-        self.valuation_date = Date::default();
+        self.valuation_date = None;
         self.value = Money::default();
         self.error_estimate = Money::default();
         self.additional_results.clear();
+        self.performance.reset();
     }
     fn get(&self) -> &BaseResults {
         self
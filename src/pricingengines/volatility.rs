@@ -0,0 +1,163 @@
+use super::black_formula;
+use crate::definitions::{DiscountFactor, Time, Volatility};
+use crate::instruments::{OptionType, VolatilityIndexFuture, VolatilityIndexOption};
+use crate::termstructures::traits::BlackVolTermStructure;
+use crate::utils::trace::Level;
+
+/// Width of the replication strike range in ATM standard deviations.
+const REPLICATION_WIDTH: f64 = 10.0;
+
+/// Fair annualized variance of a variance swap expiring at `t`, replicated
+/// from out-of-the-money options on the smile (Demeterfi, Derman, Kamal and
+/// Zou 1999) with `points` strikes evenly spaced in log-moneyness.
+pub fn variance_swap_strike<V: BlackVolTermStructure>(
+    vol: &V,
+    forward: f64,
+    t: Time,
+    points: usize,
+) -> f64 {
+    assert!(t > 0.0, "non positive expiry");
+    assert!(points > 2, "too few replication points");
+    let width = REPLICATION_WIDTH * vol.black_vol(t, forward) * t.sqrt();
+    let dx = 2.0 * width / (points - 1) as f64;
+    let mut integral = 0.0;
+    for i in 0..points {
+        let x = -width + i as f64 * dx;
+        let strike = forward * x.exp();
+        let option_type = if x < 0.0 {
+            OptionType::Put
+        } else {
+            OptionType::Call
+        };
+        let std_dev = vol.black_variance(t, strike).sqrt();
+        let price = black_formula(option_type, strike, forward, std_dev, 1.0, 0.0);
+        let weight = if i == 0 || i == points - 1 { 0.5 } else { 1.0 };
+        integral += weight * price / strike * dx;
+    }
+    2.0 * integral / t
+}
+
+/// Fair annualized variance between `t1` and `t2` implied by the variance
+/// swap strikes at both expiries.
+pub fn forward_variance<V: BlackVolTermStructure>(
+    vol: &V,
+    forward1: f64,
+    t1: Time,
+    forward2: f64,
+    t2: Time,
+    points: usize,
+) -> f64 {
+    assert!(t2 > t1, "t2 must be later than t1");
+    let v2 = variance_swap_strike(vol, forward2, t2, points) * t2;
+    if t1 <= 0.0 {
+        return v2 / t2;
+    }
+    let v1 = variance_swap_strike(vol, forward1, t1, points) * t1;
+    (v2 - v1) / (t2 - t1)
+}
+
+/// Volatility index futures price from the forward smile: the expected
+/// squared index is the forward variance over the index tenor, and its
+/// variance, which the smile does not determine, is given.
+pub fn smile_future_price<V: BlackVolTermStructure>(
+    future: &VolatilityIndexFuture,
+    vol: &V,
+    forward_at_expiry: f64,
+    forward_at_end: f64,
+    variance_of_variance: f64,
+    points: usize,
+) -> Volatility {
+    let mean = forward_variance(
+        vol,
+        forward_at_expiry,
+        future.expiry,
+        forward_at_end,
+        future.expiry + future.tenor,
+        points,
+    );
+    VolatilityIndexFuture::fair_value(mean, variance_of_variance)
+}
+
+/// Variance dynamics of the Heston model,
+/// `dv = kappa (theta - v) dt + sigma sqrt(v) dW`.
+#[derive(Copy, Clone, Debug)]
+pub struct HestonVariance {
+    pub v0: f64,
+    pub kappa: f64,
+    pub theta: f64,
+    pub sigma: f64,
+}
+
+impl HestonVariance {
+    pub fn new(v0: f64, kappa: f64, theta: f64, sigma: f64) -> HestonVariance {
+        assert!(
+            v0 >= 0.0 && theta >= 0.0 && sigma >= 0.0,
+            "negative parameter"
+        );
+        assert!(kappa > 0.0, "non positive mean reversion");
+        HestonVariance {
+            v0,
+            kappa,
+            theta,
+            sigma,
+        }
+    }
+
+    /// Mean and variance of the instantaneous variance at `t`.
+    pub fn variance_moments(&self, t: Time) -> (f64, f64) {
+        let e = (-self.kappa * t).exp();
+        let mean = self.theta + (self.v0 - self.theta) * e;
+        let var = self.v0 * self.sigma * self.sigma / self.kappa * (e - e * e)
+            + self.theta * self.sigma * self.sigma / (2.0 * self.kappa) * (1.0 - e) * (1.0 - e);
+        (mean, var)
+    }
+
+    /// Mean and variance of the squared volatility index fixed at `expiry`
+    /// over `tenor`, which is affine in the variance at expiry.
+    pub fn index_squared_moments(&self, expiry: Time, tenor: Time) -> (f64, f64) {
+        let kt = self.kappa * tenor;
+        let a = (1.0 - (-kt).exp()) / kt;
+        let b = self.theta * (1.0 - a);
+        let (mean, var) = self.variance_moments(expiry);
+        (a * mean + b, a * a * var)
+    }
+
+    pub fn future_price(&self, future: &VolatilityIndexFuture) -> Volatility {
+        let (mean, var) = self.index_squared_moments(future.expiry, future.tenor);
+        VolatilityIndexFuture::fair_value(mean, var)
+    }
+}
+
+/// Prices volatility index options as Black options on the displaced
+/// futures price `future + displacement`.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplacedLognormalEngine {
+    pub volatility: Volatility,
+    pub displacement: f64,
+}
+
+impl DisplacedLognormalEngine {
+    pub fn new(volatility: Volatility, displacement: f64) -> DisplacedLognormalEngine {
+        DisplacedLognormalEngine {
+            volatility,
+            displacement,
+        }
+    }
+
+    pub fn npv(
+        &self,
+        option: &VolatilityIndexOption,
+        futures_price: f64,
+        discount: DiscountFactor,
+    ) -> f64 {
+        let _span = crate::trace_span!(Level::Debug, "volatility_index_option.npv");
+        black_formula(
+            option.option_type,
+            option.strike,
+            futures_price,
+            self.volatility * option.expiry.sqrt(),
+            discount,
+            self.displacement,
+        )
+    }
+}
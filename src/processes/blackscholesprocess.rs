@@ -0,0 +1,105 @@
+use super::StochasticProcess1D;
+use crate::definitions::{Rate, Time};
+use crate::quotes::{Quote, SimpleQuote};
+use crate::termstructures::traits::{BlackVolTermStructure, YieldTermStructure};
+use std::rc::Rc;
+
+/// Step of the finite differences giving instantaneous rates and
+/// variances from the curves.
+const DT: Time = 1.0e-4;
+
+/// Black-Scholes-Merton process: the spot grows at the risk-free minus
+/// the dividend yield with the Black volatility of `volatility`, looked up
+/// at the current spot.
+///
+/// Drift and diffusion are those of the log spot, the increments being
+/// applied multiplicatively. Times are measured on the risk-free curve and
+/// the spot read from its quote on each call.
+pub struct BlackScholesMertonProcess<R, D, V, Q = SimpleQuote>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub spot: Q,
+    pub risk_free: Rc<R>,
+    pub dividend: Rc<D>,
+    pub volatility: Rc<V>,
+}
+
+impl<R, D, V, Q> BlackScholesMertonProcess<R, D, V, Q>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+    Q: Quote,
+{
+    pub fn new(
+        spot: Q,
+        risk_free: Rc<R>,
+        dividend: Rc<D>,
+        volatility: Rc<V>,
+    ) -> BlackScholesMertonProcess<R, D, V, Q> {
+        BlackScholesMertonProcess {
+            spot,
+            risk_free,
+            dividend,
+            volatility,
+        }
+    }
+
+    /// Continuously compounded forward rate of `curve` from `t0` to
+    /// `t0 + dt`.
+    fn forward_rate<Y: YieldTermStructure>(curve: &Y, t0: Time, dt: Time) -> Rate {
+        assert!(dt > 0.0, "non positive time step");
+        let d0 = curve.discount_with_time(t0, true);
+        let d1 = curve.discount_with_time(t0 + dt, true);
+        (d0 / d1).ln() / dt
+    }
+
+    /// Carry `r - q` from `t0` to `t0 + dt`.
+    fn carry(&self, t0: Time, dt: Time) -> Rate {
+        Self::forward_rate(self.risk_free.as_ref(), t0, dt)
+            - Self::forward_rate(self.dividend.as_ref(), t0, dt)
+    }
+
+    /// Black variance from `t0` to `t0 + dt` at the strike `x`.
+    fn forward_variance(&self, t0: Time, x: f64, dt: Time) -> f64 {
+        self.volatility.black_forward_variance(t0, t0 + dt, x)
+    }
+}
+
+impl<R, D, V, Q> StochasticProcess1D for BlackScholesMertonProcess<R, D, V, Q>
+where
+    R: YieldTermStructure,
+    D: YieldTermStructure,
+    V: BlackVolTermStructure,
+    Q: Quote,
+{
+    fn x0(&self) -> f64 {
+        self.spot.value()
+    }
+    fn drift(&self, t: Time, x: f64) -> f64 {
+        self.carry(t, DT) - 0.5 * self.forward_variance(t, x, DT) / DT
+    }
+    fn diffusion(&self, t: Time, x: f64) -> f64 {
+        (self.forward_variance(t, x, DT) / DT).sqrt()
+    }
+    /// The forward of the spot `x0` at `t0` to `t0 + dt`.
+    fn expectation(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        x0 * (self.carry(t0, dt) * dt).exp()
+    }
+    /// Standard deviation of the log spot increment.
+    fn std_deviation(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        self.forward_variance(t0, x0, dt).sqrt()
+    }
+    fn evolve(&self, t0: Time, x0: f64, dt: Time, dw: f64) -> f64 {
+        let variance = self.forward_variance(t0, x0, dt);
+        let dx = self.carry(t0, dt) * dt - 0.5 * variance + variance.sqrt() * dw;
+        self.apply(x0, dx)
+    }
+    fn apply(&self, x0: f64, dx: f64) -> f64 {
+        x0 * dx.exp()
+    }
+}
@@ -0,0 +1,45 @@
+use super::StochasticProcess1D;
+use crate::definitions::{Time, Volatility};
+
+/// Geometric Brownian motion `dS = mu S dt + sigma S dW`, evolved by
+/// its exact lognormal law.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GeometricBrownianMotionProcess {
+    pub initial_value: f64,
+    pub mu: f64,
+    pub sigma: Volatility,
+}
+
+impl GeometricBrownianMotionProcess {
+    pub fn new(initial_value: f64, mu: f64, sigma: Volatility) -> GeometricBrownianMotionProcess {
+        assert!(initial_value > 0.0, "non positive initial value");
+        assert!(sigma >= 0.0, "negative volatility");
+        GeometricBrownianMotionProcess {
+            initial_value,
+            mu,
+            sigma,
+        }
+    }
+}
+
+impl StochasticProcess1D for GeometricBrownianMotionProcess {
+    fn x0(&self) -> f64 {
+        self.initial_value
+    }
+    fn drift(&self, _t: Time, x: f64) -> f64 {
+        self.mu * x
+    }
+    fn diffusion(&self, _t: Time, x: f64) -> f64 {
+        self.sigma * x
+    }
+    fn expectation(&self, _t0: Time, x0: f64, dt: Time) -> f64 {
+        x0 * (self.mu * dt).exp()
+    }
+    fn std_deviation(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        self.expectation(t0, x0, dt) * ((self.sigma * self.sigma * dt).exp() - 1.0).sqrt()
+    }
+    fn evolve(&self, _t0: Time, x0: f64, dt: Time, dw: f64) -> f64 {
+        let sigma = self.sigma;
+        x0 * ((self.mu - 0.5 * sigma * sigma) * dt + sigma * dt.sqrt() * dw).exp()
+    }
+}
@@ -0,0 +1,7 @@
+pub mod blackscholesprocess;
+pub mod geometricbrownianprocess;
+pub mod stochasticprocess;
+
+pub use self::blackscholesprocess::BlackScholesMertonProcess;
+pub use self::geometricbrownianprocess::GeometricBrownianMotionProcess;
+pub use self::stochasticprocess::StochasticProcess1D;
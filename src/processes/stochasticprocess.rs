@@ -0,0 +1,36 @@
+use crate::definitions::Time;
+
+/// One dimensional diffusion `dx = mu(t, x) dt + sigma(t, x) dW`.
+///
+/// The defaults discretize a step by Euler; processes knowing their
+/// transition law override them.
+pub trait StochasticProcess1D {
+    /// Initial value of the state.
+    fn x0(&self) -> f64;
+    fn drift(&self, t: Time, x: f64) -> f64;
+    fn diffusion(&self, t: Time, x: f64) -> f64;
+
+    /// Expectation of the state at `t0 + dt` given `x0` at `t0`.
+    fn expectation(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        self.apply(x0, self.drift(t0, x0) * dt)
+    }
+    /// Standard deviation of the increment over `dt` from `x0` at `t0`.
+    fn std_deviation(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        self.diffusion(t0, x0) * dt.sqrt()
+    }
+    fn variance(&self, t0: Time, x0: f64, dt: Time) -> f64 {
+        let std_dev = self.std_deviation(t0, x0, dt);
+        std_dev * std_dev
+    }
+    /// State at `t0 + dt` from `x0` at `t0` given a standard normal draw.
+    fn evolve(&self, t0: Time, x0: f64, dt: Time, dw: f64) -> f64 {
+        self.apply(
+            self.expectation(t0, x0, dt),
+            self.std_deviation(t0, x0, dt) * dw,
+        )
+    }
+    /// Adds the increment `dx` to the state `x0`.
+    fn apply(&self, x0: f64, dx: f64) -> f64 {
+        x0 + dx
+    }
+}
@@ -0,0 +1,133 @@
+use super::conventions::{compounding, frequency, AnyDayCounter};
+use super::curves::Curve;
+use super::ffi::{boxed, date, handle, write};
+use crate::instruments::{Duration, FixedRateBond};
+use crate::time::Schedule;
+use std::os::raw::c_int;
+
+pub type Bond = FixedRateBond<AnyDayCounter>;
+
+/// A bond paying `coupon` on `schedule` and `face_amount` at maturity,
+/// `frequency` payments a year.
+#[no_mangle]
+pub extern "C" fn ql_bond_new(
+    schedule: Option<&Schedule>,
+    coupon: f64,
+    day_counter: i32,
+    frequency_per_year: i32,
+    face_amount: f64,
+) -> Option<Box<Bond>> {
+    boxed(|| {
+        Ok(FixedRateBond::new(
+            handle(schedule, "schedule")?.clone(),
+            coupon,
+            AnyDayCounter::from_code(day_counter)?,
+            frequency(frequency_per_year)?,
+            face_amount,
+        ))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_clean_price(
+    bond: Option<&Bond>,
+    curve: Option<&Curve>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = &*handle(curve, "curve")?.curve;
+        Ok(handle(bond, "bond")?.clean_price(curve, date(settlement)?))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_dirty_price(
+    bond: Option<&Bond>,
+    curve: Option<&Curve>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = &*handle(curve, "curve")?.curve;
+        Ok(handle(bond, "bond")?.dirty_price(curve, date(settlement)?))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_accrued_amount(
+    bond: Option<&Bond>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        Ok(handle(bond, "bond")?.accrued_amount(date(settlement)?))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_clean_price_from_yield(
+    bond: Option<&Bond>,
+    yield_rate: f64,
+    compounding_code: i32,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let bond = handle(bond, "bond")?;
+        Ok(bond.clean_price_from_yield(
+            yield_rate,
+            compounding(compounding_code)?,
+            date(settlement)?,
+        ))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_yield(
+    bond: Option<&Bond>,
+    clean_price: f64,
+    compounding_code: i32,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let bond = handle(bond, "bond")?;
+        Ok(bond.yield_to_maturity(
+            clean_price,
+            compounding(compounding_code)?,
+            date(settlement)?,
+        ))
+    })
+}
+
+/// The Macaulay duration, or the modified one if `modified`.
+#[no_mangle]
+pub extern "C" fn ql_bond_duration(
+    bond: Option<&Bond>,
+    yield_rate: f64,
+    compounding_code: i32,
+    modified: bool,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let bond = handle(bond, "bond")?;
+        let duration = if modified {
+            Duration::Modified
+        } else {
+            Duration::Macaulay
+        };
+        Ok(bond.duration(
+            yield_rate,
+            compounding(compounding_code)?,
+            duration,
+            date(settlement)?,
+        ))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_bond_free(bond: Option<Box<Bond>>) {
+    drop(bond);
+}
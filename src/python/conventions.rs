@@ -0,0 +1,194 @@
+use crate::definitions::Time;
+use crate::instruments::{OptionType, SwapType};
+use crate::termstructures::Compounding;
+use crate::time::traits::{Calendar as Cal, DayCounter};
+use crate::time::{
+    Actual360, Actual365Fixed, ActualActual, Brazil, BusinessDayConvention, Date, DateGenerator,
+    Frequency, NullCalendar, Sweden, Thirty360, TimeUnit, Weekday, WeekendsOnly,
+};
+
+/// The calendars selectable from Python, by code.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MarketCalendar {
+    WeekendsOnly = 0,
+    Null = 1,
+    Brazil = 2,
+    Sweden = 3,
+}
+
+impl MarketCalendar {
+    pub fn from_code(code: i32) -> Result<MarketCalendar, String> {
+        match code {
+            0 => Ok(MarketCalendar::WeekendsOnly),
+            1 => Ok(MarketCalendar::Null),
+            2 => Ok(MarketCalendar::Brazil),
+            3 => Ok(MarketCalendar::Sweden),
+            _ => Err(format!("unknown calendar code {}", code)),
+        }
+    }
+}
+
+impl Cal for MarketCalendar {
+    fn name(&self) -> String {
+        match self {
+            MarketCalendar::WeekendsOnly => WeekendsOnly.name(),
+            MarketCalendar::Null => NullCalendar.name(),
+            MarketCalendar::Brazil => Brazil.name(),
+            MarketCalendar::Sweden => Sweden.name(),
+        }
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        match self {
+            MarketCalendar::WeekendsOnly => WeekendsOnly.is_business_day(date),
+            MarketCalendar::Null => NullCalendar.is_business_day(date),
+            MarketCalendar::Brazil => Brazil.is_business_day(date),
+            MarketCalendar::Sweden => Sweden.is_business_day(date),
+        }
+    }
+    fn is_weekend(&self, weekday: &Weekday) -> bool {
+        match self {
+            MarketCalendar::WeekendsOnly => WeekendsOnly.is_weekend(weekday),
+            MarketCalendar::Null => NullCalendar.is_weekend(weekday),
+            MarketCalendar::Brazil => Brazil.is_weekend(weekday),
+            MarketCalendar::Sweden => Sweden.is_weekend(weekday),
+        }
+    }
+}
+
+/// The day counters selectable from Python, by code.
+#[derive(Copy, Clone)]
+pub enum AnyDayCounter {
+    Actual360(Actual360),
+    Actual365Fixed(Actual365Fixed),
+    Thirty360(Thirty360),
+    ActualActual(ActualActual),
+}
+
+impl AnyDayCounter {
+    /// Actual/360, Actual/365 (Fixed), 30/360 (bond basis) and
+    /// Actual/Actual (ISDA) for codes 0 to 3.
+    pub fn from_code(code: i32) -> Result<AnyDayCounter, String> {
+        match code {
+            0 => Ok(AnyDayCounter::Actual360(Actual360)),
+            1 => Ok(AnyDayCounter::Actual365Fixed(Actual365Fixed)),
+            2 => Ok(AnyDayCounter::Thirty360(Thirty360::default())),
+            3 => Ok(AnyDayCounter::ActualActual(ActualActual::default())),
+            _ => Err(format!("unknown day counter code {}", code)),
+        }
+    }
+}
+
+impl DayCounter for AnyDayCounter {
+    fn name(&self) -> String {
+        match self {
+            AnyDayCounter::Actual360(dc) => dc.name(),
+            AnyDayCounter::Actual365Fixed(dc) => dc.name(),
+            AnyDayCounter::Thirty360(dc) => dc.name(),
+            AnyDayCounter::ActualActual(dc) => dc.name(),
+        }
+    }
+    fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
+        match self {
+            AnyDayCounter::Actual360(dc) => dc.day_count(date_start, date_end),
+            AnyDayCounter::Actual365Fixed(dc) => dc.day_count(date_start, date_end),
+            AnyDayCounter::Thirty360(dc) => dc.day_count(date_start, date_end),
+            AnyDayCounter::ActualActual(dc) => dc.day_count(date_start, date_end),
+        }
+    }
+    fn year_fraction(
+        &self,
+        date_start: Date,
+        date_end: Date,
+        ref_period_start: Option<Date>,
+        ref_period_end: Option<Date>,
+    ) -> Time {
+        let (s, e, rs, re) = (date_start, date_end, ref_period_start, ref_period_end);
+        match self {
+            AnyDayCounter::Actual360(dc) => dc.year_fraction(s, e, rs, re),
+            AnyDayCounter::Actual365Fixed(dc) => dc.year_fraction(s, e, rs, re),
+            AnyDayCounter::Thirty360(dc) => dc.year_fraction(s, e, rs, re),
+            AnyDayCounter::ActualActual(dc) => dc.year_fraction(s, e, rs, re),
+        }
+    }
+}
+
+/// Following, modified following, preceding, modified preceding and
+/// unadjusted for codes 0 to 4.
+pub fn business_day_convention(code: i32) -> Result<BusinessDayConvention, String> {
+    match code {
+        0 => Ok(BusinessDayConvention::Following),
+        1 => Ok(BusinessDayConvention::ModifiedFollowing),
+        2 => Ok(BusinessDayConvention::Preceding),
+        3 => Ok(BusinessDayConvention::ModifiedPreceding),
+        4 => Ok(BusinessDayConvention::Unadjusted),
+        _ => Err(format!("unknown business day convention code {}", code)),
+    }
+}
+
+/// Days, weeks, months and years for codes 0 to 3.
+pub fn time_unit(code: i32) -> Result<TimeUnit, String> {
+    match code {
+        0 => Ok(TimeUnit::Days),
+        1 => Ok(TimeUnit::Weeks),
+        2 => Ok(TimeUnit::Months),
+        3 => Ok(TimeUnit::Years),
+        _ => Err(format!("unknown time unit code {}", code)),
+    }
+}
+
+/// The rule of the same discriminant, e.g. 1 for backward generation.
+pub fn date_generator(code: i32) -> Result<DateGenerator, String> {
+    match code {
+        1 => Ok(DateGenerator::Backward),
+        2 => Ok(DateGenerator::Forward),
+        3 => Ok(DateGenerator::Zero),
+        4 => Ok(DateGenerator::ThirdWednesday),
+        5 => Ok(DateGenerator::Twentieth),
+        6 => Ok(DateGenerator::TwentiethIMM),
+        _ => Err(format!("unknown date generation rule code {}", code)),
+    }
+}
+
+/// The frequency of as many payments a year, e.g. 2 for semiannual.
+pub fn frequency(payments: i32) -> Result<Frequency, String> {
+    match payments {
+        1 => Ok(Frequency::Annual),
+        2 => Ok(Frequency::Semiannual),
+        3 => Ok(Frequency::EveryFourthMonth),
+        4 => Ok(Frequency::Quarterly),
+        6 => Ok(Frequency::Bimonthly),
+        12 => Ok(Frequency::Monthly),
+        _ => Err(format!(
+            "unsupported frequency of {} payments a year",
+            payments
+        )),
+    }
+}
+
+/// Simple, compounded and continuous compounding for codes 0 to 2.
+pub fn compounding(code: i32) -> Result<Compounding, String> {
+    match code {
+        0 => Ok(Compounding::Simple),
+        1 => Ok(Compounding::Compounded),
+        2 => Ok(Compounding::Continuous),
+        _ => Err(format!("unknown compounding code {}", code)),
+    }
+}
+
+/// Calls for 1 and puts for -1.
+pub fn option_type(sign: i32) -> Result<OptionType, String> {
+    match sign {
+        1 => Ok(OptionType::Call),
+        -1 => Ok(OptionType::Put),
+        _ => Err(format!("unknown option type {}", sign)),
+    }
+}
+
+/// Payer swaps for 1 and receiver swaps for -1.
+pub fn swap_type(sign: i32) -> Result<SwapType, String> {
+    match sign {
+        1 => Ok(SwapType::Payer),
+        -1 => Ok(SwapType::Receiver),
+        _ => Err(format!("unknown swap type {}", sign)),
+    }
+}
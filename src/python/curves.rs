@@ -0,0 +1,215 @@
+use super::conventions::{AnyDayCounter, MarketCalendar};
+use super::ffi::{boxed, date, handle, ordinal, write};
+use crate::definitions::{DiscountFactor, Rate};
+use crate::math::Interpolation;
+use crate::quotes::SimpleQuote;
+use crate::termstructures::traits::{RateHelper, TermStructure, YieldTermStructure as Yts};
+use crate::termstructures::{
+    DepositRateHelper, PiecewiseYieldCurve, SwapRateHelper, YieldTermStructure,
+};
+use crate::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Period,
+    Schedule, TimeUnit,
+};
+use std::os::raw::c_int;
+use std::rc::Rc;
+use std::slice;
+
+/// An Actual/365 (Fixed) discount curve handed to Python, shared with the
+/// indexes and engines built on it.
+pub struct Curve {
+    pub curve: Rc<YieldTermStructure<MarketCalendar, SimpleQuote>>,
+    pub reference_date: Date,
+}
+
+impl Curve {
+    fn discount(&self, date: Date, extrapolate: bool) -> Result<DiscountFactor, String> {
+        self.curve
+            .checked_discount(date, extrapolate)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// The `n` values at `values`, or an error if null and not empty.
+///
+/// # Safety
+///
+/// `values` must point to `n` initialized values, or be null for none.
+unsafe fn values<'a, T>(values: *const T, n: usize, what: &str) -> Result<&'a [T], String> {
+    if n == 0 {
+        Ok(&[])
+    } else if values.is_null() {
+        Err(format!("null {} array", what))
+    } else {
+        Ok(slice::from_raw_parts(values, n))
+    }
+}
+
+/// A curve of flat continuously compounded `rate` as of `reference_date`.
+#[no_mangle]
+pub extern "C" fn ql_curve_flat(
+    reference_date: i32,
+    rate: Rate,
+    calendar: i32,
+) -> Option<Box<Curve>> {
+    boxed(|| {
+        let reference_date = date(reference_date)?;
+        let curve = YieldTermStructure::new(
+            Calendar::new(MarketCalendar::from_code(calendar)?),
+            reference_date,
+            Actual365Fixed,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| (-rate * t).exp()),
+        );
+        Ok(Curve {
+            curve: Rc::new(curve),
+            reference_date,
+        })
+    })
+}
+
+/// A log-linear discount curve bootstrapped on Actual/360 deposits from
+/// the reference date and on par swaps from it, paying a fixed leg every
+/// `fixed_tenor_months` on `fixed_day_counter` against a floating leg
+/// projected off the curve itself.
+///
+/// # Safety
+///
+/// Each maturity array must hold as many dates as its rate array holds
+/// rates, given by `deposits` and `swaps`.
+#[no_mangle]
+pub unsafe extern "C" fn ql_curve_bootstrap(
+    reference_date: i32,
+    calendar: i32,
+    deposit_maturities: *const i32,
+    deposit_rates: *const f64,
+    deposits: usize,
+    swap_maturities: *const i32,
+    swap_rates: *const f64,
+    swaps: usize,
+    fixed_tenor_months: i64,
+    fixed_day_counter: i32,
+) -> Option<Box<Curve>> {
+    boxed(|| {
+        let reference_date = date(reference_date)?;
+        let calendar = Calendar::new(MarketCalendar::from_code(calendar)?);
+        let fixed_day_counter = AnyDayCounter::from_code(fixed_day_counter)?;
+        let deposit_helpers = values(deposit_maturities, deposits, "deposit maturity")?
+            .iter()
+            .zip(values(deposit_rates, deposits, "deposit rate")?)
+            .map(|(maturity, rate)| {
+                Ok(DepositRateHelper::new(
+                    SimpleQuote::new(*rate),
+                    reference_date,
+                    date(*maturity)?,
+                    Actual360,
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let swap_helpers = values(swap_maturities, swaps, "swap maturity")?
+            .iter()
+            .zip(values(swap_rates, swaps, "swap rate")?)
+            .map(|(maturity, rate)| {
+                let schedule = Schedule::new(
+                    reference_date,
+                    date(*maturity)?,
+                    Period::new(fixed_tenor_months, TimeUnit::Months),
+                    calendar,
+                    BusinessDayConvention::ModifiedFollowing,
+                    BusinessDayConvention::ModifiedFollowing,
+                    DateGenerator::Backward,
+                    false,
+                );
+                Ok(SwapRateHelper::new(
+                    SimpleQuote::new(*rate),
+                    schedule,
+                    fixed_day_counter,
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let mut helpers: Vec<&dyn RateHelper> = vec![];
+        helpers.extend(deposit_helpers.iter().map(|h| h as &dyn RateHelper));
+        helpers.extend(swap_helpers.iter().map(|h| h as &dyn RateHelper));
+        let bootstrapped = PiecewiseYieldCurve::new(
+            reference_date,
+            &helpers,
+            calendar,
+            Actual365Fixed,
+            Interpolation::LogLinear,
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(Curve {
+            curve: Rc::new(bootstrapped.curve.term_structure()),
+            reference_date,
+        })
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_curve_reference_date(curve: Option<&Curve>, out: Option<&mut i32>) -> c_int {
+    write(out, || Ok(ordinal(handle(curve, "curve")?.reference_date)))
+}
+
+/// The discount factor to `date`.
+#[no_mangle]
+pub extern "C" fn ql_curve_discount(
+    curve: Option<&Curve>,
+    date_ordinal: i32,
+    extrapolate: bool,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        handle(curve, "curve")?.discount(date(date_ordinal)?, extrapolate)
+    })
+}
+
+/// The continuously compounded Actual/365 (Fixed) zero rate to `date`.
+#[no_mangle]
+pub extern "C" fn ql_curve_zero_rate(
+    curve: Option<&Curve>,
+    date_ordinal: i32,
+    extrapolate: bool,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = handle(curve, "curve")?;
+        let date = date(date_ordinal)?;
+        let t = curve.curve.time_from_reference(date);
+        if t <= 0.0 {
+            return Err(format!(
+                "zero rate to {}, not after the reference date",
+                date
+            ));
+        }
+        Ok(-curve.discount(date, extrapolate)?.ln() / t)
+    })
+}
+
+/// The continuously compounded Actual/365 (Fixed) forward rate from
+/// `start` to `end`.
+#[no_mangle]
+pub extern "C" fn ql_curve_forward_rate(
+    curve: Option<&Curve>,
+    start: i32,
+    end: i32,
+    extrapolate: bool,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = handle(curve, "curve")?;
+        let (start, end) = (date(start)?, date(end)?);
+        if end <= start {
+            return Err(format!("forward period ends on {} before {}", end, start));
+        }
+        let tau = curve.curve.time_from_reference(end) - curve.curve.time_from_reference(start);
+        let growth = curve.discount(start, extrapolate)? / curve.discount(end, extrapolate)?;
+        Ok(growth.ln() / tau)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_curve_free(curve: Option<Box<Curve>>) {
+    drop(curve);
+}
@@ -0,0 +1,102 @@
+use crate::settings::PricingContext;
+use crate::time::{Date, Month};
+use chrono::{Datelike, NaiveDate};
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Status of a call that wrote its result.
+pub const QL_OK: c_int = 0;
+/// Status of a call that failed, as described by `ql_last_error`.
+pub const QL_FAILED: c_int = -1;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: Option<String>) {
+    let message = message.map(|m| CString::new(m.replace('\0', " ")).unwrap());
+    LAST_ERROR.with(|e| *e.borrow_mut() = message);
+}
+
+/// Runs `f`, recording its error or panic as the last error of the thread.
+pub(crate) fn guard<T, F: FnOnce() -> Result<T, String>>(f: F) -> Option<T> {
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|cause| {
+        Err(cause
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| cause.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "unknown panic".to_string()))
+    });
+    match result {
+        Ok(value) => {
+            set_last_error(None);
+            Some(value)
+        }
+        Err(message) => {
+            set_last_error(Some(message));
+            None
+        }
+    }
+}
+
+/// Writes the result of `f` to `out`, returning the status of the call.
+pub(crate) fn write<T, F: FnOnce() -> Result<T, String>>(out: Option<&mut T>, f: F) -> c_int {
+    let out = match out {
+        Some(out) => out,
+        None => {
+            set_last_error(Some("null output pointer".to_string()));
+            return QL_FAILED;
+        }
+    };
+    match guard(f) {
+        Some(value) => {
+            *out = value;
+            QL_OK
+        }
+        None => QL_FAILED,
+    }
+}
+
+/// Boxes the result of `f` for the caller, or returns null.
+pub(crate) fn boxed<T, F: FnOnce() -> Result<T, String>>(f: F) -> Option<Box<T>> {
+    guard(f).map(Box::new)
+}
+
+/// The object behind a handle, or an error naming it if null.
+pub(crate) fn handle<'a, T>(object: Option<&'a T>, what: &str) -> Result<&'a T, String> {
+    object.ok_or_else(|| format!("null {} handle", what))
+}
+
+/// The date of a proleptic Gregorian ordinal.
+pub(crate) fn date(ordinal: i32) -> Result<Date, String> {
+    let invalid = || format!("invalid date ordinal {}", ordinal);
+    let d = NaiveDate::from_num_days_from_ce_opt(ordinal).ok_or_else(invalid)?;
+    let month = Month::from_int(d.month()).ok_or_else(invalid)?;
+    Ok(Date::new(d.day(), month, d.year()))
+}
+
+/// The proleptic Gregorian ordinal of `date`.
+pub(crate) fn ordinal(date: Date) -> i32 {
+    date.d.num_days_from_ce()
+}
+
+/// The description of the last failed call on this thread, or null if the
+/// last call succeeded. Valid until the next call.
+#[no_mangle]
+pub extern "C" fn ql_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// Shares a pricing context evaluating as of `evaluation_date` with every
+/// thread, as needed for fixings and without the `clock` feature.
+#[no_mangle]
+pub extern "C" fn ql_set_evaluation_date(evaluation_date: i32) -> c_int {
+    let context = guard(|| Ok(PricingContext::new(date(evaluation_date)?)));
+    context.map_or(QL_FAILED, |context| {
+        PricingContext::set_shared(context);
+        QL_OK
+    })
+}
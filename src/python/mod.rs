@@ -0,0 +1,31 @@
+//! C ABI for the Python package in `python/quantlib`, which loads the
+//! library built as a `cdylib` through `ctypes`:
+//!
+//! ```text
+//! cargo rustc --release --features python --lib --crate-type cdylib
+//! ```
+//!
+//! Objects are handed out as boxed pointers and released by their `*_free`
+//! function. Dates cross as proleptic Gregorian ordinals, 1 being January
+//! 1st of the year 1, as given by Python's `date.toordinal()`. Functions
+//! returning a pointer return null on failure, the others return
+//! `QL_FAILED` and write their result through an out pointer otherwise;
+//! `ql_last_error` then describes the failure, panics included.
+//!
+//! Curves are Actual/365 (Fixed) discount curves on one of the calendars
+//! of `MarketCalendar`.
+
+// exporting unmangled symbols is unsafe code to the compiler
+#![allow(unsafe_code)]
+
+pub mod bonds;
+pub mod conventions;
+pub mod curves;
+pub mod ffi;
+pub mod options;
+pub mod schedules;
+pub mod swaps;
+
+pub use self::conventions::{AnyDayCounter, MarketCalendar};
+pub use self::curves::Curve;
+pub use self::ffi::{ql_last_error, ql_set_evaluation_date, QL_FAILED, QL_OK};
@@ -0,0 +1,137 @@
+use super::conventions::{option_type, MarketCalendar};
+use super::curves::Curve;
+use super::ffi::{boxed, date, handle, write};
+use crate::instruments::{EarlyExerciseOption, Exercise, VanillaOption};
+use crate::methods::lattices::BinomialTreeType;
+use crate::pricingengines::{AnalyticEuropeanEngine, BinomialVanillaEngine};
+use crate::quotes::SimpleQuote;
+use crate::termstructures::{BlackConstantVol, YieldTermStructure};
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+type Market = YieldTermStructure<MarketCalendar, SimpleQuote>;
+
+/// A call, if `option_type_sign` is 1, or a put, if -1, on a spot price.
+#[no_mangle]
+pub extern "C" fn ql_option_new(
+    option_type_sign: i32,
+    strike: f64,
+    exercise_date: i32,
+) -> Option<Box<VanillaOption>> {
+    boxed(|| {
+        Ok(VanillaOption::new(
+            option_type(option_type_sign)?,
+            strike,
+            date(exercise_date)?,
+        ))
+    })
+}
+
+fn flat_volatility(volatility: f64) -> Result<Rc<BlackConstantVol>, String> {
+    if volatility.is_nan() || volatility < 0.0 {
+        return Err(format!("negative volatility {}", volatility));
+    }
+    Ok(Rc::new(BlackConstantVol::new(SimpleQuote::new(volatility))))
+}
+
+fn european_engine(
+    risk_free: Option<&Curve>,
+    dividend: Option<&Curve>,
+    volatility: f64,
+) -> Result<AnalyticEuropeanEngine<Market, Market, BlackConstantVol>, String> {
+    Ok(AnalyticEuropeanEngine::new(
+        Rc::clone(&handle(risk_free, "curve")?.curve),
+        Rc::clone(&handle(dividend, "curve")?.curve),
+        flat_volatility(volatility)?,
+    ))
+}
+
+/// The Black-Scholes-Merton value of the European option at `spot`, with
+/// the dividend yield of `dividend` and a flat `volatility`.
+#[no_mangle]
+pub extern "C" fn ql_option_npv(
+    option: Option<&VanillaOption>,
+    spot: f64,
+    risk_free: Option<&Curve>,
+    dividend: Option<&Curve>,
+    volatility: f64,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let engine = european_engine(risk_free, dividend, volatility)?;
+        Ok(engine.npv(handle(option, "option")?, spot))
+    })
+}
+
+/// The value, delta, gamma, vega, theta, rho and dividend rho of the
+/// European option, as for `ql_option_npv`.
+#[no_mangle]
+pub extern "C" fn ql_option_greeks(
+    option: Option<&VanillaOption>,
+    spot: f64,
+    risk_free: Option<&Curve>,
+    dividend: Option<&Curve>,
+    volatility: f64,
+    out: Option<&mut [f64; 7]>,
+) -> c_int {
+    write(out, || {
+        let engine = european_engine(risk_free, dividend, volatility)?;
+        let g = engine.greeks(handle(option, "option")?, spot);
+        Ok([
+            g.npv,
+            g.delta,
+            g.gamma,
+            g.vega,
+            g.theta,
+            g.rho,
+            g.dividend_rho,
+        ])
+    })
+}
+
+/// The value of the option on a Cox-Ross-Rubinstein tree of `steps`,
+/// exercisable at any time up to its exercise date if `american`.
+#[no_mangle]
+pub extern "C" fn ql_option_binomial_npv(
+    option: Option<&VanillaOption>,
+    american: bool,
+    spot: f64,
+    risk_free: Option<&Curve>,
+    dividend: Option<&Curve>,
+    volatility: f64,
+    steps: usize,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let option = handle(option, "option")?;
+        let risk_free = handle(risk_free, "curve")?;
+        if steps == 0 {
+            return Err("no time steps".to_string());
+        }
+        let engine = BinomialVanillaEngine::new(
+            Rc::clone(&risk_free.curve),
+            Rc::clone(&handle(dividend, "curve")?.curve),
+            flat_volatility(volatility)?,
+            BinomialTreeType::CoxRossRubinstein,
+            steps,
+        );
+        let exercisable = if american {
+            EarlyExerciseOption::new(
+                option.option_type,
+                option.strike,
+                Exercise::American {
+                    earliest: risk_free.reference_date,
+                    latest: option.exercise_date,
+                },
+            )
+        } else {
+            (*option).into()
+        };
+        Ok(engine.npv(&exercisable, spot))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_option_free(option: Option<Box<VanillaOption>>) {
+    drop(option);
+}
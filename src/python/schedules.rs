@@ -0,0 +1,74 @@
+use super::conventions::{business_day_convention, date_generator, time_unit, MarketCalendar};
+use super::ffi::{boxed, date, handle, ordinal, write};
+use crate::time::{Calendar, Period, Schedule};
+use std::os::raw::c_int;
+
+/// The schedule from `effective_date` to `termination_date` every
+/// `tenor_length` tenor units, as `Schedule::new` generates it.
+#[no_mangle]
+pub extern "C" fn ql_schedule_new(
+    effective_date: i32,
+    termination_date: i32,
+    tenor_length: i64,
+    tenor_unit: i32,
+    calendar: i32,
+    convention: i32,
+    termination_date_convention: i32,
+    rule: i32,
+    end_of_month: bool,
+) -> Option<Box<Schedule>> {
+    boxed(|| {
+        Ok(Schedule::new(
+            date(effective_date)?,
+            date(termination_date)?,
+            Period::new(tenor_length, time_unit(tenor_unit)?),
+            Calendar::new(MarketCalendar::from_code(calendar)?),
+            business_day_convention(convention)?,
+            business_day_convention(termination_date_convention)?,
+            date_generator(rule)?,
+            end_of_month,
+        ))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_schedule_size(schedule: Option<&Schedule>, out: Option<&mut usize>) -> c_int {
+    write(out, || Ok(handle(schedule, "schedule")?.size()))
+}
+
+/// The `i`-th date of the schedule.
+#[no_mangle]
+pub extern "C" fn ql_schedule_date(
+    schedule: Option<&Schedule>,
+    i: usize,
+    out: Option<&mut i32>,
+) -> c_int {
+    write(out, || {
+        let dates = &handle(schedule, "schedule")?.dates;
+        dates
+            .get(i)
+            .map(|d| ordinal(*d))
+            .ok_or_else(|| format!("schedule date {} out of {}", i, dates.len()))
+    })
+}
+
+/// Whether the `i`-th period spans a full tenor.
+#[no_mangle]
+pub extern "C" fn ql_schedule_is_regular(
+    schedule: Option<&Schedule>,
+    i: usize,
+    out: Option<&mut bool>,
+) -> c_int {
+    write(out, || {
+        let is_regular = &handle(schedule, "schedule")?.is_regular;
+        is_regular
+            .get(i)
+            .copied()
+            .ok_or_else(|| format!("schedule period {} out of {}", i, is_regular.len()))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_schedule_free(schedule: Option<Box<Schedule>>) {
+    drop(schedule);
+}
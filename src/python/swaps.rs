@@ -0,0 +1,116 @@
+use super::conventions::{swap_type, AnyDayCounter, MarketCalendar};
+use super::curves::Curve;
+use super::ffi::{boxed, date, handle, write};
+use crate::cashflows::{FixedRateCoupon, FixedRateLeg, IborCoupon, IborLeg};
+use crate::indexes::IborIndex;
+use crate::instruments::VanillaSwap;
+use crate::quotes::SimpleQuote;
+use crate::termstructures::YieldTermStructure;
+use crate::time::{Actual360, BusinessDayConvention, Calendar, Period, Schedule, TimeUnit};
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+pub type Index =
+    IborIndex<MarketCalendar, YieldTermStructure<MarketCalendar, SimpleQuote>, Actual360>;
+pub type Swap = VanillaSwap<FixedRateCoupon<AnyDayCounter>, IborCoupon<Index, Actual360>>;
+
+/// A swap paying, if `swap_type` is 1, or receiving, if -1, `fixed_rate`
+/// on `fixed_schedule` against an Actual/360 Ibor index of
+/// `index_tenor_months` plus `spread` on `floating_schedule`, projected
+/// off `forwarding_curve`.
+#[no_mangle]
+pub extern "C" fn ql_swap_new(
+    swap_type_sign: i32,
+    notional: f64,
+    fixed_schedule: Option<&Schedule>,
+    fixed_rate: f64,
+    fixed_day_counter: i32,
+    floating_schedule: Option<&Schedule>,
+    index_tenor_months: i64,
+    fixing_days: i64,
+    calendar: i32,
+    forwarding_curve: Option<&Curve>,
+    spread: f64,
+) -> Option<Box<Swap>> {
+    boxed(|| {
+        let index = Rc::new(IborIndex::new(
+            "Ibor",
+            Period::new(index_tenor_months, TimeUnit::Months),
+            fixing_days,
+            Calendar::new(MarketCalendar::from_code(calendar)?),
+            BusinessDayConvention::ModifiedFollowing,
+            false,
+            Actual360,
+            Some(Rc::clone(&handle(forwarding_curve, "curve")?.curve)),
+        ));
+        let fixed_leg = FixedRateLeg::new(
+            handle(fixed_schedule, "schedule")?.clone(),
+            AnyDayCounter::from_code(fixed_day_counter)?,
+        )
+        .with_notional(notional)
+        .with_rate(fixed_rate)
+        .build();
+        let floating_leg = IborLeg::new(
+            handle(floating_schedule, "schedule")?.clone(),
+            index,
+            Actual360,
+        )
+        .with_notional(notional)
+        .with_spread(spread)
+        .build();
+        Ok(VanillaSwap::new(
+            swap_type(swap_type_sign)?,
+            fixed_leg,
+            fixed_rate,
+            floating_leg,
+            spread,
+        ))
+    })
+}
+
+/// The value of the flows after `settlement`, discounted on `curve`.
+#[no_mangle]
+pub extern "C" fn ql_swap_npv(
+    swap: Option<&Swap>,
+    curve: Option<&Curve>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = &*handle(curve, "curve")?.curve;
+        Ok(handle(swap, "swap")?.npv(curve, date(settlement)?))
+    })
+}
+
+/// The fixed rate making the swap worth zero.
+#[no_mangle]
+pub extern "C" fn ql_swap_fair_rate(
+    swap: Option<&Swap>,
+    curve: Option<&Curve>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = &*handle(curve, "curve")?.curve;
+        Ok(handle(swap, "swap")?.fair_rate(curve, date(settlement)?))
+    })
+}
+
+/// The floating spread making the swap worth zero.
+#[no_mangle]
+pub extern "C" fn ql_swap_fair_spread(
+    swap: Option<&Swap>,
+    curve: Option<&Curve>,
+    settlement: i32,
+    out: Option<&mut f64>,
+) -> c_int {
+    write(out, || {
+        let curve = &*handle(curve, "curve")?.curve;
+        Ok(handle(swap, "swap")?.fair_spread(curve, date(settlement)?))
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ql_swap_free(swap: Option<Box<Swap>>) {
+    drop(swap);
+}
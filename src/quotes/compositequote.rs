@@ -0,0 +1,51 @@
+use super::derivedquote::observe;
+use super::Quote;
+use crate::patterns::{ChangeFlag, Observable};
+use std::rc::Rc;
+
+/// Quote whose value is a function of two quotes, e.g. a spread over a
+/// benchmark or a cross FX rate. Changes of either quote are passed on to
+/// its observers.
+pub struct CompositeQuote<Q1: Quote, Q2: Quote, F: Fn(f64, f64) -> f64> {
+    pub first: Q1,
+    pub second: Q2,
+    pub function: F,
+    changes: Rc<ChangeFlag>,
+}
+
+impl<Q1, Q2, F> CompositeQuote<Q1, Q2, F>
+where
+    Q1: Quote,
+    Q2: Quote,
+    F: Fn(f64, f64) -> f64,
+{
+    pub fn new(first: Q1, second: Q2, function: F) -> CompositeQuote<Q1, Q2, F> {
+        let changes = ChangeFlag::new();
+        observe(&changes, &first);
+        observe(&changes, &second);
+        CompositeQuote {
+            first,
+            second,
+            function,
+            changes,
+        }
+    }
+}
+
+impl<Q1, Q2, F> Quote for CompositeQuote<Q1, Q2, F>
+where
+    Q1: Quote,
+    Q2: Quote,
+    F: Fn(f64, f64) -> f64,
+{
+    fn value(&self) -> f64 {
+        assert!(self.is_valid(), "invalid CompositeQuote");
+        (self.function)(self.first.value(), self.second.value())
+    }
+    fn is_valid(&self) -> bool {
+        self.first.is_valid() && self.second.is_valid()
+    }
+    fn observable(&self) -> Option<&Observable> {
+        Some(self.changes.observable())
+    }
+}
@@ -0,0 +1,43 @@
+use super::Quote;
+use crate::patterns::{ChangeFlag, Observable, Observer};
+use std::rc::Rc;
+
+pub(crate) fn observe<Q: Quote>(changes: &Rc<ChangeFlag>, quote: &Q) {
+    if let Some(observable) = quote.observable() {
+        observable.register_observer(Rc::clone(changes) as Rc<dyn Observer>);
+    }
+}
+
+/// Quote whose value is a function of another quote, e.g. the inverse of
+/// an FX rate or a price quoted as a rate. Changes of the underlying
+/// quote are passed on to its observers.
+pub struct DerivedQuote<Q: Quote, F: Fn(f64) -> f64> {
+    pub underlying: Q,
+    pub function: F,
+    changes: Rc<ChangeFlag>,
+}
+
+impl<Q: Quote, F: Fn(f64) -> f64> DerivedQuote<Q, F> {
+    pub fn new(underlying: Q, function: F) -> DerivedQuote<Q, F> {
+        let changes = ChangeFlag::new();
+        observe(&changes, &underlying);
+        DerivedQuote {
+            underlying,
+            function,
+            changes,
+        }
+    }
+}
+
+impl<Q: Quote, F: Fn(f64) -> f64> Quote for DerivedQuote<Q, F> {
+    fn value(&self) -> f64 {
+        assert!(self.is_valid(), "invalid DerivedQuote");
+        (self.function)(self.underlying.value())
+    }
+    fn is_valid(&self) -> bool {
+        self.underlying.is_valid()
+    }
+    fn observable(&self) -> Option<&Observable> {
+        Some(self.changes.observable())
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Shared counter identifying the state of a set of market data.
+///
+/// Every quote registered with the same `MarketVersion` bumps it when its
+/// value changes, so a consumer can tell whether anything it depends on
+/// moved by comparing a single number. Clones share the same counter.
+#[derive(Clone, Debug, Default)]
+pub struct MarketVersion {
+    counter: Arc<AtomicU64>,
+}
+
+impl MarketVersion {
+    pub fn new() -> MarketVersion {
+        MarketVersion::default()
+    }
+
+    /// The current version.
+    pub fn current(&self) -> u64 {
+        self.counter.load(Ordering::Acquire)
+    }
+
+    /// Records a market change and returns the new version.
+    pub fn bump(&self) -> u64 {
+        self.counter.fetch_add(1, Ordering::AcqRel) + 1
+    }
+}
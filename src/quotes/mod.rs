@@ -1,3 +1,11 @@
+pub mod compositequote;
+pub mod derivedquote;
+pub mod marketversion;
+pub mod simplequote;
 pub mod traits;
 
+pub use self::compositequote::CompositeQuote;
+pub use self::derivedquote::DerivedQuote;
+pub use self::marketversion::MarketVersion;
+pub use self::simplequote::SimpleQuote;
 pub use self::traits::Quote;
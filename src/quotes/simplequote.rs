@@ -0,0 +1,66 @@
+use super::{MarketVersion, Quote};
+use crate::patterns::Observable;
+use std::cell::Cell;
+
+/// Market quote holding a settable value, notifying its observers and
+/// bumping its market version on changes.
+///
+/// The value is set through a shared reference, so that one quote held in
+/// an `Rc` can drive every curve and instrument built on it.
+#[derive(Debug, Default)]
+pub struct SimpleQuote {
+    value: Cell<Option<f64>>,
+    version: Option<MarketVersion>,
+    observable: Observable,
+}
+
+impl SimpleQuote {
+    pub fn new(value: f64) -> SimpleQuote {
+        SimpleQuote {
+            value: Cell::new(Some(value)),
+            version: None,
+            observable: Observable::new(),
+        }
+    }
+
+    /// Attaches the quote to a market version, which is bumped on changes.
+    pub fn with_version(mut self, version: MarketVersion) -> SimpleQuote {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the value and returns the difference with the previous one.
+    pub fn set_value(&self, value: f64) -> f64 {
+        let previous = self.value.replace(Some(value));
+        if previous != Some(value) {
+            self.notify();
+        }
+        value - previous.unwrap_or(0.0)
+    }
+
+    /// Invalidates the quote.
+    pub fn reset(&self) {
+        if self.value.take().is_some() {
+            self.notify();
+        }
+    }
+
+    fn notify(&self) {
+        if let Some(version) = &self.version {
+            version.bump();
+        }
+        self.observable.notify_observers();
+    }
+}
+
+impl Quote for SimpleQuote {
+    fn value(&self) -> f64 {
+        self.value.get().expect("invalid SimpleQuote")
+    }
+    fn is_valid(&self) -> bool {
+        self.value.get().is_some()
+    }
+    fn observable(&self) -> Option<&Observable> {
+        Some(&self.observable)
+    }
+}
@@ -1,4 +1,25 @@
+use crate::patterns::Observable;
+use std::rc::Rc;
+
 pub trait Quote {
     fn value(&self) -> f64;
     fn is_valid(&self) -> bool;
+    /// Notifies of value changes, for quotes that can change.
+    fn observable(&self) -> Option<&Observable> {
+        None
+    }
+}
+
+/// A quote shared by several curves or instruments, which all see its
+/// changes.
+impl<Q: Quote + ?Sized> Quote for Rc<Q> {
+    fn value(&self) -> f64 {
+        (**self).value()
+    }
+    fn is_valid(&self) -> bool {
+        (**self).is_valid()
+    }
+    fn observable(&self) -> Option<&Observable> {
+        (**self).observable()
+    }
 }
@@ -0,0 +1,102 @@
+use super::{Cell, Table};
+use crate::cashflows::CashFlow;
+use crate::risk::CurveDelta;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::RateHelper;
+use crate::time::Date;
+
+/// Key rate deltas by curve and pillar, in the order of `deltas`, each
+/// curve closed by its total with an empty pillar.
+pub fn risk_ladder(deltas: &[CurveDelta]) -> Table {
+    let mut table = Table::new("Risk ladder", &["curve", "pillar", "delta"]);
+    let mut curves: Vec<&str> = vec![];
+    for d in deltas {
+        if !curves.contains(&d.curve.as_str()) {
+            curves.push(&d.curve);
+        }
+    }
+    for curve in curves {
+        let mut total = 0.0;
+        for d in deltas.iter().filter(|d| d.curve == curve) {
+            table.push_row(vec![
+                Cell::Text(curve.to_string()),
+                Cell::Number(d.pillar),
+                Cell::Number(d.delta),
+            ]);
+            total += d.delta;
+        }
+        table.push_row(vec![
+            Cell::Text(format!("{} total", curve)),
+            Cell::Empty,
+            Cell::Number(total),
+        ]);
+    }
+    table
+}
+
+/// Cash flows of `leg` still to be paid after `settlement`, with their
+/// coupon terms, empty for other flows, and present values on
+/// `discount_curve`.
+pub fn cashflow_report<C: CashFlow, Y: YieldTermStructure>(
+    leg: &[C],
+    discount_curve: &Y,
+    settlement: Date,
+) -> Table {
+    let mut table = Table::new(
+        "Cash flows",
+        &[
+            "payment_date",
+            "accrual_start",
+            "accrual_end",
+            "nominal",
+            "rate",
+            "amount",
+            "discount",
+            "present_value",
+        ],
+    );
+    for flow in leg
+        .iter()
+        .filter(|c| !CashFlow::has_occured(*c, settlement, false))
+    {
+        let discount = discount_curve.discount(flow.date(), true);
+        let terms = match flow.try_as_coup() {
+            Some(c) => vec![
+                Cell::Date(c.accrual_start_date()),
+                Cell::Date(c.accrual_end_date()),
+                Cell::Number(c.nominal()),
+                Cell::Number(c.rate()),
+            ],
+            None => vec![Cell::Empty; 4],
+        };
+        let mut row = vec![Cell::Date(flow.date())];
+        row.extend(terms);
+        row.extend(vec![
+            Cell::Number(flow.amount()),
+            Cell::Number(discount),
+            Cell::Number(flow.amount() * discount),
+        ]);
+        table.push_row(row);
+    }
+    table.with_precision(6)
+}
+
+/// Market against implied quotes of the instruments `curve` is calibrated
+/// to, by pillar date.
+pub fn calibration_report<Y: YieldTermStructure>(curve: &Y, helpers: &[&dyn RateHelper]) -> Table {
+    let mut table = Table::new(
+        "Calibration",
+        &["pillar_date", "market_quote", "implied_quote", "error"],
+    );
+    let discount = |d| curve.discount(d, true);
+    for h in helpers {
+        let implied = h.implied_quote_on(&discount);
+        table.push_row(vec![
+            Cell::Date(h.pillar_date()),
+            Cell::Number(h.quote()),
+            Cell::Number(implied),
+            Cell::Number(h.quote() - implied),
+        ]);
+    }
+    table.with_precision(8)
+}
@@ -0,0 +1,10 @@
+//! Reports of risk, cash flows and calibrations as tables rendered to
+//! aligned text, CSV or JSON. Each report has fixed columns, so that the
+//! CSV header and JSON keys downstream consumers read do not change with
+//! the data.
+
+pub mod builders;
+pub mod table;
+
+pub use self::builders::{calibration_report, cashflow_report, risk_ladder};
+pub use self::table::{Cell, Table};
@@ -0,0 +1,173 @@
+use crate::time::Date;
+use crate::utils::csv::csv_field;
+use crate::utils::json::{json_number, json_string};
+use std::fmt::Write;
+
+/// A value of a report table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cell {
+    Text(String),
+    Number(f64),
+    Date(Date),
+    Empty,
+}
+
+impl Cell {
+    fn text(&self, precision: usize) -> String {
+        match self {
+            Cell::Text(s) => s.clone(),
+            Cell::Number(x) => format!("{:.*}", precision, x),
+            Cell::Date(d) => d.to_string(),
+            Cell::Empty => String::new(),
+        }
+    }
+
+    fn csv(&self) -> String {
+        match self {
+            Cell::Text(s) => csv_field(s),
+            Cell::Number(x) => format!("{}", x),
+            Cell::Date(d) => d.to_string(),
+            Cell::Empty => String::new(),
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Cell::Text(s) => json_string(s),
+            Cell::Number(x) => json_number(*x),
+            Cell::Date(d) => json_string(&d.to_string()),
+            Cell::Empty => String::from("null"),
+        }
+    }
+}
+
+/// Table of a report, rendering numbers to `precision` decimals as text
+/// and in full in CSV and JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    pub title: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Cell>>,
+    pub precision: usize,
+}
+
+impl Table {
+    pub fn new(title: &str, columns: &[&str]) -> Table {
+        Table {
+            title: title.to_string(),
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: vec![],
+            precision: 2,
+        }
+    }
+
+    pub fn with_precision(mut self, precision: usize) -> Table {
+        self.precision = precision;
+        self
+    }
+
+    pub fn push_row(&mut self, row: Vec<Cell>) {
+        assert!(
+            row.len() == self.columns.len(),
+            "row and columns size mismatch"
+        );
+        self.rows.push(row);
+    }
+
+    /// Title, header and rows in columns padded to their widest entry,
+    /// numbers aligned right and other cells left.
+    pub fn to_text(&self) -> String {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|r| r.iter().map(|c| c.text(self.precision)).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(j, name)| {
+                cells
+                    .iter()
+                    .map(|r| r[j].chars().count())
+                    .fold(name.chars().count(), usize::max)
+            })
+            .collect();
+        // headers of number columns are aligned with their numbers
+        let numeric: Vec<bool> = (0..self.columns.len())
+            .map(|j| {
+                self.rows.iter().any(|r| matches!(r[j], Cell::Number(_)))
+                    && self
+                        .rows
+                        .iter()
+                        .all(|r| matches!(r[j], Cell::Number(_) | Cell::Empty))
+            })
+            .collect();
+        let mut out = format!("{}\n", self.title);
+        let header: Vec<String> = self
+            .columns
+            .iter()
+            .zip(&widths)
+            .zip(&numeric)
+            .map(|((name, w), right)| {
+                if *right {
+                    format!("{:>w$}", name, w = w)
+                } else {
+                    format!("{:<w$}", name, w = w)
+                }
+            })
+            .collect();
+        writeln!(out, "{}", header.join("  ").trim_end()).unwrap();
+        let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        writeln!(out, "{}", rule.join("  ")).unwrap();
+        for (row, text) in self.rows.iter().zip(&cells) {
+            let padded: Vec<String> = row
+                .iter()
+                .zip(text)
+                .zip(&widths)
+                .map(|((cell, s), w)| match cell {
+                    Cell::Number(_) => format!("{:>w$}", s, w = w),
+                    _ => format!("{:<w$}", s, w = w),
+                })
+                .collect();
+            writeln!(out, "{}", padded.join("  ").trim_end()).unwrap();
+        }
+        out
+    }
+
+    /// Header and rows, without the title.
+    pub fn to_csv(&self) -> String {
+        let header: Vec<String> = self.columns.iter().map(|c| csv_field(c)).collect();
+        let mut out = format!("{}\n", header.join(","));
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().map(Cell::csv).collect();
+            writeln!(out, "{}", cells.join(",")).unwrap();
+        }
+        out
+    }
+
+    /// `{"title", "columns", "rows"}` with each row an object keyed by
+    /// the column names; empty cells are `null`.
+    pub fn to_json(&self) -> String {
+        let columns: Vec<String> = self.columns.iter().map(|c| json_string(c)).collect();
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let fields: Vec<String> = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(name, cell)| format!("{}:{}", json_string(name), cell.json()))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            })
+            .collect();
+        format!(
+            "{{\"title\":{},\"columns\":[{}],\"rows\":[{}]}}",
+            json_string(&self.title),
+            columns.join(","),
+            rows.join(",")
+        )
+    }
+}
@@ -0,0 +1,211 @@
+//! Portfolio compression of swaps: offsetting positions on one index with
+//! overlapping schedules are torn up against a single replacement trade
+//! for their net notional, struck so that the portfolio keeps its NPV and,
+//! within a tolerance, its delta.
+
+use crate::definitions::Rate;
+use crate::time::Date;
+
+/// Rate at which the replacement is valued to find how its NPV moves with
+/// the fixed rate.
+const RATE_PROBE: Rate = 0.01;
+
+/// A booked fixed-for-floating swap; a positive notional pays fixed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapPosition {
+    pub id: String,
+    pub index: String,
+    pub start_date: Date,
+    pub maturity_date: Date,
+    pub notional: f64,
+    pub fixed_rate: Rate,
+}
+
+impl SwapPosition {
+    pub fn new(
+        id: &str,
+        index: &str,
+        start_date: Date,
+        maturity_date: Date,
+        notional: f64,
+        fixed_rate: Rate,
+    ) -> SwapPosition {
+        assert!(start_date < maturity_date, "swap matures before it starts");
+        SwapPosition {
+            id: id.to_string(),
+            index: index.to_string(),
+            start_date,
+            maturity_date,
+            notional,
+            fixed_rate,
+        }
+    }
+}
+
+fn gross<'a, I: IntoIterator<Item = &'a SwapPosition>>(positions: I) -> f64 {
+    positions.into_iter().map(|p| p.notional.abs()).sum()
+}
+
+/// Value and delta of a position, as given by the pricer of a compression
+/// run.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PositionRisk {
+    pub npv: f64,
+    pub delta: f64,
+}
+
+/// Trades torn up together and what replaces them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressionProposal {
+    pub terminated: Vec<String>,
+    /// The trade for the net notional, none when the positions offset
+    /// exactly.
+    pub replacement: Option<SwapPosition>,
+    /// Value of the terminated trades not carried over by the
+    /// replacement, settled in cash.
+    pub cash: f64,
+    /// Change of the delta of the portfolio.
+    pub delta_change: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressionReport {
+    pub proposals: Vec<CompressionProposal>,
+    pub gross_notional_before: f64,
+    pub gross_notional_after: f64,
+}
+
+impl CompressionReport {
+    pub fn notional_reduction(&self) -> f64 {
+        self.gross_notional_before - self.gross_notional_after
+    }
+
+    /// The reduction as a fraction of the gross notional before
+    /// compression.
+    pub fn reduction_ratio(&self) -> f64 {
+        if self.gross_notional_before == 0.0 {
+            0.0
+        } else {
+            self.notional_reduction() / self.gross_notional_before
+        }
+    }
+}
+
+/// Compression run settings: positions are candidates for netting when
+/// they share an index, their schedules overlap and their maturities are
+/// at most `maturity_window` days apart; a proposal is only made when it
+/// moves the portfolio delta by no more than `delta_tolerance`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Compression {
+    pub maturity_window: i64,
+    pub delta_tolerance: f64,
+}
+
+impl Compression {
+    pub fn new(delta_tolerance: f64) -> Compression {
+        assert!(delta_tolerance >= 0.0, "negative delta tolerance");
+        Compression {
+            maturity_window: 0,
+            delta_tolerance,
+        }
+    }
+
+    pub fn with_maturity_window(mut self, days: i64) -> Compression {
+        assert!(days >= 0, "negative maturity window");
+        self.maturity_window = days;
+        self
+    }
+
+    /// Candidate groups of `positions`, by index and then by maturity.
+    fn clusters<'a>(&self, positions: &'a [SwapPosition]) -> Vec<Vec<&'a SwapPosition>> {
+        let mut sorted: Vec<&SwapPosition> = positions.iter().collect();
+        sorted.sort_by(|a, b| {
+            (&a.index, a.maturity_date, &a.id).cmp(&(&b.index, b.maturity_date, &b.id))
+        });
+        let mut clusters: Vec<Vec<&SwapPosition>> = vec![];
+        for position in sorted {
+            let joins = clusters.last().is_some_and(|cluster| {
+                let first = cluster[0];
+                let overlaps = cluster
+                    .iter()
+                    .all(|p| position.start_date < p.maturity_date);
+                first.index == position.index
+                    && position.maturity_date.sub(first.maturity_date) <= self.maturity_window
+                    && overlaps
+            });
+            if joins {
+                clusters.last_mut().unwrap().push(position);
+            } else {
+                clusters.push(vec![position]);
+            }
+        }
+        clusters
+    }
+
+    /// Proposes replacements for the offsetting positions of `positions`,
+    /// valued by `pricer`. The replacement of a group is on the schedule
+    /// of its largest trade, for the net notional, at the fixed rate
+    /// giving it the value of the group.
+    pub fn compress<F: Fn(&SwapPosition) -> PositionRisk>(
+        &self,
+        positions: &[SwapPosition],
+        pricer: F,
+    ) -> CompressionReport {
+        let gross_notional_before = gross(positions);
+        let mut gross_notional_after = gross_notional_before;
+        let mut proposals = vec![];
+        for cluster in self.clusters(positions) {
+            let offsetting = cluster.iter().any(|p| p.notional > 0.0)
+                && cluster.iter().any(|p| p.notional < 0.0);
+            if !offsetting {
+                continue;
+            }
+            let (npv, delta) = cluster.iter().fold((0.0, 0.0), |(n, d), p| {
+                let risk = pricer(p);
+                (n + risk.npv, d + risk.delta)
+            });
+            let cluster_gross = gross(cluster.iter().copied());
+            let net: f64 = cluster.iter().map(|p| p.notional).sum();
+            let (replacement, cash, replacement_delta) = if net.abs() <= 1.0e-12 * cluster_gross {
+                (None, npv, 0.0)
+            } else {
+                let largest = cluster
+                    .iter()
+                    .max_by(|a, b| a.notional.abs().partial_cmp(&b.notional.abs()).unwrap())
+                    .unwrap();
+                let ids: Vec<&str> = cluster.iter().map(|p| p.id.as_str()).collect();
+                let mut trade = SwapPosition::new(
+                    &ids.join("+"),
+                    &largest.index,
+                    largest.start_date,
+                    largest.maturity_date,
+                    net,
+                    0.0,
+                );
+                let at_zero = pricer(&trade).npv;
+                trade.fixed_rate = RATE_PROBE;
+                let slope = (pricer(&trade).npv - at_zero) / RATE_PROBE;
+                assert!(slope != 0.0, "replacement value insensitive to its rate");
+                trade.fixed_rate = (npv - at_zero) / slope;
+                let risk = pricer(&trade);
+                (Some(trade), npv - risk.npv, risk.delta)
+            };
+            let delta_change = replacement_delta - delta;
+            if delta_change.abs() > self.delta_tolerance {
+                continue;
+            }
+            gross_notional_after -= cluster_gross - net.abs();
+            proposals.push(CompressionProposal {
+                terminated: cluster.iter().map(|p| p.id.clone()).collect(),
+                replacement,
+                cash,
+                delta_change,
+            });
+        }
+        CompressionReport {
+            proposals,
+            gross_notional_before,
+            gross_notional_after,
+        }
+    }
+}
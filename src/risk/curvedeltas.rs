@@ -0,0 +1,94 @@
+use super::frtb::{RiskClass, Sensitivity};
+use crate::definitions::Time;
+use crate::termstructures::KeyRateShift;
+
+/// A curve to take deltas to, by its key rate pillars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveBuckets {
+    pub name: String,
+    pub pillars: Vec<Time>,
+}
+
+impl CurveBuckets {
+    pub fn new(name: &str, pillars: &[Time]) -> CurveBuckets {
+        assert!(!pillars.is_empty(), "no pillars given");
+        CurveBuckets {
+            name: name.to_string(),
+            pillars: pillars.to_vec(),
+        }
+    }
+}
+
+/// Value change for a shift of one pillar of one curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveDelta {
+    pub curve: String,
+    pub pillar: Time,
+    pub delta: f64,
+}
+
+/// Key rate deltas of a trade to each pillar of each of `curves`, kept
+/// apart so that the discounting and every projection curve get their
+/// own risk.
+///
+/// `pricer` values the trade on the curves shifted by one key rate shift
+/// each, in the order of `curves`; it is called with a single curve shifted
+/// at a time. Deltas are central differences for a zero rate shift of
+/// `shift`, e.g. 1bp.
+pub fn curve_deltas<F: Fn(&[KeyRateShift]) -> f64>(
+    curves: &[CurveBuckets],
+    shift: f64,
+    pricer: F,
+) -> Vec<CurveDelta> {
+    assert!(shift > 0.0, "non positive shift");
+    let mut deltas = vec![];
+    for (k, curve) in curves.iter().enumerate() {
+        for (i, pillar) in curve.pillars.iter().enumerate() {
+            let npv = |size: f64| {
+                let shifts: Vec<KeyRateShift> = (0..curves.len())
+                    .map(|j| {
+                        if j == k {
+                            KeyRateShift::new(&curve.pillars, i, size)
+                        } else {
+                            KeyRateShift::none()
+                        }
+                    })
+                    .collect();
+                pricer(&shifts)
+            };
+            deltas.push(CurveDelta {
+                curve: curve.name.clone(),
+                pillar: *pillar,
+                delta: (npv(shift) - npv(-shift)) / 2.0,
+            });
+        }
+    }
+    deltas
+}
+
+/// The sum of the deltas to the pillars of `curve`, its parallel delta.
+pub fn total_delta(deltas: &[CurveDelta], curve: &str) -> f64 {
+    deltas
+        .iter()
+        .filter(|d| d.curve == curve)
+        .map(|d| d.delta)
+        .sum()
+}
+
+/// FRTB interest rate delta sensitivities of `bucket`, the currency, one
+/// per curve and pillar, from deltas for a zero rate shift of `shift`:
+/// the 1bp value change over 0.0001 is the change per unit of rate.
+pub fn frtb_deltas(deltas: &[CurveDelta], bucket: &str, shift: f64) -> Vec<Sensitivity> {
+    deltas
+        .iter()
+        .map(|d| {
+            Sensitivity::delta(
+                RiskClass::InterestRate,
+                bucket,
+                &d.curve,
+                Some(d.pillar),
+                d.delta / shift,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,454 @@
+use crate::definitions::Time;
+use std::collections::BTreeMap;
+
+/// FRTB risk classes with prescribed SBM parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskClass {
+    /// General interest rate risk; buckets are currencies and risk
+    /// factors are curves.
+    InterestRate,
+    /// Buckets "1" to "13" of the standard, risk factors are issuers.
+    Equity,
+    /// Buckets are currency pairs, each its only risk factor.
+    Fx,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskMeasure {
+    Delta,
+    Vega,
+    Curvature,
+}
+
+/// Correlation scenario the charges are computed under; the capital is the
+/// largest of the three totals.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CorrelationScenario {
+    Low,
+    Medium,
+    High,
+}
+
+impl CorrelationScenario {
+    /// The prescribed correlation scaled for the scenario.
+    pub fn apply(&self, correlation: f64) -> f64 {
+        match self {
+            CorrelationScenario::Low => (2.0 * correlation - 1.0).max(0.75 * correlation),
+            CorrelationScenario::Medium => correlation,
+            CorrelationScenario::High => (1.25 * correlation).min(1.0),
+        }
+    }
+}
+
+const SCENARIOS: [CorrelationScenario; 3] = [
+    CorrelationScenario::Low,
+    CorrelationScenario::Medium,
+    CorrelationScenario::High,
+];
+
+/// Sensitivity to one FRTB risk factor.
+///
+/// Interest rate deltas are value changes for a 1bp shift divided by
+/// 0.0001, equity and FX deltas value changes for a 1% relative shift
+/// divided by 0.01, and vegas the vega times the implied volatility, so
+/// that the prescribed risk weights apply directly. Curvature
+/// sensitivities are the CVR of the upward and downward shocks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sensitivity {
+    pub risk_class: RiskClass,
+    pub measure: RiskMeasure,
+    pub bucket: String,
+    pub risk_factor: String,
+    /// Tenor of an interest rate delta or option maturity of a vega.
+    pub tenor: Option<Time>,
+    /// The sensitivity, or the upward CVR for curvature.
+    pub value: f64,
+    /// The downward CVR for curvature, zero otherwise.
+    pub down: f64,
+}
+
+impl Sensitivity {
+    pub fn delta(
+        risk_class: RiskClass,
+        bucket: &str,
+        risk_factor: &str,
+        tenor: Option<Time>,
+        value: f64,
+    ) -> Sensitivity {
+        assert!(
+            (risk_class == RiskClass::InterestRate) == tenor.is_some(),
+            "interest rate deltas, and only them, have a tenor"
+        );
+        Sensitivity {
+            risk_class,
+            measure: RiskMeasure::Delta,
+            bucket: bucket.to_string(),
+            risk_factor: risk_factor.to_string(),
+            tenor,
+            value,
+            down: 0.0,
+        }
+    }
+
+    pub fn vega(
+        risk_class: RiskClass,
+        bucket: &str,
+        risk_factor: &str,
+        option_maturity: Time,
+        value: f64,
+    ) -> Sensitivity {
+        assert!(option_maturity > 0.0, "non positive option maturity");
+        Sensitivity {
+            risk_class,
+            measure: RiskMeasure::Vega,
+            bucket: bucket.to_string(),
+            risk_factor: risk_factor.to_string(),
+            tenor: Some(option_maturity),
+            value,
+            down: 0.0,
+        }
+    }
+
+    pub fn curvature(
+        risk_class: RiskClass,
+        bucket: &str,
+        risk_factor: &str,
+        up: f64,
+        down: f64,
+    ) -> Sensitivity {
+        Sensitivity {
+            risk_class,
+            measure: RiskMeasure::Curvature,
+            bucket: bucket.to_string(),
+            risk_factor: risk_factor.to_string(),
+            tenor: None,
+            value: up,
+            down,
+        }
+    }
+
+    fn equity_bucket(&self) -> usize {
+        equity_bucket(&self.bucket)
+    }
+
+    /// Prescribed risk weight; curvature sensitivities are already
+    /// weighted.
+    pub fn risk_weight(&self) -> f64 {
+        match (self.measure, self.risk_class) {
+            (RiskMeasure::Curvature, _) => 1.0,
+            (RiskMeasure::Delta, RiskClass::InterestRate) => {
+                let tenor = self.tenor.unwrap();
+                match GIRR_TENORS.iter().position(|t| (t - tenor).abs() < 1.0e-9) {
+                    Some(i) => GIRR_RISK_WEIGHTS[i],
+                    None => panic!("{} is not a prescribed interest rate tenor", tenor),
+                }
+            }
+            (RiskMeasure::Delta, RiskClass::Equity) => {
+                EQUITY_RISK_WEIGHTS[self.equity_bucket() - 1]
+            }
+            (RiskMeasure::Delta, RiskClass::Fx) => 0.15,
+            // 0.55 sqrt(LH / 10) capped at 100%, with a 20 day horizon for
+            // large caps and indices and 60 days otherwise.
+            (RiskMeasure::Vega, RiskClass::Equity) => match self.equity_bucket() {
+                9..=11 => 1.0,
+                _ => (0.55 * 2f64.sqrt()).min(1.0),
+            },
+            (RiskMeasure::Vega, _) => 1.0,
+        }
+    }
+
+    fn weighted(&self) -> f64 {
+        self.risk_weight() * self.value
+    }
+}
+
+const GIRR_TENORS: [Time; 10] = [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0, 15.0, 20.0, 30.0];
+const GIRR_RISK_WEIGHTS: [f64; 10] = [
+    0.017, 0.017, 0.016, 0.013, 0.012, 0.011, 0.011, 0.011, 0.011, 0.011,
+];
+const EQUITY_RISK_WEIGHTS: [f64; 13] = [
+    0.55, 0.60, 0.45, 0.55, 0.30, 0.35, 0.40, 0.50, 0.70, 0.50, 0.70, 0.15, 0.25,
+];
+
+fn equity_bucket(bucket: &str) -> usize {
+    match bucket.parse::<usize>() {
+        Ok(b) if (1..=13).contains(&b) => b,
+        _ => panic!("{} is not an equity bucket", bucket),
+    }
+}
+
+/// Equity bucket 11, other sector, is aggregated without diversification.
+fn is_other_sector(class: RiskClass, bucket: &str) -> bool {
+    class == RiskClass::Equity && equity_bucket(bucket) == 11
+}
+
+/// `exp(-theta |t1 - t2| / min(t1, t2))`.
+fn tenor_correlation(theta: f64, t1: Time, t2: Time) -> f64 {
+    (-theta * (t1 - t2).abs() / t1.min(t2)).exp()
+}
+
+/// Medium scenario correlation between two risk factors of one bucket,
+/// ignoring the tenor for curvature.
+fn factor_correlation(a: &Sensitivity, b: &Sensitivity) -> f64 {
+    let same_factor = a.risk_factor == b.risk_factor;
+    let names = match a.risk_class {
+        RiskClass::InterestRate => {
+            if same_factor {
+                1.0
+            } else {
+                0.999
+            }
+        }
+        RiskClass::Equity if same_factor => 1.0,
+        RiskClass::Equity => match a.equity_bucket() {
+            1..=4 => 0.15,
+            5..=8 => 0.25,
+            9 => 0.075,
+            10 => 0.125,
+            _ => 0.80,
+        },
+        RiskClass::Fx => 1.0,
+    };
+    let tenors = match (a.measure, a.tenor, b.tenor) {
+        (RiskMeasure::Delta, Some(t1), Some(t2)) => tenor_correlation(0.03, t1, t2).max(0.4),
+        (RiskMeasure::Vega, Some(t1), Some(t2)) => tenor_correlation(0.01, t1, t2),
+        _ => 1.0,
+    };
+    (names * tenors).min(1.0)
+}
+
+/// Medium scenario correlation between two buckets of a risk class.
+fn bucket_correlation(class: RiskClass, b: &str, c: &str) -> f64 {
+    match class {
+        RiskClass::InterestRate => 0.5,
+        RiskClass::Fx => 0.6,
+        RiskClass::Equity => match (equity_bucket(b), equity_bucket(c)) {
+            (11, _) | (_, 11) => 0.0,
+            (12, 13) | (13, 12) => 0.75,
+            (12, _) | (13, _) | (_, 12) | (_, 13) => 0.45,
+            _ => 0.15,
+        },
+    }
+}
+
+/// Capital of one bucket: `capital` is `K_b` and `sum` the sum of its
+/// weighted sensitivities `S_b`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketCapital {
+    pub bucket: String,
+    pub capital: f64,
+    pub sum: f64,
+}
+
+/// Delta, vega or curvature charge of a risk class under a scenario.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiskCharge {
+    pub risk_class: RiskClass,
+    pub measure: RiskMeasure,
+    pub scenario: CorrelationScenario,
+    pub buckets: Vec<BucketCapital>,
+    pub capital: f64,
+}
+
+/// Outcome of the SBM aggregation.
+#[derive(Clone, Debug)]
+pub struct SbmReport {
+    pub charges: Vec<RiskCharge>,
+    /// The scenario giving the largest total.
+    pub scenario: CorrelationScenario,
+    pub capital: f64,
+}
+
+impl SbmReport {
+    /// Sum of the charges under `scenario`.
+    pub fn capital_under(&self, scenario: CorrelationScenario) -> f64 {
+        self.charges
+            .iter()
+            .filter(|c| c.scenario == scenario)
+            .map(|c| c.capital)
+            .sum()
+    }
+
+    pub fn charge(
+        &self,
+        risk_class: RiskClass,
+        measure: RiskMeasure,
+        scenario: CorrelationScenario,
+    ) -> Option<&RiskCharge> {
+        self.charges
+            .iter()
+            .find(|c| c.risk_class == risk_class && c.measure == measure && c.scenario == scenario)
+    }
+}
+
+/// Nets sensitivities to the same risk factor and tenor.
+fn net(sensitivities: &[&Sensitivity]) -> Vec<Sensitivity> {
+    let mut netted: Vec<Sensitivity> = vec![];
+    for s in sensitivities {
+        match netted
+            .iter_mut()
+            .find(|n| n.risk_factor == s.risk_factor && n.tenor == s.tenor)
+        {
+            Some(n) => {
+                n.value += s.value;
+                n.down += s.down;
+            }
+            None => netted.push((*s).clone()),
+        }
+    }
+    netted
+}
+
+/// `K_b` of a delta or vega bucket and `S_b`.
+fn linear_bucket(
+    sensitivities: &[Sensitivity],
+    other_sector: bool,
+    scenario: CorrelationScenario,
+) -> (f64, f64) {
+    let ws: Vec<f64> = sensitivities.iter().map(Sensitivity::weighted).collect();
+    let sum: f64 = ws.iter().sum();
+    if other_sector {
+        return (ws.iter().map(|w| w.abs()).sum(), sum);
+    }
+    let mut variance = 0.0;
+    for (k, a) in sensitivities.iter().enumerate() {
+        for (l, b) in sensitivities.iter().enumerate() {
+            let rho = if k == l {
+                1.0
+            } else {
+                scenario.apply(factor_correlation(a, b))
+            };
+            variance += rho * ws[k] * ws[l];
+        }
+    }
+    (variance.max(0.0).sqrt(), sum)
+}
+
+/// `K_b` and `S_b` of a curvature bucket, on the worse of the upward and
+/// downward shocks.
+fn curvature_bucket(
+    sensitivities: &[Sensitivity],
+    other_sector: bool,
+    scenario: CorrelationScenario,
+) -> (f64, f64) {
+    let side = |cvr: &dyn Fn(&Sensitivity) -> f64| {
+        let sum: f64 = sensitivities.iter().map(cvr).sum();
+        if other_sector {
+            return (sensitivities.iter().map(|s| cvr(s).max(0.0)).sum(), sum);
+        }
+        let mut variance = 0.0;
+        for (k, a) in sensitivities.iter().enumerate() {
+            for (l, b) in sensitivities.iter().enumerate() {
+                let (x, y) = (cvr(a), cvr(b));
+                if k == l {
+                    variance += x.max(0.0).powi(2);
+                } else if x >= 0.0 || y >= 0.0 {
+                    variance += scenario.apply(factor_correlation(a, b)).powi(2) * x * y;
+                }
+            }
+        }
+        (variance.max(0.0).sqrt(), sum)
+    };
+    let (up, down) = (side(&|s| s.value), side(&|s| s.down));
+    if up.0 > down.0 || (up.0 == down.0 && up.1 >= down.1) {
+        up
+    } else {
+        down
+    }
+}
+
+/// Aggregates bucket capitals across buckets, bounding the bucket sums
+/// when the unbounded total would be negative.
+fn across_buckets(
+    class: RiskClass,
+    measure: RiskMeasure,
+    scenario: CorrelationScenario,
+    buckets: &[BucketCapital],
+) -> f64 {
+    let total = |sums: &[f64]| {
+        let mut variance = 0.0;
+        for (b, x) in buckets.iter().enumerate() {
+            variance += x.capital * x.capital;
+            for (c, y) in buckets.iter().enumerate() {
+                if b == c {
+                    continue;
+                }
+                let gamma = scenario.apply(bucket_correlation(class, &x.bucket, &y.bucket));
+                variance += match measure {
+                    RiskMeasure::Curvature if sums[b] < 0.0 && sums[c] < 0.0 => 0.0,
+                    RiskMeasure::Curvature => gamma * gamma * sums[b] * sums[c],
+                    _ => gamma * sums[b] * sums[c],
+                };
+            }
+        }
+        variance
+    };
+    let sums: Vec<f64> = buckets.iter().map(|b| b.sum).collect();
+    let variance = total(&sums);
+    if variance >= 0.0 {
+        return variance.sqrt();
+    }
+    let bounded: Vec<f64> = buckets
+        .iter()
+        .map(|b| b.sum.min(b.capital).max(-b.capital))
+        .collect();
+    total(&bounded).max(0.0).sqrt()
+}
+
+/// FRTB sensitivities-based method capital of `sensitivities`: the delta,
+/// vega and curvature charges of each risk class, summed under each of
+/// the low, medium and high correlation scenarios, with the capital the
+/// largest total.
+pub fn sbm_capital(sensitivities: &[Sensitivity]) -> SbmReport {
+    let mut groups: BTreeMap<(RiskClass, RiskMeasure), BTreeMap<&str, Vec<&Sensitivity>>> =
+        BTreeMap::new();
+    for s in sensitivities {
+        groups
+            .entry((s.risk_class, s.measure))
+            .or_default()
+            .entry(s.bucket.as_str())
+            .or_default()
+            .push(s);
+    }
+    let mut charges = vec![];
+    for scenario in SCENARIOS.iter() {
+        for ((class, measure), buckets) in &groups {
+            let capitals: Vec<BucketCapital> = buckets
+                .iter()
+                .map(|(bucket, members)| {
+                    let netted = net(members);
+                    let other = is_other_sector(*class, bucket);
+                    let (capital, sum) = match measure {
+                        RiskMeasure::Curvature => curvature_bucket(&netted, other, *scenario),
+                        _ => linear_bucket(&netted, other, *scenario),
+                    };
+                    BucketCapital {
+                        bucket: bucket.to_string(),
+                        capital,
+                        sum,
+                    }
+                })
+                .collect();
+            charges.push(RiskCharge {
+                risk_class: *class,
+                measure: *measure,
+                scenario: *scenario,
+                capital: across_buckets(*class, *measure, *scenario, &capitals),
+                buckets: capitals,
+            });
+        }
+    }
+    let mut report = SbmReport {
+        charges,
+        scenario: CorrelationScenario::Medium,
+        capital: 0.0,
+    };
+    for scenario in SCENARIOS.iter() {
+        let capital = report.capital_under(*scenario);
+        if capital > report.capital {
+            report.capital = capital;
+            report.scenario = *scenario;
+        }
+    }
+    report
+}
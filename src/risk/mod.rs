@@ -0,0 +1,20 @@
+pub mod compression;
+pub mod curvedeltas;
+pub mod frtb;
+pub mod parrisk;
+pub mod saccr;
+pub mod scenariomorphing;
+
+pub use self::compression::{
+    Compression, CompressionProposal, CompressionReport, PositionRisk, SwapPosition,
+};
+pub use self::curvedeltas::{curve_deltas, frtb_deltas, total_delta, CurveBuckets, CurveDelta};
+pub use self::frtb::{
+    sbm_capital, BucketCapital, CorrelationScenario, RiskCharge, RiskClass, RiskMeasure, SbmReport,
+    Sensitivity,
+};
+pub use self::parrisk::{QuoteDelta, QuoteJacobian};
+pub use self::saccr::{
+    supervisory_option_delta, AssetClass, CreditRating, NettingSet, SaccrResult, Trade, Underlying,
+};
+pub use self::scenariomorphing::{worst_point, MorphingPoint, ScenarioMorph};
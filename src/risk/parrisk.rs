@@ -0,0 +1,113 @@
+//! Risk to the quotes of the instruments a curve is bootstrapped on, par
+//! swap rates, futures prices or deposit rates, as desks quote it. Key
+//! rate deltas to the pillars of those instruments are mapped through the
+//! Jacobian of their implied quotes in the zero rates at the pillars.
+
+use super::curvedeltas::{CurveBuckets, CurveDelta};
+use crate::definitions::Time;
+use crate::math::{linear_least_squares, Matrix};
+use crate::termstructures::traits::YieldTermStructure;
+use crate::termstructures::{KeyRateShift, RateHelper};
+use crate::time::Date;
+
+/// Sensitivities of the implied quotes of the curve instruments to the
+/// zero rates at their pillars.
+#[derive(Clone, Debug)]
+pub struct QuoteJacobian {
+    pub pillar_dates: Vec<Date>,
+    pub pillars: Vec<Time>,
+    /// Change of the implied quote of each helper, by row, for a given
+    /// change of the zero rate at each pillar, by column.
+    pub matrix: Matrix,
+}
+
+impl QuoteJacobian {
+    /// The Jacobian of `helpers`, sorted by pillar date, on `curve`, by
+    /// central differences for key rate shifts of `shift` at the helper
+    /// pillars.
+    pub fn new<Y: YieldTermStructure>(
+        curve: &Y,
+        helpers: &[&dyn RateHelper],
+        shift: f64,
+    ) -> QuoteJacobian {
+        assert!(!helpers.is_empty(), "no helpers given");
+        assert!(shift > 0.0, "non positive shift");
+        let pillar_dates: Vec<Date> = helpers.iter().map(|h| h.pillar_date()).collect();
+        assert!(
+            pillar_dates.windows(2).all(|w| w[0] < w[1]),
+            "helpers not sorted by pillar date"
+        );
+        let pillars: Vec<Time> = pillar_dates
+            .iter()
+            .map(|d| curve.time_from_reference(*d))
+            .collect();
+        let n = helpers.len();
+        let mut matrix = Matrix::new(n, n, 0.0);
+        for j in 0..n {
+            let quotes = |size: f64| {
+                let key_rate = KeyRateShift::new(&pillars, j, size);
+                let discount = |d: Date| {
+                    let t = curve.time_from_reference(d);
+                    curve.discount(d, true) * (-key_rate.zero_shift(t) * t).exp()
+                };
+                helpers
+                    .iter()
+                    .map(|h| h.implied_quote_on(&discount))
+                    .collect::<Vec<f64>>()
+            };
+            let (up, down) = (quotes(shift), quotes(-shift));
+            for i in 0..n {
+                matrix[(i, j)] = (up[i] - down[i]) / (2.0 * shift);
+            }
+        }
+        QuoteJacobian {
+            pillar_dates,
+            pillars,
+            matrix,
+        }
+    }
+
+    /// The key rate pillars of `name` to take the deltas mapped here to.
+    pub fn buckets(&self, name: &str) -> CurveBuckets {
+        CurveBuckets::new(name, &self.pillars)
+    }
+
+    /// Value changes for a change of each quote, from the value changes
+    /// `zero_deltas` for the same change of the zero rate at each pillar,
+    /// solving `J^T x = d`.
+    pub fn market_deltas(&self, zero_deltas: &[f64]) -> Vec<f64> {
+        assert!(
+            zero_deltas.len() == self.pillars.len(),
+            "one delta per pillar needed"
+        );
+        linear_least_squares(&self.matrix.transpose(), zero_deltas)
+    }
+
+    /// Market quote deltas of `curve` from its key rate deltas, for a
+    /// quote move of the size of the zero rate shift they were taken for.
+    pub fn quote_deltas(&self, deltas: &[CurveDelta], curve: &str) -> Vec<QuoteDelta> {
+        let zero_deltas: Vec<f64> = deltas
+            .iter()
+            .filter(|d| d.curve == curve)
+            .map(|d| d.delta)
+            .collect();
+        self.market_deltas(&zero_deltas)
+            .into_iter()
+            .zip(&self.pillar_dates)
+            .map(|(delta, pillar)| QuoteDelta {
+                curve: curve.to_string(),
+                pillar: *pillar,
+                delta,
+            })
+            .collect()
+    }
+}
+
+/// Value change for a move of the quote of the curve instrument with
+/// pillar `pillar`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteDelta {
+    pub curve: String,
+    pub pillar: Date,
+    pub delta: f64,
+}
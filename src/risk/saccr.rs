@@ -0,0 +1,452 @@
+use crate::cashflows::CashFlow;
+use crate::definitions::Time;
+use crate::instruments::{SwapType, VanillaSwap};
+use crate::math::normal_cdf;
+use crate::termstructures::traits::YieldTermStructure;
+use crate::time::{Actual365Fixed, Date, DayCounter};
+use std::collections::BTreeMap;
+
+/// SA-CCR asset classes, each aggregated into its own add-on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AssetClass {
+    InterestRate,
+    Fx,
+    Credit,
+    Equity,
+    Commodity,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CreditRating {
+    Aaa,
+    Aa,
+    A,
+    Bbb,
+    Bb,
+    B,
+    Ccc,
+}
+
+/// Primary risk driver of a trade, fixing its supervisory factor and
+/// correlation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Underlying {
+    InterestRate,
+    Fx,
+    Credit(CreditRating),
+    CreditIndex {
+        investment_grade: bool,
+    },
+    Equity,
+    EquityIndex,
+    Electricity,
+    /// Commodities other than electricity.
+    Commodity,
+}
+
+impl Underlying {
+    pub fn asset_class(&self) -> AssetClass {
+        match self {
+            Underlying::InterestRate => AssetClass::InterestRate,
+            Underlying::Fx => AssetClass::Fx,
+            Underlying::Credit(_) | Underlying::CreditIndex { .. } => AssetClass::Credit,
+            Underlying::Equity | Underlying::EquityIndex => AssetClass::Equity,
+            Underlying::Electricity | Underlying::Commodity => AssetClass::Commodity,
+        }
+    }
+
+    pub fn supervisory_factor(&self) -> f64 {
+        match self {
+            Underlying::InterestRate => 0.005,
+            Underlying::Fx => 0.04,
+            Underlying::Credit(rating) => match rating {
+                CreditRating::Aaa | CreditRating::Aa => 0.0038,
+                CreditRating::A => 0.0042,
+                CreditRating::Bbb => 0.0054,
+                CreditRating::Bb => 0.0106,
+                CreditRating::B => 0.016,
+                CreditRating::Ccc => 0.06,
+            },
+            Underlying::CreditIndex { investment_grade } => {
+                if *investment_grade {
+                    0.0038
+                } else {
+                    0.0106
+                }
+            }
+            Underlying::Equity => 0.32,
+            Underlying::EquityIndex => 0.20,
+            Underlying::Electricity => 0.40,
+            Underlying::Commodity => 0.18,
+        }
+    }
+
+    /// Correlation of the entity (or commodity type) with the systematic
+    /// factor of its hedging set.
+    pub fn correlation(&self) -> f64 {
+        match self {
+            Underlying::Credit(_) | Underlying::Equity => 0.5,
+            Underlying::CreditIndex { .. } | Underlying::EquityIndex => 0.8,
+            Underlying::Electricity | Underlying::Commodity => 0.4,
+            Underlying::InterestRate | Underlying::Fx => 1.0,
+        }
+    }
+}
+
+/// Supervisory delta of an option on `price` struck at `strike`,
+/// positive when long the underlying, i.e. for bought calls and sold
+/// puts.
+pub fn supervisory_option_delta(
+    call: bool,
+    bought: bool,
+    price: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: Time,
+) -> f64 {
+    assert!(price > 0.0 && strike > 0.0, "non positive price or strike");
+    let sd = volatility * expiry.sqrt();
+    let d1 = (price / strike).ln() / sd + 0.5 * sd;
+    let sign = if bought { 1.0 } else { -1.0 };
+    if call {
+        sign * normal_cdf(d1)
+    } else {
+        -sign * normal_cdf(-d1)
+    }
+}
+
+/// A derivative as seen by SA-CCR.
+///
+/// `hedging_set` is the currency of interest rate trades, the currency
+/// pair of FX trades and the commodity group (energy, metals, ...) of
+/// commodity trades; credit and equity trades share one hedging set per
+/// asset class. `risk_factor` is the reference entity, index or
+/// commodity type offsetting within the hedging set. Times are in years
+/// from the valuation date.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trade {
+    pub underlying: Underlying,
+    pub hedging_set: String,
+    pub risk_factor: String,
+    /// Notional in the netting set currency.
+    pub notional: f64,
+    pub start: Time,
+    pub end: Time,
+    pub maturity: Time,
+    pub delta: f64,
+    pub mtm: f64,
+}
+
+impl Trade {
+    /// Trade long its underlying from now on to `maturity`.
+    pub fn new(
+        underlying: Underlying,
+        hedging_set: &str,
+        risk_factor: &str,
+        notional: f64,
+        maturity: Time,
+        mtm: f64,
+    ) -> Trade {
+        assert!(maturity >= 0.0, "negative maturity");
+        Trade {
+            underlying,
+            hedging_set: hedging_set.to_string(),
+            risk_factor: risk_factor.to_string(),
+            notional,
+            start: 0.0,
+            end: maturity,
+            maturity,
+            delta: 1.0,
+            mtm,
+        }
+    }
+
+    /// The period the underlying rate or credit protection refers to, for
+    /// forward starting trades.
+    pub fn with_period(mut self, start: Time, end: Time) -> Trade {
+        assert!(0.0 <= start && start <= end, "invalid period");
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn with_delta(mut self, delta: f64) -> Trade {
+        self.delta = delta;
+        self
+    }
+
+    /// Interest rate trade of a swap valued on `discount_curve`; payers are
+    /// long the floating rate.
+    pub fn vanilla_swap<FX, FL, Y>(
+        swap: &VanillaSwap<FX, FL>,
+        currency: &str,
+        discount_curve: &Y,
+        settlement: Date,
+    ) -> Trade
+    where
+        FX: CashFlow,
+        FL: CashFlow,
+        Y: YieldTermStructure,
+    {
+        let coupons: Vec<_> = swap
+            .fixed_leg
+            .iter()
+            .filter_map(|c| c.try_as_coup())
+            .collect();
+        assert!(!coupons.is_empty(), "fixed leg without coupons");
+        let time = |d: Date| {
+            Actual365Fixed
+                .year_fraction(settlement, d, None, None)
+                .max(0.0)
+        };
+        let start = time(coupons[0].accrual_start_date());
+        let end = time(coupons[coupons.len() - 1].accrual_end_date());
+        let delta = match swap.swap_type {
+            SwapType::Payer => 1.0,
+            SwapType::Receiver => -1.0,
+        };
+        Trade::new(
+            Underlying::InterestRate,
+            currency,
+            currency,
+            coupons[0].nominal(),
+            end,
+            swap.npv(discount_curve, settlement),
+        )
+        .with_period(start, end)
+        .with_delta(delta)
+    }
+
+    /// Notional times the supervisory duration for interest rate and
+    /// credit trades, the notional otherwise.
+    pub fn adjusted_notional(&self) -> f64 {
+        match self.underlying.asset_class() {
+            AssetClass::InterestRate | AssetClass::Credit => {
+                let duration = ((-0.05 * self.start).exp() - (-0.05 * self.end).exp()) / 0.05;
+                self.notional * duration
+            }
+            _ => self.notional,
+        }
+    }
+
+    /// `sqrt(min(M, 1))` for unmargined trades, `1.5 sqrt(MPOR / 250)`
+    /// for margined ones.
+    pub fn maturity_factor(&self, margin_period_of_risk: Option<i64>) -> f64 {
+        match margin_period_of_risk {
+            Some(days) => 1.5 * (days as f64 / 250.0).sqrt(),
+            None => self.maturity.clamp(10.0 / 250.0, 1.0).sqrt(),
+        }
+    }
+}
+
+/// Trades under one netting agreement with the margin terms of its CSA.
+#[derive(Clone, Debug)]
+pub struct NettingSet {
+    pub trades: Vec<Trade>,
+    /// Net collateral held, after haircuts.
+    pub collateral: f64,
+    /// Margin period of risk in business days for margined sets.
+    pub margin_period_of_risk: Option<i64>,
+    pub threshold: f64,
+    pub minimum_transfer_amount: f64,
+    /// Net independent collateral amount.
+    pub independent_amount: f64,
+}
+
+impl NettingSet {
+    /// Unmargined netting set without collateral.
+    pub fn new(trades: Vec<Trade>) -> NettingSet {
+        NettingSet {
+            trades,
+            collateral: 0.0,
+            margin_period_of_risk: None,
+            threshold: 0.0,
+            minimum_transfer_amount: 0.0,
+            independent_amount: 0.0,
+        }
+    }
+
+    pub fn with_collateral(mut self, collateral: f64) -> NettingSet {
+        self.collateral = collateral;
+        self
+    }
+
+    /// Variation margin under a CSA, with a margin period of risk of at
+    /// least ten business days.
+    pub fn with_margin(
+        mut self,
+        threshold: f64,
+        minimum_transfer_amount: f64,
+        independent_amount: f64,
+        margin_period_of_risk: i64,
+    ) -> NettingSet {
+        assert!(
+            margin_period_of_risk >= 10,
+            "margin period of risk below ten days"
+        );
+        self.threshold = threshold;
+        self.minimum_transfer_amount = minimum_transfer_amount;
+        self.independent_amount = independent_amount;
+        self.margin_period_of_risk = Some(margin_period_of_risk);
+        self
+    }
+
+    pub fn mtm(&self) -> f64 {
+        self.trades.iter().map(|t| t.mtm).sum()
+    }
+
+    pub fn exposure_at_default(&self) -> SaccrResult {
+        let result = saccr(self, self.margin_period_of_risk);
+        if self.margin_period_of_risk.is_none() {
+            return result;
+        }
+        // a margined set never needs more than its unmargined exposure
+        let unmargined = saccr(self, None);
+        if unmargined.ead < result.ead {
+            SaccrResult {
+                ead: unmargined.ead,
+                ..result
+            }
+        } else {
+            result
+        }
+    }
+}
+
+/// The SA-CCR exposure of a netting set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SaccrResult {
+    pub replacement_cost: f64,
+    pub add_ons: BTreeMap<AssetClass, f64>,
+    pub add_on: f64,
+    pub multiplier: f64,
+    pub potential_future_exposure: f64,
+    /// `1.4 (RC + PFE)`.
+    pub ead: f64,
+}
+
+const ALPHA: f64 = 1.4;
+
+fn saccr(set: &NettingSet, margin_period_of_risk: Option<i64>) -> SaccrResult {
+    let exposure = set.mtm() - set.collateral;
+    let replacement_cost = match margin_period_of_risk {
+        Some(_) => exposure
+            .max(set.threshold + set.minimum_transfer_amount - set.independent_amount)
+            .max(0.0),
+        None => exposure.max(0.0),
+    };
+    let mut add_ons = BTreeMap::new();
+    for class in [
+        AssetClass::InterestRate,
+        AssetClass::Fx,
+        AssetClass::Credit,
+        AssetClass::Equity,
+        AssetClass::Commodity,
+    ] {
+        let trades: Vec<&Trade> = set
+            .trades
+            .iter()
+            .filter(|t| t.underlying.asset_class() == class)
+            .collect();
+        if !trades.is_empty() {
+            add_ons.insert(class, class_add_on(class, &trades, margin_period_of_risk));
+        }
+    }
+    let add_on: f64 = add_ons.values().sum();
+    let multiplier = if add_on > 0.0 {
+        (0.05 + 0.95 * (exposure / (2.0 * 0.95 * add_on)).exp()).min(1.0)
+    } else {
+        1.0
+    };
+    let potential_future_exposure = multiplier * add_on;
+    SaccrResult {
+        replacement_cost,
+        add_ons,
+        add_on,
+        multiplier,
+        potential_future_exposure,
+        ead: ALPHA * (replacement_cost + potential_future_exposure),
+    }
+}
+
+/// `delta d MF` of a trade.
+fn effective_notional(trade: &Trade, margin_period_of_risk: Option<i64>) -> f64 {
+    trade.delta * trade.adjusted_notional() * trade.maturity_factor(margin_period_of_risk)
+}
+
+fn by_key<'a, K: Ord>(
+    trades: &[&'a Trade],
+    key: impl Fn(&Trade) -> K,
+) -> BTreeMap<K, Vec<&'a Trade>> {
+    let mut groups: BTreeMap<K, Vec<&Trade>> = BTreeMap::new();
+    for t in trades {
+        groups.entry(key(t)).or_default().push(t);
+    }
+    groups
+}
+
+fn class_add_on(class: AssetClass, trades: &[&Trade], mpor: Option<i64>) -> f64 {
+    match class {
+        AssetClass::InterestRate => by_key(trades, |t| t.hedging_set.clone())
+            .values()
+            .map(|set| interest_rate_add_on(set, mpor))
+            .sum(),
+        AssetClass::Fx => by_key(trades, |t| t.hedging_set.clone())
+            .values()
+            .map(|set| {
+                let notional: f64 = set.iter().map(|t| effective_notional(t, mpor)).sum();
+                Underlying::Fx.supervisory_factor() * notional.abs()
+            })
+            .sum(),
+        AssetClass::Credit | AssetClass::Equity => entity_add_on(trades, mpor),
+        AssetClass::Commodity => by_key(trades, |t| t.hedging_set.clone())
+            .values()
+            .map(|set| entity_add_on(set, mpor))
+            .sum(),
+    }
+}
+
+/// Offsets within the under one year, one to five years and over five
+/// years maturity buckets, partially across them.
+fn interest_rate_add_on(trades: &[&Trade], mpor: Option<i64>) -> f64 {
+    let mut d = [0.0; 3];
+    for t in trades {
+        let bucket = if t.end < 1.0 {
+            0
+        } else if t.end <= 5.0 {
+            1
+        } else {
+            2
+        };
+        d[bucket] += effective_notional(t, mpor);
+    }
+    let notional = (d[0] * d[0]
+        + d[1] * d[1]
+        + d[2] * d[2]
+        + 1.4 * d[0] * d[1]
+        + 1.4 * d[1] * d[2]
+        + 0.6 * d[0] * d[2])
+        .max(0.0)
+        .sqrt();
+    Underlying::InterestRate.supervisory_factor() * notional
+}
+
+/// Single factor aggregation of the add-ons of each entity or commodity
+/// type.
+fn entity_add_on(trades: &[&Trade], mpor: Option<i64>) -> f64 {
+    let (mut systematic, mut idiosyncratic) = (0.0, 0.0);
+    for entity in by_key(trades, |t| t.risk_factor.clone()).values() {
+        let underlying = entity[0].underlying;
+        assert!(
+            entity.iter().all(|t| t.underlying == underlying),
+            "{} trades with different underlyings",
+            entity[0].risk_factor
+        );
+        let notional: f64 = entity.iter().map(|t| effective_notional(t, mpor)).sum();
+        let add_on = underlying.supervisory_factor() * notional;
+        let rho = underlying.correlation();
+        systematic += rho * add_on;
+        idiosyncratic += (1.0 - rho * rho) * add_on * add_on;
+    }
+    (systematic * systematic + idiosyncratic).sqrt()
+}
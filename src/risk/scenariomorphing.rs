@@ -0,0 +1,131 @@
+use crate::persistence::{CurveSnapshot, MarketState, ModelSnapshot};
+use crate::time::Date;
+
+/// Deterministic path from one market to another, e.g. from today's
+/// market to a stressed one reached over the days of a liquidity horizon.
+///
+/// Intermediate markets move each curve's continuously compounded zero
+/// rates, on the union of the nodes of both ends, and each model parameter
+/// linearly in the weight; their `as_of` dates move to the nearest day,
+/// while the curves keep the reference dates and the markets the version
+/// of `start`. Both ends must hold the same curves, models and parameters
+/// by name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScenarioMorph {
+    pub start: MarketState,
+    pub end: MarketState,
+}
+
+/// The value of a portfolio on one market of a morphing path.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MorphingPoint {
+    /// Weight of the end market, from 0 to 1.
+    pub weight: f64,
+    pub as_of: Date,
+    pub value: f64,
+    /// Change of value from the start market.
+    pub pnl: f64,
+}
+
+impl ScenarioMorph {
+    pub fn new(start: MarketState, end: MarketState) -> ScenarioMorph {
+        assert!(
+            start.curves.len() == end.curves.len()
+                && start.curves.iter().all(|c| end.curve(&c.name).is_some()),
+            "curves of the two markets mismatch"
+        );
+        assert!(
+            start.models.len() == end.models.len()
+                && start
+                    .models
+                    .iter()
+                    .all(|m| end.model(&m.name).is_some_and(|e| same_parameters(m, e))),
+            "models of the two markets mismatch"
+        );
+        ScenarioMorph { start, end }
+    }
+
+    /// The market `weight` of the way from `start` to `end`.
+    pub fn market(&self, weight: f64) -> MarketState {
+        assert!((0.0..=1.0).contains(&weight), "weight out of [0, 1]");
+        let days = self.end.as_of.sub(self.start.as_of) as f64;
+        let as_of = self.start.as_of.add_days((weight * days).round() as i64);
+        let mut market = MarketState::new(as_of, self.start.market_version);
+        for c in &self.start.curves {
+            market
+                .curves
+                .push(morph_curve(c, self.end.curve(&c.name).unwrap(), weight));
+        }
+        for m in &self.start.models {
+            let to = self.end.model(&m.name).unwrap();
+            let parameters = m
+                .parameters
+                .iter()
+                .map(|(p, x)| (p.clone(), x + weight * (to.parameter(p).unwrap() - x)))
+                .collect();
+            market.models.push(ModelSnapshot::new(&m.name, parameters));
+        }
+        market
+    }
+
+    /// The `steps + 1` markets at equally spaced weights from `start` to
+    /// `end`, both included, e.g. one per day of a stress onset.
+    pub fn path(&self, steps: usize) -> Vec<MarketState> {
+        assert!(steps > 0, "no morphing steps");
+        (0..=steps)
+            .map(|k| self.market(k as f64 / steps as f64))
+            .collect()
+    }
+
+    /// Values of a portfolio, as given by `value`, along `path(steps)`.
+    pub fn revalue<F: Fn(&MarketState) -> f64>(
+        &self,
+        steps: usize,
+        value: F,
+    ) -> Vec<MorphingPoint> {
+        let markets = self.path(steps);
+        let base = value(&markets[0]);
+        markets
+            .iter()
+            .enumerate()
+            .map(|(k, market)| {
+                let v = if k == 0 { base } else { value(market) };
+                MorphingPoint {
+                    weight: k as f64 / steps as f64,
+                    as_of: market.as_of,
+                    value: v,
+                    pnl: v - base,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The point of worst profit and loss along a morphing path, the start if
+/// value is never lost.
+pub fn worst_point(points: &[MorphingPoint]) -> MorphingPoint {
+    assert!(!points.is_empty(), "no morphing points");
+    *points.iter().fold(
+        &points[0],
+        |worst, p| if p.pnl < worst.pnl { p } else { worst },
+    )
+}
+
+fn same_parameters(a: &ModelSnapshot, b: &ModelSnapshot) -> bool {
+    a.parameters.len() == b.parameters.len()
+        && a.parameters.iter().all(|(p, _)| b.parameter(p).is_some())
+}
+
+fn morph_curve(from: &CurveSnapshot, to: &CurveSnapshot, weight: f64) -> CurveSnapshot {
+    let mut times: Vec<f64> = from.times.iter().chain(&to.times).cloned().collect();
+    times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    times.dedup();
+    let discounts = times
+        .iter()
+        .map(|t| {
+            let (l1, l2) = (from.discount(*t).ln(), to.discount(*t).ln());
+            (l1 + weight * (l2 - l1)).exp()
+        })
+        .collect();
+    CurveSnapshot::new(&from.name, from.reference_date, times, discounts)
+}
@@ -0,0 +1,133 @@
+use crate::errors::QuantLibError;
+use crate::indexes::MissingFixingPolicy;
+use crate::time::Date;
+use std::cell::RefCell;
+use std::sync::RwLock;
+
+/// The evaluation settings a calculation runs under, in place of a global
+/// evaluation date.
+///
+/// A context can be passed explicitly, entered on the current thread with
+/// `scope` or `enter`, or shared by the whole process with `set_shared`.
+/// `current` resolves them in that order of precedence: the innermost
+/// thread override, then the shared context, then today (only with the
+/// `clock` feature; without it one must be given). Overrides on
+/// different threads are independent, so calculations at several
+/// evaluation dates can run side by side, e.g. the scenarios of a
+/// historical VaR.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PricingContext {
+    pub evaluation_date: Date,
+    /// Whether events falling on the reference date count as occurred.
+    pub include_reference_date_events: bool,
+    /// Whether cash flows paid on the evaluation date are still included.
+    pub include_todays_cashflows: bool,
+    /// How fixings missing before the evaluation date are resolved;
+    /// forecast by default.
+    pub missing_fixing_policy: MissingFixingPolicy,
+}
+
+static SHARED: RwLock<Option<PricingContext>> = RwLock::new(None);
+
+thread_local! {
+    static OVERRIDES: RefCell<Vec<PricingContext>> = const { RefCell::new(Vec::new()) };
+}
+
+impl PricingContext {
+    pub fn new(evaluation_date: Date) -> PricingContext {
+        PricingContext {
+            evaluation_date,
+            include_reference_date_events: false,
+            include_todays_cashflows: false,
+            missing_fixing_policy: MissingFixingPolicy::UseForecast,
+        }
+    }
+
+    /// A context evaluating as of today's date.
+    #[cfg(feature = "clock")]
+    pub fn today() -> PricingContext {
+        PricingContext::new(Date::default())
+    }
+
+    pub fn with_reference_date_events(mut self, include: bool) -> PricingContext {
+        self.include_reference_date_events = include;
+        self
+    }
+
+    pub fn with_todays_cashflows(mut self, include: bool) -> PricingContext {
+        self.include_todays_cashflows = include;
+        self
+    }
+
+    pub fn with_missing_fixing_policy(mut self, policy: MissingFixingPolicy) -> PricingContext {
+        self.missing_fixing_policy = policy;
+        self
+    }
+
+    /// The context in effect on the calling thread, or an error without the
+    /// `clock` feature if none was entered or shared.
+    pub fn checked_current() -> Result<PricingContext, QuantLibError> {
+        match OVERRIDES
+            .with(|o| o.borrow().last().cloned())
+            .or_else(|| *SHARED.read().unwrap())
+        {
+            Some(context) => Ok(context),
+            None => PricingContext::fallback(),
+        }
+    }
+
+    /// The context in effect on the calling thread.
+    pub fn current() -> PricingContext {
+        PricingContext::checked_current().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    #[cfg(feature = "clock")]
+    fn fallback() -> Result<PricingContext, QuantLibError> {
+        Ok(PricingContext::today())
+    }
+
+    #[cfg(not(feature = "clock"))]
+    fn fallback() -> Result<PricingContext, QuantLibError> {
+        Err(QuantLibError::Uninitialized {
+            what: "evaluation date".to_string(),
+        })
+    }
+
+    /// Makes `context` the default of every thread without an override.
+    pub fn set_shared(context: PricingContext) {
+        *SHARED.write().unwrap() = Some(context);
+    }
+
+    /// Removes the shared context, so that threads without an override
+    /// evaluate as of today, or have no context without the `clock` feature.
+    pub fn clear_shared() {
+        *SHARED.write().unwrap() = None;
+    }
+
+    /// Overrides the context of the calling thread until the returned guard
+    /// is dropped. Overrides nest.
+    pub fn enter(&self) -> ContextGuard {
+        OVERRIDES.with(|o| o.borrow_mut().push(*self));
+        ContextGuard { _private: () }
+    }
+
+    /// Runs `f` with this context overriding the one of the calling thread.
+    pub fn scope<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        let _guard = self.enter();
+        f()
+    }
+}
+
+/// Restores the previous context of the thread when dropped, also when
+/// unwinding.
+pub struct ContextGuard {
+    _private: (),
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        OVERRIDES.with(|o| {
+            o.borrow_mut().pop();
+        });
+    }
+}
@@ -0,0 +1,178 @@
+use super::traits::BlackVolTermStructure;
+use crate::definitions::Time;
+
+/// Step of the finite differences in log-moneyness.
+const MONEYNESS_STEP: f64 = 1.0e-3;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ArbitrageKind {
+    /// Negative implied density, i.e. Durrleman's `g(k) < 0`.
+    Butterfly,
+    /// Total variance decreasing with expiry at fixed forward moneyness.
+    Calendar,
+}
+
+/// A grid point where the surface admits arbitrage; `value` is `g(k)` for
+/// butterfly arbitrage and the decrease of total variance since the
+/// previous expiry for calendar arbitrage.
+#[derive(Copy, Clone, Debug)]
+pub struct ArbitrageViolation {
+    pub kind: ArbitrageKind,
+    pub expiry: Time,
+    pub strike: f64,
+    pub log_moneyness: f64,
+    pub value: f64,
+}
+
+/// Contiguous range of offending strikes at one expiry.
+#[derive(Copy, Clone, Debug)]
+pub struct ArbitrageRegion {
+    pub kind: ArbitrageKind,
+    pub expiry: Time,
+    pub min_strike: f64,
+    pub max_strike: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArbitrageReport {
+    pub violations: Vec<ArbitrageViolation>,
+    /// The log-moneyness grid that was scanned.
+    pub log_moneyness: Vec<f64>,
+}
+
+impl ArbitrageReport {
+    pub fn is_arbitrage_free(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn violations_of(&self, kind: ArbitrageKind) -> Vec<ArbitrageViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.kind == kind)
+            .cloned()
+            .collect()
+    }
+
+    /// Offending grid points merged into strike ranges; points are merged
+    /// when they are neighbours on the moneyness grid.
+    pub fn regions(&self) -> Vec<ArbitrageRegion> {
+        let node = |k: f64| self.log_moneyness.iter().position(|&x| x == k).unwrap();
+        let mut regions: Vec<(ArbitrageRegion, usize)> = Vec::new();
+        for v in &self.violations {
+            let i = node(v.log_moneyness);
+            if let Some((region, last)) = regions.last_mut() {
+                if region.kind == v.kind && region.expiry == v.expiry && *last + 1 == i {
+                    region.max_strike = v.strike;
+                    *last = i;
+                    continue;
+                }
+            }
+            let region = ArbitrageRegion {
+                kind: v.kind,
+                expiry: v.expiry,
+                min_strike: v.strike,
+                max_strike: v.strike,
+            };
+            regions.push((region, i));
+        }
+        regions.into_iter().map(|(r, _)| r).collect()
+    }
+}
+
+/// Scans a volatility surface for static arbitrage over a grid of expiries
+/// and forward log-moneyness (Gatheral and Jacquier 2014).
+///
+/// Butterfly arbitrage is detected by the sign of Durrleman's function
+/// `g(k) = (1 - k w'/(2w))^2 - w'^2/4 (1/w + 1/4) + w''/2` of the total
+/// variance `w`, and calendar arbitrage by total variance decreasing
+/// between consecutive expiries.
+#[derive(Clone, Debug)]
+pub struct ArbitrageValidator {
+    pub expiries: Vec<Time>,
+    pub log_moneyness: Vec<f64>,
+    pub tolerance: f64,
+}
+
+impl ArbitrageValidator {
+    pub fn new(expiries: Vec<Time>, log_moneyness: Vec<f64>) -> ArbitrageValidator {
+        assert!(
+            !expiries.is_empty() && !log_moneyness.is_empty(),
+            "empty validation grid"
+        );
+        assert!(
+            expiries[0] > 0.0 && expiries.windows(2).all(|t| t[0] < t[1]),
+            "expiries must be positive and increasing"
+        );
+        assert!(
+            log_moneyness.windows(2).all(|k| k[0] < k[1]),
+            "log-moneyness must be increasing"
+        );
+        ArbitrageValidator {
+            expiries,
+            log_moneyness,
+            tolerance: 1.0e-6,
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> ArbitrageValidator {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Validates `vol`, whose strikes are related to log-moneyness by the
+    /// given `forward` at each expiry.
+    pub fn validate<V, F>(&self, vol: &V, forward: F) -> ArbitrageReport
+    where
+        V: BlackVolTermStructure,
+        F: Fn(Time) -> f64,
+    {
+        let mut report = ArbitrageReport {
+            violations: Vec::new(),
+            log_moneyness: self.log_moneyness.clone(),
+        };
+        for &t in &self.expiries {
+            let f = forward(t);
+            let w = |k: f64| vol.black_variance(t, f * k.exp());
+            for &k in &self.log_moneyness {
+                let g = durrleman(&w, k);
+                if g < -self.tolerance {
+                    report.violations.push(ArbitrageViolation {
+                        kind: ArbitrageKind::Butterfly,
+                        expiry: t,
+                        strike: f * k.exp(),
+                        log_moneyness: k,
+                        value: g,
+                    });
+                }
+            }
+        }
+        for t in self.expiries.windows(2) {
+            let (f1, f2) = (forward(t[0]), forward(t[1]));
+            for &k in &self.log_moneyness {
+                let w1 = vol.black_variance(t[0], f1 * k.exp());
+                let w2 = vol.black_variance(t[1], f2 * k.exp());
+                if w2 - w1 < -self.tolerance {
+                    report.violations.push(ArbitrageViolation {
+                        kind: ArbitrageKind::Calendar,
+                        expiry: t[1],
+                        strike: f2 * k.exp(),
+                        log_moneyness: k,
+                        value: w2 - w1,
+                    });
+                }
+            }
+        }
+        report
+    }
+}
+
+fn durrleman<W: Fn(f64) -> f64>(w: &W, k: f64) -> f64 {
+    let h = MONEYNESS_STEP;
+    let (w_down, w0, w_up) = (w(k - h), w(k), w(k + h));
+    if w0 <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let dw = (w_up - w_down) / (2.0 * h);
+    let d2w = (w_up - 2.0 * w0 + w_down) / (h * h);
+    (1.0 - k * dw / (2.0 * w0)).powi(2) - dw * dw / 4.0 * (1.0 / w0 + 0.25) + d2w / 2.0
+}
@@ -0,0 +1,209 @@
+use super::traits::YieldTermStructure;
+use crate::definitions::Rate;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{
+    BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Period, Schedule, TimeUnit,
+};
+use std::rc::Rc;
+
+/// At-the-money level of a swaption or cap and the annuity it is quoted
+/// against, i.e. the value of one unit of rate paid on the fixed leg or
+/// on every caplet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AtmForward {
+    pub expiry: Date,
+    pub tenor: Period,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub rate: Rate,
+    pub annuity: f64,
+}
+
+/// ATM forward swap rates and cap rates off a forwarding and a discount
+/// curve, for swaption and cap volatility construction.
+///
+/// Swaps start `settlement_days` after expiry and pay a fixed leg of
+/// `fixed_tenor` periods against a floating leg of `floating_tenor`
+/// periods, both rolled backward from the maturity. Caps are built on the
+/// floating schedule and, as quoted in the market, leave out the first
+/// caplet, whose rate is known at the start.
+pub struct AtmRateCalculator<C: Cal, Y: YieldTermStructure, DC: DayCounter, FDC: DayCounter> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub settlement_days: i64,
+    pub convention: BusinessDayConvention,
+    pub fixed_tenor: Period,
+    pub fixed_day_counter: DC,
+    pub floating_tenor: Period,
+    pub floating_day_counter: FDC,
+    pub forwarding_curve: Rc<Y>,
+    pub discounting_curve: Option<Rc<Y>>,
+    pub include_first_caplet: bool,
+}
+
+impl<C, Y, DC, FDC> AtmRateCalculator<C, Y, DC, FDC>
+where
+    C: Cal,
+    Y: YieldTermStructure,
+    DC: DayCounter,
+    FDC: DayCounter,
+{
+    pub fn new(
+        reference_date: Date,
+        calendar: Calendar<C>,
+        settlement_days: i64,
+        fixed_tenor: Period,
+        fixed_day_counter: DC,
+        floating_tenor: Period,
+        floating_day_counter: FDC,
+        forwarding_curve: Rc<Y>,
+    ) -> AtmRateCalculator<C, Y, DC, FDC> {
+        AtmRateCalculator {
+            reference_date,
+            calendar,
+            settlement_days,
+            convention: BusinessDayConvention::ModifiedFollowing,
+            fixed_tenor,
+            fixed_day_counter,
+            floating_tenor,
+            floating_day_counter,
+            forwarding_curve,
+            discounting_curve: None,
+            include_first_caplet: false,
+        }
+    }
+
+    /// Discounts on `curve` instead of the forwarding curve.
+    pub fn with_discounting_curve(mut self, curve: Rc<Y>) -> AtmRateCalculator<C, Y, DC, FDC> {
+        self.discounting_curve = Some(curve);
+        self
+    }
+
+    /// Keeps the first caplet, e.g. for forward starting caps.
+    pub fn with_first_caplet(mut self) -> AtmRateCalculator<C, Y, DC, FDC> {
+        self.include_first_caplet = true;
+        self
+    }
+
+    pub fn discount_curve(&self) -> &Y {
+        self.discounting_curve
+            .as_ref()
+            .unwrap_or(&self.forwarding_curve)
+    }
+
+    /// The option expiry `period` after the reference date.
+    pub fn expiry_date(&self, period: Period) -> Date {
+        self.calendar.advance(
+            self.reference_date,
+            period.length,
+            period.units,
+            self.convention,
+            false,
+        )
+    }
+
+    pub fn start_date(&self, expiry: Date) -> Date {
+        self.calendar.advance(
+            expiry,
+            self.settlement_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Following,
+            false,
+        )
+    }
+
+    fn schedule(&self, start: Date, tenor: Period, leg_tenor: Period) -> Schedule {
+        let end = start.advance(tenor.length, tenor.units);
+        Schedule::new(
+            start,
+            end,
+            leg_tenor,
+            self.calendar,
+            self.convention,
+            self.convention,
+            DateGenerator::Backward,
+            false,
+        )
+    }
+
+    /// Value of the floating leg over the periods between `dates`, and
+    /// its annuity.
+    fn floating_leg(&self, dates: &[Date]) -> (f64, f64) {
+        let (forwarding, discount) = (self.forwarding_curve.as_ref(), self.discount_curve());
+        dates.windows(2).fold((0.0, 0.0), |(value, annuity), w| {
+            let tau = self
+                .floating_day_counter
+                .year_fraction(w[0], w[1], None, None);
+            let growth = forwarding.discount(w[0], true) / forwarding.discount(w[1], true);
+            let weight = tau * discount.discount(w[1], true);
+            (value + weight * (growth - 1.0) / tau, annuity + weight)
+        })
+    }
+
+    /// ATM forward rate and fixed leg annuity of the swap of `tenor`
+    /// underlying a swaption expiring on `expiry`.
+    pub fn swap_rate(&self, expiry: Date, tenor: Period) -> AtmForward {
+        let start = self.start_date(expiry);
+        let floating = self.schedule(start, tenor, self.floating_tenor);
+        let fixed = self.schedule(start, tenor, self.fixed_tenor);
+        let discount = self.discount_curve();
+        let annuity: f64 = fixed
+            .dates
+            .windows(2)
+            .map(|w| {
+                self.fixed_day_counter.year_fraction(w[0], w[1], None, None)
+                    * discount.discount(w[1], true)
+            })
+            .sum();
+        let (value, _) = self.floating_leg(&floating.dates);
+        AtmForward {
+            expiry,
+            tenor,
+            start_date: start,
+            end_date: fixed.dates[fixed.dates.len() - 1],
+            rate: value / annuity,
+            annuity,
+        }
+    }
+
+    /// ATM strike and caplet annuity of the cap of `tenor` starting after
+    /// `expiry`.
+    pub fn cap_rate(&self, expiry: Date, tenor: Period) -> AtmForward {
+        let start = self.start_date(expiry);
+        let schedule = self.schedule(start, tenor, self.floating_tenor);
+        let skip = if self.include_first_caplet { 0 } else { 1 };
+        assert!(
+            schedule.dates.len() > skip + 1,
+            "cap of {} has no caplets",
+            tenor
+        );
+        let (value, annuity) = self.floating_leg(&schedule.dates[skip..]);
+        AtmForward {
+            expiry,
+            tenor,
+            start_date: start,
+            end_date: schedule.dates[schedule.dates.len() - 1],
+            rate: value / annuity,
+            annuity,
+        }
+    }
+
+    /// ATM swap rates for every expiry and tenor, expiries outermost.
+    pub fn swap_rates(&self, expiries: &[Period], tenors: &[Period]) -> Vec<AtmForward> {
+        expiries
+            .iter()
+            .flat_map(|e| {
+                let expiry = self.expiry_date(*e);
+                tenors.iter().map(move |t| self.swap_rate(expiry, *t))
+            })
+            .collect()
+    }
+
+    /// ATM rates of spot starting caps of the given tenors.
+    pub fn cap_rates(&self, tenors: &[Period]) -> Vec<AtmForward> {
+        tenors
+            .iter()
+            .map(|t| self.cap_rate(self.reference_date, *t))
+            .collect()
+    }
+}
@@ -1,5 +1,7 @@
 use super::traits::TermStructure;
 use crate::definitions::Time;
+use crate::settings::PricingContext;
+use crate::time::date;
 use crate::time::traits::Calendar as Cal;
 use crate::time::Actual365Fixed;
 use crate::time::Calendar;
@@ -16,49 +18,43 @@ pub struct Base<C: Cal, DC = Actual365Fixed> {
     pub reference_date: Option<Date>,
 }
 
-//impl<DC: DayCounter> Default for Base<DC> {}
+impl<C: Cal> Default for Base<C, Actual365Fixed> {
+    fn default() -> Base<C, Actual365Fixed> {
+        Base::new(Actual365Fixed {})
+    }
+}
 
 impl<C, DC> Base<C, DC>
 where
     C: Cal,
     DC: DayCounter,
 {
-    pub fn default() -> Base<C, Actual365Fixed> {
-        Base {
-            moving: false,
-            updated: true,
-            settlement_days: 0,
-            day_counter: Actual365Fixed {},
-            calendar: None,
-            reference_date: None,
-        }
-    }
-
     pub fn new(day_counter: DC) -> Base<C, DC> {
         Base {
             moving: false,
             updated: true,
             settlement_days: 0,
-            day_counter: day_counter,
+            day_counter,
             calendar: None,
             reference_date: None,
         }
     }
 
-    pub fn check_range(&self, d: Date, ref_date: Date, max: Date, extrapolate: bool) {
+    pub fn check_range(&self, d: Date, ref_date: Date, max: Date, _extrapolate: bool) {
         assert!(d >= ref_date);
         assert!(d <= max);
     }
-    pub fn check_range_with_time(&self, t: Time, max: Time, extrapolate: bool) {
+    pub fn check_range_with_time(&self, t: Time, max: Time, _extrapolate: bool) {
         assert!(t >= 0.0);
         assert!(t <= max);
     }
 }
 
 impl<C: Cal, DC: DayCounter> TermStructure for Base<C, DC> {
-    /// The latest date for which the curve can return values.
+    /// The latest date for which the curve can return values; the base
+    /// structure sets no limit.
     fn max_date(&self) -> Date {
-        Date::default()
+        date::MAX_DATE
     }
 
     /// The settlement days used for reference date calculation.
@@ -79,11 +75,16 @@ impl<C: Cal, DC: DayCounter> TermStructure for Base<C, DC> {
     }
 
     /// The date at which discount = 1.0 and/or variance = 0.0.
+    ///
+    /// Structures without a fixed reference date advance it from the
+    /// evaluation date of the current `PricingContext`; moving ones follow
+    /// that date as it changes.
     fn reference_date(&mut self) -> Date {
-        if !self.updated {
+        if self.moving || !self.updated {
+            let today = PricingContext::current().evaluation_date;
             self.reference_date = Some(self.calendar.unwrap().advance_by_units(
-                Date::default(),
-                self.settlement_days as usize,
+                today,
+                self.settlement_days,
                 TimeUnit::Days,
             ));
             self.updated = true;
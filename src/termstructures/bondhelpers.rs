@@ -0,0 +1,173 @@
+use super::traits::RateHelper;
+use crate::cashflows::{CashFlow, FixedRateCoupon, FixedRateLeg, Leg};
+use crate::definitions::{DiscountFactor, Rate};
+use crate::quotes::Quote;
+use crate::time::{Date, DayCounter, Schedule};
+use std::collections::BTreeMap;
+
+/// Clean price quote of a bond, per 100 of face, settling on
+/// `settlement_date`.
+///
+/// Coupons paid on or before settlement belong to the seller; the accrued
+/// interest of the running coupon is taken off the dirty price. The
+/// redemption is paid on the maturity date, which is the pillar.
+pub struct BondHelper<Q: Quote, CF: CashFlow> {
+    pub quote: Q,
+    pub coupons: Leg<CF>,
+    pub face_amount: f64,
+    /// Redemption per 100 of face.
+    pub redemption: f64,
+    pub maturity_date: Date,
+    pub settlement_date: Date,
+    /// Preference among issues maturing on the same date; zero excludes
+    /// the issue from the curve.
+    pub weight: f64,
+    pub on_the_run: bool,
+}
+
+/// Helper on a fixed rate bond.
+pub type FixedRateBondHelper<Q, DC> = BondHelper<Q, FixedRateCoupon<DC>>;
+
+impl<Q: Quote, CF: CashFlow> BondHelper<Q, CF> {
+    pub fn new(
+        quote: Q,
+        coupons: Leg<CF>,
+        face_amount: f64,
+        maturity_date: Date,
+        settlement_date: Date,
+    ) -> BondHelper<Q, CF> {
+        assert!(face_amount > 0.0, "non positive face amount");
+        assert!(
+            settlement_date < maturity_date,
+            "bond settles after its maturity"
+        );
+        BondHelper {
+            quote,
+            coupons,
+            face_amount,
+            redemption: 100.0,
+            maturity_date,
+            settlement_date,
+            weight: 1.0,
+            on_the_run: true,
+        }
+    }
+
+    pub fn with_redemption(mut self, redemption: f64) -> BondHelper<Q, CF> {
+        self.redemption = redemption;
+        self
+    }
+
+    pub fn with_weight(mut self, weight: f64) -> BondHelper<Q, CF> {
+        assert!(weight >= 0.0, "negative weight");
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_on_the_run(mut self, on_the_run: bool) -> BondHelper<Q, CF> {
+        self.on_the_run = on_the_run;
+        self
+    }
+
+    /// Interest accrued at settlement, per 100 of face.
+    pub fn accrued_amount(&self) -> f64 {
+        let accrued: f64 = self
+            .coupons
+            .iter()
+            .filter_map(|c| c.try_as_coup())
+            .map(|c| c.accrued_amount(self.settlement_date))
+            .sum();
+        accrued * 100.0 / self.face_amount
+    }
+
+    /// Payments after settlement as (date, amount) pairs.
+    fn flows(&self) -> Vec<(Date, f64)> {
+        let mut flows: Vec<(Date, f64)> = self
+            .coupons
+            .iter()
+            .filter(|c| c.date() > self.settlement_date)
+            .map(|c| (c.date(), c.amount()))
+            .collect();
+        flows.push((
+            self.maturity_date,
+            self.face_amount * self.redemption / 100.0,
+        ));
+        flows
+    }
+}
+
+impl<Q: Quote, DC: DayCounter> BondHelper<Q, FixedRateCoupon<DC>> {
+    /// Bond paying `coupon` on `schedule`, maturing on its last date.
+    pub fn fixed_rate(
+        quote: Q,
+        schedule: Schedule,
+        coupon: Rate,
+        day_counter: DC,
+        face_amount: f64,
+        settlement_date: Date,
+    ) -> FixedRateBondHelper<Q, DC> {
+        let maturity_date = schedule.dates[schedule.dates.len() - 1];
+        let coupons = FixedRateLeg::new(schedule, day_counter)
+            .with_notional(face_amount)
+            .with_rate(coupon)
+            .build();
+        BondHelper::new(quote, coupons, face_amount, maturity_date, settlement_date)
+    }
+}
+
+impl<Q: Quote, CF: CashFlow> RateHelper for BondHelper<Q, CF> {
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid bond price");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        let npv: f64 = self.flows().iter().map(|(d, a)| a * discount(*d)).sum();
+        let dirty = npv / discount(self.settlement_date) * 100.0 / self.face_amount;
+        dirty - self.accrued_amount()
+    }
+    fn pillar_date(&self) -> Date {
+        self.maturity_date
+    }
+    /// The yield of the undiscounted payments over the dirty price.
+    fn initial_forward(&self) -> Rate {
+        let total: f64 = self.flows().iter().map(|(_, a)| a).sum();
+        let dirty = (self.quote() + self.accrued_amount()) * self.face_amount / 100.0;
+        let t = self.maturity_date.sub(self.settlement_date) as f64 / 365.0;
+        (total / dirty).ln() / t
+    }
+}
+
+/// The bonds of `helpers` to bootstrap a curve on, ordered by maturity.
+///
+/// A bootstrap reprices one instrument per pillar exactly, so weights
+/// choose between issues maturing on the same date rather than
+/// averaging them: the heaviest one is kept, the first of equal weights.
+/// Issues of zero weight, and off-the-run ones unless asked for, are left
+/// out.
+pub fn bond_curve_helpers<'a, Q, CF>(
+    helpers: &'a [BondHelper<Q, CF>],
+    include_off_the_run: bool,
+) -> Vec<&'a dyn RateHelper>
+where
+    Q: Quote + 'a,
+    CF: CashFlow + 'a,
+{
+    let mut selected: BTreeMap<Date, &'a BondHelper<Q, CF>> = BTreeMap::new();
+    for h in helpers {
+        if h.weight == 0.0 || (!h.on_the_run && !include_off_the_run) {
+            continue;
+        }
+        selected
+            .entry(h.maturity_date)
+            .and_modify(|s| {
+                if h.weight > s.weight {
+                    *s = h;
+                }
+            })
+            .or_insert(h);
+    }
+    selected
+        .into_values()
+        .map(|h| h as &dyn RateHelper)
+        .collect()
+}
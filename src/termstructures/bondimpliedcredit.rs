@@ -0,0 +1,142 @@
+use super::hazardratecurve::HazardRateCurve;
+use super::traits::YieldTermStructure;
+use crate::cashflows::{CashFlow, Leg};
+use crate::time::{Date, DayCounter};
+
+/// Highest hazard rate searched when fitting a bond.
+const MAX_HAZARD_RATE: f64 = 5.0;
+
+/// Quoted dirty price of a defaultable bond paying `cashflows` and its
+/// `notional` at the last cash flow date; the holder recovers a fraction
+/// of the notional on default.
+#[derive(Clone)]
+pub struct BondCreditHelper<CF: CashFlow> {
+    pub cashflows: Leg<CF>,
+    pub notional: f64,
+    pub dirty_price: f64,
+}
+
+impl<CF: CashFlow> BondCreditHelper<CF> {
+    pub fn new(cashflows: Leg<CF>, notional: f64, dirty_price: f64) -> BondCreditHelper<CF> {
+        assert!(!cashflows.is_empty(), "no cash flows given");
+        BondCreditHelper {
+            cashflows,
+            notional,
+            dirty_price,
+        }
+    }
+
+    pub fn maturity_date(&self) -> Date {
+        self.cashflows.iter().map(|c| c.date()).max().unwrap()
+    }
+
+    /// Price of the bond under `hazard`: surviving flows are discounted and
+    /// the recovery is paid in the middle of each month it defaults in.
+    pub fn risky_price<Y: YieldTermStructure, DC: DayCounter>(
+        &self,
+        discount_curve: &Y,
+        hazard: &HazardRateCurve<DC>,
+        recovery_rate: f64,
+    ) -> f64 {
+        let reference = hazard.reference_date;
+        let maturity = self.maturity_date();
+        let flows: f64 = self
+            .cashflows
+            .iter()
+            .filter(|c| c.date() > reference)
+            .map(|c| {
+                c.amount()
+                    * discount_curve.discount(c.date(), true)
+                    * hazard.survival_probability(c.date())
+            })
+            .sum();
+        let redemption = self.notional
+            * discount_curve.discount(maturity, true)
+            * hazard.survival_probability(maturity);
+
+        let mut recovery = 0.0;
+        let mut start = reference;
+        while start < maturity {
+            let end = std::cmp::min(start.add_months(1), maturity);
+            let middle = start.add_days(end.sub(start) / 2);
+            recovery +=
+                discount_curve.discount(middle, true) * hazard.default_probability(start, end);
+            start = end;
+        }
+        flows + redemption + recovery_rate * self.notional * recovery
+    }
+}
+
+/// Hazard rate curve fitted to bond prices, with the model minus quoted
+/// price of each bond in maturity order.
+#[derive(Clone, Debug)]
+pub struct BondImpliedCredit<DC: DayCounter> {
+    pub curve: HazardRateCurve<DC>,
+    pub errors: Vec<f64>,
+}
+
+/// Bootstraps a piecewise flat hazard rate curve, one node per bond
+/// maturity, so that each bond reprices off `discount_curve` with the given
+/// recovery rate; useful when no CDS is quoted for the issuer.
+///
+/// Bonds trading above their risk-free value get a zero hazard rate, and
+/// the remaining mismatch shows in the errors.
+pub fn fit_hazard_rates<CF: CashFlow, Y: YieldTermStructure, DC: DayCounter>(
+    bonds: &[BondCreditHelper<CF>],
+    discount_curve: &Y,
+    recovery_rate: f64,
+    reference_date: Date,
+    day_counter: DC,
+) -> BondImpliedCredit<DC> {
+    assert!(!bonds.is_empty(), "no bonds given");
+    assert!(
+        (0.0..1.0).contains(&recovery_rate),
+        "recovery rate must be in [0, 1)"
+    );
+    let mut order: Vec<usize> = (0..bonds.len()).collect();
+    order.sort_by_key(|&i| bonds[i].maturity_date());
+    assert!(
+        order
+            .windows(2)
+            .all(|w| bonds[w[0]].maturity_date() < bonds[w[1]].maturity_date()),
+        "bond maturities must be distinct"
+    );
+
+    let mut dates = vec![];
+    let mut rates = vec![];
+    for &i in &order {
+        let bond = &bonds[i];
+        dates.push(bond.maturity_date());
+        let error = |h: f64| {
+            let mut r = rates.clone();
+            r.push(h);
+            let curve = HazardRateCurve::new(reference_date, dates.clone(), r, day_counter);
+            bond.risky_price(discount_curve, &curve, recovery_rate) - bond.dirty_price
+        };
+        let (mut low, mut high) = (0.0, MAX_HAZARD_RATE);
+        let h = if error(low) <= 0.0 {
+            low
+        } else if error(high) >= 0.0 {
+            high
+        } else {
+            while high - low > 1.0e-12 {
+                let middle = 0.5 * (low + high);
+                if error(middle) > 0.0 {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            0.5 * (low + high)
+        };
+        rates.push(h);
+    }
+    let curve = HazardRateCurve::new(reference_date, dates, rates, day_counter);
+    let errors = order
+        .iter()
+        .map(|&i| {
+            bonds[i].risky_price(discount_curve, &curve, recovery_rate) - bonds[i].dirty_price
+        })
+        .collect();
+    BondImpliedCredit { curve, errors }
+}
@@ -0,0 +1,89 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use crate::math::{Matrix, PrincipalComponents};
+use crate::time::traits::Calendar as Cal;
+use crate::time::DayCounter;
+
+/// Principal components of the daily moves of continuously compounded
+/// zero rates at the curve nodes after the reference date, used to shock a
+/// curve along its historical factors (level, slope, curvature...).
+#[derive(Clone)]
+pub struct PcaCurveScenarioGenerator<C: Cal, DC: DayCounter> {
+    pub curve: InterpolatedDiscountCurve<C, DC>,
+    pub pca: PrincipalComponents,
+}
+
+impl<C: Cal, DC: DayCounter> PcaCurveScenarioGenerator<C, DC> {
+    /// `history` holds one row of node zero rates per date, oldest first.
+    pub fn new(
+        curve: InterpolatedDiscountCurve<C, DC>,
+        history: &Matrix,
+    ) -> PcaCurveScenarioGenerator<C, DC> {
+        let nodes = curve.times.len() - 1;
+        assert!(
+            history.columns() == nodes,
+            "history and curve nodes mismatch"
+        );
+        assert!(
+            history.rows() >= 3,
+            "at least three dates of history needed"
+        );
+        let mut moves = Matrix::new(history.rows() - 1, nodes, 0.0);
+        for k in 1..history.rows() {
+            for j in 0..nodes {
+                moves[(k - 1, j)] = history[(k, j)] - history[(k - 1, j)];
+            }
+        }
+        PcaCurveScenarioGenerator {
+            curve,
+            pca: PrincipalComponents::new(&moves),
+        }
+    }
+
+    /// Zero rate moves at the nodes for `shocks[k]` standard deviations
+    /// along component `k`.
+    pub fn node_shifts(&self, shocks: &[f64]) -> Vec<f64> {
+        let nodes = self.pca.mean.len();
+        assert!(shocks.len() <= nodes, "more shocks than components");
+        (0..nodes)
+            .map(|j| {
+                shocks
+                    .iter()
+                    .enumerate()
+                    .map(|(k, s)| s * self.pca.variances[k].sqrt() * self.pca.loadings[(j, k)])
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// The curve with its node zero rates shifted by `node_shifts(shocks)`,
+    /// interpolated as the base curve.
+    pub fn scenario(&self, shocks: &[f64]) -> InterpolatedDiscountCurve<C, DC> {
+        let shifts = self.node_shifts(shocks);
+        let mut discounts = vec![self.curve.discounts[0]];
+        for (j, shift) in shifts.iter().enumerate() {
+            let t = self.curve.times[j + 1];
+            discounts.push(self.curve.discounts[j + 1] * (-shift * t).exp());
+        }
+        InterpolatedDiscountCurve::new(
+            self.curve.dates.clone(),
+            discounts,
+            self.curve.calendar,
+            self.curve.day_counter,
+            self.curve.interpolation,
+        )
+    }
+
+    /// Up and down scenarios of `size` standard deviations along each of
+    /// the first `factors` components, in that order.
+    pub fn scenarios(&self, factors: usize, size: f64) -> Vec<InterpolatedDiscountCurve<C, DC>> {
+        let mut curves = Vec::with_capacity(2 * factors);
+        for k in 0..factors {
+            let mut shocks = vec![0.0; k + 1];
+            for &sign in &[1.0, -1.0] {
+                shocks[k] = sign * size;
+                curves.push(self.scenario(&shocks));
+            }
+        }
+        curves
+    }
+}
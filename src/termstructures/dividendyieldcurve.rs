@@ -0,0 +1,161 @@
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::math::simpson;
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+use std::rc::Rc;
+
+/// Simpson intervals per year of the integral of a functional yield.
+const INTERVALS_PER_YEAR: f64 = 64.0;
+
+/// Continuously compounded instantaneous dividend yield.
+#[derive(Clone)]
+pub enum DividendYield {
+    /// `yields[i]` applies up to `times[i]`, the last one also after it.
+    PiecewiseConstant {
+        times: Vec<Time>,
+        yields: Vec<Rate>,
+    },
+    Function(Rc<dyn Fn(Time) -> Rate>),
+}
+
+impl DividendYield {
+    pub fn value(&self, t: Time) -> Rate {
+        match self {
+            DividendYield::PiecewiseConstant { times, yields } => {
+                let k = times.iter().filter(|s| **s < t).count();
+                yields[k.min(yields.len() - 1)]
+            }
+            DividendYield::Function(f) => f(t),
+        }
+    }
+
+    /// Integral of the yield from zero to `t`.
+    pub fn integral(&self, t: Time) -> f64 {
+        match self {
+            DividendYield::PiecewiseConstant { times, yields } => {
+                let mut integral = 0.0;
+                let mut start = 0.0;
+                for (k, q) in yields.iter().enumerate() {
+                    let end = if k + 1 < yields.len() {
+                        times[k]
+                    } else {
+                        f64::INFINITY
+                    };
+                    integral += q * (t.min(end) - start).max(0.0);
+                    if t <= end {
+                        break;
+                    }
+                    start = end;
+                }
+                integral
+            }
+            DividendYield::Function(f) => {
+                if t <= 0.0 {
+                    return 0.0;
+                }
+                let intervals = (t * INTERVALS_PER_YEAR).ceil() as usize;
+                simpson(|s: f64| f(s), 0.0, t, intervals)
+            }
+        }
+    }
+}
+
+/// Dividend yield curve of an equity or index, discounting the forward
+/// by the integrated yield.
+#[derive(Clone)]
+pub struct DividendYieldCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub dividend_yield: DividendYield,
+}
+
+impl<C: Cal, DC: DayCounter> DividendYieldCurve<C, DC> {
+    /// Annualized yields, `yields[i]` applying up to `dates[i]` and the
+    /// last one also after it.
+    pub fn piecewise_constant(
+        reference_date: Date,
+        dates: Vec<Date>,
+        yields: Vec<Rate>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> DividendYieldCurve<C, DC> {
+        assert!(!yields.is_empty(), "no yields given");
+        assert!(
+            dates.len() == yields.len(),
+            "dates and yields size mismatch"
+        );
+        assert!(
+            dates.first().is_none_or(|d| *d > reference_date)
+                && dates.windows(2).all(|w| w[0] < w[1]),
+            "dates must be increasing and after the reference date"
+        );
+        let times = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        DividendYieldCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            dividend_yield: DividendYield::PiecewiseConstant { times, yields },
+        }
+    }
+
+    /// Instantaneous yield given as a function of time.
+    pub fn functional<F: Fn(Time) -> Rate + 'static>(
+        reference_date: Date,
+        dividend_yield: F,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> DividendYieldCurve<C, DC> {
+        DividendYieldCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            dividend_yield: DividendYield::Function(Rc::new(dividend_yield)),
+        }
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    pub fn instantaneous_yield(&self, t: Time) -> Rate {
+        self.dividend_yield.value(t)
+    }
+
+    /// Continuously compounded average yield to `t`.
+    pub fn zero_yield(&self, t: Time) -> Rate {
+        if t <= 0.0 {
+            return self.instantaneous_yield(0.0);
+        }
+        self.dividend_yield.integral(t) / t
+    }
+
+    pub fn discount_with_time(&self, t: Time) -> DiscountFactor {
+        (-self.dividend_yield.integral(t)).exp()
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date))
+    }
+
+    /// A term structure discounting with this curve, e.g. as the dividend
+    /// curve of a Black-Scholes process.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        let dividend_yield = self.dividend_yield.clone();
+        YieldTermStructure::new(
+            self.calendar,
+            self.reference_date,
+            self.day_counter,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| (-dividend_yield.integral(t)).exp()),
+        )
+    }
+}
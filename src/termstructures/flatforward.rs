@@ -0,0 +1,168 @@
+use super::base::Base;
+use super::compounding::Compounding;
+use super::interestrate::InterestRate;
+use super::traits::TermStructure;
+use super::traits::YieldTermStructure as YTS;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::errors::{check_discount, check_time_range, QuantLibError};
+use crate::patterns::{ChangeFlag, Observable, Observer};
+use crate::quotes::{Quote, SimpleQuote};
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter, Frequency};
+use std::rc::Rc;
+
+/// Curve with a single forward rate, quoted with the given day counter,
+/// compounding and frequency, at all maturities.
+///
+/// Changes of the forward quote are passed on to the observers of the
+/// curve.
+pub struct FlatForward<C: Cal, Q: Quote = SimpleQuote, DC: DayCounter = Actual365Fixed> {
+    base: Base<C, DC>,
+    pub forward: Q,
+    pub compounding: Compounding,
+    pub frequency: Frequency,
+    changes: Rc<ChangeFlag>,
+}
+
+fn observe<Q: Quote>(quote: &Q) -> Rc<ChangeFlag> {
+    let changes = ChangeFlag::new();
+    if let Some(observable) = quote.observable() {
+        observable.register_observer(Rc::clone(&changes) as Rc<dyn Observer>);
+    }
+    changes
+}
+
+impl<C, Q, DC> FlatForward<C, Q, DC>
+where
+    C: Cal,
+    Q: Quote,
+    DC: DayCounter,
+{
+    pub fn new(
+        reference_date: Date,
+        forward: Q,
+        day_counter: DC,
+        compounding: Compounding,
+        frequency: Frequency,
+        calendar: Calendar<C>,
+    ) -> FlatForward<C, Q, DC> {
+        let mut base = Base::new(day_counter);
+        base.calendar = Some(calendar);
+        base.reference_date = Some(reference_date);
+        FlatForward {
+            base,
+            changes: observe(&forward),
+            forward,
+            compounding,
+            frequency,
+        }
+    }
+
+    /// A curve whose reference date is `settlement_days` business days
+    /// after the evaluation date of the current `PricingContext`, moving
+    /// with it.
+    pub fn moving(
+        settlement_days: i64,
+        calendar: Calendar<C>,
+        forward: Q,
+        day_counter: DC,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> FlatForward<C, Q, DC> {
+        let mut base = Base::new(day_counter);
+        base.calendar = Some(calendar);
+        base.settlement_days = settlement_days;
+        base.moving = true;
+        base.reference_date();
+        FlatForward {
+            base,
+            changes: observe(&forward),
+            forward,
+            compounding,
+            frequency,
+        }
+    }
+
+    /// The forward as an interest rate.
+    pub fn rate(&self) -> InterestRate<DC> {
+        assert!(self.forward.is_valid(), "invalid forward quote");
+        InterestRate::new(
+            self.forward.value(),
+            self.base.day_counter,
+            self.compounding,
+            self.frequency,
+        )
+    }
+}
+
+impl<C: Cal, DC: DayCounter> FlatForward<C, SimpleQuote, DC> {
+    /// A curve on a constant rate rather than a quote.
+    pub fn with_rate(
+        reference_date: Date,
+        rate: Rate,
+        day_counter: DC,
+        compounding: Compounding,
+        frequency: Frequency,
+        calendar: Calendar<C>,
+    ) -> FlatForward<C, SimpleQuote, DC> {
+        Self::new(
+            reference_date,
+            SimpleQuote::new(rate),
+            day_counter,
+            compounding,
+            frequency,
+            calendar,
+        )
+    }
+}
+
+impl<C, Q, DC> YTS for FlatForward<C, Q, DC>
+where
+    C: Cal,
+    Q: Quote,
+    DC: DayCounter,
+{
+    type D = DC;
+    fn day_counter(&self) -> Self::D {
+        self.base.day_counter
+    }
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        _extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        check_time_range(time, 0.0, true)?;
+        if !self.forward.is_valid() {
+            return Err(QuantLibError::InvalidQuote {
+                what: "forward".to_string(),
+            });
+        }
+        check_discount(1.0 / self.rate().compound_factor_with_time(time))
+    }
+}
+
+impl<C, Q, DC> TermStructure for FlatForward<C, Q, DC>
+where
+    C: Cal,
+    Q: Quote,
+    DC: DayCounter,
+{
+    fn max_date(&self) -> Date {
+        self.base.max_date()
+    }
+    fn settlement_days(&self) -> i64 {
+        self.base.settlement_days()
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.base.time_from_reference(date)
+    }
+    fn max_time(&self) -> Time {
+        self.base.max_time()
+    }
+    fn reference_date(&mut self) -> Date {
+        self.base.reference_date()
+    }
+    fn observable(&self) -> Option<&Observable> {
+        Some(self.changes.observable())
+    }
+}
@@ -0,0 +1,226 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use super::traits::RateHelper;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::math::Interpolation;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{
+    is_main_cycle, third_wednesday, Actual360, Actual365Fixed, Calendar, Date, DayCounter, Month,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FuturesCycle {
+    /// Contracts starting in the months between the quarterly ones.
+    Serial,
+    /// Contracts starting in March, June, September and December.
+    Quarterly,
+}
+
+/// Short term interest rate future on the rate between `start` and `end`,
+/// quoted as 100 minus the rate in percent.
+#[derive(Copy, Clone, Debug)]
+pub struct StirFuture<DC: DayCounter = Actual360> {
+    pub start: Date,
+    pub end: Date,
+    pub price: f64,
+    pub cycle: FuturesCycle,
+    pub day_counter: DC,
+    pub convexity_adjustment: Rate,
+}
+
+impl<DC: DayCounter> StirFuture<DC> {
+    pub fn new(
+        start: Date,
+        end: Date,
+        price: f64,
+        cycle: FuturesCycle,
+        day_counter: DC,
+    ) -> StirFuture<DC> {
+        assert!(end > start, "contract must end after it starts");
+        StirFuture {
+            start,
+            end,
+            price,
+            cycle,
+            day_counter,
+            convexity_adjustment: 0.0,
+        }
+    }
+
+    /// The IMM contract starting on the third Wednesday of `month` and
+    /// running for `months` months.
+    pub fn imm(
+        month: Month,
+        year: i32,
+        months: i64,
+        price: f64,
+        day_counter: DC,
+    ) -> StirFuture<DC> {
+        let start = third_wednesday(month, year);
+        let last = start.add_months(months);
+        let end = third_wednesday(last.month(), last.year() as i32);
+        let cycle = if is_main_cycle(month) {
+            FuturesCycle::Quarterly
+        } else {
+            FuturesCycle::Serial
+        };
+        StirFuture::new(start, end, price, cycle, day_counter)
+    }
+
+    pub fn with_convexity_adjustment(mut self, adjustment: Rate) -> StirFuture<DC> {
+        self.convexity_adjustment = adjustment;
+        self
+    }
+
+    pub fn accrual(&self) -> Time {
+        self.day_counter
+            .year_fraction(self.start, self.end, None, None)
+    }
+
+    pub fn futures_rate(&self) -> Rate {
+        (100.0 - self.price) / 100.0
+    }
+
+    /// The futures rate less the convexity adjustment.
+    pub fn forward_rate(&self) -> Rate {
+        self.futures_rate() - self.convexity_adjustment
+    }
+}
+
+/// Hull-White futures convexity bias of a contract on the rate from `t` to
+/// `maturity`, to be subtracted from the futures rate.
+pub fn hull_white_convexity_bias(
+    futures_price: f64,
+    t: Time,
+    maturity: Time,
+    sigma: f64,
+    a: f64,
+) -> Rate {
+    assert!(futures_price >= 0.0, "negative futures price");
+    assert!(t >= 0.0 && maturity > t, "invalid contract times");
+    assert!(
+        sigma >= 0.0 && a >= 0.0,
+        "negative volatility or mean reversion"
+    );
+    let tau = maturity - t;
+    let b = |x: Time| {
+        if a < 1.0e-8 {
+            x
+        } else {
+            (1.0 - (-a * x).exp()) / a
+        }
+    };
+    let half_variance = 0.5 * sigma * sigma;
+    let lambda = half_variance * b(2.0 * t) * b(tau) * b(tau);
+    let phi = half_variance * b(tau) * b(t) * b(t);
+    let futures_rate = (100.0 - futures_price) / 100.0;
+    (1.0 - (-(lambda + phi)).exp()) * (futures_rate + 1.0 / tau)
+}
+
+/// Chain of STIR futures stitched into discount factors from the reference
+/// date: a deposit stub runs to the first contract, serial contracts fill
+/// the months up to the first quarterly one and quarterly contracts carry
+/// on from there. Gaps between contracts accrue at the previous forward.
+#[derive(Clone, Debug)]
+pub struct FuturesStrip<DC: DayCounter = Actual360> {
+    pub reference_date: Date,
+    pub stub_rate: Rate,
+    pub contracts: Vec<StirFuture<DC>>,
+}
+
+impl<DC: DayCounter> FuturesStrip<DC> {
+    /// Keeps the contracts starting after `reference_date` that do not
+    /// overlap an earlier kept one; serial contracts ending after the
+    /// first quarterly contract starts are dropped.
+    pub fn new(
+        reference_date: Date,
+        stub_rate: Rate,
+        contracts: Vec<StirFuture<DC>>,
+    ) -> FuturesStrip<DC> {
+        let mut live: Vec<StirFuture<DC>> = contracts
+            .into_iter()
+            .filter(|c| c.start >= reference_date)
+            .collect();
+        live.sort_by_key(|c| c.start);
+        let first_quarterly = live
+            .iter()
+            .find(|c| c.cycle == FuturesCycle::Quarterly)
+            .map(|c| c.start);
+        let mut chain: Vec<StirFuture<DC>> = vec![];
+        for cycle in &[FuturesCycle::Serial, FuturesCycle::Quarterly] {
+            for c in live.iter().filter(|c| c.cycle == *cycle) {
+                let fits_before = match (c.cycle, first_quarterly) {
+                    (FuturesCycle::Serial, Some(start)) => c.end <= start,
+                    _ => true,
+                };
+                let after_last = chain.last().map(|last| c.start >= last.end);
+                if fits_before && after_last != Some(false) {
+                    chain.push(*c);
+                }
+            }
+        }
+        assert!(!chain.is_empty(), "no live contracts given");
+        FuturesStrip {
+            reference_date,
+            stub_rate,
+            contracts: chain,
+        }
+    }
+
+    /// Sets each contract's convexity adjustment from a Hull-White model,
+    /// with Actual/365 times from the reference date.
+    pub fn with_hull_white_convexity(mut self, a: f64, sigma: f64) -> FuturesStrip<DC> {
+        let reference = self.reference_date;
+        let time = |d: Date| Actual365Fixed {}.year_fraction(reference, d, None, None);
+        for c in self.contracts.iter_mut() {
+            c.convexity_adjustment =
+                hull_white_convexity_bias(c.price, time(c.start), time(c.end), sigma, a);
+        }
+        self
+    }
+
+    pub fn end_date(&self) -> Date {
+        self.contracts[self.contracts.len() - 1].end
+    }
+
+    /// Whether the strip already determines the curve up to `date`.
+    pub fn covers(&self, date: Date) -> bool {
+        date <= self.end_date()
+    }
+
+    /// The discount factors implied at the reference date and at each
+    /// contract start and end.
+    pub fn nodes(&self) -> Vec<(Date, DiscountFactor)> {
+        let mut nodes = vec![(self.reference_date, 1.0)];
+        let mut rate = self.stub_rate;
+        for c in &self.contracts {
+            let (last, discount) = nodes[nodes.len() - 1];
+            if c.start > last {
+                let tau = c.day_counter.year_fraction(last, c.start, None, None);
+                nodes.push((c.start, discount / (1.0 + rate * tau)));
+            }
+            rate = c.forward_rate();
+            let start = nodes[nodes.len() - 1].1;
+            nodes.push((c.end, start / (1.0 + rate * c.accrual())));
+        }
+        nodes
+    }
+
+    pub fn discount_curve<C: Cal>(
+        &self,
+        calendar: Calendar<C>,
+        interpolation: Interpolation,
+    ) -> InterpolatedDiscountCurve<C> {
+        let (dates, discounts) = self.nodes().into_iter().unzip();
+        InterpolatedDiscountCurve::new(dates, discounts, calendar, Actual365Fixed {}, interpolation)
+    }
+
+    /// The helpers, e.g. FRAs and swaps, left to bootstrap once the strip
+    /// is used: those whose pillar falls within the strip are dropped, as
+    /// the futures are the more liquid quotes there.
+    pub fn prioritize<'a>(&self, helpers: Vec<&'a dyn RateHelper>) -> Vec<&'a dyn RateHelper> {
+        helpers
+            .into_iter()
+            .filter(|h| !self.covers(h.pillar_date()))
+            .collect()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::definitions::{Rate, Time};
+use crate::time::{Actual365Fixed, Date, DayCounter};
+
+/// Default intensity curve, flat between node dates and after the last
+/// one; `hazard_rates[i]` applies up to `dates[i]`.
+#[derive(Clone, Debug)]
+pub struct HazardRateCurve<DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub day_counter: DC,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub hazard_rates: Vec<Rate>,
+}
+
+impl<DC: DayCounter> HazardRateCurve<DC> {
+    pub fn new(
+        reference_date: Date,
+        dates: Vec<Date>,
+        hazard_rates: Vec<Rate>,
+        day_counter: DC,
+    ) -> HazardRateCurve<DC> {
+        assert!(!dates.is_empty(), "no hazard rates given");
+        assert!(
+            dates.len() == hazard_rates.len(),
+            "dates and hazard rates size mismatch"
+        );
+        assert!(
+            dates[0] > reference_date && dates.windows(2).all(|d| d[0] < d[1]),
+            "node dates must be increasing and after the reference date"
+        );
+        assert!(
+            hazard_rates.iter().all(|h| *h >= 0.0),
+            "negative hazard rate"
+        );
+        let times = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        HazardRateCurve {
+            reference_date,
+            day_counter,
+            dates,
+            times,
+            hazard_rates,
+        }
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    pub fn hazard_rate(&self, t: Time) -> Rate {
+        let i = self
+            .times
+            .iter()
+            .position(|x| *x >= t)
+            .unwrap_or(self.times.len() - 1);
+        self.hazard_rates[i]
+    }
+
+    pub fn survival_probability_with_time(&self, t: Time) -> f64 {
+        assert!(t >= 0.0, "negative time");
+        let mut integral = 0.0;
+        let mut previous = 0.0;
+        for (i, &node) in self.times.iter().enumerate() {
+            if t <= node {
+                return (-(integral + self.hazard_rates[i] * (t - previous))).exp();
+            }
+            integral += self.hazard_rates[i] * (node - previous);
+            previous = node;
+        }
+        let last = self.hazard_rates[self.hazard_rates.len() - 1];
+        (-(integral + last * (t - previous))).exp()
+    }
+
+    pub fn survival_probability(&self, date: Date) -> f64 {
+        self.survival_probability_with_time(self.time_from_reference(date))
+    }
+
+    pub fn default_probability(&self, d1: Date, d2: Date) -> f64 {
+        assert!(d2 >= d1, "d1 must not be later than d2");
+        self.survival_probability(d1) - self.survival_probability(d2)
+    }
+
+    /// Running spread of a CDS to `date` by the credit triangle, i.e. the
+    /// loss given default times the average hazard rate.
+    pub fn implied_cds_spread(&self, date: Date, recovery_rate: f64) -> Rate {
+        let t = self.time_from_reference(date);
+        assert!(t > 0.0, "date must be after the reference date");
+        (1.0 - recovery_rate) * -self.survival_probability_with_time(t).ln() / t
+    }
+}
@@ -1,6 +1,7 @@
 use super::Compounding;
 use crate::definitions::{Rate, Time};
-use crate::time::{Actual365Fixed, Date, DayCounter, Frequency};
+use crate::time::{Date, DayCounter, Frequency};
+use std::fmt;
 
 #[derive(Copy, Clone)]
 pub struct InterestRate<DC: DayCounter> {
@@ -26,13 +27,26 @@ where
         }
         InterestRate {
             rate: r,
-            day_counter: day_counter,
+            day_counter,
             compounding: comp,
             freq_makes_sense: makes_sense,
             freq: freq.to_float(),
         }
     }
 
+    pub fn continuous(r: Rate, day_counter: DC) -> InterestRate<DC> {
+        Self::new(r, day_counter, Compounding::Continuous, Frequency::Annual)
+    }
+
+    /// Simple (money-market) rate, accruing `1 + r t`.
+    pub fn simple(r: Rate, day_counter: DC) -> InterestRate<DC> {
+        Self::new(r, day_counter, Compounding::Simple, Frequency::Annual)
+    }
+
+    pub fn annual(r: Rate, day_counter: DC) -> InterestRate<DC> {
+        Self::new(r, day_counter, Compounding::Compounded, Frequency::Annual)
+    }
+
     pub fn implied_rate_with_time(
         compound: f64,
         day_counter: DC,
@@ -70,7 +84,7 @@ where
                 }
             }
         }
-        return Self::new(r, day_counter, comp, freq);
+        Self::new(r, day_counter, comp, freq)
     }
 
     pub fn implied_rate(
@@ -112,7 +126,7 @@ where
             }
             Compounding::Compounded => {
                 // (1+r/f)^(f*t)
-                (1.0 + self.rate / self.freq as f64).powf(self.freq as f64 * t)
+                (1.0 + self.rate / self.freq).powf(self.freq * t)
             }
             Compounding::Continuous => {
                 // e^(r*t)
@@ -121,23 +135,129 @@ where
                 e.powf(self.rate * t)
             }
             Compounding::SimpleThenCompounded => {
-                if t < (1.0 / self.freq as f64) {
+                if t < (1.0 / self.freq) {
                     // 1+r*t
                     1.0 + self.rate * t
                 } else {
                     // (1+(r/f))^(f*t)
-                    (1.0 + self.rate / self.freq as f64).powf(self.freq as f64 * t)
+                    (1.0 + self.rate / self.freq).powf(self.freq * t)
                 }
             }
             Compounding::CompoundedThenSimple => {
-                if t > (1.0 / self.freq as f64) {
+                if t > (1.0 / self.freq) {
                     // 1+r*t
                     1.0 + self.rate * t
                 } else {
                     // (1+(r/f))^(f*t)
-                    (1.0 + self.rate / self.freq as f64).powf(self.freq as f64 * t)
+                    (1.0 + self.rate / self.freq).powf(self.freq * t)
                 }
             }
         }
     }
+
+    pub fn frequency(&self) -> Frequency {
+        if self.freq_makes_sense {
+            Frequency::from_float(self.freq)
+        } else {
+            Frequency::NoFrequency
+        }
+    }
+
+    pub fn discount_factor(&self, d1: Date, d2: Date) -> f64 {
+        1.0 / self.compound_factor(d1, d2)
+    }
+
+    /// Discount factor over `d1` to `d2`, with the reference period needed
+    /// by some day counters for short or long (odd) coupon periods.
+    pub fn discount_factor_with_ref(
+        &self,
+        d1: Date,
+        d2: Date,
+        ref_start: Option<Date>,
+        ref_end: Option<Date>,
+    ) -> f64 {
+        1.0 / self.compound_factor_with_ref(d1, d2, ref_start, ref_end)
+    }
+
+    pub fn discount_factor_with_time(&self, t: Time) -> f64 {
+        1.0 / self.compound_factor_with_time(t)
+    }
+
+    /// The rate with the given conventions compounding to the same factor
+    /// over `t`.
+    pub fn equivalent_rate(&self, comp: Compounding, freq: Frequency, t: Time) -> InterestRate<DC> {
+        Self::implied_rate_with_time(
+            self.compound_factor_with_time(t),
+            self.day_counter,
+            comp,
+            freq,
+            t,
+        )
+    }
+
+    /// The continuously compounded equivalent over `t`, i.e. the
+    /// arithmetic average of the instantaneous rates over the period.
+    pub fn to_continuous(&self, t: Time) -> InterestRate<DC> {
+        self.equivalent_rate(Compounding::Continuous, Frequency::Annual, t)
+    }
+
+    /// The simple equivalent over `t`, as money-market rates are quoted.
+    pub fn to_simple(&self, t: Time) -> InterestRate<DC> {
+        self.equivalent_rate(Compounding::Simple, Frequency::Annual, t)
+    }
+
+    /// The annually compounded equivalent over `t`, i.e. the geometric
+    /// average of the yearly growth factors.
+    pub fn to_annual(&self, t: Time) -> InterestRate<DC> {
+        self.equivalent_rate(Compounding::Compounded, Frequency::Annual, t)
+    }
+
+    /// The rate with the given day counter and conventions compounding to
+    /// the same factor between `d1` and `d2`.
+    pub fn equivalent_rate_with_dates<RDC: DayCounter>(
+        &self,
+        result_day_counter: RDC,
+        comp: Compounding,
+        freq: Frequency,
+        d1: Date,
+        d2: Date,
+        ref_start: Option<Date>,
+        ref_end: Option<Date>,
+    ) -> InterestRate<RDC> {
+        assert!(d2 > d1, "d1 must be earlier than d2");
+        let t1 = self.day_counter.year_fraction(d1, d2, ref_start, ref_end);
+        let t2 = result_day_counter.year_fraction(d1, d2, ref_start, ref_end);
+        InterestRate::implied_rate_with_time(
+            self.compound_factor_with_time(t1),
+            result_day_counter,
+            comp,
+            freq,
+            t2,
+        )
+    }
+}
+
+/// Rate and conventions, e.g. `5.000000 % Actual/360 Semiannual
+/// compounding`.
+impl<DC: DayCounter> fmt::Display for InterestRate<DC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.6} % {} ", self.rate * 100.0, self.day_counter.name())?;
+        let freq = self.frequency();
+        let months = (12.0 / self.freq) as i64;
+        match self.compounding {
+            Compounding::Simple => write!(f, "simple compounding"),
+            Compounding::Compounded => write!(f, "{} compounding", freq),
+            Compounding::Continuous => write!(f, "continuous compounding"),
+            Compounding::SimpleThenCompounded => write!(
+                f,
+                "simple compounding up to {} months, then {} compounding",
+                months, freq
+            ),
+            Compounding::CompoundedThenSimple => write!(
+                f,
+                "compounding up to {} months, then {} simple compounding",
+                months, freq
+            ),
+        }
+    }
 }
@@ -0,0 +1,172 @@
+use super::traits::TermStructure;
+use super::traits::YieldTermStructure as YTS;
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::errors::{check_discount, check_time_range, QuantLibError};
+use crate::math::{Interpolation, Interpolator};
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+
+/// Discount curve interpolating discount factors between node dates, the
+/// first of which is the reference date. Beyond the last node, where term
+/// structure queries must allow extrapolation, the average forward of the
+/// last segment is kept.
+#[derive(Clone)]
+pub struct InterpolatedDiscountCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub discounts: Vec<DiscountFactor>,
+    pub interpolation: Interpolation,
+    interpolator: Interpolator,
+}
+
+/// Curve on dated discount factors, under its usual name.
+pub type DiscountCurve<C, DC = Actual365Fixed> = InterpolatedDiscountCurve<C, DC>;
+
+impl<C: Cal, DC: DayCounter> InterpolatedDiscountCurve<C, DC> {
+    pub fn new(
+        dates: Vec<Date>,
+        discounts: Vec<DiscountFactor>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+        interpolation: Interpolation,
+    ) -> InterpolatedDiscountCurve<C, DC> {
+        assert!(dates.len() >= 2, "at least two nodes needed");
+        assert!(
+            dates.len() == discounts.len(),
+            "dates and discounts size mismatch"
+        );
+        assert!(discounts.iter().all(|d| *d > 0.0), "non positive discount");
+        let reference_date = dates[0];
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        let interpolator = Interpolator::new(interpolation, times.clone(), discounts.clone());
+        InterpolatedDiscountCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            dates,
+            times,
+            discounts,
+            interpolation,
+            interpolator,
+        }
+    }
+
+    /// The (date, discount factor) pairs the curve interpolates.
+    pub fn nodes(&self) -> Vec<(Date, DiscountFactor)> {
+        self.dates
+            .iter()
+            .cloned()
+            .zip(self.discounts.iter().cloned())
+            .collect()
+    }
+
+    /// The same nodes interpolated with another scheme, e.g. to compare a
+    /// bootstrapped curve with its smoothed version without bootstrapping
+    /// again.
+    pub fn with_interpolation(
+        &self,
+        interpolation: Interpolation,
+    ) -> InterpolatedDiscountCurve<C, DC> {
+        Self::new(
+            self.dates.clone(),
+            self.discounts.clone(),
+            self.calendar,
+            self.day_counter,
+            interpolation,
+        )
+    }
+
+    pub fn discount_with_time(&self, t: Time) -> DiscountFactor {
+        discount(&self.interpolator, t)
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date))
+    }
+
+    /// Continuously compounded instantaneous forward rate at `t`.
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        let last = self.times[self.times.len() - 1];
+        if t >= last {
+            return last_forward(&self.interpolator);
+        }
+        -self.interpolator.derivative(t) / self.interpolator.value(t)
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    /// A term structure discounting with this curve.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        let interpolator = self.interpolator.clone();
+        YieldTermStructure::new(
+            self.calendar,
+            self.reference_date,
+            self.day_counter,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| discount(&interpolator, t)),
+        )
+    }
+}
+
+impl<C: Cal, DC: DayCounter> YTS for InterpolatedDiscountCurve<C, DC> {
+    type D = DC;
+    fn day_counter(&self) -> Self::D {
+        self.day_counter
+    }
+    /// Past the last node, only when extrapolating, keeping the forward of the last segment.
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        check_time_range(time, self.max_time(), extrapolate)?;
+        check_discount(discount(&self.interpolator, time))
+    }
+}
+
+impl<C: Cal, DC: DayCounter> TermStructure for InterpolatedDiscountCurve<C, DC> {
+    /// The last node date.
+    fn max_date(&self) -> Date {
+        self.dates[self.dates.len() - 1]
+    }
+    fn settlement_days(&self) -> i64 {
+        0
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        InterpolatedDiscountCurve::time_from_reference(self, date)
+    }
+    fn max_time(&self) -> Time {
+        self.times[self.times.len() - 1]
+    }
+    fn reference_date(&mut self) -> Date {
+        self.reference_date
+    }
+}
+
+fn last_forward(interpolator: &Interpolator) -> Rate {
+    let n = interpolator.xs.len();
+    let (t1, t2) = (interpolator.xs[n - 2], interpolator.xs[n - 1]);
+    (interpolator.ys[n - 2] / interpolator.ys[n - 1]).ln() / (t2 - t1)
+}
+
+fn discount(interpolator: &Interpolator, t: Time) -> DiscountFactor {
+    let n = interpolator.xs.len();
+    let last = interpolator.xs[n - 1];
+    if t <= last {
+        return interpolator.value(t);
+    }
+    interpolator.ys[n - 1] * (-last_forward(interpolator) * (t - last)).exp()
+}
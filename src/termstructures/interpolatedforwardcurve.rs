@@ -0,0 +1,232 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::math::{simpson, Interpolation, Interpolator};
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+
+/// Simpson steps per segment when integrating the forwards; exact for the
+/// linear and cubic schemes.
+const INTEGRATION_STEPS: usize = 16;
+
+/// Curve interpolating continuously compounded instantaneous forwards
+/// between node dates, the first of which is the reference date. Beyond
+/// the last node the forward is kept flat.
+///
+/// `MonotonicCubic` gives smooth forwards without overshooting the nodes,
+/// and `LeastSquaresSpline` smooths noisy ones.
+#[derive(Clone)]
+pub struct InterpolatedForwardCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub forwards: Vec<Rate>,
+    pub interpolation: Interpolation,
+    interpolator: Interpolator,
+    /// Integral of the forwards up to each node.
+    integrals: Vec<f64>,
+}
+
+impl<C: Cal, DC: DayCounter> InterpolatedForwardCurve<C, DC> {
+    pub fn new(
+        dates: Vec<Date>,
+        forwards: Vec<Rate>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+        interpolation: Interpolation,
+    ) -> InterpolatedForwardCurve<C, DC> {
+        assert!(dates.len() >= 2, "at least two nodes needed");
+        assert!(
+            dates.len() == forwards.len(),
+            "dates and forwards size mismatch"
+        );
+        let reference_date = dates[0];
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        let interpolator = Interpolator::new(interpolation, times.clone(), forwards.clone());
+        let mut integrals = vec![0.0];
+        for (i, w) in times.windows(2).enumerate() {
+            integrals.push(integrals[i] + integrate(&interpolator, w[0], w[1]));
+        }
+        InterpolatedForwardCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            dates,
+            times,
+            forwards,
+            interpolation,
+            interpolator,
+            integrals,
+        }
+    }
+
+    /// Forwards at the nodes of `curve`, taken from its interpolation.
+    pub fn from_discount_curve(
+        curve: &InterpolatedDiscountCurve<C, DC>,
+        interpolation: Interpolation,
+    ) -> InterpolatedForwardCurve<C, DC> {
+        let forwards = curve
+            .times
+            .iter()
+            .map(|t| curve.instantaneous_forward(*t))
+            .collect();
+        Self::new(
+            curve.dates.clone(),
+            forwards,
+            curve.calendar,
+            curve.day_counter,
+            interpolation,
+        )
+    }
+
+    /// The (date, forward) pairs the curve interpolates.
+    pub fn nodes(&self) -> Vec<(Date, Rate)> {
+        self.dates
+            .iter()
+            .cloned()
+            .zip(self.forwards.iter().cloned())
+            .collect()
+    }
+
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        let last = self.times[self.times.len() - 1];
+        self.interpolator.value(t.min(last))
+    }
+
+    pub fn discount_with_time(&self, t: Time) -> DiscountFactor {
+        discount(&self.interpolator, &self.integrals, t)
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date))
+    }
+
+    /// Continuously compounded zero rate to `t`; at the reference date,
+    /// the forward there.
+    pub fn zero_rate(&self, t: Time) -> Rate {
+        if t <= 0.0 {
+            return self.instantaneous_forward(0.0);
+        }
+        -self.discount_with_time(t).ln() / t
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    /// Discount curve through the discount factors at the nodes.
+    pub fn discount_curve(&self, interpolation: Interpolation) -> InterpolatedDiscountCurve<C, DC> {
+        let discounts = self
+            .times
+            .iter()
+            .map(|t| self.discount_with_time(*t))
+            .collect();
+        InterpolatedDiscountCurve::new(
+            self.dates.clone(),
+            discounts,
+            self.calendar,
+            self.day_counter,
+            interpolation,
+        )
+    }
+
+    /// A term structure discounting with this curve.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        let interpolator = self.interpolator.clone();
+        let integrals = self.integrals.clone();
+        YieldTermStructure::new(
+            self.calendar,
+            self.reference_date,
+            self.day_counter,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| discount(&interpolator, &integrals, t)),
+        )
+    }
+}
+
+/// Integral of the interpolated forwards from `t1` to `t2` by Simpson's
+/// rule, within one segment.
+fn integrate(interpolator: &Interpolator, t1: Time, t2: Time) -> f64 {
+    simpson(|t| interpolator.value(t), t1, t2, INTEGRATION_STEPS)
+}
+
+fn discount(interpolator: &Interpolator, integrals: &[f64], t: Time) -> DiscountFactor {
+    assert!(t >= 0.0, "negative time");
+    let xs = &interpolator.xs;
+    let n = xs.len();
+    let last = xs[n - 1];
+    if t >= last {
+        return (-(integrals[n - 1] + interpolator.value(last) * (t - last))).exp();
+    }
+    let i = xs.iter().rposition(|x| *x <= t).unwrap();
+    (-(integrals[i] + integrate(interpolator, xs[i], t))).exp()
+}
+
+/// Discount factors from continuously compounded zero rates.
+pub fn zero_rates_to_discounts(times: &[Time], zero_rates: &[Rate]) -> Vec<DiscountFactor> {
+    assert!(
+        times.len() == zero_rates.len(),
+        "times and zero rates size mismatch"
+    );
+    times
+        .iter()
+        .zip(zero_rates)
+        .map(|(t, z)| (-z * t).exp())
+        .collect()
+}
+
+/// Continuously compounded zero rates from discount factors; at time zero,
+/// the rate of the first period.
+pub fn discounts_to_zero_rates(times: &[Time], discounts: &[DiscountFactor]) -> Vec<Rate> {
+    assert!(times.len() >= 2, "at least two nodes needed");
+    assert!(
+        times.len() == discounts.len(),
+        "times and discounts size mismatch"
+    );
+    let mut zeros: Vec<Rate> = times
+        .iter()
+        .zip(discounts)
+        .map(|(t, d)| if *t > 0.0 { -d.ln() / t } else { 0.0 })
+        .collect();
+    if times[0] <= 0.0 {
+        zeros[0] = (discounts[0] / discounts[1]).ln() / (times[1] - times[0]);
+    }
+    zeros
+}
+
+/// Average continuously compounded forwards between consecutive nodes.
+pub fn discounts_to_forwards(times: &[Time], discounts: &[DiscountFactor]) -> Vec<Rate> {
+    assert!(
+        times.len() == discounts.len(),
+        "times and discounts size mismatch"
+    );
+    times
+        .windows(2)
+        .zip(discounts.windows(2))
+        .map(|(t, d)| (d[0] / d[1]).ln() / (t[1] - t[0]))
+        .collect()
+}
+
+/// Discount factors at `times` from the average forwards between them,
+/// starting from one.
+pub fn forwards_to_discounts(times: &[Time], forwards: &[Rate]) -> Vec<DiscountFactor> {
+    assert!(
+        times.len() == forwards.len() + 1,
+        "one forward per period needed"
+    );
+    let mut discounts = vec![1.0];
+    for (t, f) in times.windows(2).zip(forwards) {
+        let last = discounts[discounts.len() - 1];
+        discounts.push(last * (-f * (t[1] - t[0])).exp());
+    }
+    discounts
+}
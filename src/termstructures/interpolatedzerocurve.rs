@@ -0,0 +1,157 @@
+use super::traits::TermStructure;
+use super::traits::YieldTermStructure as YTS;
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::errors::{check_discount, check_time_range, QuantLibError};
+use crate::math::{Interpolation, Interpolator};
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+
+/// Zero curve interpolating continuously compounded zero rates, on its
+/// day counter, between node dates, the first of which is the reference
+/// date. Beyond the last node, where term structure queries must allow
+/// extrapolation, the last zero rate is kept.
+#[derive(Clone)]
+pub struct InterpolatedZeroCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub zero_rates: Vec<Rate>,
+    pub interpolation: Interpolation,
+    interpolator: Interpolator,
+}
+
+/// Curve on dated zero rates, under its usual name.
+pub type ZeroCurve<C, DC = Actual365Fixed> = InterpolatedZeroCurve<C, DC>;
+
+impl<C: Cal, DC: DayCounter> InterpolatedZeroCurve<C, DC> {
+    pub fn new(
+        dates: Vec<Date>,
+        zero_rates: Vec<Rate>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+        interpolation: Interpolation,
+    ) -> InterpolatedZeroCurve<C, DC> {
+        assert!(dates.len() >= 2, "at least two nodes needed");
+        assert!(
+            dates.len() == zero_rates.len(),
+            "dates and zero rates size mismatch"
+        );
+        let reference_date = dates[0];
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        let interpolator = Interpolator::new(interpolation, times.clone(), zero_rates.clone());
+        InterpolatedZeroCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            dates,
+            times,
+            zero_rates,
+            interpolation,
+            interpolator,
+        }
+    }
+
+    /// The (date, zero rate) pairs the curve interpolates.
+    pub fn nodes(&self) -> Vec<(Date, Rate)> {
+        self.dates
+            .iter()
+            .cloned()
+            .zip(self.zero_rates.iter().cloned())
+            .collect()
+    }
+
+    /// Continuously compounded zero rate to `t`.
+    pub fn zero_rate_at(&self, t: Time) -> Rate {
+        zero_rate(&self.interpolator, t)
+    }
+
+    pub fn discount_with_time(&self, t: Time) -> DiscountFactor {
+        discount(&self.interpolator, t)
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date))
+    }
+
+    /// Continuously compounded instantaneous forward rate at `t`.
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        let last = self.times[self.times.len() - 1];
+        if t >= last {
+            return self.zero_rates[self.zero_rates.len() - 1];
+        }
+        self.interpolator.value(t) + t * self.interpolator.derivative(t)
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    /// A term structure discounting with this curve.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        let interpolator = self.interpolator.clone();
+        YieldTermStructure::new(
+            self.calendar,
+            self.reference_date,
+            self.day_counter,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| discount(&interpolator, t)),
+        )
+    }
+}
+
+impl<C: Cal, DC: DayCounter> YTS for InterpolatedZeroCurve<C, DC> {
+    type D = DC;
+    fn day_counter(&self) -> Self::D {
+        self.day_counter
+    }
+    /// Past the last node, only when extrapolating, keeping the last zero rate.
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        check_time_range(time, self.max_time(), extrapolate)?;
+        check_discount(discount(&self.interpolator, time))
+    }
+}
+
+impl<C: Cal, DC: DayCounter> TermStructure for InterpolatedZeroCurve<C, DC> {
+    /// The last node date.
+    fn max_date(&self) -> Date {
+        self.dates[self.dates.len() - 1]
+    }
+    fn settlement_days(&self) -> i64 {
+        0
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        InterpolatedZeroCurve::time_from_reference(self, date)
+    }
+    fn max_time(&self) -> Time {
+        self.times[self.times.len() - 1]
+    }
+    fn reference_date(&mut self) -> Date {
+        self.reference_date
+    }
+}
+
+fn zero_rate(interpolator: &Interpolator, t: Time) -> Rate {
+    let n = interpolator.xs.len();
+    if t >= interpolator.xs[n - 1] {
+        return interpolator.ys[n - 1];
+    }
+    interpolator.value(t)
+}
+
+fn discount(interpolator: &Interpolator, t: Time) -> DiscountFactor {
+    (-zero_rate(interpolator, t) * t).exp()
+}
@@ -0,0 +1,114 @@
+use super::traits::TermStructure;
+use super::traits::YieldTermStructure as YTS;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::errors::{check_discount, QuantLibError};
+use crate::time::Date;
+
+/// Continuously compounded zero rate shift of `size` at one of the key
+/// rate `pillars`, decreasing linearly to zero at the neighbouring ones
+/// and flat beyond the first and last. The shifts of all the pillars add
+/// up to a parallel shift.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyRateShift {
+    pub pillars: Vec<Time>,
+    /// The shifted pillar, none for the unshifted curve.
+    pub pillar: Option<usize>,
+    pub size: Rate,
+}
+
+impl KeyRateShift {
+    pub fn new(pillars: &[Time], pillar: usize, size: Rate) -> KeyRateShift {
+        assert!(pillar < pillars.len(), "pillar out of range");
+        assert!(
+            pillars.windows(2).all(|w| w[0] < w[1]),
+            "pillars not increasing"
+        );
+        KeyRateShift {
+            pillars: pillars.to_vec(),
+            pillar: Some(pillar),
+            size,
+        }
+    }
+
+    /// No shift at all.
+    pub fn none() -> KeyRateShift {
+        KeyRateShift {
+            pillars: vec![],
+            pillar: None,
+            size: 0.0,
+        }
+    }
+
+    /// The zero rate shift at time `t`.
+    pub fn zero_shift(&self, t: Time) -> Rate {
+        let i = match self.pillar {
+            Some(i) => i,
+            None => return 0.0,
+        };
+        let p = &self.pillars;
+        let weight = if t <= p[i] {
+            if i == 0 {
+                1.0
+            } else if t <= p[i - 1] {
+                0.0
+            } else {
+                (t - p[i - 1]) / (p[i] - p[i - 1])
+            }
+        } else if i == p.len() - 1 {
+            1.0
+        } else if t >= p[i + 1] {
+            0.0
+        } else {
+            (p[i + 1] - t) / (p[i + 1] - p[i])
+        };
+        weight * self.size
+    }
+}
+
+/// Curve with its zero rates shifted by a key rate shift, on the day
+/// counter of the underlying curve, to attribute its risk pillar by
+/// pillar.
+pub struct KeyRateShiftedCurve<Y: YTS> {
+    pub curve: Y,
+    pub shift: KeyRateShift,
+}
+
+impl<Y: YTS> KeyRateShiftedCurve<Y> {
+    pub fn new(curve: Y, shift: KeyRateShift) -> KeyRateShiftedCurve<Y> {
+        KeyRateShiftedCurve { curve, shift }
+    }
+}
+
+impl<Y: YTS> YTS for KeyRateShiftedCurve<Y> {
+    type D = Y::D;
+    /// That of the underlying curve, which the shift moves.
+    fn day_counter(&self) -> Self::D {
+        self.curve.day_counter()
+    }
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        let discount = self.curve.checked_discount_with_time(time, extrapolate)?;
+        check_discount(discount * (-self.shift.zero_shift(time) * time).exp())
+    }
+}
+
+impl<Y: YTS> TermStructure for KeyRateShiftedCurve<Y> {
+    fn max_date(&self) -> Date {
+        self.curve.max_date()
+    }
+    fn settlement_days(&self) -> i64 {
+        self.curve.settlement_days()
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.curve.time_from_reference(date)
+    }
+    fn max_time(&self) -> Time {
+        self.curve.max_time()
+    }
+    fn reference_date(&mut self) -> Date {
+        self.curve.reference_date()
+    }
+}
@@ -0,0 +1,174 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use crate::definitions::{DiscountFactor, Rate};
+use crate::math::Interpolation;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+
+/// Curve point pinned to an exact value while bootstrapping.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CurveAnchor {
+    Discount(Date, DiscountFactor),
+    /// Continuously compounded zero rate on the curve day counter.
+    ZeroRate(Date, Rate),
+}
+
+impl CurveAnchor {
+    pub fn date(&self) -> Date {
+        match *self {
+            CurveAnchor::Discount(date, _) | CurveAnchor::ZeroRate(date, _) => date,
+        }
+    }
+
+    pub fn discount<DC: DayCounter>(
+        &self,
+        reference_date: Date,
+        day_counter: DC,
+    ) -> DiscountFactor {
+        match *self {
+            CurveAnchor::Discount(_, discount) => discount,
+            CurveAnchor::ZeroRate(date, rate) => {
+                (-rate * day_counter.year_fraction(reference_date, date, None, None)).exp()
+            }
+        }
+    }
+}
+
+/// Short end curve whose forwards only change on central bank meeting
+/// dates and pinned anchor dates, and the quotes' discount factor minus
+/// the curve's, in quote order.
+#[derive(Clone)]
+pub struct MeetingDateBootstrap<C: Cal, DC: DayCounter> {
+    pub curve: InterpolatedDiscountCurve<C, DC>,
+    pub errors: Vec<f64>,
+}
+
+/// Bootstraps step function forwards between meeting dates.
+///
+/// Quotes are discount factors to their maturities, e.g. from deposits or
+/// single payment OIS. Going forward from the reference date, the first
+/// quote past the last solved node sets one forward up to the end of the
+/// period it matures in, that is up to the next meeting or anchor date;
+/// later quotes in an already solved period are left as errors. Anchors
+/// are kept exactly and take priority over quotes, and past the last node
+/// with a quote the last forward is kept.
+#[derive(Clone)]
+pub struct MeetingDateCurveBuilder<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub meeting_dates: Vec<Date>,
+    pub anchors: Vec<CurveAnchor>,
+}
+
+impl<C: Cal, DC: DayCounter> MeetingDateCurveBuilder<C, DC> {
+    pub fn new(
+        reference_date: Date,
+        meeting_dates: Vec<Date>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> MeetingDateCurveBuilder<C, DC> {
+        MeetingDateCurveBuilder {
+            reference_date,
+            calendar,
+            day_counter,
+            meeting_dates,
+            anchors: vec![],
+        }
+    }
+
+    pub fn with_anchor(mut self, anchor: CurveAnchor) -> MeetingDateCurveBuilder<C, DC> {
+        assert!(
+            anchor.date() > self.reference_date,
+            "anchor must be after the reference date"
+        );
+        assert!(
+            self.anchors.iter().all(|a| a.date() != anchor.date()),
+            "date already anchored"
+        );
+        self.anchors.push(anchor);
+        self
+    }
+
+    fn time(&self, date: Date) -> f64 {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    pub fn bootstrap(&self, quotes: &[(Date, DiscountFactor)]) -> MeetingDateBootstrap<C, DC> {
+        assert!(
+            !quotes.is_empty() || !self.anchors.is_empty(),
+            "nothing to fit"
+        );
+        assert!(
+            quotes
+                .iter()
+                .all(|q| q.0 > self.reference_date && q.1 > 0.0),
+            "quotes must mature after the reference date with positive discounts"
+        );
+        let mut nodes: Vec<Date> = self
+            .meeting_dates
+            .iter()
+            .cloned()
+            .chain(self.anchors.iter().map(|a| a.date()))
+            .filter(|d| *d > self.reference_date)
+            .collect();
+        if let Some(last) = quotes.iter().map(|q| q.0).max() {
+            nodes.push(last);
+        }
+        nodes.sort();
+        nodes.dedup();
+        let anchored = |d: Date| {
+            self.anchors
+                .iter()
+                .find(|a| a.date() == d)
+                .map(|a| a.discount(self.reference_date, self.day_counter).ln())
+        };
+        let mut sorted = quotes.to_vec();
+        sorted.sort_by_key(|q| q.0);
+
+        // log discounts at the nodes, solved left to right
+        let (mut last, mut log_discount) = (self.reference_date, 0.0);
+        let mut values: Vec<f64> = vec![];
+        let mut forward = None;
+        while values.len() < nodes.len() {
+            let j = values.len();
+            let quote = sorted.iter().find(|q| q.0 > last);
+            // the period a quote or anchor ahead fixes, with its end value
+            let end = nodes[j..]
+                .iter()
+                .position(|&n| anchored(n).is_some() || quote.map(|q| q.0 <= n) == Some(true))
+                .map(|k| j + k);
+            let f = match end {
+                Some(k) => match anchored(nodes[k]) {
+                    Some(value) => (log_discount - value) / (self.time(nodes[k]) - self.time(last)),
+                    None => {
+                        let (date, discount) = *quote.unwrap();
+                        (log_discount - discount.ln()) / (self.time(date) - self.time(last))
+                    }
+                },
+                None => forward.expect("no quote to start the curve"),
+            };
+            let k = end.unwrap_or(nodes.len() - 1);
+            for &n in &nodes[j..=k] {
+                values.push(log_discount - f * (self.time(n) - self.time(last)));
+            }
+            log_discount = values[k];
+            last = nodes[k];
+            forward = Some(f);
+        }
+
+        let mut dates = vec![self.reference_date];
+        dates.extend_from_slice(&nodes);
+        let mut discounts = vec![1.0];
+        discounts.extend(values.iter().map(|v| v.exp()));
+        let curve = InterpolatedDiscountCurve::new(
+            dates,
+            discounts,
+            self.calendar,
+            self.day_counter,
+            Interpolation::LogLinear,
+        );
+        let errors = quotes.iter().map(|q| q.1 - curve.discount(q.0)).collect();
+        MeetingDateBootstrap { curve, errors }
+    }
+}
@@ -1,11 +1,77 @@
+pub mod arbitrage;
+pub mod atmrates;
 pub mod base;
+pub mod bondhelpers;
+pub mod bondimpliedcredit;
 pub mod compounding;
+pub mod curvescenarios;
+pub mod dividendyieldcurve;
+pub mod flatforward;
+pub mod futuresstrip;
+pub mod hazardratecurve;
 pub mod interestrate;
+pub mod interpolateddiscountcurve;
+pub mod interpolatedforwardcurve;
+pub mod interpolatedzerocurve;
+pub mod keyrateshiftedcurve;
+pub mod meetingdatecurve;
+pub mod moneymarket;
+pub mod moneyness;
+pub mod nelsonsiegel;
+pub mod piecewiseyieldcurve;
+pub mod policyscenarios;
+pub mod proxyvolatility;
+pub mod ratehelpers;
+pub mod ssvi;
+pub mod stepforwardcurve;
+pub mod svi;
 pub mod traits;
+pub mod volatility;
 pub mod yieldtermstructure;
+pub mod zeroinflationcurve;
+pub mod zerospreadedcurve;
 
+pub use self::arbitrage::{
+    ArbitrageKind, ArbitrageRegion, ArbitrageReport, ArbitrageValidator, ArbitrageViolation,
+};
+pub use self::atmrates::{AtmForward, AtmRateCalculator};
 pub use self::base::Base;
+pub use self::bondhelpers::{bond_curve_helpers, BondHelper, FixedRateBondHelper};
+pub use self::bondimpliedcredit::{fit_hazard_rates, BondCreditHelper, BondImpliedCredit};
 pub use self::compounding::Compounding;
+pub use self::curvescenarios::PcaCurveScenarioGenerator;
+pub use self::dividendyieldcurve::{DividendYield, DividendYieldCurve};
+pub use self::flatforward::FlatForward;
+pub use self::futuresstrip::{hull_white_convexity_bias, FuturesCycle, FuturesStrip, StirFuture};
+pub use self::hazardratecurve::HazardRateCurve;
 pub use self::interestrate::InterestRate;
+pub use self::interpolateddiscountcurve::{DiscountCurve, InterpolatedDiscountCurve};
+pub use self::interpolatedforwardcurve::{
+    discounts_to_forwards, discounts_to_zero_rates, forwards_to_discounts, zero_rates_to_discounts,
+    InterpolatedForwardCurve,
+};
+pub use self::interpolatedzerocurve::{InterpolatedZeroCurve, ZeroCurve};
+pub use self::keyrateshiftedcurve::{KeyRateShift, KeyRateShiftedCurve};
+pub use self::meetingdatecurve::{CurveAnchor, MeetingDateBootstrap, MeetingDateCurveBuilder};
+pub use self::moneymarket::{
+    accrued_interest, act360_to_act365, act365_to_act360, convert_basis, forward_deposit_rate,
+};
+pub use self::moneyness::Moneyness;
+pub use self::nelsonsiegel::{CurveSpread, NelsonSiegel, NelsonSiegelHistory};
+pub use self::piecewiseyieldcurve::PiecewiseYieldCurve;
+pub use self::policyscenarios::{
+    implied_move_probabilities, AverageRateFuture, MeetingProbability, PolicyScenario,
+    PolicyScenarioMixture,
+};
+pub use self::proxyvolatility::ProxyIndexVolatility;
+pub use self::ratehelpers::{
+    BasisSwapRateHelper, DepositRateHelper, DiFutureRateHelper, FraRateHelper, SwapRateHelper,
+};
+pub use self::ssvi::SsviSurface;
+pub use self::stepforwardcurve::{StepForwardCalibration, StepForwardCurve};
+pub use self::svi::{SviParameters, SviSmile, SviSurface};
 pub use self::traits::*;
-pub use self::yieldtermstructure::YieldTermStructure;
+pub use self::volatility::{BlackConstantVol, BlackVarianceSurface, StrikeExtrapolation};
+pub use self::yieldtermstructure::{YieldTermStructure, YieldTermStructureBuilder};
+pub use self::zeroinflationcurve::ZeroInflationCurve;
+pub use self::zerospreadedcurve::ZeroSpreadedTermStructure;
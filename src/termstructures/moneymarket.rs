@@ -0,0 +1,52 @@
+use super::traits::YieldTermStructure;
+use crate::definitions::Rate;
+use crate::time::{Date, DayCounter};
+
+/// Simple interest earned on a deposit or loan of `notional` between two
+/// dates.
+pub fn accrued_interest<DC: DayCounter>(
+    notional: f64,
+    rate: Rate,
+    start: Date,
+    end: Date,
+    day_counter: DC,
+) -> f64 {
+    notional * rate * day_counter.year_fraction(start, end, None, None)
+}
+
+/// Simple rate under `to` accruing the same interest over the period as
+/// `rate` does under `from`.
+pub fn convert_basis<D1: DayCounter, D2: DayCounter>(
+    rate: Rate,
+    from: D1,
+    to: D2,
+    start: Date,
+    end: Date,
+) -> Rate {
+    let tau = to.year_fraction(start, end, None, None);
+    assert!(tau > 0.0, "end date must be after the start date");
+    rate * from.year_fraction(start, end, None, None) / tau
+}
+
+/// ACT/360 money market rate quoted on an ACT/365 basis.
+pub fn act360_to_act365(rate: Rate) -> Rate {
+    rate * 365.0 / 360.0
+}
+
+/// ACT/365 money market rate quoted on an ACT/360 basis.
+pub fn act365_to_act360(rate: Rate) -> Rate {
+    rate * 360.0 / 365.0
+}
+
+/// Simple rate of a deposit between `start` and `end` implied by the
+/// curve's discount factors.
+pub fn forward_deposit_rate<Y: YieldTermStructure, DC: DayCounter>(
+    discount_curve: &Y,
+    start: Date,
+    end: Date,
+    day_counter: DC,
+) -> Rate {
+    let tau = day_counter.year_fraction(start, end, None, None);
+    assert!(tau > 0.0, "end date must be after the start date");
+    (discount_curve.discount(start, true) / discount_curve.discount(end, true) - 1.0) / tau
+}
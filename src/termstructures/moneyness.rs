@@ -0,0 +1,75 @@
+use crate::definitions::{Time, Volatility};
+use crate::instruments::OptionType;
+use crate::math::{inverse_normal_cdf, normal_cdf};
+
+/// Iterations of the fixed point for the strike at a delta on a smile.
+const MAX_DELTA_ITERATIONS: usize = 100;
+
+/// Point on a smile in one of the usual quoting conventions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Moneyness {
+    /// The strike itself.
+    Strike(f64),
+    /// Strike over forward, `K / F`.
+    Simple(f64),
+    /// Log of strike over forward, `ln(K / F)`.
+    Log(f64),
+    /// Undiscounted Black delta of the given option type, e.g. 0.25 for
+    /// a 25 delta call or -0.25 for a 25 delta put.
+    Delta(OptionType, f64),
+}
+
+impl Moneyness {
+    /// The strike at expiry `t` given the forward; `vol` is the smile by
+    /// strike, only needed for deltas.
+    pub fn strike<V: Fn(f64) -> Volatility>(&self, t: Time, forward: f64, vol: V) -> f64 {
+        assert!(forward > 0.0, "non positive forward");
+        match *self {
+            Moneyness::Strike(k) => k,
+            Moneyness::Simple(m) => m * forward,
+            Moneyness::Log(k) => forward * k.exp(),
+            Moneyness::Delta(option_type, delta) => {
+                let w = option_type.sign();
+                assert!(
+                    w * delta > 0.0 && w * delta < 1.0,
+                    "delta out of range for the option type"
+                );
+                assert!(t > 0.0, "non positive expiry");
+                // the smile vol depends on the strike, hence the fixed point
+                let d1 = w * inverse_normal_cdf(w * delta);
+                let strike_at = |sigma: f64| {
+                    let sd = sigma * t.sqrt();
+                    forward * (-d1 * sd + 0.5 * sd * sd).exp()
+                };
+                let mut k = strike_at(vol(forward));
+                for _ in 0..MAX_DELTA_ITERATIONS {
+                    let next = strike_at(vol(k));
+                    if (next - k).abs() < 1.0e-12 * forward {
+                        return next;
+                    }
+                    k = next;
+                }
+                k
+            }
+        }
+    }
+
+    /// The same convention at `strike`.
+    pub fn at_strike(&self, strike: f64, t: Time, forward: f64, vol: Volatility) -> Moneyness {
+        assert!(
+            strike > 0.0 && forward > 0.0,
+            "non positive strike or forward"
+        );
+        match *self {
+            Moneyness::Strike(_) => Moneyness::Strike(strike),
+            Moneyness::Simple(_) => Moneyness::Simple(strike / forward),
+            Moneyness::Log(_) => Moneyness::Log((strike / forward).ln()),
+            Moneyness::Delta(option_type, _) => {
+                let w = option_type.sign();
+                let sd = vol * t.sqrt();
+                let d1 = ((forward / strike).ln() + 0.5 * sd * sd) / sd;
+                Moneyness::Delta(option_type, w * normal_cdf(w * d1))
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::math::Interpolation;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Calendar, Date, DayCounter};
+
+/// Nelson-Siegel curve with the instantaneous forward
+/// `f(t) = level + slope e^(-t/decay) + curvature t/decay e^(-t/decay)`.
+///
+/// The curve is linear in its first three parameters, so that for a common
+/// decay averages and differences of parameters are averages and
+/// differences of zero and forward rates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NelsonSiegel {
+    pub level: Rate,
+    pub slope: Rate,
+    pub curvature: Rate,
+    pub decay: Time,
+}
+
+impl NelsonSiegel {
+    pub fn new(level: Rate, slope: Rate, curvature: Rate, decay: Time) -> NelsonSiegel {
+        assert!(decay > 0.0, "non positive decay");
+        NelsonSiegel {
+            level,
+            slope,
+            curvature,
+            decay,
+        }
+    }
+
+    pub fn forward(&self, t: Time) -> Rate {
+        let x = t / self.decay;
+        self.level + self.slope * (-x).exp() + self.curvature * x * (-x).exp()
+    }
+
+    /// Continuously compounded zero rate.
+    pub fn zero_rate(&self, t: Time) -> Rate {
+        let x = t / self.decay;
+        if x < 1.0e-8 {
+            return self.level + self.slope;
+        }
+        let loading = (1.0 - (-x).exp()) / x;
+        self.level + self.slope * loading + self.curvature * (loading - (-x).exp())
+    }
+
+    pub fn discount(&self, t: Time) -> DiscountFactor {
+        (-self.zero_rate(t) * t).exp()
+    }
+
+    /// Parameters `weight` of the way from `self` to `other`.
+    pub fn interpolate(&self, other: &NelsonSiegel, weight: f64) -> NelsonSiegel {
+        let mix = |a: f64, b: f64| a + weight * (b - a);
+        NelsonSiegel::new(
+            mix(self.level, other.level),
+            mix(self.slope, other.slope),
+            mix(self.curvature, other.curvature),
+            mix(self.decay, other.decay),
+        )
+    }
+
+    /// Parameter average of `curves`.
+    pub fn average(curves: &[NelsonSiegel]) -> NelsonSiegel {
+        assert!(!curves.is_empty(), "no curves given");
+        let n = curves.len() as f64;
+        let mean = |f: fn(&NelsonSiegel) -> f64| curves.iter().map(f).sum::<f64>() / n;
+        NelsonSiegel::new(
+            mean(|c| c.level),
+            mean(|c| c.slope),
+            mean(|c| c.curvature),
+            mean(|c| c.decay),
+        )
+    }
+
+    /// The spread of `self` over `base`.
+    pub fn spread_over(&self, base: &NelsonSiegel) -> CurveSpread {
+        CurveSpread {
+            curve: *self,
+            base: *base,
+        }
+    }
+
+    /// Discount curve through the curve discounts at `dates`, the first
+    /// one being the reference date.
+    pub fn discount_curve<C: Cal, DC: DayCounter>(
+        &self,
+        dates: Vec<Date>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+        interpolation: Interpolation,
+    ) -> InterpolatedDiscountCurve<C, DC> {
+        let reference = dates[0];
+        let discounts = dates
+            .iter()
+            .map(|d| self.discount(day_counter.year_fraction(reference, *d, None, None)))
+            .collect();
+        InterpolatedDiscountCurve::new(dates, discounts, calendar, day_counter, interpolation)
+    }
+}
+
+/// Difference of two Nelson-Siegel curves.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CurveSpread {
+    pub curve: NelsonSiegel,
+    pub base: NelsonSiegel,
+}
+
+impl CurveSpread {
+    pub fn zero_spread(&self, t: Time) -> Rate {
+        self.curve.zero_rate(t) - self.base.zero_rate(t)
+    }
+
+    pub fn forward_spread(&self, t: Time) -> Rate {
+        self.curve.forward(t) - self.base.forward(t)
+    }
+
+    /// The spread as a curve of its own when both curves share a decay.
+    pub fn parameters(&self) -> Option<NelsonSiegel> {
+        if self.curve.decay != self.base.decay {
+            return None;
+        }
+        Some(NelsonSiegel::new(
+            self.curve.level - self.base.level,
+            self.curve.slope - self.base.slope,
+            self.curve.curvature - self.base.curvature,
+            self.curve.decay,
+        ))
+    }
+}
+
+/// Nelson-Siegel parameters fitted on successive dates, e.g. daily fits of
+/// a government curve.
+#[derive(Clone, Debug)]
+pub struct NelsonSiegelHistory {
+    pub dates: Vec<Date>,
+    pub curves: Vec<NelsonSiegel>,
+}
+
+impl NelsonSiegelHistory {
+    pub fn new(dates: Vec<Date>, curves: Vec<NelsonSiegel>) -> NelsonSiegelHistory {
+        assert!(!dates.is_empty(), "no curves given");
+        assert!(
+            dates.len() == curves.len(),
+            "dates and curves size mismatch"
+        );
+        assert!(
+            dates.windows(2).all(|w| w[0] < w[1]),
+            "dates must be increasing"
+        );
+        NelsonSiegelHistory { dates, curves }
+    }
+
+    /// Parameters interpolated linearly between the fits around `date`,
+    /// flat outside the history.
+    pub fn at(&self, date: Date) -> NelsonSiegel {
+        let n = self.dates.len();
+        if date <= self.dates[0] {
+            return self.curves[0];
+        }
+        if date >= self.dates[n - 1] {
+            return self.curves[n - 1];
+        }
+        let i = self.dates.iter().position(|d| *d > date).unwrap();
+        let weight =
+            date.sub(self.dates[i - 1]) as f64 / self.dates[i].sub(self.dates[i - 1]) as f64;
+        self.curves[i - 1].interpolate(&self.curves[i], weight)
+    }
+
+    /// Parameters at `date` after the history, extending the trend between
+    /// the last two fits; the decay is held at its last value.
+    pub fn forecast(&self, date: Date) -> NelsonSiegel {
+        let n = self.dates.len();
+        assert!(n >= 2, "at least two fits needed");
+        assert!(
+            date >= self.dates[n - 1],
+            "forecast date within the history"
+        );
+        let weight =
+            date.sub(self.dates[n - 2]) as f64 / self.dates[n - 1].sub(self.dates[n - 2]) as f64;
+        let last = self.curves[n - 1];
+        NelsonSiegel {
+            decay: last.decay,
+            ..self.curves[n - 2].interpolate(&last, weight)
+        }
+    }
+
+    /// Parameter average over the history.
+    pub fn average(&self) -> NelsonSiegel {
+        NelsonSiegel::average(&self.curves)
+    }
+}
@@ -0,0 +1,197 @@
+use super::interpolateddiscountcurve::InterpolatedDiscountCurve;
+use super::traits::RateHelper;
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Time};
+use crate::errors::QuantLibError;
+use crate::math::{Brent, Interpolation, Solver1D};
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+use crate::utils::trace::Level;
+use crate::utils::Parallelism;
+
+/// Largest quote error accepted at each node, and accuracy of the
+/// logarithm of its discount factor.
+const ACCURACY: f64 = 1.0e-12;
+/// Passes over the nodes allowed for non-local schemes.
+const MAX_ITERATIONS: usize = 100;
+
+/// Discount curve bootstrapped on market instruments, one node per helper
+/// pillar, and the implied minus quoted rate of each helper in the order
+/// given.
+#[derive(Clone)]
+pub struct PiecewiseYieldCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub curve: InterpolatedDiscountCurve<C, DC>,
+    pub errors: Vec<f64>,
+}
+
+impl<C: Cal, DC: DayCounter> PiecewiseYieldCurve<C, DC> {
+    /// Going through the helpers by pillar date, the discount factor of
+    /// each pillar is solved for so that its helper reprices, the curve
+    /// past the solved nodes keeping the last segment's forward. As the
+    /// nodes of non-local schemes move each other, those are first built
+    /// log-linear and then solved again node by node until they settle.
+    /// `LogLinear` gives piecewise flat forwards. Fails without helpers,
+    /// on pillars not after the reference date or shared by two helpers,
+    /// and at the first node no discount factor reprices.
+    pub fn new(
+        reference_date: Date,
+        helpers: &[&dyn RateHelper],
+        calendar: Calendar<C>,
+        day_counter: DC,
+        interpolation: Interpolation,
+    ) -> Result<PiecewiseYieldCurve<C, DC>, QuantLibError> {
+        if helpers.is_empty() {
+            return Err(QuantLibError::NoHelpers);
+        }
+        let mut sorted = helpers.to_vec();
+        sorted.sort_by_key(|h| h.pillar_date());
+        if sorted[0].pillar_date() <= reference_date {
+            return Err(QuantLibError::InvalidPillar {
+                pillar_date: sorted[0].pillar_date(),
+                what: format!("not after the reference date {}", reference_date),
+            });
+        }
+        if let Some(w) = sorted
+            .windows(2)
+            .find(|w| w[0].pillar_date() == w[1].pillar_date())
+        {
+            return Err(QuantLibError::InvalidPillar {
+                pillar_date: w[0].pillar_date(),
+                what: "shared by two helpers".to_string(),
+            });
+        }
+
+        let mut dates = vec![reference_date];
+        dates.extend(sorted.iter().map(|h| h.pillar_date()));
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        let build = |discounts: &[DiscountFactor], interpolation: Interpolation| {
+            InterpolatedDiscountCurve::new(
+                dates[..discounts.len()].to_vec(),
+                discounts.to_vec(),
+                calendar,
+                day_counter,
+                interpolation,
+            )
+        };
+
+        // first pass on a local scheme, extending the curve node by node
+        let local = match interpolation {
+            Interpolation::Linear | Interpolation::LogLinear => interpolation,
+            _ => Interpolation::LogLinear,
+        };
+        let mut discounts: Vec<DiscountFactor> = vec![1.0];
+        for (k, helper) in sorted.iter().enumerate() {
+            let j = k + 1;
+            let forward = if j == 1 {
+                helper.initial_forward()
+            } else {
+                (discounts[j - 2] / discounts[j - 1]).ln() / (times[j - 1] - times[j - 2])
+            };
+            discounts.push(discounts[j - 1] * (-forward * (times[j] - times[j - 1])).exp());
+            solve(&mut discounts, j, *helper, times[j], |d| build(d, local))?;
+        }
+        if interpolation != local {
+            for _ in 0..MAX_ITERATIONS {
+                let previous = discounts.clone();
+                for (k, helper) in sorted.iter().enumerate() {
+                    solve(&mut discounts, k + 1, *helper, times[k + 1], |d| {
+                        build(d, interpolation)
+                    })?;
+                }
+                let change = previous
+                    .iter()
+                    .zip(&discounts)
+                    .map(|(a, b)| (a - b).abs())
+                    .fold(0.0, f64::max);
+                if change < ACCURACY {
+                    break;
+                }
+            }
+        }
+
+        let curve = build(&discounts, interpolation);
+        let errors = helpers
+            .iter()
+            .map(|h| h.implied_quote_on(&|d| curve.discount(d)) - h.quote())
+            .collect();
+        Ok(PiecewiseYieldCurve { curve, errors })
+    }
+
+    /// Bootstraps independent curves, e.g. the discount curves of several
+    /// currencies, under `parallelism`, in the order of `curves`. Helpers
+    /// hold quotes observed on their own thread, so each curve is built by
+    /// `bootstrap` on its worker from its plain market data, e.g. the quote
+    /// of each instrument.
+    pub fn bootstrap_all<T, F>(
+        curves: &[T],
+        parallelism: Parallelism,
+        bootstrap: F,
+    ) -> Vec<Result<PiecewiseYieldCurve<C, DC>, QuantLibError>>
+    where
+        T: Sync,
+        C: Send,
+        DC: Send,
+        F: Fn(&T) -> Result<PiecewiseYieldCurve<C, DC>, QuantLibError> + Sync,
+    {
+        parallelism.map(curves, bootstrap)
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.curve.discount(date)
+    }
+
+    /// The (date, discount) pairs the curve interpolates.
+    pub fn nodes(&self) -> Vec<(Date, DiscountFactor)> {
+        self.curve.nodes()
+    }
+
+    /// A term structure discounting with this curve.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        self.curve.term_structure()
+    }
+}
+
+/// Solves for the discount at node `j` by Brent's method on its
+/// logarithm, bracketed from its current value, holding the other nodes.
+fn solve<C, DC, B>(
+    discounts: &mut [DiscountFactor],
+    j: usize,
+    helper: &dyn RateHelper,
+    t: Time,
+    build: B,
+) -> Result<(), QuantLibError>
+where
+    C: Cal,
+    DC: DayCounter,
+    B: Fn(&[DiscountFactor]) -> InterpolatedDiscountCurve<C, DC>,
+{
+    let _span = crate::trace_span!(
+        Level::Debug,
+        "piecewise_yield_curve.bootstrap",
+        node = j,
+        time = t
+    );
+    let quote = helper.quote();
+    let error = |x: f64| {
+        let mut trial = discounts.to_vec();
+        trial[j] = x.exp();
+        let curve = build(&trial);
+        helper.implied_quote_on(&|d| curve.discount(d)) - quote
+    };
+    let guess = discounts[j].ln();
+    if error(guess).abs() < ACCURACY {
+        return Ok(());
+    }
+    let x = Brent::new(ACCURACY)
+        .checked_solve_from_guess(error, guess, 1.0e-4 * t)
+        .map_err(|cause| QuantLibError::BootstrapFailed {
+            pillar_date: helper.pillar_date(),
+            cause: Box::new(cause),
+        })?;
+    discounts[j] = x.exp();
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use super::stepforwardcurve::StepForwardCurve;
+use crate::definitions::{DiscountFactor, Rate};
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter, Month};
+
+/// Policy rate path, one rate before the first meeting and one after each,
+/// with its probability.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolicyScenario {
+    pub probability: f64,
+    pub rates: Vec<Rate>,
+}
+
+impl PolicyScenario {
+    pub fn new(probability: f64, rates: Vec<Rate>) -> PolicyScenario {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "probability must be in [0, 1]"
+        );
+        PolicyScenario { probability, rates }
+    }
+}
+
+/// Short end curve as a probability weighted mixture of policy paths over
+/// the same meeting dates, each path a step forward curve.
+#[derive(Clone)]
+pub struct PolicyScenarioMixture<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub scenarios: Vec<PolicyScenario>,
+    pub curves: Vec<StepForwardCurve<C, DC>>,
+}
+
+impl<C: Cal, DC: DayCounter> PolicyScenarioMixture<C, DC> {
+    pub fn new(
+        reference_date: Date,
+        meeting_dates: Vec<Date>,
+        scenarios: Vec<PolicyScenario>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> PolicyScenarioMixture<C, DC> {
+        assert!(!scenarios.is_empty(), "no scenarios given");
+        let total: f64 = scenarios.iter().map(|s| s.probability).sum();
+        assert!(
+            (total - 1.0).abs() < 1.0e-12,
+            "probabilities must sum to one"
+        );
+        let curves = scenarios
+            .iter()
+            .map(|s| {
+                StepForwardCurve::new(
+                    reference_date,
+                    meeting_dates.clone(),
+                    s.rates.clone(),
+                    calendar,
+                    day_counter,
+                )
+            })
+            .collect();
+        PolicyScenarioMixture { scenarios, curves }
+    }
+
+    /// The curve of the expected policy path.
+    pub fn expected_curve(&self) -> StepForwardCurve<C, DC> {
+        let first = &self.curves[0];
+        let forwards = (0..first.forwards.len())
+            .map(|k| {
+                self.scenarios
+                    .iter()
+                    .map(|s| s.probability * s.rates[k])
+                    .sum()
+            })
+            .collect();
+        StepForwardCurve::new(
+            first.reference_date,
+            first.meeting_dates.clone(),
+            forwards,
+            first.calendar,
+            first.day_counter,
+        )
+    }
+
+    /// Expected policy rate in force on `date`.
+    pub fn expected_policy_rate(&self, date: Date) -> Rate {
+        self.scenarios
+            .iter()
+            .zip(&self.curves)
+            .map(|(s, c)| s.probability * c.policy_rate(date))
+            .sum()
+    }
+
+    /// Probability weighted discount factor; above the expected path's by
+    /// the convexity of the discount in the rate.
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.scenarios
+            .iter()
+            .zip(&self.curves)
+            .map(|(s, c)| s.probability * c.discount(date))
+            .sum()
+    }
+}
+
+/// One month average overnight rate future, e.g. 30 day Fed funds, quoted
+/// as 100 minus the average rate over its calendar month in percent.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AverageRateFuture {
+    pub start: Date,
+    pub end: Date,
+    pub price: f64,
+}
+
+impl AverageRateFuture {
+    pub fn new(month: Month, year: i32, price: f64) -> AverageRateFuture {
+        let start = Date::new(1, month, year);
+        AverageRateFuture {
+            start,
+            end: start.add_months(1),
+            price,
+        }
+    }
+
+    pub fn rate(&self) -> Rate {
+        (100.0 - self.price) / 100.0
+    }
+
+    fn contains(&self, date: Date) -> bool {
+        date >= self.start && date < self.end
+    }
+}
+
+/// Rates priced around a meeting and the probability of a move of one
+/// step; above one, more than one step is priced, and negative values are
+/// cut probabilities.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MeetingProbability {
+    pub meeting_date: Date,
+    pub rate_before: Rate,
+    pub rate_after: Rate,
+    pub probability: f64,
+}
+
+/// Backs out the move priced at each meeting from average rate futures,
+/// the new rate applying from the meeting date on.
+///
+/// The rate after a meeting is the next month's future when no meeting
+/// falls in that month, and otherwise comes from the meeting month's
+/// average given the rate before; the rate before is the previous month's
+/// future when that month has no meeting, and otherwise the rate after
+/// the previous meeting, starting from `current_rate`.
+pub fn implied_move_probabilities(
+    current_rate: Rate,
+    meeting_dates: &[Date],
+    futures: &[AverageRateFuture],
+    step: Rate,
+) -> Vec<MeetingProbability> {
+    assert!(step != 0.0, "zero step");
+    let future_of = |d: Date| futures.iter().find(|f| f.contains(d));
+    let without_meeting = |f: &&AverageRateFuture| !meeting_dates.iter().any(|m| f.contains(*m));
+    let mut rate = current_rate;
+    meeting_dates
+        .iter()
+        .map(|&meeting| {
+            let month = future_of(meeting).expect("no future for the meeting month");
+            let before = future_of(month.start.add_days(-1))
+                .filter(without_meeting)
+                .map(|f| f.rate())
+                .unwrap_or(rate);
+            let after = match future_of(month.end).filter(without_meeting) {
+                Some(next) => next.rate(),
+                None => {
+                    let days = month.end.sub(month.start) as f64;
+                    let share = meeting.sub(month.start) as f64 / days;
+                    (month.rate() - share * before) / (1.0 - share)
+                }
+            };
+            rate = after;
+            MeetingProbability {
+                meeting_date: meeting,
+                rate_before: before,
+                rate_after: after,
+                probability: (after - before) / step,
+            }
+        })
+        .collect()
+}
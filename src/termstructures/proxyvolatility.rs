@@ -0,0 +1,89 @@
+use super::traits::BlackVolTermStructure;
+use crate::definitions::{Time, Volatility};
+use crate::indexes::EquityIndex;
+use crate::math::Matrix;
+use std::rc::Rc;
+
+/// Index volatility built from the volatilities of its constituents, for
+/// indexes without liquid options: with weights `w` and correlations
+/// `rho`, `sigma_I^2 = sum_ij w_i w_j rho_ij sigma_i sigma_j`.
+///
+/// Each constituent volatility is read at the same moneyness as the index
+/// strike, i.e. at `strike / level` times its spot.
+pub struct ProxyIndexVolatility<V: BlackVolTermStructure> {
+    pub index: EquityIndex,
+    pub surfaces: Vec<Rc<V>>,
+    pub correlation: Matrix,
+}
+
+impl<V: BlackVolTermStructure> ProxyIndexVolatility<V> {
+    pub fn new(
+        index: EquityIndex,
+        surfaces: Vec<Rc<V>>,
+        correlation: Matrix,
+    ) -> ProxyIndexVolatility<V> {
+        let n = index.constituents.len();
+        assert!(surfaces.len() == n, "one surface per constituent needed");
+        assert!(
+            correlation.rows() == n && correlation.columns() == n,
+            "correlation and constituents size mismatch"
+        );
+        for i in 0..n {
+            assert!(
+                (correlation[(i, i)] - 1.0).abs() < 1.0e-12,
+                "unit correlation diagonal expected"
+            );
+            for j in 0..n {
+                assert!(
+                    correlation[(i, j)] == correlation[(j, i)] && correlation[(i, j)].abs() <= 1.0,
+                    "correlation must be symmetric and within [-1, 1]"
+                );
+            }
+        }
+        ProxyIndexVolatility {
+            index,
+            surfaces,
+            correlation,
+        }
+    }
+
+    /// The same correlation between every pair of constituents.
+    pub fn with_constant_correlation(
+        index: EquityIndex,
+        surfaces: Vec<Rc<V>>,
+        correlation: f64,
+    ) -> ProxyIndexVolatility<V> {
+        let n = index.constituents.len();
+        let mut matrix = Matrix::new(n, n, correlation);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        ProxyIndexVolatility::new(index, surfaces, matrix)
+    }
+
+    /// Constituent volatilities at the moneyness of `strike`.
+    pub fn constituent_vols(&self, t: Time, strike: f64) -> Vec<Volatility> {
+        let moneyness = strike / self.index.level;
+        self.index
+            .constituents
+            .iter()
+            .zip(&self.surfaces)
+            .map(|(c, s)| s.black_vol(t, moneyness * c.spot))
+            .collect()
+    }
+}
+
+impl<V: BlackVolTermStructure> BlackVolTermStructure for ProxyIndexVolatility<V> {
+    fn black_vol(&self, t: Time, strike: f64) -> Volatility {
+        let vols = self.constituent_vols(t, strike);
+        let w = self.index.weights();
+        let n = vols.len();
+        let mut variance = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                variance += w[i] * w[j] * self.correlation[(i, j)] * vols[i] * vols[j];
+            }
+        }
+        variance.max(0.0).sqrt()
+    }
+}
@@ -0,0 +1,281 @@
+use super::traits::{RateHelper, YieldTermStructure};
+use crate::cashflows::{self as cf, CashFlow};
+use crate::definitions::DiscountFactor;
+use crate::instruments::{BasisSwap, DiFuture};
+use crate::quotes::Quote;
+use crate::time::date as df;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Date, DayCounter, Schedule};
+use crate::utils::trace::Level;
+use std::rc::Rc;
+
+/// Quoted basis spread of a single-currency basis swap, e.g. 3M vs 6M
+/// Euribor or ESTR vs 3M Euribor.
+///
+/// With the discount curve and the projection curve of the other leg
+/// known, the quote pins down the curve the spread leg is projected off:
+/// the coupons of the spread leg are forecast off the curve being built
+/// over their accrual periods, so that each is worth `P(start) / P(end) -
+/// 1` plus the spread, the other leg keeping its own forecasts.
+pub struct BasisSwapRateHelper<Q, CF1, CF2, Y>
+where
+    Q: Quote,
+    CF1: CashFlow,
+    CF2: CashFlow,
+    Y: YieldTermStructure,
+{
+    pub quote: Q,
+    pub swap: BasisSwap<CF1, CF2>,
+    pub discount_curve: Rc<Y>,
+    pub settlement_date: Date,
+}
+
+impl<Q, CF1, CF2, Y> BasisSwapRateHelper<Q, CF1, CF2, Y>
+where
+    Q: Quote,
+    CF1: CashFlow,
+    CF2: CashFlow,
+    Y: YieldTermStructure,
+{
+    pub fn new(
+        quote: Q,
+        swap: BasisSwap<CF1, CF2>,
+        discount_curve: Rc<Y>,
+        settlement_date: Date,
+    ) -> BasisSwapRateHelper<Q, CF1, CF2, Y> {
+        assert!(
+            swap.spread_leg.iter().all(|c| c.try_as_coup().is_some()),
+            "spread leg flows must be coupons"
+        );
+        BasisSwapRateHelper {
+            quote,
+            swap,
+            discount_curve,
+            settlement_date,
+        }
+    }
+}
+
+impl<Q, CF1, CF2, Y> RateHelper for BasisSwapRateHelper<Q, CF1, CF2, Y>
+where
+    Q: Quote,
+    CF1: CashFlow,
+    CF2: CashFlow,
+    Y: YieldTermStructure,
+{
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid basis quote");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, projection: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        let curve = self.discount_curve.as_ref();
+        let (mut forecast, mut annuity) = (0.0, 0.0);
+        for c in &self.swap.spread_leg {
+            if CashFlow::has_occured(c, self.settlement_date, false) {
+                continue;
+            }
+            let c = c.try_as_coup().unwrap();
+            let discount = c.nominal() * curve.discount(c.date(), true);
+            let (start, end) = (c.accrual_start_date(), c.accrual_end_date());
+            forecast += (projection(start) / projection(end) - 1.0) * discount;
+            annuity += c.accrual_period() * discount;
+        }
+        assert!(
+            annuity != 0.0,
+            "spread leg has no sensitivity to the spread"
+        );
+        let implied = (self.swap.other_leg_npv(curve, self.settlement_date) - forecast) / annuity;
+        crate::trace_event!(
+            Level::Trace,
+            "basis_swap_helper.implied_quote",
+            quote = self.quote.value(),
+            implied = implied
+        );
+        implied
+    }
+    fn pillar_date(&self) -> Date {
+        df::max(
+            cf::maturity_date(&self.swap.spread_leg),
+            cf::maturity_date(&self.swap.other_leg),
+        )
+    }
+    fn initial_forward(&self) -> f64 {
+        self.swap
+            .other_leg
+            .iter()
+            .find_map(|c| c.try_as_coup())
+            .map_or(0.0, |c| c.rate())
+            + self.quote()
+    }
+}
+
+/// Quoted rate of a DI1 future, pinning down the discount factor of the
+/// Brazilian CDI curve at the contract maturity through
+/// `P(T) / P(s) = (1 + rate)^(-business days / 252)`.
+pub struct DiFutureRateHelper<Q: Quote, C: Cal> {
+    pub quote: Q,
+    pub future: DiFuture<C>,
+    pub settlement_date: Date,
+}
+
+impl<Q: Quote, C: Cal> DiFutureRateHelper<Q, C> {
+    pub fn new(quote: Q, future: DiFuture<C>, settlement_date: Date) -> DiFutureRateHelper<Q, C> {
+        DiFutureRateHelper {
+            quote,
+            future,
+            settlement_date,
+        }
+    }
+}
+
+impl<Q: Quote, C: Cal> RateHelper for DiFutureRateHelper<Q, C> {
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid DI1 quote");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        let growth = discount(self.settlement_date) / discount(self.future.maturity);
+        let t = self.future.day_counter.year_fraction(
+            self.settlement_date,
+            self.future.maturity,
+            None,
+            None,
+        );
+        assert!(t > 0.0, "expired DI1 contract");
+        growth.powf(1.0 / t) - 1.0
+    }
+    fn pillar_date(&self) -> Date {
+        self.future.maturity
+    }
+    /// The DI1 rate compounds annually over business days.
+    fn initial_forward(&self) -> f64 {
+        self.quote().ln_1p()
+    }
+}
+
+/// Quoted simple rate of a deposit from `start_date` to `maturity`,
+/// `1 + rate tau = P(start) / P(maturity)`.
+pub struct DepositRateHelper<Q: Quote, DC: DayCounter> {
+    pub quote: Q,
+    pub start_date: Date,
+    pub maturity: Date,
+    pub day_counter: DC,
+}
+
+impl<Q: Quote, DC: DayCounter> DepositRateHelper<Q, DC> {
+    pub fn new(
+        quote: Q,
+        start_date: Date,
+        maturity: Date,
+        day_counter: DC,
+    ) -> DepositRateHelper<Q, DC> {
+        assert!(start_date < maturity, "deposit maturity before its start");
+        DepositRateHelper {
+            quote,
+            start_date,
+            maturity,
+            day_counter,
+        }
+    }
+}
+
+impl<Q: Quote, DC: DayCounter> RateHelper for DepositRateHelper<Q, DC> {
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid deposit quote");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        simple_forward(discount, self.start_date, self.maturity, self.day_counter)
+    }
+    fn pillar_date(&self) -> Date {
+        self.maturity
+    }
+}
+
+/// Quoted rate of a forward rate agreement on the period from
+/// `start_date` to `end_date`, e.g. 3x6 for a three month rate fixing in
+/// three months.
+pub struct FraRateHelper<Q: Quote, DC: DayCounter> {
+    pub quote: Q,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub day_counter: DC,
+}
+
+impl<Q: Quote, DC: DayCounter> FraRateHelper<Q, DC> {
+    pub fn new(
+        quote: Q,
+        start_date: Date,
+        end_date: Date,
+        day_counter: DC,
+    ) -> FraRateHelper<Q, DC> {
+        assert!(start_date < end_date, "FRA end before its start");
+        FraRateHelper {
+            quote,
+            start_date,
+            end_date,
+            day_counter,
+        }
+    }
+}
+
+impl<Q: Quote, DC: DayCounter> RateHelper for FraRateHelper<Q, DC> {
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid FRA quote");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        simple_forward(discount, self.start_date, self.end_date, self.day_counter)
+    }
+    fn pillar_date(&self) -> Date {
+        self.end_date
+    }
+}
+
+/// Quoted par rate of a swap paying a fixed leg on `fixed_schedule`
+/// against a floating leg projected off the curve being built, so that
+/// the floating leg is worth `P(start) - P(maturity)`.
+pub struct SwapRateHelper<Q: Quote, DC: DayCounter> {
+    pub quote: Q,
+    pub fixed_schedule: Schedule,
+    pub fixed_day_counter: DC,
+}
+
+impl<Q: Quote, DC: DayCounter> SwapRateHelper<Q, DC> {
+    pub fn new(quote: Q, fixed_schedule: Schedule, fixed_day_counter: DC) -> SwapRateHelper<Q, DC> {
+        assert!(fixed_schedule.dates.len() >= 2, "no fixed periods given");
+        SwapRateHelper {
+            quote,
+            fixed_schedule,
+            fixed_day_counter,
+        }
+    }
+}
+
+impl<Q: Quote, DC: DayCounter> RateHelper for SwapRateHelper<Q, DC> {
+    fn quote(&self) -> f64 {
+        assert!(self.quote.is_valid(), "invalid swap quote");
+        self.quote.value()
+    }
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        let dates = &self.fixed_schedule.dates;
+        let annuity: f64 = dates
+            .windows(2)
+            .map(|w| self.fixed_day_counter.year_fraction(w[0], w[1], None, None) * discount(w[1]))
+            .sum();
+        (discount(dates[0]) - discount(self.pillar_date())) / annuity
+    }
+    fn pillar_date(&self) -> Date {
+        self.fixed_schedule.dates[self.fixed_schedule.dates.len() - 1]
+    }
+}
+
+fn simple_forward<DC: DayCounter>(
+    discount: &dyn Fn(Date) -> DiscountFactor,
+    start: Date,
+    end: Date,
+    day_counter: DC,
+) -> f64 {
+    let tau = day_counter.year_fraction(start, end, None, None);
+    (discount(start) / discount(end) - 1.0) / tau
+}
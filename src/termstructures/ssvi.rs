@@ -0,0 +1,209 @@
+use super::traits::BlackVolTermStructure;
+use crate::definitions::{Time, Volatility};
+use crate::math::simplex_minimize;
+use crate::utils::trace::Level;
+
+/// Surface SVI (Gatheral and Jacquier 2014) with power-law curvature,
+/// giving the total variance at log-moneyness `k` as
+/// `w = theta/2 (1 + rho phi k + sqrt((phi k + rho)^2 + 1 - rho^2))`
+/// with `phi = eta / (theta^gamma (1 + theta)^(1 - gamma))`, where `theta`
+/// is the ATM total variance of the expiry.
+///
+/// The surface is free of calendar arbitrage as long as `theta` does not
+/// decrease with time, and of butterfly arbitrage when
+/// `eta (1 + |rho|) <= 2` and `gamma <= 1/2`.
+#[derive(Clone, Debug)]
+pub struct SsviSurface {
+    pub rho: f64,
+    pub eta: f64,
+    pub gamma: f64,
+    pub expiries: Vec<Time>,
+    pub atm_variances: Vec<f64>,
+    pub forwards: Vec<f64>,
+}
+
+impl SsviSurface {
+    pub fn new(
+        rho: f64,
+        eta: f64,
+        gamma: f64,
+        expiries: Vec<Time>,
+        atm_variances: Vec<f64>,
+        forwards: Vec<f64>,
+    ) -> SsviSurface {
+        assert!(rho.abs() < 1.0, "rho must be in (-1, 1)");
+        assert!(eta > 0.0, "non positive eta");
+        assert!(gamma > 0.0 && gamma <= 1.0, "gamma must be in (0, 1]");
+        assert!(!expiries.is_empty(), "no expiries given");
+        assert!(
+            expiries.len() == atm_variances.len() && expiries.len() == forwards.len(),
+            "expiry, variance and forward size mismatch"
+        );
+        assert!(expiries[0] > 0.0, "non positive expiry");
+        assert!(
+            expiries.windows(2).all(|t| t[0] < t[1]),
+            "expiries must be increasing"
+        );
+        assert!(atm_variances[0] > 0.0, "non positive ATM variance");
+        assert!(
+            atm_variances.windows(2).all(|v| v[0] <= v[1]),
+            "ATM total variance must not decrease"
+        );
+        SsviSurface {
+            rho,
+            eta,
+            gamma,
+            expiries,
+            atm_variances,
+            forwards,
+        }
+    }
+
+    /// Calibrates the surface to Black volatilities `vols[i]` quoted at
+    /// `strikes[i]` for each expiry.
+    ///
+    /// The ATM total variances are read off each smile and made
+    /// non-decreasing, which rules out calendar arbitrage; `rho`, `eta` and
+    /// `gamma` are then fitted by least squares within the butterfly
+    /// arbitrage-free region.
+    pub fn calibrate(
+        expiries: Vec<Time>,
+        forwards: Vec<f64>,
+        strikes: &[Vec<f64>],
+        vols: &[Vec<Volatility>],
+    ) -> SsviSurface {
+        assert!(
+            strikes.len() == expiries.len() && vols.len() == expiries.len(),
+            "quotes and expiries size mismatch"
+        );
+        let _span = crate::trace_span!(Level::Info, "ssvi.calibrate", expiries = expiries.len());
+        let mut quotes = Vec::new();
+        let mut atm_variances: Vec<f64> = Vec::new();
+        for i in 0..expiries.len() {
+            assert!(
+                strikes[i].len() == vols[i].len(),
+                "strike and vol size mismatch"
+            );
+            assert!(!strikes[i].is_empty(), "empty smile");
+            let mut smile: Vec<(f64, f64)> = strikes[i]
+                .iter()
+                .zip(&vols[i])
+                .map(|(s, v)| ((s / forwards[i]).ln(), v * v * expiries[i]))
+                .collect();
+            smile.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+            let theta = atm_variance(&smile);
+            let previous = atm_variances.last().cloned().unwrap_or(0.0);
+            atm_variances.push(theta.max(previous));
+            quotes.push(smile);
+        }
+
+        let parameters = |x: &[f64]| {
+            let rho = x[0].tanh();
+            let gamma = 0.5 * logistic(x[1]);
+            let eta = 2.0 / (1.0 + rho.abs()) * logistic(x[2]);
+            (rho, eta, gamma)
+        };
+        let error = |x: &[f64]| {
+            let (rho, eta, gamma) = parameters(x);
+            quotes
+                .iter()
+                .zip(&atm_variances)
+                .map(|(smile, &theta)| {
+                    smile
+                        .iter()
+                        .map(|(k, w)| (ssvi_variance(rho, eta, gamma, theta, *k) - w).powi(2))
+                        .sum::<f64>()
+                })
+                .sum()
+        };
+        let x = simplex_minimize(error, &[-0.3, 0.0, 0.6], 0.5, 1.0e-14, 5000);
+        let (rho, eta, gamma) = parameters(&x);
+        crate::trace_event!(
+            Level::Debug,
+            "ssvi.calibrated",
+            rho = rho,
+            eta = eta,
+            gamma = gamma,
+            error = error(&x)
+        );
+        SsviSurface::new(rho, eta, gamma, expiries, atm_variances, forwards)
+    }
+
+    pub fn phi(&self, theta: f64) -> f64 {
+        phi(self.eta, self.gamma, theta)
+    }
+
+    /// ATM total variance, interpolated linearly in time.
+    pub fn atm_variance(&self, t: Time) -> f64 {
+        let n = self.expiries.len();
+        if t <= self.expiries[0] {
+            return self.atm_variances[0] * t / self.expiries[0];
+        }
+        if t >= self.expiries[n - 1] {
+            return self.atm_variances[n - 1] * t / self.expiries[n - 1];
+        }
+        let i = self.expiries.iter().position(|&e| e >= t).unwrap();
+        let alpha = (t - self.expiries[i - 1]) / (self.expiries[i] - self.expiries[i - 1]);
+        (1.0 - alpha) * self.atm_variances[i - 1] + alpha * self.atm_variances[i]
+    }
+
+    /// Forward, interpolated log-linearly in time.
+    pub fn forward(&self, t: Time) -> f64 {
+        let n = self.expiries.len();
+        if t <= self.expiries[0] {
+            return self.forwards[0];
+        }
+        if t >= self.expiries[n - 1] {
+            return self.forwards[n - 1];
+        }
+        let i = self.expiries.iter().position(|&e| e >= t).unwrap();
+        let alpha = (t - self.expiries[i - 1]) / (self.expiries[i] - self.expiries[i - 1]);
+        ((1.0 - alpha) * self.forwards[i - 1].ln() + alpha * self.forwards[i].ln()).exp()
+    }
+
+    /// Total variance at `t` and forward log-moneyness `k`.
+    pub fn total_variance(&self, t: Time, k: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        ssvi_variance(self.rho, self.eta, self.gamma, self.atm_variance(t), k)
+    }
+}
+
+impl BlackVolTermStructure for SsviSurface {
+    fn black_vol(&self, t: Time, strike: f64) -> Volatility {
+        let t = t.max(1.0e-8);
+        let k = (strike / self.forward(t)).ln();
+        (self.total_variance(t, k) / t).sqrt()
+    }
+    fn black_variance(&self, t: Time, strike: f64) -> f64 {
+        self.total_variance(t, (strike / self.forward(t)).ln())
+    }
+}
+
+fn phi(eta: f64, gamma: f64, theta: f64) -> f64 {
+    eta / (theta.powf(gamma) * (1.0 + theta).powf(1.0 - gamma))
+}
+
+fn ssvi_variance(rho: f64, eta: f64, gamma: f64, theta: f64, k: f64) -> f64 {
+    let pk = phi(eta, gamma, theta) * k;
+    0.5 * theta * (1.0 + rho * pk + ((pk + rho).powi(2) + 1.0 - rho * rho).sqrt())
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Total variance at zero log-moneyness, interpolated linearly between the
+/// quotes around it.
+fn atm_variance(smile: &[(f64, f64)]) -> f64 {
+    match smile.iter().position(|q| q.0 >= 0.0) {
+        None => smile[smile.len() - 1].1,
+        Some(0) => smile[0].1,
+        Some(i) => {
+            let (k1, w1) = smile[i - 1];
+            let (k2, w2) = smile[i];
+            w1 + (w2 - w1) * (0.0 - k1) / (k2 - k1)
+        }
+    }
+}
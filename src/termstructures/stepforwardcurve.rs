@@ -0,0 +1,201 @@
+use super::yieldtermstructure::YieldTermStructure;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::math::{linear_least_squares, Matrix};
+use crate::quotes::SimpleQuote;
+use crate::time::traits::Calendar as Cal;
+use crate::time::{Actual365Fixed, Calendar, Date, DayCounter};
+
+/// Weight of the policy rate steps in the calibration; small enough not to
+/// move quotes that pin the forwards down, it keeps the forwards of
+/// periods without quotes at their neighbours' level.
+const STEP_PENALTY: f64 = 1.0e-6;
+
+/// Short end curve with instantaneous forwards constant between central
+/// bank meeting dates: `forwards[0]` applies up to the first meeting and
+/// `forwards[i]` from meeting `i` on, so the forwards read as the expected
+/// policy rate.
+#[derive(Clone)]
+pub struct StepForwardCurve<C: Cal, DC: DayCounter = Actual365Fixed> {
+    pub reference_date: Date,
+    pub calendar: Calendar<C>,
+    pub day_counter: DC,
+    pub meeting_dates: Vec<Date>,
+    pub meeting_times: Vec<Time>,
+    pub forwards: Vec<Rate>,
+}
+
+/// Calibrated step forward curve and the model minus quoted OIS rate of
+/// each quote.
+#[derive(Clone)]
+pub struct StepForwardCalibration<C: Cal, DC: DayCounter> {
+    pub curve: StepForwardCurve<C, DC>,
+    pub errors: Vec<Rate>,
+}
+
+impl<C: Cal, DC: DayCounter> StepForwardCurve<C, DC> {
+    pub fn new(
+        reference_date: Date,
+        meeting_dates: Vec<Date>,
+        forwards: Vec<Rate>,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> StepForwardCurve<C, DC> {
+        assert!(
+            forwards.len() == meeting_dates.len() + 1,
+            "one forward per meeting plus the current one needed"
+        );
+        assert!(
+            meeting_dates.iter().all(|d| *d > reference_date)
+                && meeting_dates.windows(2).all(|w| w[0] < w[1]),
+            "meeting dates must be increasing and after the reference date"
+        );
+        let meeting_times = meeting_dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d, None, None))
+            .collect();
+        StepForwardCurve {
+            reference_date,
+            calendar,
+            day_counter,
+            meeting_dates,
+            meeting_times,
+            forwards,
+        }
+    }
+
+    /// Fits the forwards to single payment OIS quotes `(maturity, rate)`
+    /// starting on the reference date, with `1 + rate tau = 1 / P(T)` on
+    /// `quote_day_counter`, by least squares on the log discounts.
+    pub fn calibrate<QDC: DayCounter>(
+        reference_date: Date,
+        meeting_dates: Vec<Date>,
+        quotes: &[(Date, Rate)],
+        quote_day_counter: QDC,
+        calendar: Calendar<C>,
+        day_counter: DC,
+    ) -> StepForwardCalibration<C, DC> {
+        assert!(!quotes.is_empty(), "no quotes given");
+        let n = meeting_dates.len() + 1;
+        let shape = Self::new(
+            reference_date,
+            meeting_dates,
+            vec![0.0; n],
+            calendar,
+            day_counter,
+        );
+        let m = quotes.len();
+        let mut a = Matrix::new(m + n - 1, n, 0.0);
+        let mut b = vec![0.0; m + n - 1];
+        for (i, &(maturity, rate)) in quotes.iter().enumerate() {
+            assert!(maturity > reference_date, "expired quote");
+            let t = shape.time_from_reference(maturity);
+            for k in 0..n {
+                a[(i, k)] = shape.overlap(k, t);
+            }
+            let tau = quote_day_counter.year_fraction(reference_date, maturity, None, None);
+            b[i] = (1.0 + rate * tau).ln();
+        }
+        for k in 1..n {
+            a[(m + k - 1, k)] = STEP_PENALTY;
+            a[(m + k - 1, k - 1)] = -STEP_PENALTY;
+        }
+        let curve = StepForwardCurve {
+            forwards: linear_least_squares(&a, &b),
+            ..shape
+        };
+        let errors = quotes
+            .iter()
+            .map(|&(maturity, rate)| curve.ois_rate(maturity, quote_day_counter) - rate)
+            .collect();
+        StepForwardCalibration { curve, errors }
+    }
+
+    /// Time spent in period `k` up to `t`.
+    fn overlap(&self, k: usize, t: Time) -> Time {
+        let start = if k == 0 {
+            0.0
+        } else {
+            self.meeting_times[k - 1]
+        };
+        let end = self.meeting_times.get(k).cloned().unwrap_or(f64::INFINITY);
+        (t.min(end) - start).max(0.0)
+    }
+
+    pub fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter
+            .year_fraction(self.reference_date, date, None, None)
+    }
+
+    pub fn instantaneous_forward(&self, t: Time) -> Rate {
+        let k = self.meeting_times.iter().filter(|m| **m <= t).count();
+        self.forwards[k]
+    }
+
+    /// The forward in force on `date`, i.e. the implied policy rate.
+    pub fn policy_rate(&self, date: Date) -> Rate {
+        self.instantaneous_forward(self.time_from_reference(date))
+    }
+
+    /// Average of the forwards from `start` to `end`, as settles an
+    /// average overnight rate future over the period.
+    pub fn average_forward(&self, start: Date, end: Date) -> Rate {
+        let (t1, t2) = (
+            self.time_from_reference(start),
+            self.time_from_reference(end),
+        );
+        assert!(t2 > t1 && t1 >= 0.0, "invalid averaging period");
+        (self.discount_with_time(t1) / self.discount_with_time(t2)).ln() / (t2 - t1)
+    }
+
+    /// Change of the implied policy rate at each meeting.
+    pub fn steps(&self) -> Vec<(Date, Rate)> {
+        self.meeting_dates
+            .iter()
+            .zip(self.forwards.windows(2))
+            .map(|(d, f)| (*d, f[1] - f[0]))
+            .collect()
+    }
+
+    pub fn discount_with_time(&self, t: Time) -> DiscountFactor {
+        discount(&self.meeting_times, &self.forwards, t)
+    }
+
+    pub fn discount(&self, date: Date) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date))
+    }
+
+    /// Fixed rate of the single payment OIS from the reference date to
+    /// `maturity`.
+    pub fn ois_rate<QDC: DayCounter>(&self, maturity: Date, quote_day_counter: QDC) -> Rate {
+        let tau = quote_day_counter.year_fraction(self.reference_date, maturity, None, None);
+        (1.0 / self.discount(maturity) - 1.0) / tau
+    }
+
+    /// A term structure discounting with this curve.
+    pub fn term_structure(&self) -> YieldTermStructure<C, SimpleQuote, DC> {
+        let (times, forwards) = (self.meeting_times.clone(), self.forwards.clone());
+        YieldTermStructure::new(
+            self.calendar,
+            self.reference_date,
+            self.day_counter,
+            0,
+            vec![],
+            vec![],
+            Box::new(move |t| discount(&times, &forwards, t)),
+        )
+    }
+}
+
+fn discount(meeting_times: &[Time], forwards: &[Rate], t: Time) -> DiscountFactor {
+    let mut integral = 0.0;
+    let mut start = 0.0;
+    for (k, f) in forwards.iter().enumerate() {
+        let end = meeting_times.get(k).cloned().unwrap_or(f64::INFINITY);
+        integral += f * (t.min(end) - start).max(0.0);
+        if t <= end {
+            break;
+        }
+        start = end;
+    }
+    (-integral).exp()
+}
@@ -0,0 +1,237 @@
+use super::traits::BlackVolTermStructure;
+use crate::definitions::{Time, Volatility};
+use crate::math::{linear_least_squares, simplex_minimize, Matrix};
+use crate::utils::trace::Level;
+use crate::utils::Parallelism;
+
+/// Raw SVI parameterization of a smile (Gatheral 2004), giving the total
+/// implied variance at log-moneyness `k = ln(K/F)` as
+/// `w(k) = a + b (rho (k - m) + sqrt((k - m)^2 + sigma^2))`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SviParameters {
+    pub a: f64,
+    pub b: f64,
+    pub rho: f64,
+    pub m: f64,
+    pub sigma: f64,
+}
+
+impl SviParameters {
+    pub fn new(a: f64, b: f64, rho: f64, m: f64, sigma: f64) -> SviParameters {
+        assert!(b >= 0.0, "negative b");
+        assert!(rho.abs() < 1.0, "rho must be in (-1, 1)");
+        assert!(sigma > 0.0, "non positive sigma");
+        assert!(
+            a + b * sigma * (1.0 - rho * rho).sqrt() >= 0.0,
+            "negative minimum variance"
+        );
+        SviParameters {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        }
+    }
+
+    pub fn total_variance(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        self.a + self.b * (self.rho * x + (x * x + self.sigma * self.sigma).sqrt())
+    }
+
+    /// Largest slope of the wings, which Lee's moment formula bounds by 2.
+    pub fn max_wing_slope(&self) -> f64 {
+        self.b * (1.0 + self.rho.abs())
+    }
+
+    /// Least-squares fit to total variances `w` at log-moneyness `k`.
+    ///
+    /// Uses the quasi-explicit method of De Marco and Martini: for given
+    /// `m` and `sigma` the best `a`, `b` and `rho` solve a linear problem,
+    /// which is projected onto the constraints of a non-negative variance
+    /// and of wings no steeper than Lee's bound; `m` and `sigma` are then
+    /// found by a simplex search.
+    pub fn fit(k: &[f64], w: &[f64]) -> SviParameters {
+        assert!(k.len() == w.len(), "moneyness and variance size mismatch");
+        assert!(k.len() >= 5, "at least five quotes needed");
+        let _span = crate::trace_span!(Level::Info, "svi.fit", quotes = k.len());
+
+        let atm = (0..k.len())
+            .min_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap())
+            .unwrap();
+        let error = |x: &[f64]| fit_linear(k, w, x[0], x[1].exp()).1;
+        let x = simplex_minimize(error, &[k[atm], 0.1_f64.ln()], 0.1, 1.0e-14, 5000);
+        let (m, sigma) = (x[0], x[1].exp());
+        let ((a, d, c), error) = fit_linear(k, w, m, sigma);
+        crate::trace_event!(
+            Level::Debug,
+            "svi.fitted",
+            m = m,
+            sigma = sigma,
+            error = error
+        );
+        let (b, rho) = if c > 0.0 {
+            (c / sigma, (d / c).clamp(-0.999_999, 0.999_999))
+        } else {
+            (0.0, 0.0)
+        };
+        SviParameters::new(
+            a.max(-b * sigma * (1.0 - rho * rho).sqrt()),
+            b,
+            rho,
+            m,
+            sigma,
+        )
+    }
+}
+
+/// Best `(a, d, c)` of `w = a + d y + c sqrt(y^2 + 1)` with
+/// `y = (k - m) / sigma`, i.e. `d = rho b sigma` and `c = b sigma`, under
+/// the SVI constraints, together with the sum of squared errors.
+fn fit_linear(k: &[f64], w: &[f64], m: f64, sigma: f64) -> ((f64, f64, f64), f64) {
+    let y: Vec<f64> = k.iter().map(|k| (k - m) / sigma).collect();
+    let rows: Vec<Vec<f64>> = y
+        .iter()
+        .map(|y| vec![1.0, *y, (y * y + 1.0).sqrt()])
+        .collect();
+    let x = linear_least_squares(&Matrix::from_rows(&rows), w);
+    let (mut d, mut c) = (x[1], x[2].max(0.0));
+    if d.abs() > c {
+        d = d.signum() * c;
+    }
+    // Lee: b (1 + |rho|) <= 2.
+    if c + d.abs() > 2.0 * sigma {
+        let scale = 2.0 * sigma / (c + d.abs());
+        c *= scale;
+        d *= scale;
+    }
+    let residual = |i: usize| w[i] - d * y[i] - c * (y[i] * y[i] + 1.0).sqrt();
+    let mut a = (0..y.len()).map(residual).sum::<f64>() / y.len() as f64;
+    a = a.max(-(c * c - d * d).max(0.0).sqrt());
+    let sse = (0..y.len()).map(|i| (residual(i) - a).powi(2)).sum();
+    ((a, d, c), sse)
+}
+
+/// SVI smile of a single expiry.
+#[derive(Copy, Clone, Debug)]
+pub struct SviSmile {
+    pub expiry: Time,
+    pub forward: f64,
+    pub parameters: SviParameters,
+}
+
+impl SviSmile {
+    pub fn new(expiry: Time, forward: f64, parameters: SviParameters) -> SviSmile {
+        assert!(expiry > 0.0, "non positive expiry");
+        assert!(forward > 0.0, "non positive forward");
+        SviSmile {
+            expiry,
+            forward,
+            parameters,
+        }
+    }
+
+    /// Fits the smile to Black volatilities quoted at `strikes`.
+    pub fn fit(expiry: Time, forward: f64, strikes: &[f64], vols: &[Volatility]) -> SviSmile {
+        assert!(strikes.len() == vols.len(), "strike and vol size mismatch");
+        let k: Vec<f64> = strikes.iter().map(|s| (s / forward).ln()).collect();
+        let w: Vec<f64> = vols.iter().map(|v| v * v * expiry).collect();
+        SviSmile::new(expiry, forward, SviParameters::fit(&k, &w))
+    }
+
+    pub fn total_variance(&self, strike: f64) -> f64 {
+        self.parameters.total_variance((strike / self.forward).ln())
+    }
+
+    pub fn vol(&self, strike: f64) -> Volatility {
+        (self.total_variance(strike) / self.expiry).sqrt()
+    }
+}
+
+/// Volatility surface made of SVI smiles; total variance is interpolated
+/// linearly in time at constant forward moneyness, and extrapolated with
+/// the volatility of the first and last smiles.
+#[derive(Clone, Debug)]
+pub struct SviSurface {
+    pub smiles: Vec<SviSmile>,
+}
+
+impl SviSurface {
+    pub fn new(smiles: Vec<SviSmile>) -> SviSurface {
+        assert!(!smiles.is_empty(), "no smiles given");
+        assert!(
+            smiles.windows(2).all(|s| s[0].expiry < s[1].expiry),
+            "smile expiries must be increasing"
+        );
+        SviSurface { smiles }
+    }
+
+    /// Fits one smile per expiry to Black volatilities `vols[i]` quoted at
+    /// `strikes[i]`. The smiles are calibrated independently, so that the
+    /// fits run under `parallelism`.
+    pub fn fit(
+        expiries: &[Time],
+        forwards: &[f64],
+        strikes: &[Vec<f64>],
+        vols: &[Vec<Volatility>],
+        parallelism: Parallelism,
+    ) -> SviSurface {
+        assert!(
+            forwards.len() == expiries.len()
+                && strikes.len() == expiries.len()
+                && vols.len() == expiries.len(),
+            "quotes and expiries size mismatch"
+        );
+        let indices: Vec<usize> = (0..expiries.len()).collect();
+        let smiles = parallelism.map(&indices, |&i| {
+            SviSmile::fit(expiries[i], forwards[i], &strikes[i], &vols[i])
+        });
+        SviSurface::new(smiles)
+    }
+
+    /// Total variance at `t` and forward moneyness `ln(K/F(t))`, with the
+    /// forward interpolated log-linearly between smiles.
+    pub fn total_variance_at_moneyness(&self, t: Time, k: f64) -> f64 {
+        let first = &self.smiles[0];
+        let last = &self.smiles[self.smiles.len() - 1];
+        if t <= first.expiry {
+            return first.parameters.total_variance(k) * t / first.expiry;
+        }
+        if t >= last.expiry {
+            return last.parameters.total_variance(k) * t / last.expiry;
+        }
+        let i = self.smiles.iter().position(|s| s.expiry >= t).unwrap();
+        let (s1, s2) = (&self.smiles[i - 1], &self.smiles[i]);
+        let alpha = (t - s1.expiry) / (s2.expiry - s1.expiry);
+        (1.0 - alpha) * s1.parameters.total_variance(k) + alpha * s2.parameters.total_variance(k)
+    }
+
+    pub fn forward(&self, t: Time) -> f64 {
+        let first = &self.smiles[0];
+        let last = &self.smiles[self.smiles.len() - 1];
+        if t <= first.expiry {
+            return first.forward;
+        }
+        if t >= last.expiry {
+            return last.forward;
+        }
+        let i = self.smiles.iter().position(|s| s.expiry >= t).unwrap();
+        let (s1, s2) = (&self.smiles[i - 1], &self.smiles[i]);
+        let alpha = (t - s1.expiry) / (s2.expiry - s1.expiry);
+        ((1.0 - alpha) * s1.forward.ln() + alpha * s2.forward.ln()).exp()
+    }
+}
+
+impl BlackVolTermStructure for SviSurface {
+    fn black_vol(&self, t: Time, strike: f64) -> Volatility {
+        let t = t.max(1.0e-8);
+        let k = (strike / self.forward(t)).ln();
+        (self.total_variance_at_moneyness(t, k) / t).sqrt()
+    }
+    fn black_variance(&self, t: Time, strike: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        self.total_variance_at_moneyness(t, (strike / self.forward(t)).ln())
+    }
+}
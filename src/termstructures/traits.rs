@@ -1,11 +1,16 @@
 use super::compounding::Compounding;
 use super::interestrate::InterestRate;
-use crate::definitions::{DiscountFactor, Time};
-use crate::time::traits::Calendar as Cal;
-use crate::time::Calendar;
+use super::moneyness::Moneyness;
+use crate::definitions::{DiscountFactor, Rate, Time, Volatility};
+use crate::errors::{check_time_range, QuantLibError};
+use crate::patterns::Observable;
 use crate::time::Date;
 use crate::time::DayCounter;
 use crate::time::Frequency;
+
+/// Time step standing in for zero length periods.
+pub(crate) const DT: Time = 0.0001;
+
 /// `TermStructure` describes the behavior of a simple term structure.
 ///
 ///
@@ -25,16 +30,45 @@ pub trait TermStructure {
 
     /// The date at which discount = 1.0 and/or variance = 0.0.
     fn reference_date(&mut self) -> Date;
+
+    /// Notifies of changes of the market data the curve is built on.
+    fn observable(&self) -> Option<&Observable> {
+        None
+    }
 }
 
 pub trait YieldTermStructure: TermStructure {
     type D: DayCounter;
+
+    /// The day counter of the curve times, in which zero rates to a time
+    /// are quoted.
+    fn day_counter(&self) -> Self::D;
+
     /// Returns the discount factor for a given date or time. In the
     /// latter case, the double is calculated as a fraction of year from the
     /// reference date.
-    fn discount(&self, date: Date, extrapolate: bool) -> DiscountFactor;
-    ///
-    fn discount_with_time(&self, time: Time, extrapolate: bool) -> DiscountFactor;
+    fn discount(&self, date: Date, extrapolate: bool) -> DiscountFactor {
+        self.discount_with_time(self.time_from_reference(date), extrapolate)
+    }
+    fn discount_with_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.checked_discount_with_time(time, extrapolate)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// `discount`, reporting range violations and invalid discounts as
+    /// errors rather than panicking.
+    fn checked_discount(
+        &self,
+        date: Date,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        self.checked_discount_with_time(self.time_from_reference(date), extrapolate)
+    }
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError>;
 
     /// These methods return the implied zero-yield rate for a given date or time.
     /// In the latter case, the time is calculated as a fraction of year from the
@@ -46,15 +80,91 @@ pub trait YieldTermStructure: TermStructure {
         comp: Compounding,
         freq: Frequency,
         extrapolate: bool,
-    ) -> InterestRate<Self::D>;
-    ///
+    ) -> InterestRate<Self::D> {
+        self.checked_zero_rate(date, result_day_counter, comp, freq, extrapolate)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
     fn zero_rate_with_time(
         &mut self,
         time: Time,
         comp: Compounding,
         freq: Frequency,
         extrapolate: bool,
-    ) -> InterestRate<Self::D>;
+    ) -> InterestRate<Self::D> {
+        self.checked_zero_rate_with_time(time, comp, freq, extrapolate)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// `zero_rate`, reporting the errors of the discount factor it is
+    /// implied from.
+    fn checked_zero_rate(
+        &mut self,
+        date: Date,
+        result_day_counter: Self::D,
+        comp: Compounding,
+        freq: Frequency,
+        extrapolate: bool,
+    ) -> Result<InterestRate<Self::D>, QuantLibError> {
+        let reference_date = self.reference_date();
+        if date == reference_date {
+            let compound = 1.0 / self.checked_discount_with_time(DT, extrapolate)?;
+            return Ok(InterestRate::implied_rate_with_time(
+                compound,
+                result_day_counter,
+                comp,
+                freq,
+                DT,
+            ));
+        }
+        let compound = 1.0 / self.checked_discount(date, extrapolate)?;
+        Ok(InterestRate::implied_rate(
+            compound,
+            result_day_counter,
+            comp,
+            freq,
+            reference_date,
+            date,
+            None,
+            None,
+        ))
+    }
+    fn checked_zero_rate_with_time(
+        &mut self,
+        time: Time,
+        comp: Compounding,
+        freq: Frequency,
+        extrapolate: bool,
+    ) -> Result<InterestRate<Self::D>, QuantLibError> {
+        let t = if time == 0.0 { DT } else { time };
+        let compound = 1.0 / self.checked_discount_with_time(t, extrapolate)?;
+        Ok(InterestRate::implied_rate_with_time(
+            compound,
+            self.day_counter(),
+            comp,
+            freq,
+            t,
+        ))
+    }
+
+    /// Continuously compounded zero rate to `date` in the curve day
+    /// counter, i.e. the arithmetic average of the instantaneous forwards.
+    fn continuous_zero_rate(&mut self, date: Date, extrapolate: bool) -> InterestRate<Self::D> {
+        let t = self.time_from_reference(date);
+        self.zero_rate_with_time(t, Compounding::Continuous, Frequency::Annual, extrapolate)
+    }
+
+    /// Simple (money-market) zero rate to `date` in the curve day counter.
+    fn simple_zero_rate(&mut self, date: Date, extrapolate: bool) -> InterestRate<Self::D> {
+        let t = self.time_from_reference(date);
+        self.zero_rate_with_time(t, Compounding::Simple, Frequency::Annual, extrapolate)
+    }
+
+    /// Annually compounded zero rate to `date` in the curve day counter,
+    /// i.e. the geometric average of the yearly growth.
+    fn annual_zero_rate(&mut self, date: Date, extrapolate: bool) -> InterestRate<Self::D> {
+        let t = self.time_from_reference(date);
+        self.zero_rate_with_time(t, Compounding::Compounded, Frequency::Annual, extrapolate)
+    }
 
     /// These methods returns the forward interest rate between two dates or times.
     /// In the latter case, times are calculated as fractions of year from the
@@ -68,7 +178,10 @@ pub trait YieldTermStructure: TermStructure {
         comp: Compounding,
         freq: Frequency,
         extrapolate: bool,
-    ) -> InterestRate<Self::D>;
+    ) -> InterestRate<Self::D> {
+        self.checked_forward_rate(d1, d2, result_day_counter, comp, freq, extrapolate)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
     fn forward_rate_with_time(
         &mut self,
@@ -78,5 +191,133 @@ pub trait YieldTermStructure: TermStructure {
         comp: Compounding,
         freq: Frequency,
         extrapolate: bool,
-    ) -> InterestRate<Self::D>;
+    ) -> InterestRate<Self::D> {
+        self.checked_forward_rate_with_time(t1, t2, result_day_counter, comp, freq, extrapolate)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// `forward_rate`, reporting periods ending before they start and the
+    /// errors of the discount factors it is implied from.
+    fn checked_forward_rate(
+        &mut self,
+        d1: Date,
+        d2: Date,
+        result_day_counter: Self::D,
+        comp: Compounding,
+        freq: Frequency,
+        extrapolate: bool,
+    ) -> Result<InterestRate<Self::D>, QuantLibError> {
+        if d1 == d2 {
+            let t = self.time_from_reference(d1);
+            return self.checked_forward_rate_with_time(
+                t,
+                t,
+                result_day_counter,
+                comp,
+                freq,
+                extrapolate,
+            );
+        }
+        if d2 < d1 {
+            return Err(QuantLibError::InvertedPeriod {
+                start: self.time_from_reference(d1),
+                end: self.time_from_reference(d2),
+            });
+        }
+        let compound =
+            self.checked_discount(d1, extrapolate)? / self.checked_discount(d2, extrapolate)?;
+        Ok(InterestRate::implied_rate(
+            compound,
+            result_day_counter,
+            comp,
+            freq,
+            d1,
+            d2,
+            None,
+            None,
+        ))
+    }
+    fn checked_forward_rate_with_time(
+        &mut self,
+        t1: Time,
+        t2: Time,
+        result_day_counter: Self::D,
+        comp: Compounding,
+        freq: Frequency,
+        extrapolate: bool,
+    ) -> Result<InterestRate<Self::D>, QuantLibError> {
+        if t2 < t1 {
+            return Err(QuantLibError::InvertedPeriod { start: t1, end: t2 });
+        }
+        let compound = if t1 == t2 {
+            // past the range check, the step around `t1` may extrapolate
+            check_time_range(t1, self.max_time(), extrapolate)?;
+            let t1 = (t1 - DT / 2.0).max(0.0);
+            self.checked_discount_with_time(t1, true)?
+                / self.checked_discount_with_time(t1 + DT, true)?
+        } else {
+            self.checked_discount_with_time(t1, extrapolate)?
+                / self.checked_discount_with_time(t2, extrapolate)?
+        };
+        let t = if t1 == t2 { DT } else { t2 - t1 };
+        Ok(InterestRate::implied_rate_with_time(
+            compound,
+            result_day_counter,
+            comp,
+            freq,
+            t,
+        ))
+    }
+}
+
+/// Market instrument quote a curve is bootstrapped on, its implied quote
+/// read off the discount factors of the curve being built.
+pub trait RateHelper {
+    /// The market quote.
+    fn quote(&self) -> f64;
+    /// The quote implied by the discount factors of `discount`.
+    fn implied_quote_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64;
+    /// The latest date the implied quote depends upon.
+    fn pillar_date(&self) -> Date;
+    /// Forward rate the bootstrap starts from on the first pillar.
+    fn initial_forward(&self) -> Rate {
+        self.quote()
+    }
+    /// The quoted minus the implied quote on `discount`.
+    fn quote_error_on(&self, discount: &dyn Fn(Date) -> DiscountFactor) -> f64 {
+        self.quote() - self.implied_quote_on(discount)
+    }
+}
+
+/// Black implied volatility by expiry time and strike.
+pub trait BlackVolTermStructure {
+    fn black_vol(&self, t: Time, strike: f64) -> Volatility;
+    /// Total Black variance `vol^2 * t`.
+    fn black_variance(&self, t: Time, strike: f64) -> f64 {
+        let vol = self.black_vol(t, strike);
+        vol * vol * t
+    }
+    /// Black variance between `t1` and `t2` at a fixed strike.
+    fn black_forward_variance(&self, t1: Time, t2: Time, strike: f64) -> f64 {
+        assert!(t2 >= t1, "t2 must not be earlier than t1");
+        self.black_variance(t2, strike) - self.black_variance(t1, strike)
+    }
+    fn black_forward_vol(&self, t1: Time, t2: Time, strike: f64) -> Volatility {
+        if t2 == t1 {
+            return self.black_vol(t1, strike);
+        }
+        let variance = self.black_forward_variance(t1, t2, strike);
+        assert!(variance >= 0.0, "negative forward variance");
+        (variance / (t2 - t1)).sqrt()
+    }
+    /// Black volatility at a point given in any moneyness convention, with
+    /// the forward to expiry `t`.
+    fn black_vol_at(&self, t: Time, moneyness: Moneyness, forward: f64) -> Volatility {
+        let strike = moneyness.strike(t, forward, |k| self.black_vol(t, k));
+        self.black_vol(t, strike)
+    }
+    fn black_variance_at(&self, t: Time, moneyness: Moneyness, forward: f64) -> f64 {
+        let strike = moneyness.strike(t, forward, |k| self.black_vol(t, k));
+        self.black_variance(t, strike)
+    }
 }
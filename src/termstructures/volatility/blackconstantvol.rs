@@ -0,0 +1,23 @@
+use crate::definitions::{Time, Volatility};
+use crate::quotes::{Quote, SimpleQuote};
+use crate::termstructures::traits::BlackVolTermStructure;
+
+/// Black volatility the same at all expiries and strikes, read from its
+/// quote on each call so that it follows market updates.
+#[derive(Clone, Debug)]
+pub struct BlackConstantVol<Q: Quote = SimpleQuote> {
+    pub volatility: Q,
+}
+
+impl<Q: Quote> BlackConstantVol<Q> {
+    pub fn new(volatility: Q) -> BlackConstantVol<Q> {
+        assert!(volatility.value() >= 0.0, "negative volatility");
+        BlackConstantVol { volatility }
+    }
+}
+
+impl<Q: Quote> BlackVolTermStructure for BlackConstantVol<Q> {
+    fn black_vol(&self, _t: Time, _strike: f64) -> Volatility {
+        self.volatility.value()
+    }
+}
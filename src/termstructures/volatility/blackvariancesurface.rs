@@ -0,0 +1,108 @@
+use crate::definitions::{Time, Volatility};
+use crate::math::{Interpolation2D, Matrix};
+use crate::termstructures::traits::BlackVolTermStructure;
+
+/// Volatility surface interpolating total Black variance over a grid of
+/// expiries and strikes, with `vols[(j, i)]` quoted at `strikes[j]` and
+/// `expiries[i]`.
+///
+/// A zero variance node is added at `t = 0`; beyond the last expiry the
+/// volatility is kept constant in time. Below and above the strike range
+/// the variance follows the lower and upper strike extrapolation, flat by
+/// default.
+#[derive(Clone, Debug)]
+pub struct BlackVarianceSurface<I: Interpolation2D> {
+    pub expiries: Vec<Time>,
+    pub strikes: Vec<f64>,
+    pub vols: Matrix,
+    pub lower_extrapolation: StrikeExtrapolation,
+    pub upper_extrapolation: StrikeExtrapolation,
+    variances: I,
+}
+
+/// How a surface extends past the strikes it is quoted on.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StrikeExtrapolation {
+    /// The variance of the nearest quoted strike.
+    Constant,
+    /// The interpolation carried on from the edge cells, floored at zero
+    /// variance.
+    Interpolator,
+}
+
+impl<I: Interpolation2D> BlackVarianceSurface<I> {
+    pub fn new(expiries: Vec<Time>, strikes: Vec<f64>, vols: Matrix) -> BlackVarianceSurface<I> {
+        assert!(!expiries.is_empty(), "no expiries given");
+        assert!(expiries[0] > 0.0, "non positive expiry");
+        assert!(
+            vols.rows() == strikes.len() && vols.columns() == expiries.len(),
+            "vol matrix and grid size mismatch"
+        );
+        let mut times = vec![0.0];
+        times.extend_from_slice(&expiries);
+        let mut variances = Matrix::new(strikes.len(), times.len(), 0.0);
+        for j in 0..strikes.len() {
+            for (i, t) in expiries.iter().enumerate() {
+                variances[(j, i + 1)] = vols[(j, i)] * vols[(j, i)] * t;
+            }
+        }
+        for j in 0..strikes.len() {
+            assert!(
+                (1..times.len()).all(|i| variances[(j, i)] >= variances[(j, i - 1)]),
+                "decreasing variance at strike {}",
+                strikes[j]
+            );
+        }
+        BlackVarianceSurface {
+            variances: I::new(times, strikes.clone(), variances),
+            expiries,
+            strikes,
+            vols,
+            lower_extrapolation: StrikeExtrapolation::Constant,
+            upper_extrapolation: StrikeExtrapolation::Constant,
+        }
+    }
+
+    pub fn with_extrapolation(
+        mut self,
+        lower: StrikeExtrapolation,
+        upper: StrikeExtrapolation,
+    ) -> BlackVarianceSurface<I> {
+        self.lower_extrapolation = lower;
+        self.upper_extrapolation = upper;
+        self
+    }
+
+    /// `strike` moved onto the quoted range where the extrapolation is
+    /// constant.
+    fn extrapolated_strike(&self, strike: f64) -> f64 {
+        let (low, high) = (self.strikes[0], self.strikes[self.strikes.len() - 1]);
+        if strike < low && self.lower_extrapolation == StrikeExtrapolation::Constant {
+            low
+        } else if strike > high && self.upper_extrapolation == StrikeExtrapolation::Constant {
+            high
+        } else {
+            strike
+        }
+    }
+}
+
+impl<I: Interpolation2D> BlackVolTermStructure for BlackVarianceSurface<I> {
+    fn black_vol(&self, t: Time, strike: f64) -> Volatility {
+        let t = t.max(1.0e-8);
+        (self.black_variance(t, strike) / t).sqrt()
+    }
+    fn black_variance(&self, t: Time, strike: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let strike = self.extrapolated_strike(strike);
+        let last = self.expiries[self.expiries.len() - 1];
+        let variance = if t > last {
+            self.variances.value(last, strike) * t / last
+        } else {
+            self.variances.value(t, strike)
+        };
+        variance.max(0.0)
+    }
+}
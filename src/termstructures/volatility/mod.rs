@@ -0,0 +1,6 @@
+pub mod blackconstantvol;
+pub mod blackvariancesurface;
+
+pub use self::blackconstantvol::BlackConstantVol;
+pub use self::blackvariancesurface::{BlackVarianceSurface, StrikeExtrapolation};
+pub use crate::termstructures::traits::BlackVolTermStructure;
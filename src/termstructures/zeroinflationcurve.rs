@@ -0,0 +1,55 @@
+use crate::definitions::{Rate, Time};
+use crate::time::{Actual365Fixed, Date, DayCounter};
+
+/// Zero coupon inflation swap rates by fixing date, growing a price index
+/// from its fixing of the month of `base_date` at `(1 + z)^t`, times being
+/// measured Actual/365 from `base_date`.
+///
+/// Rates are interpolated linearly in time and flat outside the dates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZeroInflationCurve {
+    pub base_date: Date,
+    pub dates: Vec<Date>,
+    pub rates: Vec<Rate>,
+}
+
+impl ZeroInflationCurve {
+    pub fn new(base_date: Date, dates: Vec<Date>, rates: Vec<Rate>) -> ZeroInflationCurve {
+        assert!(!dates.is_empty(), "no curve dates given");
+        assert!(dates.len() == rates.len(), "dates and rates size mismatch");
+        assert!(
+            dates[0] > base_date && dates.windows(2).all(|w| w[0] < w[1]),
+            "dates must be increasing after the base date"
+        );
+        assert!(rates.iter().all(|z| *z > -1.0), "rate below -100%");
+        ZeroInflationCurve {
+            base_date,
+            dates,
+            rates,
+        }
+    }
+
+    pub fn time_from_base(&self, date: Date) -> Time {
+        Actual365Fixed.year_fraction(self.base_date, date, None, None)
+    }
+
+    pub fn zero_rate(&self, date: Date) -> Rate {
+        let t = self.time_from_base(date);
+        let times: Vec<Time> = self.dates.iter().map(|d| self.time_from_base(*d)).collect();
+        let n = times.len();
+        if t <= times[0] {
+            return self.rates[0];
+        }
+        if t >= times[n - 1] {
+            return self.rates[n - 1];
+        }
+        let i = times.iter().position(|x| *x >= t).unwrap();
+        let w = (t - times[i - 1]) / (times[i] - times[i - 1]);
+        self.rates[i - 1] + w * (self.rates[i] - self.rates[i - 1])
+    }
+
+    /// Growth of the index from the base date to `date`.
+    pub fn index_ratio(&self, date: Date) -> f64 {
+        (1.0 + self.zero_rate(date)).powf(self.time_from_base(date))
+    }
+}
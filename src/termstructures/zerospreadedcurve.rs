@@ -0,0 +1,53 @@
+use super::traits::TermStructure;
+use super::traits::YieldTermStructure as YTS;
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::errors::{check_discount, QuantLibError};
+use crate::time::Date;
+
+/// Curve shifted by a constant continuously compounded zero spread on the
+/// day counter of the underlying curve, e.g. to price at an option
+/// adjusted or Z-spread.
+pub struct ZeroSpreadedTermStructure<Y: YTS> {
+    pub curve: Y,
+    pub spread: Rate,
+}
+
+impl<Y: YTS> ZeroSpreadedTermStructure<Y> {
+    pub fn new(curve: Y, spread: Rate) -> ZeroSpreadedTermStructure<Y> {
+        ZeroSpreadedTermStructure { curve, spread }
+    }
+}
+
+impl<Y: YTS> YTS for ZeroSpreadedTermStructure<Y> {
+    type D = Y::D;
+    /// That of the underlying curve, which the spread shifts.
+    fn day_counter(&self) -> Self::D {
+        self.curve.day_counter()
+    }
+    fn checked_discount_with_time(
+        &self,
+        time: Time,
+        extrapolate: bool,
+    ) -> Result<DiscountFactor, QuantLibError> {
+        let discount = self.curve.checked_discount_with_time(time, extrapolate)?;
+        check_discount(discount * (-self.spread * time).exp())
+    }
+}
+
+impl<Y: YTS> TermStructure for ZeroSpreadedTermStructure<Y> {
+    fn max_date(&self) -> Date {
+        self.curve.max_date()
+    }
+    fn settlement_days(&self) -> i64 {
+        self.curve.settlement_days()
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.curve.time_from_reference(date)
+    }
+    fn max_time(&self) -> Time {
+        self.curve.max_time()
+    }
+    fn reference_date(&mut self) -> Date {
+        self.curve.reference_date()
+    }
+}
@@ -0,0 +1,468 @@
+//! Conformance corpora: expected outputs of QuantLib C++ for dates, day
+//! counters, calendars and schedules, checked against this crate.
+//!
+//! A corpus is a text file with one expectation per line; `#` starts a
+//! comment and dates are ISO `YYYY-MM-DD`:
+//!
+//! ```text
+//! tolerance 1e-10
+//! yearfraction ActualActual(ISMA) 2003-11-01 2004-05-01 2003-11-01 2004-05-01 0.5
+//! daycount Thirty360(USA) 2020-01-31 2020-03-31 60
+//! holidays Sweden 2020-01-01 2020-12-31 2020-01-01 2020-01-06 ...
+//! schedule WeekendsOnly 2020-01-31 2020-07-31 1M ModifiedFollowing Backward eom 2020-01-31 ...
+//! ```
+//!
+//! `yearfraction` takes the reference period dates optionally, `holidays`
+//! lists the holidays from the first to the second date which are not
+//! weekends (as `Calendar::holidayList` does by default) and `schedule`
+//! takes `eom` or `noeom`. New corpora only need a new file.
+
+use crate::time::traits::Calendar as Cal;
+use crate::time::{
+    Actual360, Actual365Fixed, ActualActual, Brazil, BusinessDayConvention, Calendar,
+    Convention360, ConventionActual, Date, DateGenerator, DayCounter, Month, NullCalendar, Period,
+    Schedule, Sweden, Thirty360, TimeUnit, Weekday, WeekendsOnly,
+};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Year fraction tolerance when a corpus does not set one.
+pub const DEFAULT_TOLERANCE: f64 = 1.0e-10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expectation {
+    YearFraction {
+        day_counter: String,
+        start: Date,
+        end: Date,
+        reference_period: Option<(Date, Date)>,
+        expected: f64,
+    },
+    DayCount {
+        day_counter: String,
+        start: Date,
+        end: Date,
+        expected: i64,
+    },
+    Holidays {
+        calendar: String,
+        from: Date,
+        to: Date,
+        expected: Vec<Date>,
+    },
+    Schedule {
+        calendar: String,
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        convention: BusinessDayConvention,
+        rule: DateGenerator,
+        end_of_month: bool,
+        expected: Vec<Date>,
+    },
+}
+
+/// An expectation and the corpus line it was read from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub line: usize,
+    pub expectation: Expectation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    pub corpus: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.corpus, self.line, self.message)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Corpus {
+    pub name: String,
+    pub tolerance: f64,
+    pub records: Vec<Record>,
+}
+
+impl Corpus {
+    pub fn parse(name: &str, text: &str) -> Result<Corpus, String> {
+        let mut corpus = Corpus {
+            name: name.to_string(),
+            tolerance: DEFAULT_TOLERANCE,
+            records: vec![],
+        };
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("");
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                continue;
+            }
+            let error = |message: String| format!("{}:{}: {}", name, i + 1, message);
+            if tokens[0] == "tolerance" {
+                corpus.tolerance = number(tokens.get(1).copied()).map_err(error)?;
+                continue;
+            }
+            let expectation = parse_expectation(&tokens).map_err(error)?;
+            corpus.records.push(Record {
+                line: i + 1,
+                expectation,
+            });
+        }
+        Ok(corpus)
+    }
+
+    pub fn load(path: &Path) -> Result<Corpus, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Corpus::parse(&name, &text)
+    }
+
+    /// Every expectation the crate does not reproduce.
+    pub fn check(&self) -> Vec<Mismatch> {
+        self.records
+            .iter()
+            .filter_map(|r| {
+                check_expectation(&r.expectation, self.tolerance)
+                    .err()
+                    .map(|message| Mismatch {
+                        corpus: self.name.clone(),
+                        line: r.line,
+                        message,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// All `.txt` corpora in `dir`, by file name.
+pub fn load_corpora(dir: &Path) -> Result<Vec<Corpus>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|x| x.to_str()) == Some("txt"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|p| Corpus::load(p)).collect()
+}
+
+fn parse_expectation(tokens: &[&str]) -> Result<Expectation, String> {
+    let at = |i: usize| tokens.get(i).copied();
+    match tokens[0] {
+        "yearfraction" if tokens.len() == 5 || tokens.len() == 7 => {
+            let reference_period = if tokens.len() == 7 {
+                Some((date(at(4))?, date(at(5))?))
+            } else {
+                None
+            };
+            Ok(Expectation::YearFraction {
+                day_counter: tokens[1].to_string(),
+                start: date(at(2))?,
+                end: date(at(3))?,
+                reference_period,
+                expected: number(tokens.last().copied())?,
+            })
+        }
+        "daycount" if tokens.len() == 5 => Ok(Expectation::DayCount {
+            day_counter: tokens[1].to_string(),
+            start: date(at(2))?,
+            end: date(at(3))?,
+            expected: tokens[4]
+                .parse()
+                .map_err(|_| format!("invalid day count {}", tokens[4]))?,
+        }),
+        "holidays" if tokens.len() >= 4 => Ok(Expectation::Holidays {
+            calendar: tokens[1].to_string(),
+            from: date(at(2))?,
+            to: date(at(3))?,
+            expected: dates(&tokens[4..])?,
+        }),
+        "schedule" if tokens.len() >= 9 => Ok(Expectation::Schedule {
+            calendar: tokens[1].to_string(),
+            effective_date: date(at(2))?,
+            termination_date: date(at(3))?,
+            tenor: period(tokens[4])?,
+            convention: convention(tokens[5])?,
+            rule: rule(tokens[6])?,
+            end_of_month: match tokens[7] {
+                "eom" => true,
+                "noeom" => false,
+                other => return Err(format!("expected eom or noeom, got {}", other)),
+            },
+            expected: dates(&tokens[8..])?,
+        }),
+        other => Err(format!("malformed {} record", other)),
+    }
+}
+
+fn check_expectation(expectation: &Expectation, tolerance: f64) -> Result<(), String> {
+    match expectation {
+        Expectation::YearFraction {
+            day_counter: name,
+            start,
+            end,
+            reference_period,
+            expected,
+        } => {
+            let (r1, r2) = match reference_period {
+                Some((r1, r2)) => (Some(*r1), Some(*r2)),
+                None => (None, None),
+            };
+            let got = day_counter(name)?.year_fraction(*start, *end, r1, r2);
+            if (got - expected).abs() > tolerance {
+                return Err(format!(
+                    "{} year fraction {} to {}: expected {}, got {}",
+                    name, start, end, expected, got
+                ));
+            }
+        }
+        Expectation::DayCount {
+            day_counter: name,
+            start,
+            end,
+            expected,
+        } => {
+            let got = day_counter(name)?.day_count(*start, *end);
+            if got != *expected {
+                return Err(format!(
+                    "{} day count {} to {}: expected {}, got {}",
+                    name, start, end, expected, got
+                ));
+            }
+        }
+        Expectation::Holidays {
+            calendar: name,
+            from,
+            to,
+            expected,
+        } => {
+            let c = calendar(name)?;
+            let mut got = vec![];
+            let mut d = *from;
+            while d <= *to {
+                if c.is_holiday(d) && !c.is_weekend(d.weekday()) {
+                    got.push(d);
+                }
+                d = d.add_days(1);
+            }
+            compare_dates(&format!("{} holidays", name), expected, &got)?;
+        }
+        Expectation::Schedule {
+            calendar: name,
+            effective_date,
+            termination_date,
+            tenor,
+            convention,
+            rule,
+            end_of_month,
+            expected,
+        } => {
+            let got = calendar(name)?
+                .schedule(
+                    *effective_date,
+                    *termination_date,
+                    *tenor,
+                    *convention,
+                    *rule,
+                    *end_of_month,
+                )
+                .dates;
+            compare_dates(&format!("{} schedule", name), expected, &got)?;
+        }
+    }
+    Ok(())
+}
+
+fn compare_dates(what: &str, expected: &[Date], got: &[Date]) -> Result<(), String> {
+    if expected == got {
+        return Ok(());
+    }
+    let show = |ds: &[Date]| {
+        ds.iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    Err(format!(
+        "{}: expected [{}], got [{}]",
+        what,
+        show(expected),
+        show(got)
+    ))
+}
+
+/// Object safe view of a day counter.
+trait DayCount {
+    fn day_count(&self, start: Date, end: Date) -> i64;
+    fn year_fraction(&self, start: Date, end: Date, r1: Option<Date>, r2: Option<Date>) -> f64;
+}
+
+impl<DC: DayCounter> DayCount for DC {
+    fn day_count(&self, start: Date, end: Date) -> i64 {
+        DayCounter::day_count(self, start, end)
+    }
+    fn year_fraction(&self, start: Date, end: Date, r1: Option<Date>, r2: Option<Date>) -> f64 {
+        DayCounter::year_fraction(self, start, end, r1, r2)
+    }
+}
+
+/// Object safe view of a calendar.
+trait DynCalendar {
+    fn is_holiday(&self, date: Date) -> bool;
+    fn is_weekend(&self, weekday: Weekday) -> bool;
+    fn schedule(
+        &self,
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        convention: BusinessDayConvention,
+        rule: DateGenerator,
+        end_of_month: bool,
+    ) -> Schedule;
+}
+
+impl<C: Cal> DynCalendar for Calendar<C> {
+    fn is_holiday(&self, date: Date) -> bool {
+        Calendar::is_holiday(self, date)
+    }
+    fn is_weekend(&self, weekday: Weekday) -> bool {
+        Calendar::is_weekend(self, weekday)
+    }
+    fn schedule(
+        &self,
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        convention: BusinessDayConvention,
+        rule: DateGenerator,
+        end_of_month: bool,
+    ) -> Schedule {
+        Schedule::new(
+            effective_date,
+            termination_date,
+            tenor,
+            *self,
+            convention,
+            convention,
+            rule,
+            end_of_month,
+        )
+    }
+}
+
+/// The day counter named as in the corpora, e.g. `ActualActual(ISMA)`.
+fn day_counter(name: &str) -> Result<Box<dyn DayCount>, String> {
+    let (base, convention) = match name.find('(') {
+        Some(i) if name.ends_with(')') => (&name[..i], Some(&name[i + 1..name.len() - 1])),
+        _ => (name, None),
+    };
+    match (base, convention) {
+        ("Actual360", None) => Ok(Box::new(Actual360)),
+        ("Actual365Fixed", None) => Ok(Box::new(Actual365Fixed {})),
+        ("ActualActual", Some(c)) => {
+            let convention = match c {
+                "ISMA" => ConventionActual::ISMA,
+                "Bond" => ConventionActual::Bond,
+                "ISDA" => ConventionActual::ISDA,
+                "Historical" => ConventionActual::Historical,
+                "Actual365" => ConventionActual::Actual365,
+                "AFB" => ConventionActual::AFB,
+                "Euro" => ConventionActual::Euro,
+                other => return Err(format!("unknown Actual/Actual convention {}", other)),
+            };
+            Ok(Box::new(ActualActual { convention }))
+        }
+        ("Thirty360", Some(c)) => {
+            let convention = match c {
+                "USA" => Convention360::USA,
+                "BondBasis" => Convention360::BondBasis,
+                "European" => Convention360::European,
+                "EurobondBasis" => Convention360::EurobondBasis,
+                "Italian" => Convention360::Italian,
+                other => return Err(format!("unknown 30/360 convention {}", other)),
+            };
+            Ok(Box::new(Thirty360 { convention }))
+        }
+        _ => Err(format!("unknown day counter {}", name)),
+    }
+}
+
+fn calendar(name: &str) -> Result<Box<dyn DynCalendar>, String> {
+    match name {
+        "NullCalendar" => Ok(Box::new(Calendar::new(NullCalendar))),
+        "WeekendsOnly" => Ok(Box::new(Calendar::new(WeekendsOnly))),
+        "Brazil" => Ok(Box::new(Calendar::new(Brazil))),
+        "Sweden" => Ok(Box::new(Calendar::new(Sweden))),
+        _ => Err(format!("unknown calendar {}", name)),
+    }
+}
+
+fn date(token: Option<&str>) -> Result<Date, String> {
+    let token = token.ok_or("missing date")?;
+    let parts: Vec<&str> = token.split('-').collect();
+    let invalid = || format!("invalid date {}", token);
+    if parts.len() != 3 {
+        return Err(invalid());
+    }
+    let year: i32 = parts[0].parse().map_err(|_| invalid())?;
+    let month: u32 = parts[1].parse().map_err(|_| invalid())?;
+    let day: u32 = parts[2].parse().map_err(|_| invalid())?;
+    let month = Month::from_int(month).ok_or_else(invalid)?;
+    if day == 0 || day as usize > Date::end_of_month(Date::new(1, month, year)).day_of_month() {
+        return Err(invalid());
+    }
+    Ok(Date::new(day, month, year))
+}
+
+fn dates(tokens: &[&str]) -> Result<Vec<Date>, String> {
+    tokens.iter().map(|t| date(Some(t))).collect()
+}
+
+fn number(token: Option<&str>) -> Result<f64, String> {
+    let token = token.ok_or("missing number")?;
+    token
+        .parse()
+        .map_err(|_| format!("invalid number {}", token))
+}
+
+fn period(token: &str) -> Result<Period, String> {
+    let invalid = || format!("invalid period {}", token);
+    let (length, unit) = token.split_at(token.len().saturating_sub(1));
+    let length: i64 = length.parse().map_err(|_| invalid())?;
+    let units = match unit {
+        "D" => TimeUnit::Days,
+        "W" => TimeUnit::Weeks,
+        "M" => TimeUnit::Months,
+        "Y" => TimeUnit::Years,
+        _ => return Err(invalid()),
+    };
+    Ok(Period::new(length, units))
+}
+
+fn convention(token: &str) -> Result<BusinessDayConvention, String> {
+    match token {
+        "Following" => Ok(BusinessDayConvention::Following),
+        "ModifiedFollowing" => Ok(BusinessDayConvention::ModifiedFollowing),
+        "Preceding" => Ok(BusinessDayConvention::Preceding),
+        "ModifiedPreceding" => Ok(BusinessDayConvention::ModifiedPreceding),
+        "Unadjusted" => Ok(BusinessDayConvention::Unadjusted),
+        _ => Err(format!("unknown business day convention {}", token)),
+    }
+}
+
+fn rule(token: &str) -> Result<DateGenerator, String> {
+    match token {
+        "Backward" => Ok(DateGenerator::Backward),
+        "Forward" => Ok(DateGenerator::Forward),
+        "Zero" => Ok(DateGenerator::Zero),
+        _ => Err(format!("unsupported date generation rule {}", token)),
+    }
+}
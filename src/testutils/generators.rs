@@ -0,0 +1,129 @@
+use crate::definitions::{Rate, Time};
+use crate::math::{BoxMullerGaussianRng, Rng as _, SplitMix64};
+use crate::termstructures::Compounding;
+use crate::time::{Date, Frequency, Month};
+use std::fmt::Debug;
+
+/// Draws the inputs of the property checks from a seeded `SplitMix64`, so
+/// that a failing case can be replayed from its seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    normals: BoxMullerGaussianRng<SplitMix64>,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            normals: BoxMullerGaussianRng::new(SplitMix64::new(seed)),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.normals.rng.next_u64()
+    }
+
+    /// Uniform number in `[0, 1)`.
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal number (Box-Muller).
+    pub fn normal(&mut self) -> f64 {
+        self.normals.next_sample()
+    }
+
+    /// Uniform number in `[low, high)`.
+    pub fn range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.uniform()
+    }
+
+    /// Uniform integer in `[low, high]`.
+    pub fn integer(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty integer range");
+        low + (self.next_u64() % ((high - low) as u64 + 1)) as i64
+    }
+
+    /// Picks one of the given values.
+    pub fn choose<T: Copy>(&mut self, values: &[T]) -> T {
+        values[self.integer(0, values.len() as i64 - 1) as usize]
+    }
+
+    /// Date uniformly drawn between `min` and `max`, both included.
+    pub fn date(&mut self, min: Date, max: Date) -> Date {
+        min.add_days(self.integer(0, max.sub(min)))
+    }
+
+    /// Date between 1950 and 2100.
+    pub fn any_date(&mut self) -> Date {
+        self.date(
+            Date::new(1, Month::January, 1950),
+            Date::new(31, Month::December, 2100),
+        )
+    }
+
+    /// Interest rate between -2% and 20%.
+    pub fn rate(&mut self) -> Rate {
+        self.range(-0.02, 0.20)
+    }
+
+    /// Time between one day and fifty years.
+    pub fn time(&mut self) -> Time {
+        self.range(1.0 / 365.0, 50.0)
+    }
+
+    pub fn compounding(&mut self) -> Compounding {
+        self.choose(&[
+            Compounding::Simple,
+            Compounding::Compounded,
+            Compounding::Continuous,
+            Compounding::SimpleThenCompounded,
+            Compounding::CompoundedThenSimple,
+        ])
+    }
+
+    /// A frequency usable with compounded rates.
+    pub fn frequency(&mut self) -> Frequency {
+        self.choose(&[
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::EveryFourthMonth,
+            Frequency::Quarterly,
+            Frequency::Bimonthly,
+            Frequency::Monthly,
+        ])
+    }
+
+    /// Increasing sequence of `n` dates starting at `start`, spaced between
+    /// `min_gap` and `max_gap` days.
+    pub fn dates(&mut self, start: Date, n: usize, min_gap: i64, max_gap: i64) -> Vec<Date> {
+        let mut dates = Vec::with_capacity(n);
+        let mut d = start;
+        for _ in 0..n {
+            dates.push(d);
+            d = d.add_days(self.integer(min_gap, max_gap));
+        }
+        dates
+    }
+}
+
+/// Checks `property` on `cases` inputs drawn by `generator` from `seed`.
+///
+/// Panics on the first failing input, reporting it together with the seed
+/// and case number needed to reproduce it.
+pub fn forall<T, G, P>(cases: usize, seed: u64, mut generator: G, mut property: P)
+where
+    T: Debug,
+    G: FnMut(&mut Rng) -> T,
+    P: FnMut(&T) -> bool,
+{
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let input = generator(&mut rng);
+        if !property(&input) {
+            panic!(
+                "property failed for case {} (seed {}): {:?}",
+                case, seed, input
+            );
+        }
+    }
+}
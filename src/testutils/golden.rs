@@ -0,0 +1,280 @@
+use crate::definitions::{Rate, Time};
+use crate::termstructures::Compounding;
+use crate::termstructures::Compounding::*;
+use crate::time::Frequency;
+use crate::time::Frequency::*;
+use crate::time::{Date, Month};
+
+/// Interest-rate conversion case: `rate` quoted with the first convention
+/// equals `expected`, rounded to `precision` decimals, in the second.
+#[derive(Copy, Clone, Debug)]
+pub struct RateConversionCase {
+    pub rate: Rate,
+    pub compounding: Compounding,
+    pub frequency: Frequency,
+    pub time: Time,
+    pub compounding2: Compounding,
+    pub frequency2: Frequency,
+    pub expected: Rate,
+    pub precision: i32,
+}
+
+const fn case(
+    rate: Rate,
+    compounding: Compounding,
+    frequency: Frequency,
+    time: Time,
+    compounding2: Compounding,
+    frequency2: Frequency,
+    expected: Rate,
+) -> RateConversionCase {
+    RateConversionCase {
+        rate,
+        compounding,
+        frequency,
+        time,
+        compounding2,
+        frequency2,
+        expected,
+        precision: 4,
+    }
+}
+
+/// Conversion cases of QuantLib's `InterestRateTest::testConversions`
+/// (Haug, "Option Pricing Formulas", pp. 181-182, plus undocumented ones).
+pub fn rate_conversion_cases() -> Vec<RateConversionCase> {
+    vec![
+        case(
+            0.0800, Compounded, Quarterly, 1.00, Continuous, Annual, 0.0792,
+        ),
+        case(0.1200, Continuous, Annual, 1.00, Compounded, Annual, 0.1275),
+        case(
+            0.0800, Compounded, Quarterly, 1.00, Compounded, Annual, 0.0824,
+        ),
+        case(
+            0.0700, Compounded, Quarterly, 1.00, Compounded, Semiannual, 0.0706,
+        ),
+        case(0.0100, Compounded, Annual, 1.00, Simple, Annual, 0.0100),
+        case(0.0200, Simple, Annual, 1.00, Compounded, Annual, 0.0200),
+        case(0.0300, Compounded, Semiannual, 0.50, Simple, Annual, 0.0300),
+        case(0.0400, Simple, Annual, 0.50, Compounded, Semiannual, 0.0400),
+        case(
+            0.0500,
+            Compounded,
+            EveryFourthMonth,
+            1.0 / 3.0,
+            Simple,
+            Annual,
+            0.0500,
+        ),
+        case(
+            0.0600,
+            Simple,
+            Annual,
+            1.0 / 3.0,
+            Compounded,
+            EveryFourthMonth,
+            0.0600,
+        ),
+        case(0.0500, Compounded, Quarterly, 0.25, Simple, Annual, 0.0500),
+        case(0.0600, Simple, Annual, 0.25, Compounded, Quarterly, 0.0600),
+        case(
+            0.0700,
+            Compounded,
+            Bimonthly,
+            1.0 / 6.0,
+            Simple,
+            Annual,
+            0.0700,
+        ),
+        case(
+            0.0800,
+            Simple,
+            Annual,
+            1.0 / 6.0,
+            Compounded,
+            Bimonthly,
+            0.0800,
+        ),
+        case(
+            0.0900,
+            Compounded,
+            Monthly,
+            1.0 / 12.0,
+            Simple,
+            Annual,
+            0.0900,
+        ),
+        case(
+            0.1000,
+            Simple,
+            Annual,
+            1.0 / 12.0,
+            Compounded,
+            Monthly,
+            0.1000,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.25,
+            Simple,
+            Annual,
+            0.0300,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.25,
+            Simple,
+            Semiannual,
+            0.0300,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.25,
+            Simple,
+            Quarterly,
+            0.0300,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.50,
+            Simple,
+            Annual,
+            0.0300,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.50,
+            Simple,
+            Semiannual,
+            0.0300,
+        ),
+        case(
+            0.0300,
+            SimpleThenCompounded,
+            Semiannual,
+            0.75,
+            Compounded,
+            Semiannual,
+            0.0300,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.25,
+            SimpleThenCompounded,
+            Quarterly,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.25,
+            SimpleThenCompounded,
+            Semiannual,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.25,
+            SimpleThenCompounded,
+            Annual,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Compounded,
+            Quarterly,
+            0.50,
+            SimpleThenCompounded,
+            Quarterly,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.50,
+            SimpleThenCompounded,
+            Semiannual,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.50,
+            SimpleThenCompounded,
+            Annual,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Compounded,
+            Quarterly,
+            0.75,
+            SimpleThenCompounded,
+            Quarterly,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Compounded,
+            Semiannual,
+            0.75,
+            SimpleThenCompounded,
+            Semiannual,
+            0.0400,
+        ),
+        case(
+            0.0400,
+            Simple,
+            Semiannual,
+            0.75,
+            SimpleThenCompounded,
+            Annual,
+            0.0400,
+        ),
+    ]
+}
+
+/// Day-count case: expected day count between two dates.
+#[derive(Copy, Clone, Debug)]
+pub struct DayCountCase {
+    pub start: Date,
+    pub end: Date,
+    pub days: i64,
+}
+
+/// Actual day counts across month ends, leap days and year ends.
+pub fn actual_day_count_cases() -> Vec<DayCountCase> {
+    let c = |d1, m1, y1, d2, m2, y2, days| DayCountCase {
+        start: Date::new(d1, m1, y1),
+        end: Date::new(d2, m2, y2),
+        days,
+    };
+    vec![
+        c(1, Month::January, 2020, 1, Month::February, 2020, 31),
+        c(28, Month::February, 2020, 1, Month::March, 2020, 2),
+        c(28, Month::February, 2021, 1, Month::March, 2021, 1),
+        c(31, Month::December, 1999, 1, Month::January, 2000, 1),
+        c(1, Month::January, 2000, 1, Month::January, 2001, 366),
+        c(1, Month::January, 2001, 1, Month::January, 2002, 365),
+        c(15, Month::June, 2002, 15, Month::December, 2002, 183),
+        c(1, Month::March, 2020, 28, Month::February, 2020, -2),
+    ]
+}
@@ -0,0 +1,255 @@
+use super::generators::Rng;
+use super::market::reference_date;
+use crate::definitions::{Rate, Time, Volatility};
+use crate::math::Interpolation;
+use crate::termstructures::{DiscountCurve, NelsonSiegel, SsviSurface};
+use crate::time::{Actual365Fixed, Calendar, DayCounter, WeekendsOnly};
+
+/// Draws in `[range.0, range.1)`.
+fn draw(rng: &mut Rng, range: (f64, f64)) -> f64 {
+    rng.range(range.0, range.1)
+}
+
+/// Nelson-Siegel family of discount curves as of `reference_date()`, with
+/// the instantaneous forward
+/// `f(t) = level + slope e^(-t/decay) + curvature t/decay e^(-t/decay)`
+/// and parameters drawn from the given ranges.
+///
+/// Draws whose forwards fall below `min_forward` are rejected, so that
+/// with the default floor of zero discount factors never increase; the
+/// curves interpolate their pillars convex monotonically, which keeps the
+/// forwards above the floor between them too.
+#[derive(Clone, Debug)]
+pub struct HypotheticalCurves {
+    pub level: (Rate, Rate),
+    pub slope: (Rate, Rate),
+    pub curvature: (Rate, Rate),
+    pub decay: (Time, Time),
+    pub min_forward: Rate,
+    /// Pillar maturities in months.
+    pub pillars: Vec<i64>,
+}
+
+impl HypotheticalCurves {
+    pub fn new() -> HypotheticalCurves {
+        HypotheticalCurves {
+            level: (0.01, 0.06),
+            slope: (-0.04, 0.03),
+            curvature: (-0.03, 0.03),
+            decay: (0.5, 5.0),
+            min_forward: 0.0,
+            pillars: vec![1, 3, 6, 12, 24, 36, 60, 84, 120, 180, 240, 360],
+        }
+    }
+
+    pub fn with_level(mut self, low: Rate, high: Rate) -> HypotheticalCurves {
+        self.level = (low, high);
+        self
+    }
+
+    pub fn with_slope(mut self, low: Rate, high: Rate) -> HypotheticalCurves {
+        self.slope = (low, high);
+        self
+    }
+
+    pub fn with_curvature(mut self, low: Rate, high: Rate) -> HypotheticalCurves {
+        self.curvature = (low, high);
+        self
+    }
+
+    pub fn with_decay(mut self, low: Time, high: Time) -> HypotheticalCurves {
+        self.decay = (low, high);
+        self
+    }
+
+    /// Lowest forward allowed; negative floors give negative rate regimes.
+    pub fn with_min_forward(mut self, min_forward: Rate) -> HypotheticalCurves {
+        self.min_forward = min_forward;
+        self
+    }
+
+    pub fn with_pillars(mut self, months: Vec<i64>) -> HypotheticalCurves {
+        assert!(!months.is_empty(), "no pillars given");
+        assert!(
+            months[0] > 0 && months.windows(2).all(|m| m[0] < m[1]),
+            "pillars must be positive and increasing"
+        );
+        self.pillars = months;
+        self
+    }
+
+    /// The Nelson-Siegel parameters (level, slope, curvature, decay) of the
+    /// next accepted draw.
+    pub fn parameters(&self, rng: &mut Rng) -> (Rate, Rate, Rate, Time) {
+        let horizon = self.pillars[self.pillars.len() - 1] as f64 / 12.0;
+        for _ in 0..1000 {
+            let p = (
+                draw(rng, self.level),
+                draw(rng, self.slope),
+                draw(rng, self.curvature),
+                draw(rng, self.decay),
+            );
+            let lowest = (0..=1000)
+                .map(|i| forward(p, horizon * i as f64 / 1000.0))
+                .fold(f64::INFINITY, f64::min);
+            if lowest >= self.min_forward {
+                return p;
+            }
+        }
+        panic!("parameter ranges give no curve above the forward floor");
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> DiscountCurve<WeekendsOnly> {
+        let p = self.parameters(rng);
+        let reference = reference_date();
+        let mut dates = vec![reference];
+        dates.extend(self.pillars.iter().map(|m| reference.add_months(*m)));
+        let discounts = dates
+            .iter()
+            .map(|d| {
+                let t = Actual365Fixed.year_fraction(reference, *d, None, None);
+                (-zero_rate(p, t) * t).exp()
+            })
+            .collect();
+        DiscountCurve::new(
+            dates,
+            discounts,
+            Calendar::new(WeekendsOnly),
+            Actual365Fixed,
+            Interpolation::ConvexMonotone,
+        )
+    }
+}
+
+impl Default for HypotheticalCurves {
+    fn default() -> HypotheticalCurves {
+        HypotheticalCurves::new()
+    }
+}
+
+fn nelson_siegel(p: (Rate, Rate, Rate, Time)) -> NelsonSiegel {
+    NelsonSiegel::new(p.0, p.1, p.2, p.3)
+}
+
+fn forward(p: (Rate, Rate, Rate, Time), t: Time) -> Rate {
+    nelson_siegel(p).forward(t)
+}
+
+fn zero_rate(p: (Rate, Rate, Rate, Time), t: Time) -> Rate {
+    nelson_siegel(p).zero_rate(t)
+}
+
+/// Family of SSVI volatility surfaces with an ATM volatility term
+/// structure `sigma(t) = long + (short - long) e^(-t/decay)` and smile
+/// parameters drawn from the given ranges, on forwards growing at a
+/// drawn carry rate from `spot`.
+///
+/// Every draw is free of static arbitrage: `gamma` stays at or below
+/// 1/2, `eta` is capped so that `eta (1 + |rho|) <= 2`, and ATM total
+/// variances are made non-decreasing.
+#[derive(Clone, Debug)]
+pub struct HypotheticalSurfaces {
+    pub short_vol: (Volatility, Volatility),
+    pub long_vol: (Volatility, Volatility),
+    pub decay: (Time, Time),
+    pub rho: (f64, f64),
+    pub eta: (f64, f64),
+    pub gamma: (f64, f64),
+    pub carry: (Rate, Rate),
+    pub spot: f64,
+    pub expiries: Vec<Time>,
+}
+
+impl HypotheticalSurfaces {
+    pub fn new() -> HypotheticalSurfaces {
+        HypotheticalSurfaces {
+            short_vol: (0.10, 0.60),
+            long_vol: (0.15, 0.35),
+            decay: (0.25, 3.0),
+            rho: (-0.9, 0.3),
+            eta: (0.2, 2.0),
+            gamma: (0.1, 0.5),
+            carry: (-0.02, 0.05),
+            spot: 100.0,
+            expiries: vec![1.0 / 12.0, 0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0],
+        }
+    }
+
+    pub fn with_short_vol(mut self, low: Volatility, high: Volatility) -> HypotheticalSurfaces {
+        self.short_vol = (low, high);
+        self
+    }
+
+    pub fn with_long_vol(mut self, low: Volatility, high: Volatility) -> HypotheticalSurfaces {
+        self.long_vol = (low, high);
+        self
+    }
+
+    pub fn with_rho(mut self, low: f64, high: f64) -> HypotheticalSurfaces {
+        assert!(-1.0 < low && high < 1.0, "rho must be in (-1, 1)");
+        self.rho = (low, high);
+        self
+    }
+
+    pub fn with_eta(mut self, low: f64, high: f64) -> HypotheticalSurfaces {
+        self.eta = (low, high);
+        self
+    }
+
+    pub fn with_gamma(mut self, low: f64, high: f64) -> HypotheticalSurfaces {
+        assert!(0.0 < low && high <= 0.5, "gamma must be in (0, 1/2]");
+        self.gamma = (low, high);
+        self
+    }
+
+    pub fn with_spot(mut self, spot: f64) -> HypotheticalSurfaces {
+        self.spot = spot;
+        self
+    }
+
+    pub fn with_expiries(mut self, expiries: Vec<Time>) -> HypotheticalSurfaces {
+        self.expiries = expiries;
+        self
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> SsviSurface {
+        let (short, long, decay) = (
+            draw(rng, self.short_vol),
+            draw(rng, self.long_vol),
+            draw(rng, self.decay),
+        );
+        let rho = draw(rng, self.rho);
+        let eta = draw(rng, self.eta).min(2.0 / (1.0 + rho.abs()));
+        let gamma = draw(rng, self.gamma);
+        let carry = draw(rng, self.carry);
+        let mut theta: f64 = 0.0;
+        let atm_variances = self
+            .expiries
+            .iter()
+            .map(|t| {
+                let vol = long + (short - long) * (-t / decay).exp();
+                theta = theta.max(vol * vol * t);
+                theta
+            })
+            .collect();
+        let forwards = self
+            .expiries
+            .iter()
+            .map(|t| self.spot * (carry * t).exp())
+            .collect();
+        SsviSurface::new(
+            rho,
+            eta,
+            gamma,
+            self.expiries.clone(),
+            atm_variances,
+            forwards,
+        )
+    }
+}
+
+impl Default for HypotheticalSurfaces {
+    fn default() -> HypotheticalSurfaces {
+        HypotheticalSurfaces::new()
+    }
+}
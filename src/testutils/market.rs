@@ -0,0 +1,39 @@
+use crate::definitions::{DiscountFactor, Rate, Time};
+use crate::quotes::SimpleQuote;
+use crate::termstructures::{Compounding, InterestRate, YieldTermStructure};
+use crate::time::{Actual365Fixed, Calendar, Date, Frequency, Month, WeekendsOnly};
+
+/// Fixed evaluation date used by the reference setups, so that results do
+/// not depend on the day the tests run.
+pub fn reference_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+/// Flat continuously compounded Actual/365 (Fixed) rate.
+pub fn flat_rate(rate: Rate) -> InterestRate<Actual365Fixed> {
+    InterestRate::new(
+        rate,
+        Actual365Fixed {},
+        Compounding::Continuous,
+        Frequency::Annual,
+    )
+}
+
+/// Discount function of a flat continuously compounded curve.
+pub fn flat_discount(rate: Rate) -> Box<dyn Fn(Time) -> DiscountFactor> {
+    Box::new(move |t| (-rate * t).exp())
+}
+
+/// Flat continuously compounded Actual/365 (Fixed) curve as of
+/// `reference_date()`.
+pub fn flat_curve(rate: Rate) -> YieldTermStructure<WeekendsOnly, SimpleQuote> {
+    YieldTermStructure::new(
+        Calendar::new(WeekendsOnly),
+        reference_date(),
+        Actual365Fixed {},
+        0,
+        vec![],
+        vec![],
+        flat_discount(rate),
+    )
+}
@@ -0,0 +1,8 @@
+pub mod conformance;
+pub mod generators;
+pub mod golden;
+pub mod hypothetical;
+pub mod market;
+
+pub use self::generators::{forall, Rng};
+pub use self::hypothetical::{HypotheticalCurves, HypotheticalSurfaces};
@@ -1,3 +1,4 @@
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum BusinessDayConvention {
     // ISDA
     /**
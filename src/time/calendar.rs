@@ -7,6 +7,12 @@ pub struct Calendar<C: Cal> {
 }
 
 impl<C: Cal> Calendar<C> {
+    pub fn new(cal_impl: C) -> Calendar<C> {
+        Calendar { cal_impl }
+    }
+    pub fn name(&self) -> String {
+        self.cal_impl.name()
+    }
     pub fn is_business_day(&self, date: Date) -> bool {
         self.cal_impl.is_business_day(date)
     }
@@ -16,21 +22,52 @@ impl<C: Cal> Calendar<C> {
     pub fn is_weekend(&self, weekday: Weekday) -> bool {
         self.cal_impl.is_weekend(&weekday)
     }
-    pub fn is_end_of_month(&self, _date: Date) -> bool {
-        false
+    /// Whether `date` is the last business day of its month.
+    pub fn is_end_of_month(&self, date: Date) -> bool {
+        date.month() != self.adjust(date.add_days(1)).month()
     }
-    pub fn end_of_month(&self, _date: Date) -> Date {
-        Date::default()
+    /// The last business day of the month of `date`.
+    pub fn end_of_month(&self, date: Date) -> Date {
+        self.adjust_with_convention(Date::end_of_month(date), BusinessDayConvention::Preceding)
     }
     pub fn add_holiday(&self, _date: Date) {}
 
     pub fn remove_holiday(&self, _date: Date) {}
 
-    pub fn adjust(&self, _date: Date) -> Date {
-        Date::default()
+    /// Adjusts a non-business day to the next business day.
+    pub fn adjust(&self, date: Date) -> Date {
+        self.adjust_with_convention(date, BusinessDayConvention::Following)
+    }
+    /// Adjusts a non-business day according to the given convention.
+    pub fn adjust_with_convention(&self, date: Date, convention: BusinessDayConvention) -> Date {
+        match convention {
+            BusinessDayConvention::Unadjusted => date,
+            BusinessDayConvention::Following => self.roll(date, 1),
+            BusinessDayConvention::Preceding => self.roll(date, -1),
+            BusinessDayConvention::ModifiedFollowing => {
+                let d = self.roll(date, 1);
+                if d.month() != date.month() {
+                    self.roll(date, -1)
+                } else {
+                    d
+                }
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let d = self.roll(date, -1);
+                if d.month() != date.month() {
+                    self.roll(date, 1)
+                } else {
+                    d
+                }
+            }
+        }
     }
-    pub fn adjust_with_convention(&self, _date: Date, _convention: BusinessDayConvention) -> Date {
-        Date::default()
+    fn roll(&self, date: Date, step: i64) -> Date {
+        let mut d = date;
+        while self.is_holiday(d) {
+            d = d.add_days(step);
+        }
+        d
     }
     pub fn advance_with_convention(
         &self,
@@ -55,7 +92,7 @@ impl<C: Cal> Calendar<C> {
             include_end_of_month,
         )
     }
-    pub fn advance_by_units(&self, date: Date, n: usize, time_unit: TimeUnit) -> Date {
+    pub fn advance_by_units(&self, date: Date, n: i64, time_unit: TimeUnit) -> Date {
         self.advance(date, n, time_unit, BusinessDayConvention::Following, false)
     }
     pub fn advance_by_period(&self, date: Date, period: Period) -> Date {
@@ -68,28 +105,80 @@ impl<C: Cal> Calendar<C> {
         )
     }
 
+    /// Advances `date` by `n` units. Days are counted as business days;
+    /// weeks, months and years are calendar periods adjusted with
+    /// `convention`. With `include_end_of_month`, a month or year period
+    /// started on the last business day of a month ends on the last
+    /// business day of the target month (the last calendar day if
+    /// unadjusted).
     pub fn advance(
         &self,
-        _date: Date,
-        _n: usize,
-        _time_unit: TimeUnit,
-        _convention: BusinessDayConvention,
-        _include_end_of_month: bool,
+        date: Date,
+        n: i64,
+        time_unit: TimeUnit,
+        convention: BusinessDayConvention,
+        include_end_of_month: bool,
     ) -> Date {
-        Date::default()
+        match time_unit {
+            TimeUnit::Days => {
+                if n == 0 {
+                    return self.adjust_with_convention(date, convention);
+                }
+                let step = n.signum();
+                let mut d = date;
+                for _ in 0..n.abs() {
+                    d = d.add_days(step);
+                    while self.is_holiday(d) {
+                        d = d.add_days(step);
+                    }
+                }
+                d
+            }
+            TimeUnit::Weeks => self.adjust_with_convention(date.advance(n, time_unit), convention),
+            TimeUnit::Months | TimeUnit::Years => {
+                let d = date.advance(n, time_unit);
+                if include_end_of_month {
+                    if convention == BusinessDayConvention::Unadjusted {
+                        if Date::is_end_of_month(date) {
+                            return Date::end_of_month(d);
+                        }
+                    } else if self.is_end_of_month(date) {
+                        return self.end_of_month(d);
+                    }
+                }
+                self.adjust_with_convention(d, convention)
+            }
+        }
     }
 
     pub fn business_days_between(&self, from: Date, to: Date) -> i64 {
         self.business_days_between_include(from, to, true, false)
     }
+    /// Number of business days between `from` and `to`, negative if `to`
+    /// is before `from`.
     pub fn business_days_between_include(
         &self,
-        _from: Date,
-        _to: Date,
-        _include_first: bool,
-        _include_last: bool,
+        from: Date,
+        to: Date,
+        include_first: bool,
+        include_last: bool,
     ) -> i64 {
-        0
+        if from > to {
+            return -self.business_days_between_include(to, from, include_last, include_first);
+        }
+        if from == to {
+            return (include_first && include_last && self.is_business_day(from)) as i64;
+        }
+        let mut count = 0;
+        let mut d = from;
+        while d <= to {
+            if self.is_business_day(d) && (include_first || d != from) && (include_last || d != to)
+            {
+                count += 1;
+            }
+            d = d.add_days(1);
+        }
+        count
     }
 }
 
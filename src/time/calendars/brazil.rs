@@ -0,0 +1,49 @@
+use crate::month::Month;
+use crate::time::Date;
+use crate::weekday::Weekday;
+
+/// Brazilian settlement calendar of national holidays, as used by ANBIMA
+/// and B3 for business/252 day counts.
+#[derive(Copy, Clone)]
+pub struct Brazil;
+
+impl crate::time::traits::Calendar for Brazil {
+    fn name(&self) -> String {
+        String::from("Brazil")
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        let d = date.day_of_month();
+        let dd = date.day_of_year();
+        let m = date.month();
+        let y = date.year();
+        let em = super::super::calendar::easter_monday(y);
+        !(self.is_weekend(&date.weekday())
+            // New Year's Day
+            || (d == 1 && m == Month::January)
+            // Tiradentes
+            || (d == 21 && m == Month::April)
+            // Labour Day
+            || (d == 1 && m == Month::May)
+            // Independence Day
+            || (d == 7 && m == Month::September)
+            // Nossa Senhora Aparecida
+            || (d == 12 && m == Month::October)
+            // All Souls' Day
+            || (d == 2 && m == Month::November)
+            // Republic Day
+            || (d == 15 && m == Month::November)
+            // Black Consciousness Day, a national holiday since 2024
+            || (d == 20 && m == Month::November && y >= 2024)
+            // Christmas
+            || (d == 25 && m == Month::December)
+            // Carnival Monday and Tuesday
+            || (dd == em - 49 || dd == em - 48)
+            // Good Friday
+            || (dd == em - 3)
+            // Corpus Christi
+            || (dd == em + 59))
+    }
+    fn is_weekend(&self, weekday: &Weekday) -> bool {
+        *weekday == Weekday::Saturday || *weekday == Weekday::Sunday
+    }
+}
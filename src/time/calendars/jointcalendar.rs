@@ -0,0 +1,61 @@
+use crate::time::traits::Calendar as Cal;
+use crate::time::Date;
+use crate::weekday::Weekday;
+
+/// How the holidays of two calendars are combined.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JointCalendarRule {
+    /// A holiday in either calendar is a holiday, e.g. the value dates of
+    /// USD Libor, which must be business days in London and New York.
+    JoinHolidays,
+    /// A business day in either calendar is a business day.
+    JoinBusinessDays,
+}
+
+/// Calendar combining the holidays of two others.
+#[derive(Copy, Clone)]
+pub struct JointCalendar<C1: Cal, C2: Cal> {
+    pub first: C1,
+    pub second: C2,
+    pub rule: JointCalendarRule,
+}
+
+impl<C1: Cal, C2: Cal> JointCalendar<C1, C2> {
+    pub fn new(first: C1, second: C2, rule: JointCalendarRule) -> JointCalendar<C1, C2> {
+        JointCalendar {
+            first,
+            second,
+            rule,
+        }
+    }
+}
+
+impl<C1: Cal, C2: Cal> crate::time::traits::Calendar for JointCalendar<C1, C2> {
+    fn name(&self) -> String {
+        let separator = match self.rule {
+            JointCalendarRule::JoinHolidays => ", ",
+            JointCalendarRule::JoinBusinessDays => " or ",
+        };
+        format!("{}{}{}", self.first.name(), separator, self.second.name())
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        match self.rule {
+            JointCalendarRule::JoinHolidays => {
+                self.first.is_business_day(date) && self.second.is_business_day(date)
+            }
+            JointCalendarRule::JoinBusinessDays => {
+                self.first.is_business_day(date) || self.second.is_business_day(date)
+            }
+        }
+    }
+    fn is_weekend(&self, weekday: &Weekday) -> bool {
+        match self.rule {
+            JointCalendarRule::JoinHolidays => {
+                self.first.is_weekend(weekday) || self.second.is_weekend(weekday)
+            }
+            JointCalendarRule::JoinBusinessDays => {
+                self.first.is_weekend(weekday) && self.second.is_weekend(weekday)
+            }
+        }
+    }
+}
@@ -1,3 +1,11 @@
+pub mod brazil;
+pub mod jointcalendar;
+pub mod nullcalendar;
 pub mod sweden;
+pub mod weekendsonly;
 
+pub use self::brazil::Brazil;
+pub use self::jointcalendar::{JointCalendar, JointCalendarRule};
+pub use self::nullcalendar::NullCalendar;
 pub use self::sweden::Sweden;
+pub use self::weekendsonly::WeekendsOnly;
@@ -0,0 +1,19 @@
+use crate::time::Date;
+use crate::weekday::Weekday;
+
+/// Calendar for reproducing theoretical calculations: every day is a
+/// business day.
+#[derive(Copy, Clone)]
+pub struct NullCalendar;
+
+impl crate::time::traits::Calendar for NullCalendar {
+    fn name(&self) -> String {
+        String::from("Null")
+    }
+    fn is_business_day(&self, _date: Date) -> bool {
+        true
+    }
+    fn is_weekend(&self, _weekday: &Weekday) -> bool {
+        false
+    }
+}
@@ -1,4 +1,3 @@
-use crate::calendar::Calendar;
 use crate::time::Date;
 use crate::weekday::Weekday;
 use crate::month::Month;
@@ -25,8 +24,8 @@ impl crate::time::traits::Calendar for Sweden {
 	                || (dd == em)
 	                // Ascension Thursday
 	                || (dd == em+38)
-	                // Whit Monday
-	                || (dd == em+49)
+	                // Whit Monday (till 2004)
+	                || (dd == em+49 && y < 2005)
 	                // New Year's Day
 	                || (d == 1  && m == Month::January)
 	                // Epiphany
@@ -39,7 +38,7 @@ impl crate::time::traits::Calendar for Sweden {
 	                // As of 2002 the Stockholmborsen is open that day
 	                // || (d == 6  && m == June)
 	                // Midsummer Eve (Friday between June 18-24)
-	                || (wkdy == Weekday::Friday && (d >= 18 && d <= 24) && m == Month::June)
+	                || (wkdy == Weekday::Friday && (18..=24).contains(&d) && m == Month::June)
 	                // Christmas Eve
 	                || (d == 24 && m == Month::December)
 	                // Christmas Day
@@ -0,0 +1,18 @@
+use crate::time::Date;
+use crate::weekday::Weekday;
+
+/// Calendar whose only holidays are Saturdays and Sundays.
+#[derive(Copy, Clone)]
+pub struct WeekendsOnly;
+
+impl crate::time::traits::Calendar for WeekendsOnly {
+    fn name(&self) -> String {
+        String::from("weekends only")
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        !self.is_weekend(&date.weekday())
+    }
+    fn is_weekend(&self, weekday: &Weekday) -> bool {
+        *weekday == Weekday::Saturday || *weekday == Weekday::Sunday
+    }
+}
@@ -1,14 +1,30 @@
+#![allow(deprecated)]
 use super::month::Month;
+use super::timeunit::TimeUnit;
 use super::weekday::Weekday;
 use chrono::prelude::*;
 use chrono::Date as ChronDate;
+use std::fmt;
 //use chrono::TimeZone as ChronZone;
 
-#[derive(PartialEq, Copy, Debug, Clone, PartialOrd)]
+#[derive(PartialEq, Eq, Copy, Debug, Clone, PartialOrd, Ord, Hash)]
 pub struct Date {
     pub d: ChronDate<Utc>,
 }
 
+/// ISO 8601 format, e.g. `2020-01-15`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.d.format("%Y-%m-%d"))
+    }
+}
+
+/// Today's date, read from the system clock.
+///
+/// Only with the `clock` feature: without it (e.g. `wasm32-unknown-unknown`
+/// without `wasmbind`) there is no clock to read, so there is no default
+/// date and evaluation dates must be given explicitly.
+#[cfg(feature = "clock")]
 impl Default for Date {
     fn default() -> Date {
         Date { d: Utc::today() }
@@ -83,6 +99,47 @@ impl Date {
         }
     }
 
+    /// The earliest date supported, January 1st, 1901.
+    pub fn min_date() -> Date {
+        Date::new(1, Month::January, 1901)
+    }
+
+    /// The latest date supported, December 31st, 2199.
+    pub fn max_date() -> Date {
+        Date::new(31, Month::December, 2199)
+    }
+
+    /// The date `n` calendar days after this one (before, if negative).
+    pub fn add_days(&self, n: i64) -> Date {
+        Date {
+            d: self.d + chrono::Duration::days(n),
+        }
+    }
+
+    /// The date `n` months after this one; the day is capped at the end of
+    /// the target month, e.g. 31st January plus one month is 28th February.
+    pub fn add_months(&self, n: i64) -> Date {
+        let months = self.d.year() as i64 * 12 + self.d.month0() as i64 + n;
+        let (year, month) = (
+            months.div_euclid(12) as i32,
+            months.rem_euclid(12) as u32 + 1,
+        );
+        let length = Date::month_length(month as usize, Date::is_leap(year as usize));
+        Date {
+            d: Utc.ymd(year, month, self.d.day().min(length as u32)),
+        }
+    }
+
+    /// The date `n` units after this one, without any calendar adjustment.
+    pub fn advance(&self, n: i64, units: TimeUnit) -> Date {
+        match units {
+            TimeUnit::Days => self.add_days(n),
+            TimeUnit::Weeks => self.add_days(7 * n),
+            TimeUnit::Months => self.add_months(n),
+            TimeUnit::Years => self.add_months(12 * n),
+        }
+    }
+
     pub fn sub(&self, date: Date) -> i64 {
         self.d.signed_duration_since(date.d).num_days()
     }
@@ -108,22 +165,27 @@ impl Date {
     }
 
     pub fn is_end_of_month(date: Date) -> bool {
-        //
         let day = date.day_of_month();
         let month = date.month();
 
         day == Date::month_length(month as usize, Date::is_leap(date.year()))
     }
 
+    /// The last day of the month of `date`.
+    pub fn end_of_month(date: Date) -> Date {
+        let length = Date::month_length(date.month() as usize, Date::is_leap(date.year()));
+        Date::new(length as u32, date.month(), date.year() as i32)
+    }
+
     pub fn is_leap(year: usize) -> bool {
-        YEAR_IS_LEAP[(year - 1900) as usize]
+        YEAR_IS_LEAP[year - 1900]
     }
 
     fn month_length(month: usize, is_leap_year: bool) -> usize {
         if is_leap_year {
-            MONTH_LEAP_LENGTHS[(month - 1) as usize]
+            MONTH_LEAP_LENGTHS[month - 1]
         } else {
-            MONTH_LENGTHS[(month - 1) as usize]
+            MONTH_LENGTHS[month - 1]
         }
     }
 }
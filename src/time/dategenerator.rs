@@ -1,3 +1,4 @@
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum DateGenerator {
     /**
      * Backward from termination date to effective date.
@@ -10,6 +10,10 @@ pub struct Actual360;
 // http://en.wikipedia.org/wiki/Day_count_convention
 //
 impl DayCounter for Actual360 {
+    fn name(&self) -> String {
+        "Actual/360".to_string()
+    }
+
     //
     //
     fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
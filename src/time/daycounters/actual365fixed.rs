@@ -17,6 +17,10 @@ pub struct Actual365Fixed;
 // http://en.wikipedia.org/wiki/Day_count_convention
 //
 impl DayCounter for Actual365Fixed {
+    fn name(&self) -> String {
+        "Actual/365 (Fixed)".to_string()
+    }
+
     //
     //
     fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
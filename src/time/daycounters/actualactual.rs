@@ -1,6 +1,6 @@
 use super::day_count;
 use crate::time::traits::*;
-use crate::time::Date;
+use crate::time::{Date, Month};
 
 #[derive(Copy, Clone)]
 pub enum ConventionActual {
@@ -22,6 +22,12 @@ pub enum ConventionActual {
 pub struct ActualActual {
     pub convention: ConventionActual,
 }
+impl ActualActual {
+    pub fn new(convention: ConventionActual) -> ActualActual {
+        ActualActual { convention }
+    }
+}
+
 impl Default for ActualActual {
     fn default() -> ActualActual {
         ActualActual {
@@ -34,6 +40,17 @@ impl Default for ActualActual {
 //
 //
 impl DayCounter for ActualActual {
+    fn name(&self) -> String {
+        match self.convention {
+            ConventionActual::ISMA | ConventionActual::Bond => "Actual/Actual (ISMA)",
+            ConventionActual::ISDA | ConventionActual::Historical | ConventionActual::Actual365 => {
+                "Actual/Actual (ISDA)"
+            }
+            ConventionActual::AFB | ConventionActual::Euro => "Actual/Actual (AFB)",
+        }
+        .to_string()
+    }
+
     //
     //
     //
@@ -49,22 +66,142 @@ impl DayCounter for ActualActual {
         &self,
         date_start: Date,
         date_end: Date,
-        _ref_period_start: Option<Date>,
-        _ref_period_end: Option<Date>,
+        ref_period_start: Option<Date>,
+        ref_period_end: Option<Date>,
     ) -> f64 {
-        let mut dm1 = date_start.day_of_month();
-        let mut dm2 = date_end.day_of_month();
-        let m1 = date_start.month();
-        let mut m2 = date_end.month();
-        let y1 = date_start.year();
-        let y2 = date_end.year();
-
         match self.convention {
-            ConventionActual::ISMA | ConventionActual::Bond => 0.0,
+            ConventionActual::ISMA | ConventionActual::Bond => {
+                isma_year_fraction(date_start, date_end, ref_period_start, ref_period_end)
+            }
             ConventionActual::ISDA | ConventionActual::Actual365 | ConventionActual::Historical => {
-                0.0
+                isda_year_fraction(date_start, date_end)
+            }
+            ConventionActual::AFB | ConventionActual::Euro => {
+                afb_year_fraction(date_start, date_end)
+            }
+        }
+    }
+}
+
+fn days_in_year(year: usize) -> f64 {
+    if Date::is_leap(year) {
+        366.0
+    } else {
+        365.0
+    }
+}
+
+/// Days in each calendar year over the length of that year.
+fn isda_year_fraction(d1: Date, d2: Date) -> f64 {
+    if d1 == d2 {
+        return 0.0;
+    }
+    if d1 > d2 {
+        return -isda_year_fraction(d2, d1);
+    }
+    let (y1, y2) = (d1.year(), d2.year());
+    let start_of_next = Date::new(1, Month::January, y1 as i32 + 1);
+    let start_of_last = Date::new(1, Month::January, y2 as i32);
+    (y2 - y1) as f64 - 1.0
+        + start_of_next.sub(d1) as f64 / days_in_year(y1)
+        + d2.sub(start_of_last) as f64 / days_in_year(y2)
+}
+
+/// Whole years counted back from the end date, then the remaining days
+/// over 366 if they include a 29th February and 365 otherwise.
+fn afb_year_fraction(d1: Date, d2: Date) -> f64 {
+    if d1 == d2 {
+        return 0.0;
+    }
+    if d1 > d2 {
+        return -afb_year_fraction(d2, d1);
+    }
+    let mut end = d2;
+    let mut years = 0.0;
+    loop {
+        let mut previous = end.add_months(-12);
+        if previous.day_of_month() == 28
+            && previous.month() == Month::February
+            && Date::is_leap(previous.year())
+        {
+            previous = previous.add_days(1);
+        }
+        if previous < d1 {
+            break;
+        }
+        years += 1.0;
+        end = previous;
+    }
+    let contains_leap_day = |year: usize| {
+        let leap_day = Date::new(29, Month::February, year as i32);
+        end > leap_day && d1 <= leap_day
+    };
+    let mut denominator = 365.0;
+    if Date::is_leap(end.year()) {
+        if contains_leap_day(end.year()) {
+            denominator += 1.0;
+        }
+    } else if Date::is_leap(d1.year()) && contains_leap_day(d1.year()) {
+        denominator += 1.0;
+    }
+    years + end.sub(d1) as f64 / denominator
+}
+
+/// Days over the days in the reference coupon period, times the period
+/// length in years; irregular periods are split along rolled reference
+/// periods.
+fn isma_year_fraction(d1: Date, d2: Date, ref_start: Option<Date>, ref_end: Option<Date>) -> f64 {
+    if d1 == d2 {
+        return 0.0;
+    }
+    if d1 > d2 {
+        return -isma_year_fraction(d2, d1, ref_start, ref_end);
+    }
+    let mut ref_start = ref_start.unwrap_or(d1);
+    let mut ref_end = ref_end.unwrap_or(d2);
+    assert!(
+        ref_end > ref_start && ref_end > d1,
+        "invalid reference period: {} to {}",
+        ref_start,
+        ref_end
+    );
+    let mut months = (12.0 * ref_end.sub(ref_start) as f64 / 365.0).round() as i64;
+    if months == 0 {
+        ref_start = d1;
+        ref_end = d1.add_months(12);
+        months = 12;
+    }
+    let period = months as f64 / 12.0;
+
+    if d2 <= ref_end {
+        if d1 >= ref_start {
+            period * d2.sub(d1) as f64 / ref_end.sub(ref_start) as f64
+        } else {
+            // long first coupon
+            let previous = ref_start.add_months(-months);
+            if d2 > ref_start {
+                isma_year_fraction(d1, ref_start, Some(previous), Some(ref_start))
+                    + isma_year_fraction(ref_start, d2, Some(ref_start), Some(ref_end))
+            } else {
+                isma_year_fraction(d1, d2, Some(previous), Some(ref_start))
+            }
+        }
+    } else {
+        // long final coupon
+        assert!(
+            ref_start <= d1,
+            "invalid dates: d1 < reference period start"
+        );
+        let mut sum = isma_year_fraction(d1, ref_end, Some(ref_start), Some(ref_end));
+        let mut i = 0;
+        loop {
+            let start = ref_end.add_months(months * i);
+            let end = ref_end.add_months(months * (i + 1));
+            if d2 < end {
+                return sum + isma_year_fraction(start, d2, Some(start), Some(end));
             }
-            ConventionActual::AFB | ConventionActual::Euro => 0.0,
+            sum += period;
+            i += 1;
         }
     }
 }
@@ -7,11 +7,21 @@ pub struct Business252<C: Cal> {
     pub calendar: Calendar<C>,
 }
 
+impl<C: Cal> Business252<C> {
+    pub fn new(calendar: Calendar<C>) -> Business252<C> {
+        Business252 { calendar }
+    }
+}
+
 //
 // Business/252 day count convention.
 // http://en.wikipedia.org/wiki/Day_count_convention
 //
 impl<C: Cal> DayCounter for Business252<C> {
+    fn name(&self) -> String {
+        format!("Business/252({})", self.calendar.name())
+    }
+
     //
     //
     fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
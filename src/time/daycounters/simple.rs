@@ -9,6 +9,10 @@ pub fn day_count(date_start: Date, date_end: Date) -> i64 {
 pub struct Simple;
 
 impl DayCounter for Simple {
+    fn name(&self) -> String {
+        "Simple".to_string()
+    }
+
     fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
         //
         // Supposed to implement whatever Thirty360 implements.
@@ -33,7 +37,7 @@ impl DayCounter for Simple {
             || dm1 > dm2 && Date::is_end_of_month(date_end)
             || dm1 < dm2 && Date::is_end_of_month(date_start)
         {
-            ((y2 - y1) + (m2 - m1) as usize) as f64 / 12.0
+            ((y2 - y1) + (m2 - m1)) as f64 / 12.0
         } else {
             // fallback to Thirty360
             0.0
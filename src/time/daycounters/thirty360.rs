@@ -1,5 +1,5 @@
 use crate::time::traits::*;
-use crate::time::Date;
+use crate::time::{Date, Month};
 use std::cmp;
 
 #[derive(Copy, Clone)]
@@ -27,6 +27,10 @@ pub enum Convention360 {
 // a month become equal to the 30th of the same month. Also known as "30E/360",
 // or "Eurobond Basis"
 //
+// The US convention further treats the last day of February as the 30th
+// when it starts the period, and when it ends a period also starting on
+// one.
+//
 // Italian convention: starting dates or ending dates that occur on February and
 // are grater than 27 become equal to 30 for computational sake.
 //
@@ -37,6 +41,12 @@ pub enum Convention360 {
 pub struct Thirty360 {
     pub convention: Convention360,
 }
+impl Thirty360 {
+    pub fn new(convention: Convention360) -> Thirty360 {
+        Thirty360 { convention }
+    }
+}
+
 impl Default for Thirty360 {
     fn default() -> Thirty360 {
         Thirty360 {
@@ -49,38 +59,62 @@ impl Default for Thirty360 {
 //
 //
 impl DayCounter for Thirty360 {
+    fn name(&self) -> String {
+        match self.convention {
+            Convention360::USA => "30/360 (US)",
+            Convention360::BondBasis => "30/360 (Bond Basis)",
+            Convention360::European | Convention360::EurobondBasis => "30E/360 (Eurobond Basis)",
+            Convention360::Italian => "30/360 (Italian)",
+        }
+        .to_string()
+    }
+
     //
     //
     //
     fn day_count(&self, date_start: Date, date_end: Date) -> i64 {
-        let mut dm1 = date_start.day_of_month();
-        let mut dm2 = date_end.day_of_month();
-        let m1 = date_start.month() as usize;
-        let mut m2 = date_end.month() as usize;
-        let y1 = date_start.year();
-        let y2 = date_end.year();
+        let mut dm1 = date_start.day_of_month() as i64;
+        let mut dm2 = date_end.day_of_month() as i64;
+        let m1 = date_start.month() as i64;
+        let mut m2 = date_end.month() as i64;
+        let y1 = date_start.year() as i64;
+        let y2 = date_end.year() as i64;
 
         match self.convention {
-            // US and regular bonds.
+            // US.
+            // =====================
+            Convention360::USA => {
+                let last_of_february =
+                    |d: Date| d.month() == Month::February && Date::is_end_of_month(d);
+                if last_of_february(date_end) && last_of_february(date_start) {
+                    dm2 = 30;
+                }
+                if last_of_february(date_start) {
+                    dm1 = 30;
+                }
+                if dm2 == 31 && dm1 >= 30 {
+                    dm2 = 30;
+                }
+                if dm1 == 31 {
+                    dm1 = 30;
+                }
+
+                360 * (y2 - y1) + 30 * (m2 - m1) + dm2 - dm1
+            }
+            // Regular bonds.
             // =====================
-            Convention360::USA | Convention360::BondBasis => {
+            Convention360::BondBasis => {
                 if dm2 == 31 && dm1 < 30 {
                     dm2 = 1;
-                    m2 = m2 + 1; // clearly this could go wrong if it was december?
+                    m2 += 1; // clearly this could go wrong if it was december?
                 }
 
-                (360 * (y2 - y1)
-                    + 30 * (m2 as usize - m1 as usize - 1)
-                    + cmp::max(0, 30 - dm1) as usize
-                    + cmp::min(30, dm2) as usize) as i64
+                360 * (y2 - y1) + 30 * (m2 - m1 - 1) + cmp::max(0, 30 - dm1) + cmp::min(30, dm2)
             }
             // European and euro bonds.
             // =====================
             Convention360::European | Convention360::EurobondBasis => {
-                (360 * (y2 - y1)
-                    + 30 * (m2 as usize - m1 as usize - 1)
-                    + cmp::max(0, 30 - dm1) as usize
-                    + cmp::min(30, dm2) as usize) as i64
+                360 * (y2 - y1) + 30 * (m2 - m1 - 1) + cmp::max(0, 30 - dm1) + cmp::min(30, dm2)
             }
             // Italian bonds.
             // =====================
@@ -93,10 +127,7 @@ impl DayCounter for Thirty360 {
                     dm2 = 30;
                 }
 
-                (360 * (y2 - y1)
-                    + 30 * (m2 as usize - m1 as usize - 1)
-                    + cmp::max(0, 30 - dm1) as usize
-                    + cmp::min(30, dm2) as usize) as i64
+                360 * (y2 - y1) + 30 * (m2 - m1 - 1) + cmp::max(0, 30 - dm1) + cmp::min(30, dm2)
             }
         }
     }
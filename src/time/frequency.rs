@@ -1,4 +1,6 @@
-#[derive(PartialEq)]
+use std::fmt;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum Frequency {
     /** null frequency */
     NoFrequency = -1,
@@ -47,4 +49,44 @@ impl Frequency {
             Frequency::OtherFrequency => 999.0,
         }
     }
+
+    /// The frequency with `n` periods per year; inverse of `to_float`.
+    pub fn from_float(n: f64) -> Frequency {
+        match n as i64 {
+            -1 => Frequency::NoFrequency,
+            0 => Frequency::Once,
+            1 => Frequency::Annual,
+            2 => Frequency::Semiannual,
+            3 => Frequency::EveryFourthMonth,
+            4 => Frequency::Quarterly,
+            6 => Frequency::Bimonthly,
+            12 => Frequency::Monthly,
+            13 => Frequency::EveryFourthWeek,
+            26 => Frequency::Biweekly,
+            52 => Frequency::Weekly,
+            365 => Frequency::Daily,
+            _ => Frequency::OtherFrequency,
+        }
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Frequency::NoFrequency => "No-Frequency",
+            Frequency::Once => "Once",
+            Frequency::Annual => "Annual",
+            Frequency::Semiannual => "Semiannual",
+            Frequency::EveryFourthMonth => "Every-Fourth-Month",
+            Frequency::Quarterly => "Quarterly",
+            Frequency::Bimonthly => "Bimonthly",
+            Frequency::Monthly => "Monthly",
+            Frequency::EveryFourthWeek => "Every-fourth-week",
+            Frequency::Biweekly => "Biweekly",
+            Frequency::Weekly => "Weekly",
+            Frequency::Daily => "Daily",
+            Frequency::OtherFrequency => "Unknown frequency",
+        };
+        write!(f, "{}", name)
+    }
 }
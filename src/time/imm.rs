@@ -0,0 +1,37 @@
+use super::{Date, Month, Weekday};
+
+/// Third Wednesday of the month, the start date of IMM futures contracts.
+pub fn third_wednesday(month: Month, year: i32) -> Date {
+    let first = Date::new(1, month, year);
+    let offset = (Weekday::Wednesday as i64 - first.weekday() as i64 + 7) % 7;
+    first.add_days(offset + 14)
+}
+
+/// Whether `month` is in the March, June, September and December cycle.
+pub fn is_main_cycle(month: Month) -> bool {
+    matches!(
+        month,
+        Month::March | Month::June | Month::September | Month::December
+    )
+}
+
+/// Whether `date` is an IMM date, i.e. a third Wednesday; the main cycle
+/// only has those of March, June, September and December.
+pub fn is_imm_date(date: Date, main_cycle: bool) -> bool {
+    if main_cycle && !is_main_cycle(date.month()) {
+        return false;
+    }
+    date == third_wednesday(date.month(), date.year() as i32)
+}
+
+/// The first IMM date strictly after `date`.
+pub fn next_imm_date(date: Date, main_cycle: bool) -> Date {
+    let mut month = Date::new(1, date.month(), date.year() as i32);
+    loop {
+        let candidate = third_wednesday(month.month(), month.year() as i32);
+        if candidate > date && is_imm_date(candidate, main_cycle) {
+            return candidate;
+        }
+        month = month.add_months(1);
+    }
+}
@@ -5,9 +5,11 @@ pub mod date;
 pub mod dategenerator;
 mod daycounters;
 pub mod frequency;
+pub mod imm;
 pub mod month;
 pub mod period;
 pub mod schedule;
+pub mod timeseries;
 pub mod timeunit;
 pub mod traits;
 pub mod weekday;
@@ -18,6 +20,7 @@ pub use self::calendars::*;
 pub use self::date::Date;
 pub use self::dategenerator::DateGenerator;
 pub use self::daycounters::*;
+pub use self::imm::{is_imm_date, is_main_cycle, next_imm_date, third_wednesday};
 pub use self::month::Month;
 pub use self::timeunit::TimeUnit;
 pub use self::traits::*;
@@ -26,5 +29,6 @@ pub use self::weekday::Weekday;
 pub use self::frequency::Frequency;
 pub use self::period::Period;
 pub use self::schedule::Schedule;
+pub use self::timeseries::TimeSeries;
 
 extern crate chrono;
@@ -1,6 +1,27 @@
 use super::timeunit::TimeUnit;
+use std::fmt;
 
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub struct Period {
     pub units: TimeUnit,
-    pub length: usize,
+    pub length: i64,
+}
+
+impl Period {
+    pub fn new(length: i64, units: TimeUnit) -> Period {
+        Period { units, length }
+    }
+}
+
+/// Short market notation, e.g. `3M` or `10Y`.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = match self.units {
+            TimeUnit::Days => "D",
+            TimeUnit::Weeks => "W",
+            TimeUnit::Months => "M",
+            TimeUnit::Years => "Y",
+        };
+        write!(f, "{}{}", self.length, unit)
+    }
 }
@@ -1 +1,240 @@
-pub struct Schedule {}
+use super::traits::Calendar as Cal;
+use super::{
+    is_main_cycle, third_wednesday, BusinessDayConvention, Calendar, Date, DateGenerator,
+    NullCalendar, Period, TimeUnit,
+};
+
+/// Payment schedule generated from an effective date, a termination date
+/// and a tenor.
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    pub dates: Vec<Date>,
+    /// Whether the period `[dates[i], dates[i + 1]]` spans a full tenor.
+    pub is_regular: Vec<bool>,
+    pub tenor: Period,
+    pub convention: BusinessDayConvention,
+    pub termination_date_convention: BusinessDayConvention,
+    pub rule: DateGenerator,
+    pub end_of_month: bool,
+}
+
+impl Schedule {
+    /// Generates the schedule following the ISDA conventions used by
+    /// QuantLib. Unadjusted dates are rolled by whole tenors from the
+    /// termination date (`Backward`) or from the effective date
+    /// (`Forward`), leaving any stub at the other end.
+    ///
+    /// With `end_of_month` and a tenor of at least a month, a schedule
+    /// seeded on the last business day of a month keeps every date on the
+    /// last business day of its month (the last calendar day if
+    /// unadjusted), which is how February and the 30-day months are
+    /// handled for month-end swaps.
+    ///
+    /// `ThirdWednesday` rolls forward and moves every date but the first
+    /// and the last to the third Wednesday of its month, as for IMM dated
+    /// money market instruments; it does not combine with `end_of_month`.
+    ///
+    /// `Twentieth` and `TwentiethIMM` also roll forward, from the first
+    /// twentieth of a month (of a March, June, September or December for
+    /// `TwentiethIMM`) on or after the effective date, leaving a short
+    /// front stub, and move the termination date to the next such
+    /// twentieth, as for CDS schedules. Neither combines with
+    /// `end_of_month`.
+    pub fn new<C: Cal>(
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        calendar: Calendar<C>,
+        convention: BusinessDayConvention,
+        termination_date_convention: BusinessDayConvention,
+        rule: DateGenerator,
+        end_of_month: bool,
+    ) -> Schedule {
+        assert!(
+            effective_date < termination_date,
+            "effective date must be before termination date"
+        );
+        assert!(
+            tenor.length > 0 || rule == DateGenerator::Zero,
+            "non positive tenor"
+        );
+        // the rules moving dates to a fixed day of their month
+        let fixed_day_rule = match rule {
+            DateGenerator::ThirdWednesday => Some("third Wednesday"),
+            DateGenerator::Twentieth => Some("twentieth"),
+            DateGenerator::TwentiethIMM => Some("twentieth IMM"),
+            _ => None,
+        };
+        assert!(
+            !end_of_month || fixed_day_rule.is_none(),
+            "end of month is incompatible with the {} rule",
+            fixed_day_rule.unwrap_or_default()
+        );
+        let end_of_month = end_of_month
+            && rule != DateGenerator::Zero
+            && (tenor.units == TimeUnit::Months || tenor.units == TimeUnit::Years);
+
+        let null_calendar = Calendar::new(NullCalendar);
+        let adjusted = |d: Date| calendar.adjust_with_convention(d, convention);
+        let mut dates = vec![];
+        let mut is_regular = vec![];
+        let seed = match rule {
+            DateGenerator::Zero => {
+                dates = vec![effective_date, termination_date];
+                is_regular.push(true);
+                effective_date
+            }
+            DateGenerator::Backward => {
+                dates.push(termination_date);
+                for periods in 1.. {
+                    let d = null_calendar.advance(
+                        termination_date,
+                        -periods * tenor.length,
+                        tenor.units,
+                        convention,
+                        end_of_month,
+                    );
+                    if d < effective_date {
+                        if *dates.last().unwrap() != effective_date {
+                            dates.push(effective_date);
+                            is_regular.push(false);
+                        }
+                        break;
+                    }
+                    // skip dates that would collapse onto the previous one
+                    // once adjusted.
+                    if adjusted(*dates.last().unwrap()) != adjusted(d) {
+                        dates.push(d);
+                        is_regular.push(true);
+                    }
+                }
+                dates.reverse();
+                is_regular.reverse();
+                termination_date
+            }
+            DateGenerator::Forward
+            | DateGenerator::ThirdWednesday
+            | DateGenerator::Twentieth
+            | DateGenerator::TwentiethIMM => {
+                let twentieth =
+                    matches!(rule, DateGenerator::Twentieth | DateGenerator::TwentiethIMM);
+                dates.push(effective_date);
+                let mut seed = effective_date;
+                if twentieth {
+                    let next = next_twentieth(effective_date, rule);
+                    if next != effective_date {
+                        dates.push(next);
+                        is_regular.push(false);
+                        seed = next;
+                    }
+                }
+                for periods in 1.. {
+                    let d = null_calendar.advance(
+                        seed,
+                        periods * tenor.length,
+                        tenor.units,
+                        convention,
+                        end_of_month,
+                    );
+                    if d > termination_date {
+                        if *dates.last().unwrap() != termination_date {
+                            if twentieth {
+                                dates.push(next_twentieth(termination_date, rule));
+                                is_regular.push(true);
+                            } else {
+                                dates.push(termination_date);
+                                is_regular.push(false);
+                            }
+                        }
+                        break;
+                    }
+                    if adjusted(*dates.last().unwrap()) != adjusted(d) {
+                        dates.push(d);
+                        is_regular.push(true);
+                    }
+                }
+                seed
+            }
+        };
+
+        let n = dates.len();
+        if rule == DateGenerator::ThirdWednesday {
+            for d in dates.iter_mut().take(n - 1).skip(1) {
+                *d = third_wednesday(d.month(), d.year() as i32);
+            }
+        }
+        if end_of_month && calendar.is_end_of_month(seed) {
+            for d in dates.iter_mut().take(n - 1).skip(1) {
+                *d = if convention == BusinessDayConvention::Unadjusted {
+                    Date::end_of_month(*d)
+                } else {
+                    calendar.end_of_month(*d)
+                };
+            }
+            if termination_date_convention != BusinessDayConvention::Unadjusted {
+                dates[0] = calendar.end_of_month(dates[0]);
+                dates[n - 1] = calendar.end_of_month(dates[n - 1]);
+            } else if rule == DateGenerator::Backward {
+                // the termination date is the first one when going
+                // backwards and the last one otherwise.
+                dates[n - 1] = Date::end_of_month(dates[n - 1]);
+            } else {
+                dates[0] = Date::end_of_month(dates[0]);
+            }
+        } else {
+            for d in dates.iter_mut().take(n - 1) {
+                *d = adjusted(*d);
+            }
+            dates[n - 1] =
+                calendar.adjust_with_convention(dates[n - 1], termination_date_convention);
+        }
+
+        // month-end adjustments can move the next-to-last date onto or past
+        // the termination date, and the second date onto or before the
+        // effective date.
+        let n = dates.len();
+        if n > 2 && dates[n - 2] >= dates[n - 1] {
+            is_regular[n - 3] = dates[n - 2] == dates[n - 1];
+            dates.remove(n - 2);
+            is_regular.pop();
+        }
+        if dates.len() > 2 && dates[1] <= dates[0] {
+            is_regular[1] = dates[1] == dates[0];
+            dates.remove(1);
+            is_regular.remove(0);
+        }
+
+        Schedule {
+            dates,
+            is_regular,
+            tenor,
+            convention,
+            termination_date_convention,
+            rule,
+            end_of_month,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.dates.len()
+    }
+    pub fn start_date(&self) -> Date {
+        self.dates[0]
+    }
+    pub fn end_date(&self) -> Date {
+        *self.dates.last().unwrap()
+    }
+}
+
+/// The first twentieth of a month on or after `date`, of a month of the
+/// March, June, September and December cycle for `TwentiethIMM`.
+fn next_twentieth(date: Date, rule: DateGenerator) -> Date {
+    let mut result = Date::new(20, date.month(), date.year() as i32);
+    if result < date {
+        result = result.add_months(1);
+    }
+    while rule == DateGenerator::TwentiethIMM && !is_main_cycle(result.month()) {
+        result = result.add_months(1);
+    }
+    result
+}
@@ -0,0 +1,64 @@
+use super::Date;
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+
+/// Values observed on dates, kept in date order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimeSeries<T> {
+    values: BTreeMap<Date, T>,
+}
+
+impl<T: Clone> TimeSeries<T> {
+    pub fn new() -> TimeSeries<T> {
+        TimeSeries {
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_pairs(dates: &[Date], values: &[T]) -> TimeSeries<T> {
+        assert!(
+            dates.len() == values.len(),
+            "dates and values size mismatch"
+        );
+        TimeSeries {
+            values: dates.iter().cloned().zip(values.iter().cloned()).collect(),
+        }
+    }
+
+    /// Sets the value at `date`, replacing any previous one.
+    pub fn insert(&mut self, date: Date, value: T) {
+        self.values.insert(date, value);
+    }
+
+    pub fn get(&self, date: Date) -> Option<&T> {
+        self.values.get(&date)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn first_date(&self) -> Option<Date> {
+        self.values.keys().next().cloned()
+    }
+
+    pub fn last_date(&self) -> Option<Date> {
+        self.values.keys().next_back().cloned()
+    }
+
+    pub fn dates(&self) -> Vec<Date> {
+        self.values.keys().cloned().collect()
+    }
+
+    pub fn values(&self) -> Vec<T> {
+        self.values.values().cloned().collect()
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, Date, T> {
+        self.values.iter()
+    }
+}
@@ -1,3 +1,4 @@
+#[derive(PartialEq, Copy, Clone, Debug)]
 pub enum TimeUnit {
     Days,
     Weeks,
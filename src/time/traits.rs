@@ -3,6 +3,7 @@ use super::weekday::Weekday;
 use crate::definitions::Time;
 
 pub trait DayCounter: Copy {
+    fn name(&self) -> String;
     fn day_count(&self, date_start: Date, date_end: Date) -> i64;
     fn year_fraction(
         &self,
@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+/// Quoted and escaped JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// JSON number; non-finite values become `null`.
+pub fn json_number(x: f64) -> String {
+    // JSON has no representation for NaN or infinities.
+    if x.is_finite() {
+        format!("{}", x)
+    } else {
+        String::from("null")
+    }
+}
+
+/// JSON array of numbers.
+pub fn json_array(values: &[f64]) -> String {
+    let items: Vec<String> = values.iter().map(|x| json_number(*x)).collect();
+    format!("[{}]", items.join(","))
+}
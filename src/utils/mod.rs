@@ -0,0 +1,6 @@
+pub mod csv;
+pub mod json;
+pub mod parallel;
+pub mod trace;
+
+pub use self::parallel::Parallelism;
@@ -0,0 +1,94 @@
+use crate::settings::PricingContext;
+use std::thread;
+
+/// Execution mode for embarrassingly parallel work such as bootstrapping
+/// independent curves, fitting the smiles of an SVI surface or GARCH
+/// models to several series. Joint fits, e.g. of an SSVI surface, evaluate
+/// their objective on the calling thread.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Parallelism {
+    /// Run everything on the calling thread, in order.
+    Sequential,
+    /// Use at most the given number of worker threads.
+    Threads(usize),
+    /// Use as many worker threads as the machine reports.
+    Available,
+}
+
+impl Default for Parallelism {
+    fn default() -> Parallelism {
+        if cfg!(feature = "parallel") {
+            Parallelism::Available
+        } else {
+            Parallelism::Sequential
+        }
+    }
+}
+
+impl Parallelism {
+    /// The number of threads this mode would use for `tasks` tasks.
+    pub fn threads(&self, tasks: usize) -> usize {
+        // threads cannot be spawned in the browser.
+        if cfg!(target_arch = "wasm32") {
+            return 1;
+        }
+        let n = match *self {
+            Parallelism::Sequential => 1,
+            Parallelism::Threads(n) => n.max(1),
+            Parallelism::Available => thread::available_parallelism().map_or(1, |n| n.get()),
+        };
+        n.min(tasks).max(1)
+    }
+
+    /// Applies `f` to every item and returns the results in input order.
+    ///
+    /// Items are split into contiguous chunks, one per thread, so the output
+    /// only depends on the inputs and never on scheduling; running with
+    /// `Sequential` gives bit-identical results. Workers run under the
+    /// `PricingContext` of the calling thread.
+    pub fn map<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let threads = self.threads(items.len());
+        if threads <= 1 {
+            return items.iter().map(f).collect();
+        }
+        let chunk = items.len().div_ceil(threads);
+        let f = &f;
+        let context = PricingContext::current();
+        thread::scope(|s| {
+            let handles: Vec<_> = items
+                .chunks(chunk)
+                .map(|c| s.spawn(move || context.scope(|| c.iter().map(f).collect::<Vec<R>>())))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("parallel task panicked"))
+                .collect()
+        })
+    }
+
+    /// Applies `f` to every item in place.
+    pub fn for_each_mut<T, F>(&self, items: &mut [T], f: F)
+    where
+        T: Send,
+        F: Fn(&mut T) + Sync,
+    {
+        let threads = self.threads(items.len());
+        if threads <= 1 {
+            items.iter_mut().for_each(f);
+            return;
+        }
+        let chunk = items.len().div_ceil(threads);
+        let f = &f;
+        let context = PricingContext::current();
+        thread::scope(|s| {
+            for c in items.chunks_mut(chunk) {
+                s.spawn(move || context.scope(|| c.iter_mut().for_each(f)));
+            }
+        });
+    }
+}
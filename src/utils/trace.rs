@@ -0,0 +1,295 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// Severity of a trace record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// Value of a structured field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldValue::Bool(x) => write!(f, "{}", x),
+            FieldValue::Int(x) => write!(f, "{}", x),
+            FieldValue::Float(x) => write!(f, "{}", x),
+            FieldValue::Str(x) => write!(f, "{:?}", x),
+        }
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(x: bool) -> FieldValue {
+        FieldValue::Bool(x)
+    }
+}
+impl From<i64> for FieldValue {
+    fn from(x: i64) -> FieldValue {
+        FieldValue::Int(x)
+    }
+}
+impl From<usize> for FieldValue {
+    fn from(x: usize) -> FieldValue {
+        FieldValue::Int(x as i64)
+    }
+}
+impl From<u64> for FieldValue {
+    fn from(x: u64) -> FieldValue {
+        FieldValue::Int(x as i64)
+    }
+}
+impl From<f64> for FieldValue {
+    fn from(x: f64) -> FieldValue {
+        FieldValue::Float(x)
+    }
+}
+impl From<&str> for FieldValue {
+    fn from(x: &str) -> FieldValue {
+        FieldValue::Str(x.to_string())
+    }
+}
+
+pub type Fields = Vec<(&'static str, FieldValue)>;
+
+/// An event, or a span when it closes with its elapsed time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceRecord {
+    pub level: Level,
+    pub name: &'static str,
+    pub fields: Fields,
+    /// Names of the enclosing spans on this thread, outermost first.
+    pub spans: Vec<&'static str>,
+    /// Set for spans only.
+    pub elapsed: Option<Duration>,
+}
+
+impl TraceRecord {
+    pub fn field(&self, name: &str) -> Option<&FieldValue> {
+        self.fields.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+}
+
+impl fmt::Display for TraceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ", self.level)?;
+        for s in &self.spans {
+            write!(f, "{}:", s)?;
+        }
+        write!(f, "{}", self.name)?;
+        for (name, value) in &self.fields {
+            write!(f, " {}={}", name, value)?;
+        }
+        if let Some(elapsed) = self.elapsed {
+            write!(f, " elapsed_us={}", elapsed.as_micros())?;
+        }
+        Ok(())
+    }
+}
+
+/// Receiver of trace records, installed process-wide with
+/// `set_subscriber`.
+pub trait Subscriber: Send + Sync {
+    fn enabled(&self, level: Level) -> bool;
+    fn record(&self, record: &TraceRecord);
+}
+
+/// Writes records at or above a level to standard error, one per line.
+pub struct StderrSubscriber {
+    pub level: Level,
+}
+
+impl Subscriber for StderrSubscriber {
+    fn enabled(&self, level: Level) -> bool {
+        level >= self.level
+    }
+    fn record(&self, record: &TraceRecord) {
+        eprintln!("{}", record);
+    }
+}
+
+/// Keeps records in memory, e.g. to inspect the convergence of a
+/// calibration after the fact.
+pub struct MemorySubscriber {
+    pub level: Level,
+    records: Mutex<Vec<TraceRecord>>,
+}
+
+impl MemorySubscriber {
+    pub fn new(level: Level) -> MemorySubscriber {
+        MemorySubscriber {
+            level,
+            records: Mutex::new(vec![]),
+        }
+    }
+    pub fn records(&self) -> Vec<TraceRecord> {
+        self.records.lock().unwrap().clone()
+    }
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+impl Subscriber for MemorySubscriber {
+    fn enabled(&self, level: Level) -> bool {
+        level >= self.level
+    }
+    fn record(&self, record: &TraceRecord) {
+        self.records.lock().unwrap().push(record.clone());
+    }
+}
+
+static SUBSCRIBER: RwLock<Option<Arc<dyn Subscriber>>> = RwLock::new(None);
+
+thread_local! {
+    static SPANS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn set_subscriber(subscriber: Arc<dyn Subscriber>) {
+    *SUBSCRIBER.write().unwrap() = Some(subscriber);
+}
+
+pub fn clear_subscriber() {
+    *SUBSCRIBER.write().unwrap() = None;
+}
+
+fn subscriber(level: Level) -> Option<Arc<dyn Subscriber>> {
+    SUBSCRIBER
+        .read()
+        .unwrap()
+        .as_ref()
+        .filter(|s| s.enabled(level))
+        .cloned()
+}
+
+/// Whether records at `level` would be kept; lets callers skip building
+/// expensive fields.
+pub fn enabled(level: Level) -> bool {
+    subscriber(level).is_some()
+}
+
+pub fn event(level: Level, name: &'static str, fields: Fields) {
+    if let Some(s) = subscriber(level) {
+        let spans = SPANS.with(|spans| spans.borrow().clone());
+        s.record(&TraceRecord {
+            level,
+            name,
+            fields,
+            spans,
+            elapsed: None,
+        });
+    }
+}
+
+/// Span entered by `enter_span`, reported with its elapsed time when
+/// dropped. Inactive when no subscriber wants its level.
+pub struct SpanGuard {
+    active: Option<(Level, &'static str, Fields, std::time::Instant)>,
+}
+
+impl SpanGuard {
+    pub fn disabled() -> SpanGuard {
+        SpanGuard { active: None }
+    }
+}
+
+pub fn enter_span(level: Level, name: &'static str, fields: Fields) -> SpanGuard {
+    if !enabled(level) {
+        return SpanGuard::disabled();
+    }
+    SPANS.with(|spans| spans.borrow_mut().push(name));
+    SpanGuard {
+        active: Some((level, name, fields, std::time::Instant::now())),
+    }
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if let Some((level, name, fields, start)) = self.active.take() {
+            let spans = SPANS.with(|spans| {
+                let mut spans = spans.borrow_mut();
+                spans.pop();
+                spans.clone()
+            });
+            if let Some(s) = subscriber(level) {
+                s.record(&TraceRecord {
+                    level,
+                    name,
+                    fields,
+                    spans,
+                    elapsed: Some(start.elapsed()),
+                });
+            }
+        }
+    }
+}
+
+/// Records an event when the `tracing` feature is enabled, e.g.
+/// `trace_event!(Level::Debug, "simplex.converged", iterations = n)`.
+/// Without the feature the fields are not evaluated.
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_event {
+    ($level:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::utils::trace::enabled($level) {
+            $crate::utils::trace::event(
+                $level,
+                $name,
+                vec![$((stringify!($key), $crate::utils::trace::FieldValue::from($value))),*],
+            );
+        }
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[macro_export]
+macro_rules! trace_event {
+    ($level:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if false {
+            let _ = ($level, $name, $(&$value),*);
+        }
+    };
+}
+
+/// Enters a span that lasts until the returned guard is dropped, when the
+/// `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::utils::trace::enabled($level) {
+            $crate::utils::trace::enter_span(
+                $level,
+                $name,
+                vec![$((stringify!($key), $crate::utils::trace::FieldValue::from($value))),*],
+            )
+        } else {
+            $crate::utils::trace::SpanGuard::disabled()
+        }
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[macro_export]
+macro_rules! trace_span {
+    ($level:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {{
+        if false {
+            let _ = ($level, $name, $(&$value),*);
+        }
+        $crate::utils::trace::SpanGuard::disabled()
+    }};
+}
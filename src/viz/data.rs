@@ -0,0 +1,92 @@
+use crate::definitions::Time;
+use crate::termstructures::traits::YieldTermStructure;
+
+/// Sampled one-dimensional series, e.g. a zero or forward curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveData {
+    pub name: String,
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+}
+
+impl CurveData {
+    pub fn new(name: &str, x: Vec<f64>, y: Vec<f64>) -> CurveData {
+        assert!(x.len() == y.len(), "x and y sizes mismatch");
+        CurveData {
+            name: name.to_string(),
+            x,
+            y,
+        }
+    }
+
+    /// Samples `f` on the given abscissas.
+    pub fn sample<F: Fn(f64) -> f64>(name: &str, x: &[f64], f: F) -> CurveData {
+        CurveData::new(name, x.to_vec(), x.iter().map(|v| f(*v)).collect())
+    }
+}
+
+/// Values on an expiry x strike grid, `values[expiry][strike]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurfaceData {
+    pub name: String,
+    pub expiries: Vec<Time>,
+    pub strikes: Vec<f64>,
+    pub values: Vec<Vec<f64>>,
+}
+
+impl SurfaceData {
+    /// Samples `f(expiry, strike)` on the grid.
+    pub fn sample<F: Fn(Time, f64) -> f64>(
+        name: &str,
+        expiries: &[Time],
+        strikes: &[f64],
+        f: F,
+    ) -> SurfaceData {
+        SurfaceData {
+            name: name.to_string(),
+            expiries: expiries.to_vec(),
+            strikes: strikes.to_vec(),
+            values: expiries
+                .iter()
+                .map(|t| strikes.iter().map(|k| f(*t, *k)).collect())
+                .collect(),
+        }
+    }
+
+    /// The smile at the `i`-th expiry.
+    pub fn smile(&self, i: usize) -> CurveData {
+        CurveData::new(
+            &format!("{} T={}", self.name, self.expiries[i]),
+            self.strikes.clone(),
+            self.values[i].clone(),
+        )
+    }
+
+    /// The term structure at the `j`-th strike.
+    pub fn term_structure(&self, j: usize) -> CurveData {
+        CurveData::new(
+            &format!("{} K={}", self.name, self.strikes[j]),
+            self.expiries.clone(),
+            self.values.iter().map(|row| row[j]).collect(),
+        )
+    }
+}
+
+/// Continuously compounded zero rates of `curve` at the given times.
+pub fn zero_curve<Y: YieldTermStructure>(name: &str, curve: &Y, times: &[Time]) -> CurveData {
+    CurveData::sample(name, times, |t| {
+        let t = t.max(1.0e-4);
+        -curve.discount_with_time(t, true).ln() / t
+    })
+}
+
+/// Instantaneous continuously compounded forwards of `curve` at the given
+/// times, by central differences of the log-discount.
+pub fn forward_curve<Y: YieldTermStructure>(name: &str, curve: &Y, times: &[Time]) -> CurveData {
+    const DT: Time = 1.0e-4;
+    CurveData::sample(name, times, |t| {
+        let t1 = (t - DT / 2.0).max(0.0);
+        let t2 = t1 + DT;
+        (curve.discount_with_time(t1, true) / curve.discount_with_time(t2, true)).ln() / DT
+    })
+}
@@ -0,0 +1,64 @@
+use super::{CurveData, SurfaceData};
+use crate::utils::csv::csv_field;
+use crate::utils::json::{json_array, json_string};
+use std::fmt::Write;
+
+/// CSV with one `x` column and one column per curve; all curves must be
+/// sampled on the same abscissas.
+pub fn curves_to_csv(curves: &[CurveData]) -> String {
+    let mut out = String::from("x");
+    for c in curves {
+        write!(out, ",{}", csv_field(&c.name)).unwrap();
+    }
+    out.push('\n');
+    if let Some(first) = curves.first() {
+        for (i, x) in first.x.iter().enumerate() {
+            write!(out, "{}", x).unwrap();
+            for c in curves {
+                assert!(c.x.len() == first.x.len(), "curves sampled differently");
+                write!(out, ",{}", c.y[i]).unwrap();
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// JSON array of `{"name", "x", "y"}` objects.
+pub fn curves_to_json(curves: &[CurveData]) -> String {
+    let items: Vec<String> = curves
+        .iter()
+        .map(|c| {
+            format!(
+                "{{\"name\":{},\"x\":{},\"y\":{}}}",
+                json_string(&c.name),
+                json_array(&c.x),
+                json_array(&c.y)
+            )
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Long-format CSV with `expiry,strike,value` rows.
+pub fn surface_to_csv(surface: &SurfaceData) -> String {
+    let mut out = String::from("expiry,strike,value\n");
+    for (i, t) in surface.expiries.iter().enumerate() {
+        for (j, k) in surface.strikes.iter().enumerate() {
+            writeln!(out, "{},{},{}", t, k, surface.values[i][j]).unwrap();
+        }
+    }
+    out
+}
+
+/// JSON object with `name`, `expiries`, `strikes` and row-major `values`.
+pub fn surface_to_json(surface: &SurfaceData) -> String {
+    let rows: Vec<String> = surface.values.iter().map(|r| json_array(r)).collect();
+    format!(
+        "{{\"name\":{},\"expiries\":{},\"strikes\":{},\"values\":[{}]}}",
+        json_string(&surface.name),
+        json_array(&surface.expiries),
+        json_array(&surface.strikes),
+        rows.join(",")
+    )
+}
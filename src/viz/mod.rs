@@ -0,0 +1,7 @@
+pub mod data;
+pub mod export;
+pub mod svg;
+
+pub use self::data::{forward_curve, zero_curve, CurveData, SurfaceData};
+pub use self::export::{curves_to_csv, curves_to_json, surface_to_csv, surface_to_json};
+pub use self::svg::SvgPlot;
@@ -0,0 +1,176 @@
+use super::CurveData;
+use std::fmt::Write;
+
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#d62728", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b",
+];
+const MARGIN: f64 = 50.0;
+const TICKS: usize = 5;
+
+/// Minimal line plot rendered to a standalone SVG document, meant for
+/// eyeballing zero curves, forward curves and smile slices.
+#[derive(Clone, Debug)]
+pub struct SvgPlot {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    pub width: f64,
+    pub height: f64,
+    pub series: Vec<CurveData>,
+}
+
+impl SvgPlot {
+    pub fn new(title: &str, x_label: &str, y_label: &str) -> SvgPlot {
+        SvgPlot {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            width: 640.0,
+            height: 400.0,
+            series: vec![],
+        }
+    }
+
+    pub fn with_series(mut self, series: CurveData) -> SvgPlot {
+        self.series.push(series);
+        self
+    }
+
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let mut b = (f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+        for s in &self.series {
+            for (x, y) in s.x.iter().zip(&s.y) {
+                if x.is_finite() && y.is_finite() {
+                    b = (b.0.min(*x), b.1.max(*x), b.2.min(*y), b.3.max(*y));
+                }
+            }
+        }
+        if b.0 > b.1 {
+            return (0.0, 1.0, 0.0, 1.0);
+        }
+        if b.1 - b.0 < 1.0e-12 {
+            b.1 = b.0 + 1.0;
+        }
+        if b.3 - b.2 < 1.0e-12 {
+            b = (
+                b.0,
+                b.1,
+                b.2 - 0.5 * b.2.abs().max(1.0e-4),
+                b.3 + 0.5 * b.3.abs().max(1.0e-4),
+            );
+        }
+        b
+    }
+
+    /// The SVG document.
+    pub fn render(&self) -> String {
+        let (x0, x1, y0, y1) = self.bounds();
+        let (w, h) = (self.width - 2.0 * MARGIN, self.height - 2.0 * MARGIN);
+        let px = |x: f64| MARGIN + (x - x0) / (x1 - x0) * w;
+        let py = |y: f64| MARGIN + h - (y - y0) / (y1 - y0) * h;
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"sans-serif\" font-size=\"11\">",
+            self.width, self.height
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n<text x=\"{}\" y=\"20\" text-anchor=\"middle\" font-size=\"14\">{}</text>",
+            self.width / 2.0,
+            escape(&self.title)
+        )
+        .unwrap();
+
+        // axes and ticks.
+        writeln!(
+            out,
+            "<path d=\"M{m},{m} V{b} H{r}\" fill=\"none\" stroke=\"black\"/>",
+            m = MARGIN,
+            b = MARGIN + h,
+            r = MARGIN + w
+        )
+        .unwrap();
+        for i in 0..=TICKS {
+            let f = i as f64 / TICKS as f64;
+            let (x, y) = (x0 + f * (x1 - x0), y0 + f * (y1 - y0));
+            writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                px(x),
+                MARGIN + h + 15.0,
+                label(x)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+                MARGIN - 5.0,
+                py(y) + 4.0,
+                label(y)
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+            MARGIN + w / 2.0,
+            self.height - 10.0,
+            escape(&self.x_label)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "<text x=\"12\" y=\"{:.1}\" text-anchor=\"middle\" transform=\"rotate(-90 12 {:.1})\">{}</text>",
+            MARGIN + h / 2.0,
+            MARGIN + h / 2.0,
+            escape(&self.y_label)
+        )
+        .unwrap();
+
+        for (i, s) in self.series.iter().enumerate() {
+            let color = COLORS[i % COLORS.len()];
+            let points: Vec<String> =
+                s.x.iter()
+                    .zip(&s.y)
+                    .filter(|(x, y)| x.is_finite() && y.is_finite())
+                    .map(|(x, y)| format!("{:.2},{:.2}", px(*x), py(*y)))
+                    .collect();
+            writeln!(
+                out,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1.5\"/>",
+                points.join(" "),
+                color
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\">{}</text>",
+                MARGIN + w - 120.0,
+                MARGIN + 14.0 * (i as f64 + 1.0),
+                color,
+                escape(&s.name)
+            )
+            .unwrap();
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn label(v: f64) -> String {
+    if v != 0.0 && v.abs() < 0.1 {
+        format!("{:.4}", v)
+    } else {
+        format!("{:.2}", v)
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
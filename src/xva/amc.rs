@@ -0,0 +1,294 @@
+use super::ExposureProfile;
+use crate::definitions::Time;
+use crate::methods::montecarlo::{LsmBasisSystem, LsmRegression};
+use crate::pricingengines::{PerformanceCounters, Stopwatch};
+use crate::utils::trace::Level;
+
+/// The party holding the exercise right of a callable trade.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExerciseRight {
+    /// We hold the option and exercise when it maximizes our value.
+    Holder,
+    /// The counterparty holds the option and exercises against us.
+    Counterparty,
+}
+
+/// What is left of the trade after exercise.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Settlement {
+    /// The exercise value is paid and the trade terminates.
+    Cash,
+    /// The underlying is entered; its simulated values drive the exposure
+    /// after the exercise date.
+    Physical,
+}
+
+/// One simulated path of a callable trade on the exposure grid.
+///
+/// All vectors are indexed by grid date. Amounts are in currency units at
+/// the grid date and are deflated internally with `numeraire`.
+#[derive(Clone, Debug)]
+pub struct AmcPath {
+    pub numeraire: Vec<f64>,
+    /// Regression variables observed at each date.
+    pub states: Vec<Vec<f64>>,
+    /// Trade cash flow paid at each date while the trade is alive.
+    pub cashflows: Vec<f64>,
+    /// Value received at each date upon exercise, excluding that date's
+    /// cash flow.
+    pub exercise_values: Vec<f64>,
+    /// Value of the underlying at each date; only read for physical
+    /// settlement.
+    pub underlying_values: Vec<f64>,
+}
+
+/// Exercise frontier at an exercise date, e.g. the critical swap rate of
+/// a Bermudan swaption, estimated from the simulated decisions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExerciseBoundary {
+    pub critical_state: f64,
+    /// Whether exercise happens above rather than below the critical
+    /// state.
+    pub exercise_above: bool,
+}
+
+/// Output of the AMC backward induction.
+#[derive(Clone, Debug)]
+pub struct AmcResults {
+    pub npv: f64,
+    /// Conditional future values laid out as `values[path][date]`.
+    pub values: Vec<Vec<f64>>,
+    /// Share of paths exercising at each date.
+    pub exercise_probabilities: Vec<f64>,
+    /// Frontier at each exercise date when requested; `None` elsewhere and
+    /// where all paths take the same decision.
+    pub exercise_boundary: Vec<Option<ExerciseBoundary>>,
+    pub performance: PerformanceCounters,
+}
+
+/// American Monte Carlo valuation of callable trades along exposure paths.
+///
+/// Future values are conditional expectations regressed on the path state
+/// (Longstaff-Schwartz), and the exercise policy is applied path by path, so
+/// that a Bermudan or callable trade disappears from, or turns into its
+/// underlying on, the paths where it is exercised instead of being valued
+/// as if it were European.
+#[derive(Clone, Debug)]
+pub struct AmcExposureEngine {
+    pub basis: LsmBasisSystem,
+    /// Whether the trade can be exercised at each grid date.
+    pub exercise_dates: Vec<bool>,
+    pub exercise_right: ExerciseRight,
+    pub settlement: Settlement,
+    /// Restrict the exercise regression to paths with a positive exercise
+    /// value, as in the original Longstaff-Schwartz algorithm.
+    pub in_the_money_only: bool,
+    /// Regression variable the exercise frontier is read on, if any.
+    pub boundary_state: Option<usize>,
+}
+
+impl AmcExposureEngine {
+    pub fn new(
+        basis: LsmBasisSystem,
+        exercise_dates: Vec<bool>,
+        exercise_right: ExerciseRight,
+        settlement: Settlement,
+    ) -> AmcExposureEngine {
+        AmcExposureEngine {
+            basis,
+            exercise_dates,
+            exercise_right,
+            settlement,
+            in_the_money_only: false,
+            boundary_state: None,
+        }
+    }
+
+    /// Reports the exercise frontier on regression variable `state`.
+    pub fn with_exercise_boundary(mut self, state: usize) -> AmcExposureEngine {
+        self.boundary_state = Some(state);
+        self
+    }
+
+    fn should_exercise(&self, exercise: f64, continuation: f64) -> bool {
+        match self.exercise_right {
+            ExerciseRight::Holder => exercise > continuation,
+            ExerciseRight::Counterparty => exercise < continuation,
+        }
+    }
+
+    /// Runs the backward induction and the forward exercise pass.
+    pub fn calculate(&self, paths: &[AmcPath]) -> AmcResults {
+        assert!(!paths.is_empty(), "no simulated paths given");
+        let stopwatch = Stopwatch::start();
+        let mut performance = PerformanceCounters::default();
+        let n = self.exercise_dates.len();
+        let np = paths.len();
+        let _span = crate::trace_span!(Level::Info, "amc.calculate", paths = np, dates = n);
+        for p in paths {
+            assert!(
+                p.numeraire.len() == n
+                    && p.states.len() == n
+                    && p.cashflows.len() == n
+                    && p.exercise_values.len() == n,
+                "path length does not match the exercise grid"
+            );
+            if self.settlement == Settlement::Physical {
+                assert!(
+                    p.underlying_values.len() == n,
+                    "physical settlement requires underlying values"
+                );
+            }
+        }
+
+        let mut continuation = vec![vec![0.0; n]; np];
+        let mut exercised = vec![vec![false; n]; np];
+        let mut exercise_boundary = vec![None; n];
+        // deflated value of the flows strictly after the current date.
+        let mut y = vec![0.0; np];
+        for i in (0..n).rev() {
+            let states: Vec<&[f64]> = paths.iter().map(|p| p.states[i].as_slice()).collect();
+            let regression = LsmRegression::fit(&self.basis, &states, &y);
+            performance.evaluations += 1;
+            crate::trace_event!(Level::Trace, "amc.regression", date = i);
+            for (k, c) in continuation.iter_mut().enumerate() {
+                c[i] = regression.value(states[k]);
+            }
+
+            if self.exercise_dates[i] {
+                let exercise: Vec<f64> = paths
+                    .iter()
+                    .map(|p| p.exercise_values[i] / p.numeraire[i])
+                    .collect();
+                let mut decision_regression = regression;
+                if self.in_the_money_only {
+                    let itm: Vec<usize> = (0..np).filter(|&k| exercise[k] > 0.0).collect();
+                    if itm.len() >= self.basis.size() {
+                        let s: Vec<&[f64]> = itm.iter().map(|&k| states[k]).collect();
+                        let t: Vec<f64> = itm.iter().map(|&k| y[k]).collect();
+                        decision_regression = LsmRegression::fit(&self.basis, &s, &t);
+                        performance.evaluations += 1;
+                    }
+                }
+                for k in 0..np {
+                    if self.in_the_money_only && exercise[k] <= 0.0 {
+                        continue;
+                    }
+                    let c = decision_regression.value(states[k]);
+                    if self.should_exercise(exercise[k], c) {
+                        exercised[k][i] = true;
+                        y[k] = exercise[k];
+                    }
+                }
+                if let Some(j) = self.boundary_state {
+                    let x: Vec<f64> = states.iter().map(|s| s[j]).collect();
+                    let decisions: Vec<bool> = exercised.iter().map(|e| e[i]).collect();
+                    exercise_boundary[i] = boundary(&x, &decisions);
+                }
+            }
+
+            if i > 0 {
+                for (k, p) in paths.iter().enumerate() {
+                    y[k] += p.cashflows[i] / p.numeraire[i];
+                }
+            }
+        }
+
+        let npv = paths
+            .iter()
+            .zip(&y)
+            .map(|(p, v)| v * p.numeraire[0])
+            .sum::<f64>()
+            / np as f64;
+
+        let mut values = vec![vec![0.0; n]; np];
+        let mut exercise_probabilities = vec![0.0; n];
+        for (k, p) in paths.iter().enumerate() {
+            let mut exercise_date = None;
+            for i in 0..n {
+                values[k][i] = match exercise_date {
+                    Some(_) => match self.settlement {
+                        Settlement::Cash => 0.0,
+                        Settlement::Physical => p.underlying_values[i],
+                    },
+                    None if exercised[k][i] => {
+                        exercise_date = Some(i);
+                        exercise_probabilities[i] += 1.0 / np as f64;
+                        p.exercise_values[i]
+                    }
+                    None if i == 0 => npv,
+                    None => continuation[k][i] * p.numeraire[i],
+                };
+            }
+        }
+
+        performance.paths = np as u64;
+        performance.elapsed = stopwatch.elapsed();
+        crate::trace_event!(
+            Level::Debug,
+            "amc.calculated",
+            npv = npv,
+            regressions = performance.evaluations
+        );
+        AmcResults {
+            npv,
+            values,
+            exercise_probabilities,
+            exercise_boundary,
+            performance,
+        }
+    }
+
+    /// Exposure profile of the trade implied by the AMC future values.
+    pub fn exposure(&self, times: Vec<Time>, paths: &[AmcPath], quantile: f64) -> ExposureProfile {
+        assert!(
+            times.len() == self.exercise_dates.len(),
+            "grid size mismatch"
+        );
+        let results = self.calculate(paths);
+        ExposureProfile::new(times, &results.values, quantile)
+    }
+}
+
+/// The threshold on `states` agreeing with the most exercise `decisions`,
+/// halfway between the neighbouring states.
+fn boundary(states: &[f64], decisions: &[bool]) -> Option<ExerciseBoundary> {
+    let exercised = decisions.iter().filter(|d| **d).count();
+    if exercised == 0 || exercised == decisions.len() {
+        return None;
+    }
+    let mut order: Vec<usize> = (0..states.len()).collect();
+    order.sort_by(|a, b| states[*a].total_cmp(&states[*b]));
+    // misclassified paths when exercising above the split, and below.
+    let mut above = decisions.len() - exercised;
+    let mut below = exercised;
+    let mut best: Option<(usize, ExerciseBoundary)> = None;
+    for w in order.windows(2) {
+        if decisions[w[0]] {
+            above += 1;
+            below -= 1;
+        } else {
+            above -= 1;
+            below += 1;
+        }
+        if states[w[0]] == states[w[1]] {
+            continue;
+        }
+        let (errors, exercise_above) = if above <= below {
+            (above, true)
+        } else {
+            (below, false)
+        };
+        if best.is_none_or(|(e, _)| errors < e) {
+            let critical_state = 0.5 * (states[w[0]] + states[w[1]]);
+            best = Some((
+                errors,
+                ExerciseBoundary {
+                    critical_state,
+                    exercise_above,
+                },
+            ));
+        }
+    }
+    best.map(|(_, b)| b)
+}
@@ -0,0 +1,83 @@
+use crate::definitions::Time;
+use crate::errors::QuantLibError;
+
+/// Exposure statistics of a trade or netting set over a simulation grid.
+#[derive(Clone, Debug)]
+pub struct ExposureProfile {
+    pub times: Vec<Time>,
+    /// Expected positive exposure `E[max(V, 0)]` per grid time.
+    pub expected_exposure: Vec<f64>,
+    /// Expected negative exposure `E[min(V, 0)]` per grid time.
+    pub expected_negative_exposure: Vec<f64>,
+    /// Quantile of the positive exposure per grid time.
+    pub potential_future_exposure: Vec<f64>,
+    pub quantile: f64,
+}
+
+impl ExposureProfile {
+    /// Builds the profile from simulated future values laid out as
+    /// `values[path][date]`.
+    pub fn new(times: Vec<Time>, values: &[Vec<f64>], quantile: f64) -> ExposureProfile {
+        ExposureProfile::checked_new(times, values, quantile).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds the profile, or an error if a simulated value is NaN.
+    pub fn checked_new(
+        times: Vec<Time>,
+        values: &[Vec<f64>],
+        quantile: f64,
+    ) -> Result<ExposureProfile, QuantLibError> {
+        assert!(!values.is_empty(), "no simulated paths given");
+        assert!(
+            quantile > 0.0 && quantile < 1.0,
+            "quantile must be in (0, 1)"
+        );
+        for (path, v) in values.iter().enumerate() {
+            if let Some(i) = v.iter().take(times.len()).position(|x| x.is_nan()) {
+                return Err(QuantLibError::InvalidValue {
+                    what: format!("value on path {} at time {}", path, times[i]),
+                    value: f64::NAN,
+                });
+            }
+        }
+        let paths = values.len() as f64;
+        let mut ee = vec![];
+        let mut ene = vec![];
+        let mut pfe = vec![];
+        for i in 0..times.len() {
+            let mut column: Vec<f64> = values.iter().map(|v| v[i]).collect();
+            ee.push(column.iter().map(|v| v.max(0.0)).sum::<f64>() / paths);
+            ene.push(column.iter().map(|v| v.min(0.0)).sum::<f64>() / paths);
+            column.sort_by(f64::total_cmp);
+            let idx = ((quantile * paths).ceil() as usize).clamp(1, column.len()) - 1;
+            pfe.push(column[idx].max(0.0));
+        }
+        Ok(ExposureProfile {
+            times,
+            expected_exposure: ee,
+            expected_negative_exposure: ene,
+            potential_future_exposure: pfe,
+            quantile,
+        })
+    }
+
+    /// Time-averaged expected positive exposure (EPE) up to the last grid
+    /// time, by trapezoidal integration.
+    pub fn expected_positive_exposure(&self) -> f64 {
+        let n = self.times.len();
+        if n < 2 {
+            return self.expected_exposure.first().copied().unwrap_or(0.0);
+        }
+        let horizon = self.times[n - 1] - self.times[0];
+        if horizon <= 0.0 {
+            return self.expected_exposure[0];
+        }
+        let mut area = 0.0;
+        for i in 1..n {
+            area += 0.5
+                * (self.expected_exposure[i] + self.expected_exposure[i - 1])
+                * (self.times[i] - self.times[i - 1]);
+        }
+        area / horizon
+    }
+}
@@ -0,0 +1,7 @@
+pub mod amc;
+pub mod exposure;
+
+pub use self::amc::{
+    AmcExposureEngine, AmcPath, AmcResults, ExerciseBoundary, ExerciseRight, Settlement,
+};
+pub use self::exposure::ExposureProfile;
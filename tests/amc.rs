@@ -0,0 +1,139 @@
+extern crate quantlib;
+
+use quantlib::methods::montecarlo::{LsmBasisSystem, PolynomialType};
+use quantlib::xva::{AmcExposureEngine, AmcPath, ExerciseRight, ExposureProfile, Settlement};
+
+// The eight-path American put example of Longstaff & Schwartz (2001).
+fn put_paths() -> Vec<AmcPath> {
+    let prices = [
+        [1.00, 1.09, 1.08, 1.34],
+        [1.00, 1.16, 1.26, 1.54],
+        [1.00, 1.22, 1.07, 1.03],
+        [1.00, 0.93, 0.97, 0.92],
+        [1.00, 1.11, 1.56, 1.52],
+        [1.00, 0.76, 0.77, 0.90],
+        [1.00, 0.92, 0.84, 1.01],
+        [1.00, 0.88, 1.22, 1.34],
+    ];
+    let numeraire: Vec<f64> = (0..4).map(|i| (0.06 * i as f64).exp()).collect();
+    prices
+        .iter()
+        .map(|s| AmcPath {
+            numeraire: numeraire.clone(),
+            states: s.iter().map(|x| vec![*x]).collect(),
+            cashflows: vec![0.0; 4],
+            exercise_values: s.iter().map(|x| (1.10 - x).max(0.0)).collect(),
+            underlying_values: vec![],
+        })
+        .collect()
+}
+
+#[test]
+fn test_longstaff_schwartz_put() {
+    let basis = LsmBasisSystem::path_basis_system(2, PolynomialType::Monomial);
+    let mut engine = AmcExposureEngine::new(
+        basis,
+        vec![false, true, true, true],
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    );
+    engine.in_the_money_only = true;
+
+    let results = engine.calculate(&put_paths());
+    assert!((results.npv - 0.1144).abs() < 1.0e-4);
+    assert_eq!(results.performance.paths, 8);
+
+    // exercised paths are dead afterwards.
+    let exercised: f64 = results.exercise_probabilities.iter().sum();
+    assert!((exercised - 5.0 / 8.0).abs() < 1.0e-12);
+    // path 4 exercises at the first date.
+    let v = &results.values[3];
+    assert!((v[1] - 0.17).abs() < 1.0e-12);
+    assert_eq!(v[2], 0.0);
+    assert_eq!(v[3], 0.0);
+}
+
+#[test]
+fn test_exposure_profile_of_callable() {
+    let basis = LsmBasisSystem::path_basis_system(2, PolynomialType::Monomial);
+    let engine = AmcExposureEngine::new(
+        basis,
+        vec![false, true, true, true],
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    );
+    let profile = engine.exposure(vec![0.0, 1.0, 2.0, 3.0], &put_paths(), 0.95);
+    assert_eq!(profile.expected_exposure.len(), 4);
+    for i in 0..4 {
+        assert!(profile.expected_exposure[i] >= 0.0);
+        assert!(profile.potential_future_exposure[i] >= 0.0);
+    }
+    assert!(profile.expected_positive_exposure() > 0.0);
+}
+
+#[test]
+fn test_put_exercise_boundary() {
+    let basis = LsmBasisSystem::path_basis_system(2, PolynomialType::Monomial);
+    let mut engine = AmcExposureEngine::new(
+        basis,
+        vec![false, true, true, true],
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    )
+    .with_exercise_boundary(0);
+    engine.in_the_money_only = true;
+
+    let results = engine.calculate(&put_paths());
+    let boundary = &results.exercise_boundary;
+    assert!(boundary[0].is_none());
+    // the put is exercised on low prices, halfway between the highest
+    // exercising and the lowest continuing paths
+    assert!(boundary[1..].iter().all(|b| !b.unwrap().exercise_above));
+    let critical: Vec<f64> = boundary[1..]
+        .iter()
+        .map(|b| b.unwrap().critical_state)
+        .collect();
+    assert!((critical[0] - 1.01).abs() < 1.0e-12);
+    assert!((critical[1] - 1.02).abs() < 1.0e-12);
+    assert!((critical[2] - 1.185).abs() < 1.0e-12);
+    assert!(AmcExposureEngine::new(
+        LsmBasisSystem::path_basis_system(2, PolynomialType::Monomial),
+        vec![false, true, true, true],
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    )
+    .calculate(&put_paths())
+    .exercise_boundary
+    .iter()
+    .all(|b| b.is_none()));
+}
+
+#[test]
+fn test_nan_states_and_values_do_not_panic_in_sorts() {
+    let mut engine = AmcExposureEngine::new(
+        LsmBasisSystem::path_basis_system(2, PolynomialType::Monomial),
+        vec![false, true, true, true],
+        ExerciseRight::Holder,
+        Settlement::Cash,
+    )
+    .with_exercise_boundary(0);
+    engine.in_the_money_only = true;
+    let times = vec![0.0, 1.0, 2.0, 3.0];
+    let results = engine.calculate(&put_paths());
+    let mut values = results.values.clone();
+    values[2][1] = f64::NAN;
+    assert_eq!(
+        ExposureProfile::checked_new(times.clone(), &values, 0.95)
+            .unwrap_err()
+            .to_string(),
+        "invalid value on path 2 at time 1: NaN"
+    );
+    assert!(ExposureProfile::checked_new(times, &results.values, 0.95).is_ok());
+
+    // an out of the money path with an unusable state still gives a
+    // boundary
+    let mut paths = put_paths();
+    paths[1].states[1] = vec![f64::NAN];
+    let results = engine.calculate(&paths);
+    assert!(results.exercise_boundary[1].is_some());
+}
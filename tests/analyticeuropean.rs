@@ -0,0 +1,134 @@
+extern crate quantlib;
+
+use quantlib::definitions::{Time, Volatility};
+use quantlib::instruments::{OptionType, ValuationStatus, VanillaOption};
+use quantlib::pricingengines::AnalyticEuropeanEngine;
+use quantlib::quotes::SimpleQuote;
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::traits::BlackVolTermStructure;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual360, Calendar, WeekendsOnly};
+use std::rc::Rc;
+
+struct ConstantVol(Volatility);
+
+impl BlackVolTermStructure for ConstantVol {
+    fn black_vol(&self, _t: Time, _strike: f64) -> Volatility {
+        self.0
+    }
+}
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote, Actual360>;
+
+fn curve(rate: f64) -> Rc<Curve> {
+    Rc::new(YieldTermStructure::new(
+        Calendar::new(WeekendsOnly),
+        reference_date(),
+        Actual360,
+        0,
+        vec![],
+        vec![],
+        Box::new(move |t| (-rate * t).exp()),
+    ))
+}
+
+fn engine(r: f64, q: f64, vol: Volatility) -> AnalyticEuropeanEngine<Curve, Curve, ConstantVol> {
+    AnalyticEuropeanEngine::new(curve(r), curve(q), Rc::new(ConstantVol(vol)))
+}
+
+/// Six months on Actual/360.
+fn option(option_type: OptionType, strike: f64) -> VanillaOption {
+    VanillaOption::new(option_type, strike, reference_date().add_days(180))
+}
+
+#[test]
+fn test_reference_values() {
+    // Hull, "Options, Futures and Other Derivatives", example 15.6
+    let hull = engine(0.10, 0.0, 0.20);
+    let call = hull.npv(&option(OptionType::Call, 40.0), 42.0);
+    let put = hull.npv(&option(OptionType::Put, 40.0), 42.0);
+    assert!((call - 4.7594).abs() < 5.0e-5);
+    assert!((put - 0.8086).abs() < 5.0e-5);
+
+    // Haug, "The Complete Guide to Option Pricing Formulas", generalized
+    // Black-Scholes with a continuous dividend yield
+    let put = engine(0.10, 0.05, 0.20).npv(&option(OptionType::Put, 95.0), 100.0);
+    assert!((put - 2.4648).abs() < 5.0e-5);
+}
+
+#[test]
+fn test_put_call_parity() {
+    let engine = engine(0.03, 0.02, 0.25);
+    let (call, put) = (
+        engine.greeks(&option(OptionType::Call, 105.0), 100.0),
+        engine.greeks(&option(OptionType::Put, 105.0), 100.0),
+    );
+    let (r, q, t) = (0.03, 0.02, 0.5f64);
+    let forward_value = 100.0 * (-q * t).exp() - 105.0 * (-r * t).exp();
+    assert!((call.npv - put.npv - forward_value).abs() < 1.0e-12);
+    assert!((call.delta - put.delta - (-q * t).exp()).abs() < 1.0e-12);
+    assert!((call.gamma - put.gamma).abs() < 1.0e-12);
+    assert!((call.vega - put.vega).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_greeks_match_finite_differences() {
+    let (r, q, vol, spot) = (0.04, 0.01, 0.3, 100.0);
+    let option = option(OptionType::Call, 95.0);
+    let greeks = engine(r, q, vol).greeks(&option, spot);
+    let npv = |r: f64, q: f64, vol: f64, spot: f64| engine(r, q, vol).npv(&option, spot);
+    let npv_of = |option: &VanillaOption| engine(r, q, vol).npv(option, spot);
+    let h = 1.0e-4;
+    let delta = (npv(r, q, vol, spot + h) - npv(r, q, vol, spot - h)) / (2.0 * h);
+    let gamma = (npv(r, q, vol, spot + 0.01) - 2.0 * greeks.npv + npv(r, q, vol, spot - 0.01))
+        / (0.01 * 0.01);
+    let vega = (npv(r, q, vol + h, spot) - npv(r, q, vol - h, spot)) / (2.0 * h);
+    let rho = (npv(r + h, q, vol, spot) - npv(r - h, q, vol, spot)) / (2.0 * h);
+    let dividend_rho = (npv(r, q + h, vol, spot) - npv(r, q - h, vol, spot)) / (2.0 * h);
+    assert!((greeks.delta - delta).abs() < 1.0e-7);
+    assert!((greeks.gamma - gamma).abs() < 1.0e-5);
+    assert!((greeks.vega - vega).abs() < 1.0e-6);
+    assert!((greeks.rho - rho).abs() < 1.0e-6);
+    assert!((greeks.dividend_rho - dividend_rho).abs() < 1.0e-6);
+
+    // a day either side at constant rates and volatility
+    let expiring = |days| VanillaOption {
+        exercise_date: option.exercise_date.add_days(days),
+        ..option
+    };
+    let theta = (npv_of(&expiring(-1)) - npv_of(&expiring(1))) * 360.0 / 2.0;
+    assert!((greeks.theta - theta).abs() < 1.0e-4);
+}
+
+#[test]
+fn test_limiting_values() {
+    let (r, q, t) = (0.05, 0.02, 0.5f64);
+    let forward = 100.0 * ((r - q) * t).exp();
+    let discount = (-r * t).exp();
+    // without volatility, the discounted intrinsic value of the forward
+    let quiet = engine(r, q, 1.0e-8);
+    let call = quiet.greeks(&option(OptionType::Call, 95.0), 100.0);
+    assert!((call.npv - discount * (forward - 95.0)).abs() < 1.0e-10);
+    assert!((call.delta - (-q * t).exp()).abs() < 1.0e-10);
+    assert!(quiet.npv(&option(OptionType::Put, 95.0), 100.0).abs() < 1.0e-10);
+    // at anything but a vanishing strike, a call is worth less than the
+    // spot, and as the strike vanishes it approaches the discounted spot
+    let call = engine(r, q, 3.0).npv(&option(OptionType::Call, 1.0e-6), 100.0);
+    assert!(call < 100.0 * (-q * t).exp());
+    assert!((call - 100.0 * (-q * t).exp()).abs() < 1.0e-5);
+}
+
+#[test]
+fn test_options_expiring_today() {
+    let engine = engine(0.05, 0.0, 0.2);
+    let today = VanillaOption::new(OptionType::Put, 110.0, reference_date());
+    let greeks = engine.greeks(&today, 100.0);
+    assert_eq!(greeks.status, ValuationStatus::Expired);
+    assert_eq!((greeks.npv, greeks.delta), (0.0, 0.0));
+    // unless today's flows are included, the payoff is settled already
+    let with_todays_flows = PricingContext::new(reference_date()).with_todays_cashflows(true);
+    let valuation = with_todays_flows.scope(|| engine.valuation(&today, 100.0));
+    assert_eq!(valuation.status, ValuationStatus::PastSettlement);
+    assert_eq!(valuation.npv, 0.0);
+}
@@ -0,0 +1,68 @@
+extern crate quantlib;
+
+use quantlib::termstructures::{
+    ArbitrageKind, ArbitrageValidator, SsviSurface, SviParameters, SviSmile, SviSurface,
+};
+
+fn moneyness_grid() -> Vec<f64> {
+    (0..61).map(|i| -1.5 + 0.05 * i as f64).collect()
+}
+
+#[test]
+fn test_ssvi_surface_is_arbitrage_free() {
+    let surface = SsviSurface::new(
+        -0.6,
+        1.2,
+        0.4,
+        vec![0.25, 0.5, 1.0, 2.0],
+        vec![0.01, 0.018, 0.035, 0.066],
+        vec![100.0; 4],
+    );
+    let validator = ArbitrageValidator::new(vec![0.25, 0.4, 0.5, 1.0, 1.5, 2.0], moneyness_grid());
+    let report = validator.validate(&surface, |_| 100.0);
+    assert!(report.is_arbitrage_free());
+}
+
+#[test]
+fn test_butterfly_arbitrage() {
+    // Axel Vogt's example from Gatheral and Jacquier (2014).
+    let vogt = SviParameters::new(-0.041, 0.1331, 0.306, 0.3586, 0.4153);
+    let surface = SviSurface::new(vec![SviSmile::new(1.0, 100.0, vogt)]);
+    let validator = ArbitrageValidator::new(vec![1.0], moneyness_grid());
+    let report = validator.validate(&surface, |_| 100.0);
+
+    assert!(!report.is_arbitrage_free());
+    assert!(report.violations_of(ArbitrageKind::Calendar).is_empty());
+    let regions = report.regions();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].kind, ArbitrageKind::Butterfly);
+    // the density is negative for log-moneyness between about 0.65 and 1.25.
+    assert!(regions[0].min_strike > 150.0 && regions[0].max_strike < 400.0);
+    assert!(report.violations.iter().all(|v| v.value < 0.0));
+}
+
+#[test]
+fn test_calendar_arbitrage() {
+    let short = SviParameters::new(0.02, 0.1, -0.5, 0.0, 0.2);
+    // lower variance in the put wing only.
+    let long = SviParameters::new(0.03, 0.1, 0.3, 0.0, 0.2);
+    let surface = SviSurface::new(vec![
+        SviSmile::new(0.5, 100.0, short),
+        SviSmile::new(1.0, 100.0, long),
+    ]);
+    let validator = ArbitrageValidator::new(vec![0.5, 1.0], moneyness_grid());
+    let report = validator.validate(&surface, |_| 100.0);
+
+    let calendar = report.violations_of(ArbitrageKind::Calendar);
+    assert!(!calendar.is_empty());
+    assert!(calendar
+        .iter()
+        .all(|v| v.expiry == 1.0 && v.log_moneyness < 0.0));
+    let regions: Vec<_> = report
+        .regions()
+        .into_iter()
+        .filter(|r| r.kind == ArbitrageKind::Calendar)
+        .collect();
+    assert_eq!(regions.len(), 1);
+    assert!((regions[0].min_strike - 100.0 * (-1.5_f64).exp()).abs() < 1.0e-10);
+}
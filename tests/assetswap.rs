@@ -0,0 +1,96 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{Base, FixedRateCoupon};
+use quantlib::instruments::{AssetSwap, AssetSwapType};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::testutils::market::{flat_curve, flat_rate, reference_date};
+use quantlib::time::{Actual360, Actual365Fixed, Date, DayCounter};
+
+fn annual_coupons(coupon: f64, years: i64) -> Vec<FixedRateCoupon<Actual365Fixed>> {
+    let start = reference_date();
+    (0..years)
+        .map(|k| {
+            let (d1, d2) = (start.add_months(12 * k), start.add_months(12 * (k + 1)));
+            let base = Base {
+                nominal: 100.0,
+                day_counter: Actual365Fixed {},
+                payment_date: d2,
+                accrual_start_date: d1,
+                accrual_end_date: d2,
+                reference_period_start: d1,
+                reference_period_end: d2,
+            };
+            FixedRateCoupon::new(base, flat_rate(coupon))
+        })
+        .collect()
+}
+
+fn semiannual_dates(years: i64) -> Vec<Date> {
+    (0..=2 * years)
+        .map(|k| reference_date().add_months(6 * k))
+        .collect()
+}
+
+fn package(
+    asset_swap_type: AssetSwapType,
+    price: f64,
+) -> AssetSwap<FixedRateCoupon<Actual365Fixed>, Actual360> {
+    AssetSwap::new(
+        asset_swap_type,
+        annual_coupons(0.04, 5),
+        100.0,
+        price,
+        semiannual_dates(5),
+        Actual360,
+    )
+}
+
+#[test]
+fn test_par_par_spread_on_a_single_curve() {
+    let curve = flat_curve(0.02);
+    let model = package(AssetSwapType::ParPar, 100.0).model_price(&curve);
+    let price = model - 3.0;
+    let asw = package(AssetSwapType::ParPar, price);
+    let annuity: f64 = asw
+        .floating_dates
+        .windows(2)
+        .map(|w| Actual360.year_fraction(w[0], w[1], None, None) * curve.discount(w[1], true))
+        .sum();
+    let spread = asw.spread(&curve, &curve);
+    assert!((spread - 0.03 / annuity).abs() < 1.0e-12);
+    // priced at the curve, the bond swaps flat
+    let flat = package(AssetSwapType::ParPar, model);
+    assert!(flat.spread(&curve, &curve).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_proceeds_spread_scales_with_price() {
+    let curve = flat_curve(0.02);
+    let price = package(AssetSwapType::ParPar, 100.0).model_price(&curve) - 4.0;
+    let par_par = package(AssetSwapType::ParPar, price).spread(&curve, &curve);
+    let proceeds = package(AssetSwapType::Proceeds, price).spread(&curve, &curve);
+    assert!((proceeds - par_par * 100.0 / price).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_spread_net_of_tenor_basis() {
+    let (discount, forwarding) = (flat_curve(0.02), flat_curve(0.025));
+    let price = package(AssetSwapType::ParPar, 100.0).model_price(&discount) - 2.0;
+    let asw = package(AssetSwapType::ParPar, price);
+    let single = asw.spread(&discount, &discount);
+    let dual = asw.spread(&discount, &forwarding);
+    // a higher index leaves less spread to pay
+    assert!(dual < single - 0.004 && dual > single - 0.006);
+}
+
+#[test]
+fn test_matched_maturity_swap_spread() {
+    let curve = flat_curve(0.02);
+    let asw = package(AssetSwapType::ParPar, 100.0);
+    // continuously compounded 2% on Actual/360 semiannual floating vs
+    // Actual/365 annual fixed
+    let swap_rate = asw.matched_swap_rate(&curve, &curve);
+    assert!(swap_rate > 0.0201 && swap_rate < 0.0204);
+    let spread = asw.swap_spread(0.035, &curve, &curve);
+    assert!((spread - (0.035 - swap_rate)).abs() < 1.0e-15);
+}
@@ -0,0 +1,143 @@
+extern crate quantlib;
+
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{AtmRateCalculator, YieldTermStructure};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, Calendar, Date, DayCounter, Month, Period, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+fn months(n: i64) -> Period {
+    Period::new(n, TimeUnit::Months)
+}
+
+fn years(n: i64) -> Period {
+    Period::new(n, TimeUnit::Years)
+}
+
+fn calculator(
+    forwarding: f64,
+) -> AtmRateCalculator<WeekendsOnly, Curve, Actual365Fixed, Actual360> {
+    AtmRateCalculator::new(
+        reference_date(),
+        Calendar::new(WeekendsOnly),
+        2,
+        years(1),
+        Actual365Fixed,
+        months(6),
+        Actual360,
+        Rc::new(flat_curve(forwarding)),
+    )
+}
+
+#[test]
+fn test_single_curve_swap_rates() {
+    let c = calculator(0.03);
+    let curve = flat_curve(0.03);
+    let expiry = c.expiry_date(years(1));
+    let atm = c.swap_rate(expiry, years(5));
+    assert_eq!(atm.start_date, Date::new(19, Month::January, 2021));
+    // the floating leg collapses to P(start) - P(end)
+    let floating = curve.discount(atm.start_date, true) - curve.discount(atm.end_date, true);
+    assert!((atm.rate - floating / atm.annuity).abs() < 1.0e-14);
+    // annual Actual/365 coupons on a 3% continuous curve
+    assert!(
+        (atm.rate - (0.03_f64.exp() - 1.0)).abs() < 2.0e-4,
+        "{:?}",
+        atm
+    );
+    let dates: Vec<Date> = (0..=5)
+        .map(|k| {
+            c.calendar
+                .adjust_with_convention(atm.start_date.add_months(12 * k), c.convention)
+        })
+        .collect();
+    let annuity: f64 = dates
+        .windows(2)
+        .map(|w| Actual365Fixed.year_fraction(w[0], w[1], None, None) * curve.discount(w[1], true))
+        .sum();
+    assert!((atm.annuity - annuity).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_dual_curve_swap_rates() {
+    let single = calculator(0.03);
+    let dual = calculator(0.035).with_discounting_curve(Rc::new(flat_curve(0.03)));
+    let expiry = single.expiry_date(years(2));
+    let (s, d) = (
+        single.swap_rate(expiry, years(10)),
+        dual.swap_rate(expiry, years(10)),
+    );
+    assert!((s.annuity - d.annuity).abs() < 1.0e-14);
+    assert!(
+        d.rate - s.rate > 0.004 && d.rate - s.rate < 0.006,
+        "{} {}",
+        s.rate,
+        d.rate
+    );
+}
+
+#[test]
+fn test_cap_rates_are_swap_rates_on_the_caplet_schedule() {
+    let c = AtmRateCalculator::new(
+        reference_date(),
+        Calendar::new(WeekendsOnly),
+        2,
+        months(6),
+        Actual360,
+        months(6),
+        Actual360,
+        Rc::new(flat_curve(0.03)),
+    );
+    let expiry = c.expiry_date(years(1));
+    let swap = c.swap_rate(expiry, years(3));
+    let with_first = AtmRateCalculator {
+        include_first_caplet: true,
+        ..c
+    };
+    let cap = with_first.cap_rate(expiry, years(3));
+    assert!((cap.rate - swap.rate).abs() < 1.0e-14);
+    assert!((cap.annuity - swap.annuity).abs() < 1.0e-14);
+
+    // spot caps leave out the first caplet
+    let spot = AtmRateCalculator {
+        include_first_caplet: false,
+        ..with_first
+    };
+    let caps = spot.cap_rates(&[years(1), years(2), years(5)]);
+    let first = spot.start_date(reference_date()).add_months(6);
+    let one_year = &caps[0];
+    let curve = flat_curve(0.03);
+    let tau = Actual360.year_fraction(first, one_year.end_date, None, None);
+    let forward =
+        (curve.discount(first, true) / curve.discount(one_year.end_date, true) - 1.0) / tau;
+    assert!(
+        (one_year.rate - forward).abs() < 1.0e-4,
+        "{} {}",
+        one_year.rate,
+        forward
+    );
+    assert!(caps.windows(2).all(|w| w[1].annuity > w[0].annuity));
+}
+
+#[test]
+fn test_expiry_tenor_grid() {
+    let c = calculator(0.03);
+    let expiries = [months(6), years(1), years(5)];
+    let tenors = [years(2), years(10)];
+    let grid = c.swap_rates(&expiries, &tenors);
+    assert_eq!(grid.len(), 6);
+    for (i, e) in expiries.iter().enumerate() {
+        for (j, t) in tenors.iter().enumerate() {
+            let atm = &grid[i * tenors.len() + j];
+            assert_eq!(atm.expiry, c.expiry_date(*e));
+            assert_eq!(atm.tenor, *t);
+            assert!(atm.rate > 0.029 && atm.rate < 0.032);
+        }
+        assert!(grid[i * 2 + 1].annuity > grid[i * 2].annuity);
+    }
+}
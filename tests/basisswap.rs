@@ -0,0 +1,228 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{
+    Base, CashFlow, Coupon, Event, IborCoupon, IborLeg, OvernightIndexedCoupon, OvernightLeg,
+};
+use quantlib::indexes::{IborIndex, Index};
+use quantlib::instruments::{BasisSwap, SwapType};
+use quantlib::math::Interpolation;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    BasisSwapRateHelper, PiecewiseYieldCurve, RateHelper, YieldTermStructure,
+};
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index360 = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+fn euribor(months: i64, curve: &Rc<Curve>) -> Rc<Index360> {
+    Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(months, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::clone(curve)),
+    ))
+}
+
+fn estr(curve: &Rc<Curve>) -> Index360 {
+    IborIndex::overnight(
+        "Estr",
+        Calendar::new(WeekendsOnly),
+        Actual360,
+        Some(Rc::clone(curve)),
+    )
+}
+
+// two years from spot on the 15th January 2020 evaluation date.
+fn schedule(months: i64) -> Schedule {
+    Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2022),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn settlement() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+#[test]
+fn test_overnight_compounding_with_fixings() {
+    let curve = Rc::new(flat_curve(0.01));
+    let index = estr(&curve);
+    assert_eq!(index.name(), "EstrON");
+    index.add_fixing(Date::new(17, Month::January, 2020), 0.01);
+    index.add_fixing(Date::new(20, Month::January, 2020), 0.02);
+
+    // Friday to Tuesday: one fixing over the weekend, one overnight.
+    let (start, end) = (
+        Date::new(17, Month::January, 2020),
+        Date::new(21, Month::January, 2020),
+    );
+    let base = Base {
+        nominal: 1.0,
+        day_counter: Actual360,
+        payment_date: end,
+        accrual_start_date: start,
+        accrual_end_date: end,
+        reference_period_start: start,
+        reference_period_end: end,
+    };
+    let coupon = OvernightIndexedCoupon::new(base, Rc::new(index), 1.0, 0.0005);
+    assert_eq!(coupon.value_dates().len(), 3);
+    let compound = (1.0 + 0.01 * 3.0 / 360.0) * (1.0 + 0.02 / 360.0);
+    let expected = (compound - 1.0) / (4.0 / 360.0) + 0.0005;
+    assert!((coupon.rate() - expected).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_overnight_accrual_compounds_observed_fixings() {
+    let curve = Rc::new(flat_curve(0.01));
+    let index = estr(&curve);
+    index.add_fixing(Date::new(17, Month::January, 2020), 0.01);
+    index.add_fixing(Date::new(20, Month::January, 2020), 0.02);
+    index.add_fixing(Date::new(21, Month::January, 2020), 0.03);
+    let (start, end) = (
+        Date::new(17, Month::January, 2020),
+        Date::new(22, Month::January, 2020),
+    );
+    let base = Base {
+        nominal: 100.0,
+        day_counter: Actual360,
+        payment_date: end,
+        accrual_start_date: start,
+        accrual_end_date: end,
+        reference_period_start: start,
+        reference_period_end: end,
+    };
+    let coupon = OvernightIndexedCoupon::new(base, Rc::new(index), 2.0, 0.001);
+    // on Tuesday the Friday and Monday fixings have accrued
+    let compound = (1.0 + 0.01 * 3.0 / 360.0) * (1.0 + 0.02 / 360.0);
+    let expected = 100.0 * (2.0 * (compound - 1.0) + 0.001 * 4.0 / 360.0);
+    let accrued = coupon.accrued_amount(Date::new(21, Month::January, 2020));
+    assert!((accrued - expected).abs() < 1.0e-13);
+    assert!((coupon.accrued_amount(end) - coupon.amount()).abs() < 1.0e-13);
+}
+
+#[test]
+fn test_overnight_leg_payment_lag() {
+    let curve = Rc::new(flat_curve(0.015));
+    let index = Rc::new(estr(&curve));
+    let leg = OvernightLeg::new(schedule(3), index, Actual360)
+        .with_payment_lag(2)
+        .build();
+    // 17th April 2020 is a Friday
+    assert_eq!(leg[0].accrual_end_date(), Date::new(17, Month::April, 2020));
+    assert_eq!(leg[0].date(), Date::new(21, Month::April, 2020));
+    assert_eq!(leg[1].accrual_start_date(), leg[0].accrual_end_date());
+}
+
+#[test]
+fn test_overnight_forecast_telescopes() {
+    let curve = Rc::new(flat_curve(0.015));
+    let index = Rc::new(estr(&curve));
+    let leg = OvernightLeg::new(schedule(3), index, Actual360).build();
+    assert_eq!(leg.len(), 8);
+    let coupon = &leg[0];
+    let (s, e) = (coupon.accrual_start_date(), coupon.accrual_end_date());
+    let expected =
+        (curve.discount(s, true) / curve.discount(e, true) - 1.0) / coupon.accrual_period();
+    assert!((coupon.compounded_fixing() - expected).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_single_curve_basis_is_zero() {
+    let curve = Rc::new(flat_curve(0.02));
+    let swap = BasisSwap::new(
+        SwapType::Receiver,
+        OvernightLeg::new(schedule(3), Rc::new(estr(&curve)), Actual360).build(),
+        IborLeg::new(schedule(3), euribor(3, &curve), Actual360).build(),
+        0.0,
+    );
+    // only the mismatch between index and coupon periods is left.
+    assert!(swap.fair_spread(curve.as_ref(), settlement()).abs() < 1.0e-6);
+}
+
+fn tenor_basis_swap(
+    swap_type: SwapType,
+    spread: f64,
+    three_months: &Rc<Index360>,
+    six_months: &Rc<Index360>,
+) -> BasisSwap<IborCoupon<Index360, Actual360>, IborCoupon<Index360, Actual360>> {
+    BasisSwap::new(
+        swap_type,
+        IborLeg::new(schedule(3), Rc::clone(three_months), Actual360)
+            .with_notional(1.0e6)
+            .with_spread(spread)
+            .build(),
+        IborLeg::new(schedule(6), Rc::clone(six_months), Actual360)
+            .with_notional(1.0e6)
+            .build(),
+        spread,
+    )
+}
+
+#[test]
+fn test_tenor_basis_swap() {
+    let discount = Rc::new(flat_curve(0.015));
+    let three_months = euribor(3, &Rc::new(flat_curve(0.02)));
+    let six_months = euribor(6, &Rc::new(flat_curve(0.022)));
+
+    let swap = tenor_basis_swap(SwapType::Receiver, 0.0, &three_months, &six_months);
+    let fair = swap.fair_spread(discount.as_ref(), settlement());
+    assert!(fair > 0.0015 && fair < 0.0025);
+    assert!(swap.npv(discount.as_ref(), settlement()) < 0.0);
+
+    let fair_swap = tenor_basis_swap(SwapType::Receiver, fair, &three_months, &six_months);
+    assert!(fair_swap.npv(discount.as_ref(), settlement()).abs() < 1.0e-6);
+    assert!((fair_swap.fair_spread(discount.as_ref(), settlement()) - fair).abs() < 1.0e-12);
+
+    // one basis point on the spread leg is worth its bps.
+    let bumped = tenor_basis_swap(SwapType::Payer, fair + 1.0e-4, &three_months, &six_months);
+    let bps = bumped.spread_leg_bps(discount.as_ref(), settlement());
+    assert!((bumped.npv(discount.as_ref(), settlement()) + bps).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_basis_swap_rate_helper() {
+    let discount = Rc::new(flat_curve(0.015));
+    let projection = Rc::new(flat_curve(0.02));
+    let three_months = euribor(3, &projection);
+    let six_months = euribor(6, &Rc::new(flat_curve(0.022)));
+    let swap = tenor_basis_swap(SwapType::Receiver, 0.0, &three_months, &six_months);
+    let fair = swap.fair_spread(discount.as_ref(), settlement());
+
+    let helper = BasisSwapRateHelper::new(SimpleQuote::new(fair), swap, discount, settlement());
+    // the spread leg reprices off its own projection curve
+    let on_projection = |d: Date| projection.discount(d, true);
+    assert!(helper.quote_error_on(&on_projection).abs() < 1.0e-6);
+    assert_eq!(helper.pillar_date(), Date::new(17, Month::January, 2022));
+
+    // and the quote bootstraps it back
+    let curve = PiecewiseYieldCurve::new(
+        settlement(),
+        &[&helper],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed {},
+        Interpolation::LogLinear,
+    )
+    .unwrap();
+    assert!(curve.errors[0].abs() < 1.0e-10);
+    let pillar = helper.pillar_date();
+    assert!((curve.discount(pillar) - projection.discount(pillar, true)).abs() < 1.0e-4);
+}
@@ -0,0 +1,71 @@
+extern crate quantlib;
+
+use quantlib::math::{BilinearInterpolation, Matrix};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::{
+    BlackConstantVol, BlackVarianceSurface, BlackVolTermStructure, StrikeExtrapolation,
+};
+
+fn surface() -> BlackVarianceSurface<BilinearInterpolation> {
+    let vols = Matrix::from_rows(&[
+        vec![0.30, 0.28, 0.26],
+        vec![0.25, 0.24, 0.23],
+        vec![0.27, 0.25, 0.24],
+    ]);
+    BlackVarianceSurface::new(vec![0.5, 1.0, 2.0], vec![80.0, 100.0, 120.0], vols)
+}
+
+#[test]
+fn test_constant_vol_is_flat() {
+    let vol = BlackConstantVol::new(SimpleQuote::new(0.2));
+    for &(t, k) in &[(0.1, 50.0), (1.0, 100.0), (10.0, 300.0)] {
+        assert_eq!(vol.black_vol(t, k), 0.2);
+        assert!((vol.black_variance(t, k) - 0.04 * t).abs() < 1.0e-15);
+    }
+    assert!((vol.black_forward_vol(1.0, 3.0, 100.0) - 0.2).abs() < 1.0e-15);
+    assert!((vol.black_forward_variance(1.0, 3.0, 100.0) - 0.08).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_constant_vol_follows_its_quote() {
+    let vol = BlackConstantVol::new(SimpleQuote::new(0.2));
+    vol.volatility.set_value(0.35);
+    assert_eq!(vol.black_vol(2.0, 100.0), 0.35);
+    assert!((vol.black_variance(2.0, 100.0) - 0.245).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_surface_strike_extrapolation() {
+    let flat = surface();
+    assert_eq!(flat.lower_extrapolation, StrikeExtrapolation::Constant);
+    assert!((flat.black_vol(1.0, 60.0) - 0.28).abs() < 1.0e-14);
+    assert!((flat.black_vol(1.0, 140.0) - 0.25).abs() < 1.0e-14);
+    let extrapolated = surface().with_extrapolation(
+        StrikeExtrapolation::Interpolator,
+        StrikeExtrapolation::Constant,
+    );
+    // variance carried on linearly from the 80-100 cell
+    let (v80, v100): (f64, f64) = (0.28 * 0.28, 0.24 * 0.24);
+    let expected = (v80 + (v80 - v100)).sqrt();
+    assert!((extrapolated.black_vol(1.0, 60.0) - expected).abs() < 1.0e-14);
+    assert!((extrapolated.black_vol(1.0, 140.0) - 0.25).abs() < 1.0e-14);
+    // inside the grid nothing changes
+    assert_eq!(
+        extrapolated.black_vol(0.75, 90.0),
+        flat.black_vol(0.75, 90.0)
+    );
+}
+
+#[test]
+fn test_extrapolated_variance_is_floored() {
+    // a skew falling with the strike
+    let vols = Matrix::from_rows(&[vec![0.30, 0.30], vec![0.20, 0.20]]);
+    let surface: BlackVarianceSurface<BilinearInterpolation> =
+        BlackVarianceSurface::new(vec![1.0, 2.0], vec![90.0, 110.0], vols).with_extrapolation(
+            StrikeExtrapolation::Constant,
+            StrikeExtrapolation::Interpolator,
+        );
+    assert!(surface.black_vol(1.0, 120.0) < 0.2);
+    assert_eq!(surface.black_variance(1.0, 200.0), 0.0);
+    assert_eq!(surface.black_vol(1.0, 50.0), 0.3);
+}
@@ -0,0 +1,166 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{AverageBmaLeg, Coupon, IborLeg};
+use quantlib::indexes::{BmaIndex, IborIndex, Index, InterestRateIndex};
+use quantlib::instruments::{BmaSwap, SwapType};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{
+    Actual360, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule,
+    Sweden, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Bma = BmaIndex<WeekendsOnly, Curve, Actual360>;
+
+fn bma(curve: &Rc<Curve>) -> Bma {
+    BmaIndex::new(
+        Calendar::new(WeekendsOnly),
+        Actual360,
+        Some(Rc::clone(curve)),
+    )
+}
+
+fn schedule(months: i64) -> Schedule {
+    Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2022),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+#[test]
+fn test_fixes_on_wednesdays_or_the_next_business_day() {
+    let curve = Rc::new(flat_curve(0.01));
+    let index = bma(&curve);
+    assert!(index.is_valid_fixing_date(Date::new(15, Month::January, 2020)));
+    assert!(!index.is_valid_fixing_date(Date::new(16, Month::January, 2020)));
+    assert_eq!(index.name(), "BMA");
+
+    // Christmas and Boxing day 2019 fall on Wednesday and Thursday.
+    let sweden: BmaIndex<Sweden, Curve, Actual360> =
+        BmaIndex::new(Calendar::new(Sweden), Actual360, None);
+    assert!(!sweden.is_valid_fixing_date(Date::new(25, Month::December, 2019)));
+    assert!(sweden.is_valid_fixing_date(Date::new(27, Month::December, 2019)));
+    assert!(!sweden.is_valid_fixing_date(Date::new(30, Month::December, 2019)));
+}
+
+#[test]
+fn test_resets_are_effective_for_a_week() {
+    let curve = Rc::new(flat_curve(0.01));
+    let index = bma(&curve);
+    let fixing = Date::new(15, Month::January, 2020);
+    let start = index.value_date(fixing);
+    assert_eq!(start, Date::new(16, Month::January, 2020));
+    assert_eq!(
+        index.maturity_date(start),
+        Date::new(23, Month::January, 2020)
+    );
+
+    let dates = index.fixing_dates(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::February, 2020),
+    );
+    assert_eq!(dates[0], fixing);
+    assert_eq!(dates.len(), 5);
+    assert_eq!(dates[4], Date::new(12, Month::February, 2020));
+}
+
+#[test]
+fn test_averages_resets_by_days_in_effect() {
+    let curve = Rc::new(flat_curve(0.01));
+    let index = bma(&curve);
+    // in effect from the 16th, 23rd and 30th January.
+    index.add_fixing(Date::new(15, Month::January, 2020), 0.010);
+    index.add_fixing(Date::new(22, Month::January, 2020), 0.012);
+    index.add_fixing(Date::new(29, Month::January, 2020), 0.014);
+    let schedule = Schedule::new(
+        Date::new(20, Month::January, 2020),
+        Date::new(3, Month::February, 2020),
+        Period::new(2, TimeUnit::Weeks),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Following,
+        BusinessDayConvention::Following,
+        DateGenerator::Forward,
+        false,
+    );
+    let leg = AverageBmaLeg::new(schedule, Rc::new(index), Actual360)
+        .with_gearing(2.0)
+        .with_spread(0.001)
+        .build();
+    assert_eq!(leg.len(), 1);
+    let expected = (3.0 * 0.010 + 7.0 * 0.012 + 4.0 * 0.014) / 14.0;
+    assert!((leg[0].average_fixing() - expected).abs() < 1.0e-15);
+    assert!((leg[0].rate() - (2.0 * expected + 0.001)).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_forecast_average_matches_the_curve() {
+    let curve = Rc::new(flat_curve(0.01));
+    let leg = AverageBmaLeg::new(schedule(3), Rc::new(bma(&curve)), Actual360).build();
+    // simple weekly forwards of a 1% continuously compounded Act/365 curve.
+    let weekly = (0.01 * 7.0 / 365.0f64).exp_m1() * 360.0 / 7.0;
+    for coupon in &leg {
+        assert!((coupon.rate() - weekly).abs() < 2.0e-6);
+    }
+}
+
+#[test]
+fn test_fair_fraction_and_spread_reprice_to_zero() {
+    let curve = Rc::new(flat_curve(0.02));
+    let libor = Rc::new(IborIndex::new(
+        "USDLibor",
+        Period::new(3, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        false,
+        Actual360,
+        Some(Rc::clone(&curve)),
+    ));
+    let bma_leg = || AverageBmaLeg::new(schedule(3), Rc::new(bma(&curve)), Actual360).build();
+    let libor_leg = |fraction: f64, spread: f64| {
+        IborLeg::new(schedule(3), Rc::clone(&libor), Actual360)
+            .with_gearing(fraction)
+            .with_spread(spread)
+            .build()
+    };
+    let settlement = Date::new(15, Month::January, 2020);
+
+    let swap = BmaSwap::new(
+        SwapType::Payer,
+        libor_leg(0.67, 0.0005),
+        0.67,
+        0.0005,
+        bma_leg(),
+    );
+    let fraction = swap.fair_libor_fraction(curve.as_ref(), settlement);
+    let spread = swap.fair_libor_spread(curve.as_ref(), settlement);
+    // BMA forecast off the Libor curve needs a bit less than full Libor
+    // on top of the spread.
+    assert!(fraction > 0.9 && fraction < 1.0);
+
+    let at_fraction = BmaSwap::new(
+        SwapType::Payer,
+        libor_leg(fraction, 0.0005),
+        fraction,
+        0.0005,
+        bma_leg(),
+    );
+    assert!(at_fraction.npv(curve.as_ref(), settlement).abs() < 1.0e-12);
+    let at_spread = BmaSwap::new(
+        SwapType::Receiver,
+        libor_leg(0.67, spread),
+        0.67,
+        spread,
+        bma_leg(),
+    );
+    assert!(at_spread.npv(curve.as_ref(), settlement).abs() < 1.0e-12);
+}
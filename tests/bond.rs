@@ -0,0 +1,91 @@
+extern crate quantlib;
+
+use quantlib::instruments::{Duration, FixedRateBond};
+use quantlib::termstructures::Compounding;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Frequency,
+    Period, Schedule, Thirty360, TimeUnit, WeekendsOnly,
+};
+
+fn schedule(start: Date, years: i64) -> Schedule {
+    Schedule::new(
+        start,
+        start.add_months(12 * years),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn bond<DC: DayCounter>(start: Date, coupon: f64, day_counter: DC) -> FixedRateBond<DC> {
+    FixedRateBond::new(
+        schedule(start, 5),
+        coupon,
+        day_counter,
+        Frequency::Annual,
+        1.0e6,
+    )
+}
+
+#[test]
+fn test_par_bond_yields_its_coupon() {
+    let bond = bond(reference_date(), 0.05, Thirty360::default());
+    let settlement = reference_date();
+    let price = bond.clean_price_from_yield(0.05, Compounding::Compounded, settlement);
+    assert!((price - 100.0).abs() < 1.0e-10);
+    let y = bond.yield_to_maturity(100.0, Compounding::Compounded, settlement);
+    assert!((y - 0.05).abs() < 1.0e-10);
+    let y = bond.yield_to_maturity(95.0, Compounding::Compounded, settlement);
+    assert!(y > 0.05);
+    let repriced = bond.clean_price_from_yield(y, Compounding::Compounded, settlement);
+    assert!((repriced - 95.0).abs() < 1.0e-8);
+}
+
+#[test]
+fn test_curve_price_matches_flat_yield() {
+    let curve = flat_curve(0.04);
+    let bond = bond(reference_date().add_months(-3), 0.05, Actual365Fixed {});
+    let settlement = reference_date();
+    let dirty = bond.dirty_price(&curve, settlement);
+    let expected = bond.dirty_price_from_yield(0.04, Compounding::Continuous, settlement);
+    assert!((dirty - expected).abs() < 1.0e-10);
+    assert!((bond.npv(&curve, settlement) - dirty * 1.0e4).abs() < 1.0e-6);
+    let clean = bond.clean_price(&curve, settlement);
+    assert!((dirty - clean - bond.accrued_amount(settlement)).abs() < 1.0e-12);
+    let y = bond.yield_to_maturity(clean, Compounding::Continuous, settlement);
+    assert!((y - 0.04).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_accrued_amount() {
+    let start = reference_date();
+    let bond = bond(start, 0.06, Thirty360::default());
+    assert_eq!(bond.accrued_amount(start), 0.0);
+    assert!((bond.accrued_amount(start.add_months(3)) - 1.5).abs() < 1.0e-12);
+    assert!((bond.accrued_amount(start.add_months(12)) - 6.0).abs() < 1.0e-12);
+    assert!((bond.accrued_amount(start.add_months(15)) - 1.5).abs() < 1.0e-12);
+    // the coupon paid on settlement belongs to the seller
+    assert_eq!(bond.cashflows(start.add_months(12)).len(), 5);
+}
+
+#[test]
+fn test_duration_and_convexity() {
+    let bond = bond(reference_date(), 0.05, Thirty360::default());
+    let settlement = reference_date().add_months(5);
+    let comp = Compounding::Compounded;
+    let (y, h) = (0.045, 1.0e-5);
+    let price = |y| bond.dirty_price_from_yield(y, comp, settlement);
+    let p = price(y);
+    let modified = bond.duration(y, comp, Duration::Modified, settlement);
+    let slope = (price(y + h) - price(y - h)) / (2.0 * h);
+    assert!((modified + slope / p).abs() < 1.0e-6);
+    let macaulay = bond.duration(y, comp, Duration::Macaulay, settlement);
+    assert!((macaulay - modified * (1.0 + y)).abs() < 1.0e-12);
+    let convexity = bond.convexity(y, comp, settlement);
+    let curvature = (price(y + h) - 2.0 * p + price(y - h)) / (h * h);
+    assert!((convexity - curvature / p).abs() < 1.0e-3);
+}
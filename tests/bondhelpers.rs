@@ -0,0 +1,147 @@
+extern crate quantlib;
+
+use quantlib::math::Interpolation;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    bond_curve_helpers, BondHelper, DepositRateHelper, FixedRateBondHelper, PiecewiseYieldCurve,
+    RateHelper,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, ActualActual, BusinessDayConvention, Calendar, Date, DateGenerator,
+    Period, Schedule, TimeUnit, WeekendsOnly,
+};
+
+fn months(n: i64) -> Date {
+    reference_date().add_months(n)
+}
+
+/// Annual bond issued four months ago, quoted clean off `market`.
+fn bond(
+    years: i64,
+    coupon: f64,
+    market: &dyn Fn(Date) -> f64,
+) -> FixedRateBondHelper<SimpleQuote, ActualActual> {
+    let schedule = Schedule::new(
+        months(-4),
+        months(12 * years - 4),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    );
+    let helper = BondHelper::fixed_rate(
+        SimpleQuote::new(0.0),
+        schedule,
+        coupon,
+        ActualActual::default(),
+        1.0e6,
+        reference_date(),
+    );
+    let quote = SimpleQuote::new(helper.implied_quote_on(market));
+    BondHelper { quote, ..helper }
+}
+
+fn bootstrap(helpers: &[&dyn RateHelper]) -> PiecewiseYieldCurve<WeekendsOnly> {
+    PiecewiseYieldCurve::new(
+        reference_date(),
+        helpers,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::LogLinear,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_bond_curve_recovers_market() {
+    let market = flat_curve(0.03);
+    let discount = |d: Date| market.discount(d, true);
+    let bonds: Vec<_> = [2, 3, 5, 7]
+        .iter()
+        .map(|&y| bond(y, 0.04, &discount))
+        .collect();
+    // a third of the coupon has accrued, and the bonds trade above par
+    assert!((bonds[0].accrued_amount() - 4.0 / 3.0).abs() < 0.02);
+    assert!(bonds.iter().all(|b| b.quote() > 100.0));
+
+    let curve = bootstrap(&bond_curve_helpers(&bonds, false));
+    assert!(curve.errors.iter().all(|e| e.abs() < 1.0e-10));
+    let date = months(48);
+    assert!((curve.discount(date) - market.discount(date, true)).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_bonds_alongside_deposits() {
+    let market = |d: Date| {
+        let t = d.sub(reference_date()) as f64 / 365.0;
+        (-(0.01 * t + 0.002 * t * t)).exp()
+    };
+    let deposit = DepositRateHelper::new(
+        SimpleQuote::new(0.0),
+        reference_date(),
+        months(3),
+        Actual360,
+    );
+    let quote = SimpleQuote::new(deposit.implied_quote_on(&market));
+    let deposit = DepositRateHelper { quote, ..deposit };
+    let bonds: Vec<_> = [2, 5].iter().map(|&y| bond(y, 0.02, &market)).collect();
+
+    let mut helpers: Vec<&dyn RateHelper> = vec![&deposit];
+    helpers.extend(bond_curve_helpers(&bonds, false));
+    let curve = bootstrap(&helpers);
+    assert_eq!(curve.nodes().len(), 4);
+    assert!(curve.errors.iter().all(|e| e.abs() < 1.0e-10));
+    let date = deposit.pillar_date();
+    assert!((curve.discount(date) - market(date)).abs() < 1.0e-12);
+    // coupons between pillars see flat forwards rather than the market
+    let date = bonds[1].pillar_date();
+    assert!((curve.discount(date) - market(date)).abs() < 1.0e-3);
+}
+
+#[test]
+fn test_off_the_run_issues_excluded() {
+    let market = flat_curve(0.03);
+    let discount = |d: Date| market.discount(d, true);
+    let bonds = vec![
+        bond(2, 0.04, &discount),
+        // cheap old issue
+        BondHelper {
+            quote: SimpleQuote::new(95.0),
+            ..bond(3, 0.01, &discount)
+        }
+        .with_on_the_run(false),
+        bond(5, 0.04, &discount),
+    ];
+    assert_eq!(bond_curve_helpers(&bonds, false).len(), 2);
+    assert_eq!(bond_curve_helpers(&bonds, true).len(), 3);
+
+    let curve = bootstrap(&bond_curve_helpers(&bonds, false));
+    let date = months(36);
+    assert!((curve.discount(date) - market.discount(date, true)).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_weights_choose_between_issues() {
+    let market = flat_curve(0.03);
+    let discount = |d: Date| market.discount(d, true);
+    let on_curve = bond(3, 0.04, &discount);
+    let rich = BondHelper {
+        quote: SimpleQuote::new(on_curve.quote() + 1.0),
+        ..bond(3, 0.04, &discount)
+    };
+    let bonds = vec![
+        bond(2, 0.04, &discount),
+        rich.with_weight(0.5),
+        on_curve.with_weight(2.0),
+        bond(5, 0.04, &discount).with_weight(0.0),
+    ];
+    let selected = bond_curve_helpers(&bonds, false);
+    assert_eq!(selected.len(), 2);
+    let curve = bootstrap(&selected);
+    let date = months(32);
+    assert!((curve.discount(date) - market.discount(date, true)).abs() < 1.0e-10);
+}
@@ -0,0 +1,100 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{Base, FixedRateCoupon};
+use quantlib::termstructures::{fit_hazard_rates, BondCreditHelper, HazardRateCurve};
+use quantlib::testutils::market::{flat_curve, flat_rate, reference_date};
+use quantlib::time::{Actual365Fixed, Date};
+
+fn annual_coupons(coupon: f64, years: i64) -> Vec<FixedRateCoupon<Actual365Fixed>> {
+    let start = reference_date();
+    (0..years)
+        .map(|k| {
+            let (d1, d2) = (start.add_months(12 * k), start.add_months(12 * (k + 1)));
+            let base = Base {
+                nominal: 100.0,
+                day_counter: Actual365Fixed {},
+                payment_date: d2,
+                accrual_start_date: d1,
+                accrual_end_date: d2,
+                reference_period_start: d1,
+                reference_period_end: d2,
+            };
+            FixedRateCoupon::new(base, flat_rate(coupon))
+        })
+        .collect()
+}
+
+fn maturity(years: i64) -> Date {
+    reference_date().add_months(12 * years)
+}
+
+#[test]
+fn test_flat_hazard_rate_survival_and_spread() {
+    let curve = HazardRateCurve::new(
+        reference_date(),
+        vec![maturity(5)],
+        vec![0.02],
+        Actual365Fixed {},
+    );
+    let t = curve.time_from_reference(maturity(3));
+    assert!((curve.survival_probability(maturity(3)) - (-0.02 * t).exp()).abs() < 1.0e-14);
+    // flat after the last node
+    let t = curve.time_from_reference(maturity(8));
+    assert!((curve.survival_probability(maturity(8)) - (-0.02 * t).exp()).abs() < 1.0e-14);
+    assert!((curve.implied_cds_spread(maturity(4), 0.4) - 0.012).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_fitted_hazard_rates_reprice_bonds() {
+    let benchmark = flat_curve(0.02);
+    let recovery = 0.4;
+    let hazards = vec![0.01, 0.015, 0.025];
+    let true_curve = HazardRateCurve::new(
+        reference_date(),
+        vec![maturity(2), maturity(5), maturity(10)],
+        hazards.clone(),
+        Actual365Fixed {},
+    );
+    let bonds: Vec<_> = [(0.03, 5), (0.025, 2), (0.04, 10)]
+        .iter()
+        .map(|&(c, n)| {
+            let flows = annual_coupons(c, n);
+            let helper = BondCreditHelper::new(flows.clone(), 100.0, 0.0);
+            let price = helper.risky_price(&benchmark, &true_curve, recovery);
+            BondCreditHelper::new(flows, 100.0, price)
+        })
+        .collect();
+
+    let fit = fit_hazard_rates(
+        &bonds,
+        &benchmark,
+        recovery,
+        reference_date(),
+        Actual365Fixed {},
+    );
+    assert_eq!(fit.curve.dates, true_curve.dates);
+    for (fitted, expected) in fit.curve.hazard_rates.iter().zip(&hazards) {
+        assert!((fitted - expected).abs() < 1.0e-8);
+    }
+    assert!(fit.errors.iter().all(|e| e.abs() < 1.0e-8));
+}
+
+#[test]
+fn test_bonds_above_risk_free_value_report_errors() {
+    let benchmark = flat_curve(0.02);
+    let flows = annual_coupons(0.03, 3);
+    let riskless = BondCreditHelper::new(flows.clone(), 100.0, 0.0).risky_price(
+        &benchmark,
+        &HazardRateCurve::new(
+            reference_date(),
+            vec![maturity(3)],
+            vec![0.0],
+            Actual365Fixed {},
+        ),
+        0.4,
+    );
+    let bonds = vec![BondCreditHelper::new(flows, 100.0, riskless + 1.0)];
+    let fit = fit_hazard_rates(&bonds, &benchmark, 0.4, reference_date(), Actual365Fixed {});
+    assert_eq!(fit.curve.hazard_rates, vec![0.0]);
+    assert!((fit.errors[0] + 1.0).abs() < 1.0e-10);
+}
@@ -0,0 +1,176 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{CashFlow, CdiLeg, Coupon};
+use quantlib::indexes::{CdiIndex, Index, ZeroInflationIndex};
+use quantlib::instruments::{DiFuture, NtnB, DI_FUTURE_NOTIONAL};
+use quantlib::math::Interpolation;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    DiFutureRateHelper, PiecewiseYieldCurve, RateHelper, YieldTermStructure,
+};
+use quantlib::time::{
+    Brazil, Business252, BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Month,
+    Period, Schedule, TimeUnit,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<Brazil, SimpleQuote, Business252<Brazil>>;
+
+fn settlement() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+fn bus252() -> Business252<Brazil> {
+    Business252 {
+        calendar: Calendar::new(Brazil),
+    }
+}
+
+// flat 10% per year exponential business/252 CDI curve.
+fn cdi_curve() -> Rc<Curve> {
+    Rc::new(YieldTermStructure::new(
+        Calendar::new(Brazil),
+        settlement(),
+        bus252(),
+        0,
+        vec![],
+        vec![],
+        Box::new(|t| 1.1f64.powf(-t)),
+    ))
+}
+
+#[test]
+fn test_brazil_calendar_holidays() {
+    let brazil = Calendar::new(Brazil);
+    // Carnival, Tiradentes and Corpus Christi 2020.
+    assert!(!brazil.is_business_day(Date::new(24, Month::February, 2020)));
+    assert!(!brazil.is_business_day(Date::new(25, Month::February, 2020)));
+    assert!(brazil.is_business_day(Date::new(26, Month::February, 2020)));
+    assert!(!brazil.is_business_day(Date::new(21, Month::April, 2020)));
+    assert!(!brazil.is_business_day(Date::new(11, Month::June, 2020)));
+    // Black Consciousness Day became national in 2024.
+    assert!(brazil.is_business_day(Date::new(20, Month::November, 2023)));
+    assert!(!brazil.is_business_day(Date::new(20, Month::November, 2024)));
+}
+
+#[test]
+fn test_di_futures_price_off_business_days() {
+    let future = DiFuture::contract(Month::January, 2021, Calendar::new(Brazil));
+    // the 1st January is a holiday.
+    assert_eq!(future.maturity, Date::new(4, Month::January, 2021));
+    let days = future.business_days(settlement());
+    let price = future.unit_price(0.045, settlement());
+    let expected = DI_FUTURE_NOTIONAL / 1.045f64.powf(days as f64 / 252.0);
+    assert!((price - expected).abs() < 1.0e-9);
+    assert!((future.implied_rate(price, settlement()) - 0.045).abs() < 1.0e-14);
+
+    let curve = cdi_curve();
+    let helper = DiFutureRateHelper::new(SimpleQuote::new(0.1), future, settlement());
+    assert!((helper.implied_quote_on(&|d| curve.discount(d, true)) - 0.1).abs() < 1.0e-12);
+    assert_eq!(helper.pillar_date(), future.maturity);
+}
+
+#[test]
+fn test_di_futures_bootstrap_the_cdi_curve() {
+    let helpers: Vec<DiFutureRateHelper<SimpleQuote, Brazil>> = [0.045, 0.05, 0.055]
+        .iter()
+        .enumerate()
+        .map(|(k, rate)| {
+            let future = DiFuture::contract(Month::January, 2021 + k as i32, Calendar::new(Brazil));
+            DiFutureRateHelper::new(SimpleQuote::new(*rate), future, settlement())
+        })
+        .collect();
+    let refs: Vec<&dyn RateHelper> = helpers.iter().map(|h| h as &dyn RateHelper).collect();
+    let curve = PiecewiseYieldCurve::new(
+        settlement(),
+        &refs,
+        Calendar::new(Brazil),
+        bus252(),
+        Interpolation::LogLinear,
+    )
+    .unwrap();
+    assert!(curve.errors.iter().all(|e| e.abs() < 1.0e-10));
+    // the first contract pins down its own discount factor
+    let first = &helpers[0].future;
+    let t = first.business_days(settlement()) as f64 / 252.0;
+    assert!((curve.discount(first.maturity) - 1.045f64.powf(-t)).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_cdi_coupons_compound_daily() {
+    let curve = cdi_curve();
+    let index = CdiIndex::new(Calendar::new(Brazil), Some(Rc::clone(&curve)));
+    assert!((index.fixing(Date::new(20, Month::January, 2020)) - 0.1).abs() < 1.0e-12);
+    // a past fixing on the 15th.
+    index.add_fixing(settlement(), 0.0440);
+    let schedule = Schedule::new(
+        settlement(),
+        Date::new(15, Month::July, 2020),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(Brazil),
+        BusinessDayConvention::Following,
+        BusinessDayConvention::Following,
+        DateGenerator::Backward,
+        false,
+    );
+    let index = Rc::new(index);
+    let full = CdiLeg::new(schedule.clone(), Rc::clone(&index), bus252())
+        .with_notional(1.0e6)
+        .build();
+    let geared = CdiLeg::new(schedule, Rc::clone(&index), bus252())
+        .with_notional(1.0e6)
+        .with_gearing(1.1)
+        .build();
+
+    let days = bus252().day_count(settlement(), Date::new(15, Month::July, 2020));
+    let daily = |cdi: f64, gearing: f64| ((1.0 + cdi).powf(1.0 / 252.0) - 1.0) * gearing + 1.0;
+    let compound = |gearing: f64| daily(0.044, gearing) * daily(0.1, gearing).powi(days as i32 - 1);
+    assert!((full[0].amount() - 1.0e6 * (compound(1.0) - 1.0)).abs() < 1.0e-6);
+    assert!((geared[0].amount() - 1.0e6 * (compound(1.1) - 1.0)).abs() < 1.0e-6);
+    let rate = compound(1.0).powf(252.0 / days as f64) - 1.0;
+    assert!((full[0].rate() - rate).abs() < 1.0e-12);
+}
+
+fn ipca() -> ZeroInflationIndex {
+    let ipca = ZeroInflationIndex::ipca();
+    ipca.add_fixing(Date::new(1, Month::June, 2000), 1000.0);
+    ipca.add_fixing(Date::new(1, Month::December, 2019), 2000.0);
+    ipca.add_fixing(Date::new(1, Month::January, 2020), 2010.0);
+    ipca
+}
+
+#[test]
+fn test_ntnb_vna_accrues_ipca_pro_rata() {
+    let bond = NtnB::new(
+        Date::new(15, Month::January, 2010),
+        Date::new(15, Month::May, 2035),
+        Calendar::new(Brazil),
+    );
+    assert_eq!(bond.coupon_dates[0], Date::new(15, Month::May, 2010));
+    assert_eq!(bond.coupon_dates.len(), 51);
+    assert!((NtnB::<Brazil>::coupon() - 0.029563014).abs() < 1.0e-9);
+
+    let ipca = ipca();
+    assert!((bond.vna(settlement(), &ipca) - 2000.0).abs() < 1.0e-9);
+    let date = Date::new(3, Month::February, 2020);
+    let accrued = bus252().day_count(settlement(), date) as f64
+        / bus252().day_count(settlement(), Date::new(15, Month::February, 2020)) as f64;
+    let expected = 2000.0 * 1.005f64.powf(accrued);
+    assert!((bond.vna(date, &ipca) - expected).abs() < 1.0e-9);
+}
+
+#[test]
+fn test_ntnb_quotation_and_real_yield_round_trip() {
+    let bond = NtnB::new(
+        Date::new(15, Month::January, 2010),
+        Date::new(15, Month::May, 2035),
+        Calendar::new(Brazil),
+    );
+    let quotation = bond.quotation(0.045, settlement());
+    // near par plus the premium of a 6% coupon over a 4.5% yield.
+    assert!(quotation > 1.1 && quotation < 1.25);
+    assert!((bond.real_yield(quotation, settlement()) - 0.045).abs() < 1.0e-10);
+    let price = bond.price(0.045, settlement(), &ipca());
+    assert!((price - 2000.0 * quotation).abs() < 1.0e-9);
+}
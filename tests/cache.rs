@@ -0,0 +1,123 @@
+extern crate quantlib;
+
+use quantlib::definitions::Money;
+use quantlib::instruments::{CacheMetrics, CachedInstrument, Instrument};
+use quantlib::pricingengines::{Arguments, BaseResults, PricingEngine, Results};
+use quantlib::quotes::{MarketVersion, Quote, SimpleQuote};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::Date;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct NoArguments;
+impl Arguments for NoArguments {
+    fn validate(&self) {}
+}
+
+struct NoEngine;
+impl PricingEngine for NoEngine {
+    type R = BaseResults;
+    type A = NoArguments;
+    fn get_results(&self) -> BaseResults {
+        unimplemented!()
+    }
+    fn get_arguments(&self) -> NoArguments {
+        NoArguments
+    }
+    fn reset(&self) {}
+    fn update(&self) {}
+    fn calculate(&self) {}
+}
+
+// An instrument worth `notional * spot`, counting its pricings.
+struct Forward {
+    notional: f64,
+    spot: SimpleQuote,
+    pricings: Arc<AtomicUsize>,
+    results: HashMap<String, Money>,
+}
+
+impl Instrument for Forward {
+    type E = NoEngine;
+    fn npv(&mut self) -> Money {
+        self.pricings.fetch_add(1, Ordering::SeqCst);
+        Money {
+            value: self.notional * self.spot.value(),
+            currency: None,
+        }
+    }
+    fn error_estimate(&mut self) -> Money {
+        Money::default()
+    }
+    fn valuation_date(&mut self) -> Date {
+        reference_date()
+    }
+    fn result(&mut self, _tag: String) -> Result<Money, &str> {
+        Err("not found")
+    }
+    fn additional_results(&self) -> &HashMap<String, Money> {
+        &self.results
+    }
+    fn is_expired(&self) -> bool {
+        false
+    }
+    fn set_pricing_engine(&mut self, _engine: NoEngine) {}
+    fn setup_arguments<A: Arguments>(&self, _args: A) {}
+    fn fetch_results<R: Results>(&mut self, _results: R) {}
+    fn calculate(&mut self) {}
+    fn setup_expired(&mut self) {}
+    fn perform_calculations(&mut self) {}
+}
+
+#[test]
+fn test_cache_keyed_by_market_and_trade_version() {
+    let market = MarketVersion::new();
+    let pricings = Arc::new(AtomicUsize::new(0));
+    let metrics = CacheMetrics::new();
+    let forward = Forward {
+        notional: 10.0,
+        spot: SimpleQuote::new(100.0).with_version(market.clone()),
+        pricings: pricings.clone(),
+        results: HashMap::new(),
+    };
+    let mut cached = CachedInstrument::new(forward, market.clone()).with_metrics(metrics.clone());
+
+    assert_eq!(cached.npv().value, 1000.0);
+    assert_eq!(cached.npv().value, 1000.0);
+    assert_eq!(pricings.load(Ordering::SeqCst), 1);
+
+    // a quote change bumps the market version.
+    cached.modify(|f| f.notional = 20.0);
+    assert_eq!(cached.npv().value, 2000.0);
+    cached.modify(|f| {
+        f.spot.set_value(101.0);
+    });
+    assert!(market.current() > 0);
+    assert_eq!(cached.npv().value, 2020.0);
+    assert_eq!(cached.npv().value, 2020.0);
+
+    assert_eq!(pricings.load(Ordering::SeqCst), 3);
+    assert_eq!(metrics.hits(), 2);
+    assert_eq!(metrics.misses(), 3);
+    assert!((metrics.hit_rate() - 0.4).abs() < 1e-12);
+}
+
+#[test]
+fn test_market_bump_invalidates() {
+    let market = MarketVersion::new();
+    let pricings = Arc::new(AtomicUsize::new(0));
+    let forward = Forward {
+        notional: 1.0,
+        spot: SimpleQuote::new(1.0),
+        pricings: pricings.clone(),
+        results: HashMap::new(),
+    };
+    let mut cached = CachedInstrument::new(forward, market.clone());
+    cached.npv();
+    assert!(cached.is_cached());
+    market.bump();
+    assert!(!cached.is_cached());
+    cached.npv();
+    assert_eq!(pricings.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,106 @@
+extern crate quantlib;
+
+use quantlib::time::{
+    BusinessDayConvention, Calendar, Date, Month, Sweden, TimeUnit, WeekendsOnly,
+};
+
+#[test]
+fn test_adjust_with_convention() {
+    let calendar = Calendar::new(WeekendsOnly);
+    // Saturday 31st October 2020.
+    let d = Date::new(31, Month::October, 2020);
+    let adjust = |c| calendar.adjust_with_convention(d, c);
+    assert_eq!(
+        adjust(BusinessDayConvention::Following),
+        Date::new(2, Month::November, 2020)
+    );
+    assert_eq!(
+        adjust(BusinessDayConvention::ModifiedFollowing),
+        Date::new(30, Month::October, 2020)
+    );
+    assert_eq!(
+        adjust(BusinessDayConvention::Preceding),
+        Date::new(30, Month::October, 2020)
+    );
+    assert_eq!(adjust(BusinessDayConvention::Unadjusted), d);
+    // Sunday 1st November 2020.
+    assert_eq!(
+        calendar.adjust_with_convention(
+            Date::new(1, Month::November, 2020),
+            BusinessDayConvention::ModifiedPreceding
+        ),
+        Date::new(2, Month::November, 2020)
+    );
+}
+
+#[test]
+fn test_end_of_month() {
+    let calendar = Calendar::new(Sweden);
+    // Good Friday and Easter Monday 2013 around the last weekend of March.
+    let d = Date::new(28, Month::March, 2013);
+    assert!(calendar.is_end_of_month(d));
+    assert!(!Date::is_end_of_month(d));
+    assert_eq!(calendar.end_of_month(Date::new(1, Month::March, 2013)), d);
+    assert_eq!(
+        Date::end_of_month(Date::new(3, Month::February, 2020)),
+        Date::new(29, Month::February, 2020)
+    );
+}
+
+#[test]
+fn test_advance() {
+    let calendar = Calendar::new(WeekendsOnly);
+    let d = Date::new(31, Month::January, 2020);
+    assert_eq!(
+        d.advance(1, TimeUnit::Months),
+        Date::new(29, Month::February, 2020)
+    );
+    assert_eq!(
+        d.advance(-13, TimeUnit::Months),
+        Date::new(31, Month::December, 2018)
+    );
+    // Friday plus two business days.
+    assert_eq!(
+        calendar.advance_by_units(d, 2, TimeUnit::Days),
+        Date::new(4, Month::February, 2020)
+    );
+    assert_eq!(
+        calendar.advance_by_units(d, -1, TimeUnit::Days),
+        Date::new(30, Month::January, 2020)
+    );
+    // month-end rolls to month-end.
+    let eom = Date::new(28, Month::February, 2019);
+    assert_eq!(
+        calendar.advance(
+            eom,
+            1,
+            TimeUnit::Months,
+            BusinessDayConvention::ModifiedFollowing,
+            true
+        ),
+        Date::new(29, Month::March, 2019)
+    );
+    assert_eq!(
+        calendar.advance(
+            eom,
+            1,
+            TimeUnit::Months,
+            BusinessDayConvention::ModifiedFollowing,
+            false
+        ),
+        Date::new(28, Month::March, 2019)
+    );
+}
+
+#[test]
+fn test_business_days_between() {
+    let calendar = Calendar::new(WeekendsOnly);
+    let from = Date::new(3, Month::February, 2020);
+    let to = Date::new(17, Month::February, 2020);
+    assert_eq!(calendar.business_days_between(from, to), 10);
+    assert_eq!(calendar.business_days_between(to, from), -10);
+    assert_eq!(
+        calendar.business_days_between_include(from, to, true, true),
+        11
+    );
+}
@@ -0,0 +1,133 @@
+extern crate quantlib;
+
+use quantlib::cashflows::analysis::{self, Duration};
+use quantlib::cashflows::{CashFlow, FixedRateLeg, SimpleCashFlow};
+use quantlib::termstructures::{Compounding, InterestRate};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Frequency,
+    Period, Schedule, TimeUnit, WeekendsOnly,
+};
+
+fn schedule() -> Schedule {
+    Schedule::new(
+        reference_date().add_months(-3),
+        reference_date().add_months(57),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+/// Five year 3% semiannual coupons and the redemption, as one leg.
+fn leg() -> Vec<Box<dyn CashFlow>> {
+    let mut leg: Vec<Box<dyn CashFlow>> = FixedRateLeg::new(schedule(), Actual365Fixed)
+        .with_notional(100.0)
+        .with_rate(0.03)
+        .build()
+        .into_iter()
+        .map(|c| Box::new(c) as Box<dyn CashFlow>)
+        .collect();
+    let maturity: Date = *schedule().dates.last().unwrap();
+    leg.push(Box::new(SimpleCashFlow::new(100.0, maturity)));
+    leg
+}
+
+fn continuous(rate: f64) -> InterestRate<Actual365Fixed> {
+    InterestRate::new(
+        rate,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+    )
+}
+
+#[test]
+fn test_flat_yield_matches_flat_curve() {
+    let leg = leg();
+    let settlement = reference_date();
+    let curve = flat_curve(0.04);
+    let npv = analysis::npv(&leg, &curve, false, settlement);
+    let at_yield = analysis::npv_at_yield(&leg, &continuous(0.04), false, settlement);
+    assert!((npv - at_yield).abs() < 1.0e-10);
+    let bps = analysis::bps(&leg, &curve, false, settlement);
+    let bps_at_yield = analysis::bps_at_yield(&leg, &continuous(0.04), false, settlement);
+    assert!((bps - bps_at_yield).abs() < 1.0e-12);
+    let irr = analysis::yield_rate(
+        &leg,
+        npv,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        false,
+        settlement,
+        1.0e-12,
+    );
+    assert!((irr - 0.04).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_atm_rate_and_accrued_amount() {
+    let leg = leg();
+    let settlement = reference_date();
+    let curve = flat_curve(0.04);
+    let atm = analysis::atm_rate(&leg, &curve, false, settlement, None);
+    assert!((atm - 0.03).abs() < 1.0e-12);
+    let bps = analysis::bps(&leg, &curve, false, settlement);
+    let atm = analysis::atm_rate(&leg, &curve, false, settlement, Some(bps * 50.0));
+    assert!((atm - 0.005).abs() < 1.0e-12);
+    // three months into the running coupon period
+    let start = reference_date().add_months(-3);
+    let expected = 100.0 * 0.03 * settlement.sub(start) as f64 / 365.0;
+    assert!((analysis::accrued_amount(&leg, settlement) - expected).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_duration_and_convexity() {
+    let leg = leg();
+    let settlement = reference_date();
+    let (r, h) = (0.035, 1.0e-5);
+    let y = InterestRate::new(
+        r,
+        Actual365Fixed,
+        Compounding::Compounded,
+        Frequency::Semiannual,
+    );
+    let npv = |r: f64| {
+        let y = InterestRate::new(
+            r,
+            Actual365Fixed,
+            Compounding::Compounded,
+            Frequency::Semiannual,
+        );
+        analysis::npv_at_yield(&leg, &y, false, settlement)
+    };
+    let p = npv(r);
+    let modified = analysis::duration(&leg, &y, Duration::Modified, false, settlement);
+    assert!((modified + (npv(r + h) - npv(r - h)) / (2.0 * h) / p).abs() < 1.0e-6);
+    let macaulay = analysis::duration(&leg, &y, Duration::Macaulay, false, settlement);
+    assert!((macaulay - modified * (1.0 + r / 2.0)).abs() < 1.0e-12);
+    let convexity = analysis::convexity(&leg, &y, false, settlement);
+    let curvature = (npv(r + h) - 2.0 * p + npv(r - h)) / (h * h) / p;
+    assert!((convexity - curvature).abs() < 1.0e-3);
+}
+
+#[test]
+fn test_continuous_durations_coincide() {
+    let leg = leg();
+    let settlement = reference_date();
+    let y = continuous(0.04);
+    let macaulay = analysis::duration(&leg, &y, Duration::Macaulay, false, settlement);
+    let modified = analysis::duration(&leg, &y, Duration::Modified, false, settlement);
+    assert!((macaulay - modified).abs() < 1.0e-12);
+    // a lone redemption lasts until it is paid
+    let zero = vec![SimpleCashFlow::new(100.0, reference_date().add_months(24))];
+    let t = Actual365Fixed.year_fraction(settlement, zero[0].date, None, None);
+    assert!(
+        (analysis::duration(&zero, &y, Duration::Macaulay, false, settlement) - t).abs() < 1.0e-12
+    );
+    assert!((analysis::convexity(&zero, &y, false, settlement) - t * t).abs() < 1.0e-12);
+}
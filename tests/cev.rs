@@ -0,0 +1,114 @@
+extern crate quantlib;
+
+use quantlib::instruments::OptionType;
+use quantlib::math::{
+    chi_squared_cdf, incomplete_gamma_p, ln_gamma, non_central_chi_squared_cdf, normal_cdf,
+};
+use quantlib::models::CevModel;
+use quantlib::pricingengines::{bachelier_formula, black_formula};
+use quantlib::testutils::Rng;
+
+#[test]
+fn test_gamma_and_chi_squared_distributions() {
+    assert!((ln_gamma(5.0) - 24.0_f64.ln()).abs() < 1.0e-13);
+    assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1.0e-13);
+    for &x in &[0.1_f64, 1.0, 3.0, 20.0] {
+        assert!((incomplete_gamma_p(1.0, x) - (1.0 - (-x).exp())).abs() < 1.0e-14);
+        assert!((chi_squared_cdf(x, 2.0) - (1.0 - (-0.5 * x).exp())).abs() < 1.0e-14);
+        assert!(
+            (non_central_chi_squared_cdf(x, 3.0, 0.0) - chi_squared_cdf(x, 3.0)).abs() < 1.0e-15
+        );
+        // one degree of freedom is the square of a shifted normal
+        for &lambda in &[0.5_f64, 4.0, 60.0] {
+            let (s, m) = (x.sqrt(), lambda.sqrt());
+            let expected = normal_cdf(s - m) - normal_cdf(-s - m);
+            assert!((non_central_chi_squared_cdf(x, 1.0, lambda) - expected).abs() < 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn test_cev_limits_and_parity() {
+    let (forward, t, discount) = (100.0, 1.5, 0.96);
+    for &strike in &[80.0, 100.0, 125.0] {
+        // beta = 0 far from zero is normal
+        let normal = CevModel::new(12.0, 0.0);
+        for &o in &[OptionType::Call, OptionType::Put] {
+            let cev = normal.vanilla_price(o, strike, forward, t, discount);
+            let expected = bachelier_formula(o, strike, forward, 12.0 * t.sqrt(), discount);
+            assert!((cev - expected).abs() < 1.0e-8, "{} {}", cev, expected);
+        }
+        // beta close to one approaches Black
+        let beta = 0.999;
+        let lognormal = CevModel::new(0.25 * forward.powf(1.0 - beta), beta);
+        let cev = lognormal.vanilla_price(OptionType::Call, strike, forward, t, discount);
+        let expected = black_formula(
+            OptionType::Call,
+            strike,
+            forward,
+            0.25 * t.sqrt(),
+            discount,
+            0.0,
+        );
+        assert!(
+            (cev / expected - 1.0).abs() < 2.0e-3,
+            "{} {}",
+            cev,
+            expected
+        );
+
+        let model = CevModel::new(2.0, 0.4);
+        let call = model.vanilla_price(OptionType::Call, strike, forward, t, discount);
+        let put = model.vanilla_price(OptionType::Put, strike, forward, t, discount);
+        assert!((call - put - discount * (forward - strike)).abs() < 1.0e-9);
+    }
+    // a lower beta skews implied volatility towards low strikes
+    let model = CevModel::new(0.3 * 100.0_f64.powf(0.5), 0.5);
+    let ratio = |k: f64| {
+        model.vanilla_price(OptionType::Put, k, forward, t, 1.0)
+            / black_formula(OptionType::Put, k, forward, 0.3 * t.sqrt(), 1.0, 0.0)
+    };
+    assert!(ratio(70.0) > ratio(100.0));
+}
+
+#[test]
+fn test_cev_monte_carlo() {
+    let model = CevModel::new(1.2, 0.3);
+    let (forward, t, strike) = (4.0, 2.0, 4.0);
+    let steps = 100;
+    let times: Vec<f64> = (1..=steps).map(|i| t * i as f64 / steps as f64).collect();
+    let mut rng = Rng::new(7);
+    let paths = 20_000;
+    let (mut sum, mut sum2, mut absorbed) = (0.0, 0.0, 0);
+    for _ in 0..paths {
+        let normals: Vec<f64> = (0..steps).map(|_| rng.normal()).collect();
+        let f = *model.simulate(forward, &times, &normals).last().unwrap();
+        let payoff = (f - strike).max(0.0);
+        sum += payoff;
+        sum2 += payoff * payoff;
+        if f == 0.0 {
+            absorbed += 1;
+        }
+    }
+    let n = paths as f64;
+    let mean = sum / n;
+    let error = ((sum2 / n - mean * mean) / n).sqrt();
+    let analytic = model.vanilla_price(OptionType::Call, strike, forward, t, 1.0);
+    assert!(
+        (mean - analytic).abs() < 4.0 * error,
+        "{} {} {}",
+        mean,
+        analytic,
+        error
+    );
+
+    let p = model.absorption_probability(forward, t);
+    let p_mc = absorbed as f64 / n;
+    assert!(p > 0.01);
+    assert!(
+        (p_mc - p).abs() < 4.0 * (p * (1.0 - p) / n).sqrt(),
+        "{} {}",
+        p_mc,
+        p
+    );
+}
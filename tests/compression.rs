@@ -0,0 +1,178 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateLeg, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{SwapType, VanillaSwap};
+use quantlib::risk::{Compression, PositionRisk, SwapPosition};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+const SHIFT: f64 = 1.0e-4;
+
+fn schedule(start: Date, maturity: Date, months: i64) -> Schedule {
+    Schedule::new(
+        start,
+        maturity,
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn npv(p: &SwapPosition, rate: f64) -> f64 {
+    let index = Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(rate))),
+    ));
+    let swap_type = if p.notional > 0.0 {
+        SwapType::Payer
+    } else {
+        SwapType::Receiver
+    };
+    VanillaSwap::new(
+        swap_type,
+        FixedRateLeg::new(schedule(p.start_date, p.maturity_date, 12), Actual365Fixed)
+            .with_notional(p.notional.abs())
+            .with_rate(p.fixed_rate)
+            .build(),
+        p.fixed_rate,
+        IborLeg::new(schedule(p.start_date, p.maturity_date, 6), index, Actual360)
+            .with_notional(p.notional.abs())
+            .build(),
+        0.0,
+    )
+    .npv(&flat_curve(rate), reference_date())
+}
+
+/// Value on a flat 2% curve and the change for a 1bp parallel shift.
+fn pricer(p: &SwapPosition) -> PositionRisk {
+    PositionRisk {
+        npv: npv(p, 0.02),
+        delta: (npv(p, 0.02 + SHIFT) - npv(p, 0.02 - SHIFT)) / 2.0,
+    }
+}
+
+fn years(n: i64) -> Date {
+    reference_date().add_months(12 * n)
+}
+
+fn position(id: &str, index: &str, maturity: i64, notional: f64, rate: f64) -> SwapPosition {
+    SwapPosition::new(id, index, reference_date(), years(maturity), notional, rate)
+}
+
+fn portfolio_risk(positions: &[SwapPosition]) -> PositionRisk {
+    positions.iter().map(pricer).fold(
+        PositionRisk {
+            npv: 0.0,
+            delta: 0.0,
+        },
+        |a, r| PositionRisk {
+            npv: a.npv + r.npv,
+            delta: a.delta + r.delta,
+        },
+    )
+}
+
+#[test]
+fn test_offsetting_swaps_are_replaced_by_their_net() {
+    let positions = vec![
+        position("A", "EUR-6M", 5, 100.0e6, 0.021),
+        position("B", "EUR-6M", 5, -60.0e6, 0.019),
+        position("C", "USD-3M", 5, -40.0e6, 0.020),
+    ];
+    let report = Compression::new(1.0).compress(&positions, pricer);
+    assert_eq!(report.proposals.len(), 1);
+    let proposal = &report.proposals[0];
+    assert_eq!(proposal.terminated, vec!["A", "B"]);
+    let replacement = proposal.replacement.as_ref().unwrap();
+    assert_eq!(replacement.notional, 40.0e6);
+    assert_eq!(replacement.index, "EUR-6M");
+    // the net rate is the notional weighted rate of the torn up trades
+    assert!((replacement.fixed_rate - 0.024).abs() < 1.0e-10);
+    let before = portfolio_risk(&positions[..2]);
+    let after = pricer(replacement);
+    assert!((after.npv + proposal.cash - before.npv).abs() < 1.0e-6);
+    assert!(proposal.cash.abs() < 1.0e-6);
+    assert!((after.delta - before.delta).abs() < 1.0e-6);
+    assert_eq!(report.gross_notional_before, 200.0e6);
+    assert_eq!(report.gross_notional_after, 80.0e6);
+    assert!((report.reduction_ratio() - 0.6).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_exact_offsets_settle_in_cash() {
+    let positions = vec![
+        position("A", "EUR-6M", 3, 50.0e6, 0.025),
+        position("B", "EUR-6M", 3, -50.0e6, 0.020),
+    ];
+    // the floating legs cancel, a fixed annuity of 0.5% is torn up
+    let risk = portfolio_risk(&positions);
+    assert!(Compression::new(1.0)
+        .compress(&positions, pricer)
+        .proposals
+        .is_empty());
+    let report = Compression::new(1.0e3).compress(&positions, pricer);
+    let proposal = &report.proposals[0];
+    assert!(proposal.replacement.is_none());
+    assert!((proposal.cash - risk.npv).abs() < 1.0e-9);
+    assert!(proposal.cash < 0.0);
+    assert!((proposal.delta_change + risk.delta).abs() < 1.0e-9);
+    assert_eq!(report.gross_notional_after, 0.0);
+    assert_eq!(report.notional_reduction(), 100.0e6);
+}
+
+#[test]
+fn test_maturity_window_and_delta_tolerance() {
+    let positions = vec![
+        SwapPosition::new("A", "EUR-6M", reference_date(), years(5), 100.0e6, 0.02),
+        SwapPosition::new(
+            "B",
+            "EUR-6M",
+            reference_date(),
+            years(5).add_months(1),
+            -90.0e6,
+            0.02,
+        ),
+    ];
+    // a month apart, not netted without a window
+    let strict = Compression::new(1.0e6).compress(&positions, pricer);
+    assert!(strict.proposals.is_empty());
+    assert_eq!(strict.notional_reduction(), 0.0);
+    let windowed = Compression::new(1.0e6)
+        .with_maturity_window(31)
+        .compress(&positions, pricer);
+    let proposal = &windowed.proposals[0];
+    assert!(proposal.delta_change.abs() > 1.0);
+    // the month of risk left over is too much under a tight tolerance
+    let tight = Compression::new(proposal.delta_change.abs() / 2.0)
+        .with_maturity_window(31)
+        .compress(&positions, pricer);
+    assert!(tight.proposals.is_empty());
+}
+
+#[test]
+fn test_same_side_and_disjoint_positions_are_kept() {
+    let positions = vec![
+        position("A", "EUR-6M", 5, 100.0e6, 0.021),
+        position("B", "EUR-6M", 5, 60.0e6, 0.019),
+        SwapPosition::new("C", "EUR-6M", years(5), years(10), -60.0e6, 0.02),
+    ];
+    let report = Compression::new(1.0)
+        .with_maturity_window(366 * 5)
+        .compress(&positions, pricer);
+    assert!(report.proposals.is_empty());
+    assert_eq!(report.gross_notional_after, report.gross_notional_before);
+}
@@ -0,0 +1,48 @@
+extern crate quantlib;
+
+use quantlib::testutils::conformance::{load_corpora, Corpus};
+use std::path::Path;
+
+#[test]
+fn test_quantlib_corpora() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/quantlib");
+    let corpora = load_corpora(&dir).unwrap();
+    assert!(!corpora.is_empty());
+    let mismatches: Vec<String> = corpora
+        .iter()
+        .flat_map(|c| c.check())
+        .map(|m| m.to_string())
+        .collect();
+    assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn test_mismatches_are_reported_by_line() {
+    let corpus = Corpus::parse(
+        "inline",
+        "# comment\ndaycount Actual360 2020-01-01 2020-02-01 31\n\ndaycount Actual360 2020-01-01 2020-02-01 30\n",
+    )
+    .unwrap();
+    assert_eq!(corpus.records.len(), 2);
+    let mismatches = corpus.check();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].line, 4);
+}
+
+#[test]
+fn test_malformed_corpora_are_rejected() {
+    for text in &[
+        "daycount Actual360 2020-01-01 31",
+        "daycount Actual360 2020-02-30 2020-03-01 1",
+        "yearfraction Unknown 2020-01-01 2020-02-01 0.1",
+        "schedule WeekendsOnly 2020-01-01 2021-01-01 3X Following Forward eom 2020-01-01",
+        "tolerance abc",
+    ] {
+        let error = Corpus::parse("bad", text);
+        assert!(
+            error.is_err() || !error.unwrap().check().is_empty(),
+            "{}",
+            text
+        );
+    }
+}
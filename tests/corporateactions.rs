@@ -0,0 +1,94 @@
+extern crate quantlib;
+
+use quantlib::indexes::{AdjustmentTarget, CorporateActionKind, CorporateActions};
+use quantlib::instruments::{OptionType, VanillaOption};
+use quantlib::time::{Date, Month};
+use std::collections::BTreeMap;
+
+fn day(d: u32) -> Date {
+    Date::new(d, Month::March, 2021)
+}
+
+fn history() -> BTreeMap<Date, f64> {
+    vec![
+        (day(1), 100.0),
+        (day(2), 104.0),
+        (day(3), 51.0),
+        (day(4), 52.0),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_split_adjusts_fixing_history() {
+    let mut actions =
+        CorporateActions::new().with_action(day(3), CorporateActionKind::Split { ratio: 2.0 });
+    let adjusted = actions.adjust_fixings(&history());
+    assert_eq!(adjusted[&day(1)], 50.0);
+    assert_eq!(adjusted[&day(2)], 52.0);
+    assert_eq!(adjusted[&day(3)], 51.0);
+    assert_eq!(adjusted[&day(4)], 52.0);
+    let log = actions.log();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].target, AdjustmentTarget::FixingHistory);
+    assert_eq!((log[0].before, log[0].after), (104.0, 52.0));
+}
+
+#[test]
+fn test_special_dividend_and_spin_off_factors() {
+    let mut actions = CorporateActions::new()
+        .with_action(day(4), CorporateActionKind::SpinOff { retained: 0.8 })
+        .with_action(
+            day(2),
+            CorporateActionKind::SpecialDividend { amount: 10.0 },
+        );
+    assert_eq!(actions.actions[0].ex_date, day(2));
+    let adjusted = actions.adjust_fixings(&history());
+    // cum price 100, 10 paid out
+    assert!((adjusted[&day(1)] - 100.0 * 0.9 * 0.8).abs() < 1.0e-12);
+    assert!((adjusted[&day(2)] - 104.0 * 0.8).abs() < 1.0e-12);
+    assert!((adjusted[&day(3)] - 51.0 * 0.8).abs() < 1.0e-12);
+    assert_eq!(adjusted[&day(4)], 52.0);
+    assert_eq!(actions.log().len(), 2);
+}
+
+#[test]
+fn test_option_terms_adjustment() {
+    let mut actions = CorporateActions::new()
+        .with_action(day(1), CorporateActionKind::SpecialDividend { amount: 5.0 })
+        .with_action(day(2), CorporateActionKind::Split { ratio: 4.0 })
+        .with_action(day(3), CorporateActionKind::SpecialDividend { amount: 1.0 })
+        .with_action(day(10), CorporateActionKind::SpinOff { retained: 0.5 });
+    let option = VanillaOption::new(OptionType::Call, 100.0, day(5));
+    let (adjusted, multiplier) = actions.adjust_option(&option, day(1));
+    // the first dividend went ex before the trade, the spin-off after expiry
+    assert_eq!(adjusted.strike, 24.0);
+    assert_eq!(multiplier, 4.0);
+    assert_eq!(adjusted.exercise_date, option.exercise_date);
+    let strikes: Vec<(f64, f64)> = actions.log().iter().map(|e| (e.before, e.after)).collect();
+    assert_eq!(strikes, vec![(100.0, 25.0), (25.0, 24.0)]);
+    assert!(actions
+        .log()
+        .iter()
+        .all(|e| e.target == AdjustmentTarget::Strike));
+    actions.clear_log();
+    assert!(actions.log().is_empty());
+}
+
+#[test]
+fn test_forward_adjustment() {
+    let mut actions = CorporateActions::new()
+        .with_action(day(2), CorporateActionKind::SpecialDividend { amount: 2.0 })
+        .with_action(day(3), CorporateActionKind::Split { ratio: 2.0 });
+    let growth = |d: Date| 1.0 + 0.001 * day(10).sub(d) as f64;
+    let forward = actions.adjust_forward(100.0, day(1), day(10), growth);
+    assert!((forward - (100.0 - 2.0 * 1.008) / 2.0).abs() < 1.0e-12);
+    let forward = actions.adjust_forward(100.0, day(2), day(10), growth);
+    assert_eq!(forward, 50.0);
+    assert_eq!(actions.log().len(), 3);
+    assert!(actions
+        .log()
+        .iter()
+        .all(|e| e.target == AdjustmentTarget::Forward));
+}
@@ -0,0 +1,104 @@
+extern crate quantlib;
+
+use quantlib::math::{
+    correlation_to_covariance, covariance_to_correlation, nearest_correlation_matrix,
+    rank_reduced_correlation, rank_reduced_sqrt, Matrix, SymmetricEigen,
+};
+
+fn forward_correlation(n: usize, beta: f64) -> Matrix {
+    let mut m = Matrix::new(n, n, 0.0);
+    for i in 0..n {
+        for j in 0..n {
+            m[(i, j)] = (-beta * (i as f64 - j as f64).abs()).exp();
+        }
+    }
+    m
+}
+
+fn max_difference(a: &Matrix, b: &Matrix) -> f64 {
+    let mut d: f64 = 0.0;
+    for i in 0..a.rows() {
+        for j in 0..a.columns() {
+            d = d.max((a[(i, j)] - b[(i, j)]).abs());
+        }
+    }
+    d
+}
+
+#[test]
+fn test_eigen_decomposition_rebuilds_the_matrix() {
+    let m = Matrix::from_rows(&[
+        vec![4.0, 1.0, 0.5],
+        vec![1.0, 3.0, -0.2],
+        vec![0.5, -0.2, 1.0],
+    ]);
+    let eigen = SymmetricEigen::new(&m);
+    assert!(eigen.values.windows(2).all(|v| v[0] >= v[1]));
+    let trace: f64 = eigen.values.iter().sum();
+    assert!((trace - 8.0).abs() < 1.0e-12);
+    let mut rebuilt = Matrix::new(3, 3, 0.0);
+    for k in 0..3 {
+        for i in 0..3 {
+            for j in 0..3 {
+                rebuilt[(i, j)] += eigen.values[k] * eigen.vectors[(i, k)] * eigen.vectors[(j, k)];
+            }
+        }
+    }
+    assert!(max_difference(&rebuilt, &m) < 1.0e-12);
+    let vtv = eigen.vectors.transpose().mul(&eigen.vectors);
+    assert!(max_difference(&vtv, &Matrix::identity(3)) < 1.0e-12);
+}
+
+#[test]
+fn test_covariance_and_correlation_round_trip() {
+    let correlation = forward_correlation(4, 0.2);
+    let stdevs = vec![0.1, 0.2, 0.15, 0.3];
+    let covariance = correlation_to_covariance(&stdevs, &correlation);
+    assert!((covariance[(1, 3)] - 0.2 * 0.3 * (-0.4_f64).exp()).abs() < 1.0e-15);
+    let (s, c) = covariance_to_correlation(&covariance);
+    for (x, y) in s.iter().zip(&stdevs) {
+        assert!((x - y).abs() < 1.0e-15);
+    }
+    assert!(max_difference(&c, &correlation) < 1.0e-14);
+}
+
+#[test]
+fn test_nearest_correlation_repairs_estimated_matrices() {
+    // inconsistent pairwise estimates: not positive semi-definite.
+    let m = Matrix::from_rows(&[
+        vec![1.0, 0.9, 0.7],
+        vec![0.9, 1.0, -0.4],
+        vec![0.7, -0.4, 1.0],
+    ]);
+    assert!(SymmetricEigen::new(&m).values[2] < 0.0);
+    let repaired = nearest_correlation_matrix(&m, 1.0e-12, 1000);
+    for i in 0..3 {
+        assert!((repaired[(i, i)] - 1.0).abs() < 1.0e-12);
+    }
+    assert!(SymmetricEigen::new(&repaired).values[2] > -1.0e-8);
+    assert!(max_difference(&repaired, &m) < 0.3);
+
+    // a valid correlation matrix is left alone.
+    let valid = forward_correlation(5, 0.1);
+    assert!(max_difference(&nearest_correlation_matrix(&valid, 1.0e-12, 100), &valid) < 1.0e-10);
+}
+
+#[test]
+fn test_rank_reduction_keeps_unit_diagonal() {
+    let correlation = forward_correlation(10, 0.05);
+    let b = rank_reduced_sqrt(&correlation, 3);
+    assert_eq!((b.rows(), b.columns()), (10, 3));
+    let reduced = rank_reduced_correlation(&correlation, 3);
+    for i in 0..10 {
+        assert!((reduced[(i, i)] - 1.0).abs() < 1.0e-12);
+    }
+    // more factors fit better, and three already explain most of it.
+    let error = |rank| max_difference(&rank_reduced_correlation(&correlation, rank), &correlation);
+    assert!(error(3) < 0.1);
+    assert!(error(5) < error(3) && error(3) < error(2));
+    let eigen = SymmetricEigen::new(&reduced);
+    assert!(eigen.values[3].abs() < 1.0e-10);
+
+    // full rank gives back the matrix.
+    assert!(max_difference(&rank_reduced_correlation(&correlation, 10), &correlation) < 1.0e-10);
+}
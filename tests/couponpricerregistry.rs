@@ -0,0 +1,152 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{
+    set_coupon_pricers, CmsCouponPricer, CmsFixing, CmsLeg, Coupon, CouponPricerRegistry,
+    IborCouponPricer, IborFixing, IborLeg,
+};
+use quantlib::definitions::Rate;
+use quantlib::indexes::{IborIndex, SwapIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, DateGenerator, Period, Schedule,
+    TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+/// Pays the forward shifted by a fixed amount, whatever the coupon.
+struct Shift(f64);
+
+impl IborCouponPricer for Shift {
+    fn adjusted_fixing(&self, fixing: &IborFixing) -> Rate {
+        fixing.forward + self.0
+    }
+}
+
+impl CmsCouponPricer for Shift {
+    fn adjusted_fixing(&self, fixing: &CmsFixing) -> Rate {
+        fixing.forward + self.0
+    }
+}
+
+fn index() -> Rc<Index> {
+    Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.02))),
+    ))
+}
+
+fn schedule(months: i64) -> Schedule {
+    let start = reference_date().add_months(6);
+    Schedule::new(
+        start,
+        start.add_months(36),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn ibor_leg() -> IborLeg<Index, Actual360> {
+    IborLeg::new(schedule(6), index(), Actual360)
+}
+
+fn cms_leg() -> CmsLeg<SwapIndex<WeekendsOnly, Curve, Actual365Fixed, Actual360>, Actual360> {
+    let swap_index = SwapIndex::new(
+        "EuriborSwapIsdaFixA",
+        Period::new(5, TimeUnit::Years),
+        2,
+        Calendar::new(WeekendsOnly),
+        Period::new(1, TimeUnit::Years),
+        BusinessDayConvention::ModifiedFollowing,
+        Actual365Fixed {},
+        index(),
+    );
+    CmsLeg::new(schedule(12), Rc::new(swap_index), Actual360)
+}
+
+#[test]
+fn test_registry_sets_pricers_on_a_leg() {
+    let mut leg = ibor_leg().build();
+    assert!(leg.iter().all(|c| c.pricer.is_none()));
+    let registry = CouponPricerRegistry::new().with_ibor_pricer(Rc::new(Shift(0.01)));
+    set_coupon_pricers(&mut leg, &registry);
+    for coupon in &leg {
+        assert!((coupon.rate() - (coupon.index_fixing() + 0.01)).abs() < 1.0e-15);
+    }
+    // a later registry replaces them
+    set_coupon_pricers(
+        &mut leg,
+        &CouponPricerRegistry::new().with_ibor_pricer(Rc::new(Shift(0.0))),
+    );
+    assert!(leg.iter().all(|c| c.rate() == c.index_fixing()));
+}
+
+#[test]
+fn test_pricers_are_set_by_coupon_type() {
+    let registry = CouponPricerRegistry::new().with_cms_pricer(Rc::new(Shift(0.005)));
+    let mut cms = cms_leg().build();
+    let mut ibor = ibor_leg().with_pricer(Rc::new(Shift(0.01))).build();
+    set_coupon_pricers(&mut cms, &registry);
+    set_coupon_pricers(&mut ibor, &registry);
+    for coupon in &cms {
+        assert!((coupon.rate() - (coupon.index_fixing() + 0.005)).abs() < 1.0e-15);
+    }
+    // no Ibor pricer registered, so the leg's own stays
+    for coupon in &ibor {
+        assert!((coupon.rate() - (coupon.index_fixing() + 0.01)).abs() < 1.0e-15);
+    }
+}
+
+#[test]
+fn test_leg_overrides_take_precedence() {
+    let book = CouponPricerRegistry::new()
+        .with_ibor_pricer(Rc::new(Shift(0.01)))
+        .with_cms_pricer(Rc::new(Shift(0.02)));
+    let overrides = CouponPricerRegistry::new().with_ibor_pricer(Rc::new(Shift(0.03)));
+    let merged = book.overridden_by(&overrides);
+    let mut ibor = ibor_leg().build();
+    let mut cms = cms_leg().build();
+    set_coupon_pricers(&mut ibor, &merged);
+    set_coupon_pricers(&mut cms, &merged);
+    assert!((ibor[0].rate() - (ibor[0].index_fixing() + 0.03)).abs() < 1.0e-15);
+    assert!((cms[0].rate() - (cms[0].index_fixing() + 0.02)).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_leg_builders_take_registry_pricers() {
+    let registry = CouponPricerRegistry::new()
+        .with_ibor_pricer(Rc::new(Shift(0.01)))
+        .with_cms_pricer(Rc::new(Shift(0.02)));
+    let defaulted = ibor_leg().with_pricers(&registry).build();
+    assert!((defaulted[1].rate() - (defaulted[1].index_fixing() + 0.01)).abs() < 1.0e-15);
+    // the leg's own pricer wins whichever is given first
+    let before = ibor_leg()
+        .with_pricer(Rc::new(Shift(0.05)))
+        .with_pricers(&registry)
+        .build();
+    let after = ibor_leg()
+        .with_pricers(&registry)
+        .with_pricer(Rc::new(Shift(0.05)))
+        .build();
+    for coupon in before.iter().chain(&after) {
+        assert!((coupon.rate() - (coupon.index_fixing() + 0.05)).abs() < 1.0e-15);
+    }
+    let cms = cms_leg().with_pricers(&registry).build();
+    assert!(cms
+        .iter()
+        .all(|c| (c.rate() - (c.index_fixing() + 0.02)).abs() < 1.0e-15));
+}
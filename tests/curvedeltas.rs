@@ -0,0 +1,158 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateLeg, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{SwapType, VanillaSwap};
+use quantlib::quotes::SimpleQuote;
+use quantlib::risk::{curve_deltas, frtb_deltas, total_delta, CurveBuckets, RiskClass};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    KeyRateShift, KeyRateShiftedCurve, YieldTermStructure, ZeroSpreadedTermStructure,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+const PILLARS: [f64; 4] = [0.5, 1.0, 2.0, 5.0];
+const SHIFT: f64 = 1.0e-4;
+
+fn schedule(months: i64) -> Schedule {
+    Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2022),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+/// Payer swap on the discounting curve at 2% and a 6M projection curve at
+/// 2.5%, each shifted by its key rate shift.
+fn swap_npv(shifts: &[KeyRateShift], fixed_only: bool) -> f64 {
+    let discount = KeyRateShiftedCurve::new(flat_curve(0.02), shifts[0].clone());
+    let projection = KeyRateShiftedCurve::new(flat_curve(0.025), shifts[1].clone());
+    let index = Rc::new(IborIndex::new(
+        "Term6M",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(projection)),
+    ));
+    let swap = VanillaSwap::new(
+        SwapType::Payer,
+        FixedRateLeg::new(schedule(12), Actual365Fixed)
+            .with_notional(1.0e6)
+            .with_rate(0.024)
+            .build(),
+        0.024,
+        IborLeg::new(schedule(6), index, Actual360)
+            .with_notional(1.0e6)
+            .build(),
+        0.0,
+    );
+    if fixed_only {
+        -swap.fixed_leg_npv(&discount, reference_date())
+    } else {
+        swap.npv(&discount, reference_date())
+    }
+}
+
+fn buckets() -> Vec<CurveBuckets> {
+    vec![
+        CurveBuckets::new("USD-SOFR", &PILLARS),
+        CurveBuckets::new("USD-TERM-SOFR-6M", &PILLARS),
+    ]
+}
+
+#[test]
+fn test_key_rate_shifts_add_up_to_parallel() {
+    for &t in &[0.1, 0.5, 0.75, 1.6, 2.0, 3.3, 7.0] {
+        let total: f64 = (0..PILLARS.len())
+            .map(|i| KeyRateShift::new(&PILLARS, i, SHIFT).zero_shift(t))
+            .sum();
+        assert!((total - SHIFT).abs() < 1.0e-18);
+    }
+    let shift = KeyRateShift::new(&PILLARS, 2, SHIFT);
+    assert_eq!(shift.zero_shift(1.0), 0.0);
+    assert!((shift.zero_shift(1.5) - SHIFT / 2.0).abs() < 1.0e-18);
+    assert_eq!(shift.zero_shift(5.0), 0.0);
+    assert_eq!(KeyRateShift::none().zero_shift(1.0), 0.0);
+    let shifted = KeyRateShiftedCurve::new(flat_curve(0.02), shift);
+    let expected = flat_curve(0.02).discount_with_time(2.0, true) * (-SHIFT * 2.0).exp();
+    assert!((shifted.discount_with_time(2.0, true) - expected).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_swap_deltas_are_reported_per_curve() {
+    let deltas = curve_deltas(&buckets(), SHIFT, |s| swap_npv(s, false));
+    assert_eq!(deltas.len(), 8);
+    assert_eq!(deltas[0].curve, "USD-SOFR");
+    assert_eq!(deltas[4].curve, "USD-TERM-SOFR-6M");
+    // receiving the floating leg gains when projected rates rise
+    let projection = total_delta(&deltas, "USD-TERM-SOFR-6M");
+    assert!(projection > 0.0);
+    // the parallel shift of both curves is the sum of the key rate deltas
+    let parallel = |s: f64| {
+        let discount = ZeroSpreadedTermStructure::new(flat_curve(0.02), s);
+        let projection: Curve = flat_curve(0.025 + s);
+        let index = Rc::new(IborIndex::new(
+            "Term6M",
+            Period::new(6, TimeUnit::Months),
+            2,
+            Calendar::new(WeekendsOnly),
+            BusinessDayConvention::ModifiedFollowing,
+            true,
+            Actual360,
+            Some(Rc::new(projection)),
+        ));
+        VanillaSwap::new(
+            SwapType::Payer,
+            FixedRateLeg::new(schedule(12), Actual365Fixed)
+                .with_notional(1.0e6)
+                .with_rate(0.024)
+                .build(),
+            0.024,
+            IborLeg::new(schedule(6), index, Actual360)
+                .with_notional(1.0e6)
+                .build(),
+            0.0,
+        )
+        .npv(&discount, reference_date())
+    };
+    let expected = (parallel(SHIFT) - parallel(-SHIFT)) / 2.0;
+    let total = total_delta(&deltas, "USD-SOFR") + projection;
+    assert!((total - expected).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_fixed_leg_has_no_projection_risk() {
+    let deltas = curve_deltas(&buckets(), SHIFT, |s| swap_npv(s, true));
+    assert!(deltas[4..].iter().all(|d| d.delta == 0.0));
+    // paying fixed gains when discount rates rise
+    assert!(total_delta(&deltas, "USD-SOFR") > 0.0);
+}
+
+#[test]
+fn test_frtb_delta_sensitivities() {
+    let deltas = curve_deltas(&buckets(), SHIFT, |s| swap_npv(s, false));
+    let sensitivities = frtb_deltas(&deltas, "USD", SHIFT);
+    assert_eq!(sensitivities.len(), deltas.len());
+    for (s, d) in sensitivities.iter().zip(&deltas) {
+        assert_eq!(s.risk_class, RiskClass::InterestRate);
+        assert_eq!(s.bucket, "USD");
+        assert_eq!(s.risk_factor, d.curve);
+        assert_eq!(s.tenor, Some(d.pillar));
+        assert!((s.value - d.delta / SHIFT).abs() < 1.0e-9);
+    }
+}
@@ -1,10 +1,18 @@
+#![allow(deprecated)]
 extern crate chrono;
 extern crate quantlib;
 
-use crate::quantlib::DayCounter;
-use quantlib::time::{Actual360, Date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, ActualActual, Business252, Calendar, Convention360,
+    ConventionActual, Date, DayCounter, Month, Thirty360, WeekendsOnly,
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1.0e-12
+}
 
 #[test]
+#[cfg(feature = "clock")]
 fn test_actual_360() {
     let start = Date {
         d: chrono::Utc::today(),
@@ -18,3 +26,99 @@ fn test_actual_360() {
 
     assert_eq!(dc.day_count(start, end), 4);
 }
+
+#[test]
+fn test_actual_actual_variants() {
+    let (d1, d2) = (
+        Date::new(1, Month::November, 2003),
+        Date::new(1, Month::May, 2004),
+    );
+    let fraction = |c: ConventionActual, r1, r2| ActualActual::new(c).year_fraction(d1, d2, r1, r2);
+    assert!(close(
+        fraction(ConventionActual::ISDA, None, None),
+        61.0 / 365.0 + 121.0 / 366.0
+    ));
+    assert!(close(
+        fraction(ConventionActual::ISMA, Some(d1), Some(d2)),
+        0.5
+    ));
+    assert!(close(
+        fraction(ConventionActual::AFB, None, None),
+        182.0 / 366.0
+    ));
+    assert!(close(
+        Actual360.year_fraction(d1, d2, None, None),
+        182.0 / 360.0
+    ));
+    assert!(close(
+        Actual365Fixed.year_fraction(d1, d2, None, None),
+        182.0 / 365.0
+    ));
+}
+
+#[test]
+fn test_actual_actual_isma_stub_uses_reference_period() {
+    let dc = ActualActual::new(ConventionActual::ISMA);
+    // short first coupon of an annual bond
+    let (d1, d2) = (
+        Date::new(1, Month::February, 1999),
+        Date::new(1, Month::July, 1999),
+    );
+    let reference = Date::new(1, Month::July, 1998);
+    assert!(close(
+        dc.year_fraction(d1, d2, Some(reference), Some(d2)),
+        150.0 / 365.0
+    ));
+    // long first coupon
+    let d1 = Date::new(15, Month::August, 2002);
+    let (ref_start, d2) = (
+        Date::new(15, Month::January, 2003),
+        Date::new(15, Month::July, 2003),
+    );
+    assert!(close(
+        dc.year_fraction(d1, d2, Some(ref_start), Some(d2)),
+        0.5 * 153.0 / 184.0 + 0.5
+    ));
+}
+
+#[test]
+fn test_thirty_360_variants() {
+    let end_of_february = Date::new(28, Month::February, 2007);
+    let end_of_march = Date::new(31, Month::March, 2007);
+    let days = |c: Convention360, d1, d2| Thirty360::new(c).day_count(d1, d2);
+    assert_eq!(days(Convention360::USA, end_of_february, end_of_march), 30);
+    assert_eq!(
+        days(Convention360::BondBasis, end_of_february, end_of_march),
+        33
+    );
+    assert_eq!(
+        days(Convention360::European, end_of_february, end_of_march),
+        32
+    );
+    assert_eq!(
+        days(Convention360::Italian, end_of_february, end_of_march),
+        30
+    );
+
+    // the 28th of February is not the end of the month in a leap year
+    let next = Date::new(28, Month::February, 2008);
+    assert_eq!(days(Convention360::USA, end_of_february, next), 358);
+    assert_eq!(days(Convention360::BondBasis, end_of_february, next), 360);
+    let end_of_next = Date::new(29, Month::February, 2008);
+    assert_eq!(days(Convention360::USA, end_of_february, end_of_next), 360);
+    assert!(close(
+        Thirty360::default().year_fraction(end_of_february, next, None, None),
+        1.0
+    ));
+}
+
+#[test]
+fn test_business_252() {
+    let dc = Business252::new(Calendar::new(WeekendsOnly));
+    let (d1, d2) = (
+        Date::new(6, Month::January, 2020),
+        Date::new(20, Month::January, 2020),
+    );
+    assert_eq!(dc.day_count(d1, d2), 10);
+    assert!(close(dc.year_fraction(d1, d2, None, None), 10.0 / 252.0));
+}
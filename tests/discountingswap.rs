@@ -0,0 +1,144 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateCoupon, FixedRateLeg, IborCoupon, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{SwapType, VanillaSwap};
+use quantlib::pricingengines::DiscountingSwapEngine;
+use quantlib::quotes::SimpleQuote;
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index = IborIndex<WeekendsOnly, Curve, Actual360>;
+type Swap = VanillaSwap<FixedRateCoupon<Actual365Fixed>, IborCoupon<Index, Actual360>>;
+
+fn schedule(months: i64) -> Schedule {
+    Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2025),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+/// Five year payer swap against 6M fixings projected at `forwarding`.
+fn swap(fixed_rate: f64, spread: f64, forwarding: f64) -> Swap {
+    let index = Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(forwarding))),
+    ));
+    VanillaSwap::new(
+        SwapType::Payer,
+        FixedRateLeg::new(schedule(12), Actual365Fixed)
+            .with_notional(1.0e6)
+            .with_rate(fixed_rate)
+            .build(),
+        fixed_rate,
+        IborLeg::new(schedule(6), index, Actual360)
+            .with_notional(1.0e6)
+            .with_spread(spread)
+            .build(),
+        spread,
+    )
+}
+
+fn engine(rate: f64) -> DiscountingSwapEngine<Curve> {
+    DiscountingSwapEngine::new(Rc::new(flat_curve(rate)))
+}
+
+#[test]
+fn test_engine_matches_swap_analytics() {
+    let swap = swap(0.025, 0.0, 0.02);
+    let curve = flat_curve(0.02);
+    let results = engine(0.02)
+        .with_settlement_date(reference_date())
+        .with_npv_date(reference_date())
+        .calculate(&swap);
+    assert!((results.npv - swap.npv(&curve, reference_date())).abs() < 1.0e-8);
+    assert!((results.fixed_leg_bps - swap.fixed_leg_bps(&curve, reference_date())).abs() < 1.0e-9);
+    assert!((results.fair_rate - swap.fair_rate(&curve, reference_date())).abs() < 1.0e-12);
+    assert!((results.fair_spread - swap.fair_spread(&curve, reference_date())).abs() < 1.0e-12);
+    assert!(results.npv < 0.0);
+    assert!(results.fixed_leg_bps > 0.0 && results.floating_leg_bps > 0.0);
+}
+
+#[test]
+fn test_fair_rate_and_spread_reprice_to_zero() {
+    let engine = engine(0.015).with_settlement_date(reference_date());
+    let results = engine.calculate(&swap(0.025, 0.0, 0.02));
+    let at_par = engine.calculate(&swap(results.fair_rate, 0.0, 0.02));
+    assert!(at_par.npv.abs() < 1.0e-6);
+    let at_par = engine.calculate(&swap(0.025, results.fair_spread, 0.02));
+    assert!(at_par.npv.abs() < 1.0e-6);
+    // projecting above the discounting curve raises the fair rate
+    let single_curve = engine.calculate(&swap(0.025, 0.0, 0.015));
+    assert!(results.fair_rate > single_curve.fair_rate);
+}
+
+#[test]
+fn test_npv_date() {
+    let swap = swap(0.025, 0.0, 0.02);
+    let spot = Date::new(17, Month::January, 2020);
+    let at_reference = engine(0.02)
+        .with_settlement_date(reference_date())
+        .with_npv_date(reference_date())
+        .calculate(&swap);
+    let at_spot = engine(0.02)
+        .with_settlement_date(reference_date())
+        .with_npv_date(spot)
+        .calculate(&swap);
+    let discount = flat_curve(0.02).discount(spot, true);
+    assert!((at_spot.npv - at_reference.npv / discount).abs() < 1.0e-8);
+    assert!((at_spot.fair_rate - at_reference.fair_rate).abs() < 1.0e-15);
+    assert_eq!(at_spot.npv_date, spot);
+}
+
+#[test]
+fn test_settlement_defaults_to_evaluation_date() {
+    let swap = swap(0.025, 0.0, 0.02);
+    let coupon_date = Date::new(17, Month::January, 2022);
+    let explicit = engine(0.02)
+        .with_settlement_date(coupon_date)
+        .with_npv_date(coupon_date)
+        .calculate(&swap);
+    let context = PricingContext::new(coupon_date);
+    let implicit = context.scope(|| engine(0.02).calculate(&swap));
+    assert_eq!(explicit, implicit);
+    // the flows paid on the settlement date only count when asked for
+    let included = engine(0.02)
+        .with_settlement_date(coupon_date)
+        .with_npv_date(coupon_date)
+        .with_settlement_date_flows(true)
+        .calculate(&swap);
+    assert!(included.fixed_leg_npv > explicit.fixed_leg_npv);
+    let today = context.with_todays_cashflows(true);
+    assert_eq!(included, today.scope(|| engine(0.02).calculate(&swap)));
+}
+
+#[test]
+fn test_fair_rate_and_spread_of_a_paid_swap_are_nan() {
+    let results = engine(0.02)
+        .with_settlement_date(Date::new(1, Month::February, 2025))
+        .with_npv_date(reference_date())
+        .calculate(&swap(0.025, 0.0, 0.02));
+    assert_eq!(results.npv, 0.0);
+    assert_eq!(results.fixed_leg_bps, 0.0);
+    assert!(results.fair_rate.is_nan() && results.fair_spread.is_nan());
+}
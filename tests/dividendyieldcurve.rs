@@ -0,0 +1,62 @@
+extern crate quantlib;
+
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::DividendYieldCurve;
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual365Fixed, Calendar, Date, WeekendsOnly};
+
+fn months(n: i64) -> Date {
+    reference_date().add_months(n)
+}
+
+#[test]
+fn test_piecewise_constant_yields() {
+    let curve = DividendYieldCurve::piecewise_constant(
+        reference_date(),
+        vec![months(6), months(12)],
+        vec![0.03, 0.01],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    );
+    let (t1, t2) = (
+        curve.time_from_reference(months(6)),
+        curve.time_from_reference(months(12)),
+    );
+    assert_eq!(curve.instantaneous_yield(0.1), 0.03);
+    assert_eq!(curve.instantaneous_yield(0.9), 0.01);
+    // the last yield extends past the last date
+    assert_eq!(curve.instantaneous_yield(5.0), 0.01);
+    let expected = (-(0.03 * t1 + 0.01 * (t2 - t1))).exp();
+    assert!((curve.discount(months(12)) - expected).abs() < 1.0e-15);
+    let t = 3.0;
+    assert!((curve.zero_yield(t) - (0.03 * t1 + 0.01 * (t - t1)) / t).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_functional_yield_is_integrated() {
+    // q(t) = 0.02 + 0.01 t integrates to 0.02 t + 0.005 t^2
+    let curve = DividendYieldCurve::functional(
+        reference_date(),
+        |t| 0.02 + 0.01 * t,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    );
+    for &t in &[0.0f64, 0.5, 2.0, 10.0] {
+        let expected = (-(0.02 * t + 0.005 * t * t)).exp();
+        assert!((curve.discount_with_time(t) - expected).abs() < 1.0e-14);
+    }
+    assert_eq!(curve.zero_yield(0.0), 0.02);
+}
+
+#[test]
+fn test_dividend_term_structure() {
+    let curve = DividendYieldCurve::functional(
+        reference_date(),
+        |t| 0.015 * (-t).exp(),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    );
+    let ts = curve.term_structure();
+    let date = months(30);
+    assert!((ts.discount(date, false) - curve.discount(date)).abs() < 1.0e-15);
+}
@@ -0,0 +1,83 @@
+extern crate quantlib;
+
+use quantlib::definitions::{Time, Volatility};
+use quantlib::indexes::{Constituent, EquityIndex};
+use quantlib::termstructures::traits::BlackVolTermStructure;
+use quantlib::termstructures::ProxyIndexVolatility;
+use std::rc::Rc;
+
+/// Volatility with a linear skew around `atm` in strike.
+struct Skew {
+    level: Volatility,
+    atm: f64,
+    slope: f64,
+}
+
+impl BlackVolTermStructure for Skew {
+    fn black_vol(&self, _t: Time, strike: f64) -> Volatility {
+        self.level + self.slope * (strike / self.atm - 1.0)
+    }
+}
+
+fn index() -> EquityIndex {
+    EquityIndex::new(
+        "Basket",
+        1000.0,
+        vec![
+            Constituent::new("A", 3.0, 50.0),
+            Constituent::new("B", 1.0, 200.0),
+        ],
+    )
+}
+
+fn surfaces() -> Vec<Rc<Skew>> {
+    vec![
+        Rc::new(Skew {
+            level: 0.2,
+            atm: 50.0,
+            slope: -0.1,
+        }),
+        Rc::new(Skew {
+            level: 0.4,
+            atm: 200.0,
+            slope: -0.2,
+        }),
+    ]
+}
+
+#[test]
+fn test_index_weights_and_level() {
+    let index = index();
+    assert_eq!(index.weights(), vec![0.75, 0.25]);
+    assert_eq!(index.level_at(&[50.0, 200.0]), 1000.0);
+    // a tenth up on the heavier constituent
+    assert!((index.level_at(&[55.0, 200.0]) - 1075.0).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_proxy_vol_bounds() {
+    let perfect = ProxyIndexVolatility::with_constant_correlation(index(), surfaces(), 1.0);
+    assert!((perfect.black_vol(1.0, 1000.0) - (0.75 * 0.2 + 0.25 * 0.4)).abs() < 1.0e-12);
+    let independent = ProxyIndexVolatility::with_constant_correlation(index(), surfaces(), 0.0);
+    let expected = (0.75f64.powi(2) * 0.04 + 0.25f64.powi(2) * 0.16).sqrt();
+    assert!((independent.black_vol(1.0, 1000.0) - expected).abs() < 1.0e-12);
+    let partial = ProxyIndexVolatility::with_constant_correlation(index(), surfaces(), 0.5);
+    let vol = partial.black_vol(1.0, 1000.0);
+    assert!(expected < vol && vol < 0.25);
+}
+
+#[test]
+fn test_constituents_are_read_at_the_index_moneyness() {
+    let proxy = ProxyIndexVolatility::with_constant_correlation(index(), surfaces(), 1.0);
+    // 90% strike on the index is 45 and 180 on the constituents
+    let vols = proxy.constituent_vols(1.0, 900.0);
+    assert!((vols[0] - 0.21).abs() < 1.0e-12 && (vols[1] - 0.42).abs() < 1.0e-12);
+    assert!(proxy.black_vol(1.0, 900.0) > proxy.black_vol(1.0, 1000.0));
+    assert!((proxy.black_variance(2.0, 900.0) - 2.0 * 0.2625f64.powi(2)).abs() < 1.0e-12);
+}
+
+#[test]
+#[should_panic(expected = "correlation must be symmetric")]
+fn test_invalid_correlation() {
+    ProxyIndexVolatility::with_constant_correlation(index(), surfaces(), 1.5);
+}
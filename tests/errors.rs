@@ -0,0 +1,160 @@
+extern crate quantlib;
+
+use quantlib::errors::QuantLibError;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    Compounding, FlatForward, YieldTermStructure, ZeroSpreadedTermStructure,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Actual365Fixed, Calendar, Date, Frequency, Month, WeekendsOnly};
+
+fn curve(jumps: Vec<SimpleQuote>) -> YieldTermStructure<WeekendsOnly, SimpleQuote> {
+    let jump_dates = (0..jumps.len())
+        .map(|n| Date::new(31, Month::December, 2020 + n as i32))
+        .collect();
+    YieldTermStructure::new(
+        Calendar::new(WeekendsOnly),
+        reference_date(),
+        Actual365Fixed,
+        0,
+        jumps,
+        jump_dates,
+        Box::new(|t| (-0.02 * t).exp()),
+    )
+}
+
+#[test]
+fn test_checked_discount_agrees_with_discount() {
+    let curve = curve(vec![SimpleQuote::new(0.999)]);
+    let date = Date::new(15, Month::June, 2022);
+    assert_eq!(
+        curve.checked_discount(date, false),
+        Ok(curve.discount(date, false))
+    );
+    assert_eq!(curve.checked_discount_with_time(0.0, false), Ok(1.0));
+}
+
+#[test]
+fn test_range_violations_are_errors() {
+    let curve = curve(vec![]);
+    assert_eq!(
+        curve.checked_discount_with_time(-0.5, true),
+        Err(QuantLibError::NegativeTime { time: -0.5 })
+    );
+    let mut flat = flat_curve(0.02);
+    let rate =
+        flat.checked_zero_rate_with_time(-1.0, Compounding::Continuous, Frequency::Annual, true);
+    assert!(matches!(rate, Err(QuantLibError::NegativeTime { .. })));
+}
+
+#[test]
+fn test_invalid_jumps_are_errors() {
+    let date = Date::new(15, Month::June, 2022);
+    let jump = SimpleQuote::new(0.999);
+    jump.reset();
+    let invalid = curve(vec![jump]);
+    let error = invalid.checked_discount(date, false).unwrap_err();
+    assert!(matches!(error, QuantLibError::InvalidQuote { .. }));
+    assert_eq!(error.to_string(), "invalid jump at 2020-12-31 quote");
+
+    let negative = curve(vec![SimpleQuote::new(-0.5)]);
+    assert!(matches!(
+        negative.checked_discount(date, false),
+        Err(QuantLibError::InvalidValue { value, .. }) if value == -0.5
+    ));
+    // before the jump the curve is still usable
+    assert!(negative.checked_discount_with_time(0.5, false).is_ok());
+}
+
+#[test]
+fn test_wrapped_and_flat_curves_report_errors() {
+    let spreaded = ZeroSpreadedTermStructure::new(curve(vec![]), 0.01);
+    assert_eq!(
+        spreaded.checked_discount_with_time(-0.5, true),
+        Err(QuantLibError::NegativeTime { time: -0.5 })
+    );
+    assert!(spreaded.checked_discount_with_time(1.0, true).is_ok());
+
+    let forward = SimpleQuote::new(0.02);
+    forward.reset();
+    let flat = FlatForward::new(
+        reference_date(),
+        forward,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        Calendar::new(WeekendsOnly),
+    );
+    assert!(matches!(
+        flat.checked_discount_with_time(1.0, true),
+        Err(QuantLibError::InvalidQuote { .. })
+    ));
+}
+
+#[test]
+fn test_zero_and_forward_rates_report_errors() {
+    let (d1, d2) = (
+        Date::new(15, Month::June, 2020),
+        Date::new(15, Month::June, 2022),
+    );
+    let mut curve = curve(vec![SimpleQuote::new(0.999)]);
+    let dc = Actual365Fixed;
+    let (comp, freq) = (Compounding::Continuous, Frequency::Annual);
+    assert_eq!(
+        curve
+            .checked_forward_rate(d1, d2, dc, comp, freq, false)
+            .map(|r| r.rate),
+        Ok(curve.forward_rate(d1, d2, dc, comp, freq, false).rate)
+    );
+    assert_eq!(
+        curve
+            .checked_zero_rate(d2, dc, comp, freq, false)
+            .map(|r| r.rate),
+        Ok(curve.zero_rate(d2, dc, comp, freq, false).rate)
+    );
+    assert!(matches!(
+        curve.checked_forward_rate(d2, d1, dc, comp, freq, false),
+        Err(QuantLibError::InvertedPeriod { start, end }) if start > end
+    ));
+    assert_eq!(
+        curve
+            .checked_forward_rate_with_time(2.0, 1.0, dc, comp, freq, false)
+            .err(),
+        Some(QuantLibError::InvertedPeriod {
+            start: 2.0,
+            end: 1.0
+        })
+    );
+    let before = reference_date().add_days(-10);
+    assert!(matches!(
+        curve.checked_zero_rate(before, dc, comp, freq, false),
+        Err(QuantLibError::NegativeTime { .. })
+    ));
+
+    let jump = SimpleQuote::new(0.999);
+    jump.reset();
+    let mut invalid = self::curve(vec![jump]);
+    assert!(matches!(
+        invalid.checked_forward_rate(d1, d2, dc, comp, freq, false),
+        Err(QuantLibError::InvalidQuote { .. })
+    ));
+    // the instantaneous forward before the jump is still available
+    assert!(invalid
+        .checked_forward_rate_with_time(0.1, 0.1, dc, comp, freq, false)
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "period ends at time 1 before it starts at 2")]
+fn test_inverted_forward_periods_panic() {
+    let mut curve = curve(vec![]);
+    curve.forward_rate_with_time(
+        2.0,
+        1.0,
+        Actual365Fixed,
+        Compounding::Simple,
+        Frequency::Annual,
+        true,
+    );
+}
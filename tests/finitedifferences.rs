@@ -0,0 +1,154 @@
+extern crate quantlib;
+
+use quantlib::instruments::{EarlyExerciseOption, Exercise, OptionType, VanillaOption};
+use quantlib::methods::finitedifferences::{
+    EarlyExerciseMethod, FdScheme, Mesher1D, TridiagonalOperator,
+};
+use quantlib::methods::lattices::BinomialTreeType;
+use quantlib::pricingengines::{
+    AnalyticEuropeanEngine, BinomialVanillaEngine, FdBlackScholesVanillaEngine,
+};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Date, WeekendsOnly};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Engine = FdBlackScholesVanillaEngine<Curve, Curve, BlackConstantVol>;
+
+fn expiry() -> Date {
+    reference_date().add_days(365)
+}
+
+fn market(r: f64, q: f64, sigma: f64) -> (Rc<Curve>, Rc<Curve>, Rc<BlackConstantVol>) {
+    (
+        Rc::new(flat_curve(r)),
+        Rc::new(flat_curve(q)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma))),
+    )
+}
+
+fn engine(r: f64, q: f64, sigma: f64, x_grid: usize, t_grid: usize) -> Engine {
+    let (risk_free, dividend, volatility) = market(r, q, sigma);
+    FdBlackScholesVanillaEngine::new(risk_free, dividend, volatility, x_grid, t_grid)
+}
+
+fn american_put() -> EarlyExerciseOption {
+    EarlyExerciseOption::new(
+        OptionType::Put,
+        40.0,
+        Exercise::American {
+            earliest: reference_date(),
+            latest: expiry(),
+        },
+    )
+}
+
+#[test]
+fn test_operators_are_exact_on_quadratics() {
+    let mesher = Mesher1D::concentrating(-1.0, 2.0, 41, 0.3, 0.1);
+    assert_eq!(mesher.locations[0], -1.0);
+    assert_eq!(mesher.locations[40], 2.0);
+    // denser at the center than at the ends
+    let centre = mesher.locations.iter().position(|x| *x >= 0.3).unwrap();
+    assert!(mesher.dplus(centre) < 0.5 * mesher.dplus(0));
+    let f: Vec<f64> = mesher
+        .locations
+        .iter()
+        .map(|x| 3.0 * x * x - x + 1.0)
+        .collect();
+    let d1 = TridiagonalOperator::first_derivative(&mesher).apply(&f);
+    let d2 = TridiagonalOperator::second_derivative(&mesher).apply(&f);
+    for i in 1..40 {
+        let x = mesher.locations[i];
+        assert!((d1[i] - (6.0 * x - 1.0)).abs() < 1.0e-9);
+        assert!((d2[i] - 6.0).abs() < 1.0e-8);
+    }
+    assert_eq!((d1[0], d2[40]), (0.0, 0.0));
+    assert!((mesher.interpolate(&f, 0.123) - (3.0 * 0.123 * 0.123 - 0.123 + 1.0)).abs() < 1.0e-12);
+    // solving inverts applying
+    let op = TridiagonalOperator::identity(41)
+        .plus(&TridiagonalOperator::second_derivative(&mesher).scaled(-0.01));
+    let x = op.solve_for(&f);
+    for (a, b) in op.apply(&x).iter().zip(&f) {
+        assert!((a - b).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn test_european_options_match_black_scholes() {
+    let (r, q, sigma) = (0.05, 0.02, 0.3);
+    let (risk_free, dividend, volatility) = market(r, q, sigma);
+    let analytic = AnalyticEuropeanEngine::new(risk_free, dividend, volatility);
+    for option_type in &[OptionType::Call, OptionType::Put] {
+        for strike in &[80.0, 100.0, 125.0] {
+            let option = VanillaOption::new(*option_type, *strike, expiry());
+            let expected = analytic.npv(&option, 100.0);
+            let crank_nicolson = engine(r, q, sigma, 301, 200).with_damping_steps(2);
+            assert!((crank_nicolson.npv(&option.into(), 100.0) - expected).abs() < 2.0e-3);
+            let concentrated = engine(r, q, sigma, 201, 200).with_concentration(0.1);
+            assert!((concentrated.npv(&option.into(), 100.0) - expected).abs() < 2.0e-3);
+            let implicit = engine(r, q, sigma, 301, 400).with_scheme(FdScheme::ImplicitEuler);
+            assert!((implicit.npv(&option.into(), 100.0) - expected).abs() < 1.0e-2);
+        }
+    }
+}
+
+#[test]
+fn test_american_put_by_penalty_and_psor() {
+    let psor = engine(0.06, 0.0, 0.2, 401, 400).npv(&american_put(), 36.0);
+    let penalty = engine(0.06, 0.0, 0.2, 401, 400)
+        .with_american_method(EarlyExerciseMethod::Penalty)
+        .npv(&american_put(), 36.0);
+    assert!((psor - 4.4867).abs() < 2.0e-3);
+    assert!((penalty - psor).abs() < 1.0e-6);
+    let (risk_free, dividend, volatility) = market(0.06, 0.0, 0.2);
+    let tree = BinomialVanillaEngine::new(
+        risk_free,
+        dividend,
+        volatility,
+        BinomialTreeType::CoxRossRubinstein,
+        1000,
+    );
+    assert!((tree.npv(&american_put(), 36.0) - psor).abs() < 2.0e-3);
+    // deep in the money the put is exercised at once
+    assert!((engine(0.06, 0.0, 0.2, 401, 400).npv(&american_put(), 20.0) - 20.0).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_bermudan_put_lies_between() {
+    let fd = engine(0.06, 0.0, 0.2, 401, 400);
+    let put = |exercise| EarlyExerciseOption::new(OptionType::Put, 40.0, exercise);
+    let european = fd.npv(&put(Exercise::European(expiry())), 36.0);
+    let quarterly = Exercise::bermudan(
+        (1..=4)
+            .map(|k| reference_date().add_months(3 * k))
+            .collect(),
+    );
+    let bermudan = fd.npv(&put(quarterly), 36.0);
+    let american = fd.npv(&american_put(), 36.0);
+    assert!(european < bermudan && bermudan < american);
+    assert!((european - 3.8443).abs() < 2.0e-3);
+}
+
+#[test]
+fn test_errors_shrink_as_the_grid_refines() {
+    let (r, q, sigma) = (0.05, 0.02, 0.3);
+    let (risk_free, dividend, volatility) = market(r, q, sigma);
+    let option = VanillaOption::new(OptionType::Call, 100.0, expiry());
+    let expected = AnalyticEuropeanEngine::new(risk_free, dividend, volatility).npv(&option, 100.0);
+    let errors: Vec<f64> = [(51, 25), (101, 50), (201, 100), (401, 200)]
+        .iter()
+        .map(|(x, t)| {
+            let fd = engine(r, q, sigma, *x, *t).with_damping_steps(2);
+            (fd.npv(&option.into(), 100.0) - expected).abs()
+        })
+        .collect();
+    // Crank-Nicolson is second order: halving the steps quarters the error
+    for pair in errors.windows(2) {
+        assert!(pair[1] < 0.3 * pair[0], "{:?}", errors);
+    }
+    assert!(errors[3] < 1.0e-3);
+}
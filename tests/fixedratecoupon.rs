@@ -0,0 +1,98 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{self as cf, CashFlow, Coupon, Event, FixedRateLeg};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::Compounding;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, DayCounter, Frequency,
+    Month, Period, Schedule, TimeUnit, WeekendsOnly,
+};
+
+fn schedule(effective: Date, months: i64) -> Schedule {
+    Schedule::new(
+        effective,
+        Date::new(15, Month::January, 2025),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Following,
+        BusinessDayConvention::Following,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+#[test]
+fn test_coupons_accrue_the_rate() {
+    let leg = FixedRateLeg::new(schedule(reference_date(), 12), Actual365Fixed)
+        .with_notional(1_000_000.0)
+        .with_rate(0.04)
+        .build();
+    assert_eq!(leg.len(), 5);
+    for c in &leg {
+        let t =
+            Actual365Fixed.year_fraction(c.accrual_start_date(), c.accrual_end_date(), None, None);
+        assert!((c.amount() - 1_000_000.0 * 0.04 * t).abs() < 1.0e-8);
+        assert_eq!(c.date(), c.accrual_end_date());
+        assert_eq!(c.rate(), 0.04);
+    }
+    // 15th January 2022 is a Saturday
+    assert_eq!(leg[1].date(), Date::new(17, Month::January, 2022));
+    assert_eq!(
+        leg[2].accrual_start_date(),
+        Date::new(17, Month::January, 2022)
+    );
+}
+
+#[test]
+fn test_amortizing_step_up_leg() {
+    let leg = FixedRateLeg::new(schedule(reference_date(), 12), Actual365Fixed)
+        .with_notionals(vec![100.0, 80.0, 60.0])
+        .with_rates(vec![0.03, 0.035])
+        .build();
+    let notionals: Vec<f64> = leg.iter().map(|c| c.nominal()).collect();
+    assert_eq!(notionals, vec![100.0, 80.0, 60.0, 60.0, 60.0]);
+    let rates: Vec<f64> = leg.iter().map(|c| c.rate()).collect();
+    assert_eq!(rates, vec![0.03, 0.035, 0.035, 0.035, 0.035]);
+}
+
+#[test]
+fn test_compounded_rates_and_stubs() {
+    // semiannual schedule with a three-month front stub
+    let leg = FixedRateLeg::new(
+        schedule(Date::new(15, Month::April, 2020), 6),
+        Actual365Fixed,
+    )
+    .with_rate(0.05)
+    .with_compounding(Compounding::Compounded, Frequency::Annual)
+    .build();
+    assert_eq!(
+        leg[0].accrual_start_date(),
+        Date::new(15, Month::April, 2020)
+    );
+    assert_eq!(
+        leg[0].reference_period_start(),
+        Date::new(15, Month::January, 2020)
+    );
+    let c = &leg[2];
+    let t = Actual365Fixed.year_fraction(c.accrual_start_date(), c.accrual_end_date(), None, None);
+    assert!((c.amount() - (1.05_f64.powf(t) - 1.0)).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_leg_prices_on_a_curve() {
+    let curve = flat_curve(0.03);
+    let leg = FixedRateLeg::new(schedule(reference_date(), 12), Actual365Fixed)
+        .with_notional(100.0)
+        .with_rate(0.03)
+        .build();
+    let expected: f64 = leg
+        .iter()
+        .map(|c| c.amount() * curve.discount(c.date(), true))
+        .sum();
+    assert!((cf::npv(&leg, &curve, false, reference_date()) - expected).abs() < 1.0e-12);
+    // the first coupon has been paid by its payment date
+    let settlement = leg[0].date();
+    let remaining = expected - leg[0].amount() * curve.discount(settlement, true);
+    assert!((cf::npv(&leg, &curve, false, settlement) - remaining).abs() < 1.0e-12);
+}
@@ -0,0 +1,128 @@
+extern crate quantlib;
+
+use quantlib::cashflows::IborLeg;
+use quantlib::currencies::Currency;
+use quantlib::indexes::{IborIndex, InterestRateIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::time::traits::Calendar as Cal;
+use quantlib::time::{
+    Actual360, Actual365Fixed, Brazil, BusinessDayConvention, Calendar, Date, DateGenerator,
+    JointCalendar, JointCalendarRule, Month, Period, Schedule, Sweden, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+fn index<C: Cal>(currency: Currency, calendar: C) -> IborIndex<C, Curve, Actual360> {
+    IborIndex::for_currency(
+        "Ibor",
+        Period::new(3, TimeUnit::Months),
+        currency,
+        Calendar::new(calendar),
+        BusinessDayConvention::ModifiedFollowing,
+        false,
+        Actual360,
+        None,
+    )
+}
+
+#[test]
+fn test_spot_lags_by_currency() {
+    assert_eq!(Currency::EUR.spot_lag(), 2);
+    assert_eq!(Currency::USD.spot_lag(), 2);
+    assert_eq!(Currency::GBP.spot_lag(), 0);
+    assert_eq!(Currency::CAN.spot_lag(), 0);
+    assert_eq!(index(Currency::JPY, Sweden).fixing_days, 2);
+}
+
+#[test]
+fn test_spot_value_dates_skip_holidays() {
+    let stibor = index(Currency::EUR, Sweden);
+    // Christmas Eve, Christmas and Boxing day are Swedish holidays.
+    let fixing = Date::new(23, Month::December, 2019);
+    let value = Date::new(30, Month::December, 2019);
+    assert_eq!(stibor.value_date(fixing), value);
+    assert_eq!(stibor.fixing_date(value), fixing);
+    assert_eq!(
+        stibor.fixing_date(Date::new(27, Month::December, 2019)),
+        Date::new(20, Month::December, 2019)
+    );
+}
+
+#[test]
+fn test_same_day_indexes_fix_before_holidays() {
+    let libor = index(Currency::GBP, Sweden);
+    let fixing = Date::new(27, Month::December, 2019);
+    assert_eq!(libor.value_date(fixing), fixing);
+    assert_eq!(libor.fixing_date(fixing), fixing);
+    assert_eq!(
+        libor.fixing_date(Date::new(25, Month::December, 2019)),
+        Date::new(23, Month::December, 2019)
+    );
+}
+
+#[test]
+fn test_joint_calendars_combine_holidays() {
+    let both = Calendar::new(JointCalendar::new(
+        Sweden,
+        Brazil,
+        JointCalendarRule::JoinHolidays,
+    ));
+    let either = Calendar::new(JointCalendar::new(
+        Sweden,
+        Brazil,
+        JointCalendarRule::JoinBusinessDays,
+    ));
+    // Christmas Eve is a holiday in Sweden only, Carnival in Brazil only.
+    let christmas_eve = Date::new(24, Month::December, 2019);
+    let carnival = Date::new(25, Month::February, 2020);
+    assert!(!both.is_business_day(christmas_eve) && either.is_business_day(christmas_eve));
+    assert!(!both.is_business_day(carnival) && either.is_business_day(carnival));
+    assert!(!either.is_business_day(Date::new(25, Month::December, 2019)));
+
+    let ibor = index(
+        Currency::USD,
+        JointCalendar::new(Sweden, Brazil, JointCalendarRule::JoinHolidays),
+    );
+    let fixing = Date::new(20, Month::February, 2020);
+    let value = Date::new(26, Month::February, 2020);
+    assert_eq!(ibor.value_date(fixing), value);
+    assert_eq!(ibor.fixing_date(value), fixing);
+}
+
+#[test]
+fn test_coupons_fix_on_the_index_fixing_date() {
+    let curve = Rc::new(YieldTermStructure::new(
+        Calendar::new(WeekendsOnly),
+        Date::new(2, Month::December, 2019),
+        Actual365Fixed {},
+        0,
+        vec![],
+        vec![],
+        Box::new(|t| (-0.01 * t).exp()),
+    ));
+    let stibor: IborIndex<Sweden, Curve, Actual360> = IborIndex::for_currency(
+        "Stibor",
+        Period::new(3, TimeUnit::Months),
+        Currency::EUR,
+        Calendar::new(Sweden),
+        BusinessDayConvention::ModifiedFollowing,
+        false,
+        Actual360,
+        Some(curve),
+    );
+    let schedule = Schedule::new(
+        Date::new(30, Month::December, 2019),
+        Date::new(30, Month::June, 2020),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(Sweden),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Forward,
+        false,
+    );
+    let leg = IborLeg::new(schedule, Rc::new(stibor), Actual360).build();
+    assert_eq!(leg[0].fixing_date, Date::new(23, Month::December, 2019));
+    assert_eq!(leg[1].fixing_date, Date::new(26, Month::March, 2020));
+}
@@ -0,0 +1,228 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{check_leg_fixings, Coupon, FloatingRateCoupon, IborCoupon, IborLeg};
+use quantlib::errors::QuantLibError;
+use quantlib::indexes::{
+    IborIndex, Index, IndexManager, InterestRateIndex, MissingFixingPolicy, OvernightIndex,
+};
+use quantlib::patterns::{ChangeFlag, Observer};
+use quantlib::quotes::SimpleQuote;
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule,
+    TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Euribor = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+fn euribor() -> Euribor {
+    IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.01))),
+    )
+}
+
+fn context(policy: MissingFixingPolicy) -> PricingContext {
+    PricingContext::new(reference_date()).with_missing_fixing_policy(policy)
+}
+
+#[test]
+fn test_missing_past_fixings_are_reported() {
+    let index = euribor();
+    let past = Date::new(10, Month::January, 2020);
+    let error = context(MissingFixingPolicy::Error)
+        .scope(|| index.checked_fixing(past))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        QuantLibError::MissingFixing { fixing_date, .. } if fixing_date == past
+    ));
+    assert_eq!(
+        error.to_string(),
+        "missing Euribor6M fixing for 2020-01-10 (evaluation date 2020-01-15)"
+    );
+    // today's fixing may not be published yet and is forecast.
+    let today =
+        context(MissingFixingPolicy::Error).scope(|| index.checked_fixing(reference_date()));
+    assert_eq!(today, index.forecast_fixing(reference_date()));
+}
+
+#[test]
+fn test_indexes_without_a_curve_report_it() {
+    let index: Euribor = IborIndex::new(
+        "Pibor",
+        Period::new(3, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        None,
+    );
+    let future = Date::new(15, Month::June, 2020);
+    let error = QuantLibError::Uninitialized {
+        what: "forwarding curve of Pibor3M".to_string(),
+    };
+    assert_eq!(index.forecast_fixing(future), Err(error.clone()));
+    assert_eq!(
+        context(MissingFixingPolicy::Error).scope(|| index.checked_fixing(future)),
+        Err(error)
+    );
+    // stored fixings need no curve
+    index.add_fixing(future, 0.002);
+    assert_eq!(index.checked_fixing(future), Ok(0.002));
+}
+
+#[test]
+fn test_missing_fixings_can_be_forecast_or_carried_forward() {
+    let index = euribor();
+    // fixed before, but accruing after the curve reference date.
+    let past = Date::new(14, Month::January, 2020);
+    let forecast = context(MissingFixingPolicy::UseForecast).scope(|| index.checked_fixing(past));
+    assert_eq!(forecast, index.forecast_fixing(past));
+
+    let last = context(MissingFixingPolicy::UseLastAvailable);
+    let error = last.scope(|| index.checked_fixing(past)).unwrap_err();
+    assert!(error.to_string().contains("no earlier fixing stored"));
+    index.add_fixing(Date::new(10, Month::January, 2020), -0.0031);
+    index.add_fixing(Date::new(16, Month::January, 2020), -0.0030);
+    assert_eq!(last.scope(|| index.checked_fixing(past)), Ok(-0.0031));
+    assert_eq!(
+        index.last_fixing(past),
+        Some((Date::new(10, Month::January, 2020), -0.0031))
+    );
+}
+
+#[test]
+fn test_fixings_are_shared_by_indexes_of_the_same_name() {
+    let forecasting = euribor();
+    let coupon_index = Rc::new(euribor());
+    let flag = ChangeFlag::new();
+    forecasting
+        .observable()
+        .unwrap()
+        .register_observer(Rc::clone(&flag) as Rc<dyn Observer>);
+    let past = Date::new(10, Month::January, 2020);
+    coupon_index.add_fixing(past, -0.0031);
+    assert!(flag.take());
+    assert_eq!(forecasting.stored_fixing(past), Some(-0.0031));
+    assert_eq!(IndexManager::history("Euribor6M").len(), 1);
+    // other tenors keep their own history
+    let mut three_months = euribor();
+    three_months.tenor = Period::new(3, TimeUnit::Months);
+    assert_eq!(three_months.stored_fixing(past), None);
+
+    IndexManager::clear_history("Euribor6M");
+    assert!(flag.take());
+    assert!(forecasting.fixing_history().is_empty());
+}
+
+fn leg() -> Vec<IborCoupon<Euribor, Actual360>> {
+    let index = euribor();
+    index.add_fixing(Date::new(15, Month::July, 2019), -0.0035);
+    let schedule = Schedule::new(
+        Date::new(17, Month::January, 2018),
+        Date::new(17, Month::January, 2021),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    );
+    IborLeg::new(schedule, Rc::new(index), Actual360).build()
+}
+
+#[test]
+fn test_legs_report_every_missing_fixing() {
+    let leg = leg();
+    let errors = context(MissingFixingPolicy::Error)
+        .scope(|| check_leg_fixings(&leg))
+        .unwrap_err();
+    // July 2019 is stored and January 2020 is today's fixing.
+    let dates: Vec<Date> = errors
+        .iter()
+        .filter_map(|e| match e {
+            QuantLibError::MissingFixing { fixing_date, .. } => Some(*fixing_date),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        dates,
+        vec![
+            Date::new(15, Month::January, 2018),
+            Date::new(13, Month::July, 2018),
+            Date::new(15, Month::January, 2019),
+        ]
+    );
+    assert_eq!(
+        leg[3].fixing_dates(),
+        vec![Date::new(15, Month::July, 2019)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "missing Euribor6M fixing for 2018-01-15")]
+fn test_pricing_with_missing_fixings_names_the_fixing() {
+    let leg = leg();
+    context(MissingFixingPolicy::Error).scope(|| leg[0].rate());
+}
+
+#[test]
+fn test_named_indexes_follow_market_conventions() {
+    let curve = Rc::new(flat_curve(0.01));
+    let euribor: Euribor = IborIndex::euribor(
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Some(Rc::clone(&curve)),
+    );
+    assert_eq!(euribor.name(), "Euribor6M");
+    assert_eq!(euribor.fixing_days, 2);
+    assert!(euribor.end_of_month);
+    let libor: IborIndex<WeekendsOnly, Curve, Actual360> = IborIndex::usd_libor(
+        Period::new(1, TimeUnit::Weeks),
+        Calendar::new(WeekendsOnly),
+        None,
+    );
+    assert_eq!(libor.convention, BusinessDayConvention::Following);
+    assert!(!libor.end_of_month);
+    // fixed Wednesday 29th January 2020 for Friday, maturing end of July
+    let fixing = Date::new(29, Month::January, 2020);
+    assert_eq!(
+        euribor.value_date(fixing),
+        Date::new(31, Month::January, 2020)
+    );
+    assert_eq!(
+        euribor.maturity_date(euribor.value_date(fixing)),
+        Date::new(31, Month::July, 2020)
+    );
+}
+
+#[test]
+fn test_overnight_indexes_store_their_fixings() {
+    let curve = Rc::new(flat_curve(0.02));
+    let sofr: OvernightIndex<WeekendsOnly, Curve> =
+        IborIndex::sofr(Calendar::new(WeekendsOnly), Some(curve));
+    assert_eq!(sofr.name(), "SOFRON");
+    assert!(sofr.is_overnight());
+    let past = Date::new(10, Month::January, 2020);
+    sofr.add_fixing(past, 0.0155);
+    assert_eq!(sofr.fixing(past), 0.0155);
+    assert_eq!(sofr.last_fixing(reference_date()), Some((past, 0.0155)));
+    // Friday to Monday
+    assert_eq!(
+        sofr.maturity_date(past),
+        Date::new(13, Month::January, 2020)
+    );
+    assert_eq!(sofr.fixing_date(past), past);
+}
@@ -0,0 +1,109 @@
+extern crate quantlib;
+
+use quantlib::quotes::SimpleQuote;
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{Compounding, FlatForward};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual360, Actual365Fixed, Calendar, Date, Frequency, Month, WeekendsOnly};
+
+#[test]
+fn test_discount_follows_the_compounding() {
+    let calendar = Calendar::new(WeekendsOnly);
+    let continuous = FlatForward::with_rate(
+        reference_date(),
+        0.03,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        calendar,
+    );
+    let annual = FlatForward::with_rate(
+        reference_date(),
+        0.03,
+        Actual365Fixed,
+        Compounding::Compounded,
+        Frequency::Annual,
+        calendar,
+    );
+    let date = Date::new(15, Month::January, 2023);
+    let t = continuous.time_from_reference(date);
+    assert!((continuous.discount(date, false) - (-0.03 * t).exp()).abs() < 1.0e-15);
+    assert!((annual.discount(date, false) - 1.03_f64.powf(-t)).abs() < 1.0e-15);
+    assert_eq!(continuous.discount(reference_date(), false), 1.0);
+}
+
+#[test]
+fn test_zero_and_forward_rates_are_flat() {
+    let mut curve = FlatForward::with_rate(
+        reference_date(),
+        0.025,
+        Actual360,
+        Compounding::Compounded,
+        Frequency::Semiannual,
+        Calendar::new(WeekendsOnly),
+    );
+    let (d1, d2) = (
+        Date::new(15, Month::March, 2021),
+        Date::new(15, Month::September, 2024),
+    );
+    let zero = curve.zero_rate(
+        d2,
+        Actual360,
+        Compounding::Compounded,
+        Frequency::Semiannual,
+        false,
+    );
+    assert!((zero.rate - 0.025).abs() < 1.0e-12);
+    let forward = curve.forward_rate(
+        d1,
+        d2,
+        Actual360,
+        Compounding::Compounded,
+        Frequency::Semiannual,
+        false,
+    );
+    assert!((forward.rate - 0.025).abs() < 1.0e-12);
+    let continuous = curve.continuous_zero_rate(d1, false);
+    assert!((continuous.rate - 2.0 * (1.0125_f64).ln()).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_quote_changes_reach_the_curve() {
+    let curve = FlatForward::with_rate(
+        reference_date(),
+        0.01,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        Calendar::new(WeekendsOnly),
+    );
+    let date = Date::new(15, Month::January, 2022);
+    let before = curve.discount(date, false);
+    curve.forward.set_value(0.02);
+    let t = curve.time_from_reference(date);
+    assert!(curve.discount(date, false) < before);
+    assert!((curve.discount(date, false) - (-0.02 * t).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_moving_curve_follows_the_evaluation_date() {
+    let context = PricingContext::new(Date::new(17, Month::January, 2020));
+    let mut curve = context.scope(|| {
+        let mut moving = FlatForward::moving(
+            2,
+            Calendar::new(WeekendsOnly),
+            SimpleQuote::new(0.02),
+            Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Annual,
+        );
+        // Friday plus two business days
+        assert_eq!(moving.reference_date(), Date::new(21, Month::January, 2020));
+        moving
+    });
+    let later = PricingContext::new(Date::new(3, Month::February, 2020));
+    later.scope(|| {
+        assert_eq!(curve.reference_date(), Date::new(5, Month::February, 2020));
+    });
+}
@@ -0,0 +1,138 @@
+extern crate quantlib;
+
+use quantlib::math::Interpolation;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    discounts_to_forwards, discounts_to_zero_rates, forwards_to_discounts, zero_rates_to_discounts,
+    InterpolatedDiscountCurve, InterpolatedForwardCurve,
+};
+use quantlib::time::{Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+fn dates(years: &[i64]) -> Vec<Date> {
+    let today = Date::new(15, Month::January, 2020);
+    years.iter().map(|y| today.add_months(12 * y)).collect()
+}
+
+fn curve(
+    forwards: Vec<f64>,
+    interpolation: Interpolation,
+) -> InterpolatedForwardCurve<WeekendsOnly> {
+    InterpolatedForwardCurve::new(
+        dates(&[0, 1, 2, 5, 10]),
+        forwards,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+}
+
+#[test]
+fn test_linear_forwards_integrate_exactly() {
+    let c = curve(vec![0.03; 5], Interpolation::Linear);
+    for &t in &[0.0_f64, 0.5, 3.0, 12.0] {
+        assert!((c.discount_with_time(t) - (-0.03 * t).exp()).abs() < 1.0e-14);
+        assert!((c.zero_rate(t) - 0.03).abs() < 1.0e-12);
+    }
+
+    // forwards rising by 20bp a year: -ln P(t) = 0.02 t + 0.001 t^2
+    let times = c.times.clone();
+    let rising: Vec<f64> = times.iter().map(|t| 0.02 + 0.002 * t).collect();
+    let c = curve(rising, Interpolation::Linear);
+    for &t in &[0.3_f64, 1.7, 4.0, 7.5] {
+        let expected = (-(0.02 * t + 0.001 * t * t)).exp();
+        assert!((c.discount_with_time(t) - expected).abs() < 1.0e-13);
+    }
+    // flat beyond the last node
+    let last = times[times.len() - 1];
+    let ratio = c.discount_with_time(last + 2.0) / c.discount_with_time(last);
+    assert!((ratio - (-c.forwards[4] * 2.0).exp()).abs() < 1.0e-13);
+    assert!(
+        (c.term_structure().discount_with_time(4.0, true) - c.discount_with_time(4.0)).abs()
+            < 1.0e-15
+    );
+}
+
+#[test]
+fn test_node_representations_round_trip() {
+    let times = vec![0.0, 0.5, 1.0, 3.0, 7.0];
+    let zeros = vec![0.01, 0.012, 0.015, 0.02, 0.025];
+    let discounts = zero_rates_to_discounts(&times, &zeros);
+    let back = discounts_to_zero_rates(&times, &discounts);
+    for i in 1..times.len() {
+        assert!((back[i] - zeros[i]).abs() < 1.0e-15);
+    }
+    assert!((back[0] - discounts_to_forwards(&times, &discounts)[0]).abs() < 1.0e-15);
+
+    let forwards = discounts_to_forwards(&times, &discounts);
+    assert_eq!(forwards.len(), 4);
+    for (d, e) in forwards_to_discounts(&times, &forwards)
+        .iter()
+        .zip(&discounts)
+    {
+        assert!((d - e).abs() < 1.0e-15);
+    }
+}
+
+#[test]
+fn test_forward_and_discount_curves_convert() {
+    let discount_curve = InterpolatedDiscountCurve::new(
+        dates(&[0, 1, 2, 5, 10]),
+        vec![1.0, 0.985, 0.968, 0.91, 0.8],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::MonotonicCubic,
+    );
+    let forward_curve = InterpolatedForwardCurve::from_discount_curve(
+        &discount_curve,
+        Interpolation::MonotonicCubic,
+    );
+    for (i, t) in forward_curve.times.iter().enumerate() {
+        assert!(
+            (forward_curve.instantaneous_forward(*t) - discount_curve.instantaneous_forward(*t))
+                .abs()
+                < 1.0e-12
+        );
+        // the forwards between nodes differ, but little
+        let error = (forward_curve.discount_with_time(*t) / discount_curve.discounts[i]).ln();
+        assert!(error.abs() <= 1.0e-3 * t, "{} {}", t, error);
+    }
+    let back = forward_curve.discount_curve(Interpolation::LogLinear);
+    assert_eq!(back.dates, forward_curve.dates);
+    for (t, d) in back.times.iter().zip(&back.discounts) {
+        assert!((d - forward_curve.discount_with_time(*t)).abs() < 1.0e-15);
+    }
+}
+
+/// Sum of squared second differences of the forwards on a fine grid.
+fn roughness(c: &InterpolatedForwardCurve<WeekendsOnly>) -> f64 {
+    let h = 0.05;
+    (1..199)
+        .map(|k| {
+            let t = k as f64 * h;
+            let f = |x: f64| c.instantaneous_forward(x);
+            (f(t + h) - 2.0 * f(t) + f(t - h)).powi(2)
+        })
+        .sum()
+}
+
+#[test]
+fn test_smoothing_options() {
+    let noisy = vec![0.02, 0.028, 0.022, 0.031, 0.027];
+    let natural = curve(noisy.clone(), Interpolation::NaturalCubic);
+    let smoothed = curve(
+        noisy.clone(),
+        Interpolation::LeastSquaresSpline { knots: 3 },
+    );
+    assert!(roughness(&smoothed) < roughness(&natural));
+    assert!(
+        (smoothed.discount_with_time(10.0) / natural.discount_with_time(10.0) - 1.0).abs() < 0.01
+    );
+
+    // the constrained spline does not overshoot monotone nodes
+    let rising = vec![0.01, 0.03, 0.031, 0.032, 0.05];
+    let monotonic = curve(rising, Interpolation::MonotonicCubic);
+    let forwards: Vec<f64> = (0..=100)
+        .map(|k| monotonic.instantaneous_forward(0.1 * k as f64))
+        .collect();
+    assert!(forwards.windows(2).all(|w| w[1] >= w[0] - 1.0e-15));
+}
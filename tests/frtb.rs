@@ -0,0 +1,103 @@
+extern crate quantlib;
+
+use quantlib::risk::{sbm_capital, CorrelationScenario, RiskClass, RiskMeasure, Sensitivity};
+
+#[test]
+fn test_single_sensitivity_is_weighted() {
+    let report = sbm_capital(&[Sensitivity::delta(
+        RiskClass::Equity,
+        "5",
+        "ACME",
+        None,
+        -1_000_000.0,
+    )]);
+    assert!((report.capital - 300_000.0).abs() < 1.0e-6);
+    for scenario in [
+        CorrelationScenario::Low,
+        CorrelationScenario::Medium,
+        CorrelationScenario::High,
+    ] {
+        assert!((report.capital_under(scenario) - 300_000.0).abs() < 1.0e-6);
+    }
+    // offsetting trades on the same name net before weighting
+    let report = sbm_capital(&[
+        Sensitivity::delta(RiskClass::Fx, "EURUSD", "EURUSD", None, 500.0),
+        Sensitivity::delta(RiskClass::Fx, "EURUSD", "EURUSD", None, -200.0),
+    ]);
+    assert!((report.capital - 0.15 * 300.0).abs() < 1.0e-9);
+}
+
+#[test]
+fn test_interest_rate_tenors_are_correlated() {
+    let ws = (0.011 * 100.0, 0.011 * 100.0);
+    let rho: f64 = (-0.03f64 * 5.0 / 5.0).exp();
+    let report = sbm_capital(&[
+        Sensitivity::delta(RiskClass::InterestRate, "USD", "SOFR", Some(5.0), 100.0),
+        Sensitivity::delta(RiskClass::InterestRate, "USD", "SOFR", Some(10.0), -100.0),
+    ]);
+    let charge = report
+        .charge(
+            RiskClass::InterestRate,
+            RiskMeasure::Delta,
+            CorrelationScenario::Medium,
+        )
+        .unwrap();
+    let expected = (ws.0 * ws.0 + ws.1 * ws.1 - 2.0 * rho * ws.0 * ws.1).sqrt();
+    assert!((charge.capital - expected).abs() < 1.0e-12);
+    // lower correlations penalize the hedge
+    assert_eq!(report.scenario, CorrelationScenario::Low);
+    assert!(report.capital_under(CorrelationScenario::High) < charge.capital);
+}
+
+#[test]
+fn test_buckets_aggregate_with_gamma() {
+    let sensitivities = [
+        Sensitivity::delta(RiskClass::Fx, "EURUSD", "EURUSD", None, 1000.0),
+        Sensitivity::delta(RiskClass::Fx, "USDJPY", "USDJPY", None, 1000.0),
+        Sensitivity::delta(RiskClass::Equity, "11", "A", None, 100.0),
+        Sensitivity::delta(RiskClass::Equity, "11", "B", None, -100.0),
+    ];
+    let report = sbm_capital(&sensitivities);
+    let fx = report
+        .charge(RiskClass::Fx, RiskMeasure::Delta, CorrelationScenario::High)
+        .unwrap();
+    assert_eq!(fx.buckets.len(), 2);
+    let k: f64 = 150.0;
+    assert!((fx.capital - (2.0 * k * k * (1.0 + 0.75)).sqrt()).abs() < 1.0e-9);
+    // the other sector bucket does not diversify
+    let other = report
+        .charge(
+            RiskClass::Equity,
+            RiskMeasure::Delta,
+            CorrelationScenario::Medium,
+        )
+        .unwrap();
+    assert!((other.buckets[0].capital - 140.0).abs() < 1.0e-9);
+    assert_eq!(report.scenario, CorrelationScenario::High);
+    assert!((report.capital - (fx.capital + 140.0)).abs() < 1.0e-9);
+}
+
+#[test]
+fn test_curvature_takes_the_worse_shock() {
+    let report = sbm_capital(&[
+        Sensitivity::curvature(RiskClass::Equity, "1", "A", -50.0, 80.0),
+        Sensitivity::curvature(RiskClass::Equity, "1", "B", -30.0, 40.0),
+    ]);
+    let charge = report
+        .charge(
+            RiskClass::Equity,
+            RiskMeasure::Curvature,
+            CorrelationScenario::Medium,
+        )
+        .unwrap();
+    let rho = 0.15f64 * 0.15;
+    let down = (80.0f64 * 80.0 + 40.0 * 40.0 + 2.0 * rho * 80.0 * 40.0).sqrt();
+    assert!((charge.buckets[0].capital - down).abs() < 1.0e-9);
+    assert!((charge.buckets[0].sum - 120.0).abs() < 1.0e-12);
+    // negative CVRs on both names give no capital
+    let report = sbm_capital(&[
+        Sensitivity::curvature(RiskClass::Equity, "1", "A", -50.0, -80.0),
+        Sensitivity::curvature(RiskClass::Equity, "1", "B", -30.0, -40.0),
+    ]);
+    assert_eq!(report.capital, 0.0);
+}
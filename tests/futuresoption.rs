@@ -0,0 +1,95 @@
+extern crate quantlib;
+
+use quantlib::instruments::{FuturesOption, OptionType, PremiumSettlement};
+use quantlib::pricingengines::{BachelierEngine, Black76Engine};
+
+fn option(option_type: OptionType, strike: f64, settlement: PremiumSettlement) -> FuturesOption {
+    FuturesOption::new(option_type, strike, 0.75, settlement)
+}
+
+#[test]
+fn test_black76_reference_value() {
+    // Haug, "The Complete Guide to Option Pricing Formulas": option on
+    // Brent futures at 19, nine months, r = 10%, vol = 28%
+    let engine = Black76Engine::new(0.28);
+    let discount = (-0.10_f64 * 0.75).exp();
+    for &t in &[OptionType::Call, OptionType::Put] {
+        let npv = engine.npv(&option(t, 19.0, PremiumSettlement::Upfront), 19.0, discount);
+        assert!((npv - 1.7011).abs() < 5.0e-5, "{:?}: {}", t, npv);
+    }
+}
+
+#[test]
+fn test_margined_options_are_not_discounted() {
+    let discount = 0.95;
+    for engine in &[
+        Black76Engine::new(0.3),
+        Black76Engine::new(0.3).with_displacement(2.0),
+    ] {
+        let call = |s| engine.npv(&option(OptionType::Call, 95.0, s), 100.0, discount);
+        let put = |s| engine.npv(&option(OptionType::Put, 95.0, s), 100.0, discount);
+        let margined = PremiumSettlement::Margined;
+        let upfront = PremiumSettlement::Upfront;
+        assert!((call(margined) - put(margined) - 5.0).abs() < 1.0e-12);
+        assert!((call(upfront) - put(upfront) - 5.0 * discount).abs() < 1.0e-12);
+        assert!((call(upfront) - discount * call(margined)).abs() < 1.0e-12);
+    }
+    let engine = BachelierEngine::new(0.6);
+    let call = engine.npv(
+        &option(OptionType::Call, 97.5, PremiumSettlement::Margined),
+        97.0,
+        discount,
+    );
+    let put = engine.npv(
+        &option(OptionType::Put, 97.5, PremiumSettlement::Margined),
+        97.0,
+        discount,
+    );
+    assert!((call - put + 0.5).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_bachelier_at_the_money() {
+    let engine = BachelierEngine::new(0.5);
+    let npv = engine.npv(
+        &option(OptionType::Call, 98.0, PremiumSettlement::Margined),
+        98.0,
+        1.0,
+    );
+    let expected = 0.5 * 0.75_f64.sqrt() / (2.0 * std::f64::consts::PI).sqrt();
+    assert!((npv - expected).abs() < 1.0e-14);
+    // a negative strike is fine under the normal model
+    let deep = engine.npv(
+        &option(OptionType::Put, -0.5, PremiumSettlement::Upfront),
+        0.1,
+        0.9,
+    );
+    assert!(deep > 0.0 && deep < 0.9 * 0.6);
+}
+
+#[test]
+fn test_greeks_match_finite_differences() {
+    let h = 1.0e-5;
+    for &s in &[PremiumSettlement::Upfront, PremiumSettlement::Margined] {
+        for &t in &[OptionType::Call, OptionType::Put] {
+            let o = option(t, 96.0, s);
+            let black = |vol: f64, f: f64| {
+                Black76Engine::new(vol)
+                    .with_displacement(1.0)
+                    .npv(&o, f, 0.97)
+            };
+            let engine = Black76Engine::new(0.25).with_displacement(1.0);
+            let delta = (black(0.25, 98.0 + h) - black(0.25, 98.0 - h)) / (2.0 * h);
+            let vega = (black(0.25 + h, 98.0) - black(0.25 - h, 98.0)) / (2.0 * h);
+            assert!((engine.delta(&o, 98.0, 0.97) - delta).abs() < 1.0e-7);
+            assert!((engine.vega(&o, 98.0, 0.97) - vega).abs() < 1.0e-6);
+
+            let normal = |vol: f64, f: f64| BachelierEngine::new(vol).npv(&o, f, 0.97);
+            let engine = BachelierEngine::new(1.5);
+            let delta = (normal(1.5, 98.0 + h) - normal(1.5, 98.0 - h)) / (2.0 * h);
+            let vega = (normal(1.5 + h, 98.0) - normal(1.5 - h, 98.0)) / (2.0 * h);
+            assert!((engine.delta(&o, 98.0, 0.97) - delta).abs() < 1.0e-7);
+            assert!((engine.vega(&o, 98.0, 0.97) - vega).abs() < 1.0e-7);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+extern crate quantlib;
+
+use quantlib::math::Interpolation;
+use quantlib::termstructures::{
+    hull_white_convexity_bias, FuturesCycle, FuturesStrip, RateHelper, StirFuture,
+};
+use quantlib::time::{
+    is_imm_date, next_imm_date, third_wednesday, Actual360, Calendar, Date, DayCounter, Month,
+    WeekendsOnly,
+};
+
+fn reference_date() -> Date {
+    Date::new(2, Month::January, 2020)
+}
+
+/// One month serials from January and three month quarterlies from March,
+/// plus a three month serial overlapping the March contract.
+fn contracts(price: f64) -> Vec<StirFuture> {
+    let mut contracts = vec![
+        StirFuture::imm(Month::January, 2020, 1, price, Actual360),
+        StirFuture::imm(Month::February, 2020, 1, price, Actual360),
+        StirFuture::imm(Month::April, 2020, 3, price, Actual360),
+    ];
+    for &(month, year) in &[
+        (Month::March, 2020),
+        (Month::June, 2020),
+        (Month::September, 2020),
+        (Month::December, 2020),
+    ] {
+        contracts.push(StirFuture::imm(month, year, 3, price, Actual360));
+    }
+    contracts
+}
+
+#[test]
+fn test_imm_dates() {
+    assert_eq!(
+        third_wednesday(Month::January, 2020),
+        Date::new(15, Month::January, 2020)
+    );
+    assert!(is_imm_date(Date::new(18, Month::March, 2020), true));
+    assert!(!is_imm_date(Date::new(15, Month::January, 2020), true));
+    assert!(is_imm_date(Date::new(15, Month::January, 2020), false));
+    let d = Date::new(15, Month::January, 2020);
+    assert_eq!(
+        next_imm_date(d, false),
+        Date::new(19, Month::February, 2020)
+    );
+    assert_eq!(next_imm_date(d, true), Date::new(18, Month::March, 2020));
+    assert_eq!(
+        next_imm_date(Date::new(20, Month::December, 2020), true),
+        Date::new(17, Month::March, 2021)
+    );
+}
+
+#[test]
+fn test_serial_contracts_stitch_to_the_first_quarterly() {
+    let strip = FuturesStrip::new(reference_date(), 0.02, contracts(98.0));
+    let cycles: Vec<FuturesCycle> = strip.contracts.iter().map(|c| c.cycle).collect();
+    assert_eq!(
+        cycles,
+        vec![
+            FuturesCycle::Serial,
+            FuturesCycle::Serial,
+            FuturesCycle::Quarterly,
+            FuturesCycle::Quarterly,
+            FuturesCycle::Quarterly,
+            FuturesCycle::Quarterly,
+        ]
+    );
+    for w in strip.contracts.windows(2) {
+        assert_eq!(w[0].end, w[1].start);
+    }
+
+    // a flat two percent strip compounds simply from the reference date
+    let nodes = strip.nodes();
+    assert_eq!(nodes[1].0, Date::new(15, Month::January, 2020));
+    let mut expected = 1.0;
+    for w in nodes.windows(2) {
+        expected /= 1.0 + 0.02 * Actual360.year_fraction(w[0].0, w[1].0, None, None);
+        assert!((w[1].1 - expected).abs() < 1.0e-14);
+    }
+    assert_eq!(strip.end_date(), Date::new(17, Month::March, 2021));
+
+    let curve = strip.discount_curve(Calendar::new(WeekendsOnly), Interpolation::LogLinear);
+    for c in &strip.contracts {
+        let forward = (curve.discount(c.start) / curve.discount(c.end) - 1.0) / c.accrual();
+        assert!((forward - c.forward_rate()).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn test_convexity_adjustments() {
+    assert_eq!(hull_white_convexity_bias(97.0, 1.0, 1.25, 0.0, 0.03), 0.0);
+    // continuous as the mean reversion vanishes
+    let ho_lee = hull_white_convexity_bias(97.0, 2.0, 2.25, 0.01, 0.0);
+    let nearly = hull_white_convexity_bias(97.0, 2.0, 2.25, 0.01, 1.0e-6);
+    assert!(ho_lee > 0.0 && (ho_lee - nearly).abs() < 1.0e-9);
+    let short = hull_white_convexity_bias(97.0, 1.0, 1.25, 0.01, 0.03);
+    let long = hull_white_convexity_bias(97.0, 5.0, 5.25, 0.01, 0.03);
+    assert!(short > 0.0 && long > short);
+    let reverting = hull_white_convexity_bias(97.0, 5.0, 5.25, 0.01, 0.2);
+    assert!(reverting < long);
+
+    let plain = FuturesStrip::new(reference_date(), 0.02, contracts(98.0));
+    let adjusted = plain.clone().with_hull_white_convexity(0.03, 0.01);
+    for (p, a) in plain.contracts.iter().zip(&adjusted.contracts) {
+        assert!(a.convexity_adjustment > 0.0);
+        assert!(a.forward_rate() < p.forward_rate());
+    }
+    let end = adjusted.end_date();
+    let discount = |s: &FuturesStrip| s.nodes().last().unwrap().1;
+    assert_eq!(plain.end_date(), end);
+    assert!(discount(&adjusted) > discount(&plain));
+}
+
+struct Pillar(Date);
+
+impl RateHelper for Pillar {
+    fn quote(&self) -> f64 {
+        0.0
+    }
+    fn implied_quote_on(&self, _: &dyn Fn(Date) -> f64) -> f64 {
+        0.0
+    }
+    fn pillar_date(&self) -> Date {
+        self.0
+    }
+}
+
+#[test]
+fn test_futures_take_priority_over_overlapping_helpers() {
+    let strip = FuturesStrip::new(reference_date(), 0.02, contracts(98.0));
+    let fra = Pillar(Date::new(2, Month::October, 2020));
+    let two_years = Pillar(Date::new(2, Month::January, 2022));
+    let five_years = Pillar(Date::new(2, Month::January, 2025));
+    let kept = strip.prioritize(vec![&fra, &two_years, &five_years]);
+    let pillars: Vec<Date> = kept.iter().map(|h| h.pillar_date()).collect();
+    assert_eq!(pillars, vec![two_years.0, five_years.0]);
+    assert!(strip.covers(fra.0));
+}
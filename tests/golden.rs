@@ -0,0 +1,91 @@
+extern crate quantlib;
+
+use quantlib::termstructures::InterestRate;
+use quantlib::testutils::{forall, golden, market};
+use quantlib::time::{Actual360, Actual365Fixed, DayCounter};
+
+#[test]
+fn test_rate_conversions() {
+    for c in golden::rate_conversion_cases() {
+        let ir = InterestRate::new(c.rate, Actual360 {}, c.compounding, c.frequency);
+        let compound = ir.compound_factor_with_time(c.time);
+        let ir2 = InterestRate::implied_rate_with_time(
+            compound,
+            Actual360 {},
+            c.compounding2,
+            c.frequency2,
+            c.time,
+        );
+        let scale = 10f64.powi(c.precision);
+        let rounded = (ir2.rate * scale).round() / scale;
+        assert!(
+            (rounded - c.expected).abs() < 1.0e-12,
+            "{:?}: got {}",
+            c,
+            ir2.rate
+        );
+    }
+}
+
+#[test]
+fn test_actual_day_counts() {
+    for c in golden::actual_day_count_cases() {
+        assert_eq!(Actual360 {}.day_count(c.start, c.end), c.days, "{:?}", c);
+        assert_eq!(
+            Actual365Fixed {}.day_count(c.start, c.end),
+            c.days,
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn test_day_count_is_additive() {
+    forall(
+        500,
+        42,
+        |rng| {
+            let d1 = rng.any_date();
+            let d2 = rng.any_date();
+            let d3 = rng.any_date();
+            (d1, d2, d3)
+        },
+        |(d1, d2, d3)| {
+            let dc = Actual365Fixed {};
+            dc.day_count(*d1, *d3) == dc.day_count(*d1, *d2) + dc.day_count(*d2, *d3)
+        },
+    );
+}
+
+#[test]
+fn test_compound_factor_round_trip() {
+    forall(
+        500,
+        7,
+        |rng| {
+            (
+                rng.rate().abs(),
+                rng.compounding(),
+                rng.frequency(),
+                rng.time(),
+            )
+        },
+        |(r, comp, freq, t)| {
+            let ir = InterestRate::new(*r, Actual365Fixed {}, *comp, *freq);
+            let compound = ir.compound_factor_with_time(*t);
+            let back =
+                InterestRate::implied_rate_with_time(compound, Actual365Fixed {}, *comp, *freq, *t);
+            (back.rate - r).abs() < 1.0e-10
+        },
+    );
+}
+
+#[test]
+fn test_flat_reference_market() {
+    let discount = market::flat_discount(0.05);
+    let rate = market::flat_rate(0.05);
+    for t in &[0.5, 1.0, 10.0] {
+        assert!((discount(*t) * rate.compound_factor_with_time(*t) - 1.0).abs() < 1.0e-14);
+    }
+}
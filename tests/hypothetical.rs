@@ -0,0 +1,69 @@
+extern crate quantlib;
+
+use quantlib::termstructures::ArbitrageValidator;
+use quantlib::testutils::{HypotheticalCurves, HypotheticalSurfaces, Rng};
+
+#[test]
+fn test_curves_have_non_increasing_discounts() {
+    let family = HypotheticalCurves::new();
+    let mut rng = Rng::new(7);
+    for _ in 0..200 {
+        let curve = family.generate(&mut rng);
+        assert!(curve.discounts.iter().all(|d| *d > 0.0));
+        assert!(curve.discounts.windows(2).all(|d| d[1] <= d[0]));
+        for i in 1..600 {
+            assert!(curve.instantaneous_forward(i as f64 * 0.05) >= -1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn test_forward_floor_allows_negative_rates() {
+    let family = HypotheticalCurves::new()
+        .with_level(-0.01, 0.01)
+        .with_slope(-0.02, 0.0)
+        .with_min_forward(-0.02)
+        .with_pillars(vec![6, 12, 60, 120]);
+    let mut rng = Rng::new(11);
+    let mut negative = false;
+    for _ in 0..100 {
+        let (level, slope, _, _) = family.parameters(&mut rng);
+        assert!(level + slope >= -0.02);
+        let curve = family.generate(&mut rng);
+        assert_eq!(curve.dates.len(), 5);
+        negative |= curve.discounts.iter().any(|d| *d > 1.0);
+    }
+    assert!(negative);
+}
+
+#[test]
+fn test_surfaces_are_free_of_static_arbitrage() {
+    let family = HypotheticalSurfaces::new();
+    let validator = ArbitrageValidator::new(
+        family.expiries.clone(),
+        (-30..=30).map(|i| i as f64 * 0.05).collect(),
+    );
+    let mut rng = Rng::new(3);
+    for _ in 0..50 {
+        let surface = family.generate(&mut rng);
+        assert!(surface.eta * (1.0 + surface.rho.abs()) <= 2.0 + 1.0e-12);
+        let report = validator.validate(&surface, |t| surface.forward(t));
+        assert!(report.is_arbitrage_free(), "{:?}", surface);
+    }
+}
+
+#[test]
+fn test_same_seed_replays_the_draws() {
+    let (curves, surfaces) = (HypotheticalCurves::new(), HypotheticalSurfaces::new());
+    let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+    for _ in 0..5 {
+        assert_eq!(
+            curves.generate(&mut a).discounts,
+            curves.generate(&mut b).discounts
+        );
+        assert_eq!(
+            surfaces.generate(&mut a).atm_variances,
+            surfaces.generate(&mut b).atm_variances
+        );
+    }
+}
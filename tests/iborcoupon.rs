@@ -0,0 +1,132 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{CashFlow, Coupon, IborLeg};
+use quantlib::indexes::{IborIndex, Index, InterestRateIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{
+    Actual360, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule,
+    TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Euribor = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+fn euribor(months: i64, curve: &Rc<Curve>) -> Euribor {
+    IborIndex::new(
+        "Euribor",
+        Period::new(months, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::clone(curve)),
+    )
+}
+
+// 3M schedule with a two-month front stub from 19th February to 20th April
+// 2020 (61 days).
+fn schedule(rule: DateGenerator) -> Schedule {
+    Schedule::new(
+        Date::new(19, Month::February, 2020),
+        Date::new(19, Month::January, 2021),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        rule,
+        false,
+    )
+}
+
+#[test]
+fn test_index_forecast() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = euribor(3, &curve);
+    assert_eq!(index.name(), "Euribor3M");
+    let fixing_date = Date::new(16, Month::April, 2020);
+    let value_date = index.value_date(fixing_date);
+    assert_eq!(value_date, Date::new(20, Month::April, 2020));
+    assert_eq!(index.fixing_date(value_date), fixing_date);
+    assert_eq!(
+        index.maturity_date(value_date),
+        Date::new(20, Month::July, 2020)
+    );
+    let expected = ((0.02 * 91.0 / 365.0_f64).exp() - 1.0) / (91.0 / 360.0);
+    assert!((index.fixing(fixing_date) - expected).abs() < 1.0e-12);
+}
+
+#[test]
+#[should_panic(expected = "invalid fixing date")]
+fn test_fixing_on_holiday() {
+    let curve = Rc::new(flat_curve(0.02));
+    euribor(3, &curve).add_fixing(Date::new(15, Month::February, 2020), 0.01);
+}
+
+#[test]
+fn test_interpolated_front_stub() {
+    let curve = Rc::new(flat_curve(0.02));
+    let stub_fixing = Date::new(17, Month::February, 2020);
+    let one_month = euribor(1, &curve);
+    let three_months = euribor(3, &curve);
+    one_month.add_fixing(stub_fixing, 0.01);
+    three_months.add_fixing(stub_fixing, 0.02);
+    let (one_month, three_months) = (Rc::new(one_month), Rc::new(three_months));
+
+    let leg = IborLeg::new(
+        schedule(DateGenerator::Backward),
+        Rc::clone(&three_months),
+        Actual360,
+    )
+    .with_notional(1.0e6)
+    .with_spread(0.001)
+    .with_first_stub_interpolation(Rc::clone(&one_month), Rc::clone(&three_months))
+    .build();
+    assert_eq!(leg.len(), 4);
+
+    // the 1M and 3M periods are 29 and 90 days long.
+    let stub = &leg[0];
+    assert_eq!(stub.fixing_date, stub_fixing);
+    assert_eq!(stub.accrual_days(), 61);
+    let expected = 0.01 + 0.01 * (61.0 - 29.0) / (90.0 - 29.0);
+    assert!((stub.index_fixing() - expected).abs() < 1.0e-15);
+    assert!((stub.amount() - 1.0e6 * (expected + 0.001) * 61.0 / 360.0).abs() < 1.0e-8);
+
+    // regular coupons use the leg index only.
+    let regular = &leg[1];
+    assert!(regular.interpolation.is_none());
+    assert!((regular.index_fixing() - three_months.fixing(regular.fixing_date)).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_stubs_are_configured_per_end() {
+    let curve = Rc::new(flat_curve(0.02));
+    let (one_month, three_months) = (Rc::new(euribor(1, &curve)), Rc::new(euribor(3, &curve)));
+
+    // forward generation leaves the stub at the back.
+    let leg = IborLeg::new(
+        schedule(DateGenerator::Forward),
+        Rc::clone(&three_months),
+        Actual360,
+    )
+    .with_first_stub_interpolation(Rc::clone(&one_month), Rc::clone(&three_months))
+    .build();
+    assert!(leg.iter().all(|c| c.interpolation.is_none()));
+
+    let leg = IborLeg::new(
+        schedule(DateGenerator::Forward),
+        Rc::clone(&three_months),
+        Actual360,
+    )
+    .with_last_stub_interpolation(Rc::clone(&one_month), Rc::clone(&three_months))
+    .build();
+    let last = leg.last().unwrap();
+    assert!(last.interpolation.is_some());
+    let f1 = one_month.fixing(last.fixing_date);
+    let f3 = three_months.fixing(last.fixing_date);
+    let fixing = last.index_fixing();
+    assert!(fixing > f1.min(f3) && fixing < f1.max(f3));
+}
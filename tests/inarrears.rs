@@ -0,0 +1,139 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{
+    BlackIborCouponPricer, Coupon, IborCoupon, IborCouponPricer, IborLeg, InArrearsMethod,
+};
+use quantlib::indexes::{IborIndex, InterestRateIndex};
+use quantlib::math::{BilinearInterpolation, Matrix};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::{BlackConstantVol, BlackVarianceSurface};
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, DateGenerator, DayCounter, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+fn index() -> Rc<Index> {
+    Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.03))),
+    ))
+}
+
+/// Semiannual coupons over five years from two years out.
+fn leg(pricer: Option<Rc<dyn IborCouponPricer>>) -> Vec<IborCoupon<Index, Actual360>> {
+    let start = reference_date().add_months(24);
+    let schedule = Schedule::new(
+        start,
+        start.add_months(60),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    );
+    let leg = IborLeg::new(schedule, index(), Actual360)
+        .with_notional(100.0)
+        .with_in_arrears(true);
+    match pricer {
+        Some(p) => leg.with_pricer(p).build(),
+        None => leg.build(),
+    }
+}
+
+fn pricer(vol: f64, method: InArrearsMethod) -> Rc<dyn IborCouponPricer> {
+    let vol = Rc::new(BlackConstantVol::new(SimpleQuote::new(vol)));
+    Rc::new(BlackIborCouponPricer::new(vol, reference_date()).with_method(method))
+}
+
+/// The forward of `coupon`'s fixing, its index accrual and fixing time.
+fn terms(coupon: &IborCoupon<Index, Actual360>) -> (f64, f64, f64) {
+    let value_date = coupon.index.value_date(coupon.fixing_date);
+    let tau = Actual360.year_fraction(
+        value_date,
+        coupon.index.maturity_date(value_date),
+        None,
+        None,
+    );
+    let t = Actual365Fixed.year_fraction(reference_date(), coupon.fixing_date, None, None);
+    (coupon.index_fixing(), tau, t)
+}
+
+#[test]
+fn test_in_arrears_coupons_fix_at_period_end() {
+    let index = index();
+    for coupon in leg(None) {
+        assert!(coupon.in_arrears);
+        assert_eq!(
+            coupon.fixing_date,
+            index.fixing_date(coupon.base.accrual_end_date)
+        );
+        // with no pricer the forward is paid as it is
+        assert_eq!(
+            coupon.rate(),
+            index.forecast_fixing(coupon.fixing_date).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_black_convexity_adjustment() {
+    let sigma: f64 = 0.2;
+    for coupon in leg(Some(pricer(sigma, InArrearsMethod::Black))) {
+        let (f, tau, t) = terms(&coupon);
+        let expected = f + f * f * sigma * sigma * t * tau / (1.0 + f * tau);
+        assert!((coupon.rate() - expected).abs() < 1.0e-15);
+        assert!(coupon.rate() > f);
+    }
+    // no volatility, no adjustment
+    for coupon in leg(Some(pricer(0.0, InArrearsMethod::Black))) {
+        assert_eq!(coupon.rate(), coupon.index_fixing());
+    }
+}
+
+#[test]
+fn test_replication_matches_lognormal_moment() {
+    let sigma: f64 = 0.2;
+    let replication = InArrearsMethod::Replication { intervals: 400 };
+    let black = leg(Some(pricer(sigma, InArrearsMethod::Black)));
+    for (coupon, approx) in leg(Some(pricer(sigma, replication))).iter().zip(&black) {
+        let (f, tau, t) = terms(coupon);
+        // E[L^2] = F^2 exp(sigma^2 t) for a lognormal fixing
+        let exact = f + tau * f * f * ((sigma * sigma * t).exp() - 1.0) / (1.0 + f * tau);
+        assert!((coupon.rate() - exact).abs() < 1.0e-7);
+        assert!(coupon.rate() >= approx.rate());
+    }
+}
+
+#[test]
+fn test_replication_picks_up_the_smile() {
+    // 20% at the money with a symmetric wing of 40% either side
+    let vols = Matrix::from_rows(&[vec![0.4, 0.4], vec![0.2, 0.2], vec![0.4, 0.4]]);
+    let smile: Rc<BlackVarianceSurface<BilinearInterpolation>> = Rc::new(
+        BlackVarianceSurface::new(vec![1.0, 10.0], vec![0.005, 0.03, 0.1], vols),
+    );
+    let method = InArrearsMethod::Replication { intervals: 400 };
+    let on_smile = |method| -> Rc<dyn IborCouponPricer> {
+        Rc::new(BlackIborCouponPricer::new(Rc::clone(&smile), reference_date()).with_method(method))
+    };
+    let replicated = leg(Some(on_smile(method)));
+    let black = leg(Some(on_smile(InArrearsMethod::Black)));
+    let flat = leg(Some(pricer(0.2, method)));
+    for ((r, b), f) in replicated.iter().zip(&black).zip(&flat) {
+        // the wings add to the second moment, Black only sees the forward
+        assert!(r.rate() > f.rate());
+        assert!(r.rate() > b.rate());
+    }
+}
@@ -0,0 +1,140 @@
+extern crate quantlib;
+
+use quantlib::indexes::{Index, ZeroInflationIndex};
+use quantlib::instruments::{InflationSpreadOption, OptionType, ZeroCouponInflationCapFloor};
+use quantlib::pricingengines::{
+    black_formula, BachelierZeroInflationCapFloorEngine, BlackZeroInflationCapFloorEngine,
+    KirkInflationSpreadEngine,
+};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::{YieldTermStructure, ZeroInflationCurve};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Actual365Fixed, Date, DayCounter, Month, WeekendsOnly};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+/// An index fixed at 100 for October 2019, forecast off zero coupon rates
+/// from `rate` at one year rising 10bp a year.
+fn index(index: ZeroInflationIndex, rate: f64) -> Rc<ZeroInflationIndex> {
+    let base = Date::new(1, Month::October, 2019);
+    let dates = (1..=10).map(|k| base.add_months(12 * k)).collect();
+    let rates = (0..10).map(|k| rate + 0.001 * k as f64).collect();
+    let index = index.with_inflation_curve(Rc::new(ZeroInflationCurve::new(base, dates, rates)));
+    index.add_fixing(base, 100.0);
+    Rc::new(index)
+}
+
+fn cap_floor(option_type: OptionType, strike: f64) -> ZeroCouponInflationCapFloor {
+    ZeroCouponInflationCapFloor::new(
+        option_type,
+        index(ZeroInflationIndex::hicpx(), 0.015),
+        1.0e6,
+        strike,
+        reference_date(),
+        reference_date().add_months(60),
+        3,
+    )
+}
+
+fn vol(sigma: f64) -> Rc<BlackConstantVol> {
+    Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma)))
+}
+
+fn discount() -> Rc<Curve> {
+    Rc::new(flat_curve(0.02))
+}
+
+#[test]
+fn test_index_ratio_off_the_inflation_curve() {
+    let cap = cap_floor(OptionType::Call, 0.02);
+    assert_eq!(cap.base_fixing_date(), Date::new(1, Month::October, 2019));
+    assert_eq!(cap.fixing_date(), Date::new(1, Month::October, 2024));
+    assert_eq!(cap.years(), 5.0);
+    assert!((cap.strike_ratio() - 1.02f64.powi(5)).abs() < 1.0e-15);
+    // five years out the curve is at 1.9%, over Actual/365 from the base
+    let t = Actual365Fixed.year_fraction(cap.base_fixing_date(), cap.fixing_date(), None, None);
+    assert!((cap.index_ratio().unwrap() - 1.019f64.powf(t)).abs() < 1.0e-14);
+    let index = ZeroInflationIndex::hicpx();
+    index.add_fixing(Date::new(1, Month::November, 2019), 100.4);
+    // published months are not forecast
+    assert_eq!(
+        index.forecast_fixing(Date::new(15, Month::November, 2019)),
+        Ok(100.4)
+    );
+    // and the others need a curve
+    assert_eq!(
+        index
+            .forecast_fixing(Date::new(15, Month::December, 2019))
+            .unwrap_err()
+            .to_string(),
+        "inflation curve of HICPx not set"
+    );
+    assert_eq!(index.name(), "HICPx");
+}
+
+#[test]
+fn test_black_cap_floor_parity() {
+    let engine = BlackZeroInflationCapFloorEngine::new(discount(), vol(0.02));
+    let d = flat_curve(0.02).discount(reference_date().add_months(60), true);
+    for strike in &[0.01, 0.019, 0.03] {
+        let cap = cap_floor(OptionType::Call, *strike);
+        let floor = cap_floor(OptionType::Put, *strike);
+        let forward = 1.0e6 * d * (cap.index_ratio().unwrap() - cap.strike_ratio());
+        assert!((engine.npv(&cap) - engine.npv(&floor) - forward).abs() < 1.0e-8);
+        assert!(engine.npv(&cap) > 0.0 && engine.npv(&floor) > 0.0);
+    }
+    // no volatility, only the intrinsic value
+    let cap = cap_floor(OptionType::Call, 0.01);
+    let intrinsic = BlackZeroInflationCapFloorEngine::new(discount(), vol(0.0)).npv(&cap);
+    assert!((intrinsic - d * cap.payoff(cap.index_ratio().unwrap())).abs() < 1.0e-8);
+}
+
+#[test]
+fn test_bachelier_matches_black_at_the_money() {
+    let cap = cap_floor(OptionType::Call, 0.019);
+    let floor = cap_floor(OptionType::Put, 0.019);
+    let forward = cap.index_ratio().unwrap();
+    // equal for small volatilities with the normal volatility at F sigma
+    let sigma = 0.005;
+    let black = BlackZeroInflationCapFloorEngine::new(discount(), vol(sigma));
+    let bachelier = BachelierZeroInflationCapFloorEngine::new(discount(), vol(sigma * forward));
+    assert!((black.npv(&cap) - bachelier.npv(&cap)).abs() / black.npv(&cap) < 1.0e-3);
+    let d = flat_curve(0.02).discount(reference_date().add_months(60), true);
+    let parity = 1.0e6 * d * (forward - cap.strike_ratio());
+    assert!((bachelier.npv(&cap) - bachelier.npv(&floor) - parity).abs() < 1.0e-8);
+}
+
+#[test]
+fn test_kirk_spread_options() {
+    let spread = |option_type, strike| {
+        InflationSpreadOption::new(
+            option_type,
+            index(ZeroInflationIndex::hicpx(), 0.015),
+            index(ZeroInflationIndex::french_cpi(), 0.013),
+            1.0e6,
+            strike,
+            reference_date(),
+            reference_date().add_months(60),
+            3,
+        )
+    };
+    let call = spread(OptionType::Call, 0.01);
+    let (f1, f2) = call.index_ratios().unwrap();
+    assert!(f1 > f2);
+    let engine = |s2: f64, rho: f64| {
+        KirkInflationSpreadEngine::new(discount(), vol(0.02), vol(s2), SimpleQuote::new(rho))
+    };
+    let d = flat_curve(0.02).discount(reference_date().add_months(60), true);
+    let t = flat_curve(0.02).time_from_reference(call.fixing_date());
+    // a deterministic second index leaves a Black option on the first
+    let expected = 1.0e6 * black_formula(OptionType::Call, f2 + 0.01, f1, 0.02 * t.sqrt(), d, 0.0);
+    assert!((engine(0.0, 0.5).npv(&call) - expected).abs() < 1.0e-8);
+    // call put parity, and correlated indexes spread less
+    let put = spread(OptionType::Put, 0.01);
+    let parity = 1.0e6 * d * (f1 - f2 - 0.01);
+    assert!((engine(0.015, 0.6).npv(&call) - engine(0.015, 0.6).npv(&put) - parity).abs() < 1.0e-8);
+    assert!(engine(0.015, 0.9).npv(&call) < engine(0.015, 0.0).npv(&call));
+}
@@ -0,0 +1,191 @@
+extern crate quantlib;
+
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{Compounding, InterestRate};
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{Actual360, Actual365Fixed, Date, DayCounter, Frequency, Month};
+
+const COMPOUNDINGS: [Compounding; 5] = [
+    Compounding::Simple,
+    Compounding::Compounded,
+    Compounding::Continuous,
+    Compounding::SimpleThenCompounded,
+    Compounding::CompoundedThenSimple,
+];
+
+const FREQUENCIES: [Frequency; 7] = [
+    Frequency::Annual,
+    Frequency::Semiannual,
+    Frequency::EveryFourthMonth,
+    Frequency::Quarterly,
+    Frequency::Bimonthly,
+    Frequency::Monthly,
+    Frequency::Daily,
+];
+
+#[test]
+fn test_equivalent_rates_round_trip_across_conventions() {
+    for &rate in &[-0.01, 0.0001, 0.03, 0.15] {
+        for &t in &[0.05, 0.25, 0.5, 1.0, 2.5, 10.0] {
+            for &c1 in &COMPOUNDINGS {
+                for &f1 in &FREQUENCIES {
+                    let r1 = InterestRate::new(rate, Actual365Fixed, c1, f1);
+                    for &c2 in &COMPOUNDINGS {
+                        for &f2 in &FREQUENCIES {
+                            let r2 = r1.equivalent_rate(c2, f2, t);
+                            let back = r2.equivalent_rate(c1, f1, t);
+                            let cf1 = r1.compound_factor_with_time(t);
+                            let cf2 = r2.compound_factor_with_time(t);
+                            assert!(
+                                (cf2 / cf1 - 1.0).abs() < 1.0e-12,
+                                "{} to {} over {}",
+                                r1,
+                                r2,
+                                t
+                            );
+                            assert!(
+                                (back.rate - rate).abs() < 1.0e-10,
+                                "{} back to {}",
+                                r2,
+                                back
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_known_conversions() {
+    let r = InterestRate::new(
+        0.05,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+    );
+    let annual = r.equivalent_rate(Compounding::Compounded, Frequency::Annual, 1.0);
+    assert!((annual.rate - (0.05_f64.exp() - 1.0)).abs() < 1.0e-14);
+    let semiannual = r.equivalent_rate(Compounding::Compounded, Frequency::Semiannual, 3.0);
+    assert!((semiannual.rate - 2.0 * (0.025_f64.exp() - 1.0)).abs() < 1.0e-14);
+    let simple = r.equivalent_rate(Compounding::Simple, Frequency::Annual, 0.5);
+    assert!((simple.rate - (0.025_f64.exp() - 1.0) / 0.5).abs() < 1.0e-14);
+    assert!((r.discount_factor_with_time(2.0) - (-0.1_f64).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_odd_periods_use_the_reference_period() {
+    let r = InterestRate::new(0.04, Actual360, Compounding::Simple, Frequency::Annual);
+    let start = Date::new(15, Month::January, 2020);
+    let end = Date::new(15, Month::April, 2020);
+    let t = Actual360.year_fraction(start, end, None, None);
+    assert!((r.discount_factor(start, end) - 1.0 / (1.0 + 0.04 * t)).abs() < 1.0e-15);
+    assert!(
+        (r.discount_factor_with_ref(start, end, Some(start), Some(end))
+            * r.compound_factor(start, end)
+            - 1.0)
+            .abs()
+            < 1.0e-15
+    );
+
+    // converting to another day counter keeps the factor between the dates.
+    let act365 = r.equivalent_rate_with_dates(
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        start,
+        end,
+        None,
+        None,
+    );
+    assert!((act365.compound_factor(start, end) - r.compound_factor(start, end)).abs() < 1.0e-14);
+    assert!((act365.rate - (1.0 + 0.04 * t).ln() * 365.0 / 91.0).abs() < 1.0e-14);
+}
+
+#[test]
+fn test_display_shows_the_conventions() {
+    let r = InterestRate::new(
+        0.05,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+    );
+    assert_eq!(
+        r.to_string(),
+        "5.000000 % Actual/365 (Fixed) continuous compounding"
+    );
+    let r = InterestRate::new(
+        0.0325,
+        Actual360,
+        Compounding::Compounded,
+        Frequency::Semiannual,
+    );
+    assert_eq!(
+        r.to_string(),
+        "3.250000 % Actual/360 Semiannual compounding"
+    );
+    assert_eq!(r.frequency(), Frequency::Semiannual);
+    let r = InterestRate::new(0.01, Actual360, Compounding::Simple, Frequency::NoFrequency);
+    assert_eq!(r.to_string(), "1.000000 % Actual/360 simple compounding");
+    assert_eq!(r.frequency(), Frequency::NoFrequency);
+    let r = InterestRate::new(
+        0.02,
+        Actual360,
+        Compounding::SimpleThenCompounded,
+        Frequency::Quarterly,
+    );
+    assert_eq!(
+        r.to_string(),
+        "2.000000 % Actual/360 simple compounding up to 3 months, then Quarterly compounding"
+    );
+}
+
+#[test]
+fn test_continuous_simple_and_annual_shortcuts() {
+    let r = InterestRate::continuous(0.04, Actual365Fixed);
+    assert_eq!(r.compounding, Compounding::Continuous);
+    let t: f64 = 2.0;
+    let growth = (0.04 * t).exp();
+    assert!((r.to_simple(t).rate - (growth - 1.0) / t).abs() < 1.0e-14);
+    assert!((r.to_annual(t).rate - (0.04_f64.exp() - 1.0)).abs() < 1.0e-14);
+    assert!((r.to_annual(t).to_continuous(t).rate - 0.04).abs() < 1.0e-14);
+
+    let mm = InterestRate::simple(0.03, Actual360);
+    assert!((mm.to_continuous(0.25).rate - (1.0 + 0.03 * 0.25_f64).ln() / 0.25).abs() < 1.0e-14);
+    let annual = InterestRate::annual(0.03, Actual360);
+    assert_eq!(annual.frequency(), Frequency::Annual);
+    assert!((annual.compound_factor_with_time(3.0) - 1.03_f64.powi(3)).abs() < 1.0e-14);
+}
+
+#[test]
+fn test_curve_zero_rates_in_any_convention() {
+    let mut curve = flat_curve(0.02);
+    let date = Date::new(15, Month::January, 2023);
+    let t = Actual365Fixed.year_fraction(curve.reference_date(), date, None, None);
+    let discount = curve.discount(date, true);
+
+    let continuous = curve.continuous_zero_rate(date, true);
+    assert!((continuous.rate - 0.02).abs() < 1.0e-12);
+    let simple = curve.simple_zero_rate(date, true);
+    assert!((simple.rate - (1.0 / discount - 1.0) / t).abs() < 1.0e-12);
+    let annual = curve.annual_zero_rate(date, true);
+    assert!((annual.rate - (0.02_f64.exp() - 1.0)).abs() < 1.0e-12);
+
+    // the conversions agree with converting the continuous rate.
+    assert!((continuous.to_simple(t).rate - simple.rate).abs() < 1.0e-12);
+    assert!(
+        (curve
+            .zero_rate(
+                date,
+                Actual365Fixed,
+                Compounding::Simple,
+                Frequency::Annual,
+                true
+            )
+            .rate
+            - simple.rate)
+            .abs()
+            < 1.0e-12
+    );
+}
@@ -0,0 +1,281 @@
+extern crate quantlib;
+
+use quantlib::math::{
+    CubicNaturalSpline, Interpolation, Interpolation1D, Interpolator, LinearInterpolation,
+    LogLinearInterpolation, MonotoneConvex,
+};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::InterpolatedDiscountCurve;
+use quantlib::time::{Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+fn curve(interpolation: Interpolation) -> InterpolatedDiscountCurve<WeekendsOnly> {
+    let dates = vec![
+        Date::new(15, Month::January, 2020),
+        Date::new(15, Month::July, 2020),
+        Date::new(15, Month::January, 2021),
+        Date::new(15, Month::January, 2023),
+        Date::new(15, Month::January, 2025),
+        Date::new(15, Month::January, 2030),
+    ];
+    let discounts = vec![1.0, 0.992, 0.981, 0.935, 0.88, 0.74];
+    InterpolatedDiscountCurve::new(
+        dates,
+        discounts,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+}
+
+#[test]
+fn test_interpolators_go_through_the_nodes() {
+    let xs = vec![0.0, 0.5, 1.0, 2.0, 5.0];
+    let ys = vec![1.0, 0.9, 0.85, 0.7, 0.4];
+    for &kind in &[
+        Interpolation::Linear,
+        Interpolation::LogLinear,
+        Interpolation::NaturalCubic,
+        Interpolation::MonotonicCubic,
+        Interpolation::ConvexMonotone,
+    ] {
+        let f = Interpolator::new(kind, xs.clone(), ys.clone());
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!((f.value(*x) - y).abs() < 1.0e-14, "{:?}", kind);
+        }
+        // the derivative agrees with finite differences inside segments.
+        let h = 1.0e-6;
+        let x = 1.3;
+        let fd = (f.value(x + h) - f.value(x - h)) / (2.0 * h);
+        assert!((f.derivative(x) - fd).abs() < 1.0e-7, "{:?}", kind);
+    }
+}
+
+#[test]
+fn test_natural_cubic_spline_reproduces_lines_and_is_smooth() {
+    let xs = vec![0.0, 1.0, 3.0, 4.0, 7.0];
+    let line: Vec<f64> = xs.iter().map(|x| 2.0 - 0.5 * x).collect();
+    let f = Interpolator::new(Interpolation::NaturalCubic, xs.clone(), line);
+    assert!((f.value(2.2) - (2.0 - 1.1)).abs() < 1.0e-14);
+
+    let ys = vec![0.0, 1.0, 0.0, 2.0, 1.0];
+    let f = Interpolator::new(Interpolation::NaturalCubic, xs, ys);
+    let h = 1.0e-6;
+    for &x in &[1.0, 3.0, 4.0] {
+        assert!((f.derivative(x - h) - f.derivative(x + h)).abs() < 1.0e-5);
+    }
+    // zero curvature at the ends.
+    let d2 = |x: f64| (f.derivative(x + h) - f.derivative(x - h)) / (2.0 * h);
+    assert!(d2(h).abs() < 1.0e-4);
+    assert!(d2(7.0 - h).abs() < 1.0e-4);
+}
+
+#[test]
+fn test_curve_exposes_its_nodes() {
+    let c = curve(Interpolation::LogLinear);
+    let nodes = c.nodes();
+    assert_eq!(nodes.len(), 6);
+    assert_eq!(nodes[0], (Date::new(15, Month::January, 2020), 1.0));
+    for (date, discount) in &nodes {
+        assert!((c.discount(*date) - discount).abs() < 1.0e-14);
+    }
+    assert_eq!(c.reference_date, nodes[0].0);
+}
+
+#[test]
+fn test_reinterpolation_keeps_the_nodes() {
+    let loglinear = curve(Interpolation::LogLinear);
+    let cubic = loglinear.with_interpolation(Interpolation::NaturalCubic);
+    assert_eq!(cubic.nodes(), loglinear.nodes());
+    assert_eq!(cubic.interpolation, Interpolation::NaturalCubic);
+    for (date, discount) in cubic.nodes() {
+        assert!((cubic.discount(date) - discount).abs() < 1.0e-14);
+    }
+    let between = Date::new(15, Month::January, 2027);
+    assert!((cubic.discount(between) - loglinear.discount(between)).abs() > 1.0e-5);
+
+    // log-linear discounts give flat forwards within each segment.
+    let t1 = loglinear.time_from_reference(Date::new(15, Month::February, 2025));
+    let t2 = loglinear.time_from_reference(Date::new(15, Month::December, 2029));
+    assert!(
+        (loglinear.instantaneous_forward(t1) - loglinear.instantaneous_forward(t2)).abs() < 1.0e-12
+    );
+}
+
+#[test]
+fn test_term_structure_discounts_like_the_curve() {
+    let c = curve(Interpolation::NaturalCubic);
+    let ts = c.term_structure();
+    for &t in &[0.1, 0.7, 2.5, 9.0, 15.0] {
+        assert!((ts.discount_with_time(t, true) - c.discount_with_time(t)).abs() < 1.0e-15);
+    }
+    // flat forward beyond the last node.
+    let f = c.instantaneous_forward(12.0);
+    assert!((c.discount_with_time(12.0) / c.discount_with_time(11.0) - (-f).exp()).abs() < 1.0e-14);
+}
+
+fn steep_curve(interpolation: Interpolation) -> InterpolatedDiscountCurve<WeekendsOnly> {
+    // a sharp drop followed by almost flat discounts, where a natural
+    // spline overshoots.
+    let dates = vec![
+        Date::new(15, Month::January, 2020),
+        Date::new(15, Month::January, 2021),
+        Date::new(15, Month::January, 2022),
+        Date::new(15, Month::January, 2023),
+        Date::new(15, Month::January, 2030),
+    ];
+    let discounts = vec![1.0, 0.9, 0.899, 0.898, 0.89];
+    InterpolatedDiscountCurve::new(
+        dates,
+        discounts,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+}
+
+fn min_forward(c: &InterpolatedDiscountCurve<WeekendsOnly>) -> f64 {
+    (1..1000)
+        .map(|i| c.instantaneous_forward(i as f64 * 0.01))
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[test]
+fn test_monotone_schemes_avoid_negative_forwards() {
+    let natural = steep_curve(Interpolation::NaturalCubic);
+    assert!(min_forward(&natural) < 0.0);
+    for &kind in &[Interpolation::MonotonicCubic, Interpolation::ConvexMonotone] {
+        let c = natural.with_interpolation(kind);
+        assert!(min_forward(&c) >= 0.0, "{:?}", kind);
+        for (date, discount) in c.nodes() {
+            assert!((c.discount(date) - discount).abs() < 1.0e-14, "{:?}", kind);
+        }
+    }
+}
+
+#[test]
+fn test_convex_monotone_forwards_are_continuous_and_keep_averages() {
+    let c = curve(Interpolation::ConvexMonotone);
+    let h = 1.0e-9;
+    for &t in &c.times[1..c.times.len() - 1] {
+        let jump = c.instantaneous_forward(t + h) - c.instantaneous_forward(t - h);
+        assert!(jump.abs() < 1.0e-6);
+    }
+    // the forwards integrate to the node discounts over each segment.
+    for i in 0..c.times.len() - 1 {
+        let (t1, t2) = (c.times[i], c.times[i + 1]);
+        let steps = 2000;
+        let dt = (t2 - t1) / steps as f64;
+        let integral: f64 = (0..steps)
+            .map(|k| c.instantaneous_forward(t1 + (k as f64 + 0.5) * dt) * dt)
+            .sum();
+        let average = (c.discounts[i] / c.discounts[i + 1]).ln();
+        assert!((integral - average).abs() < 1.0e-6);
+    }
+
+    // flat discrete forwards stay flat.
+    let xs = vec![0.0, 1.0, 2.5, 4.0];
+    let ys: Vec<f64> = xs.iter().map(|t: &f64| (-0.03 * t).exp()).collect();
+    let f = Interpolator::new(Interpolation::ConvexMonotone, xs, ys);
+    assert!((f.value(3.3) - (-0.03 * 3.3_f64).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_kernel_interpolation_goes_through_sparse_nodes() {
+    let xs = vec![0.0, 0.3, 2.0, 2.2, 7.0];
+    let ys = vec![0.01, 0.015, 0.022, 0.021, 0.03];
+    let f = Interpolator::new(
+        Interpolation::Kernel { bandwidth: 1.5 },
+        xs.clone(),
+        ys.clone(),
+    );
+    for (x, y) in xs.iter().zip(&ys) {
+        assert!((f.value(*x) - y).abs() < 1.0e-10);
+    }
+    let h = 1.0e-6;
+    for &x in &[0.1, 1.0, 4.5] {
+        let fd = (f.value(x + h) - f.value(x - h)) / (2.0 * h);
+        assert!((f.derivative(x) - fd).abs() < 1.0e-6);
+    }
+
+    // a wider bandwidth still goes through the nodes on a discount curve.
+    let c = curve(Interpolation::Kernel { bandwidth: 3.0 });
+    for (date, discount) in c.nodes() {
+        assert!((c.discount(date) - discount).abs() < 1.0e-8);
+    }
+}
+
+#[test]
+fn test_least_squares_spline_smooths_noisy_data() {
+    let xs: Vec<f64> = (0..21).map(|i| i as f64 * 0.5).collect();
+    let noise = [1.0, -1.0, 0.5, -0.5, 0.0];
+    let line = |x: f64| 0.02 + 0.001 * x;
+    let ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| line(*x) + 1.0e-4 * noise[i % noise.len()])
+        .collect();
+    let f = Interpolator::new(
+        Interpolation::LeastSquaresSpline { knots: 4 },
+        xs.clone(),
+        ys.clone(),
+    );
+    let fit_error: f64 = xs
+        .iter()
+        .map(|x| (f.value(*x) - line(*x)).abs())
+        .fold(0.0, f64::max);
+    assert!(fit_error < 1.0e-4);
+    assert!((f.derivative(5.0) - 0.001).abs() < 1.0e-4);
+
+    // a line is reproduced exactly by any knot count.
+    let exact: Vec<f64> = xs.iter().map(|x| line(*x)).collect();
+    let f = Interpolator::new(
+        Interpolation::LeastSquaresSpline { knots: 3 },
+        xs.clone(),
+        exact,
+    );
+    assert!((f.value(3.7) - line(3.7)).abs() < 1.0e-12);
+}
+
+/// Zero rate to `t` from discount factors interpolated with `I`.
+fn zero_rate<I: Interpolation1D>(xs: &[f64], discounts: &[f64], t: f64) -> f64 {
+    let f = I::new(xs.to_vec(), discounts.to_vec());
+    -f.value(t).ln() / t
+}
+
+#[test]
+fn test_schemes_are_interchangeable_behind_the_trait() {
+    let xs = vec![0.0, 0.5, 1.0, 2.0, 5.0];
+    let ys = vec![1.0, 0.99, 0.978, 0.95, 0.86];
+    let (linear, loglinear, spline, convex) = (
+        LinearInterpolation::new(xs.clone(), ys.clone()),
+        LogLinearInterpolation::new(xs.clone(), ys.clone()),
+        CubicNaturalSpline::new(xs.clone(), ys.clone()),
+        MonotoneConvex::new(xs.clone(), ys.clone()),
+    );
+    let schemes: Vec<(&dyn Interpolation1D, Interpolation)> = vec![
+        (&linear, Interpolation::Linear),
+        (&loglinear, Interpolation::LogLinear),
+        (&spline, Interpolation::NaturalCubic),
+        (&convex, Interpolation::ConvexMonotone),
+    ];
+    for (scheme, kind) in schemes {
+        assert_eq!(scheme.xs(), &xs[..]);
+        assert_eq!(scheme.ys(), &ys[..]);
+        let f = Interpolator::new(kind, xs.clone(), ys.clone());
+        for &x in &[0.2, 0.75, 1.6, 3.5, 6.0] {
+            assert_eq!(scheme.value(x), f.value(x), "{:?}", kind);
+            assert_eq!(scheme.derivative(x), f.derivative(x), "{:?}", kind);
+        }
+    }
+}
+
+#[test]
+fn test_generic_code_picks_the_scheme() {
+    let xs = vec![0.0, 1.0, 3.0];
+    let ys = vec![1.0, 0.98, 0.92];
+    // log-linear discounts keep the average forward of the segment
+    let forward = (0.98_f64 / 0.92).ln() / 2.0;
+    let z = zero_rate::<LogLinearInterpolation>(&xs, &ys, 2.0);
+    assert!((z - (forward - 0.98_f64.ln()) / 2.0).abs() < 1.0e-15);
+    assert!((zero_rate::<LinearInterpolation>(&xs, &ys, 2.0) - z).abs() > 1.0e-5);
+}
@@ -0,0 +1,85 @@
+extern crate quantlib;
+
+use quantlib::math::{BicubicInterpolation, BilinearInterpolation, Interpolation2D, Matrix};
+use quantlib::termstructures::{BlackVarianceSurface, BlackVolTermStructure};
+
+fn grid(f: &dyn Fn(f64, f64) -> f64) -> (Vec<f64>, Vec<f64>, Matrix) {
+    let xs = vec![0.0, 0.5, 1.0, 2.0, 3.5];
+    let ys = vec![-1.0, 0.0, 0.5, 2.0];
+    let rows: Vec<Vec<f64>> = ys
+        .iter()
+        .map(|y| xs.iter().map(|x| f(*x, *y)).collect())
+        .collect();
+    (xs, ys, Matrix::from_rows(&rows))
+}
+
+#[test]
+fn test_both_schemes_go_through_the_grid() {
+    let f = |x: f64, y: f64| (x * 0.7).sin() + y * y * 0.3;
+    let (xs, ys, zs) = grid(&f);
+    let bilinear = BilinearInterpolation::new(xs.clone(), ys.clone(), zs.clone());
+    let bicubic = BicubicInterpolation::new(xs.clone(), ys.clone(), zs.clone());
+    for (j, y) in ys.iter().enumerate() {
+        for (i, x) in xs.iter().enumerate() {
+            assert!((bilinear.value(*x, *y) - zs[(j, i)]).abs() < 1.0e-14);
+            assert!((bicubic.value(*x, *y) - zs[(j, i)]).abs() < 1.0e-14);
+        }
+    }
+    assert_eq!(bicubic.xs(), &xs[..]);
+    assert_eq!(bilinear.ys(), &ys[..]);
+}
+
+#[test]
+fn test_bilinear_is_exact_on_bilinear_functions() {
+    let f = |x: f64, y: f64| 1.0 + 2.0 * x - y + 0.5 * x * y;
+    let (xs, ys, zs) = grid(&f);
+    let bilinear = BilinearInterpolation::new(xs, ys, zs);
+    for &(x, y) in &[(0.3, -0.4), (1.7, 1.2), (3.0, 0.1), (4.0, 2.5)] {
+        assert!((bilinear.value(x, y) - f(x, y)).abs() < 1.0e-13);
+    }
+}
+
+#[test]
+fn test_bicubic_is_closer_on_smooth_functions() {
+    let f = |x: f64, y: f64| (x * 0.7).sin() * (0.4 * y).cos();
+    let (xs, ys, zs) = grid(&f);
+    let bilinear = BilinearInterpolation::new(xs.clone(), ys.clone(), zs.clone());
+    let bicubic = BicubicInterpolation::new(xs, ys, zs);
+    let points = [(0.25, -0.5), (1.4, 0.25), (2.7, 1.1), (0.8, 1.6)];
+    let error = |g: &dyn Interpolation2D| {
+        points
+            .iter()
+            .map(|(x, y)| (g.value(*x, *y) - f(*x, *y)).abs())
+            .fold(0.0, f64::max)
+    };
+    assert!(error(&bicubic) < error(&bilinear));
+}
+
+#[test]
+fn test_variance_surface_interpolates_total_variance() {
+    let expiries = vec![0.5, 1.0, 2.0];
+    let strikes = vec![80.0, 100.0, 120.0];
+    let vols = Matrix::from_rows(&[
+        vec![0.30, 0.28, 0.26],
+        vec![0.25, 0.24, 0.23],
+        vec![0.27, 0.25, 0.24],
+    ]);
+    let surface: BlackVarianceSurface<BilinearInterpolation> =
+        BlackVarianceSurface::new(expiries, strikes, vols);
+    assert!((surface.black_vol(1.0, 100.0) - 0.24).abs() < 1.0e-14);
+
+    // linear in variance between expiries and from zero before the first.
+    let v = 0.5 * (0.25 * 0.25 * 0.5 + 0.24 * 0.24 * 1.0);
+    assert!((surface.black_variance(0.75, 100.0) - v).abs() < 1.0e-14);
+    assert!((surface.black_vol(0.25, 100.0) - 0.25).abs() < 1.0e-14);
+    // flat outside the grid.
+    assert!((surface.black_vol(1.0, 60.0) - 0.28).abs() < 1.0e-14);
+    assert!((surface.black_vol(5.0, 120.0) - 0.24).abs() < 1.0e-14);
+
+    let smooth: BlackVarianceSurface<BicubicInterpolation> = BlackVarianceSurface::new(
+        surface.expiries.clone(),
+        surface.strikes.clone(),
+        surface.vols.clone(),
+    );
+    assert!((smooth.black_vol(2.0, 80.0) - 0.26).abs() < 1.0e-14);
+}
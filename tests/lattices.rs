@@ -0,0 +1,181 @@
+extern crate quantlib;
+
+use quantlib::instruments::{EarlyExerciseOption, Exercise, OptionType, VanillaOption};
+use quantlib::methods::lattices::{BinomialTree, BinomialTreeType};
+use quantlib::pricingengines::{AnalyticEuropeanEngine, BinomialVanillaEngine};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Date, WeekendsOnly};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+const TREES: [BinomialTreeType; 3] = [
+    BinomialTreeType::CoxRossRubinstein,
+    BinomialTreeType::JarrowRudd,
+    BinomialTreeType::Tian,
+];
+
+fn expiry() -> Date {
+    reference_date().add_days(365)
+}
+
+fn engine(
+    tree_type: BinomialTreeType,
+    r: f64,
+    q: f64,
+    sigma: f64,
+    steps: usize,
+) -> BinomialVanillaEngine<Curve, Curve, BlackConstantVol> {
+    BinomialVanillaEngine::new(
+        Rc::new(flat_curve(r)),
+        Rc::new(flat_curve(q)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma))),
+        tree_type,
+        steps,
+    )
+}
+
+fn analytic(option: &VanillaOption, r: f64, q: f64, sigma: f64, spot: f64) -> f64 {
+    AnalyticEuropeanEngine::new(
+        Rc::new(flat_curve(r)),
+        Rc::new(flat_curve(q)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma))),
+    )
+    .npv(option, spot)
+}
+
+#[test]
+fn test_trees_match_the_lognormal_step() {
+    let (carry, sigma, dt): (f64, f64, f64) = (0.03, 0.25, 0.01);
+    for tree_type in &TREES {
+        let tree = BinomialTree::new(*tree_type, 100.0, carry, sigma, 1.0, 100);
+        assert!((tree.dt - dt).abs() < 1.0e-15);
+        let p = tree.probability;
+        let mean = p * tree.up + (1.0 - p) * tree.down;
+        let second = p * tree.up * tree.up + (1.0 - p) * tree.down * tree.down;
+        let growth = (carry * dt).exp();
+        let tolerance = match tree_type {
+            BinomialTreeType::JarrowRudd => 1.0e-5,
+            _ => 1.0e-14,
+        };
+        assert!((mean - growth).abs() < tolerance);
+        let expected = growth * growth * (sigma * sigma * dt).exp();
+        assert!((second - expected).abs() < 1.0e-4);
+        assert_eq!(tree.underlying(0, 0), 100.0);
+        assert!((tree.underlying(2, 1) - 100.0 * tree.up * tree.down).abs() < 1.0e-12);
+    }
+    // Cox-Ross-Rubinstein recombines around the spot, Tian matches the
+    // second moment exactly
+    let crr = BinomialTree::new(TREES[0], 100.0, carry, sigma, 1.0, 100);
+    assert!((crr.up * crr.down - 1.0).abs() < 1.0e-15);
+    assert_eq!(
+        BinomialTree::new(TREES[1], 100.0, carry, sigma, 1.0, 100).probability,
+        0.5
+    );
+    let tian = BinomialTree::new(TREES[2], 100.0, carry, sigma, 1.0, 100);
+    let p = tian.probability;
+    let second = p * tian.up * tian.up + (1.0 - p) * tian.down * tian.down;
+    let growth: f64 = (carry * dt).exp();
+    assert!((second - growth * growth * (sigma * sigma * dt).exp()).abs() < 1.0e-14);
+}
+
+#[test]
+fn test_european_options_converge_to_black_scholes() {
+    let (r, q, sigma) = (0.05, 0.02, 0.3);
+    for option_type in &[OptionType::Call, OptionType::Put] {
+        let option = VanillaOption::new(*option_type, 105.0, expiry());
+        let expected = analytic(&option, r, q, sigma, 100.0);
+        for tree_type in &TREES {
+            let value = engine(*tree_type, r, q, sigma, 800).npv(&option.into(), 100.0);
+            assert!((value - expected).abs() < 1.0e-2, "{:?}", tree_type);
+        }
+    }
+}
+
+#[test]
+fn test_american_options() {
+    // the converged tree value of Longstaff and Schwartz's first case
+    let american = EarlyExerciseOption::new(
+        OptionType::Put,
+        40.0,
+        Exercise::American {
+            earliest: reference_date(),
+            latest: expiry(),
+        },
+    );
+    assert!(american.exercise.is_early());
+    for tree_type in &TREES {
+        let value = engine(*tree_type, 0.06, 0.0, 0.2, 1000).npv(&american, 36.0);
+        assert!((value - 4.4867).abs() < 2.0e-3, "{:?}", tree_type);
+    }
+    // the early exercise premium over Black-Scholes' 3.844
+    let european = VanillaOption::new(OptionType::Put, 40.0, expiry());
+    assert!((analytic(&european, 0.06, 0.0, 0.2, 36.0) - 3.8443).abs() < 1.0e-4);
+    // a call without dividends is never exercised early
+    let call = EarlyExerciseOption {
+        option_type: OptionType::Call,
+        ..american
+    };
+    let tree = engine(TREES[0], 0.06, 0.0, 0.2, 500);
+    let european_call =
+        EarlyExerciseOption::from(VanillaOption::new(OptionType::Call, 40.0, expiry()));
+    assert!((tree.npv(&call, 36.0) - tree.npv(&european_call, 36.0)).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_bermudan_options_lie_between() {
+    let tree = engine(BinomialTreeType::CoxRossRubinstein, 0.06, 0.0, 0.2, 600);
+    let put = |exercise| EarlyExerciseOption::new(OptionType::Put, 40.0, exercise);
+    let european = tree.npv(&put(Exercise::European(expiry())), 36.0);
+    let american = tree.npv(
+        &put(Exercise::American {
+            earliest: reference_date(),
+            latest: expiry(),
+        }),
+        36.0,
+    );
+    let quarterly = Exercise::bermudan(
+        (1..=4)
+            .map(|k| reference_date().add_months(3 * k))
+            .collect(),
+    );
+    assert_eq!(quarterly.last_date(), reference_date().add_months(12));
+    let bermudan = tree.npv(&put(quarterly), 36.0);
+    assert!(european < bermudan && bermudan < american);
+    // a single date at expiry is European
+    let at_expiry = tree.npv(&put(Exercise::bermudan(vec![expiry()])), 36.0);
+    assert_eq!(at_expiry, european);
+    // exercise starting late is worth less than from today
+    let late = tree.npv(
+        &put(Exercise::American {
+            earliest: reference_date().add_months(6),
+            latest: expiry(),
+        }),
+        36.0,
+    );
+    assert!(european < late && late < american);
+}
+
+#[test]
+fn test_errors_shrink_with_the_steps() {
+    let (r, q, sigma) = (0.05, 0.02, 0.3);
+    let option = VanillaOption::new(OptionType::Put, 100.0, expiry());
+    let expected = analytic(&option, r, q, sigma, 100.0);
+    for tree_type in &TREES {
+        let errors: Vec<f64> = [25, 100, 400, 1600]
+            .iter()
+            .map(|n| {
+                (engine(*tree_type, r, q, sigma, *n).npv(&option.into(), 100.0) - expected).abs()
+            })
+            .collect();
+        // of first order, if oscillating: four times the steps take off
+        // most of the error
+        for pair in errors.windows(2) {
+            assert!(pair[1] < 0.6 * pair[0], "{:?}: {:?}", tree_type, errors);
+        }
+        assert!(errors[3] < 2.5e-3, "{:?}", tree_type);
+    }
+}
@@ -0,0 +1,124 @@
+extern crate quantlib;
+
+use quantlib::patterns::{ChangeFlag, LazyObject};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{Compounding, FlatForward};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual365Fixed, Calendar, Date, Frequency, Month, WeekendsOnly};
+
+/// A zero coupon bond caching its value on a curve.
+struct ZeroBond {
+    curve: FlatForward<WeekendsOnly>,
+    maturity: Date,
+    lazy: LazyObject,
+    value: f64,
+    calculations: usize,
+}
+
+impl ZeroBond {
+    fn new(rate: f64) -> ZeroBond {
+        let curve = FlatForward::new(
+            reference_date(),
+            SimpleQuote::new(rate),
+            Actual365Fixed,
+            Compounding::Continuous,
+            Frequency::Annual,
+            Calendar::new(WeekendsOnly),
+        );
+        let lazy = LazyObject::new();
+        lazy.register_with(curve.observable().unwrap());
+        ZeroBond {
+            curve,
+            maturity: Date::new(15, Month::January, 2025),
+            lazy,
+            value: 0.0,
+            calculations: 0,
+        }
+    }
+
+    fn npv(&mut self) -> f64 {
+        if self.lazy.calculate() {
+            self.value = 100.0 * self.curve.discount(self.maturity, false);
+            self.calculations += 1;
+        }
+        self.value
+    }
+}
+
+#[test]
+fn test_results_are_cached_until_an_input_changes() {
+    let mut bond = ZeroBond::new(0.01);
+    let first = bond.npv();
+    assert_eq!(bond.npv(), first);
+    assert_eq!(bond.calculations, 1);
+    bond.curve.forward.set_value(0.02);
+    assert!(!bond.lazy.is_calculated());
+    assert!(bond.npv() < first);
+    assert_eq!(bond.calculations, 2);
+}
+
+#[test]
+fn test_frozen_objects_keep_their_results() {
+    let mut bond = ZeroBond::new(0.01);
+    let dependent = ChangeFlag::new();
+    bond.lazy.observable().register_observer(dependent.clone());
+    let first = bond.npv();
+
+    bond.lazy.freeze();
+    for rate in [0.02, 0.03, 0.04] {
+        bond.curve.forward.set_value(rate);
+        assert_eq!(bond.npv(), first);
+    }
+    assert!(!dependent.is_set());
+    assert_eq!(bond.calculations, 1);
+
+    // a single revaluation pass on unfreezing
+    bond.lazy.unfreeze();
+    assert!(dependent.take());
+    let t = bond.curve.time_from_reference(bond.maturity);
+    assert!((bond.npv() - 100.0 * (-0.04 * t).exp()).abs() < 1.0e-12);
+    assert_eq!(bond.npv(), bond.value);
+    assert_eq!(bond.calculations, 2);
+}
+
+#[test]
+fn test_recalculation_overrides_the_freeze() {
+    let mut bond = ZeroBond::new(0.01);
+    let dependent = ChangeFlag::new();
+    bond.lazy.observable().register_observer(dependent.clone());
+    bond.npv();
+    bond.lazy.freeze();
+    bond.curve.forward.set_value(0.03);
+    bond.lazy.recalculate();
+    assert!(dependent.take());
+    let recalculated = bond.npv();
+    assert_eq!(bond.calculations, 2);
+    assert!(bond.lazy.is_frozen());
+    // and stays frozen afterwards
+    bond.curve.forward.set_value(0.05);
+    assert_eq!(bond.npv(), recalculated);
+}
+
+#[test]
+fn test_stale_objects_notify_once() {
+    let mut bond = ZeroBond::new(0.01);
+    let dependent = ChangeFlag::new();
+    bond.lazy.observable().register_observer(dependent.clone());
+    // nothing was calculated yet
+    bond.curve.forward.set_value(0.02);
+    assert!(!dependent.is_set());
+    bond.npv();
+    bond.curve.forward.set_value(0.03);
+    assert!(dependent.take());
+    bond.curve.forward.set_value(0.04);
+    assert!(!dependent.is_set());
+
+    let forwarding = LazyObject::new().with_always_forward();
+    let flag = ChangeFlag::new();
+    forwarding.observable().register_observer(flag.clone());
+    forwarding.update();
+    assert!(flag.take());
+    forwarding.update();
+    assert!(flag.take());
+}
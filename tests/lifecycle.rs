@@ -0,0 +1,276 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{Base, FixedRateCoupon, FixedRateLeg, IborCoupon, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{
+    valuation, BasisSwap, DiFuture, EarlyExerciseOption, Exercise, FixedRateBond, Lifecycle,
+    LifecycleEventKind, NonstandardSwap, NtnB, OptionType, SwapType, ValuationStatus,
+    VanillaOption, VanillaSwap,
+};
+use quantlib::quotes::SimpleQuote;
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, flat_rate};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Frequency,
+    Month, Period, Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index360 = IborIndex<WeekendsOnly, Curve, Actual360>;
+
+fn euribor(months: i64) -> Rc<Index360> {
+    Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(months, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.02))),
+    ))
+}
+
+// one year from spot on the 15th January 2020 evaluation date.
+fn schedule(months: i64) -> Schedule {
+    Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2021),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn fixed_leg(schedule: &Schedule) -> Vec<FixedRateCoupon<Actual365Fixed>> {
+    schedule
+        .dates
+        .windows(2)
+        .map(|w| {
+            let base = Base {
+                nominal: 1.0,
+                day_counter: Actual365Fixed,
+                payment_date: w[1],
+                accrual_start_date: w[0],
+                accrual_end_date: w[1],
+                reference_period_start: w[0],
+                reference_period_end: w[1],
+            };
+            FixedRateCoupon::new(base, flat_rate(0.02))
+        })
+        .collect()
+}
+
+fn swap() -> VanillaSwap<FixedRateCoupon<Actual365Fixed>, IborCoupon<Index360, Actual360>> {
+    VanillaSwap::new(
+        SwapType::Payer,
+        fixed_leg(&schedule(12)),
+        0.02,
+        IborLeg::new(schedule(6), euribor(6), Actual360).build(),
+        0.0,
+    )
+}
+
+#[test]
+fn test_vanilla_swap_events() {
+    let swap = swap();
+    let events = swap.events();
+    let count = |kind| events.iter().filter(|e| e.kind == kind).count();
+    assert_eq!(count(LifecycleEventKind::Fixing), 2);
+    // one annual fixed payment and two semiannual floating ones
+    assert_eq!(count(LifecycleEventKind::Payment), 3);
+    assert!(events.windows(2).all(|w| w[0].date <= w[1].date));
+
+    let first = swap
+        .next_event(Date::new(15, Month::January, 2020))
+        .unwrap();
+    assert_eq!(first.kind, LifecycleEventKind::Fixing);
+    assert_eq!(first.date, Date::new(15, Month::January, 2020));
+    assert_eq!(first.source, "floating leg");
+}
+
+#[test]
+fn test_upcoming_events_over_a_horizon() {
+    let swap = BasisSwap::new(
+        SwapType::Receiver,
+        IborLeg::new(schedule(3), euribor(3), Actual360).build(),
+        IborLeg::new(schedule(6), euribor(6), Actual360).build(),
+        0.0,
+    );
+    let (from, to) = (
+        Date::new(1, Month::April, 2020),
+        Date::new(30, Month::July, 2020),
+    );
+    let upcoming = swap.upcoming_events(from, to);
+    assert!(upcoming.iter().all(|e| e.date >= from && e.date <= to));
+    let fixings: Vec<_> = upcoming
+        .iter()
+        .filter(|e| e.kind == LifecycleEventKind::Fixing)
+        .collect();
+    // 3M fixings for the April and July periods and the 6M one for July
+    assert_eq!(fixings.len(), 3);
+    let payments = upcoming
+        .iter()
+        .filter(|e| e.kind == LifecycleEventKind::Payment)
+        .count();
+    // April and July 3M payments and the July 6M one
+    assert_eq!(payments, 3);
+}
+
+#[test]
+fn test_di_future_expiry_and_settlement() {
+    let future = DiFuture::contract(Month::June, 2020, Calendar::new(WeekendsOnly));
+    let events = future.events();
+    assert_eq!(events.len(), 2);
+    // 1st June 2020 is a Monday, the last trading day the Friday before
+    assert_eq!(events[0].kind, LifecycleEventKind::Expiry);
+    assert_eq!(events[0].date, Date::new(29, Month::May, 2020));
+    assert_eq!(events[1].kind, LifecycleEventKind::Settlement);
+    assert_eq!(events[1].date, Date::new(1, Month::June, 2020));
+    assert!(future
+        .upcoming_events(
+            Date::new(2, Month::June, 2020),
+            Date::new(1, Month::July, 2020)
+        )
+        .is_empty());
+}
+
+#[test]
+fn test_expiry_follows_the_evaluation_date() {
+    let swap = swap();
+    // the last payments fall on 18th January 2021, a Monday
+    let last = Date::new(18, Month::January, 2021);
+    assert!(!swap.is_expired_at(last, true));
+    assert!(swap.is_expired_at(last, false));
+    let live = PricingContext::new(Date::new(15, Month::June, 2020));
+    assert!(!live.scope(|| swap.is_expired()));
+    let expired = PricingContext::new(Date::new(1, Month::February, 2021));
+    assert!(expired.scope(|| swap.is_expired()));
+    let today = PricingContext::new(last).with_todays_cashflows(true);
+    assert!(!today.scope(|| swap.is_expired()));
+}
+
+#[test]
+fn test_expired_instruments_value_at_zero() {
+    let swap = swap();
+    let curve = flat_curve(0.03);
+    let settlement = Date::new(17, Month::January, 2020);
+    let live = PricingContext::new(Date::new(15, Month::January, 2020))
+        .scope(|| valuation(&swap, settlement, |s| s.npv(&curve, settlement)));
+    assert_eq!(live.status, ValuationStatus::Live);
+    assert!((live.npv - swap.npv(&curve, settlement)).abs() < 1.0e-15);
+
+    let expired = PricingContext::new(Date::new(1, Month::February, 2021)).scope(|| {
+        valuation(&swap, settlement, |_| {
+            panic!("expired swaps are not priced")
+        })
+    });
+    assert_eq!(expired.status, ValuationStatus::Expired);
+    assert_eq!(expired.npv, 0.0);
+}
+
+#[test]
+fn test_flows_past_settlement() {
+    let future = DiFuture::contract(Month::June, 2020, Calendar::new(WeekendsOnly));
+    // traded on the last trading day for settlement on maturity
+    let context = PricingContext::new(Date::new(29, Month::May, 2020));
+    let result = context.scope(|| {
+        valuation(&future, Date::new(1, Month::June, 2020), |f| {
+            f.unit_price(0.03, Date::new(1, Month::June, 2020))
+        })
+    });
+    assert_eq!(result.status, ValuationStatus::PastSettlement);
+    assert_eq!(result.npv, 0.0);
+    let result = context.scope(|| {
+        valuation(&future, Date::new(29, Month::May, 2020), |f| {
+            f.unit_price(0.03, Date::new(29, Month::May, 2020))
+        })
+    });
+    assert_eq!(result.status, ValuationStatus::Live);
+    assert!(result.npv < 100_000.0);
+}
+
+#[test]
+fn test_option_expiry_and_exercise_events() {
+    let expiry = Date::new(15, Month::January, 2021);
+    let option = VanillaOption::new(OptionType::Put, 100.0, expiry);
+    let kinds: Vec<_> = option.events().iter().map(|e| (e.date, e.kind)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (expiry, LifecycleEventKind::Expiry),
+            (expiry, LifecycleEventKind::Settlement)
+        ]
+    );
+
+    let dates = vec![
+        Date::new(15, Month::July, 2020),
+        Date::new(15, Month::October, 2020),
+        expiry,
+    ];
+    let bermudan = EarlyExerciseOption::new(OptionType::Put, 100.0, Exercise::bermudan(dates));
+    let events = bermudan.events();
+    let exercises = events
+        .iter()
+        .filter(|e| e.kind == LifecycleEventKind::Exercise)
+        .count();
+    assert_eq!(exercises, 2);
+    assert_eq!(events[2].kind, LifecycleEventKind::Expiry);
+    assert_eq!(events[2].date, expiry);
+
+    let expired = PricingContext::new(Date::new(1, Month::February, 2021)).scope(|| {
+        valuation(&option, expiry, |_| {
+            panic!("expired options are not priced")
+        })
+    });
+    assert_eq!(expired.status, ValuationStatus::Expired);
+    assert_eq!(expired.npv, 0.0);
+}
+
+#[test]
+fn test_bond_coupon_and_redemption_events() {
+    let bond = FixedRateBond::new(schedule(12), 0.02, Actual365Fixed, Frequency::Annual, 100.0);
+    let events = bond.events();
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|e| e.kind == LifecycleEventKind::Payment));
+    assert_eq!(events[1].source, "redemption");
+    assert_eq!(events[1].date, bond.maturity_date);
+
+    let ntnb = NtnB::new(
+        Date::new(15, Month::January, 2020),
+        Date::new(15, Month::November, 2021),
+        Calendar::new(WeekendsOnly),
+    );
+    let events = ntnb.events();
+    // four semiannual coupons and the principal with the last one
+    assert_eq!(events.len(), 5);
+    // 15th November 2020 is a Sunday
+    assert_eq!(events[1].date, Date::new(16, Month::November, 2020));
+    let last = events.last().unwrap();
+    assert_eq!(last.source, "redemption");
+    assert_eq!(last.date, Date::new(15, Month::November, 2021));
+}
+
+#[test]
+fn test_nonstandard_swap_events() {
+    let swap = NonstandardSwap::new(
+        SwapType::Payer,
+        FixedRateLeg::new(schedule(12), Actual365Fixed)
+            .with_notional(1.0)
+            .with_rate(0.02)
+            .build(),
+        IborLeg::new(schedule(6), euribor(6), Actual360).build(),
+        0.0,
+    );
+    let events = swap.events();
+    let count = |kind| events.iter().filter(|e| e.kind == kind).count();
+    assert_eq!(count(LifecycleEventKind::Fixing), 2);
+    assert_eq!(count(LifecycleEventKind::Payment), 3);
+    let expired = PricingContext::new(Date::new(1, Month::February, 2021));
+    assert!(expired.scope(|| swap.is_expired()));
+}
@@ -0,0 +1,121 @@
+extern crate quantlib;
+
+use quantlib::instruments::{
+    Amortization, AmortizingLoan, LoanPayment, MortgagePool, PrepaymentModel,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::Frequency;
+
+fn loan(amortization: Amortization) -> AmortizingLoan {
+    AmortizingLoan::new(
+        1000.0,
+        0.06,
+        Frequency::Quarterly,
+        reference_date(),
+        20,
+        amortization,
+    )
+}
+
+fn repaid(payments: &[LoanPayment]) -> f64 {
+    payments.iter().map(|p| p.principal + p.prepayment).sum()
+}
+
+#[test]
+fn test_amortization_schedules() {
+    let annuity = loan(Amortization::Annuity).payments();
+    let instalment = loan(Amortization::Annuity).annuity_payment();
+    assert!(annuity
+        .iter()
+        .all(|p| (p.interest + p.principal - instalment).abs() < 1.0e-9));
+
+    let linear = loan(Amortization::Linear).payments();
+    assert!(linear.iter().all(|p| (p.principal - 50.0).abs() < 1.0e-9));
+    assert!((linear[1].interest - 950.0 * 0.015).abs() < 1.0e-9);
+
+    let bullet = loan(Amortization::Bullet).payments();
+    assert!(bullet[..19]
+        .iter()
+        .all(|p| p.principal == 0.0 && (p.interest - 15.0).abs() < 1.0e-12));
+    assert_eq!(bullet[19].principal, 1000.0);
+
+    for payments in &[annuity, linear, bullet] {
+        assert_eq!(payments.len(), 20);
+        assert_eq!(
+            payments[19].date,
+            loan(Amortization::Bullet).maturity_date()
+        );
+        assert!((repaid(payments) - 1000.0).abs() < 1.0e-9);
+        assert!(payments[19].outstanding.abs() < 1.0e-9);
+    }
+}
+
+#[test]
+fn test_loan_npv_with_spread() {
+    // discounting at the loan's own quarterly rate prices it near par
+    let own_rate = 4.0 * 1.015_f64.ln();
+    for &a in &[
+        Amortization::Annuity,
+        Amortization::Linear,
+        Amortization::Bullet,
+    ] {
+        let l = loan(a);
+        let par = l.npv(&flat_curve(own_rate), 0.0, reference_date());
+        assert!((par / 1000.0 - 1.0).abs() < 1.0e-3);
+        let spread = l.npv(&flat_curve(0.02), 0.01, reference_date());
+        let shifted = l.npv(&flat_curve(0.03), 0.0, reference_date());
+        assert!((spread - shifted).abs() < 1.0e-9);
+    }
+}
+
+#[test]
+fn test_prepayment_speeds() {
+    assert!((PrepaymentModel::Psa(1.0).cpr(15) - 0.03).abs() < 1.0e-15);
+    assert!((PrepaymentModel::Psa(2.0).cpr(45) - 0.12).abs() < 1.0e-15);
+    let smm = PrepaymentModel::Cpr(0.1).single_monthly_mortality(1);
+    assert!(((1.0 - smm).powi(12) - 0.9).abs() < 1.0e-12);
+    // a seasoned pool starts further up the PSA ramp
+    let seasoned = MortgagePool::new(
+        1.0e6,
+        0.05,
+        reference_date(),
+        360,
+        PrepaymentModel::Psa(1.0),
+    )
+    .with_age(30);
+    let first = seasoned.payments()[0];
+    let expected =
+        PrepaymentModel::Cpr(0.06).single_monthly_mortality(1) * (1.0e6 - first.principal);
+    assert!((first.prepayment - expected).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_mortgage_pool_projection() {
+    let start = reference_date();
+    let level = MortgagePool::new(1.0e6, 0.05, start, 360, PrepaymentModel::Cpr(0.0));
+    let scheduled = AmortizingLoan::new(
+        1.0e6,
+        0.05,
+        Frequency::Monthly,
+        start,
+        360,
+        Amortization::Annuity,
+    )
+    .payments();
+    for (p, q) in level.payments().iter().zip(&scheduled) {
+        assert!((p.principal - q.principal).abs() < 1.0e-6 && p.prepayment == 0.0);
+    }
+
+    let mut previous = level.weighted_average_life();
+    for &speed in &[1.0, 2.0, 4.0] {
+        let pool = MortgagePool::new(1.0e6, 0.05, start, 360, PrepaymentModel::Psa(speed));
+        let payments = pool.payments();
+        assert!((repaid(&payments) - 1.0e6).abs() < 1.0e-4);
+        let wal = pool.weighted_average_life();
+        assert!(wal < previous);
+        previous = wal;
+        // faster prepayment pulls value forward: worth more at a discount
+        let curve = flat_curve(0.07);
+        assert!(pool.npv(&curve, 0.0, start) > level.npv(&curve, 0.0, start));
+    }
+}
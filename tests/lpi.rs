@@ -0,0 +1,148 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{CashFlow, LpiCashFlow, LpiLeg, SimpleCashFlow};
+use quantlib::indexes::{Index, ZeroInflationIndex};
+use quantlib::instruments::{LpiSwap, SwapType};
+use quantlib::pricingengines::McLpiEngine;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{YieldTermStructure, ZeroInflationCurve};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule, TimeUnit,
+    WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+/// RPI with the October fixings `published` up to October 2019, forecast
+/// from there at a flat zero coupon rate `rate`.
+fn rpi(rate: f64, published: &[f64]) -> Rc<ZeroInflationIndex> {
+    let base = Date::new(1, Month::October, 2019);
+    let curve = ZeroInflationCurve::new(base, vec![base.add_months(360)], vec![rate]);
+    let index = ZeroInflationIndex::uk_rpi().with_inflation_curve(Rc::new(curve));
+    let first = base.add_months(-12 * (published.len() as i64 - 1));
+    for (k, f) in published.iter().enumerate() {
+        index.add_fixing(first.add_months(12 * k as i64), *f);
+    }
+    Rc::new(index)
+}
+
+/// Annual LPI amounts over `years` from `start`, on a three month lag.
+fn leg(index: Rc<ZeroInflationIndex>, start: Date, years: i64) -> LpiLeg {
+    let schedule = Schedule::new(
+        start,
+        start.add_months(12 * years),
+        Period::new(12, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Forward,
+        false,
+    );
+    LpiLeg::new(schedule, index, 3).with_notional(1.0e6)
+}
+
+fn engine(sigma: f64, samples: usize) -> McLpiEngine<Curve> {
+    McLpiEngine::new(Rc::new(flat_curve(0.02)), sigma, samples, 42)
+}
+
+fn intrinsic(flows: &[LpiCashFlow]) -> f64 {
+    let curve = flat_curve(0.02);
+    flows
+        .iter()
+        .map(|c| c.amount() * curve.discount(c.payment_date, true))
+        .sum()
+}
+
+#[test]
+fn test_lpi_leg_collars_each_year() {
+    let flows = leg(rpi(0.03, &[300.0]), reference_date(), 5).build();
+    assert_eq!(flows.len(), 5);
+    for (k, c) in flows.iter().enumerate() {
+        assert_eq!(c.fixing_dates.len(), k + 2);
+        assert_eq!(c.fixing_dates[0], Date::new(1, Month::October, 2019));
+        assert_eq!(
+            c.payment_date,
+            reference_date().add_months(12 * (k as i64 + 1))
+        );
+        assert_eq!((c.floor, c.cap), (0.0, 0.05));
+    }
+    // within the collar the amount is the index growth
+    let last = &flows[4];
+    let fixings = last.forecast_fixings().unwrap();
+    assert!((last.amount() - 1.0e6 * fixings[5] / fixings[0]).abs() < 1.0e-6);
+    // years of 7% and -1% are capped at 5% and floored at 0%
+    let path = [100.0, 107.0, 105.93, 111.2265];
+    let c = &leg(rpi(0.03, &[300.0]), reference_date(), 3).build()[2];
+    assert!((c.lpi_ratio(&path) - 1.05 * 1.0 * 1.05).abs() < 1.0e-12);
+    let hot = leg(rpi(0.08, &[300.0]), reference_date(), 3).build();
+    assert!((hot[2].amount() - 1.0e6 * 1.05f64.powi(3)).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_zero_volatility_is_intrinsic() {
+    for rate in &[-0.01, 0.03, 0.07] {
+        let flows = leg(rpi(*rate, &[300.0]), reference_date(), 10).build();
+        let mc = engine(0.0, 10).leg_npv(&flows, reference_date());
+        assert!((mc.value - intrinsic(&flows)).abs() < 1.0e-6);
+        assert!(mc.error_estimate < 1.0e-6);
+    }
+}
+
+#[test]
+fn test_collar_optionality() {
+    let index = rpi(0.025, &[300.0]);
+    let uncollared = leg(Rc::clone(&index), reference_date(), 10)
+        .with_collar(-0.99, 10.0)
+        .build();
+    let mc = engine(0.01, 20_000)
+        .with_antithetic()
+        .leg_npv(&uncollared, reference_date());
+    // no collar, no optionality: the forecast fixings are the expected ones
+    assert!((mc.value - intrinsic(&uncollared)).abs() < 3.0 * mc.error_estimate);
+    // path by path, the cap only takes value away and the floor adds it
+    let plain = engine(0.01, 20_000).leg_npv(&uncollared, reference_date());
+    let capped = leg(Rc::clone(&index), reference_date(), 10)
+        .with_collar(-0.99, 0.05)
+        .build();
+    let floored = leg(Rc::clone(&index), reference_date(), 10)
+        .with_collar(0.0, 10.0)
+        .build();
+    let cap = engine(0.01, 20_000).leg_npv(&capped, reference_date());
+    let floor = engine(0.01, 20_000).leg_npv(&floored, reference_date());
+    assert!(cap.value < plain.value && plain.value < floor.value);
+    let collared = leg(index, reference_date(), 10).build();
+    let collar = engine(0.01, 20_000).leg_npv(&collared, reference_date());
+    assert!(cap.value < collar.value && collar.value < floor.value);
+    // antithetic pairs reduce the error of the same number of samples
+    assert!(mc.error_estimate < plain.error_estimate);
+}
+
+#[test]
+fn test_lpi_swap_with_published_fixings() {
+    // started two years ago, with years of 6% and 1% published since
+    let start = reference_date().add_months(-24);
+    let index = rpi(0.03, &[300.0, 318.0, 321.18]);
+    let flows = leg(index, start, 5).build();
+    assert_eq!(flows[2].fixing_dates[0], Date::new(1, Month::October, 2017));
+    assert!(CashFlow::has_occured(&flows[1], reference_date(), false));
+    let intrinsic_third = flows[2].amount();
+    let published = 1.0e6 * 1.05 * 1.01;
+    assert!(intrinsic_third > published);
+    let fixed: Vec<SimpleCashFlow> = flows
+        .iter()
+        .map(|c| SimpleCashFlow::new(1.1e6, c.payment_date))
+        .collect();
+    let swap = LpiSwap::new(SwapType::Payer, flows, fixed);
+    let curve = flat_curve(0.02);
+    let mc = engine(0.0, 10).npv(&swap, reference_date());
+    assert!((mc.value - swap.npv(&curve, reference_date())).abs() < 1.0e-6);
+    let expected = swap.fixed_leg_npv(&curve, reference_date()) - intrinsic(&swap.lpi_leg[2..]);
+    assert!((mc.value - expected).abs() < 1.0e-6);
+    let receiver = LpiSwap::new(SwapType::Receiver, swap.lpi_leg, swap.fixed_leg);
+    let stressed = engine(0.02, 2_000).npv(&receiver, reference_date());
+    assert!(stressed.value.is_finite() && stressed.error_estimate > 0.0);
+    assert_eq!(receiver.sign(), 1.0);
+}
@@ -0,0 +1,187 @@
+extern crate quantlib;
+
+use quantlib::instruments::{OptionType, VanillaOption};
+use quantlib::math::{InverseCumulativeRsg, SobolRsg};
+use quantlib::methods::montecarlo::{MonteCarloModel, Path, PathGenerator, PathPricer, Statistics};
+use quantlib::pricingengines::{
+    black_formula, AnalyticEuropeanEngine, EuropeanPathPricer, McEuropeanEngine,
+};
+use quantlib::processes::{BlackScholesMertonProcess, GeometricBrownianMotionProcess};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::WeekendsOnly;
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Bsm = BlackScholesMertonProcess<Curve, Curve, BlackConstantVol>;
+
+fn process() -> Rc<Bsm> {
+    Rc::new(BlackScholesMertonProcess::new(
+        SimpleQuote::new(100.0),
+        Rc::new(flat_curve(0.05)),
+        Rc::new(flat_curve(0.02)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(0.25))),
+    ))
+}
+
+fn option(option_type: OptionType, strike: f64) -> VanillaOption {
+    VanillaOption::new(option_type, strike, reference_date().add_months(12))
+}
+
+fn analytic(option: &VanillaOption) -> f64 {
+    AnalyticEuropeanEngine::new(
+        Rc::new(flat_curve(0.05)),
+        Rc::new(flat_curve(0.02)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(0.25))),
+    )
+    .npv(option, 100.0)
+}
+
+fn engine(samples: usize) -> McEuropeanEngine<Bsm, Curve> {
+    McEuropeanEngine::new(process(), Rc::new(flat_curve(0.05)), 4, samples, 7)
+}
+
+/// Average of the path, as for an Asian option.
+struct MeanPricer;
+
+impl PathPricer for MeanPricer {
+    fn price(&self, path: &Path) -> f64 {
+        path.values[1..].iter().sum::<f64>() / (path.len() - 1) as f64
+    }
+}
+
+#[test]
+fn test_path_generation() {
+    let process = Rc::new(GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2));
+    let generator = PathGenerator::uniform(process, 1.0, 4);
+    assert_eq!(generator.times, vec![0.25, 0.5, 0.75, 1.0]);
+    let path = generator.path(&[0.0; 4]);
+    assert_eq!(path.len(), 5);
+    assert_eq!(path.times[0], 0.0);
+    assert_eq!(path.front(), 100.0);
+    // the median grows at the drift less half the variance
+    for (t, x) in path.times.iter().zip(&path.values) {
+        assert!((x - 100.0 * (0.03 * t).exp()).abs() < 1.0e-10);
+    }
+    let statistics = {
+        let mut s = Statistics::default();
+        for v in &[1.0, 2.0, 3.0, 4.0] {
+            s.add(*v);
+        }
+        s
+    };
+    assert_eq!(statistics.mean(), 2.5);
+    assert!((statistics.variance() - 5.0 / 3.0).abs() < 1.0e-14);
+}
+
+#[test]
+fn test_european_options_converge_to_black_scholes() {
+    for option in &[
+        option(OptionType::Call, 105.0),
+        option(OptionType::Put, 95.0),
+    ] {
+        let result = engine(50_000).npv(option);
+        let expected = analytic(option);
+        assert!(result.error_estimate > 0.0);
+        assert!((result.value - expected).abs() < 3.0 * result.error_estimate);
+    }
+}
+
+#[test]
+fn test_antithetic_variates_reduce_the_error() {
+    let call = option(OptionType::Call, 100.0);
+    let plain = engine(20_000).npv(&call);
+    let antithetic = engine(20_000).with_antithetic().npv(&call);
+    assert!(antithetic.error_estimate < plain.error_estimate);
+    assert!((antithetic.value - analytic(&call)).abs() < 3.0 * antithetic.error_estimate);
+    // the mean of a path average is the average forward, which antithetic
+    // pairs nearly pin down
+    let generator = PathGenerator::uniform(process(), 1.0, 4);
+    let mut model = MonteCarloModel::new(generator, MeanPricer, 3).with_antithetic();
+    model.add_samples(5_000);
+    let forward: f64 = (1..=4)
+        .map(|i| 100.0 * (0.03 * i as f64 / 4.0).exp())
+        .sum::<f64>()
+        / 4.0;
+    assert_eq!(model.statistics.samples, 5_000);
+    assert!((model.result().value - forward).abs() < 3.0 * model.result().error_estimate);
+}
+
+#[test]
+fn test_sobol_paths_converge_faster() {
+    let process = Rc::new(GeometricBrownianMotionProcess::new(100.0, 0.03, 0.2));
+    let pricer = EuropeanPathPricer {
+        option_type: OptionType::Call,
+        strike: 100.0,
+        discount: 1.0,
+    };
+    let expected = black_formula(
+        OptionType::Call,
+        100.0,
+        100.0 * 0.03f64.exp(),
+        0.2,
+        1.0,
+        0.0,
+    );
+    let generator = PathGenerator::uniform(process.clone(), 1.0, 4);
+    let rsg = InverseCumulativeRsg::new(SobolRsg::new(4));
+    let mut sobol = MonteCarloModel::with_rsg(generator, pricer, rsg);
+    sobol.add_samples(16_384);
+    let generator = PathGenerator::uniform(process, 1.0, 4);
+    let mut pseudo = MonteCarloModel::new(generator, pricer, 5);
+    pseudo.add_samples(16_384);
+    let sobol_error = (sobol.statistics.mean() - expected).abs();
+    assert!(sobol_error < 0.02);
+    assert!(sobol_error < (pseudo.statistics.mean() - expected).abs());
+}
+
+#[test]
+fn test_samples_added_until_the_tolerance() {
+    let put = option(OptionType::Put, 100.0);
+    let result = engine(1_000).with_tolerance(0.05, 1_000_000).npv(&put);
+    assert!(result.error_estimate <= 0.05);
+    assert!((result.value - analytic(&put)).abs() < 4.0 * 0.05);
+    let pricer = EuropeanPathPricer {
+        option_type: OptionType::Put,
+        strike: 100.0,
+        discount: 1.0,
+    };
+    let generator = PathGenerator::uniform(process(), 1.0, 1);
+    let mut model = MonteCarloModel::new(generator, pricer, 11);
+    let coarse = model.value_with_tolerance(0.2, 100, 1_000_000);
+    let samples = model.statistics.samples;
+    let fine = model.value_with_tolerance(0.05, 100, 1_000_000);
+    // a quarter of the error takes about sixteen times the samples
+    assert!(coarse.error_estimate <= 0.2 && fine.error_estimate <= 0.05);
+    assert!(model.statistics.samples > 8 * samples);
+}
+
+#[test]
+fn test_error_estimate_is_the_standard_error() {
+    let call = option(OptionType::Call, 100.0);
+    let results: Vec<_> = [4_000, 16_000, 64_000]
+        .iter()
+        .map(|n| engine(*n).npv(&call))
+        .collect();
+    // four times the samples halve the standard error
+    for pair in results.windows(2) {
+        let ratio = pair[1].error_estimate / pair[0].error_estimate;
+        assert!((ratio - 0.5).abs() < 0.05, "{}", ratio);
+    }
+    let expected = analytic(&call);
+    for result in &results {
+        assert!((result.value - expected).abs() < 3.0 * result.error_estimate);
+    }
+    // the standard error of the discounted payoff, by its variance
+    let pricer = EuropeanPathPricer {
+        option_type: OptionType::Call,
+        strike: 100.0,
+        discount: (-0.05f64).exp(),
+    };
+    let mut model = MonteCarloModel::new(PathGenerator::uniform(process(), 1.0, 4), pricer, 7);
+    model.add_samples(16_000);
+    let standard_error = (model.statistics.variance() / 16_000.0).sqrt();
+    assert!((model.result().error_estimate - standard_error).abs() < 1.0e-12);
+}
@@ -0,0 +1,130 @@
+extern crate quantlib;
+
+use quantlib::termstructures::{CurveAnchor, MeetingDateCurveBuilder};
+use quantlib::time::{Actual365Fixed, Calendar, Date, DayCounter, Month, WeekendsOnly};
+
+fn reference_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+fn meetings() -> Vec<Date> {
+    vec![
+        Date::new(29, Month::January, 2020),
+        Date::new(18, Month::March, 2020),
+        Date::new(29, Month::April, 2020),
+        Date::new(10, Month::June, 2020),
+    ]
+}
+
+fn builder() -> MeetingDateCurveBuilder<WeekendsOnly> {
+    MeetingDateCurveBuilder::new(
+        reference_date(),
+        meetings(),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+}
+
+fn time(d: Date) -> f64 {
+    Actual365Fixed.year_fraction(reference_date(), d, None, None)
+}
+
+/// Discount factor under forwards of 1.5% stepping up 25bp on each of the
+/// first two meetings.
+fn market_discount(d: Date) -> f64 {
+    let m = meetings();
+    let steps = [(m[0], 0.0025), (m[1], 0.0025)];
+    let integral = 0.015 * time(d)
+        + steps
+            .iter()
+            .map(|(s, dr)| dr * (time(d) - time(*s)).max(0.0))
+            .sum::<f64>();
+    (-integral).exp()
+}
+
+fn quotes(dates: &[Date]) -> Vec<(Date, f64)> {
+    dates.iter().map(|d| (*d, market_discount(*d))).collect()
+}
+
+#[test]
+fn test_forwards_step_on_meeting_dates() {
+    let q = quotes(&[
+        Date::new(22, Month::January, 2020),
+        Date::new(15, Month::February, 2020),
+        Date::new(15, Month::April, 2020),
+        Date::new(15, Month::July, 2020),
+    ]);
+    let fit = builder().bootstrap(&q);
+    assert!(
+        fit.errors.iter().all(|e| e.abs() < 1.0e-15),
+        "{:?}",
+        fit.errors
+    );
+    for &(d, forward) in &[
+        (Date::new(20, Month::January, 2020), 0.015),
+        (Date::new(28, Month::January, 2020), 0.015),
+        (Date::new(30, Month::January, 2020), 0.0175),
+        (Date::new(1, Month::March, 2020), 0.0175),
+        (Date::new(1, Month::April, 2020), 0.02),
+        (Date::new(1, Month::June, 2020), 0.02),
+        (Date::new(1, Month::December, 2020), 0.02),
+    ] {
+        let f = fit.curve.instantaneous_forward(time(d));
+        assert!((f - forward).abs() < 1.0e-12, "{} {}", d, f);
+    }
+}
+
+#[test]
+fn test_extra_quotes_in_a_solved_period_are_errors() {
+    let mut q = quotes(&[
+        Date::new(22, Month::January, 2020),
+        Date::new(15, Month::February, 2020),
+    ]);
+    // a second quote between the same meetings, 5bp off the market
+    let d = Date::new(1, Month::March, 2020);
+    q.push((d, market_discount(d) * (-0.0005 * time(d)).exp()));
+    let fit = builder().bootstrap(&q);
+    assert!(fit.errors[0].abs() < 1.0e-15 && fit.errors[1].abs() < 1.0e-15);
+    assert!(fit.errors[2] < -1.0e-6);
+}
+
+#[test]
+fn test_anchors_are_kept_exactly() {
+    let march = meetings()[1];
+    let anchored = builder().with_anchor(CurveAnchor::ZeroRate(march, 0.02));
+    let fit = anchored.bootstrap(&quotes(&[
+        Date::new(22, Month::January, 2020),
+        Date::new(15, Month::February, 2020),
+        Date::new(15, Month::April, 2020),
+    ]));
+    let zero = -fit.curve.discount(march).ln() / time(march);
+    assert!((zero - 0.02).abs() < 1.0e-14);
+    // the anchor overrides the quote before it, not the one before the meeting
+    assert!(fit.errors[0].abs() < 1.0e-15);
+    assert!(fit.errors[1].abs() > 1.0e-6);
+    assert!(fit.errors[2].abs() < 1.0e-15);
+}
+
+#[test]
+fn test_year_end_turn_from_pinned_discounts() {
+    let (start, end) = (
+        Date::new(31, Month::December, 2020),
+        Date::new(4, Month::January, 2021),
+    );
+    let turn = 0.01;
+    let before = market_discount(start);
+    let after = market_discount(end) * (-turn * (time(end) - time(start))).exp();
+    let fit = builder()
+        .with_anchor(CurveAnchor::Discount(start, before))
+        .with_anchor(CurveAnchor::Discount(end, after))
+        .bootstrap(&quotes(&[
+            Date::new(22, Month::January, 2020),
+            Date::new(15, Month::February, 2020),
+            Date::new(15, Month::April, 2020),
+        ]));
+    let f = |d: Date| fit.curve.instantaneous_forward(time(d));
+    let inside = f(Date::new(2, Month::January, 2021));
+    assert!((inside - 0.02 - turn).abs() < 1.0e-12, "{}", inside);
+    assert!((f(Date::new(1, Month::December, 2020)) - 0.02).abs() < 1.0e-12);
+    assert!((fit.curve.discount(end) - after).abs() < 1.0e-15);
+}
@@ -0,0 +1,50 @@
+extern crate quantlib;
+
+use quantlib::termstructures::{
+    accrued_interest, act360_to_act365, act365_to_act360, convert_basis, forward_deposit_rate,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Actual360, Actual365Fixed, Date, Month, Thirty360};
+
+#[test]
+fn test_deposit_accrual() {
+    let start = Date::new(15, Month::January, 2020);
+    let end = Date::new(15, Month::April, 2020);
+    let interest = accrued_interest(1.0e6, 0.05, start, end, Actual360);
+    assert!((interest - 1.0e6 * 0.05 * 91.0 / 360.0).abs() < 1.0e-9);
+    let interest = accrued_interest(1.0e6, 0.05, start, end, Thirty360::default());
+    assert!((interest - 1.0e6 * 0.05 * 0.25).abs() < 1.0e-9);
+}
+
+#[test]
+fn test_basis_conversions() {
+    assert!((act360_to_act365(0.036) - 0.0365).abs() < 1.0e-15);
+    assert!((act365_to_act360(act360_to_act365(0.05)) - 0.05).abs() < 1.0e-15);
+    let start = Date::new(1, Month::February, 2020);
+    let end = Date::new(1, Month::August, 2020);
+    let converted = convert_basis(0.04, Actual360, Thirty360::default(), start, end);
+    let interest = accrued_interest(100.0, 0.04, start, end, Actual360);
+    assert!(
+        (accrued_interest(100.0, converted, start, end, Thirty360::default()) - interest).abs()
+            < 1.0e-12
+    );
+}
+
+#[test]
+fn test_forward_forward_deposits() {
+    let curve = flat_curve(0.03);
+    let start = reference_date().add_months(3);
+    let end = reference_date().add_months(6);
+    let rate = forward_deposit_rate(&curve, start, end, Actual360);
+    let days = end.sub(start) as f64;
+    let expected = ((0.03 * days / 365.0).exp() - 1.0) * 360.0 / days;
+    assert!((rate - expected).abs() < 1.0e-14);
+    // investing to the start and rolling into the forward deposit matches
+    // investing to the end
+    let spot_start = forward_deposit_rate(&curve, reference_date(), start, Actual365Fixed {});
+    let spot_end = forward_deposit_rate(&curve, reference_date(), end, Actual365Fixed {});
+    let t1 = start.sub(reference_date()) as f64 / 365.0;
+    let t2 = end.sub(reference_date()) as f64 / 365.0;
+    let rolled = (1.0 + spot_start * t1) * (1.0 + rate * days / 360.0);
+    assert!((rolled - (1.0 + spot_end * t2)).abs() < 1.0e-14);
+}
@@ -0,0 +1,95 @@
+extern crate quantlib;
+
+use quantlib::instruments::OptionType;
+use quantlib::math::{inverse_normal_cdf, normal_cdf, BilinearInterpolation, Matrix};
+use quantlib::termstructures::{BlackVarianceSurface, BlackVolTermStructure, Moneyness};
+
+fn surface() -> BlackVarianceSurface<BilinearInterpolation> {
+    let strikes = vec![60.0, 80.0, 100.0, 120.0, 150.0];
+    let vols = Matrix::from_rows(&[
+        vec![0.32, 0.30],
+        vec![0.27, 0.26],
+        vec![0.22, 0.22],
+        vec![0.20, 0.205],
+        vec![0.19, 0.20],
+    ]);
+    BlackVarianceSurface::new(vec![0.5, 2.0], strikes, vols)
+}
+
+#[test]
+fn test_inverse_normal_cdf_inverts_the_cdf() {
+    for &p in &[
+        1.0e-10_f64,
+        1.0e-4,
+        0.01,
+        0.2,
+        0.5,
+        0.77,
+        0.99,
+        1.0 - 1.0e-8,
+    ] {
+        let x = inverse_normal_cdf(p);
+        assert!((normal_cdf(x) - p).abs() < 1.0e-14 * p.max(1.0e-2), "{}", p);
+    }
+    assert_eq!(inverse_normal_cdf(0.5), 0.0);
+}
+
+#[test]
+fn test_strike_conventions_agree() {
+    let s = surface();
+    let (t, forward) = (1.0, 100.0);
+    let vol = s.black_vol(t, 110.0);
+    for m in &[
+        Moneyness::Strike(110.0),
+        Moneyness::Simple(1.1),
+        Moneyness::Log(1.1_f64.ln()),
+    ] {
+        assert!((s.black_vol_at(t, *m, forward) - vol).abs() < 1.0e-14);
+        assert!((s.black_variance_at(t, *m, forward) - vol * vol * t).abs() < 1.0e-14);
+        let back = m.at_strike(110.0, t, forward, vol);
+        assert!((back.strike(t, forward, |_| vol) - 110.0).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn test_delta_quotes_on_a_flat_smile() {
+    let (t, forward, sigma) = (2.0_f64, 100.0, 0.2);
+    let sd = sigma * t.sqrt();
+    // the delta neutral straddle strike
+    let k = Moneyness::Delta(OptionType::Call, 0.5).strike(t, forward, |_| sigma);
+    assert!((k - forward * (0.5 * sd * sd).exp()).abs() < 1.0e-10);
+    let call = Moneyness::Delta(OptionType::Call, 0.25).strike(t, forward, |_| sigma);
+    let put = Moneyness::Delta(OptionType::Put, -0.25).strike(t, forward, |_| sigma);
+    assert!(put < forward && call > forward);
+    // call and put deltas at one strike differ by one
+    match Moneyness::Delta(OptionType::Put, -0.5).at_strike(call, t, forward, sigma) {
+        Moneyness::Delta(OptionType::Put, d) => assert!((d - (0.25 - 1.0)).abs() < 1.0e-14),
+        m => panic!("{:?}", m),
+    }
+}
+
+#[test]
+fn test_delta_quotes_on_a_skewed_surface() {
+    let s = surface();
+    let (t, forward) = (1.0, 100.0);
+    for &(option_type, delta) in &[
+        (OptionType::Call, 0.25),
+        (OptionType::Call, 0.1),
+        (OptionType::Put, -0.25),
+        (OptionType::Put, -0.1),
+    ] {
+        let m = Moneyness::Delta(option_type, delta);
+        let strike = m.strike(t, forward, |k| s.black_vol(t, k));
+        let vol = s.black_vol(t, strike);
+        assert!((s.black_vol_at(t, m, forward) - vol).abs() < 1.0e-14);
+        // the strike found is consistent with the smile vol there
+        match m.at_strike(strike, t, forward, vol) {
+            Moneyness::Delta(_, d) => assert!((d - delta).abs() < 1.0e-10, "{} {}", d, delta),
+            _ => unreachable!(),
+        }
+    }
+    // the put wing is richer
+    let put = Moneyness::Delta(OptionType::Put, -0.25);
+    let call = Moneyness::Delta(OptionType::Call, 0.25);
+    assert!(s.black_vol_at(t, put, forward) > s.black_vol_at(t, call, forward));
+}
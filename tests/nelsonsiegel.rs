@@ -0,0 +1,89 @@
+extern crate quantlib;
+
+use quantlib::math::Interpolation;
+use quantlib::termstructures::{NelsonSiegel, NelsonSiegelHistory};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual365Fixed, Calendar, Date, WeekendsOnly};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1.0e-12
+}
+
+#[test]
+fn test_forwards_and_zero_rates_agree() {
+    let ns = NelsonSiegel::new(0.04, -0.02, 0.015, 2.0);
+    assert!(close(ns.zero_rate(0.0), ns.forward(0.0)));
+    for &t in &[0.5, 2.0, 10.0] {
+        let h = 1.0e-5;
+        let forward = (ns.discount(t - h) / ns.discount(t + h)).ln() / (2.0 * h);
+        assert!((forward - ns.forward(t)).abs() < 1.0e-8);
+    }
+    assert!((ns.forward(60.0) - 0.04).abs() < 1.0e-6);
+
+    let dates: Vec<Date> = (0..=10)
+        .map(|y| reference_date().add_months(12 * y))
+        .collect();
+    let curve = ns.discount_curve(
+        dates.clone(),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::LogLinear,
+    );
+    let t = dates[5].sub(dates[0]) as f64 / 365.0;
+    assert!(close(curve.discount(dates[5]), ns.discount(t)));
+}
+
+#[test]
+fn test_curves_with_a_common_decay_combine_linearly() {
+    let a = NelsonSiegel::new(0.03, -0.01, 0.02, 1.5);
+    let b = NelsonSiegel::new(0.05, 0.01, -0.01, 1.5);
+    let average = NelsonSiegel::average(&[a, b]);
+    let spread = b.spread_over(&a).parameters().unwrap();
+    for &t in &[0.25, 1.0, 7.0] {
+        assert!(close(
+            average.zero_rate(t),
+            0.5 * (a.zero_rate(t) + b.zero_rate(t))
+        ));
+        assert!(close(spread.zero_rate(t), b.spread_over(&a).zero_spread(t)));
+        assert!(close(spread.forward(t), b.forward(t) - a.forward(t)));
+    }
+    assert!(close(a.interpolate(&b, 0.5).level, average.level));
+}
+
+#[test]
+fn test_spread_between_decays() {
+    let a = NelsonSiegel::new(0.03, -0.01, 0.02, 1.0);
+    let b = NelsonSiegel::new(0.03, -0.01, 0.02, 3.0);
+    let spread = b.spread_over(&a);
+    assert!(spread.parameters().is_none());
+    assert!(close(spread.zero_spread(0.0), 0.0));
+    assert!(close(
+        spread.zero_spread(2.0),
+        b.zero_rate(2.0) - a.zero_rate(2.0)
+    ));
+    assert!(spread.forward_spread(2.0) != 0.0);
+}
+
+#[test]
+fn test_history_interpolates_and_forecasts() {
+    let d0 = reference_date();
+    let (d1, d2) = (d0.add_days(10), d0.add_days(20));
+    let history = NelsonSiegelHistory::new(
+        vec![d0, d1, d2],
+        vec![
+            NelsonSiegel::new(0.03, -0.01, 0.0, 2.0),
+            NelsonSiegel::new(0.032, -0.012, 0.002, 2.0),
+            NelsonSiegel::new(0.034, -0.01, 0.004, 2.5),
+        ],
+    );
+    assert_eq!(history.at(d0.add_days(-5)), history.curves[0]);
+    let mid = history.at(d0.add_days(5));
+    assert!(close(mid.level, 0.031));
+    assert!(close(mid.slope, -0.011));
+
+    let forecast = history.forecast(d2.add_days(10));
+    assert!(close(forecast.level, 0.036));
+    assert!(close(forecast.slope, -0.008));
+    assert!(close(forecast.decay, 2.5));
+    assert!(close(history.average().level, 0.032));
+}
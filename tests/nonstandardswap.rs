@@ -0,0 +1,152 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{Event, FixedRateCoupon, FixedRateLeg, IborCoupon, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{NonstandardSwap, SwapType, VanillaSwap};
+use quantlib::pricingengines::DiscountingSwapEngine;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index = IborIndex<WeekendsOnly, Curve, Actual360>;
+type Swap = NonstandardSwap<FixedRateCoupon<Actual365Fixed>, IborCoupon<Index, Actual360>>;
+
+fn schedule(start: Date, years: i64, months: i64) -> Schedule {
+    Schedule::new(
+        start,
+        start.add_months(12 * years),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn index() -> Rc<Index> {
+    Rc::new(IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.02))),
+    ))
+}
+
+fn spot() -> Date {
+    Date::new(17, Month::January, 2020)
+}
+
+/// Payer swap from `start` over `years`, annual fixed against 6M
+/// floating, with per year fixed rates and notionals, the floating
+/// notionals following the fixed ones.
+fn swap(start: Date, years: i64, rates: Vec<f64>, notionals: Vec<f64>) -> Swap {
+    let floating: Vec<f64> = notionals.iter().flat_map(|n| vec![*n, *n]).collect();
+    NonstandardSwap::new(
+        SwapType::Payer,
+        FixedRateLeg::new(schedule(start, years, 12), Actual365Fixed)
+            .with_notionals(notionals)
+            .with_rates(rates)
+            .build(),
+        IborLeg::new(schedule(start, years, 6), index(), Actual360)
+            .with_notionals(floating)
+            .build(),
+        0.0,
+    )
+}
+
+fn engine() -> DiscountingSwapEngine<Curve> {
+    DiscountingSwapEngine::new(Rc::new(flat_curve(0.02)))
+        .with_settlement_date(reference_date())
+        .with_npv_date(reference_date())
+}
+
+#[test]
+fn test_constant_terms_match_vanilla_swap() {
+    let nonstandard = swap(spot(), 5, vec![0.025], vec![1.0e6]);
+    let vanilla = VanillaSwap::new(
+        SwapType::Payer,
+        FixedRateLeg::new(schedule(spot(), 5, 12), Actual365Fixed)
+            .with_notional(1.0e6)
+            .with_rate(0.025)
+            .build(),
+        0.025,
+        IborLeg::new(schedule(spot(), 5, 6), index(), Actual360)
+            .with_notional(1.0e6)
+            .build(),
+        0.0,
+    );
+    let expected = engine().calculate(&vanilla);
+    let results = engine().calculate_nonstandard(&nonstandard);
+    assert!((results.npv - expected.npv).abs() < 1.0e-8);
+    assert!((results.fair_rate - expected.fair_rate).abs() < 1.0e-12);
+    assert!((results.fair_spread - expected.fair_spread).abs() < 1.0e-12);
+    let curve = flat_curve(0.02);
+    assert!((nonstandard.npv(&curve, reference_date()) - results.npv).abs() < 1.0e-8);
+}
+
+#[test]
+fn test_amortizing_swap_fair_rate_reprices_to_zero() {
+    let notionals = vec![100.0e6, 80.0e6, 60.0e6, 40.0e6, 20.0e6];
+    let amortizing = swap(spot(), 5, vec![0.025], notionals.clone());
+    assert_eq!(amortizing.fixed_notionals(), notionals);
+    assert_eq!(amortizing.floating_notionals()[3], 80.0e6);
+    let results = engine().calculate_nonstandard(&amortizing);
+    let curve = flat_curve(0.02);
+    assert!((results.fair_rate - amortizing.fair_rate(&curve, reference_date())).abs() < 1.0e-12);
+    let at_par = swap(spot(), 5, vec![results.fair_rate], notionals.clone());
+    assert!(engine().calculate_nonstandard(&at_par).npv.abs() < 1.0e-5);
+    // the same average notional, but front loaded onto larger discounts
+    let bullet = engine().calculate_nonstandard(&swap(spot(), 5, vec![0.025], vec![60.0e6]));
+    assert!(results.fixed_leg_bps > bullet.fixed_leg_bps);
+}
+
+#[test]
+fn test_step_up_coupons() {
+    let rates = vec![0.01, 0.015, 0.02, 0.025, 0.03];
+    let step_up = swap(spot(), 5, rates.clone(), vec![1.0e6]);
+    assert_eq!(step_up.fixed_rates(), rates);
+    let results = engine().calculate_nonstandard(&step_up);
+    // the flat equivalent lies inside the steps and prices to par
+    assert!(results.fair_rate > 0.01 && results.fair_rate < 0.03);
+    let flat = swap(spot(), 5, vec![results.fair_rate], vec![1.0e6]);
+    assert!(engine().calculate_nonstandard(&flat).npv.abs() < 1.0e-6);
+    let with_spread = NonstandardSwap::new(
+        SwapType::Payer,
+        step_up.fixed_leg,
+        IborLeg::new(schedule(spot(), 5, 6), index(), Actual360)
+            .with_notional(1.0e6)
+            .with_spread(results.fair_spread)
+            .build(),
+        results.fair_spread,
+    );
+    assert!(engine().calculate_nonstandard(&with_spread).npv.abs() < 1.0e-6);
+}
+
+#[test]
+fn test_forward_start_with_different_frequencies() {
+    let start = spot().add_months(24);
+    let forward = swap(start, 3, vec![0.025], vec![1.0e6]);
+    assert_eq!(forward.fixed_leg.len(), 3);
+    assert_eq!(forward.floating_leg.len(), 6);
+    assert!(forward.fixed_leg[0].date() > start);
+    let curve = flat_curve(0.02);
+    let results = engine()
+        .with_npv_date(start)
+        .calculate_nonstandard(&forward);
+    let today = forward.npv(&curve, reference_date());
+    assert!((results.npv - today / curve.discount(start, true)).abs() < 1.0e-8);
+    // on one curve the forward swap rate is close to the flat zero rate
+    assert!((results.fair_rate - 0.02).abs() < 5.0e-4);
+}
@@ -0,0 +1,99 @@
+extern crate quantlib;
+
+use quantlib::cashflows as cf;
+use quantlib::indexes::{IborIndex, InterestRateIndex, SwapIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, Month, Period, TimeUnit,
+    WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Euribor = IborIndex<WeekendsOnly, Curve, Actual360>;
+type EurSwap = SwapIndex<WeekendsOnly, Curve, Actual365Fixed, Actual360>;
+
+fn swap_index(curve: &Rc<Curve>) -> EurSwap {
+    let euribor: Euribor = IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::clone(curve)),
+    );
+    SwapIndex::new(
+        "EuriborSwapIsdaFixA",
+        Period::new(5, TimeUnit::Years),
+        2,
+        Calendar::new(WeekendsOnly),
+        Period::new(1, TimeUnit::Years),
+        BusinessDayConvention::ModifiedFollowing,
+        Actual365Fixed {},
+        Rc::new(euribor),
+    )
+}
+
+fn evaluation_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+#[test]
+fn test_forward_npv_is_spot_npv_compounded_to_the_npv_date() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(&curve);
+    let swap = index.underlying_swap(evaluation_date(), 0.03);
+    let spot = index.value_date(evaluation_date());
+    let npv = swap.npv(curve.as_ref(), evaluation_date());
+    let forward = swap.npv_at(curve.as_ref(), evaluation_date(), spot);
+    assert!((forward - npv / curve.discount(spot, true)).abs() < 1.0e-12);
+    assert!(forward.abs() > npv.abs());
+
+    // valuing as of the evaluation date gives back the plain npv.
+    let same = swap.npv_at(curve.as_ref(), evaluation_date(), evaluation_date());
+    assert!((same - npv).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_leg_values_exclude_flows_up_to_settlement() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(&curve);
+    let swap = index.underlying_swap(evaluation_date(), 0.03);
+    let first = swap.fixed_leg[0].base.payment_date;
+    let spot = index.value_date(evaluation_date());
+
+    let full = cf::npv_at(&swap.fixed_leg, curve.as_ref(), false, spot, spot);
+    let later = cf::npv_at(&swap.fixed_leg, curve.as_ref(), false, first, spot);
+    let first_amount = full - later;
+    let expected =
+        cf::npv(&swap.fixed_leg[..1], curve.as_ref(), false, spot) / curve.discount(spot, true);
+    assert!((first_amount - expected).abs() < 1.0e-10);
+
+    // flows paid on the settlement date are kept only when asked for.
+    let with = cf::npv_at(&swap.fixed_leg, curve.as_ref(), true, first, spot);
+    assert!((with - full).abs() < 1.0e-10);
+
+    let bps = cf::bps_at(&swap.fixed_leg, curve.as_ref(), false, spot, spot);
+    assert!(
+        (bps - cf::bps(&swap.fixed_leg, curve.as_ref(), false, spot) / curve.discount(spot, true))
+            .abs()
+            < 1.0e-12
+    );
+}
+
+#[test]
+fn test_fair_rate_does_not_depend_on_the_npv_date() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(&curve);
+    let spot = index.value_date(evaluation_date());
+    let fair = index
+        .underlying_swap(evaluation_date(), 0.03)
+        .fair_rate(curve.as_ref(), spot);
+    let swap = index.underlying_swap(evaluation_date(), fair);
+    assert!(swap.npv_at(curve.as_ref(), spot, spot).abs() < 1.0e-12);
+}
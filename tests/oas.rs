@@ -0,0 +1,80 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{self as cf, Base, FixedRateCoupon};
+use quantlib::pricingengines::option_adjusted_spread;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::ZeroSpreadedTermStructure;
+use quantlib::testutils::market::{flat_curve, flat_rate, reference_date};
+use quantlib::time::{Actual365Fixed, Date};
+
+fn annual_coupons(coupon: f64, years: i64) -> Vec<FixedRateCoupon<Actual365Fixed>> {
+    let start = reference_date();
+    (0..years)
+        .map(|k| {
+            let (d1, d2) = (start.add_months(12 * k), start.add_months(12 * (k + 1)));
+            let base = Base {
+                nominal: 100.0,
+                day_counter: Actual365Fixed {},
+                payment_date: d2,
+                accrual_start_date: d1,
+                accrual_end_date: d2,
+                reference_period_start: d1,
+                reference_period_end: d2,
+            };
+            FixedRateCoupon::new(base, flat_rate(coupon))
+        })
+        .collect()
+}
+
+fn maturity(years: i64) -> Date {
+    reference_date().add_months(12 * years)
+}
+
+fn bullet_price<Y: Yts>(curve: &Y) -> f64 {
+    cf::npv(&annual_coupons(0.05, 5), curve, false, reference_date())
+        + 100.0 * curve.discount(maturity(5), true)
+}
+
+#[test]
+fn test_spreaded_curve_discounts() {
+    let curve = ZeroSpreadedTermStructure::new(flat_curve(0.02), 0.015);
+    let t = curve.time_from_reference(maturity(3));
+    assert!((curve.discount(maturity(3), true) - (-0.035 * t).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_discounting_pricer_gives_the_z_spread() {
+    let market = ZeroSpreadedTermStructure::new(flat_curve(0.02), 0.0125);
+    let target = bullet_price(&market);
+    let mut curve = ZeroSpreadedTermStructure::new(flat_curve(0.02), 0.0);
+    let spread = option_adjusted_spread(&mut curve, target, bullet_price);
+    assert!((spread - 0.0125).abs() < 1.0e-9);
+    assert!((curve.spread - spread).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_embedded_call_lowers_the_oas() {
+    // issuer call at par after three years, valued as the intrinsic value
+    // of the remaining bond on its forward price
+    let callable_price = |c: &ZeroSpreadedTermStructure<_>| {
+        let call = maturity(3);
+        let forward: f64 = (cf::npv(&annual_coupons(0.05, 5), c, false, call)
+            - cf::npv(&annual_coupons(0.05, 3), c, false, call)
+            + 100.0 * c.discount(maturity(5), true))
+            / c.discount(call, true);
+        bullet_price(c) - c.discount(call, true) * (forward - 100.0).max(0.0)
+    };
+    let target = 101.0;
+    let mut curve = ZeroSpreadedTermStructure::new(flat_curve(0.02), 0.0);
+    let z_spread = option_adjusted_spread(&mut curve, target, bullet_price);
+    let oas = option_adjusted_spread(&mut curve, target, callable_price);
+    assert!(oas < z_spread);
+    assert!((callable_price(&curve) - target).abs() < 1.0e-7);
+}
+
+#[test]
+#[should_panic(expected = "no spread reprices the target")]
+fn test_unreachable_price() {
+    let mut curve = ZeroSpreadedTermStructure::new(flat_curve(0.02), 0.0);
+    option_adjusted_spread(&mut curve, -1.0, |_| 100.0);
+}
@@ -0,0 +1,143 @@
+extern crate quantlib;
+
+use quantlib::indexes::{IborIndex, Index};
+use quantlib::patterns::{ChangeFlag, Observable, Observer};
+use quantlib::quotes::{Quote, SimpleQuote};
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure};
+use quantlib::termstructures::{Compounding, FlatForward};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{
+    Actual365Fixed, BusinessDayConvention, Calendar, Frequency, Period, TimeUnit, WeekendsOnly,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type Curve = FlatForward<WeekendsOnly>;
+
+fn curve(rate: f64) -> Curve {
+    FlatForward::new(
+        reference_date(),
+        SimpleQuote::new(rate),
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        Calendar::new(WeekendsOnly),
+    )
+}
+
+#[derive(Default)]
+struct Counter {
+    updates: Cell<usize>,
+}
+
+impl Observer for Counter {
+    fn update(&self) {
+        self.updates.set(self.updates.get() + 1);
+    }
+}
+
+#[test]
+fn test_registrations_are_unique_and_weak() {
+    let observable = Observable::new();
+    let counter = Rc::new(Counter::default());
+    let observer: Rc<dyn Observer> = counter.clone();
+    observable.register_observer(Rc::clone(&observer));
+    observable.register_observer(Rc::clone(&observer));
+    observable.notify_observers();
+    assert_eq!(counter.updates.get(), 1);
+
+    observable.unregister_observer(&observer);
+    observable.notify_observers();
+    assert_eq!(counter.updates.get(), 1);
+
+    // dropping an observer unregisters it
+    let flag = ChangeFlag::new();
+    observable.register_observer(flag.clone());
+    assert_eq!(observable.observer_count(), 1);
+    drop(flag);
+    assert_eq!(observable.observer_count(), 0);
+    observable.notify_observers();
+}
+
+#[test]
+fn test_quote_changes_flag_dependent_curves() {
+    let curve = curve(0.01);
+    let flag = ChangeFlag::new();
+    curve.observable().unwrap().register_observer(flag.clone());
+    assert!(!flag.is_set());
+    // setting the same value is not a change
+    curve.forward.set_value(0.01);
+    assert!(!flag.take());
+    curve.forward.set_value(0.02);
+    assert!(flag.take());
+    assert!(!flag.is_set());
+    curve.forward.reset();
+    assert!(flag.is_set());
+    assert!(!curve.forward.is_valid());
+}
+
+#[test]
+fn test_notifications_reach_index_observers() {
+    let curve = Rc::new(curve(0.01));
+    let index: IborIndex<WeekendsOnly, Curve, Actual365Fixed> = IborIndex::new(
+        "Euribor",
+        Period::new(3, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        false,
+        Actual365Fixed,
+        Some(Rc::clone(&curve)),
+    );
+    let instrument = ChangeFlag::new();
+    index
+        .observable()
+        .unwrap()
+        .register_observer(instrument.clone());
+    // a new fixing changes the values of past coupons
+    index.add_fixing(reference_date(), 0.011);
+    assert!(instrument.take());
+    // as does the curve, through the index
+    curve.observable().unwrap().notify_observers();
+    assert!(instrument.take());
+    // a quote shared through an `Rc` is one quote
+    let quote = Rc::new(SimpleQuote::new(1.0));
+    let flag = ChangeFlag::new();
+    quote.observable().unwrap().register_observer(flag.clone());
+    Rc::clone(&quote).set_value(2.0);
+    assert!(flag.take());
+    assert_eq!(quote.value(), 2.0);
+}
+
+#[test]
+fn test_one_quote_drives_two_curves() {
+    let quote = Rc::new(SimpleQuote::new(0.02));
+    let on = |compounding| {
+        FlatForward::new(
+            reference_date(),
+            Rc::clone(&quote),
+            Actual365Fixed,
+            compounding,
+            Frequency::Annual,
+            Calendar::new(WeekendsOnly),
+        )
+    };
+    let (continuous, annual) = (on(Compounding::Continuous), on(Compounding::Compounded));
+    let flags = (ChangeFlag::new(), ChangeFlag::new());
+    continuous
+        .observable()
+        .unwrap()
+        .register_observer(flags.0.clone());
+    annual
+        .observable()
+        .unwrap()
+        .register_observer(flags.1.clone());
+    // the market quote moves, not either curve
+    quote.set_value(0.03);
+    assert!(flags.0.take() && flags.1.take());
+    assert!((continuous.discount_with_time(2.0, false) - (-0.06f64).exp()).abs() < 1.0e-15);
+    assert!((annual.discount_with_time(2.0, false) - 1.03f64.powi(-2)).abs() < 1.0e-15);
+    quote.reset();
+    assert!(flags.0.is_set() && flags.1.is_set());
+    assert!(!continuous.forward.is_valid() && !annual.forward.is_valid());
+}
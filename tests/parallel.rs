@@ -0,0 +1,19 @@
+extern crate quantlib;
+
+use quantlib::utils::Parallelism;
+
+#[test]
+fn test_parallel_map_matches_sequential() {
+    let items: Vec<f64> = (0..1000).map(|i| i as f64 * 0.01).collect();
+    let f = |x: &f64| (-x).exp() * x.sin();
+
+    let sequential = Parallelism::Sequential.map(&items, f);
+    let threaded = Parallelism::Threads(7).map(&items, f);
+    assert_eq!(sequential, threaded);
+
+    let mut bumped = items.clone();
+    Parallelism::Threads(3).for_each_mut(&mut bumped, |x| *x += 1.0);
+    for (a, b) in bumped.iter().zip(&items) {
+        assert_eq!(*a, b + 1.0);
+    }
+}
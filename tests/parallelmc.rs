@@ -0,0 +1,124 @@
+extern crate quantlib;
+
+use quantlib::instruments::{OptionType, VanillaOption};
+use quantlib::math::{BoxMullerGaussianRng, RandomSequenceGenerator, SplitMix64};
+use quantlib::methods::montecarlo::{
+    MonteCarloModel, ParallelMonteCarlo, PathGenerator, Statistics,
+};
+use quantlib::pricingengines::{AnalyticEuropeanEngine, EuropeanPathPricer, McEuropeanEngine};
+use quantlib::processes::GeometricBrownianMotionProcess;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::utils::Parallelism;
+use std::rc::Rc;
+
+const TIMES: [f64; 4] = [0.25, 0.5, 0.75, 1.0];
+
+fn process() -> GeometricBrownianMotionProcess {
+    GeometricBrownianMotionProcess::new(100.0, 0.03, 0.2)
+}
+
+fn call() -> EuropeanPathPricer {
+    EuropeanPathPricer {
+        option_type: OptionType::Call,
+        strike: 100.0,
+        discount: 1.0,
+    }
+}
+
+fn statistics(batches: usize, parallelism: Parallelism, samples: usize) -> Statistics {
+    ParallelMonteCarlo::new(batches, parallelism).statistics(
+        &process(),
+        &TIMES,
+        &call(),
+        samples,
+        42,
+        true,
+    )
+}
+
+#[test]
+fn test_results_do_not_depend_on_threads() {
+    let sequential = statistics(8, Parallelism::Sequential, 20_001);
+    for parallelism in &[
+        Parallelism::Threads(2),
+        Parallelism::Threads(3),
+        Parallelism::Available,
+    ] {
+        assert_eq!(statistics(8, *parallelism, 20_001), sequential);
+    }
+    assert_eq!(sequential.samples, 20_001);
+}
+
+#[test]
+fn test_batches_run_on_their_own_streams() {
+    let starts: Vec<u64> = (0..100)
+        .map(|k| SplitMix64::stream(42, k).next_u64())
+        .collect();
+    for (i, a) in starts.iter().enumerate() {
+        assert!(starts[i + 1..].iter().all(|b| a != b));
+    }
+    assert_ne!(
+        SplitMix64::stream(42, 0).next_u64(),
+        SplitMix64::stream(43, 0).next_u64()
+    );
+    // a single batch is the plain model on the first stream
+    let generator = PathGenerator::new(Rc::new(process()), TIMES.to_vec());
+    let normals = BoxMullerGaussianRng::new(SplitMix64::stream(42, 0));
+    let rsg = RandomSequenceGenerator::new(normals, generator.times.len());
+    let mut model = MonteCarloModel::with_rsg(generator, call(), rsg).with_antithetic();
+    model.add_samples(1_000);
+    assert_eq!(
+        statistics(1, Parallelism::Threads(4), 1_000),
+        model.statistics
+    );
+}
+
+#[test]
+fn test_merged_statistics() {
+    let mut merged = Statistics::default();
+    let (mut a, mut b) = (Statistics::default(), Statistics::default());
+    for x in &[1.0, 2.0, 3.0] {
+        a.add(*x);
+        merged.add(*x);
+    }
+    for x in &[4.0, 5.0] {
+        b.add(*x);
+        merged.add(*x);
+    }
+    a.merge(&b);
+    assert_eq!(a, merged);
+    assert_eq!(a.mean(), 3.0);
+    // different batchings are different samples of the same value
+    let (four, sixteen) = (
+        statistics(4, Parallelism::Available, 40_000),
+        statistics(16, Parallelism::Available, 40_000),
+    );
+    assert_ne!(four.sum, sixteen.sum);
+    let error = four.error_estimate().hypot(sixteen.error_estimate());
+    assert!((four.mean() - sixteen.mean()).abs() < 4.0 * error);
+}
+
+#[test]
+fn test_parallel_european_engine() {
+    let (r, sigma) = (0.05, 0.2);
+    let option = VanillaOption::new(OptionType::Put, 95.0, reference_date().add_months(12));
+    let risk_neutral = Rc::new(GeometricBrownianMotionProcess::new(100.0, r, sigma));
+    let engine = McEuropeanEngine::new(risk_neutral, Rc::new(flat_curve(r)), 1, 100_000, 5)
+        .with_antithetic();
+    let parallel = ParallelMonteCarlo::new(8, Parallelism::Available);
+    let result = engine.npv_parallel(&option, &parallel);
+    let sequential = engine.npv_parallel(
+        &option,
+        &ParallelMonteCarlo::new(8, Parallelism::Sequential),
+    );
+    assert_eq!(result, sequential);
+    let expected = AnalyticEuropeanEngine::new(
+        Rc::new(flat_curve(r)),
+        Rc::new(flat_curve(0.0)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma))),
+    )
+    .npv(&option, 100.0);
+    assert!((result.value - expected).abs() < 3.0 * result.error_estimate);
+}
@@ -0,0 +1,175 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{self, FixedRateLeg};
+use quantlib::math::Interpolation;
+use quantlib::quotes::SimpleQuote;
+use quantlib::risk::{curve_deltas, QuoteJacobian};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    DepositRateHelper, KeyRateShiftedCurve, PiecewiseYieldCurve, RateHelper, SwapRateHelper,
+    YieldTermStructure,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Period,
+    Schedule, Thirty360, TimeUnit, WeekendsOnly,
+};
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+
+const SHIFT: f64 = 1.0e-4;
+
+fn months(n: i64) -> Date {
+    reference_date().add_months(n)
+}
+
+fn annual_schedule(years: i64) -> Schedule {
+    Schedule::new(
+        reference_date(),
+        months(12 * years),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+/// Deposits to six months and annual swaps to five years, the swaps
+/// bumped by `bumps` over the flat 2% curve quotes.
+fn helpers(bumps: &[f64]) -> Vec<Box<dyn RateHelper>> {
+    let market = flat_curve(0.02);
+    let quoted = |h: &dyn RateHelper, bump: f64| {
+        SimpleQuote::new(h.implied_quote_on(&|d| market.discount(d, true)) + bump)
+    };
+    let mut helpers: Vec<Box<dyn RateHelper>> = vec![];
+    for (k, &n) in [3, 6].iter().enumerate() {
+        let helper = DepositRateHelper::new(
+            SimpleQuote::new(0.0),
+            reference_date(),
+            months(n),
+            Actual360,
+        );
+        let quote = quoted(&helper, bumps[k]);
+        helpers.push(Box::new(DepositRateHelper { quote, ..helper }));
+    }
+    for (k, &years) in [2, 3, 5].iter().enumerate() {
+        let helper = SwapRateHelper::new(
+            SimpleQuote::new(0.0),
+            annual_schedule(years),
+            Thirty360::default(),
+        );
+        let quote = quoted(&helper, bumps[k + 2]);
+        helpers.push(Box::new(SwapRateHelper { quote, ..helper }));
+    }
+    helpers
+}
+
+fn refs(helpers: &[Box<dyn RateHelper>]) -> Vec<&dyn RateHelper> {
+    helpers.iter().map(|h| h.as_ref()).collect()
+}
+
+fn bootstrap(bumps: &[f64]) -> Curve {
+    let helpers = helpers(bumps);
+    PiecewiseYieldCurve::new(
+        reference_date(),
+        &refs(&helpers),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::LogLinear,
+    )
+    .unwrap()
+    .term_structure()
+}
+
+/// A four year annual 2.5% bond, between the last two swap pillars.
+fn bond_npv<Y: Yts>(curve: &Y) -> f64 {
+    let leg = FixedRateLeg::new(annual_schedule(4), Actual365Fixed)
+        .with_notional(100.0)
+        .with_rate(0.025)
+        .build();
+    cashflows::npv(&leg, curve, false, reference_date()) + 100.0 * curve.discount(months(48), true)
+}
+
+#[test]
+fn test_jacobian_is_lower_triangular() {
+    let helpers = helpers(&[0.0; 5]);
+    let jacobian = QuoteJacobian::new(&flat_curve(0.02), &refs(&helpers), SHIFT);
+    assert_eq!(jacobian.pillar_dates[4], months(60));
+    for i in 0..5 {
+        // a quote only depends on the pillars up to its own
+        for j in i + 1..5 {
+            assert!(jacobian.matrix[(i, j)].abs() < 1.0e-12);
+        }
+        assert!(jacobian.matrix[(i, i)] > 0.5);
+    }
+    // deposits depend on their maturity discount alone
+    assert!(jacobian.matrix[(1, 0)].abs() < 1.0e-12);
+}
+
+#[test]
+fn test_helper_replica_has_risk_to_its_own_quote_only() {
+    let helpers = helpers(&[0.0; 5]);
+    let refs = refs(&helpers);
+    let jacobian = QuoteJacobian::new(&flat_curve(0.02), &refs, SHIFT);
+    for (k, helper) in refs.iter().enumerate() {
+        let deltas = curve_deltas(&[jacobian.buckets("USD-SOFR")], SHIFT, |s| {
+            let curve = KeyRateShiftedCurve::new(flat_curve(0.02), s[0].clone());
+            helper.implied_quote_on(&|d| curve.discount(d, true))
+        });
+        let quote_deltas = jacobian.quote_deltas(&deltas, "USD-SOFR");
+        for (i, q) in quote_deltas.iter().enumerate() {
+            let expected = if i == k { SHIFT } else { 0.0 };
+            assert!((q.delta - expected).abs() < 1.0e-12);
+            assert_eq!(q.pillar, jacobian.pillar_dates[i]);
+        }
+    }
+}
+
+#[test]
+fn test_quote_deltas_match_rebootstrapped_curves() {
+    let curve = bootstrap(&[0.0; 5]);
+    let helpers = helpers(&[0.0; 5]);
+    let jacobian = QuoteJacobian::new(&curve, &refs(&helpers), SHIFT);
+    let deltas = curve_deltas(&[jacobian.buckets("USD-SOFR")], SHIFT, |s| {
+        bond_npv(&KeyRateShiftedCurve::new(
+            bootstrap(&[0.0; 5]),
+            s[0].clone(),
+        ))
+    });
+    let quote_deltas = jacobian.quote_deltas(&deltas, "USD-SOFR");
+    // against bumping each quote and bootstrapping again; the key rate
+    // shifts spread the risk between the bond's neighbouring pillars
+    // unlike the curve's interpolation, but the total is kept
+    let mut repriced = 0.0;
+    for k in 0..5 {
+        let mut up = [0.0; 5];
+        up[k] = SHIFT;
+        let mut down = [0.0; 5];
+        down[k] = -SHIFT;
+        repriced += (bond_npv(&bootstrap(&up)) - bond_npv(&bootstrap(&down))) / 2.0;
+    }
+    let total: f64 = quote_deltas.iter().map(|q| q.delta).sum();
+    assert!((total - repriced).abs() < 1.0e-2 * repriced.abs());
+    // nearly all of it on the three and five year swaps
+    assert!(quote_deltas[3].delta < 0.0 && quote_deltas[4].delta < 0.0);
+    assert!(quote_deltas[3].delta + quote_deltas[4].delta < 0.99 * total);
+}
+
+#[test]
+fn test_parallel_risk_is_preserved() {
+    let helpers = helpers(&[0.0; 5]);
+    let jacobian = QuoteJacobian::new(&flat_curve(0.02), &refs(&helpers), SHIFT);
+    let deltas = curve_deltas(&[jacobian.buckets("USD-SOFR")], SHIFT, |s| {
+        bond_npv(&KeyRateShiftedCurve::new(flat_curve(0.02), s[0].clone()))
+    });
+    let zero_deltas: Vec<f64> = deltas.iter().map(|d| d.delta).collect();
+    let market = jacobian.market_deltas(&zero_deltas);
+    // moving the quotes as a parallel zero shift would give the parallel
+    // delta back
+    let parallel = jacobian.matrix.mul_vec(&[1.0; 5]);
+    let total: f64 = market.iter().zip(&parallel).map(|(x, q)| x * q).sum();
+    let expected: f64 = zero_deltas.iter().sum();
+    assert!((total - expected).abs() < 1.0e-12);
+}
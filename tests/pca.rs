@@ -0,0 +1,102 @@
+extern crate quantlib;
+
+use quantlib::math::{Interpolation, Matrix, PrincipalComponents};
+use quantlib::termstructures::{InterpolatedDiscountCurve, PcaCurveScenarioGenerator};
+use quantlib::time::{Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+fn curve() -> InterpolatedDiscountCurve<WeekendsOnly> {
+    let dates = vec![
+        Date::new(15, Month::January, 2020),
+        Date::new(15, Month::January, 2021),
+        Date::new(15, Month::January, 2022),
+        Date::new(15, Month::January, 2025),
+        Date::new(15, Month::January, 2030),
+    ];
+    let discounts = vec![1.0, 0.98, 0.961, 0.9, 0.8];
+    InterpolatedDiscountCurve::new(
+        dates,
+        discounts,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::LogLinear,
+    )
+}
+
+/// Node zero rates moving by a level and a smaller slope factor.
+fn history(days: usize) -> Matrix {
+    let maturities = [1.0, 2.0, 5.0, 10.0];
+    let mut rates = vec![0.02, 0.021, 0.023, 0.025];
+    let mut rows = vec![rates.clone()];
+    for k in 1..days {
+        let level = 1.0e-3 * (k as f64 * 1.3).sin();
+        let slope = 2.0e-4 * (k as f64 * 0.7).cos();
+        for (r, t) in rates.iter_mut().zip(&maturities) {
+            *r += level + slope * (t - 4.5) / 4.5;
+        }
+        rows.push(rates.clone());
+    }
+    Matrix::from_rows(&rows)
+}
+
+#[test]
+fn test_two_factors_explain_level_and_slope_moves() {
+    let generator = PcaCurveScenarioGenerator::new(curve(), &history(250));
+    let pca = &generator.pca;
+    assert_eq!(pca.components_for(0.999999), 2);
+    let ratios = pca.explained_variance_ratio();
+    assert!(ratios[0] > 0.9);
+    assert!((ratios.iter().sum::<f64>() - 1.0).abs() < 1.0e-12);
+
+    // the first factor is a parallel move.
+    let level: Vec<f64> = (0..4).map(|j| pca.loadings[(j, 0)]).collect();
+    assert!(level.iter().all(|l| (l - 0.5).abs() < 0.1));
+}
+
+#[test]
+fn test_scores_rebuild_the_observations() {
+    let observations = Matrix::from_rows(&[
+        vec![1.0, 2.0, 0.5],
+        vec![0.5, 1.0, 1.5],
+        vec![2.0, 0.0, 1.0],
+        vec![1.5, 1.5, -0.5],
+    ]);
+    let pca = PrincipalComponents::new(&observations);
+    let scores = pca.scores(&observations);
+    for k in 0..4 {
+        for j in 0..3 {
+            let rebuilt: f64 = pca.mean[j]
+                + (0..3)
+                    .map(|c| scores[(k, c)] * pca.loadings[(j, c)])
+                    .sum::<f64>();
+            assert!((rebuilt - observations[(k, j)]).abs() < 1.0e-12);
+        }
+    }
+    // score variances are the component variances.
+    for c in 0..3 {
+        let var: f64 = (0..4).map(|k| scores[(k, c)] * scores[(k, c)]).sum::<f64>() / 3.0;
+        assert!((var - pca.variances[c]).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn test_scenarios_shift_node_zero_rates_along_factors() {
+    let base = curve();
+    let generator = PcaCurveScenarioGenerator::new(base.clone(), &history(250));
+    let shifts = generator.node_shifts(&[2.0]);
+    let shocked = generator.scenario(&[2.0]);
+    assert_eq!(shocked.dates, base.dates);
+    assert_eq!(shocked.discounts[0], 1.0);
+    for j in 1..base.times.len() {
+        let zero = |c: &InterpolatedDiscountCurve<WeekendsOnly>| -c.discounts[j].ln() / c.times[j];
+        assert!((zero(&shocked) - zero(&base) - shifts[j - 1]).abs() < 1.0e-14);
+    }
+    let sigma = generator.pca.variances[0].sqrt();
+    assert!(shifts.iter().all(|s| *s > 0.0 && *s < 2.0 * sigma));
+
+    let scenarios = generator.scenarios(2, 1.0);
+    assert_eq!(scenarios.len(), 4);
+    // up and down scenarios are symmetric in zero rates.
+    let j = 3;
+    let zero = |c: &InterpolatedDiscountCurve<WeekendsOnly>| -c.discounts[j].ln() / c.times[j];
+    assert!((zero(&scenarios[0]) + zero(&scenarios[1]) - 2.0 * zero(&base)).abs() < 1.0e-14);
+}
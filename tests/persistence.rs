@@ -0,0 +1,99 @@
+extern crate quantlib;
+
+use quantlib::persistence::{CurveSnapshot, MarketState, ModelSnapshot, SCHEMA_VERSION};
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Date, Month};
+
+fn market_state() -> MarketState {
+    let mut curve = flat_curve(0.03);
+    let times = vec![0.5, 1.0, 2.0, 5.0, 10.0];
+    let snapshot = CurveSnapshot::from_curve("EUR-ESTR", &mut curve, times);
+    let heston = ModelSnapshot::new(
+        "SX5E-Heston",
+        vec![
+            ("v0".to_string(), 0.04),
+            ("kappa".to_string(), 1.5),
+            ("theta".to_string(), 0.05),
+        ],
+    );
+    MarketState::new(Date::new(14, Month::October, 2026), 42)
+        .with_curve(snapshot)
+        .with_model(heston)
+}
+
+#[test]
+fn test_round_trip() {
+    let state = market_state();
+    let bytes = state.to_bytes();
+    assert_eq!(&bytes[..4], b"QLMS");
+    assert_eq!(u16::from_le_bytes([bytes[4], bytes[5]]), SCHEMA_VERSION);
+
+    let restored = MarketState::from_bytes(&bytes).unwrap();
+    assert_eq!(restored, state);
+    assert_eq!(
+        restored.model("SX5E-Heston").unwrap().parameter("kappa"),
+        Some(1.5)
+    );
+    assert!(restored.curve("USD-SOFR").is_none());
+}
+
+#[test]
+fn test_snapshot_reproduces_curve() {
+    let curve = flat_curve(0.03);
+    let state = market_state();
+    let snapshot = state.curve("EUR-ESTR").unwrap();
+    for t in &[0.1, 0.5, 0.75, 3.0, 10.0, 15.0] {
+        let expected = curve.discount_with_time(*t, true);
+        assert!((snapshot.discount(*t) - expected).abs() < 1.0e-12);
+    }
+    assert_eq!(snapshot.reference_date, reference_date());
+}
+
+#[test]
+fn test_rejects_corrupted_data() {
+    let bytes = market_state().to_bytes();
+
+    let mut flipped = bytes.clone();
+    let last = flipped.len() - 1;
+    flipped[last] ^= 0x01;
+    assert_eq!(
+        MarketState::from_bytes(&flipped),
+        Err("integrity hash mismatch")
+    );
+
+    let truncated = &bytes[..bytes.len() - 3];
+    assert_eq!(
+        MarketState::from_bytes(truncated),
+        Err("payload length mismatch")
+    );
+
+    let mut newer = bytes.clone();
+    newer[4..6].copy_from_slice(&(SCHEMA_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        MarketState::from_bytes(&newer),
+        Err("unsupported schema version")
+    );
+
+    assert_eq!(
+        MarketState::from_bytes(b"{}"),
+        Err("not a market state file")
+    );
+    assert_eq!(
+        MarketState::from_bytes(b"QLMS"),
+        Err("unexpected end of data")
+    );
+}
+
+#[test]
+fn test_save_and_load() {
+    let path = std::env::temp_dir().join(format!("quantlib-state-{}.bin", std::process::id()));
+    let state = market_state();
+    state.save(&path).unwrap();
+    assert_eq!(MarketState::load(&path).unwrap(), state);
+
+    std::fs::write(&path, b"QLMS garbage").unwrap();
+    let error = MarketState::load(&path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,218 @@
+extern crate quantlib;
+
+use quantlib::errors::QuantLibError;
+use quantlib::math::Interpolation;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{
+    DepositRateHelper, FraRateHelper, PiecewiseYieldCurve, RateHelper, SwapRateHelper,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Period,
+    Schedule, Thirty360, TimeUnit, WeekendsOnly,
+};
+use quantlib::utils::Parallelism;
+
+fn months(n: i64) -> Date {
+    reference_date().add_months(n)
+}
+
+fn swap_schedule(years: i64) -> Schedule {
+    Schedule::new(
+        reference_date(),
+        months(12 * years),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+/// Deposits to six months, FRAs to a year and annual swaps to five years,
+/// quoted off `market`.
+fn helpers(market: &dyn Fn(Date) -> f64) -> Vec<Box<dyn RateHelper>> {
+    let quoted = |h: &dyn RateHelper| SimpleQuote::new(h.implied_quote_on(market));
+    let mut helpers: Vec<Box<dyn RateHelper>> = vec![];
+    for &n in &[3, 6] {
+        let helper = DepositRateHelper::new(
+            SimpleQuote::new(0.0),
+            reference_date(),
+            months(n),
+            Actual360,
+        );
+        let quote = quoted(&helper);
+        helpers.push(Box::new(DepositRateHelper { quote, ..helper }));
+    }
+    for &(start, end) in &[(6, 9), (9, 12)] {
+        let helper =
+            FraRateHelper::new(SimpleQuote::new(0.0), months(start), months(end), Actual360);
+        let quote = quoted(&helper);
+        helpers.push(Box::new(FraRateHelper { quote, ..helper }));
+    }
+    for &years in &[2, 3, 5] {
+        let helper = SwapRateHelper::new(
+            SimpleQuote::new(0.0),
+            swap_schedule(years),
+            Thirty360::default(),
+        );
+        let quote = quoted(&helper);
+        helpers.push(Box::new(SwapRateHelper { quote, ..helper }));
+    }
+    helpers
+}
+
+fn bootstrap(
+    helpers: &[Box<dyn RateHelper>],
+    interpolation: Interpolation,
+) -> PiecewiseYieldCurve<WeekendsOnly> {
+    let refs: Vec<&dyn RateHelper> = helpers.iter().map(|h| h.as_ref()).collect();
+    PiecewiseYieldCurve::new(
+        reference_date(),
+        &refs,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_log_linear_bootstrap_recovers_flat_curve() {
+    let market = flat_curve(0.02);
+    let helpers = helpers(&|d| market.discount(d, true));
+    let curve = bootstrap(&helpers, Interpolation::LogLinear);
+    assert!(curve.errors.iter().all(|e| e.abs() < 1.0e-10));
+    for (date, discount) in curve.nodes() {
+        assert!((discount - market.discount(date, true)).abs() < 1.0e-10);
+    }
+    let date = months(40);
+    assert!((curve.discount(date) - market.discount(date, true)).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_log_linear_forwards_are_flat_between_pillars() {
+    // upward sloping market
+    let market = |d: Date| {
+        let t = d.sub(reference_date()) as f64 / 365.0;
+        (-(0.01 * t + 0.002 * t * t)).exp()
+    };
+    let helpers = helpers(&market);
+    let curve = bootstrap(&helpers, Interpolation::LogLinear).curve;
+    let (t1, t2) = (curve.times[6] + 0.1, curve.times[7] - 0.1);
+    let (f1, f2) = (
+        curve.instantaneous_forward(t1),
+        curve.instantaneous_forward(t2),
+    );
+    assert!((f1 - f2).abs() < 1.0e-10);
+    assert!(curve.instantaneous_forward(4.0) > curve.instantaneous_forward(0.1));
+}
+
+#[test]
+fn test_cubic_bootstrap_reprices_helpers() {
+    let market = |d: Date| {
+        let t = d.sub(reference_date()) as f64 / 365.0;
+        (-(0.01 * t + 0.002 * t * t)).exp()
+    };
+    let helpers = helpers(&market);
+    let curve = bootstrap(&helpers, Interpolation::MonotonicCubic);
+    assert_eq!(curve.curve.interpolation, Interpolation::MonotonicCubic);
+    assert!(curve.errors.iter().all(|e| e.abs() < 1.0e-10));
+}
+
+#[test]
+fn test_bootstrapped_term_structure() {
+    let market = flat_curve(0.03);
+    let helpers = helpers(&|d| market.discount(d, true));
+    let curve = bootstrap(&helpers, Interpolation::LogLinear);
+    let ts = curve.term_structure();
+    let date = months(30);
+    assert!((ts.discount(date, true) - curve.discount(date)).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_unattainable_quote_fails_to_bootstrap() {
+    // no positive discount factor gives a -1000% three month deposit
+    let deposit = DepositRateHelper::new(
+        SimpleQuote::new(-10.0),
+        reference_date(),
+        months(3),
+        Actual360,
+    );
+    let error = PiecewiseYieldCurve::new(
+        reference_date(),
+        &[&deposit],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        Interpolation::LogLinear,
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(
+        error,
+        QuantLibError::BootstrapFailed { pillar_date, .. } if pillar_date == months(3)
+    ));
+}
+
+#[test]
+fn test_invalid_helpers_are_errors() {
+    let bootstrap = |helpers: &[&dyn RateHelper]| {
+        PiecewiseYieldCurve::new(
+            reference_date(),
+            helpers,
+            Calendar::new(WeekendsOnly),
+            Actual365Fixed,
+            Interpolation::LogLinear,
+        )
+        .err()
+    };
+    assert_eq!(bootstrap(&[]), Some(QuantLibError::NoHelpers));
+    let deposit = |quote, start: Date, end| {
+        DepositRateHelper::new(SimpleQuote::new(quote), start, end, Actual360)
+    };
+    let (three, also_three) = (
+        deposit(0.01, reference_date(), months(3)),
+        deposit(0.02, reference_date(), months(3)),
+    );
+    assert!(matches!(
+        bootstrap(&[&three, &also_three]),
+        Some(QuantLibError::InvalidPillar { pillar_date, .. }) if pillar_date == months(3)
+    ));
+    let past = deposit(0.01, reference_date().add_months(-6), reference_date());
+    assert_eq!(
+        bootstrap(&[&three, &past]).unwrap().to_string(),
+        format!(
+            "invalid pillar {}: not after the reference date {}",
+            reference_date(),
+            reference_date()
+        )
+    );
+}
+
+#[test]
+fn test_independent_curves_bootstrap_in_parallel() {
+    let curves = |parallelism: Parallelism| {
+        PiecewiseYieldCurve::bootstrap_all(&[0.01, 0.02, 0.03], parallelism, |rate: &f64| {
+            let market = flat_curve(*rate);
+            let helpers = helpers(&|d| market.discount(d, true));
+            let refs: Vec<&dyn RateHelper> = helpers.iter().map(|h| h.as_ref()).collect();
+            PiecewiseYieldCurve::new(
+                reference_date(),
+                &refs,
+                Calendar::new(WeekendsOnly),
+                Actual365Fixed,
+                Interpolation::LogLinear,
+            )
+        })
+    };
+    let sequential = curves(Parallelism::Sequential);
+    let parallel = curves(Parallelism::Threads(3));
+    for (s, p) in sequential.iter().zip(&parallel) {
+        assert_eq!(s.as_ref().unwrap().nodes(), p.as_ref().unwrap().nodes());
+    }
+    let date = months(30);
+    let third = flat_curve(0.03).discount(date, true);
+    assert!((parallel[2].as_ref().unwrap().discount(date) - third).abs() < 1.0e-10);
+}
@@ -0,0 +1,112 @@
+extern crate quantlib;
+
+use quantlib::termstructures::{
+    implied_move_probabilities, AverageRateFuture, PolicyScenario, PolicyScenarioMixture,
+    StepForwardCurve,
+};
+use quantlib::time::{Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+fn reference_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+fn meetings() -> Vec<Date> {
+    vec![
+        Date::new(18, Month::March, 2020),
+        Date::new(29, Month::April, 2020),
+        Date::new(10, Month::June, 2020),
+    ]
+}
+
+fn curve(forwards: Vec<f64>) -> StepForwardCurve<WeekendsOnly> {
+    StepForwardCurve::new(
+        reference_date(),
+        meetings(),
+        forwards,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+}
+
+/// Monthly futures from February to July priced off `curve`.
+fn futures(curve: &StepForwardCurve<WeekendsOnly>) -> Vec<AverageRateFuture> {
+    let months = [
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+    ];
+    months
+        .iter()
+        .map(|m| {
+            let future = AverageRateFuture::new(*m, 2020, 0.0);
+            let rate = curve.average_forward(future.start, future.end);
+            AverageRateFuture::new(*m, 2020, 100.0 * (1.0 - rate))
+        })
+        .collect()
+}
+
+/// 60% chance of hiking twice and 40% of holding.
+fn mixture() -> PolicyScenarioMixture<WeekendsOnly> {
+    PolicyScenarioMixture::new(
+        reference_date(),
+        meetings(),
+        vec![
+            PolicyScenario::new(0.6, vec![0.015, 0.0175, 0.02, 0.02]),
+            PolicyScenario::new(0.4, vec![0.015, 0.015, 0.015, 0.015]),
+        ],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+}
+
+#[test]
+fn test_expected_path_of_mixture() {
+    let mixture = mixture();
+    let expected = mixture.expected_curve();
+    for (f, target) in expected.forwards.iter().zip(&[0.015, 0.0165, 0.018, 0.018]) {
+        assert!((f - target).abs() < 1.0e-15);
+    }
+    let date = Date::new(1, Month::May, 2020);
+    assert!((mixture.expected_policy_rate(date) - 0.018).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_mixture_discount_is_above_expected_path() {
+    let mixture = mixture();
+    let expected = mixture.expected_curve();
+    let date = Date::new(15, Month::January, 2021);
+    let discount = mixture.discount(date);
+    let (hike, hold) = (
+        mixture.curves[0].discount(date),
+        mixture.curves[1].discount(date),
+    );
+    assert!(hike < discount && discount < hold);
+    assert!(discount > expected.discount(date));
+}
+
+#[test]
+fn test_probabilities_recovered_from_futures() {
+    let market = curve(vec![0.015, 0.0175, 0.02, 0.015]);
+    let probabilities = implied_move_probabilities(0.015, &meetings(), &futures(&market), 0.0025);
+    assert_eq!(probabilities.len(), 3);
+    for (p, target) in probabilities.iter().zip(&[1.0, 1.0, -2.0]) {
+        assert!((p.probability - target).abs() < 1.0e-9);
+    }
+    // April has a meeting, so the March move comes from the March average
+    assert!((probabilities[0].rate_after - 0.0175).abs() < 1.0e-12);
+    assert!((probabilities[1].rate_before - 0.0175).abs() < 1.0e-12);
+    assert!((probabilities[2].rate_after - 0.015).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_probabilities_of_mixture() {
+    let mixture = mixture();
+    let futures = futures(&mixture.expected_curve());
+    let probabilities = implied_move_probabilities(0.015, &meetings(), &futures, 0.0025);
+    for (p, target) in probabilities.iter().zip(&[0.6, 0.6, 0.0]) {
+        assert!((p.probability - target).abs() < 1.0e-9);
+    }
+}
@@ -0,0 +1,106 @@
+extern crate quantlib;
+
+use quantlib::errors::QuantLibError;
+use quantlib::math::{simpson, Bisection, Brent, DoubleDouble, NewtonSafe, Real, Secant, Solver1D};
+
+fn dd(x: f64) -> DoubleDouble {
+    DoubleDouble::from(x)
+}
+
+#[test]
+fn test_double_double_arithmetic() {
+    // 1 + 1e-20 is lost in double precision but not in double-double
+    assert_eq!((1.0 + 1.0e-20) - 1.0, 0.0);
+    let x = (dd(1.0) + dd(1.0e-20)) - dd(1.0);
+    assert!((x.to_f64() - 1.0e-20).abs() < 1.0e-36);
+    let third = dd(1.0) / dd(3.0);
+    let error = (third * dd(3.0) - dd(1.0)).abs();
+    assert!(error.to_f64() < 1.0e-31);
+    let root = dd(2.0).sqrt();
+    assert!((root * root - dd(2.0)).abs().to_f64() < 1.0e-31);
+}
+
+#[test]
+fn test_double_double_exp_and_ln() {
+    for &x in &[-30.0, -1.5, -1.0e-8, 0.3, 1.0, 7.25, 40.0] {
+        let y = dd(x).exp();
+        assert!((y.to_f64() / x.exp() - 1.0).abs() < 1.0e-15, "{}", x);
+        let back = y.ln();
+        assert!(
+            (back - dd(x)).abs().to_f64() < 1.0e-30 * x.abs().max(1.0),
+            "{}",
+            x
+        );
+    }
+    // e to 32 digits
+    let e = dd(1.0).exp();
+    let reference = DoubleDouble::new(std::f64::consts::E, 1.445_646_891_729_250_2e-16);
+    assert!((e - reference).abs().to_f64() < 1.0e-31);
+}
+
+#[test]
+fn test_kernels_in_either_precision() {
+    let f = |x: DoubleDouble| x * x - dd(2.0);
+    let root = dd(2.0).sqrt();
+    let brent = Brent::new(1.0e-30).checked_solve(f, dd(1.0), dd(2.0));
+    assert!((brent.unwrap() - root).abs().to_f64() < 1.0e-30);
+    let newton =
+        NewtonSafe::new(1.0e-30).solve_with_derivative(f, |x| dd(2.0) * x, dd(1.0), dd(2.0));
+    assert!((newton - root).abs().to_f64() < 1.0e-30);
+    let secant = Secant::new(1.0e-30).checked_solve(f, dd(1.0), dd(2.0));
+    assert!((secant.unwrap() - root).abs().to_f64() < 1.0e-30);
+    let bisection = Bisection::new(1.0e-30)
+        .with_max_iterations(120)
+        .solve(f, dd(1.0), dd(2.0));
+    assert!((bisection - root).abs().to_f64() < 1.0e-30);
+    let root = Secant::new(1.0e-14).solve(|x: f64| x * x - 2.0, 1.0, 2.0);
+    assert!((root - 2f64.sqrt()).abs() < 1.0e-15);
+    // failures are reported in either precision
+    let error = Secant::new(1.0e-30)
+        .with_max_iterations(2)
+        .checked_solve(f, dd(1.0), dd(2.0));
+    assert_eq!(
+        error,
+        Err(QuantLibError::NotConverged {
+            what: "secant solver".to_string(),
+            iterations: 2
+        })
+    );
+    assert!(matches!(
+        Secant::new(1.0e-12).checked_solve(f, dd(2.0), dd(3.0)),
+        Err(QuantLibError::RootNotBracketed { .. })
+    ));
+
+    // the error of Simpson's rule drops below double precision
+    let exact = dd(1.0).exp() - dd(1.0);
+    let integral = simpson(|x: DoubleDouble| x.exp(), dd(0.0), dd(1.0), 20_000);
+    assert!((integral - exact).abs().to_f64() < 1.0e-18);
+    let integral = simpson(f64::exp, 0.0, 1.0, 20_000);
+    assert!((integral - exact.to_f64()).abs() < 1.0e-13);
+}
+
+#[test]
+fn test_long_dated_yield_round_off() {
+    // the yield of 100 years of semiannual coupons of 4% priced at 90,
+    // discounting each flow separately
+    fn price<R: Real>(y: R) -> R {
+        let discount = R::from_f64(1.0) / (R::from_f64(1.0) + y * R::from_f64(0.5));
+        let (mut d, mut value) = (R::from_f64(1.0), R::from_f64(0.0));
+        for _ in 0..200 {
+            d = d * discount;
+            value = value + R::from_f64(2.0) * d;
+        }
+        value + R::from_f64(100.0) * d
+    }
+    let single = Brent::new(1.0e-15).solve(|y: f64| price(y) - 90.0, 0.01, 0.1);
+    let double =
+        Brent::new(1.0e-30).solve(|y: DoubleDouble| price(y) - dd(90.0), dd(0.01), dd(0.1));
+    // the extended precision yield reprices to far more digits
+    let error_single = (price(dd(single)) - dd(90.0)).abs().to_f64();
+    let error_double = (price(double) - dd(90.0)).abs().to_f64();
+    assert!(error_double < 1.0e-25);
+    assert!(error_double < error_single);
+    let difference = (dd(single) - double).abs().to_f64();
+    assert!(difference < 1.0e-14 && difference > 0.0);
+    assert!(DoubleDouble::epsilon() < f64::EPSILON);
+}
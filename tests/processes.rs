@@ -0,0 +1,91 @@
+extern crate quantlib;
+
+use quantlib::math::{BoxMullerGaussianRng, Rng, SplitMix64};
+use quantlib::processes::{
+    BlackScholesMertonProcess, GeometricBrownianMotionProcess, StochasticProcess1D,
+};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::WeekendsOnly;
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Bsm = BlackScholesMertonProcess<Curve, Curve, BlackConstantVol>;
+
+fn bsm(spot: f64, r: f64, q: f64, sigma: f64) -> Bsm {
+    BlackScholesMertonProcess::new(
+        SimpleQuote::new(spot),
+        Rc::new(flat_curve(r)),
+        Rc::new(flat_curve(q)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma))),
+    )
+}
+
+#[test]
+fn test_geometric_brownian_motion_moments() {
+    let gbm = GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2);
+    assert_eq!(gbm.x0(), 100.0);
+    assert!((gbm.drift(0.0, 50.0) - 2.5).abs() < 1.0e-15);
+    assert!((gbm.diffusion(0.0, 50.0) - 10.0).abs() < 1.0e-15);
+    let expected = 100.0 * 0.1f64.exp();
+    assert!((gbm.expectation(0.0, 100.0, 2.0) - expected).abs() < 1.0e-12);
+    let std_dev = expected * (0.08f64.exp() - 1.0).sqrt();
+    assert!((gbm.std_deviation(0.0, 100.0, 2.0) - std_dev).abs() < 1.0e-12);
+    assert!((gbm.variance(0.0, 100.0, 2.0) - std_dev * std_dev).abs() < 1.0e-9);
+    // a zero draw lands on the median
+    let median = 100.0 * ((0.05 - 0.02) * 2.0f64).exp();
+    assert!((gbm.evolve(0.0, 100.0, 2.0, 0.0) - median).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_geometric_brownian_motion_sampled_mean() {
+    let gbm = GeometricBrownianMotionProcess::new(100.0, 0.03, 0.25);
+    let mut rng = BoxMullerGaussianRng::new(SplitMix64::new(42));
+    let paths = 200_000;
+    let (dt, steps) = (0.25, 4);
+    let mut sum = 0.0;
+    for _ in 0..paths {
+        let mut x = gbm.x0();
+        for i in 0..steps {
+            x = gbm.evolve(i as f64 * dt, x, dt, rng.next_sample());
+        }
+        sum += x;
+    }
+    let mean = sum / paths as f64;
+    let expected = gbm.expectation(0.0, 100.0, 1.0);
+    let error = gbm.std_deviation(0.0, 100.0, 1.0) / (paths as f64).sqrt();
+    assert!((mean - expected).abs() < 4.0 * error);
+}
+
+#[test]
+fn test_black_scholes_merton_on_flat_curves() {
+    let process = bsm(100.0, 0.05, 0.02, 0.2);
+    assert_eq!(process.x0(), 100.0);
+    // drift and diffusion of the log spot
+    assert!((process.drift(1.0, 100.0) - (0.05 - 0.02 - 0.02)).abs() < 1.0e-10);
+    assert!((process.diffusion(1.0, 100.0) - 0.2).abs() < 1.0e-10);
+    let forward = 100.0 * (0.03f64 * 2.0).exp();
+    assert!((process.expectation(0.5, 100.0, 2.0) - forward).abs() < 1.0e-9);
+    assert!((process.std_deviation(0.5, 100.0, 2.0) - 0.2 * 2.0f64.sqrt()).abs() < 1.0e-12);
+    let median = forward * (-0.5 * 0.04f64 * 2.0).exp();
+    assert!((process.evolve(0.5, 100.0, 2.0, 0.0) - median).abs() < 1.0e-9);
+    assert!((process.apply(100.0, 0.1) - 100.0 * 0.1f64.exp()).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_black_scholes_merton_follows_market_data() {
+    let process = bsm(100.0, 0.05, 0.0, 0.2);
+    process.spot.set_value(120.0);
+    assert_eq!(process.x0(), 120.0);
+    // without dividends and volatility the spot accrues at the risk-free rate
+    let riskless = bsm(100.0, 0.05, 0.0, 0.0);
+    let x = riskless.evolve(0.0, 100.0, 1.0, 1.5);
+    assert!((x - 100.0 * 0.05f64.exp()).abs() < 1.0e-9);
+    // the same terminal law however the time is split
+    let one_step = process.evolve(0.0, 120.0, 1.0, 0.7);
+    let dw = 0.7 / 2.0f64.sqrt();
+    let two_steps = process.evolve(0.5, process.evolve(0.0, 120.0, 0.5, dw), 0.5, dw);
+    assert!((one_step - two_steps).abs() < 1.0e-9);
+}
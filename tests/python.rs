@@ -0,0 +1,94 @@
+#![cfg(feature = "python")]
+extern crate quantlib;
+
+use quantlib::python::curves::{ql_curve_discount, ql_curve_flat, ql_curve_reference_date};
+use quantlib::python::options::{ql_option_new, ql_option_npv};
+use quantlib::python::schedules::{ql_schedule_date, ql_schedule_new, ql_schedule_size};
+use quantlib::python::{ql_last_error, QL_FAILED, QL_OK};
+use std::ffi::CStr;
+
+// 2020-01-15 as given by Python's `date.toordinal()`
+const TODAY: i32 = 737_439;
+
+fn last_error() -> Option<String> {
+    let message = ql_last_error();
+    if message.is_null() {
+        None
+    } else {
+        Some(
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+#[test]
+fn test_dates_cross_as_ordinals() {
+    let curve = ql_curve_flat(TODAY, 0.03, 0).unwrap();
+    let mut reference_date = 0;
+    assert_eq!(
+        ql_curve_reference_date(Some(&curve), Some(&mut reference_date)),
+        QL_OK
+    );
+    assert_eq!(reference_date, TODAY);
+    assert_eq!(last_error(), None);
+
+    let mut discount = 0.0;
+    let status = ql_curve_discount(Some(&curve), TODAY + 365, false, Some(&mut discount));
+    assert_eq!(status, QL_OK);
+    assert!((discount - (-0.03f64).exp()).abs() < 1.0e-12);
+
+    // 2021-01-15, modified following every six months
+    let schedule = ql_schedule_new(TODAY, TODAY + 366, 6, 2, 0, 1, 1, 1, false).unwrap();
+    let (mut size, mut last) = (0, 0);
+    assert_eq!(ql_schedule_size(Some(&schedule), Some(&mut size)), QL_OK);
+    assert_eq!(size, 3);
+    let status = ql_schedule_date(Some(&schedule), size - 1, Some(&mut last));
+    assert_eq!(status, QL_OK);
+    assert_eq!(last, TODAY + 366);
+}
+
+#[test]
+fn test_failures_are_reported_through_the_last_error() {
+    assert!(ql_curve_flat(TODAY, 0.03, 9).is_none());
+    assert_eq!(last_error().as_deref(), Some("unknown calendar code 9"));
+    assert!(ql_curve_flat(i32::MAX, 0.03, 0).is_none());
+    assert_eq!(
+        last_error().as_deref(),
+        Some("invalid date ordinal 2147483647")
+    );
+
+    let mut discount = 0.0;
+    assert_eq!(
+        ql_curve_discount(None, TODAY, false, Some(&mut discount)),
+        QL_FAILED
+    );
+    assert_eq!(last_error().as_deref(), Some("null curve handle"));
+    let curve = ql_curve_flat(TODAY, 0.03, 0).unwrap();
+    assert_eq!(
+        ql_curve_discount(Some(&curve), TODAY, false, None),
+        QL_FAILED
+    );
+    assert_eq!(last_error().as_deref(), Some("null output pointer"));
+
+    let option = ql_option_new(1, 100.0, TODAY + 365).unwrap();
+    let mut npv = 0.0;
+    let status = ql_option_npv(
+        Some(&option),
+        100.0,
+        Some(&curve),
+        Some(&curve),
+        -0.2,
+        Some(&mut npv),
+    );
+    assert_eq!(status, QL_FAILED);
+    assert_eq!(last_error().as_deref(), Some("negative volatility -0.2"));
+}
+
+#[test]
+fn test_panics_do_not_unwind_into_the_caller() {
+    // the schedule asserts that it ends after it starts
+    assert!(ql_schedule_new(TODAY, TODAY - 1, 6, 2, 0, 1, 1, 1, false).is_none());
+    assert!(last_error().is_some());
+}
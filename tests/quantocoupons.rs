@@ -0,0 +1,183 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{
+    BlackIborCouponPricer, CmsCouponPricer, CmsFixing, CmsLeg, Coupon, IborCoupon,
+    IborCouponPricer, IborFixing, IborLeg, InArrearsMethod, QuantoCmsCouponPricer,
+    QuantoIborCouponPricer,
+};
+use quantlib::indexes::{IborIndex, SwapIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, DateGenerator, DayCounter, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Index = IborIndex<WeekendsOnly, Curve, Actual360>;
+type Vol = BlackConstantVol;
+
+fn index() -> Index {
+    IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::new(flat_curve(0.03))),
+    )
+}
+
+fn schedule(months: i64) -> Schedule {
+    let start = reference_date().add_months(12);
+    Schedule::new(
+        start,
+        start.add_months(48),
+        Period::new(months, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+fn vol(sigma: f64) -> Rc<Vol> {
+    Rc::new(BlackConstantVol::new(SimpleQuote::new(sigma)))
+}
+
+fn quanto_ibor(correlation: f64, method: InArrearsMethod) -> QuantoIborCouponPricer<Vol, Vol> {
+    let caplets = BlackIborCouponPricer::new(vol(0.2), reference_date()).with_method(method);
+    QuantoIborCouponPricer::new(caplets, vol(0.1), 1.1, SimpleQuote::new(correlation))
+}
+
+fn ibor_leg(
+    pricer: Rc<dyn IborCouponPricer>,
+    in_arrears: bool,
+) -> Vec<IborCoupon<Index, Actual360>> {
+    IborLeg::new(schedule(6), Rc::new(index()), Actual360)
+        .with_notional(100.0)
+        .with_in_arrears(in_arrears)
+        .with_pricer(pricer)
+        .build()
+}
+
+fn time(coupon: &IborCoupon<Index, Actual360>) -> f64 {
+    Actual365Fixed.year_fraction(reference_date(), coupon.fixing_date, None, None)
+}
+
+#[test]
+fn test_quanto_ibor_adjustment() {
+    let leg = ibor_leg(Rc::new(quanto_ibor(0.4, InArrearsMethod::Black)), false);
+    for coupon in &leg {
+        let forward = coupon.index_fixing();
+        let expected = forward * (-0.4 * 0.2 * 0.1 * time(coupon)).exp();
+        assert!((coupon.rate() - expected).abs() < 1.0e-15);
+        assert!(coupon.rate() < forward);
+    }
+    // without correlation the currencies do not matter
+    for coupon in &ibor_leg(Rc::new(quanto_ibor(0.0, InArrearsMethod::Black)), false) {
+        assert_eq!(coupon.rate(), coupon.index_fixing());
+    }
+    // negative correlation raises the rate paid
+    for coupon in &ibor_leg(Rc::new(quanto_ibor(-0.4, InArrearsMethod::Black)), false) {
+        assert!(coupon.rate() > coupon.index_fixing());
+    }
+}
+
+#[test]
+fn test_quanto_in_arrears_adjusts_the_quanto_forward() {
+    let quanto = quanto_ibor(0.3, InArrearsMethod::Black);
+    let in_arrears = BlackIborCouponPricer::new(vol(0.2), reference_date());
+    let fixing = IborFixing {
+        fixing_date: reference_date().add_months(36),
+        forward: 0.03,
+        index_accrual: 0.5,
+        in_arrears: true,
+    };
+    let t = Actual365Fixed.year_fraction(reference_date(), fixing.fixing_date, None, None);
+    let quanto_forward = 0.03 * (-0.3 * 0.2 * 0.1 * t).exp();
+    let expected = in_arrears.adjusted_fixing(&IborFixing {
+        forward: quanto_forward,
+        ..fixing
+    });
+    assert!((quanto.adjusted_fixing(&fixing) - expected).abs() < 1.0e-15);
+    assert!(expected > quanto_forward);
+    // the same through a leg built in arrears
+    for coupon in &ibor_leg(Rc::new(quanto), true) {
+        assert!(coupon.in_arrears);
+        assert!(coupon.rate() > coupon.index_fixing() * (-0.006 * time(coupon)).exp());
+    }
+}
+
+#[test]
+fn test_quanto_cms_leg() {
+    let curve = Rc::new(flat_curve(0.03));
+    let swap_index: SwapIndex<WeekendsOnly, Curve, Actual365Fixed, Actual360> = SwapIndex::new(
+        "EuriborSwapIsdaFixA",
+        Period::new(10, TimeUnit::Years),
+        2,
+        Calendar::new(WeekendsOnly),
+        Period::new(1, TimeUnit::Years),
+        BusinessDayConvention::ModifiedFollowing,
+        Actual365Fixed {},
+        Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(6, TimeUnit::Months),
+            2,
+            Calendar::new(WeekendsOnly),
+            BusinessDayConvention::ModifiedFollowing,
+            true,
+            Actual360,
+            Some(curve),
+        )),
+    );
+    let pricer = QuantoCmsCouponPricer::new(
+        vol(0.25),
+        vol(0.12),
+        1.1,
+        SimpleQuote::new(0.5),
+        reference_date(),
+    );
+    let leg = CmsLeg::new(schedule(12), Rc::new(swap_index), Actual360)
+        .with_gearing(2.0)
+        .with_spread(0.001)
+        .with_pricer(Rc::new(pricer))
+        .build();
+    assert_eq!(leg.len(), 4);
+    for coupon in &leg {
+        let t = Actual365Fixed.year_fraction(reference_date(), coupon.fixing_date, None, None);
+        let adjusted = coupon.index_fixing() * (-0.5 * 0.25 * 0.12 * t).exp();
+        assert!((coupon.adjusted_fixing() - adjusted).abs() < 1.0e-15);
+        assert!((coupon.rate() - (2.0 * adjusted + 0.001)).abs() < 1.0e-15);
+    }
+}
+
+#[test]
+fn test_fixings_at_or_before_today_are_not_adjusted() {
+    let cms = QuantoCmsCouponPricer::new(
+        vol(0.25),
+        vol(0.12),
+        1.1,
+        SimpleQuote::new(0.5),
+        reference_date(),
+    );
+    let fixing = CmsFixing {
+        fixing_date: reference_date(),
+        forward: 0.02,
+    };
+    assert_eq!(cms.adjusted_fixing(&fixing), 0.02);
+    let ibor = quanto_ibor(0.5, InArrearsMethod::Replication { intervals: 100 });
+    let fixing = IborFixing {
+        fixing_date: reference_date().add_months(-1),
+        forward: 0.02,
+        index_accrual: 0.5,
+        in_arrears: true,
+    };
+    assert_eq!(ibor.adjusted_fixing(&fixing), 0.02);
+}
@@ -0,0 +1,65 @@
+extern crate quantlib;
+
+use quantlib::patterns::ChangeFlag;
+use quantlib::quotes::{CompositeQuote, DerivedQuote, Quote, SimpleQuote};
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{Compounding, FlatForward};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual365Fixed, Calendar, Frequency, WeekendsOnly};
+
+#[test]
+fn test_derived_quote_inverts_fx() {
+    let flag = ChangeFlag::new();
+    let usd_eur = DerivedQuote::new(SimpleQuote::new(1.25), |x| 1.0 / x);
+    usd_eur
+        .observable()
+        .unwrap()
+        .register_observer(flag.clone());
+    assert_eq!(usd_eur.value(), 0.8);
+    usd_eur.underlying.set_value(1.6);
+    assert!(flag.take());
+    assert_eq!(usd_eur.value(), 0.625);
+    usd_eur.underlying.reset();
+    assert!(!usd_eur.is_valid());
+}
+
+#[test]
+fn test_curve_on_a_spread_over_a_benchmark() {
+    let rate = CompositeQuote::new(SimpleQuote::new(0.02), SimpleQuote::new(0.005), |r, s| {
+        r + s
+    });
+    let curve = FlatForward::new(
+        reference_date(),
+        rate,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        Calendar::new(WeekendsOnly),
+    );
+    let flag = ChangeFlag::new();
+    curve.observable().unwrap().register_observer(flag.clone());
+    assert!((curve.discount_with_time(2.0, false) - (-0.05f64).exp()).abs() < 1.0e-15);
+    // either leg of the composite ticks through to the curve
+    curve.forward.second.set_value(0.01);
+    assert!(flag.take());
+    curve.forward.first.set_value(0.03);
+    assert!(flag.take());
+    assert!((curve.discount_with_time(2.0, false) - (-0.08f64).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_quotes_nest() {
+    let flag = ChangeFlag::new();
+    let cross = CompositeQuote::new(
+        DerivedQuote::new(SimpleQuote::new(110.0), |x| 1.0 / x),
+        SimpleQuote::new(150.0),
+        |usd_jpy, eur_jpy| eur_jpy * usd_jpy,
+    );
+    cross.observable().unwrap().register_observer(flag.clone());
+    assert!((cross.value() - 150.0 / 110.0).abs() < 1.0e-15);
+    cross.first.underlying.set_value(120.0);
+    assert!(flag.take());
+    assert!((cross.value() - 1.25).abs() < 1.0e-15);
+    cross.second.reset();
+    assert!(!cross.is_valid());
+}
@@ -0,0 +1,145 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateLeg, SimpleCashFlow};
+use quantlib::quotes::SimpleQuote;
+use quantlib::reports::{calibration_report, cashflow_report, risk_ladder, Cell, Table};
+use quantlib::risk::CurveDelta;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{DepositRateHelper, RateHelper};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, DateGenerator, Period, Schedule,
+    TimeUnit, WeekendsOnly,
+};
+
+fn delta(curve: &str, pillar: f64, delta: f64) -> CurveDelta {
+    CurveDelta {
+        curve: curve.to_string(),
+        pillar,
+        delta,
+    }
+}
+
+fn ladder() -> Table {
+    risk_ladder(&[
+        delta("EUR-ESTR", 1.0, -120.5),
+        delta("EUR-ESTR", 5.0, -2300.25),
+        delta("EUR-6M", 1.0, 40.0),
+        delta("EUR-6M", 5.0, 1950.0),
+    ])
+}
+
+#[test]
+fn test_risk_ladder_text() {
+    let text = ladder().to_text();
+    let expected = "\
+Risk ladder
+curve           pillar     delta
+--------------  ------  --------
+EUR-ESTR          1.00   -120.50
+EUR-ESTR          5.00  -2300.25
+EUR-ESTR total          -2420.75
+EUR-6M            1.00     40.00
+EUR-6M            5.00   1950.00
+EUR-6M total             1990.00
+";
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn test_risk_ladder_csv_and_json() {
+    let table = ladder();
+    let csv = table.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "curve,pillar,delta");
+    assert_eq!(lines[1], "EUR-ESTR,1,-120.5");
+    assert_eq!(lines[3], "EUR-ESTR total,,-2420.75");
+    assert_eq!(lines.len(), 7);
+    let json = table.to_json();
+    assert!(json.starts_with(
+        "{\"title\":\"Risk ladder\",\"columns\":[\"curve\",\"pillar\",\"delta\"],\"rows\":["
+    ));
+    assert!(json.contains("{\"curve\":\"EUR-6M\",\"pillar\":5,\"delta\":1950}"));
+    assert!(json.contains("{\"curve\":\"EUR-6M total\",\"pillar\":null,\"delta\":1990}"));
+    // text cells are escaped in both formats
+    let mut quoted = Table::new("q", &["name", "value"]);
+    quoted.push_row(vec![
+        Cell::Text("a, \"b\"".to_string()),
+        Cell::Number(f64::NAN),
+    ]);
+    assert_eq!(quoted.to_csv(), "name,value\n\"a, \"\"b\"\"\",NaN\n");
+    assert!(quoted
+        .to_json()
+        .contains("{\"name\":\"a, \\\"b\\\"\",\"value\":null}"));
+}
+
+#[test]
+fn test_cashflow_report() {
+    let schedule = Schedule::new(
+        reference_date(),
+        reference_date().add_months(24),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    );
+    let leg = FixedRateLeg::new(schedule, Actual365Fixed)
+        .with_notional(1.0e6)
+        .with_rate(0.03)
+        .build();
+    let curve = flat_curve(0.02);
+    let table = cashflow_report(&leg, &curve, reference_date());
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.columns[0], "payment_date");
+    let row = &table.rows[1];
+    assert_eq!(row[0], Cell::Date(reference_date().add_months(24)));
+    assert_eq!(row[3], Cell::Number(1.0e6));
+    assert_eq!(row[4], Cell::Number(0.03));
+    if let (Cell::Number(amount), Cell::Number(df), Cell::Number(pv)) = (&row[5], &row[6], &row[7])
+    {
+        assert!((amount * df - pv).abs() < 1.0e-9);
+    } else {
+        panic!("amounts are not numbers");
+    }
+    let csv = table.to_csv();
+    assert!(csv
+        .lines()
+        .nth(2)
+        .unwrap()
+        .starts_with("2022-01-15,2021-01-15,2022-01-15,1000000,0.03,"));
+    // other flows have no coupon terms
+    let redemption = vec![SimpleCashFlow::new(1.0e6, reference_date().add_months(24))];
+    let table = cashflow_report(&redemption, &curve, reference_date());
+    assert_eq!(table.rows[0][1..5].to_vec(), vec![Cell::Empty; 4]);
+    assert!(table
+        .to_csv()
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("2022-01-15,,,,,1000000,"));
+}
+
+#[test]
+fn test_calibration_report() {
+    let curve = flat_curve(0.02);
+    let helper = DepositRateHelper::new(
+        SimpleQuote::new(0.0205),
+        reference_date(),
+        reference_date().add_months(6),
+        Actual360,
+    );
+    let implied = helper.implied_quote_on(&|d| curve.discount(d, true));
+    let table = calibration_report(&curve, &[&helper]);
+    assert_eq!(
+        table.columns,
+        vec!["pillar_date", "market_quote", "implied_quote", "error"]
+    );
+    assert_eq!(table.rows[0][0], Cell::Date(reference_date().add_months(6)));
+    assert_eq!(table.rows[0][2], Cell::Number(implied));
+    assert_eq!(table.rows[0][3], Cell::Number(0.0205 - implied));
+    let text = table.to_text();
+    assert!(text.lines().nth(3).unwrap().starts_with("2020-07-15"));
+    assert!(text.contains(&format!("{:.8}", implied)));
+}
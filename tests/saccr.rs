@@ -0,0 +1,140 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateLeg, IborLeg};
+use quantlib::indexes::IborIndex;
+use quantlib::instruments::{SwapType, VanillaSwap};
+use quantlib::risk::{
+    supervisory_option_delta, AssetClass, CreditRating, NettingSet, Trade, Underlying,
+};
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+fn duration(start: f64, end: f64) -> f64 {
+    ((-0.05 * start).exp() - (-0.05 * end).exp()) / 0.05
+}
+
+#[test]
+fn test_single_swap_exposure() {
+    let curve = Rc::new(flat_curve(0.02));
+    let schedule = |months| {
+        Schedule::new(
+            reference_date(),
+            Date::new(15, Month::January, 2025),
+            Period::new(months, TimeUnit::Months),
+            Calendar::new(WeekendsOnly),
+            BusinessDayConvention::ModifiedFollowing,
+            BusinessDayConvention::ModifiedFollowing,
+            DateGenerator::Backward,
+            false,
+        )
+    };
+    let index = Rc::new(IborIndex::euribor(
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Some(Rc::clone(&curve)),
+    ));
+    let swap = VanillaSwap::new(
+        SwapType::Receiver,
+        FixedRateLeg::new(schedule(12), Actual365Fixed)
+            .with_notional(1.0e7)
+            .with_rate(0.03)
+            .build(),
+        0.03,
+        IborLeg::new(schedule(6), index, Actual360)
+            .with_notional(1.0e7)
+            .build(),
+        0.0,
+    );
+    let trade = Trade::vanilla_swap(&swap, "EUR", curve.as_ref(), reference_date());
+    assert_eq!(trade.delta, -1.0);
+    assert!(trade.mtm > 0.0);
+    let set = NettingSet::new(vec![trade.clone()]);
+    let result = set.exposure_at_default();
+    let add_on = 0.005 * 1.0e7 * duration(0.0, trade.end);
+    assert!((result.add_ons[&AssetClass::InterestRate] - add_on).abs() < 1.0e-6);
+    // an in the money trade gets no PFE discount
+    assert_eq!(result.multiplier, 1.0);
+    assert!((result.ead - 1.4 * (trade.mtm + add_on)).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_interest_rate_maturity_buckets_offset_partially() {
+    let long = Trade::new(Underlying::InterestRate, "USD", "USD", 100.0, 3.0, 0.0);
+    let short =
+        Trade::new(Underlying::InterestRate, "USD", "USD", 100.0, 7.0, 0.0).with_delta(-1.0);
+    let (d2, d3) = (100.0 * duration(0.0, 3.0), -100.0 * duration(0.0, 7.0));
+    let result = NettingSet::new(vec![long.clone(), short]).exposure_at_default();
+    let notional = (d2 * d2 + d3 * d3 + 1.4 * d2 * d3).sqrt();
+    assert!((result.add_on - 0.005 * notional).abs() < 1.0e-12);
+    // other currencies are separate hedging sets
+    let euro = Trade::new(Underlying::InterestRate, "EUR", "EUR", 100.0, 3.0, 0.0).with_delta(-1.0);
+    let result = NettingSet::new(vec![long, euro]).exposure_at_default();
+    assert!((result.add_on - 2.0 * 0.005 * d2).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_entities_aggregate_through_a_single_factor() {
+    let option_delta = supervisory_option_delta(true, true, 100.0, 100.0, 0.2, 0.5);
+    assert!(option_delta > 0.5 && option_delta < 0.6);
+    assert!(
+        (supervisory_option_delta(false, false, 100.0, 100.0, 0.2, 0.5) - (1.0 - option_delta))
+            .abs()
+            < 1.0e-12
+    );
+    let trades = vec![
+        Trade::new(Underlying::Equity, "", "ACME", 1000.0, 0.5, 0.0).with_delta(option_delta),
+        Trade::new(Underlying::EquityIndex, "", "SX5E", 2000.0, 2.0, 0.0).with_delta(-1.0),
+        Trade::new(
+            Underlying::Credit(CreditRating::Bbb),
+            "",
+            "ACME",
+            500.0,
+            5.0,
+            0.0,
+        ),
+    ];
+    let result = NettingSet::new(trades).exposure_at_default();
+    let single = 0.32 * 1000.0 * option_delta * 0.5f64.sqrt();
+    let index = -0.20 * 2000.0;
+    let systematic = 0.5 * single + 0.8 * index;
+    let expected =
+        (systematic * systematic + 0.75 * single * single + (1.0 - 0.64) * index * index).sqrt();
+    assert!((result.add_ons[&AssetClass::Equity] - expected).abs() < 1.0e-9);
+    let credit = 0.0054 * 500.0 * duration(0.0, 5.0);
+    assert!((result.add_ons[&AssetClass::Credit] - credit).abs() < 1.0e-9);
+}
+
+#[test]
+fn test_margin_and_collateral() {
+    let trades = vec![
+        Trade::new(Underlying::Fx, "EURUSD", "EURUSD", 1.0e6, 2.0, -20_000.0),
+        Trade::new(
+            Underlying::Commodity,
+            "Energy",
+            "Brent",
+            1.0e6,
+            0.5,
+            10_000.0,
+        ),
+    ];
+    let unmargined = NettingSet::new(trades.clone()).exposure_at_default();
+    assert_eq!(unmargined.replacement_cost, 0.0);
+    // out of the money sets have a PFE multiplier below one
+    assert!(unmargined.multiplier < 1.0);
+
+    let margined = NettingSet::new(trades)
+        .with_margin(50_000.0, 10_000.0, 5_000.0, 10)
+        .with_collateral(-15_000.0);
+    let result = margined.exposure_at_default();
+    assert_eq!(result.replacement_cost, 55_000.0);
+    let mf = 1.5 * (10.0f64 / 250.0).sqrt();
+    let fx = 0.04 * 1.0e6 * mf;
+    assert!((result.add_ons[&AssetClass::Fx] - fx).abs() < 1.0e-6);
+    assert!((result.add_ons[&AssetClass::Commodity] - 0.18 * 1.0e6 * mf).abs() < 1.0e-6);
+    assert!(result.ead <= unmargined.ead);
+    assert!((result.ead - 1.4 * (55_000.0 + result.potential_future_exposure)).abs() < 1.0e-6);
+}
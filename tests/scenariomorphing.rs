@@ -0,0 +1,92 @@
+extern crate quantlib;
+
+use quantlib::persistence::{CurveSnapshot, MarketState, ModelSnapshot};
+use quantlib::risk::{worst_point, ScenarioMorph};
+use quantlib::testutils::market::{flat_curve, reference_date};
+
+fn market(rate: f64, times: Vec<f64>, vol: f64, days: i64) -> MarketState {
+    let mut curve = flat_curve(rate);
+    MarketState::new(reference_date().add_days(days), 7)
+        .with_curve(CurveSnapshot::from_curve("EUR-ESTR", &mut curve, times))
+        .with_model(ModelSnapshot::new(
+            "SX5E-Black",
+            vec![("vol".to_string(), vol), ("kappa".to_string(), 1.0)],
+        ))
+}
+
+/// A stress onset over ten days: rates up 200bp, volatility doubled.
+fn morph() -> ScenarioMorph {
+    ScenarioMorph::new(
+        market(0.02, vec![1.0, 2.0, 5.0, 10.0], 0.2, 0),
+        market(0.04, vec![0.5, 2.0, 7.0, 10.0], 0.4, 10),
+    )
+}
+
+fn zero_rate(curve: &CurveSnapshot, t: f64) -> f64 {
+    -curve.discount(t).ln() / t
+}
+
+#[test]
+fn test_path_runs_from_start_to_end() {
+    let morph = morph();
+    let path = morph.path(10);
+    assert_eq!(path.len(), 11);
+    for (day, market) in path.iter().enumerate() {
+        assert_eq!(market.as_of, reference_date().add_days(day as i64));
+        assert_eq!(market.market_version, 7);
+    }
+    let (first, last) = (
+        path[0].curve("EUR-ESTR").unwrap(),
+        path[10].curve("EUR-ESTR").unwrap(),
+    );
+    assert_eq!(first.times, vec![0.5, 1.0, 2.0, 5.0, 7.0, 10.0]);
+    for t in &[0.5, 1.0, 3.0, 5.0, 10.0] {
+        let start = morph.start.curve("EUR-ESTR").unwrap();
+        let end = morph.end.curve("EUR-ESTR").unwrap();
+        assert!((first.discount(*t) - start.discount(*t)).abs() < 1.0e-15);
+        assert!((last.discount(*t) - end.discount(*t)).abs() < 1.0e-15);
+    }
+    assert_eq!(
+        path[10].model("SX5E-Black").unwrap().parameter("vol"),
+        Some(0.4)
+    );
+}
+
+#[test]
+fn test_intermediate_markets_interpolate_linearly() {
+    let quarter = morph().market(0.25);
+    let curve = quarter.curve("EUR-ESTR").unwrap();
+    for t in &curve.times {
+        assert!((zero_rate(curve, *t) - 0.025).abs() < 1.0e-12);
+    }
+    let model = quarter.model("SX5E-Black").unwrap();
+    assert!((model.parameter("vol").unwrap() - 0.25).abs() < 1.0e-15);
+    assert_eq!(model.parameter("kappa"), Some(1.0));
+    // 2.5 days round to the nearest day
+    assert_eq!(quarter.as_of, reference_date().add_days(3));
+}
+
+#[test]
+fn test_revaluation_along_the_path() {
+    // a zero coupon bond paying 100 in five years loses as rates rise
+    let points = morph().revalue(10, |m| 100.0 * m.curve("EUR-ESTR").unwrap().discount(5.0));
+    assert_eq!(points.len(), 11);
+    assert_eq!(points[0].pnl, 0.0);
+    assert!((points[0].value - 100.0 * (-0.02f64 * 5.0).exp()).abs() < 1.0e-12);
+    assert!(points.windows(2).all(|p| p[1].pnl < p[0].pnl));
+    let worst = worst_point(&points);
+    assert_eq!(worst, points[10]);
+    assert_eq!(worst.weight, 1.0);
+    assert!((worst.value - 100.0 * (-0.04f64 * 5.0).exp()).abs() < 1.0e-12);
+    // a position gaining throughout never loses more than at the start
+    let short = morph().revalue(10, |m| -100.0 * m.curve("EUR-ESTR").unwrap().discount(5.0));
+    assert_eq!(worst_point(&short).weight, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "models of the two markets mismatch")]
+fn test_markets_must_match() {
+    let mut end = market(0.04, vec![1.0, 5.0], 0.4, 10);
+    end.models[0].parameters.pop();
+    ScenarioMorph::new(market(0.02, vec![1.0, 5.0], 0.2, 0), end);
+}
@@ -0,0 +1,328 @@
+extern crate quantlib;
+
+use quantlib::time::{
+    BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule, Sweden,
+    TimeUnit, WeekendsOnly,
+};
+
+use BusinessDayConvention::*;
+use Month::*;
+
+fn check(s: &Schedule, expected: &[(u32, Month, i32)]) {
+    let expected: Vec<Date> = expected
+        .iter()
+        .map(|(d, m, y)| Date::new(*d, *m, *y))
+        .collect();
+    assert_eq!(s.dates, expected);
+    assert_eq!(s.is_regular.len(), s.dates.len() - 1);
+}
+
+// The following cases are from the QuantLib schedule test suite, with
+// weekend-only calendars where no holiday affects the result.
+
+#[test]
+fn test_end_date_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(30, September, 2009),
+        Date::new(15, June, 2012),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::Forward,
+        true,
+    );
+    check(
+        &s,
+        &[
+            (30, September, 2009),
+            (31, March, 2010),
+            (30, September, 2010),
+            (31, March, 2011),
+            (30, September, 2011),
+            (30, March, 2012),
+            (29, June, 2012),
+        ],
+    );
+}
+
+#[test]
+fn test_dates_past_end_date_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(28, March, 2013),
+        Date::new(30, March, 2015),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(Sweden),
+        Unadjusted,
+        Unadjusted,
+        DateGenerator::Forward,
+        true,
+    );
+    check(
+        &s,
+        &[(31, March, 2013), (31, March, 2014), (30, March, 2015)],
+    );
+    assert!(!s.is_regular[1]);
+}
+
+#[test]
+fn test_dates_same_as_end_date_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(28, March, 2013),
+        Date::new(31, March, 2015),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(Sweden),
+        Unadjusted,
+        Unadjusted,
+        DateGenerator::Forward,
+        true,
+    );
+    check(
+        &s,
+        &[(31, March, 2013), (31, March, 2014), (31, March, 2015)],
+    );
+    assert!(s.is_regular[1]);
+}
+
+#[test]
+fn test_forward_dates_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(31, August, 1996),
+        Date::new(15, September, 1997),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Unadjusted,
+        Unadjusted,
+        DateGenerator::Forward,
+        true,
+    );
+    check(
+        &s,
+        &[
+            (31, August, 1996),
+            (28, February, 1997),
+            (31, August, 1997),
+            (15, September, 1997),
+        ],
+    );
+    assert_eq!(s.is_regular, vec![true, true, false]);
+}
+
+#[test]
+fn test_backward_dates_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(31, August, 1996),
+        Date::new(15, September, 1997),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Unadjusted,
+        Unadjusted,
+        DateGenerator::Backward,
+        true,
+    );
+    check(
+        &s,
+        &[
+            (31, August, 1996),
+            (15, September, 1996),
+            (15, March, 1997),
+            (15, September, 1997),
+        ],
+    );
+    assert_eq!(s.is_regular, vec![false, true, true]);
+}
+
+#[test]
+fn test_double_first_date_with_eom_adjustment() {
+    let s = Schedule::new(
+        Date::new(22, August, 1996),
+        Date::new(31, August, 1997),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::Backward,
+        true,
+    );
+    check(
+        &s,
+        &[(30, August, 1996), (28, February, 1997), (29, August, 1997)],
+    );
+}
+
+#[test]
+fn test_month_end_swap_with_modified_following() {
+    // a 2Y quarterly swap starting on the last business day of May; the
+    // 29th February 2020 and 28th February 2021 fall on weekends.
+    let s = Schedule::new(
+        Date::new(31, May, 2019),
+        Date::new(31, May, 2021),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        ModifiedFollowing,
+        ModifiedFollowing,
+        DateGenerator::Backward,
+        true,
+    );
+    check(
+        &s,
+        &[
+            (31, May, 2019),
+            (30, August, 2019),
+            (29, November, 2019),
+            (28, February, 2020),
+            (29, May, 2020),
+            (31, August, 2020),
+            (30, November, 2020),
+            (26, February, 2021),
+            (31, May, 2021),
+        ],
+    );
+    assert!(s.is_regular.iter().all(|r| *r));
+}
+
+#[test]
+fn test_eom_rule_from_short_month() {
+    let generate = |end_of_month| {
+        Schedule::new(
+            Date::new(30, September, 2020),
+            Date::new(30, June, 2021),
+            Period::new(3, TimeUnit::Months),
+            Calendar::new(WeekendsOnly),
+            ModifiedFollowing,
+            ModifiedFollowing,
+            DateGenerator::Backward,
+            end_of_month,
+        )
+    };
+    check(
+        &generate(true),
+        &[
+            (30, September, 2020),
+            (31, December, 2020),
+            (31, March, 2021),
+            (30, June, 2021),
+        ],
+    );
+    check(
+        &generate(false),
+        &[
+            (30, September, 2020),
+            (30, December, 2020),
+            (30, March, 2021),
+            (30, June, 2021),
+        ],
+    );
+}
+
+#[test]
+fn test_zero_rule() {
+    let s = Schedule::new(
+        Date::new(15, January, 2020),
+        Date::new(18, January, 2021),
+        Period::new(0, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::Zero,
+        true,
+    );
+    check(&s, &[(15, January, 2020), (18, January, 2021)]);
+}
+
+#[test]
+fn test_third_wednesday_rule() {
+    let s = Schedule::new(
+        Date::new(18, March, 2020),
+        Date::new(17, March, 2021),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::ThirdWednesday,
+        false,
+    );
+    check(
+        &s,
+        &[
+            (18, March, 2020),
+            (17, June, 2020),
+            (16, September, 2020),
+            (16, December, 2020),
+            (17, March, 2021),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "end of month is incompatible with the third Wednesday rule")]
+fn test_third_wednesday_rule_rejects_end_of_month() {
+    Schedule::new(
+        Date::new(18, March, 2020),
+        Date::new(17, March, 2021),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::ThirdWednesday,
+        true,
+    );
+}
+
+#[test]
+fn test_twentieth_rules() {
+    let schedule = |rule| {
+        Schedule::new(
+            Date::new(10, February, 2020),
+            Date::new(10, February, 2021),
+            Period::new(3, TimeUnit::Months),
+            Calendar::new(WeekendsOnly),
+            Following,
+            Following,
+            rule,
+            false,
+        )
+    };
+    // a short stub to the next twentieth, and a termination date moved
+    // to the twentieth after it
+    let s = schedule(DateGenerator::Twentieth);
+    check(
+        &s,
+        &[
+            (10, February, 2020),
+            (20, February, 2020),
+            (20, May, 2020),
+            (20, August, 2020),
+            (20, November, 2020),
+            (22, February, 2021),
+        ],
+    );
+    assert_eq!(s.is_regular, vec![false, true, true, true, true]);
+    // on the IMM months only
+    check(
+        &schedule(DateGenerator::TwentiethIMM),
+        &[
+            (10, February, 2020),
+            (20, March, 2020),
+            (22, June, 2020),
+            (21, September, 2020),
+            (21, December, 2020),
+            (22, March, 2021),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "end of month is incompatible with the twentieth IMM rule")]
+fn test_twentieth_imm_rule_rejects_end_of_month() {
+    Schedule::new(
+        Date::new(10, February, 2020),
+        Date::new(10, February, 2021),
+        Period::new(3, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        Following,
+        Following,
+        DateGenerator::TwentiethIMM,
+        true,
+    );
+}
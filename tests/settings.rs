@@ -0,0 +1,90 @@
+extern crate quantlib;
+
+use quantlib::settings::PricingContext;
+use quantlib::termstructures::traits::TermStructure;
+use quantlib::termstructures::Base;
+use quantlib::time::calendars::WeekendsOnly;
+use quantlib::time::{Calendar, Date, Month};
+use quantlib::utils::Parallelism;
+use std::panic;
+use std::thread;
+
+fn context(day: u32) -> PricingContext {
+    PricingContext::new(Date::new(day, Month::October, 2026))
+}
+
+#[test]
+fn test_nested_scopes() {
+    let outer = context(14);
+    let inner = context(15).with_todays_cashflows(true);
+    outer.scope(|| {
+        assert_eq!(PricingContext::current(), outer);
+        inner.scope(|| {
+            assert_eq!(PricingContext::current(), inner);
+            assert!(PricingContext::current().include_todays_cashflows);
+        });
+        assert_eq!(PricingContext::current(), outer);
+
+        // the override is also restored when unwinding.
+        let result = panic::catch_unwind(|| inner.scope(|| panic!("what-if failed")));
+        assert!(result.is_err());
+        assert_eq!(PricingContext::current(), outer);
+    });
+}
+
+#[test]
+fn test_threads_are_independent() {
+    let handles: Vec<_> = (12..17)
+        .map(|day| {
+            thread::spawn(move || {
+                context(day).scope(|| {
+                    thread::yield_now();
+                    PricingContext::current().evaluation_date
+                })
+            })
+        })
+        .collect();
+    for (day, h) in (12..17).zip(handles) {
+        assert_eq!(h.join().unwrap(), context(day).evaluation_date);
+    }
+}
+
+#[test]
+fn test_parallel_workers_inherit_context() {
+    let items: Vec<usize> = (0..64).collect();
+    let dates = context(16).scope(|| {
+        Parallelism::Threads(4).map(&items, |_| PricingContext::current().evaluation_date)
+    });
+    assert!(dates.iter().all(|d| *d == context(16).evaluation_date));
+}
+
+#[test]
+fn test_moving_reference_date() {
+    let mut base = Base::new(quantlib::time::Actual365Fixed {});
+    base.calendar = Some(Calendar::new(WeekendsOnly));
+    base.settlement_days = 2;
+    base.moving = true;
+    // Friday plus two business days, then Monday plus two.
+    let friday = context(16).scope(|| base.reference_date());
+    assert_eq!(friday, Date::new(20, Month::October, 2026));
+    let monday = context(19).scope(|| base.reference_date());
+    assert_eq!(monday, Date::new(21, Month::October, 2026));
+}
+
+// The shared context is process-wide; only this test touches it.
+#[test]
+fn test_shared_context() {
+    PricingContext::set_shared(context(1));
+    let shared = thread::spawn(PricingContext::current).join().unwrap();
+    assert_eq!(shared, context(1));
+    assert_eq!(context(2).scope(PricingContext::current), context(2));
+    PricingContext::clear_shared();
+    let fallback = thread::spawn(PricingContext::checked_current)
+        .join()
+        .unwrap();
+    // today with the clock, otherwise no evaluation date at all
+    #[cfg(feature = "clock")]
+    assert_eq!(fallback.ok(), Some(PricingContext::today()));
+    #[cfg(not(feature = "clock"))]
+    assert_eq!(fallback.unwrap_err().to_string(), "evaluation date not set");
+}
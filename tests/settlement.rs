@@ -0,0 +1,136 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{FixedRateLeg, PayReceive, SettlementCalculator};
+use quantlib::currencies::Currency;
+use quantlib::math::{Rounding, RoundingMode};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{
+    Actual360, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period, Schedule,
+    TimeUnit, WeekendsOnly,
+};
+
+fn schedule() -> Schedule {
+    Schedule::new(
+        reference_date(),
+        Date::new(15, Month::January, 2022),
+        Period::new(6, TimeUnit::Months),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::Unadjusted,
+        BusinessDayConvention::Unadjusted,
+        DateGenerator::Backward,
+        false,
+    )
+}
+
+#[test]
+fn test_rounding_modes() {
+    let closest = Rounding::closest(2);
+    // 2.675 is stored as 2.67499999...
+    assert_eq!(closest.units(2.675), 268);
+    assert_eq!(closest.round(-1.005), -1.01);
+    let even = Rounding::new(2, RoundingMode::HalfEven);
+    assert_eq!(even.units(2.625), 262);
+    assert_eq!(even.units(2.635), 264);
+    assert_eq!(Rounding::new(2, RoundingMode::Up).units(-0.001), -1);
+    assert_eq!(Rounding::new(0, RoundingMode::Down).units(99.999), 99);
+    assert_eq!(closest.units(0.1 + 0.2), 30);
+}
+
+#[test]
+fn test_value_dates_and_cut_offs() {
+    let leg = FixedRateLeg::new(schedule(), Actual360)
+        .with_notional(1_000_000.0)
+        .with_rate(0.0123)
+        .build();
+    let calculator =
+        SettlementCalculator::new(Calendar::new(WeekendsOnly), Currency::EUR).with_cut_off_days(2);
+    let amounts = calculator.amounts(
+        &leg,
+        PayReceive::Receive,
+        reference_date(),
+        Date::new(31, Month::December, 2021),
+    );
+    assert_eq!(amounts.len(), 3);
+    assert_eq!(amounts[0].payment_date, Date::new(15, Month::July, 2020));
+    // Thursday 15th July 2021, with the instructions due on Tuesday
+    let a = &amounts[2];
+    assert_eq!(a.value_date, Date::new(15, Month::July, 2021));
+    assert_eq!(a.cut_off_date, Date::new(13, Month::July, 2021));
+    // 182 days of 1.23% on a million is 6218.333...
+    assert_eq!(amounts[0].minor_units, 621_833);
+    assert_eq!(amounts[0].amount(), 6218.33);
+    assert_eq!(amounts[0].pay_receive(), PayReceive::Receive);
+}
+
+#[test]
+fn test_weekend_payments_roll() {
+    let leg = FixedRateLeg::new(
+        Schedule::new(
+            Date::new(15, Month::February, 2020),
+            Date::new(15, Month::August, 2020),
+            Period::new(6, TimeUnit::Months),
+            Calendar::new(WeekendsOnly),
+            BusinessDayConvention::Unadjusted,
+            BusinessDayConvention::Unadjusted,
+            DateGenerator::Backward,
+            false,
+        ),
+        Actual360,
+    )
+    .with_notional(1.0e8)
+    .with_rate(0.01)
+    .build();
+    let calculator = SettlementCalculator::new(Calendar::new(WeekendsOnly), Currency::JPY);
+    let amounts = calculator.amounts(
+        &leg,
+        PayReceive::Pay,
+        reference_date(),
+        Date::new(17, Month::August, 2020),
+    );
+    assert_eq!(amounts.len(), 1);
+    // Saturday 15th August 2020 settles on the Monday
+    assert_eq!(amounts[0].payment_date, Date::new(15, Month::August, 2020));
+    assert_eq!(amounts[0].value_date, Date::new(17, Month::August, 2020));
+    assert_eq!(amounts[0].minor_units, -505_556);
+    assert_eq!(amounts[0].pay_receive(), PayReceive::Pay);
+    // preceding moves it back to the Friday
+    let preceding = calculator.with_convention(BusinessDayConvention::Preceding);
+    let amounts = preceding.amounts(
+        &leg,
+        PayReceive::Pay,
+        reference_date(),
+        Date::new(14, Month::August, 2020),
+    );
+    assert_eq!(amounts[0].value_date, Date::new(14, Month::August, 2020));
+}
+
+#[test]
+fn test_netting_sums_rounded_amounts() {
+    let receive = FixedRateLeg::new(schedule(), Actual360)
+        .with_notional(333.33)
+        .with_rate(0.05)
+        .build();
+    let pay = FixedRateLeg::new(schedule(), Actual360)
+        .with_notional(333.33)
+        .with_rate(0.02)
+        .build();
+    let calculator = SettlementCalculator::new(Calendar::new(WeekendsOnly), Currency::USD);
+    // the last payment, on Saturday 15th January 2022, settles after the end
+    let end = Date::new(15, Month::January, 2022);
+    let mut amounts = calculator.amounts(&receive, PayReceive::Receive, reference_date(), end);
+    amounts.extend(calculator.amounts(&pay, PayReceive::Pay, reference_date(), end));
+    let netted = calculator.net(&amounts);
+    assert_eq!(netted.len(), 3);
+    for n in &netted {
+        let gross: i64 = amounts
+            .iter()
+            .filter(|a| a.value_date == n.value_date)
+            .map(|a| a.minor_units)
+            .sum();
+        assert_eq!(n.minor_units, gross);
+    }
+    // offsetting flows disappear
+    let flat = calculator.amounts(&pay, PayReceive::Receive, reference_date(), end);
+    let gross = calculator.amounts(&pay, PayReceive::Pay, reference_date(), end);
+    assert!(calculator.net(&[flat, gross].concat()).is_empty());
+}
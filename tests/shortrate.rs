@@ -0,0 +1,98 @@
+extern crate quantlib;
+
+use quantlib::instruments::OptionType;
+use quantlib::models::{HoLee, OneFactorAffineModel, Vasicek};
+use quantlib::testutils::market::flat_curve;
+use quantlib::testutils::Rng;
+
+#[test]
+fn test_vasicek_discount_bonds() {
+    // without volatility the short rate reverts deterministically
+    let m = Vasicek::new(0.03, 0.2, 0.06, 0.0);
+    for &t in &[0.5_f64, 2.0, 10.0] {
+        let integral: f64 = 0.06 * t + (0.03 - 0.06) * (1.0 - (-0.2 * t).exp()) / 0.2;
+        assert!((m.discount(t) - (-integral).exp()).abs() < 1.0e-14);
+    }
+    // the long yield is b - sigma^2 / (2 a^2)
+    let m = Vasicek::new(0.03, 0.2, 0.06, 0.02);
+    let t = 2000.0;
+    assert!((-m.discount(t).ln() / t - (0.06 - 0.0004 / 0.08)).abs() < 1.0e-4);
+    assert!((m.discount_bond(3.0, 3.0, 0.1) - 1.0).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_ho_lee_fits_the_initial_curve() {
+    let m = HoLee::new(flat_curve(0.03), 0.01);
+    for &t in &[0.5_f64, 2.0, 10.0] {
+        assert!((m.discount(t) - (-0.03 * t).exp()).abs() < 1.0e-15);
+        assert!((m.discount_bond(0.0, t, 0.03) - m.discount(t)).abs() < 1.0e-8);
+    }
+    // a higher short rate at t lowers the bond price
+    assert!(m.discount_bond(1.0, 5.0, 0.05) < m.discount_bond(1.0, 5.0, 0.03));
+    // Ho-Lee is the Vasicek bond volatility without mean reversion
+    let v = Vasicek::new(0.03, 1.0e-8, 0.03, 0.01);
+    assert!(
+        (m.discount_bond_volatility(2.0, 7.0) - v.discount_bond_volatility(2.0, 7.0)).abs()
+            < 1.0e-8
+    );
+}
+
+#[test]
+fn test_bond_option_and_cap_floor_parity() {
+    let vasicek = Vasicek::new(0.03, 0.15, 0.05, 0.012);
+    let ho_lee = HoLee::new(flat_curve(0.03), 0.008);
+    let models: [&dyn OneFactorAffineModel; 2] = [&vasicek, &ho_lee];
+    for m in models.iter() {
+        let (t, s, k) = (2.0, 5.0, 0.9);
+        let call = m.discount_bond_option(OptionType::Call, k, t, s);
+        let put = m.discount_bond_option(OptionType::Put, k, t, s);
+        assert!((call - put - (m.discount(s) - k * m.discount(t))).abs() < 1.0e-14);
+
+        let times: Vec<f64> = (1..=20).map(|i| 0.25 * i as f64).collect();
+        let strike = 0.04;
+        let cap = m.cap_floor(OptionType::Call, strike, &times, 1.0e6);
+        let floor = m.cap_floor(OptionType::Put, strike, &times, 1.0e6);
+        let swap: f64 = times
+            .windows(2)
+            .map(|w| 1.0e6 * (m.discount(w[0]) - (1.0 + strike * (w[1] - w[0])) * m.discount(w[1])))
+            .sum();
+        assert!((cap - floor - swap).abs() < 1.0e-6);
+        assert!(cap > 0.0 && floor > 0.0);
+    }
+}
+
+#[test]
+fn test_vasicek_bond_option_monte_carlo() {
+    let m = Vasicek::new(0.03, 0.3, 0.05, 0.02);
+    let (t, s, k) = (1.0, 3.0, 0.92);
+    let steps = 50;
+    let dt = t / steps as f64;
+    let mut rng = Rng::new(11);
+    let paths = 20_000;
+    let (mut sum, mut sum2) = (0.0, 0.0);
+    for _ in 0..paths {
+        let (mut r, mut integral): (f64, f64) = (m.r0, 0.0);
+        // exact Gaussian transition, trapezoidal integral
+        let decay = (-m.a * dt).exp();
+        let sd = m.sigma * ((1.0 - decay * decay) / (2.0 * m.a)).sqrt();
+        for _ in 0..steps {
+            let next = m.b + (r - m.b) * decay + sd * rng.normal();
+            integral += 0.5 * (r + next) * dt;
+            r = next;
+        }
+        let v = (-integral).exp() * (m.discount_bond(t, s, r) - k).max(0.0);
+        sum += v;
+        sum2 += v * v;
+    }
+    let n = paths as f64;
+    let mean = sum / n;
+    let error = ((sum2 / n - mean * mean) / n).sqrt();
+    let analytic = m.discount_bond_option(OptionType::Call, k, t, s);
+    assert!(
+        (mean - analytic).abs() < 4.0 * error,
+        "{} {} {}",
+        mean,
+        analytic,
+        error
+    );
+}
@@ -0,0 +1,196 @@
+extern crate quantlib;
+
+use quantlib::instruments::OptionType;
+use quantlib::methods::montecarlo::{Numeraire, ShortRateMcEngine, ShortRatePath};
+use quantlib::models::{HullWhite, OneFactorAffineModel, Vasicek};
+use quantlib::testutils::market::flat_curve;
+
+fn grid(step: f64, end: f64) -> Vec<f64> {
+    let n = (end / step).round() as usize;
+    (1..=n).map(|i| step * i as f64).collect()
+}
+
+#[test]
+fn test_hull_white_fits_the_curve() {
+    let hw = HullWhite::new(flat_curve(0.03), 0.1, 0.01);
+    for &t in &[1.0_f64, 5.0, 10.0] {
+        assert!((hw.discount_bond(0.0, t, 0.03) - (-0.03 * t).exp()).abs() < 1.0e-8);
+    }
+    let engine = ShortRateMcEngine::new(hw, grid(0.05, 5.0), 5000, 3).with_antithetic();
+    for &(i, t) in &[(20, 1.0_f64), (100, 5.0)] {
+        let r = engine.npv(|p: &ShortRatePath| p.deflators[i]);
+        let expected = (-0.03 * t).exp();
+        assert!(
+            (r.value - expected).abs() < 4.0 * r.error_estimate + 1.0e-4,
+            "{:?} {}",
+            r,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_monte_carlo_caplets_match_analytic_values() {
+    let (t, s, k) = (2.0, 2.5, 0.035);
+    let times = grid(0.02, t);
+    let i = times.len();
+    let caplet = |p: &ShortRatePath, m: &dyn OneFactorAffineModel| {
+        let fixing = (1.0 / m.discount_bond(p.times[i], s, p.rates[i]) - 1.0) / (s - t);
+        p.deflators[i]
+            * m.discount_bond(p.times[i], s, p.rates[i])
+            * (s - t)
+            * (fixing - k).max(0.0)
+    };
+
+    let hw = HullWhite::new(flat_curve(0.03), 0.05, 0.01);
+    let analytic = hw.cap_floor(OptionType::Call, k, &[t, s], 1.0);
+    let engine = ShortRateMcEngine::new(hw, times.clone(), 20_000, 5);
+    let r = engine.npv(|p: &ShortRatePath| caplet(p, &engine.model));
+    assert!(
+        (r.value - analytic).abs() < 4.0 * r.error_estimate,
+        "{:?} {}",
+        r,
+        analytic
+    );
+
+    let vasicek = Vasicek::new(0.03, 0.2, 0.04, 0.015);
+    let analytic = vasicek.cap_floor(OptionType::Call, k, &[t, s], 1.0);
+    let engine = ShortRateMcEngine::new(vasicek, times, 20_000, 6);
+    let r = engine.npv(|p: &ShortRatePath| caplet(p, &engine.model));
+    assert!(
+        (r.value - analytic).abs() < 4.0 * r.error_estimate,
+        "{:?} {}",
+        r,
+        analytic
+    );
+}
+
+#[test]
+fn test_deflated_bonds_are_martingales_under_each_numeraire() {
+    let numeraires = vec![
+        Numeraire::BankAccount,
+        Numeraire::ForwardBond(3.0),
+        Numeraire::Annuity {
+            payment_times: vec![3.0, 4.0, 5.0],
+            accruals: vec![1.0, 1.0, 1.0],
+        },
+    ];
+    for numeraire in numeraires {
+        let hw = HullWhite::new(flat_curve(0.03), 0.1, 0.015);
+        let engine = ShortRateMcEngine::new(hw, grid(0.02, 2.0), 5000, 11)
+            .with_antithetic()
+            .with_numeraire(numeraire.clone());
+        for &maturity in &[2.0_f64, 4.0, 10.0] {
+            let r = engine
+                .npv(|p: &ShortRatePath| p.deflators[100] * p.bond(&engine.model, 100, maturity));
+            let expected = (-0.03 * maturity).exp();
+            assert!(
+                (r.value - expected).abs() < 4.0 * r.error_estimate + 2.0e-4,
+                "{:?} {:?} {}",
+                numeraire,
+                r,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_caplet_values_agree_across_measures() {
+    let (t, s, k) = (2.0, 2.5, 0.035);
+    let times = grid(0.02, t);
+    let i = times.len();
+    let caplet = |p: &ShortRatePath, m: &dyn OneFactorAffineModel| {
+        let fixing = p.fixing(m, i, s);
+        p.deflators[i] * p.bond(m, i, s) * (s - t) * (fixing - k).max(0.0)
+    };
+    let hw = HullWhite::new(flat_curve(0.03), 0.05, 0.01);
+    let analytic = hw.cap_floor(OptionType::Call, k, &[t, s], 1.0);
+    let engine = ShortRateMcEngine::new(hw, times, 20_000, 5);
+    let risk_neutral = engine.npv(|p: &ShortRatePath| caplet(p, &engine.model));
+    let engine = engine.with_numeraire(Numeraire::ForwardBond(s));
+    let forward = engine.npv(|p: &ShortRatePath| caplet(p, &engine.model));
+    for r in &[risk_neutral, forward] {
+        assert!(
+            (r.value - analytic).abs() < 4.0 * r.error_estimate,
+            "{:?} {}",
+            r,
+            analytic
+        );
+    }
+}
+
+#[test]
+fn test_swaption_under_the_annuity_measure() {
+    let (expiry, k) = (1.0, 0.03);
+    let payment_times = vec![2.0, 3.0, 4.0];
+    let accruals = vec![1.0; 3];
+    let times = grid(0.02, expiry);
+    let i = times.len();
+    let swap_rate = |p: &ShortRatePath, m: &dyn OneFactorAffineModel| {
+        let annuity: f64 = payment_times.iter().map(|&q| p.bond(m, i, q)).sum();
+        ((1.0 - p.bond(m, i, 4.0)) / annuity, annuity)
+    };
+    let hw = HullWhite::new(flat_curve(0.03), 0.1, 0.01);
+    let engine = ShortRateMcEngine::new(hw, times, 20_000, 7);
+    let risk_neutral = engine.npv(|p: &ShortRatePath| {
+        let (rate, annuity) = swap_rate(p, &engine.model);
+        p.deflators[i] * annuity * (rate - k).max(0.0)
+    });
+    let engine = engine.with_numeraire(Numeraire::Annuity {
+        payment_times: payment_times.clone(),
+        accruals,
+    });
+    let swap = engine.npv(|p: &ShortRatePath| {
+        let (rate, annuity) = swap_rate(p, &engine.model);
+        p.deflators[i] * annuity * (rate - k).max(0.0)
+    });
+    let error = (risk_neutral.error_estimate.powi(2) + swap.error_estimate.powi(2)).sqrt();
+    assert!(
+        (risk_neutral.value - swap.value).abs() < 4.0 * error,
+        "{:?} {:?}",
+        risk_neutral,
+        swap
+    );
+}
+
+/// Snowball paying `c_i = max(c_{i-1} + spread - L_i, 0)` on annual
+/// periods, `L_i` fixed at the start of each period.
+fn snowball(p: &ShortRatePath, model: &dyn OneFactorAffineModel, steps_per_year: usize) -> f64 {
+    let (spread, mut coupon, mut value) = (0.03, 0.02, 0.0);
+    for year in 1..5 {
+        let i = year * steps_per_year;
+        let end = p.times[i] + 1.0;
+        let fixing = p.fixing(model, i, end);
+        coupon = (coupon + spread - fixing).max(0.0);
+        // paid at the end of the period, valued at its start
+        value += p.deflators[i] * p.bond(model, i, end) * coupon;
+    }
+    value
+}
+
+#[test]
+fn test_snowball_pricing() {
+    // without volatility the fixings are the curve forwards
+    let hw = HullWhite::new(flat_curve(0.03), 0.1, 0.0);
+    let engine = ShortRateMcEngine::new(hw, grid(0.1, 4.0), 1, 1);
+    let r = engine.npv(|p: &ShortRatePath| snowball(p, &engine.model, 10));
+    let forward = 0.03_f64.exp() - 1.0;
+    let (mut coupon, mut expected) = (0.02, 0.0);
+    for year in 1..5 {
+        coupon = (coupon + 0.03 - forward).max(0.0);
+        expected += (-0.03 * (year + 1) as f64).exp() * coupon;
+    }
+    assert!(
+        (r.value - expected).abs() < 1.0e-6,
+        "{} {}",
+        r.value,
+        expected
+    );
+
+    // volatility adds optionality through the floors at zero
+    let hw = HullWhite::new(flat_curve(0.03), 0.1, 0.01);
+    let engine = ShortRateMcEngine::new(hw, grid(0.1, 4.0), 4000, 2).with_antithetic();
+    let r = engine.npv(|p: &ShortRatePath| snowball(p, &engine.model, 10));
+    assert!(r.value > expected);
+}
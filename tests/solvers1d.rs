@@ -0,0 +1,98 @@
+extern crate quantlib;
+
+use quantlib::errors::QuantLibError;
+use quantlib::math::{
+    bracket, checked_bracket, normal_cdf, Bisection, Brent, NewtonSafe, Solver1D,
+};
+
+const ACCURACY: f64 = 1.0e-12;
+
+fn cubic(x: f64) -> f64 {
+    x * x * x - 2.0 * x - 5.0
+}
+
+/// Black-Scholes call on spot and strike 100, one year, no rates.
+fn call(vol: f64) -> f64 {
+    let d1 = vol / 2.0;
+    100.0 * (normal_cdf(d1) - normal_cdf(d1 - vol))
+}
+
+fn check<S: Solver1D>(solver: S) {
+    let root = solver.solve(cubic, 2.0, 3.0);
+    assert!((root - 2.094_551_481_542_326_5).abs() < 1.0e-10);
+    let root = solver.solve(|x: f64| x.cos() - x, 0.0, 1.0);
+    assert!((root.cos() - root).abs() < 1.0e-10);
+    // an end of the bracket may already be the root
+    assert_eq!(solver.solve(|x: f64| x - 1.0, 1.0, 2.0), 1.0);
+}
+
+#[test]
+fn test_solvers_find_bracketed_roots() {
+    check(Brent::new(ACCURACY));
+    check(NewtonSafe::new(ACCURACY));
+    check(Bisection::new(ACCURACY));
+    let newton = NewtonSafe::new(ACCURACY);
+    let root = newton.solve_with_derivative(cubic, |x| 3.0 * x * x - 2.0, 2.0, 3.0);
+    assert!(cubic(root).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_implied_volatility() {
+    let price = call(0.25);
+    let brent = Brent::new(ACCURACY).solve_from_guess(|v| call(v) - price, 0.1, 0.05);
+    assert!((brent - 0.25).abs() < 1.0e-10);
+    let vega = |v: f64| {
+        let d1 = v / 2.0;
+        100.0 * (-d1 * d1 / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+    };
+    let newton =
+        NewtonSafe::new(ACCURACY).solve_with_derivative(|v| call(v) - price, vega, 0.01, 2.0);
+    assert!((newton - 0.25).abs() < 1.0e-10);
+}
+
+#[test]
+fn test_bracket_grows_from_guess() {
+    let (low, high) = bracket(|x| x - 10.0, 0.0, 1.0, 100);
+    assert!(low <= 10.0 && 10.0 <= high);
+    let (low, high) = bracket(|x| x + 10.0, 0.0, 1.0, 100);
+    assert!(low <= -10.0 && -10.0 <= high);
+    let root = Bisection::new(ACCURACY).solve_from_guess(|x| x * x - 2.0, 5.0, 0.5);
+    assert!((root - 2.0_f64.sqrt()).abs() < 1.0e-11);
+}
+
+#[test]
+#[should_panic(expected = "root not bracketed")]
+fn test_unbracketed_root_panics() {
+    Brent::new(ACCURACY).solve(|x| x * x + 1.0, -1.0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "failed to converge")]
+fn test_iterations_are_limited() {
+    Bisection::new(ACCURACY)
+        .with_max_iterations(5)
+        .solve(cubic, 2.0, 3.0);
+}
+
+#[test]
+fn test_checked_solvers_report_failures() {
+    let error = Brent::new(ACCURACY)
+        .checked_solve(|x| x * x + 1.0, -1.0, 1.0)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        QuantLibError::RootNotBracketed {
+            x_min: -1.0,
+            x_max: 1.0
+        }
+    );
+    let error = NewtonSafe::new(ACCURACY)
+        .with_max_iterations(2)
+        .checked_solve(cubic, 2.0, 3.0)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Newton solver failed to converge in 2 iterations"
+    );
+    assert!(checked_bracket(|x| x * x + 1.0, 0.0, 1.0, 10).is_err());
+}
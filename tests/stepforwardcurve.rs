@@ -0,0 +1,140 @@
+extern crate quantlib;
+
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::{MeetingDateCurveBuilder, StepForwardCurve};
+use quantlib::time::{Actual360, Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+type Curve = StepForwardCurve<WeekendsOnly>;
+
+fn reference_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+fn meetings() -> Vec<Date> {
+    vec![
+        Date::new(29, Month::January, 2020),
+        Date::new(18, Month::March, 2020),
+        Date::new(29, Month::April, 2020),
+        Date::new(10, Month::June, 2020),
+    ]
+}
+
+/// Policy at 1.5% with two 25bp hikes, a pause and a 50bp cut.
+fn market() -> Curve {
+    StepForwardCurve::new(
+        reference_date(),
+        meetings(),
+        vec![0.015, 0.0175, 0.02, 0.02, 0.015],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+}
+
+fn quotes(market: &Curve, dates: &[Date]) -> Vec<(Date, f64)> {
+    dates
+        .iter()
+        .map(|d| (*d, market.ois_rate(*d, Actual360)))
+        .collect()
+}
+
+fn calibrate(
+    q: &[(Date, f64)],
+) -> quantlib::termstructures::StepForwardCalibration<WeekendsOnly, Actual365Fixed> {
+    StepForwardCurve::calibrate(
+        reference_date(),
+        meetings(),
+        q,
+        Actual360,
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+}
+
+fn one_per_period() -> Vec<Date> {
+    vec![
+        Date::new(22, Month::January, 2020),
+        Date::new(15, Month::February, 2020),
+        Date::new(15, Month::April, 2020),
+        Date::new(15, Month::May, 2020),
+        Date::new(15, Month::September, 2020),
+    ]
+}
+
+#[test]
+fn test_step_forward_discounts() {
+    let c = market();
+    let t1 = c.meeting_times[0];
+    assert!((c.discount_with_time(t1) - (-0.015 * t1).exp()).abs() < 1.0e-15);
+    let t = 1.0;
+    let integral: f64 = (0..5)
+        .map(|k| {
+            let start = if k == 0 { 0.0 } else { c.meeting_times[k - 1] };
+            let end = if k == 4 { t } else { c.meeting_times[k] };
+            c.forwards[k] * (end - start)
+        })
+        .sum();
+    assert!((c.discount_with_time(t) - (-integral).exp()).abs() < 1.0e-15);
+    assert!(
+        (c.term_structure().discount_with_time(t, true) - c.discount_with_time(t)).abs() < 1.0e-15
+    );
+    assert_eq!(c.policy_rate(Date::new(1, Month::April, 2020)), 0.02);
+    assert_eq!(c.policy_rate(meetings()[3]), 0.015);
+}
+
+#[test]
+fn test_calibration_recovers_the_policy_path() {
+    let fit = calibrate(&quotes(&market(), &one_per_period()));
+    assert!(
+        fit.errors.iter().all(|e| e.abs() < 1.0e-9),
+        "{:?}",
+        fit.errors
+    );
+    for (f, e) in fit.curve.forwards.iter().zip(&market().forwards) {
+        assert!((f - e).abs() < 1.0e-8, "{} {}", f, e);
+    }
+    let steps = fit.curve.steps();
+    let expected = [0.0025, 0.0025, 0.0, -0.005];
+    for ((d, s), (m, e)) in steps.iter().zip(meetings().iter().zip(&expected)) {
+        assert_eq!(d, m);
+        assert!((s - e).abs() < 1.0e-8);
+    }
+}
+
+#[test]
+fn test_periods_without_quotes_follow_their_neighbours() {
+    // nothing matures between the March and April meetings, nor after June
+    let dates = [
+        Date::new(22, Month::January, 2020),
+        Date::new(15, Month::February, 2020),
+        Date::new(15, Month::May, 2020),
+    ];
+    let fit = calibrate(&quotes(&market(), &dates));
+    assert!(
+        fit.errors.iter().all(|e| e.abs() < 1.0e-9),
+        "{:?}",
+        fit.errors
+    );
+    let f = &fit.curve.forwards;
+    // the only constraint from February to May is on the average forward
+    assert!(f[2] > f[1] && f[3] > f[1]);
+    assert!((f[4] - f[3]).abs() < 1.0e-6);
+}
+
+#[test]
+fn test_matches_the_sequential_meeting_date_bootstrap() {
+    let market = market();
+    let dates = one_per_period();
+    let fit = calibrate(&quotes(&market, &dates));
+    let discounts: Vec<(Date, f64)> = dates.iter().map(|d| (*d, market.discount(*d))).collect();
+    let sequential = MeetingDateCurveBuilder::new(
+        reference_date(),
+        meetings(),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+    )
+    .bootstrap(&discounts);
+    for k in 1..=300 {
+        let d = reference_date().add_days(k);
+        assert!((fit.curve.discount(d) - sequential.curve.discount(d)).abs() < 1.0e-9);
+    }
+}
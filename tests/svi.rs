@@ -0,0 +1,147 @@
+extern crate quantlib;
+
+use quantlib::math::simplex_minimize;
+use quantlib::termstructures::{
+    BlackVolTermStructure, SsviSurface, SviParameters, SviSmile, SviSurface,
+};
+use quantlib::utils::Parallelism;
+
+#[test]
+fn test_simplex_rosenbrock() {
+    let rosenbrock = |x: &[f64]| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+    let x = simplex_minimize(rosenbrock, &[-1.2, 1.0], 0.5, 1.0e-16, 10000);
+    assert!((x[0] - 1.0).abs() < 1.0e-5 && (x[1] - 1.0).abs() < 1.0e-5);
+}
+
+#[test]
+fn test_svi_fit_recovers_parameters() {
+    let expected = SviParameters::new(0.02, 0.15, -0.4, 0.05, 0.2);
+    let k: Vec<f64> = (0..21).map(|i| -0.6 + 0.06 * i as f64).collect();
+    let w: Vec<f64> = k.iter().map(|k| expected.total_variance(*k)).collect();
+    let fitted = SviParameters::fit(&k, &w);
+    for (x, y) in k.iter().zip(&w) {
+        assert!((fitted.total_variance(*x) - y).abs() < 1.0e-8);
+    }
+    assert!((fitted.rho - expected.rho).abs() < 1.0e-4);
+    assert!((fitted.sigma - expected.sigma).abs() < 1.0e-4);
+}
+
+#[test]
+fn test_svi_fit_respects_lee_bound() {
+    // quotes with wings too steep for any arbitrage-free smile.
+    let k: Vec<f64> = (0..11).map(|i| -1.0 + 0.2 * i as f64).collect();
+    let w: Vec<f64> = k.iter().map(|k| 0.04 + 3.0 * k.abs()).collect();
+    let fitted = SviParameters::fit(&k, &w);
+    assert!(fitted.max_wing_slope() <= 2.0 + 1.0e-12);
+    assert!(fitted.a + fitted.b * fitted.sigma * (1.0 - fitted.rho * fitted.rho).sqrt() >= 0.0);
+}
+
+#[test]
+fn test_svi_surface() {
+    let p1 = SviParameters::new(0.01, 0.1, -0.5, 0.0, 0.2);
+    let p2 = SviParameters::new(0.03, 0.12, -0.4, 0.0, 0.25);
+    let s1 = SviSmile::new(0.5, 100.0, p1);
+    let s2 = SviSmile::new(1.0, 102.0, p2);
+    let surface = SviSurface::new(vec![s1, s2]);
+
+    assert!((surface.black_vol(0.5, 90.0) - s1.vol(90.0)).abs() < 1.0e-14);
+    assert!((surface.black_vol(1.0, 110.0) - s2.vol(110.0)).abs() < 1.0e-14);
+    // halfway, at the interpolated forward.
+    let f = surface.forward(0.75);
+    assert!((f - (100.0_f64 * 102.0).sqrt()).abs() < 1.0e-10);
+    let w = 0.5 * (p1.total_variance(0.0) + p2.total_variance(0.0));
+    assert!((surface.black_variance(0.75, f) - w).abs() < 1.0e-14);
+    assert!(surface.black_forward_variance(0.5, 1.0, 100.0) > 0.0);
+}
+
+#[test]
+fn test_svi_surface_fits_smiles_in_parallel() {
+    let quoted = [
+        SviSmile::new(0.5, 100.0, SviParameters::new(0.01, 0.1, -0.5, 0.0, 0.2)),
+        SviSmile::new(1.0, 102.0, SviParameters::new(0.03, 0.12, -0.4, 0.0, 0.25)),
+        SviSmile::new(2.0, 104.0, SviParameters::new(0.06, 0.14, -0.3, 0.05, 0.3)),
+    ];
+    let strikes: Vec<Vec<f64>> = quoted
+        .iter()
+        .map(|s| {
+            (0..15)
+                .map(|i| s.forward * (0.6 + 0.06 * i as f64))
+                .collect()
+        })
+        .collect();
+    let vols: Vec<Vec<f64>> = quoted
+        .iter()
+        .zip(&strikes)
+        .map(|(s, k)| k.iter().map(|k| s.vol(*k)).collect())
+        .collect();
+    let expiries: Vec<f64> = quoted.iter().map(|s| s.expiry).collect();
+    let forwards: Vec<f64> = quoted.iter().map(|s| s.forward).collect();
+    let fit = |parallelism| SviSurface::fit(&expiries, &forwards, &strikes, &vols, parallelism);
+    let (sequential, parallel) = (fit(Parallelism::Sequential), fit(Parallelism::Threads(3)));
+    for ((s, p), q) in sequential.smiles.iter().zip(&parallel.smiles).zip(&quoted) {
+        assert_eq!(s.parameters, p.parameters);
+        assert!((p.vol(95.0) - q.vol(95.0)).abs() < 1.0e-6);
+    }
+}
+
+#[test]
+fn test_ssvi_calibration() {
+    let expiries = vec![0.25, 0.5, 1.0, 2.0];
+    let forwards = vec![100.0, 100.5, 101.0, 102.0];
+    let truth = SsviSurface::new(
+        -0.6,
+        1.2,
+        0.4,
+        expiries.clone(),
+        vec![0.01, 0.018, 0.035, 0.066],
+        forwards.clone(),
+    );
+    let strikes: Vec<Vec<f64>> = forwards
+        .iter()
+        .map(|f| (0..9).map(|i| f * (-0.4 + 0.1 * i as f64).exp()).collect())
+        .collect();
+    let vols: Vec<Vec<f64>> = expiries
+        .iter()
+        .zip(&strikes)
+        .map(|(t, s)| s.iter().map(|k| truth.black_vol(*t, *k)).collect())
+        .collect();
+
+    let fitted = SsviSurface::calibrate(expiries.clone(), forwards, &strikes, &vols);
+    assert!((fitted.rho - truth.rho).abs() < 1.0e-4);
+    assert!((fitted.eta - truth.eta).abs() < 1.0e-4);
+    assert!((fitted.gamma - truth.gamma).abs() < 1.0e-4);
+    for (t, s) in expiries.iter().zip(&strikes) {
+        for k in s {
+            assert!((fitted.black_vol(*t, *k) - truth.black_vol(*t, *k)).abs() < 1.0e-6);
+        }
+    }
+    assert!(fitted.eta * (1.0 + fitted.rho.abs()) <= 2.0);
+}
+
+#[test]
+fn test_ssvi_calibration_removes_calendar_arbitrage() {
+    let expiries = vec![0.5, 1.0];
+    let forwards = vec![100.0, 100.0];
+    let strikes = vec![vec![80.0, 90.0, 100.0, 110.0, 120.0]; 2];
+    // the ATM total variance of the second expiry is below the first one.
+    let vols = vec![
+        vec![0.32, 0.3, 0.28, 0.27, 0.265],
+        vec![0.22, 0.2, 0.19, 0.185, 0.18],
+    ];
+    let surface = SsviSurface::calibrate(expiries, forwards, &strikes, &vols);
+    assert_eq!(surface.atm_variances[0], surface.atm_variances[1]);
+    assert!(surface.black_forward_variance(0.5, 1.0, 100.0) >= 0.0);
+}
+
+#[test]
+#[should_panic(expected = "ATM total variance must not decrease")]
+fn test_ssvi_rejects_decreasing_atm_variance() {
+    SsviSurface::new(
+        -0.5,
+        1.0,
+        0.5,
+        vec![0.5, 1.0],
+        vec![0.04, 0.03],
+        vec![100.0, 100.0],
+    );
+}
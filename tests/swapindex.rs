@@ -0,0 +1,128 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{CashFlow, CmsLeg, Coupon};
+use quantlib::indexes::{IborIndex, Index, InterestRateIndex, SwapIndex};
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::YieldTermStructure as Yts;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::flat_curve;
+use quantlib::time::{
+    Actual360, Actual365Fixed, BusinessDayConvention, Calendar, Date, DateGenerator, Month, Period,
+    Schedule, TimeUnit, WeekendsOnly,
+};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Euribor = IborIndex<WeekendsOnly, Curve, Actual360>;
+type EurSwap = SwapIndex<WeekendsOnly, Curve, Actual365Fixed, Actual360>;
+
+fn swap_index(years: i64, curve: &Rc<Curve>) -> EurSwap {
+    let euribor: Euribor = IborIndex::new(
+        "Euribor",
+        Period::new(6, TimeUnit::Months),
+        2,
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        true,
+        Actual360,
+        Some(Rc::clone(curve)),
+    );
+    SwapIndex::new(
+        "EuriborSwapIsdaFixA",
+        Period::new(years, TimeUnit::Years),
+        2,
+        Calendar::new(WeekendsOnly),
+        Period::new(1, TimeUnit::Years),
+        BusinessDayConvention::ModifiedFollowing,
+        Actual365Fixed {},
+        Rc::new(euribor),
+    )
+}
+
+fn fixing_date() -> Date {
+    Date::new(15, Month::January, 2020)
+}
+
+#[test]
+fn test_forecast_is_the_fair_rate_of_the_underlying_swap() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(10, &curve);
+    let rate = index.fixing(fixing_date());
+    let swap = index.underlying_swap(fixing_date(), rate);
+    let start = index.value_date(fixing_date());
+    assert!(swap.npv(curve.as_ref(), start).abs() < 1.0e-12);
+
+    // the fair rate is the discount factor decrease over the annuity.
+    let annuity: f64 = swap
+        .fixed_leg
+        .iter()
+        .map(|c| c.accrual_period() * curve.discount(c.base.payment_date, true))
+        .sum();
+    let end = index.maturity_date(start);
+    let expected = (curve.discount(start, true) - curve.discount(end, true)) / annuity;
+    assert!((rate - expected).abs() < 1.0e-6);
+    assert_eq!(swap.fixed_leg.len(), 10);
+    assert_eq!(swap.floating_leg.len(), 20);
+}
+
+#[test]
+fn test_longer_tenors_fix_on_later_maturities() {
+    let curve = Rc::new(flat_curve(0.02));
+    let two = swap_index(2, &curve);
+    let ten = swap_index(10, &curve);
+    let start = ten.value_date(fixing_date());
+    assert_eq!(start, Date::new(17, Month::January, 2020));
+    assert_eq!(
+        ten.maturity_date(start),
+        Date::new(17, Month::January, 2030)
+    );
+    assert_eq!(two.name(), "EuriborSwapIsdaFixA2Y");
+    assert_eq!(ten.name(), "EuriborSwapIsdaFixA10Y");
+}
+
+#[test]
+fn test_stored_fixings_take_precedence() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(10, &curve);
+    let past = Date::new(10, Month::January, 2020);
+    index.add_fixing(past, 0.0125);
+    assert_eq!(index.fixing(past), 0.0125);
+    assert!((index.fixing(fixing_date()) - 0.0125).abs() > 1.0e-4);
+}
+
+#[test]
+#[should_panic(expected = "invalid fixing date")]
+fn test_rejects_fixings_on_holidays() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = swap_index(10, &curve);
+    index.add_fixing(Date::new(11, Month::January, 2020), 0.0125);
+}
+
+#[test]
+fn test_cms_coupons_pay_the_geared_swap_rate() {
+    let curve = Rc::new(flat_curve(0.02));
+    let index = Rc::new(swap_index(10, &curve));
+    let schedule = Schedule::new(
+        Date::new(17, Month::January, 2020),
+        Date::new(17, Month::January, 2022),
+        Period::new(1, TimeUnit::Years),
+        Calendar::new(WeekendsOnly),
+        BusinessDayConvention::ModifiedFollowing,
+        BusinessDayConvention::ModifiedFollowing,
+        DateGenerator::Backward,
+        false,
+    );
+    let leg = CmsLeg::new(schedule, Rc::clone(&index), Actual360)
+        .with_notional(100.0)
+        .with_gearing(0.5)
+        .with_spread(0.001)
+        .build();
+    assert_eq!(leg.len(), 2);
+    for coupon in &leg {
+        let fixing = index.fixing(coupon.fixing_date);
+        assert_eq!(coupon.rate(), 0.5 * fixing + 0.001);
+        let amount = 100.0 * coupon.rate() * coupon.accrual_period();
+        assert!((coupon.amount() - amount).abs() < 1.0e-12);
+    }
+    assert_eq!(leg[0].fixing_date, Date::new(15, Month::January, 2020));
+}
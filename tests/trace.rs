@@ -0,0 +1,68 @@
+#![cfg(feature = "tracing")]
+extern crate quantlib;
+
+use quantlib::math::simplex_minimize;
+use quantlib::termstructures::SviParameters;
+use quantlib::utils::trace::{self, FieldValue, Level, MemorySubscriber};
+use std::sync::Arc;
+
+fn fit_smile() {
+    let svi = SviParameters::new(0.02, 0.15, -0.4, 0.05, 0.2);
+    let k: Vec<f64> = (0..11).map(|i| -0.5 + 0.1 * i as f64).collect();
+    let w: Vec<f64> = k.iter().map(|k| svi.total_variance(*k)).collect();
+    SviParameters::fit(&k, &w);
+}
+
+// The subscriber is process-wide, so all scenarios run in a single test.
+#[test]
+fn test_trace_records() {
+    let subscriber = Arc::new(MemorySubscriber::new(Level::Trace));
+    trace::set_subscriber(subscriber.clone());
+
+    simplex_minimize(|x| (x[0] - 1.0).powi(2), &[0.0], 0.5, 1.0e-12, 500);
+    let records = subscriber.records();
+    let iterations: Vec<_> = records
+        .iter()
+        .filter(|r| r.name == "simplex.iteration")
+        .collect();
+    assert!(!iterations.is_empty());
+    assert!(iterations
+        .iter()
+        .all(|r| r.spans == vec!["simplex_minimize"]));
+    let finished = records
+        .iter()
+        .find(|r| r.name == "simplex.finished")
+        .unwrap();
+    assert_eq!(finished.field("converged"), Some(&FieldValue::Bool(true)));
+    assert_eq!(
+        finished.field("iterations"),
+        Some(&FieldValue::Int(iterations.len() as i64))
+    );
+    let span = records.last().unwrap();
+    assert_eq!(span.name, "simplex_minimize");
+    assert!(span.elapsed.is_some() && span.spans.is_empty());
+
+    // nested spans of a calibration.
+    subscriber.clear();
+    fit_smile();
+    let records = subscriber.records();
+    let inner = records
+        .iter()
+        .find(|r| r.name == "simplex.finished")
+        .unwrap();
+    assert_eq!(inner.spans, vec!["svi.fit", "simplex_minimize"]);
+    assert!(format!("{}", inner)
+        .starts_with("Debug svi.fit:simplex_minimize:simplex.finished iterations="));
+    assert_eq!(records.last().unwrap().name, "svi.fit");
+
+    // records below the subscriber level are not built.
+    let info = Arc::new(MemorySubscriber::new(Level::Info));
+    trace::set_subscriber(info.clone());
+    fit_smile();
+    let names: Vec<_> = info.records().iter().map(|r| r.name).collect();
+    assert_eq!(names, vec!["svi.fit"]);
+
+    trace::clear_subscriber();
+    fit_smile();
+    assert_eq!(info.records().len(), 1);
+}
@@ -0,0 +1,134 @@
+extern crate quantlib;
+
+use quantlib::instruments::{
+    EarlyExerciseOption, Exercise, OptionType, ValuationStatus, VanillaOption,
+};
+use quantlib::methods::lattices::BinomialTreeType;
+use quantlib::pricingengines::{
+    AnalyticEuropeanEngine, BinomialVanillaEngine, FdBlackScholesVanillaEngine, McEuropeanEngine,
+};
+use quantlib::processes::BlackScholesMertonProcess;
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::volatility::BlackConstantVol;
+use quantlib::termstructures::YieldTermStructure;
+use quantlib::testutils::market::{flat_curve, reference_date};
+use quantlib::time::{Date, WeekendsOnly};
+use std::rc::Rc;
+
+type Curve = YieldTermStructure<WeekendsOnly, SimpleQuote>;
+type Valuer = Box<dyn Fn(&VanillaOption) -> (ValuationStatus, f64)>;
+
+fn market() -> (Rc<Curve>, Rc<Curve>, Rc<BlackConstantVol>) {
+    (
+        Rc::new(flat_curve(0.05)),
+        Rc::new(flat_curve(0.02)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(0.25))),
+    )
+}
+
+/// Every vanilla engine, valuing at a spot of 100.
+fn engines() -> Vec<(&'static str, Valuer)> {
+    let (r, q, vol) = market();
+    let analytic = AnalyticEuropeanEngine::new(r.clone(), q.clone(), vol.clone());
+    let fd = FdBlackScholesVanillaEngine::new(r.clone(), q.clone(), vol.clone(), 101, 50);
+    let tree = BinomialVanillaEngine::new(
+        r.clone(),
+        q.clone(),
+        vol.clone(),
+        BinomialTreeType::CoxRossRubinstein,
+        100,
+    );
+    let process = BlackScholesMertonProcess::new(SimpleQuote::new(100.0), r.clone(), q, vol);
+    let mc = McEuropeanEngine::new(Rc::new(process), r, 4, 1_000, 7);
+    vec![
+        (
+            "analytic",
+            Box::new(move |o: &VanillaOption| {
+                let v = analytic.valuation(o, 100.0);
+                (v.status, v.npv)
+            }),
+        ),
+        (
+            "finite differences",
+            Box::new(move |o: &VanillaOption| {
+                let v = fd.valuation(&(*o).into(), 100.0);
+                (v.status, v.npv)
+            }),
+        ),
+        (
+            "binomial",
+            Box::new(move |o: &VanillaOption| {
+                let v = tree.valuation(&(*o).into(), 100.0);
+                (v.status, v.npv)
+            }),
+        ),
+        (
+            "monte carlo",
+            Box::new(move |o: &VanillaOption| {
+                let (v, result) = mc.valuation(o);
+                (v.status, result.value)
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn test_engines_value_expired_options_at_zero() {
+    let expiries: [Date; 2] = [
+        reference_date().add_days(-1),
+        reference_date().add_months(-1),
+    ];
+    for (name, valuation) in engines() {
+        for option_type in &[OptionType::Call, OptionType::Put] {
+            for expiry in &expiries {
+                let expired = VanillaOption::new(*option_type, 100.0, *expiry);
+                let (status, npv) = valuation(&expired);
+                assert_eq!(status, ValuationStatus::Expired, "{}", name);
+                assert_eq!(npv, 0.0, "{}", name);
+            }
+        }
+        let live = VanillaOption::new(OptionType::Put, 100.0, reference_date().add_months(6));
+        let (status, npv) = valuation(&live);
+        assert_eq!(status, ValuationStatus::Live, "{}", name);
+        assert!(npv > 0.0, "{}", name);
+    }
+}
+
+#[test]
+fn test_american_exercise_is_worth_a_premium() {
+    let (r, q, vol) = (
+        Rc::new(flat_curve(0.06)),
+        Rc::new(flat_curve(0.0)),
+        Rc::new(BlackConstantVol::new(SimpleQuote::new(0.2))),
+    );
+    let expiry = reference_date().add_days(365);
+    let european = VanillaOption::new(OptionType::Put, 40.0, expiry);
+    let american = EarlyExerciseOption::new(
+        OptionType::Put,
+        40.0,
+        Exercise::American {
+            earliest: reference_date(),
+            latest: expiry,
+        },
+    );
+    let analytic =
+        AnalyticEuropeanEngine::new(r.clone(), q.clone(), vol.clone()).npv(&european, 36.0);
+    let fd = FdBlackScholesVanillaEngine::new(r.clone(), q.clone(), vol.clone(), 401, 400);
+    let tree = BinomialVanillaEngine::new(r, q, vol, BinomialTreeType::CoxRossRubinstein, 1000);
+    // the premium of Longstaff and Schwartz's first case, 4.4867 - 3.8443
+    for (name, european, american) in &[
+        (
+            "finite differences",
+            fd.npv(&european.into(), 36.0),
+            fd.npv(&american, 36.0),
+        ),
+        (
+            "binomial",
+            tree.npv(&european.into(), 36.0),
+            tree.npv(&american, 36.0),
+        ),
+    ] {
+        assert!((european - analytic).abs() < 2.0e-3, "{}", name);
+        assert!((american - european - 0.6424).abs() < 3.0e-3, "{}", name);
+    }
+}
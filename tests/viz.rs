@@ -0,0 +1,59 @@
+#![cfg(feature = "viz")]
+extern crate quantlib;
+
+use quantlib::testutils::market::flat_discount;
+use quantlib::viz::{curves_to_csv, curves_to_json, surface_to_csv, surface_to_json};
+use quantlib::viz::{CurveData, SurfaceData, SvgPlot};
+
+fn zero_curve() -> CurveData {
+    let discount = flat_discount(0.03);
+    CurveData::sample("zero", &[1.0, 2.0, 5.0], |t| -discount(t).ln() / t)
+}
+
+#[test]
+fn test_curves_to_csv() {
+    let curve = zero_curve();
+    let spread = CurveData::sample("spread, bp", &curve.x, |_| 25.0);
+    let csv = curves_to_csv(&[curve, spread]);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "x,zero,\"spread, bp\"");
+    assert!(lines[1].starts_with("1,0.03"));
+    assert!(lines[3].ends_with(",25"));
+}
+
+#[test]
+fn test_curves_to_json() {
+    let curve = CurveData::new("a\"b", vec![1.0, 2.0], vec![0.5, f64::NAN]);
+    assert_eq!(
+        curves_to_json(&[curve]),
+        "[{\"name\":\"a\\\"b\",\"x\":[1,2],\"y\":[0.5,null]}]"
+    );
+}
+
+#[test]
+fn test_surface_export() {
+    let surface = SurfaceData::sample("vol", &[0.5, 1.0], &[90.0, 100.0, 110.0], |t, k| {
+        0.25 * t + (k - 100.0).abs() / 8.0
+    });
+    let csv = surface_to_csv(&surface);
+    assert_eq!(csv.lines().count(), 1 + 6);
+    assert_eq!(csv.lines().nth(2).unwrap(), "0.5,100,0.125");
+    let json = surface_to_json(&surface);
+    assert!(json.starts_with("{\"name\":\"vol\",\"expiries\":[0.5,1],"));
+
+    let smile = surface.smile(1);
+    assert_eq!(smile.x, vec![90.0, 100.0, 110.0]);
+    assert_eq!(surface.term_structure(0).y.len(), 2);
+}
+
+#[test]
+fn test_svg_plot() {
+    let svg = SvgPlot::new("Zero <rates>", "Years", "Rate")
+        .with_series(zero_curve())
+        .render();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("Zero &lt;rates&gt;"));
+    assert_eq!(svg.matches("<polyline").count(), 1);
+}
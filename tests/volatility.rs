@@ -0,0 +1,95 @@
+extern crate quantlib;
+
+use quantlib::definitions::{Time, Volatility};
+use quantlib::instruments::{
+    ForwardVolatilityAgreement, OptionType, VolatilityIndexFuture, VolatilityIndexOption,
+};
+use quantlib::math::normal_cdf;
+use quantlib::pricingengines::volatility::{smile_future_price, variance_swap_strike};
+use quantlib::pricingengines::{black_formula, DisplacedLognormalEngine, HestonVariance};
+use quantlib::termstructures::BlackVolTermStructure;
+
+struct FlatVol(Volatility);
+
+impl BlackVolTermStructure for FlatVol {
+    fn black_vol(&self, _t: Time, _strike: f64) -> Volatility {
+        self.0
+    }
+}
+
+// total variance 0.04 t + 0.01 t^2, independent of strike.
+struct RisingVol;
+
+impl BlackVolTermStructure for RisingVol {
+    fn black_vol(&self, t: Time, _strike: f64) -> Volatility {
+        (0.04 + 0.01 * t).sqrt()
+    }
+}
+
+#[test]
+fn test_normal_cdf() {
+    assert_eq!(normal_cdf(0.0), 0.5);
+    assert!((normal_cdf(1.96) - 0.975_002_104_851_779_5).abs() < 1.0e-15);
+    assert!((normal_cdf(-1.0) - 0.158_655_253_931_457_07).abs() < 1.0e-15);
+    assert!((normal_cdf(-8.0) / 6.220_960_574_271_785e-16 - 1.0).abs() < 1.0e-7);
+}
+
+#[test]
+fn test_black_formula() {
+    let atm = black_formula(OptionType::Call, 100.0, 100.0, 0.2, 1.0, 0.0);
+    assert!((atm - 7.965_567_455_405_804).abs() < 1.0e-12);
+    // put-call parity, with and without displacement.
+    for d in &[0.0, 5.0] {
+        let c = black_formula(OptionType::Call, 18.0, 20.0, 0.5, 0.97, *d);
+        let p = black_formula(OptionType::Put, 18.0, 20.0, 0.5, 0.97, *d);
+        assert!((c - p - 0.97 * 2.0).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn test_variance_swap_replication() {
+    let k = variance_swap_strike(&FlatVol(0.25), 100.0, 0.5, 2001);
+    assert!((k - 0.0625).abs() < 1.0e-5);
+}
+
+#[test]
+fn test_forward_volatility_agreement() {
+    let fva = ForwardVolatilityAgreement::new(1.0e5, 0.25, 1.0, 2.0);
+    let fair = fva.fair_volatility(&RisingVol, 100.0);
+    assert!((fair - 0.07_f64.sqrt()).abs() < 1.0e-12);
+    let npv = fva.npv(&RisingVol, 100.0, 0.95);
+    assert!((npv - 1.0e5 * (0.07_f64.sqrt() - 0.25) * 0.95).abs() < 1.0e-8);
+}
+
+#[test]
+fn test_volatility_index_future() {
+    let future = VolatilityIndexFuture::new(0.25, 30.0 / 365.0, 1000.0);
+
+    // without vol of variance the index is deterministic.
+    let deterministic = HestonVariance::new(0.04, 2.0, 0.04, 0.0);
+    assert!((deterministic.future_price(&future) - 0.2).abs() < 1.0e-12);
+
+    let heston = HestonVariance::new(0.04, 2.0, 0.04, 0.5);
+    let (mean, var) = heston.index_squared_moments(future.expiry, future.tenor);
+    assert!((mean - 0.04).abs() < 1.0e-12 && var > 0.0);
+    let price = heston.future_price(&future);
+    assert!(price < 0.2 && price > 0.17);
+
+    // a flat smile has flat forward variance.
+    let smile = smile_future_price(&future, &FlatVol(0.2), 100.0, 100.0, 0.0, 2001);
+    assert!((smile - 0.2).abs() < 1.0e-5);
+    let adjusted = smile_future_price(&future, &FlatVol(0.2), 100.0, 100.0, var, 2001);
+    assert!((adjusted - price).abs() < 1.0e-5);
+}
+
+#[test]
+fn test_volatility_index_option() {
+    let call = VolatilityIndexOption::new(OptionType::Call, 0.22, 0.25);
+    let put = VolatilityIndexOption::new(OptionType::Put, 0.22, 0.25);
+    let engine = DisplacedLognormalEngine::new(0.8, 0.05);
+    let (c, p) = (engine.npv(&call, 0.2, 0.99), engine.npv(&put, 0.2, 0.99));
+    assert!((c - p - 0.99 * (0.2 - 0.22)).abs() < 1.0e-15);
+    // a larger displacement lowers the relative volatility of the index.
+    let shifted = DisplacedLognormalEngine::new(0.8, 0.1).npv(&call, 0.2, 0.99);
+    assert!(shifted > c);
+}
@@ -0,0 +1,125 @@
+extern crate quantlib;
+
+use quantlib::models::{
+    close_to_close_volatility, ewma_volatility, garman_klass_volatility, log_returns,
+    parkinson_volatility, Garch11, IntervalPrice,
+};
+use quantlib::testutils::generators::Rng;
+use quantlib::time::{Date, Month, TimeSeries};
+use quantlib::utils::Parallelism;
+
+fn normal(rng: &mut Rng) -> f64 {
+    let (u1, u2) = (rng.uniform().max(1.0e-300), rng.uniform());
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+fn day(i: i64) -> Date {
+    Date::new(1, Month::January, 2020).add_days(i)
+}
+
+/// Daily closes with constant volatility `sigma`.
+fn closes(sigma: f64, n: usize, seed: u64) -> TimeSeries<f64> {
+    let mut rng = Rng::new(seed);
+    let mut series = TimeSeries::new();
+    let mut price = 100.0;
+    series.insert(day(0), price);
+    for i in 1..n {
+        price *= (sigma / 252.0_f64.sqrt() * normal(&mut rng)).exp();
+        series.insert(day(i as i64), price);
+    }
+    series
+}
+
+#[test]
+fn test_close_to_close_recovers_constant_volatility() {
+    let series = closes(0.2, 5000, 7);
+    assert_eq!(log_returns(&series).len(), 4999);
+    let vol = close_to_close_volatility(&series, 252.0);
+    assert!((vol - 0.2).abs() < 0.01, "{}", vol);
+}
+
+#[test]
+fn test_range_estimators_on_simulated_intraday_paths() {
+    let mut rng = Rng::new(11);
+    let sigma: f64 = 0.3;
+    let steps = 100;
+    let mut prices = TimeSeries::new();
+    let mut price = 100.0;
+    for d in 0..1000 {
+        let open = price;
+        let (mut high, mut low) = (price, price);
+        for _ in 0..steps {
+            price *= (sigma / (252.0 * steps as f64).sqrt() * normal(&mut rng)).exp();
+            high = f64::max(high, price);
+            low = f64::min(low, price);
+        }
+        prices.insert(day(d), IntervalPrice::new(open, high, low, price));
+    }
+    // discrete sampling of the range biases both estimators slightly low.
+    let parkinson = parkinson_volatility(&prices, 252.0);
+    let garman_klass = garman_klass_volatility(&prices, 252.0);
+    assert!((parkinson - sigma).abs() < 0.03, "{}", parkinson);
+    assert!((garman_klass - sigma).abs() < 0.03, "{}", garman_klass);
+}
+
+#[test]
+fn test_ewma_follows_the_recursion() {
+    let series = TimeSeries::from_pairs(
+        &[day(0), day(1), day(2), day(3)],
+        &[100.0, 101.0, 99.0, 99.5],
+    );
+    let ewma = ewma_volatility(&series, 0.94, 252.0);
+    assert_eq!(ewma.dates(), vec![day(1), day(2), day(3)]);
+    let r: Vec<f64> = log_returns(&series).values();
+    let mut v = r[0] * r[0];
+    assert!((ewma.get(day(1)).unwrap() - (v * 252.0).sqrt()).abs() < 1.0e-15);
+    v = 0.94 * v + 0.06 * r[1] * r[1];
+    v = 0.94 * v + 0.06 * r[2] * r[2];
+    assert!((ewma.get(day(3)).unwrap() - (v * 252.0).sqrt()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_garch_fit_recovers_simulated_parameters() {
+    let truth = Garch11::new(2.0e-6, 0.08, 0.9);
+    let mut rng = Rng::new(3);
+    let mut v = truth.long_run_variance();
+    let returns: Vec<f64> = (0..6000)
+        .map(|_| {
+            let r = v.sqrt() * normal(&mut rng);
+            v = truth.omega + truth.alpha * r * r + truth.beta * v;
+            r
+        })
+        .collect();
+    let fitted = Garch11::fit(&returns);
+    assert!((fitted.alpha - 0.08).abs() < 0.03, "{:?}", fitted);
+    assert!((fitted.beta - 0.9).abs() < 0.04, "{:?}", fitted);
+    assert!((fitted.long_run_variance() / truth.long_run_variance() - 1.0).abs() < 0.25);
+    assert!(fitted.log_likelihood(&returns) >= truth.log_likelihood(&returns));
+
+    // forecasts revert to the long run variance.
+    let long_run = fitted.long_run_variance();
+    assert!((fitted.forecast(4.0 * long_run, 0) - 4.0 * long_run).abs() < 1.0e-18);
+    assert!((fitted.forecast(4.0 * long_run, 1000) - long_run).abs() < 1.0e-3 * long_run);
+}
+
+#[test]
+fn test_garch_fits_several_series_in_parallel() {
+    let truth = Garch11::new(2.0e-6, 0.08, 0.9);
+    let series: Vec<Vec<f64>> = (0..3)
+        .map(|seed| {
+            let mut rng = Rng::new(seed);
+            let mut v = truth.long_run_variance();
+            (0..1000)
+                .map(|_| {
+                    let r = v.sqrt() * normal(&mut rng);
+                    v = truth.omega + truth.alpha * r * r + truth.beta * v;
+                    r
+                })
+                .collect()
+        })
+        .collect();
+    let fitted = Garch11::fit_all(&series, Parallelism::Threads(3));
+    for (returns, garch) in series.iter().zip(&fitted) {
+        assert_eq!(*garch, Garch11::fit(returns));
+    }
+}
@@ -0,0 +1,71 @@
+extern crate quantlib;
+
+use quantlib::quotes::SimpleQuote;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{YieldTermStructure, YieldTermStructureBuilder};
+use quantlib::testutils::market::reference_date;
+use quantlib::time::{Actual360, Actual365Fixed, Calendar, Date, Month, WeekendsOnly};
+
+fn discount() -> Box<dyn Fn(f64) -> f64> {
+    Box::new(|t| (-0.02 * t).exp())
+}
+
+#[test]
+fn test_builder_defaults_match_new() {
+    let built: YieldTermStructure<WeekendsOnly, SimpleQuote> =
+        YieldTermStructureBuilder::new(Calendar::new(WeekendsOnly), reference_date(), discount())
+            .build();
+    let direct: YieldTermStructure<WeekendsOnly, SimpleQuote> = YieldTermStructure::new(
+        Calendar::new(WeekendsOnly),
+        reference_date(),
+        Actual365Fixed,
+        0,
+        vec![],
+        vec![],
+        discount(),
+    );
+    let date = Date::new(15, Month::March, 2023);
+    assert_eq!(built.discount(date, false), direct.discount(date, false));
+    assert_eq!(built.settlement_days(), 0);
+}
+
+#[test]
+fn test_builder_sets_day_counter_and_jumps() {
+    let curve =
+        YieldTermStructureBuilder::new(Calendar::new(WeekendsOnly), reference_date(), discount())
+            .with_day_counter(Actual360)
+            .with_settlement_days(2)
+            .with_jumps(vec![SimpleQuote::new(0.99)], vec![])
+            .build();
+    assert_eq!(curve.settlement_days(), 2);
+    let year_end = Date::new(31, Month::December, 2020);
+    let t = curve.time_from_reference(year_end.add_days(1));
+    assert!((t - 352.0 / 360.0).abs() < 1.0e-15);
+    assert!(
+        (curve.discount(year_end.add_days(1), false) - 0.99 * (-0.02 * t).exp()).abs() < 1.0e-15
+    );
+    assert!((curve.discount(year_end, false) - (-0.02 * (351.0 / 360.0f64)).exp()).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_setters_move_the_jumps() {
+    let mut curve =
+        YieldTermStructureBuilder::new(Calendar::new(WeekendsOnly), reference_date(), discount())
+            .with_jumps(
+                vec![SimpleQuote::new(0.99)],
+                vec![Date::new(30, Month::June, 2020)],
+            )
+            .build();
+    let date = Date::new(1, Month::September, 2020);
+    let before = curve.discount(date, false);
+    // the jump is now behind the reference date and no longer applies
+    curve.set_reference_date(Date::new(1, Month::July, 2020));
+    let t = curve.time_from_reference(date);
+    assert!((curve.discount(date, false) - (-0.02 * t).exp()).abs() < 1.0e-15);
+    assert!(before < curve.discount(date, false));
+
+    curve.set_reference_date(reference_date());
+    curve.set_jumps_at(vec![], vec![]);
+    let t = curve.time_from_reference(date);
+    assert!((curve.discount(date, false) - (-0.02 * t).exp()).abs() < 1.0e-15);
+}
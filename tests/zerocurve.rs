@@ -0,0 +1,159 @@
+extern crate quantlib;
+
+use quantlib::cashflows::{self as cf, Base, FixedRateCoupon};
+use quantlib::errors::QuantLibError;
+use quantlib::math::Interpolation;
+use quantlib::termstructures::traits::{TermStructure, YieldTermStructure as Yts};
+use quantlib::termstructures::{Compounding, DiscountCurve, ZeroCurve};
+use quantlib::testutils::market::{flat_rate, reference_date};
+use quantlib::time::{Actual365Fixed, Calendar, Date, Frequency, WeekendsOnly};
+
+fn dates() -> Vec<Date> {
+    [0, 12, 24, 60, 120]
+        .iter()
+        .map(|m| reference_date().add_months(*m))
+        .collect()
+}
+
+fn zero_curve(interpolation: Interpolation) -> ZeroCurve<WeekendsOnly> {
+    ZeroCurve::new(
+        dates(),
+        vec![0.010, 0.010, 0.015, 0.020, 0.025],
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+}
+
+/// The discount curve on the nodes of the zero curve.
+fn discount_curve(interpolation: Interpolation) -> DiscountCurve<WeekendsOnly> {
+    let zero = zero_curve(Interpolation::Linear);
+    let discounts = zero.times.iter().map(|t| zero.discount_with_time(*t));
+    DiscountCurve::new(
+        dates(),
+        discounts.collect(),
+        Calendar::new(WeekendsOnly),
+        Actual365Fixed,
+        interpolation,
+    )
+}
+
+fn annual_coupons(coupon: f64, years: i64) -> Vec<FixedRateCoupon<Actual365Fixed>> {
+    let start = reference_date();
+    (0..years)
+        .map(|k| {
+            let (d1, d2) = (start.add_months(12 * k), start.add_months(12 * (k + 1)));
+            let base = Base {
+                nominal: 100.0,
+                day_counter: Actual365Fixed {},
+                payment_date: d2,
+                accrual_start_date: d1,
+                accrual_end_date: d2,
+                reference_period_start: d1,
+                reference_period_end: d2,
+            };
+            FixedRateCoupon::new(base, flat_rate(coupon))
+        })
+        .collect()
+}
+
+fn bond_price<Y: Yts>(curve: &Y) -> f64 {
+    let maturity = reference_date().add_months(84);
+    cf::npv(&annual_coupons(0.03, 7), curve, false, reference_date())
+        + 100.0 * curve.discount(maturity, true)
+}
+
+#[test]
+fn test_zero_curve_discounts() {
+    let curve = zero_curve(Interpolation::Linear);
+    let date = reference_date().add_months(36);
+    let t = curve.time_from_reference(date);
+    // between the 2Y and 5Y nodes
+    let zero = 0.015 + 0.005 * (t - curve.times[2]) / (curve.times[3] - curve.times[2]);
+    assert!((curve.zero_rate_at(t) - zero).abs() < 1.0e-15);
+    assert!((curve.discount(date) - (-zero * t).exp()).abs() < 1.0e-15);
+    // flat zero rate past the last node
+    let t = curve.time_from_reference(reference_date().add_months(240));
+    assert!((curve.discount_with_time(t) - (-0.025 * t).exp()).abs() < 1.0e-15);
+    assert!((curve.instantaneous_forward(t) - 0.025).abs() < 1.0e-15);
+}
+
+#[test]
+fn test_curves_agree_on_nodes() {
+    let zero = zero_curve(Interpolation::NaturalCubic);
+    for interpolation in &[
+        Interpolation::Linear,
+        Interpolation::LogLinear,
+        Interpolation::NaturalCubic,
+    ] {
+        let discount = discount_curve(*interpolation);
+        for date in dates() {
+            assert!(
+                (Yts::discount(&zero, date, false) - Yts::discount(&discount, date, false)).abs()
+                    < 1.0e-15
+            );
+        }
+    }
+}
+
+#[test]
+fn test_engines_consume_either_curve() {
+    let zero = zero_curve(Interpolation::LogLinear);
+    let discount = discount_curve(Interpolation::LogLinear);
+    let (p_zero, p_discount) = (bond_price(&zero), bond_price(&discount));
+    // the schemes agree on the nodes and differ in between, by about 10bp
+    // in rate on the 5Y to 10Y segment
+    assert!((p_zero - p_discount).abs() < 1.0);
+    assert!(p_zero > p_discount);
+    assert!((bond_price(&zero.term_structure()) - p_zero).abs() < 1.0e-12);
+    assert!((bond_price(&discount.term_structure()) - p_discount).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_trait_zero_rates() {
+    let mut curve = zero_curve(Interpolation::NaturalCubic);
+    let date = reference_date().add_months(60);
+    let rate = curve.zero_rate(
+        date,
+        Actual365Fixed,
+        Compounding::Continuous,
+        Frequency::Annual,
+        false,
+    );
+    assert!((rate.rate - 0.020).abs() < 1.0e-12);
+    let annual = curve.annual_zero_rate(date, false);
+    assert!((annual.rate - (0.020_f64.exp() - 1.0)).abs() < 1.0e-12);
+    let mut discount = discount_curve(Interpolation::ConvexMonotone);
+    let t = TermStructure::time_from_reference(&discount, date);
+    let rate = discount.zero_rate_with_time(t, Compounding::Continuous, Frequency::Annual, false);
+    assert!((rate.rate - 0.020).abs() < 1.0e-12);
+}
+
+#[test]
+fn test_curves_extrapolate_only_when_allowed() {
+    let zero = zero_curve(Interpolation::Linear);
+    let discount = discount_curve(Interpolation::LogLinear);
+    let (last, past) = (
+        reference_date().add_months(120),
+        reference_date().add_months(240),
+    );
+    assert_eq!((zero.max_date(), discount.max_date()), (last, last));
+    assert!(matches!(
+        zero.checked_discount(past, false),
+        Err(QuantLibError::BeyondMaxTime { .. })
+    ));
+    assert!(matches!(
+        discount.checked_discount(past, false),
+        Err(QuantLibError::BeyondMaxTime { .. })
+    ));
+    // the log-linear curve keeps the forward of its last segment
+    let (t5, t10, t20) = (
+        discount.times[3],
+        discount.times[4],
+        discount.time_from_reference(past),
+    );
+    let forward = (discount.discounts[3] / discount.discounts[4]).ln() / (t10 - t5);
+    let expected = discount.discounts[4] * (-forward * (t20 - t10)).exp();
+    assert!((Yts::discount(&discount, past, true) - expected).abs() < 1.0e-15);
+    assert_eq!(Yts::discount(&zero, past, true), zero.discount(past));
+}